@@ -212,6 +212,7 @@ class NativeType:
             "multipoint",
             "multilinestring",
             "multipolygon",
+            "mixed",
             "geometry",
             "geometrycollection",
         ],
@@ -234,6 +235,7 @@ class NativeType:
             "multipoint",
             "multilinestring",
             "multipolygon",
+            "mixed",
             "geometry",
             "geometrycollection",
             "box",
@@ -246,8 +248,8 @@ class NativeType:
         Args:
             type: The string type of the geometry. One of `"point"`, `"linestring"`,
                 `"polygon"`, `"multipoint"`, `"multilinestring"`, `"multipolygon"`,
-                `"geometry"`, `"geometrycollection"`, `"box"`.
-            dimension: The coordinate dimension. Either "XY" or "XYZ". Defaults to None.
+                `"mixed"`, `"geometry"`, `"geometrycollection"`, `"box"`.
+            dimension: The coordinate dimension. One of "XY", "XYZ", "XYM" or "XYZM". Defaults to None.
             coord_type: The coordinate type. Defaults to None.
         """
     def __arrow_c_schema__(self) -> object:
@@ -42,3 +42,11 @@ class Dimension(StrEnum):
     XYZ = auto()
     """Three dimensions, X, Y, and Z
     """
+
+    XYM = auto()
+    """Three dimensions, X, Y, and M
+    """
+
+    XYZM = auto()
+    """Four dimensions, X, Y, Z, and M
+    """
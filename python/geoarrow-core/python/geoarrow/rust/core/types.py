@@ -14,6 +14,6 @@ CoordTypeT = Literal["interleaved", "separated"]
 """Acceptable coord_type strings.
 """
 
-DimensionT = Literal["XY", "XYZ", "xy", "xyz"]
+DimensionT = Literal["XY", "XYZ", "XYM", "XYZM", "xy", "xyz", "xym", "xyzm"]
 """Acceptable dimension strings.
 """
@@ -104,8 +104,16 @@ fn pyarray_to_shapely(py: Python, input: PyArray) -> PyGeoArrowResult<Bound<PyAn
                     multipolygon_arr(py, array.as_ref().as_multi_polygon().clone())
                 }
                 Rect(_) => rect_arr(py, array.as_ref().as_rect().clone()),
-                GeometryCollection(_, _) => via_wkb(py, array),
+                Mixed(_, _) | GeometryCollection(_, _) => via_wkb(py, array),
                 Geometry(_) => via_wkb(py, array),
+                LargeLineString(_, _)
+                | LargePolygon(_, _)
+                | LargeMultiPoint(_, _)
+                | LargeMultiLineString(_, _)
+                | LargeMultiPolygon(_, _)
+                | LargeMixed(_, _)
+                | LargeGeometryCollection(_, _)
+                | LargeGeometry(_) => via_wkb(py, array),
             }
         }
         AnyType::Serialized(typ) => {
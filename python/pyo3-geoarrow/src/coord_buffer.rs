@@ -149,6 +149,10 @@ impl<'py> FromPyObject<'py> for PyCoordBuffer {
                             InterleavedCoordBuffer::new(values.values().clone(), Dimension::XYZ)
                                 .into(),
                         )),
+                        4 => Ok(Self(
+                            InterleavedCoordBuffer::new(values.values().clone(), Dimension::XYZM)
+                                .into(),
+                        )),
                         _ => Err(PyValueError::new_err(format!(
                             "Unsupported fixed size list size {}",
                             list_size
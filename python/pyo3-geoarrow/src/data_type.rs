@@ -68,6 +68,10 @@ impl PyNativeType {
                 coord_type.unwrap().into(),
                 dimension.unwrap().into(),
             ))),
+            "mixed" => Ok(Self(NativeType::Mixed(
+                coord_type.unwrap().into(),
+                dimension.unwrap().into(),
+            ))),
             "geometry" => Ok(Self(NativeType::Geometry(coord_type.unwrap().into()))),
             "geometrycollection" => Ok(Self(NativeType::GeometryCollection(
                 coord_type.unwrap().into(),
@@ -128,6 +132,8 @@ impl PyNativeType {
         match self.0.dimension() {
             Some(Dimension::XY) => Ok(coord_type.getattr(intern!(py, "XY"))?.into()),
             Some(Dimension::XYZ) => Ok(coord_type.getattr(intern!(py, "XYZ"))?.into()),
+            Some(Dimension::XYM) => Ok(coord_type.getattr(intern!(py, "XYM"))?.into()),
+            Some(Dimension::XYZM) => Ok(coord_type.getattr(intern!(py, "XYZM"))?.into()),
             None => Ok(py.None()),
         }
     }
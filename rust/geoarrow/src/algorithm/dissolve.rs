@@ -0,0 +1,390 @@
+//! Merge the rows of a table that share the same values in a set of columns.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::compute::{cast_with_options, concat_batches, take, CastOptions};
+use arrow::row::{RowConverter, SortField};
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int64Type};
+use arrow_array::{Array, ArrayRef, Float64Array, Int64Array, RecordBatch, UInt32Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use indexmap::IndexMap;
+
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
+use crate::algorithm::geo_index::geo_geometries;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{CoordType, GeometryBuilder};
+use crate::error::{GeoArrowError, Result};
+use crate::table::Table;
+use crate::ArrayBase;
+
+/// How the values of a non-geometry column are combined within each group in [`dissolve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    /// The sum of the non-null values, or null if there are none. Integer columns are summed as
+    /// `Int64` and floating-point columns as `Float64`.
+    Sum,
+
+    /// The number of non-null values, as `Int64`.
+    Count,
+
+    /// The value of the first row of the group.
+    First,
+}
+
+impl Aggregation {
+    fn aggregate(
+        &self,
+        field: &Field,
+        column: &ArrayRef,
+        groups: &[Vec<u32>],
+    ) -> Result<(Field, ArrayRef)> {
+        let name = field.name();
+        match self {
+            Self::Sum if field.data_type().is_integer() => {
+                let values = cast_with_options(column, &DataType::Int64, &strict_cast())?;
+                let values = values.as_primitive::<Int64Type>();
+                let sums = groups
+                    .iter()
+                    .map(|rows| {
+                        let mut sum = None;
+                        for row in valid_rows(values, rows) {
+                            sum = Some(
+                                sum.unwrap_or(0i64)
+                                    .checked_add(values.value(row))
+                                    .ok_or(GeoArrowError::Overflow)?,
+                            );
+                        }
+                        Ok(sum)
+                    })
+                    .collect::<Result<Int64Array>>()?;
+                Ok((Field::new(name, DataType::Int64, true), Arc::new(sums)))
+            }
+            Self::Sum if field.data_type().is_floating() => {
+                let values = cast_with_options(column, &DataType::Float64, &strict_cast())?;
+                let values = values.as_primitive::<Float64Type>();
+                let sums = groups
+                    .iter()
+                    .map(|rows| {
+                        valid_rows(values, rows)
+                            .map(|row| values.value(row))
+                            .reduce(|a, b| a + b)
+                    })
+                    .collect::<Float64Array>();
+                Ok((Field::new(name, DataType::Float64, true), Arc::new(sums)))
+            }
+            Self::Sum => Err(GeoArrowError::NotYetImplemented(format!(
+                "Sum aggregation of column {name} with type {}",
+                field.data_type()
+            ))),
+            Self::Count => {
+                let counts = groups
+                    .iter()
+                    .map(|rows| valid_rows(column.as_ref(), rows).count() as i64)
+                    .collect::<Vec<_>>();
+                Ok((
+                    Field::new(name, DataType::Int64, false),
+                    Arc::new(Int64Array::from(counts)),
+                ))
+            }
+            Self::First => {
+                let first = first_rows(groups);
+                Ok((field.clone(), take(column.as_ref(), &first, None)?))
+            }
+        }
+    }
+}
+
+fn strict_cast() -> CastOptions<'static> {
+    CastOptions {
+        safe: false,
+        ..Default::default()
+    }
+}
+
+fn valid_rows<'a>(array: &'a dyn Array, rows: &'a [u32]) -> impl Iterator<Item = usize> + 'a {
+    rows.iter()
+        .map(|row| *row as usize)
+        .filter(|row| array.is_valid(*row))
+}
+
+fn first_rows(groups: &[Vec<u32>]) -> UInt32Array {
+    groups.iter().map(|rows| rows[0]).collect::<Vec<_>>().into()
+}
+
+/// The rows of `batch` grouped by their values in the columns `by`, in order of first
+/// appearance. With no columns, all rows form a single group.
+fn group_rows(batch: &RecordBatch, by: &[usize]) -> Result<Vec<Vec<u32>>> {
+    if batch.num_rows() == 0 {
+        return Ok(vec![]);
+    }
+    if by.is_empty() {
+        return Ok(vec![(0..batch.num_rows() as u32).collect()]);
+    }
+
+    let columns = by
+        .iter()
+        .map(|i| batch.column(*i).clone())
+        .collect::<Vec<_>>();
+    let converter = RowConverter::new(
+        columns
+            .iter()
+            .map(|column| SortField::new(column.data_type().clone()))
+            .collect(),
+    )?;
+    let rows = converter.convert_columns(&columns)?;
+
+    let mut groups: IndexMap<_, Vec<u32>> = IndexMap::new();
+    for (i, row) in rows.iter().enumerate() {
+        groups.entry(row.owned()).or_default().push(i as u32);
+    }
+    Ok(groups.into_values().collect())
+}
+
+/// The union of `geometries` with GEOS, or `None` if there are none.
+#[cfg(feature = "geos")]
+fn union(geometries: Vec<&geo::Geometry>) -> Result<Option<crate::io::geos::scalar::GEOSGeometry>> {
+    use crate::io::geos::scalar::{to_geos_geometry, GEOSGeometry};
+    use geos::Geom;
+
+    if geometries.is_empty() {
+        return Ok(None);
+    }
+    let geometries = geometries
+        .into_iter()
+        .map(to_geos_geometry)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let collection = geos::Geometry::create_geometry_collection(geometries)?;
+    Ok(Some(GEOSGeometry::new(collection.unary_union()?)))
+}
+
+/// The union of `geometries` with [`geo::BooleanOps`], or `None` if there are none.
+///
+/// Only polygonal geometries are supported. The polygons are merged pairwise in a balanced tree
+/// (a cascaded union), so each input takes part in a logarithmic number of unions rather than
+/// every input being merged into one ever-growing accumulator.
+#[cfg(not(feature = "geos"))]
+fn union(geometries: Vec<&geo::Geometry>) -> Result<Option<geo::Geometry>> {
+    use geo::BooleanOps;
+
+    let mut parts = geometries
+        .into_iter()
+        .map(|geometry| match geometry {
+            geo::Geometry::Polygon(g) => Ok(geo::MultiPolygon::new(vec![g.clone()])),
+            geo::Geometry::MultiPolygon(g) => Ok(g.clone()),
+            geo::Geometry::Rect(g) => Ok(geo::MultiPolygon::new(vec![g.to_polygon()])),
+            _ => Err(GeoArrowError::NotYetImplemented(
+                "Dissolving non-polygonal geometries requires the geos feature".to_string(),
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    while parts.len() > 1 {
+        parts = parts
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => left.union(right),
+                [single] => single.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    Ok(parts.pop().map(geo::Geometry::MultiPolygon))
+}
+
+/// Merge the rows of `table` that have equal values in the columns `by`, unioning their
+/// geometries.
+///
+/// The union is computed on the default geometry column, with GEOS when the `geos` feature is
+/// enabled and with [`geo::BooleanOps`] otherwise, in which case only polygonal geometries are
+/// supported. Null geometries are ignored, and a group without any non-null geometry gets a null
+/// geometry. If `by` is empty, all rows are merged into one.
+///
+/// The output has one row per group, in order of first appearance. Its columns are the `by`
+/// columns, then one column per entry of `aggs` named after the aggregated column, then the
+/// geometry column as a [`GeometryArray`][crate::array::GeometryArray]. Columns that are neither
+/// grouped on nor aggregated are dropped.
+///
+/// Where overlapping polygons cross, the crossings are computed in the plane, which misplaces them
+/// for polygons whose edges are declared spherical. `policy` decides whether such a table is
+/// rejected or dissolved anyway with a warning.
+pub fn dissolve(
+    table: &Table,
+    by: &[&str],
+    aggs: &[(&str, Aggregation)],
+    policy: SphericalEdgesPolicy,
+) -> Result<Table> {
+    let schema = table.schema();
+    let geometry_index = table.default_geometry_column_idx()?;
+
+    let by = by
+        .iter()
+        .map(|name| Ok(schema.index_of(name)?))
+        .collect::<Result<Vec<_>>>()?;
+    let aggs = aggs
+        .iter()
+        .map(|(name, agg)| Ok((schema.index_of(name)?, *agg)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut seen = HashSet::new();
+    for i in by.iter().chain(aggs.iter().map(|(i, _)| i)) {
+        let name = schema.field(*i).name();
+        if *i == geometry_index {
+            return Err(GeoArrowError::General(format!(
+                "Cannot group or aggregate the geometry column {name} in dissolve"
+            )));
+        }
+        if !seen.insert(*i) {
+            return Err(GeoArrowError::General(format!(
+                "Column {name} is used more than once in dissolve"
+            )));
+        }
+    }
+
+    let geometry_column = table.geometry_column(Some(geometry_index))?;
+    let chunks = geometry_column.geometry_chunks();
+    let (coord_type, metadata) = match chunks.first() {
+        Some(chunk) => (chunk.coord_type(), chunk.metadata()),
+        None => (CoordType::default(), Arc::new(ArrayMetadata::default())),
+    };
+    for chunk in chunks.iter() {
+        check_planar_edges("dissolve", &chunk.metadata(), policy)?;
+    }
+    let geometries = chunks
+        .iter()
+        .flat_map(|chunk| geo_geometries(chunk.as_ref()))
+        .collect::<Vec<_>>();
+
+    let batch = concat_batches(schema, table.batches())?;
+    let groups = group_rows(&batch, &by)?;
+
+    let mut fields = vec![];
+    let mut columns = vec![];
+    let first = first_rows(&groups);
+    for i in by {
+        fields.push(schema.field(i).clone());
+        columns.push(take(batch.column(i).as_ref(), &first, None)?);
+    }
+    for (i, agg) in aggs {
+        let (field, column) = agg.aggregate(schema.field(i), batch.column(i), &groups)?;
+        fields.push(field);
+        columns.push(column);
+    }
+
+    let merged = groups
+        .iter()
+        .map(|rows| {
+            union(
+                rows.iter()
+                    .filter_map(|row| geometries[*row as usize].as_ref())
+                    .collect(),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let merged =
+        GeometryBuilder::from_nullable_geometries(&merged, coord_type, metadata, true)?.finish();
+    fields.push(
+        merged
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_name(schema.field(geometry_index).name()),
+    );
+    columns.push(merged.to_array_ref());
+
+    let schema: SchemaRef = Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    Table::try_new(vec![batch], schema)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::StringArray;
+
+    use super::*;
+    use crate::algorithm::geo::Area;
+    use crate::array::{AsNativeArray, PolygonArray};
+    use crate::datatypes::Dimension;
+
+    fn counties() -> Table {
+        let squares = vec![
+            geo::Rect::new((0., 0.), (1., 1.)).to_polygon(),
+            geo::Rect::new((1., 0.), (2., 1.)).to_polygon(),
+            geo::Rect::new((5., 5.), (6., 6.)).to_polygon(),
+        ];
+        let geometry: PolygonArray = (squares.as_slice(), Dimension::XY).into();
+        let schema: SchemaRef = Schema::new(vec![
+            Field::new("state", DataType::Utf8, false),
+            Field::new("population", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, false),
+            geometry.extension_field().as_ref().clone(),
+        ])
+        .into();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(vec!["a", "a", "b"])),
+                Arc::new(arrow_array::Int32Array::from(vec![Some(10), None, Some(5)])),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])),
+                geometry.to_array_ref(),
+            ],
+        )
+        .unwrap();
+        Table::try_new(vec![batch], schema).unwrap()
+    }
+
+    #[test]
+    fn dissolve_by_state() {
+        let dissolved = dissolve(
+            &counties(),
+            &["state"],
+            &[
+                ("population", Aggregation::Sum),
+                ("name", Aggregation::First),
+            ],
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(dissolved.len(), 2);
+        assert_eq!(dissolved.num_columns(), 4);
+
+        let batch = &dissolved.batches()[0];
+        let states = batch.column(0).as_string::<i32>();
+        assert_eq!(states.value(0), "a");
+        assert_eq!(states.value(1), "b");
+        let population = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(population.values(), &[10, 5]);
+        let names = batch.column(2).as_string::<i32>();
+        assert_eq!(names.value(0), "x");
+
+        let geometry = dissolved.geometry_column(None).unwrap();
+        let areas = geometry.geometry_chunks()[0]
+            .as_ref()
+            .as_geometry()
+            .unsigned_area();
+        assert_eq!(areas.values(), &[2., 1.]);
+    }
+
+    #[test]
+    fn dissolve_all_with_count() {
+        let dissolved = dissolve(
+            &counties(),
+            &[],
+            &[("population", Aggregation::Count)],
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(dissolved.len(), 1);
+        let batch = &dissolved.batches()[0];
+        assert_eq!(batch.column(0).as_primitive::<Int64Type>().values(), &[2]);
+
+        assert!(dissolve(
+            &counties(),
+            &["state"],
+            &[("state", Aggregation::First)],
+            SphericalEdgesPolicy::Error,
+        )
+        .is_err());
+    }
+}
@@ -246,11 +246,24 @@ impl AffineOps<&AffineTransform> for &dyn NativeArray {
             MultiPoint(_, _) => impl_downcast!(as_multi_point),
             MultiLineString(_, _) => impl_downcast!(as_multi_line_string),
             MultiPolygon(_, _) => impl_downcast!(as_multi_polygon),
+            Mixed(_, _) => Arc::new(self.as_mixed().affine_transform(transform)?),
             GeometryCollection(_, _) => {
                 Arc::new(self.as_geometry_collection().affine_transform(transform)?)
             }
             Rect(_) => impl_downcast!(as_rect),
             Geometry(_) => Arc::new(self.as_geometry().affine_transform(transform)?),
+            LargeLineString(_, _)
+            | LargePolygon(_, _)
+            | LargeMultiPoint(_, _)
+            | LargeMultiLineString(_, _)
+            | LargeMultiPolygon(_, _)
+            | LargeMixed(_, _)
+            | LargeGeometryCollection(_, _)
+            | LargeGeometry(_) => {
+                return Err(GeoArrowError::NotYetImplemented(
+                    "affine transform of large-offset geometry arrays".to_string(),
+                ))
+            }
         };
         Ok(result)
     }
@@ -323,6 +336,7 @@ impl AffineOps<&AffineTransform> for &dyn ChunkedNativeArray {
             MultiPoint(_, _) => impl_downcast!(as_multi_point),
             MultiLineString(_, _) => impl_downcast!(as_multi_line_string),
             MultiPolygon(_, _) => impl_downcast!(as_multi_polygon),
+            Mixed(_, _) => Arc::new(self.as_mixed().affine_transform(transform)?),
             GeometryCollection(_, _) => {
                 Arc::new(self.as_geometry_collection().affine_transform(transform)?)
             }
@@ -1,3 +1,4 @@
+use crate::algorithm::geo::has_spherical_edges;
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
@@ -5,11 +6,16 @@ use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedNativeArra
 use crate::datatypes::NativeType;
 use crate::error::Result;
 use crate::trait_::NativeScalar;
-use crate::NativeArray;
+use crate::{ArrayBase, NativeArray};
 use arrow_array::Float64Array;
 use geo::prelude::Area as GeoArea;
+use geo::prelude::GeodesicArea as _GeodesicArea;
 
-/// Signed and unsigned planar area of a geometry.
+/// Signed and unsigned area of a geometry.
+///
+/// The area is planar, unless the array's metadata declares spherical
+/// [`edges`][crate::array::metadata::ArrayMetadata::edges]. In that case the geodesic area in
+/// square meters is computed instead, as with [`GeodesicArea`][super::GeodesicArea].
 ///
 /// # Examples
 ///
@@ -71,6 +77,9 @@ zero_impl!(PointArray);
 zero_impl!(LineStringArray);
 zero_impl!(MultiPointArray);
 zero_impl!(MultiLineStringArray);
+zero_impl!(LargeLineStringArray);
+zero_impl!(LargeMultiPointArray);
+zero_impl!(LargeMultiLineStringArray);
 
 macro_rules! iter_geo_impl {
     ($type:ty) => {
@@ -78,11 +87,19 @@ macro_rules! iter_geo_impl {
             type Output = Float64Array;
 
             fn signed_area(&self) -> Self::Output {
-                self.unary_primitive(|geom| geom.to_geo().signed_area())
+                if has_spherical_edges(&self.metadata()) {
+                    self.unary_primitive(|geom| geom.to_geo().geodesic_area_signed())
+                } else {
+                    self.unary_primitive(|geom| geom.to_geo().signed_area())
+                }
             }
 
             fn unsigned_area(&self) -> Self::Output {
-                self.unary_primitive(|geom| geom.to_geo().unsigned_area())
+                if has_spherical_edges(&self.metadata()) {
+                    self.unary_primitive(|geom| geom.to_geo().geodesic_area_unsigned())
+                } else {
+                    self.unary_primitive(|geom| geom.to_geo().unsigned_area())
+                }
             }
         }
     };
@@ -94,6 +111,11 @@ iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(RectArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl Area for &dyn NativeArray {
     type Output = Result<Float64Array>;
@@ -104,13 +126,22 @@ impl Area for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().signed_area(),
             LineString(_, _) => self.as_line_string().signed_area(),
+            LargeLineString(_, _) => self.as_large_line_string().signed_area(),
             Polygon(_, _) => self.as_polygon().signed_area(),
+            LargePolygon(_, _) => self.as_large_polygon().signed_area(),
             MultiPoint(_, _) => self.as_multi_point().signed_area(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().signed_area(),
             MultiLineString(_, _) => self.as_multi_line_string().signed_area(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().signed_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().signed_area(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().signed_area(),
+            Mixed(_, _) => self.as_mixed().signed_area(),
+            LargeMixed(_, _) => self.as_large_mixed().signed_area(),
             GeometryCollection(_, _) => self.as_geometry_collection().signed_area(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().signed_area(),
             Rect(_) => self.as_rect().signed_area(),
             Geometry(_) => self.as_geometry().signed_area(),
+            LargeGeometry(_) => self.as_large_geometry().signed_area(),
         };
         Ok(result)
     }
@@ -121,13 +152,22 @@ impl Area for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().unsigned_area(),
             LineString(_, _) => self.as_line_string().unsigned_area(),
+            LargeLineString(_, _) => self.as_large_line_string().unsigned_area(),
             Polygon(_, _) => self.as_polygon().unsigned_area(),
+            LargePolygon(_, _) => self.as_large_polygon().unsigned_area(),
             MultiPoint(_, _) => self.as_multi_point().unsigned_area(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().unsigned_area(),
             MultiLineString(_, _) => self.as_multi_line_string().unsigned_area(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().unsigned_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().unsigned_area(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().unsigned_area(),
+            Mixed(_, _) => self.as_mixed().unsigned_area(),
+            LargeMixed(_, _) => self.as_large_mixed().unsigned_area(),
             GeometryCollection(_, _) => self.as_geometry_collection().unsigned_area(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().unsigned_area(),
             Rect(_) => self.as_rect().unsigned_area(),
             Geometry(_) => self.as_geometry().unsigned_area(),
+            LargeGeometry(_) => self.as_large_geometry().unsigned_area(),
         };
         Ok(result)
     }
@@ -156,13 +196,22 @@ impl Area for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().signed_area(),
             LineString(_, _) => self.as_line_string().signed_area(),
+            LargeLineString(_, _) => self.as_large_line_string().signed_area(),
             Polygon(_, _) => self.as_polygon().signed_area(),
+            LargePolygon(_, _) => self.as_large_polygon().signed_area(),
             MultiPoint(_, _) => self.as_multi_point().signed_area(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().signed_area(),
             MultiLineString(_, _) => self.as_multi_line_string().signed_area(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().signed_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().signed_area(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().signed_area(),
+            Mixed(_, _) => self.as_mixed().signed_area(),
+            LargeMixed(_, _) => self.as_large_mixed().signed_area(),
             GeometryCollection(_, _) => self.as_geometry_collection().signed_area(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().signed_area(),
             Rect(_) => self.as_rect().signed_area(),
-            Geometry(_) => self.as_geometry().unsigned_area(),
+            Geometry(_) => self.as_geometry().signed_area(),
+            LargeGeometry(_) => self.as_large_geometry().signed_area(),
         }
     }
 
@@ -172,21 +221,35 @@ impl Area for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().unsigned_area(),
             LineString(_, _) => self.as_line_string().unsigned_area(),
+            LargeLineString(_, _) => self.as_large_line_string().unsigned_area(),
             Polygon(_, _) => self.as_polygon().unsigned_area(),
+            LargePolygon(_, _) => self.as_large_polygon().unsigned_area(),
             MultiPoint(_, _) => self.as_multi_point().unsigned_area(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().unsigned_area(),
             MultiLineString(_, _) => self.as_multi_line_string().unsigned_area(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().unsigned_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().unsigned_area(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().unsigned_area(),
+            Mixed(_, _) => self.as_mixed().unsigned_area(),
+            LargeMixed(_, _) => self.as_large_mixed().unsigned_area(),
             GeometryCollection(_, _) => self.as_geometry_collection().unsigned_area(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().unsigned_area(),
             Rect(_) => self.as_rect().unsigned_area(),
             Geometry(_) => self.as_geometry().unsigned_area(),
+            LargeGeometry(_) => self.as_large_geometry().unsigned_area(),
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::sync::Arc;
+
     use super::*;
-    use crate::test::polygon::p_array;
+    use crate::algorithm::geo::GeodesicArea;
+    use crate::array::metadata::{ArrayMetadata, Edges};
+    use crate::datatypes::Dimension;
+    use crate::test::polygon::{p0, p1, p_array};
 
     #[test]
     fn area() {
@@ -194,4 +257,20 @@ mod test {
         let area = arr.unsigned_area();
         assert_eq!(area, Float64Array::new(vec![28., 18.].into(), None));
     }
+
+    #[test]
+    fn spherical_edges_use_geodesic_area() {
+        let metadata = Arc::new(ArrayMetadata::default().with_edges(Edges::Spherical));
+        let arr = PolygonBuilder::from_polygons(
+            &[p0(), p1()],
+            Dimension::XY,
+            CoordType::Interleaved,
+            metadata,
+        )
+        .finish();
+
+        assert_eq!(arr.unsigned_area(), arr.geodesic_area_unsigned());
+        assert_eq!(arr.signed_area(), arr.geodesic_area_signed());
+        assert_ne!(arr.unsigned_area(), p_array().unsigned_area());
+    }
 }
@@ -0,0 +1,531 @@
+use std::collections::HashMap;
+
+use arrow_array::OffsetSizeTrait;
+use geo::{BooleanOps as _BooleanOps, OpType};
+use geo_traits::{CoordTrait, GeometryTrait};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedMultiPolygonArray, ChunkedNativeArray};
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geo::geometry_to_geo;
+use crate::trait_::{ArrayAccessor, GeometryArraySelfMethods};
+use crate::NativeArray;
+
+/// Convert a polygonal geometry to a `MultiPolygon`.
+pub(crate) fn to_multi_polygon(geometry: geo::Geometry) -> Result<geo::MultiPolygon> {
+    match geometry {
+        geo::Geometry::Polygon(g) => Ok(geo::MultiPolygon::new(vec![g])),
+        geo::Geometry::MultiPolygon(g) => Ok(g),
+        geo::Geometry::Rect(g) => Ok(geo::MultiPolygon::new(vec![g.to_polygon()])),
+        geo::Geometry::Triangle(g) => Ok(geo::MultiPolygon::new(vec![g.to_polygon()])),
+        _ => Err(GeoArrowError::IncorrectType(
+            "BooleanOps is only implemented for polygonal geometries".into(),
+        )),
+    }
+}
+
+/// Convert every geometry of a polygonal `array` to a `MultiPolygon`.
+fn to_multi_polygons(array: &dyn NativeArray) -> Result<Vec<Option<geo::MultiPolygon>>> {
+    use NativeType::*;
+
+    let geometries: Vec<Option<geo::MultiPolygon>> = match array.data_type() {
+        Polygon(_, _) => array
+            .as_polygon()
+            .iter_geo()
+            .map(|g| g.map(|g| geo::MultiPolygon::new(vec![g])))
+            .collect(),
+        LargePolygon(_, _) => array
+            .as_large_polygon()
+            .iter_geo()
+            .map(|g| g.map(|g| geo::MultiPolygon::new(vec![g])))
+            .collect(),
+        MultiPolygon(_, _) => array.as_multi_polygon().iter_geo().collect(),
+        LargeMultiPolygon(_, _) => array.as_large_multi_polygon().iter_geo().collect(),
+        Geometry(_) => array
+            .as_geometry()
+            .iter_geo()
+            .map(|g| g.map(to_multi_polygon).transpose())
+            .collect::<Result<_>>()?,
+        LargeGeometry(_) => array
+            .as_large_geometry()
+            .iter_geo()
+            .map(|g| g.map(to_multi_polygon).transpose())
+            .collect::<Result<_>>()?,
+        _ => {
+            return Err(GeoArrowError::IncorrectType(
+                "BooleanOps is only implemented for Polygon, MultiPolygon and Geometry arrays"
+                    .into(),
+            ))
+        }
+    };
+    Ok(geometries)
+}
+
+/// The coordinates of a Polygon or MultiPolygon array, used to restore the Z and M values of
+/// unchanged vertices.
+fn input_coords(array: &dyn NativeArray) -> Option<CoordBuffer> {
+    use NativeType::*;
+
+    match array.data_type() {
+        Polygon(_, _) => Some(array.as_polygon().coords().clone()),
+        LargePolygon(_, _) => Some(array.as_large_polygon().coords().clone()),
+        MultiPolygon(_, _) => Some(array.as_multi_polygon().coords().clone()),
+        LargeMultiPolygon(_, _) => Some(array.as_large_multi_polygon().coords().clone()),
+        _ => None,
+    }
+}
+
+/// Build the output array with the dimension of `template`.
+///
+/// The boolean operations of [`geo`] are planar. For inputs with Z or M values, each output
+/// vertex takes the extra values of an input vertex at the same position, or NaN if the vertex
+/// was created by the operation.
+pub(crate) fn finish_multi_polygons(
+    geometries: Vec<Option<geo::MultiPolygon>>,
+    template: &dyn NativeArray,
+    inputs: &[&dyn NativeArray],
+) -> MultiPolygonArray {
+    let dim = template.data_type().dimension().unwrap_or(Dimension::XY);
+    let output = MultiPolygonBuilder::from_nullable_multi_polygons(
+        &geometries,
+        Dimension::XY,
+        template.coord_type(),
+        template.metadata(),
+    )
+    .finish();
+    if dim == Dimension::XY {
+        return output;
+    }
+
+    let mut extra_values: HashMap<(u64, u64), Vec<f64>> = HashMap::new();
+    for coords in inputs.iter().filter_map(|input| input_coords(*input)) {
+        if coords.dim() != dim {
+            continue;
+        }
+        for i in 0..coords.len() {
+            let coord = coords.value(i);
+            extra_values
+                .entry((coord.x().to_bits(), coord.y().to_bits()))
+                .or_insert_with(|| (2..dim.size()).map(|n| coord.nth_or_panic(n)).collect());
+        }
+    }
+
+    let nan_values = vec![f64::NAN; dim.size() - 2];
+    let mut values = Vec::with_capacity(output.coords.len() * dim.size());
+    for i in 0..output.coords.len() {
+        let coord = output.coords.value(i);
+        let (x, y) = (coord.x(), coord.y());
+        values.push(x);
+        values.push(y);
+        values.extend_from_slice(
+            extra_values
+                .get(&(x.to_bits(), y.to_bits()))
+                .unwrap_or(&nan_values),
+        );
+    }
+    let coords = CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into(), dim));
+    output.with_coords(coords.into_coord_type(template.coord_type()))
+}
+
+fn binary_boolean_op(
+    left: &dyn NativeArray,
+    right: &dyn NativeArray,
+    op: OpType,
+    policy: SphericalEdgesPolicy,
+) -> Result<MultiPolygonArray> {
+    if left.len() != right.len() {
+        return Err(GeoArrowError::General(
+            "Cannot perform binary operation on arrays of different length".to_string(),
+        ));
+    }
+    check_planar_edges("BooleanOps", &left.metadata(), policy)?;
+    check_planar_edges("BooleanOps", &right.metadata(), policy)?;
+    left.metadata()
+        .check_crs_compatible(&right.metadata(), "BooleanOps")?;
+
+    let geometries = to_multi_polygons(left)?
+        .into_iter()
+        .zip(to_multi_polygons(right)?)
+        .map(|(left, right)| Some(left?.boolean_op(&right?, op)))
+        .collect();
+    Ok(finish_multi_polygons(geometries, left, &[left, right]))
+}
+
+fn unary_boolean_op(
+    left: &dyn NativeArray,
+    right: &geo::MultiPolygon,
+    op: OpType,
+    policy: SphericalEdgesPolicy,
+) -> Result<MultiPolygonArray> {
+    check_planar_edges("BooleanOps", &left.metadata(), policy)?;
+
+    let geometries = to_multi_polygons(left)?
+        .into_iter()
+        .map(|left| Some(left?.boolean_op(right, op)))
+        .collect();
+    Ok(finish_multi_polygons(geometries, left, &[left]))
+}
+
+// ┌────────────────────────────────┐
+// │ Implementations for RHS arrays │
+// └────────────────────────────────┘
+
+/// Boolean operations on pairs of polygonal geometries at the same row, computed with [`geo`]
+/// and without linking GEOS.
+///
+/// Polygon, MultiPolygon and Geometry arrays are supported, as long as every geometry of a
+/// Geometry array is polygonal. The output is null where either input is null.
+///
+/// # Examples
+///
+/// ```
+/// use geoarrow::algorithm::geo::{Area, BooleanOps};
+/// use geoarrow::array::PolygonArray;
+/// use geoarrow::datatypes::Dimension;
+///
+/// let left = vec![geo::Rect::new((0., 0.), (2., 2.)).to_polygon()];
+/// let right = vec![geo::Rect::new((1., 1.), (3., 3.)).to_polygon()];
+/// let left: PolygonArray = (left.as_slice(), Dimension::XY).into();
+/// let right: PolygonArray = (right.as_slice(), Dimension::XY).into();
+///
+/// let intersection = left.intersection(&right).unwrap();
+/// assert_eq!(intersection.unsigned_area().value(0), 1.);
+/// ```
+pub trait BooleanOps<Rhs = Self> {
+    type Output;
+
+    /// Apply `op` to each pair of geometries, erroring on input with spherical edges.
+    fn boolean_op(&self, rhs: &Rhs, op: OpType) -> Self::Output {
+        self.boolean_op_with_policy(rhs, op, SphericalEdgesPolicy::Error)
+    }
+
+    /// Apply `op` to each pair of geometries, handling input with spherical
+    /// edges according to `policy`.
+    fn boolean_op_with_policy(
+        &self,
+        rhs: &Rhs,
+        op: OpType,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output;
+
+    /// The area covered by both geometries.
+    fn intersection(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Intersection)
+    }
+
+    /// The area covered by either geometry.
+    fn union(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Union)
+    }
+
+    /// The area covered by the left geometry but not the right one.
+    fn difference(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Difference)
+    }
+
+    /// The area covered by exactly one of the geometries.
+    fn sym_difference(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Xor)
+    }
+}
+
+macro_rules! impl_boolean_ops {
+    ($first:ident, $second:ident) => {
+        impl<O: OffsetSizeTrait, P: OffsetSizeTrait> BooleanOps<$second<P>> for $first<O> {
+            type Output = Result<MultiPolygonArray>;
+
+            fn boolean_op_with_policy(
+                &self,
+                rhs: &$second<P>,
+                op: OpType,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                binary_boolean_op(self, rhs, op, policy)
+            }
+        }
+    };
+}
+
+impl_boolean_ops!(GenericPolygonArray, GenericPolygonArray);
+impl_boolean_ops!(GenericPolygonArray, GenericMultiPolygonArray);
+impl_boolean_ops!(GenericPolygonArray, GenericGeometryArray);
+impl_boolean_ops!(GenericMultiPolygonArray, GenericPolygonArray);
+impl_boolean_ops!(GenericMultiPolygonArray, GenericMultiPolygonArray);
+impl_boolean_ops!(GenericMultiPolygonArray, GenericGeometryArray);
+impl_boolean_ops!(GenericGeometryArray, GenericPolygonArray);
+impl_boolean_ops!(GenericGeometryArray, GenericMultiPolygonArray);
+impl_boolean_ops!(GenericGeometryArray, GenericGeometryArray);
+
+impl BooleanOps for &dyn NativeArray {
+    type Output = Result<MultiPolygonArray>;
+
+    fn boolean_op_with_policy(
+        &self,
+        rhs: &Self,
+        op: OpType,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
+        binary_boolean_op(*self, *rhs, op, policy)
+    }
+}
+
+impl BooleanOps for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedMultiPolygonArray>;
+
+    fn boolean_op_with_policy(
+        &self,
+        rhs: &Self,
+        op: OpType,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
+        let left = self.geometry_chunks();
+        let right = rhs.geometry_chunks();
+        if left.len() != right.len()
+            || left
+                .iter()
+                .zip(right.iter())
+                .any(|(l, r)| l.len() != r.len())
+        {
+            return Err(GeoArrowError::General(
+                "Cannot perform binary operation on chunked arrays with different chunk lengths"
+                    .to_string(),
+            ));
+        }
+
+        #[cfg(feature = "rayon")]
+        let chunks = left.par_iter().zip(right.par_iter());
+        #[cfg(not(feature = "rayon"))]
+        let chunks = left.iter().zip(right.iter());
+
+        let chunks = chunks
+            .map(|(left, right)| binary_boolean_op(left.as_ref(), right.as_ref(), op, policy))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+// ┌─────────────────────────────────┐
+// │ Implementations for RHS scalars │
+// └─────────────────────────────────┘
+
+/// Boolean operations between each polygonal geometry of an array and a single polygonal
+/// geometry, computed with [`geo`] and without linking GEOS.
+pub trait BooleanOpsScalar<Rhs> {
+    type Output;
+
+    /// Apply `op` to each geometry and `rhs`, erroring on input with spherical edges.
+    fn boolean_op(&self, rhs: &Rhs, op: OpType) -> Self::Output {
+        self.boolean_op_with_policy(rhs, op, SphericalEdgesPolicy::Error)
+    }
+
+    /// Apply `op` to each geometry and `rhs`, handling input with spherical edges
+    /// according to `policy`.
+    fn boolean_op_with_policy(
+        &self,
+        rhs: &Rhs,
+        op: OpType,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output;
+
+    /// The area covered by both each geometry and `rhs`.
+    fn intersection(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Intersection)
+    }
+
+    /// The area covered by either each geometry or `rhs`.
+    fn union(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Union)
+    }
+
+    /// The area covered by each geometry but not by `rhs`.
+    fn difference(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Difference)
+    }
+
+    /// The area covered by exactly one of each geometry and `rhs`.
+    fn sym_difference(&self, rhs: &Rhs) -> Self::Output {
+        self.boolean_op(rhs, OpType::Xor)
+    }
+}
+
+macro_rules! impl_boolean_ops_scalar {
+    ($array:ident) => {
+        impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> BooleanOpsScalar<G> for $array<O> {
+            type Output = Result<MultiPolygonArray>;
+
+            fn boolean_op_with_policy(
+                &self,
+                rhs: &G,
+                op: OpType,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                let rhs = to_multi_polygon(geometry_to_geo(rhs))?;
+                unary_boolean_op(self, &rhs, op, policy)
+            }
+        }
+    };
+}
+
+impl_boolean_ops_scalar!(GenericPolygonArray);
+impl_boolean_ops_scalar!(GenericMultiPolygonArray);
+impl_boolean_ops_scalar!(GenericGeometryArray);
+
+impl<G: GeometryTrait<T = f64>> BooleanOpsScalar<G> for &dyn NativeArray {
+    type Output = Result<MultiPolygonArray>;
+
+    fn boolean_op_with_policy(
+        &self,
+        rhs: &G,
+        op: OpType,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
+        let rhs = to_multi_polygon(geometry_to_geo(rhs))?;
+        unary_boolean_op(*self, &rhs, op, policy)
+    }
+}
+
+impl<G: GeometryTrait<T = f64>> BooleanOpsScalar<G> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedMultiPolygonArray>;
+
+    fn boolean_op_with_policy(
+        &self,
+        rhs: &G,
+        op: OpType,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
+        let rhs = to_multi_polygon(geometry_to_geo(rhs))?;
+        let chunks = self.geometry_chunks();
+
+        #[cfg(feature = "rayon")]
+        let chunks = chunks.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let chunks = chunks.iter();
+
+        let chunks = chunks
+            .map(|chunk| unary_boolean_op(chunk.as_ref(), &rhs, op, policy))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::Array;
+    use arrow_buffer::OffsetBuffer;
+
+    use super::*;
+    use crate::algorithm::geo::Area;
+
+    fn squares() -> (PolygonArray, PolygonArray) {
+        let left = vec![
+            Some(geo::Rect::new((0., 0.), (2., 2.)).to_polygon()),
+            Some(geo::Rect::new((0., 0.), (1., 1.)).to_polygon()),
+            None,
+        ];
+        let right = vec![
+            Some(geo::Rect::new((1., 1.), (3., 3.)).to_polygon()),
+            Some(geo::Rect::new((5., 5.), (6., 6.)).to_polygon()),
+            Some(geo::Rect::new((0., 0.), (1., 1.)).to_polygon()),
+        ];
+        ((left, Dimension::XY).into(), (right, Dimension::XY).into())
+    }
+
+    #[test]
+    fn polygon_array_ops() {
+        let (left, right) = squares();
+
+        let areas = BooleanOps::intersection(&left, &right)
+            .unwrap()
+            .unsigned_area();
+        assert_eq!(areas.value(0), 1.);
+        assert_eq!(areas.value(1), 0.);
+        assert!(areas.is_null(2));
+
+        let areas = BooleanOps::union(&left, &right).unwrap().unsigned_area();
+        assert_eq!(areas.value(0), 7.);
+        assert_eq!(areas.value(1), 2.);
+
+        let areas = BooleanOps::difference(&left, &right)
+            .unwrap()
+            .unsigned_area();
+        assert_eq!(areas.value(0), 3.);
+        assert_eq!(areas.value(1), 1.);
+
+        let areas = BooleanOps::sym_difference(&left, &right)
+            .unwrap()
+            .unsigned_area();
+        assert_eq!(areas.value(0), 6.);
+    }
+
+    #[test]
+    fn large_arrays() {
+        let (left, right) = squares();
+        let left = LargePolygonArray::from(left);
+
+        let areas = BooleanOps::intersection(&left, &right)
+            .unwrap()
+            .unsigned_area();
+        assert_eq!(areas.value(0), 1.);
+        assert!(areas.is_null(2));
+    }
+
+    #[test]
+    fn keeps_dimension() {
+        let coords = InterleavedCoordBuffer::new(
+            vec![0., 0., 5., 2., 0., 5., 2., 2., 5., 0., 2., 5., 0., 0., 5.].into(),
+            Dimension::XYZ,
+        );
+        let left = PolygonArray::new(
+            coords.into(),
+            OffsetBuffer::new(vec![0, 1].into()),
+            OffsetBuffer::new(vec![0, 5].into()),
+            None,
+            Default::default(),
+        );
+        let rhs = geo::Rect::new((1., 1.), (3., 3.)).to_polygon();
+
+        let output = BooleanOpsScalar::intersection(&left, &rhs).unwrap();
+        assert_eq!(output.dimension(), Dimension::XYZ);
+        let coords = output.coords();
+        let z_values = (0..coords.len())
+            .map(|i| {
+                let coord = coords.value(i);
+                ((coord.x(), coord.y()), coord.nth_or_panic(2))
+            })
+            .collect::<Vec<_>>();
+        // The corner of the input keeps its Z value, while the vertices created by the
+        // intersection have none.
+        assert!(z_values.contains(&((2., 2.), 5.)));
+        assert!(z_values
+            .iter()
+            .any(|((x, y), z)| (*x, *y) == (1., 1.) && z.is_nan()));
+    }
+
+    #[test]
+    fn scalar_and_geometry_array() {
+        let (left, _) = squares();
+        let rhs = geo::Rect::new((1., 0.), (2., 2.)).to_polygon();
+        let areas = BooleanOpsScalar::difference(&left, &rhs)
+            .unwrap()
+            .unsigned_area();
+        assert_eq!(areas.value(0), 2.);
+        assert_eq!(areas.value(1), 1.);
+
+        let lines: GeometryArray = GeometryBuilder::from_geometries(
+            &[geo::Geometry::LineString(geo::LineString::from(vec![
+                (0., 0.),
+                (1., 1.),
+            ]))],
+            Default::default(),
+            Default::default(),
+            false,
+        )
+        .unwrap()
+        .finish();
+        assert!(BooleanOpsScalar::union(&lines, &rhs).is_err());
+    }
+}
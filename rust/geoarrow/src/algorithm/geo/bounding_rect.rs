@@ -92,6 +92,14 @@ iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl BoundingRect for &dyn NativeArray {
     type Output = Result<RectArray>;
@@ -102,12 +110,21 @@ impl BoundingRect for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().bounding_rect(),
             LineString(_, _) => self.as_line_string().bounding_rect(),
+            LargeLineString(_, _) => self.as_large_line_string().bounding_rect(),
             Polygon(_, _) => self.as_polygon().bounding_rect(),
+            LargePolygon(_, _) => self.as_large_polygon().bounding_rect(),
             MultiPoint(_, _) => self.as_multi_point().bounding_rect(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().bounding_rect(),
             MultiLineString(_, _) => self.as_multi_line_string().bounding_rect(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().bounding_rect(),
             MultiPolygon(_, _) => self.as_multi_polygon().bounding_rect(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().bounding_rect(),
+            Mixed(_, _) => self.as_mixed().bounding_rect(),
+            LargeMixed(_, _) => self.as_large_mixed().bounding_rect(),
             GeometryCollection(_, _) => self.as_geometry_collection().bounding_rect(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().bounding_rect(),
             Geometry(_) => self.as_geometry().bounding_rect(),
+            LargeGeometry(_) => self.as_large_geometry().bounding_rect(),
             Rect(_) => self.as_rect().bounding_rect(),
         };
         Ok(result)
@@ -132,12 +149,21 @@ impl BoundingRect for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().bounding_rect(),
             LineString(_, _) => self.as_line_string().bounding_rect(),
+            LargeLineString(_, _) => self.as_large_line_string().bounding_rect(),
             Polygon(_, _) => self.as_polygon().bounding_rect(),
+            LargePolygon(_, _) => self.as_large_polygon().bounding_rect(),
             MultiPoint(_, _) => self.as_multi_point().bounding_rect(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().bounding_rect(),
             MultiLineString(_, _) => self.as_multi_line_string().bounding_rect(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().bounding_rect(),
             MultiPolygon(_, _) => self.as_multi_polygon().bounding_rect(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().bounding_rect(),
+            Mixed(_, _) => self.as_mixed().bounding_rect(),
+            LargeMixed(_, _) => self.as_large_mixed().bounding_rect(),
             GeometryCollection(_, _) => self.as_geometry_collection().bounding_rect(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().bounding_rect(),
             Geometry(_) => self.as_geometry().bounding_rect(),
+            LargeGeometry(_) => self.as_large_geometry().bounding_rect(),
             Rect(_) => self.as_rect().bounding_rect(),
         }
     }
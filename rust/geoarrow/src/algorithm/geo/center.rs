@@ -74,6 +74,14 @@ iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl Center for &dyn NativeArray {
     type Output = Result<PointArray>;
@@ -84,13 +92,22 @@ impl Center for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().center(),
             LineString(_, _) => self.as_line_string().center(),
+            LargeLineString(_, _) => self.as_large_line_string().center(),
             Polygon(_, _) => self.as_polygon().center(),
+            LargePolygon(_, _) => self.as_large_polygon().center(),
             MultiPoint(_, _) => self.as_multi_point().center(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().center(),
             MultiLineString(_, _) => self.as_multi_line_string().center(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().center(),
             MultiPolygon(_, _) => self.as_multi_polygon().center(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().center(),
+            Mixed(_, _) => self.as_mixed().center(),
+            LargeMixed(_, _) => self.as_large_mixed().center(),
             GeometryCollection(_, _) => self.as_geometry_collection().center(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().center(),
             Rect(_) => self.as_rect().center(),
             Geometry(_) => self.as_geometry().center(),
+            LargeGeometry(_) => self.as_large_geometry().center(),
         };
         Ok(result)
     }
@@ -113,13 +130,22 @@ impl Center for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().center(),
             LineString(_, _) => self.as_line_string().center(),
+            LargeLineString(_, _) => self.as_large_line_string().center(),
             Polygon(_, _) => self.as_polygon().center(),
+            LargePolygon(_, _) => self.as_large_polygon().center(),
             MultiPoint(_, _) => self.as_multi_point().center(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().center(),
             MultiLineString(_, _) => self.as_multi_line_string().center(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().center(),
             MultiPolygon(_, _) => self.as_multi_polygon().center(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().center(),
+            Mixed(_, _) => self.as_mixed().center(),
+            LargeMixed(_, _) => self.as_large_mixed().center(),
             GeometryCollection(_, _) => self.as_geometry_collection().center(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().center(),
             Rect(_) => self.as_rect().center(),
             Geometry(_) => self.as_geometry().center(),
+            LargeGeometry(_) => self.as_large_geometry().center(),
         }
     }
 }
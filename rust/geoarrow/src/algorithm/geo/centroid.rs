@@ -1,3 +1,4 @@
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray, ChunkedPointArray};
 use crate::datatypes::{Dimension, NativeType};
@@ -14,6 +15,10 @@ use geo::algorithm::centroid::Centroid as GeoCentroid;
 /// The geometric centroid of a convex object always lies in the object.
 /// A non-convex object might have a centroid that _is outside the object itself_.
 ///
+/// Centroids are weighted in the plane of the coordinates, which doesn't match the center of a
+/// shape drawn with spherical edges. Input that declares spherical edges is handled according to
+/// the [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
+///
 /// # Examples
 ///
 /// ```
@@ -35,7 +40,7 @@ use geo::algorithm::centroid::Centroid as GeoCentroid;
 ///
 /// assert_eq!(
 ///     Some(point!(x: 1., y: 1.)),
-///     polygon_array.centroid().get_as_geo(0),
+///     polygon_array.centroid().unwrap().get_as_geo(0),
 /// );
 /// ```
 pub trait Centroid {
@@ -60,24 +65,32 @@ pub trait Centroid {
     ///
     /// assert_eq!(
     ///     Some(point!(x: 40.02, y: 117.285)),
-    ///     line_string_array.centroid().get_as_geo(0),
+    ///     line_string_array.centroid().unwrap().get_as_geo(0),
     /// );
     /// ```
-    fn centroid(&self) -> Self::Output;
+    fn centroid(&self) -> Self::Output {
+        self.centroid_with_policy(SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`centroid`][Self::centroid], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn centroid_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl Centroid for PointArray {
-    type Output = PointArray;
+    type Output = Result<PointArray>;
 
-    fn centroid(&self) -> Self::Output {
-        self.clone()
+    /// Points have no edges, so `policy` is ignored.
+    fn centroid_with_policy(&self, _policy: SphericalEdgesPolicy) -> Self::Output {
+        Ok(self.clone())
     }
 }
 
 impl Centroid for RectArray {
-    type Output = PointArray;
+    type Output = Result<PointArray>;
 
-    fn centroid(&self) -> Self::Output {
+    fn centroid_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
+        check_planar_edges("Centroid", &self.metadata(), policy)?;
         let mut output_array = PointBuilder::with_capacity_and_options(
             Dimension::XY,
             self.len(),
@@ -86,7 +99,7 @@ impl Centroid for RectArray {
         );
         self.iter_geo()
             .for_each(|maybe_g| output_array.push_point(maybe_g.map(|g| g.centroid()).as_ref()));
-        output_array.into()
+        Ok(output_array.into())
     }
 }
 
@@ -94,9 +107,10 @@ impl Centroid for RectArray {
 macro_rules! iter_geo_impl {
     ($type:ty) => {
         impl Centroid for $type {
-            type Output = PointArray;
+            type Output = Result<PointArray>;
 
-            fn centroid(&self) -> Self::Output {
+            fn centroid_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
+                check_planar_edges("Centroid", &self.metadata(), policy)?;
                 let mut output_array = PointBuilder::with_capacity_and_options(
                     Dimension::XY,
                     self.len(),
@@ -106,7 +120,7 @@ macro_rules! iter_geo_impl {
                 self.iter_geo().for_each(|maybe_g| {
                     output_array.push_point(maybe_g.and_then(|g| g.centroid()).as_ref())
                 });
-                output_array.into()
+                Ok(output_array.into())
             }
         }
     };
@@ -120,52 +134,116 @@ iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl Centroid for &dyn NativeArray {
     type Output = Result<PointArray>;
 
-    fn centroid(&self) -> Self::Output {
+    fn centroid_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
         use NativeType::*;
 
-        let result = match self.data_type() {
-            Point(_, _) => self.as_point().centroid(),
-            LineString(_, _) => self.as_line_string().centroid(),
-            Polygon(_, _) => self.as_polygon().centroid(),
-            MultiPoint(_, _) => self.as_multi_point().centroid(),
-            MultiLineString(_, _) => self.as_multi_line_string().centroid(),
-            MultiPolygon(_, _) => self.as_multi_polygon().centroid(),
-            GeometryCollection(_, _) => self.as_geometry_collection().centroid(),
-            Rect(_) => self.as_rect().centroid(),
-            Geometry(_) => self.as_geometry().centroid(),
-        };
-        Ok(result)
+        match self.data_type() {
+            Point(_, _) => self.as_point().centroid_with_policy(policy),
+            LineString(_, _) => self.as_line_string().centroid_with_policy(policy),
+            LargeLineString(_, _) => self.as_large_line_string().centroid_with_policy(policy),
+            Polygon(_, _) => self.as_polygon().centroid_with_policy(policy),
+            LargePolygon(_, _) => self.as_large_polygon().centroid_with_policy(policy),
+            MultiPoint(_, _) => self.as_multi_point().centroid_with_policy(policy),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().centroid_with_policy(policy),
+            MultiLineString(_, _) => self.as_multi_line_string().centroid_with_policy(policy),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .centroid_with_policy(policy),
+            MultiPolygon(_, _) => self.as_multi_polygon().centroid_with_policy(policy),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().centroid_with_policy(policy),
+            Mixed(_, _) => self.as_mixed().centroid_with_policy(policy),
+            LargeMixed(_, _) => self.as_large_mixed().centroid_with_policy(policy),
+            GeometryCollection(_, _) => self.as_geometry_collection().centroid_with_policy(policy),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .centroid_with_policy(policy),
+            Rect(_) => self.as_rect().centroid_with_policy(policy),
+            Geometry(_) => self.as_geometry().centroid_with_policy(policy),
+            LargeGeometry(_) => self.as_large_geometry().centroid_with_policy(policy),
+        }
     }
 }
 
 impl<G: NativeArray> Centroid for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedPointArray>;
 
-    fn centroid(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().centroid())?.try_into()
+    fn centroid_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().centroid_with_policy(policy))?
+            .try_into()
     }
 }
 
 impl Centroid for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedPointArray>;
 
-    fn centroid(&self) -> Self::Output {
+    fn centroid_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, _) => self.as_point().centroid(),
-            LineString(_, _) => self.as_line_string().centroid(),
-            Polygon(_, _) => self.as_polygon().centroid(),
-            MultiPoint(_, _) => self.as_multi_point().centroid(),
-            MultiLineString(_, _) => self.as_multi_line_string().centroid(),
-            MultiPolygon(_, _) => self.as_multi_polygon().centroid(),
-            GeometryCollection(_, _) => self.as_geometry_collection().centroid(),
-            Rect(_) => self.as_rect().centroid(),
-            Geometry(_) => self.as_geometry().centroid(),
+            Point(_, _) => self.as_point().centroid_with_policy(policy),
+            LineString(_, _) => self.as_line_string().centroid_with_policy(policy),
+            LargeLineString(_, _) => self.as_large_line_string().centroid_with_policy(policy),
+            Polygon(_, _) => self.as_polygon().centroid_with_policy(policy),
+            LargePolygon(_, _) => self.as_large_polygon().centroid_with_policy(policy),
+            MultiPoint(_, _) => self.as_multi_point().centroid_with_policy(policy),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().centroid_with_policy(policy),
+            MultiLineString(_, _) => self.as_multi_line_string().centroid_with_policy(policy),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .centroid_with_policy(policy),
+            MultiPolygon(_, _) => self.as_multi_polygon().centroid_with_policy(policy),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().centroid_with_policy(policy),
+            Mixed(_, _) => self.as_mixed().centroid_with_policy(policy),
+            LargeMixed(_, _) => self.as_large_mixed().centroid_with_policy(policy),
+            GeometryCollection(_, _) => self.as_geometry_collection().centroid_with_policy(policy),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .centroid_with_policy(policy),
+            Rect(_) => self.as_rect().centroid_with_policy(policy),
+            Geometry(_) => self.as_geometry().centroid_with_policy(policy),
+            LargeGeometry(_) => self.as_large_geometry().centroid_with_policy(policy),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::metadata::{ArrayMetadata, Edges};
+    use crate::test::polygon::{p0, p1, p_array};
+
+    #[test]
+    fn spherical_edges_error() {
+        let metadata = Arc::new(ArrayMetadata::default().with_edges(Edges::Spherical));
+        let arr = PolygonBuilder::from_polygons(
+            &[p0(), p1()],
+            Dimension::XY,
+            CoordType::Interleaved,
+            metadata,
+        )
+        .finish();
+
+        assert!(arr.centroid().is_err());
+        assert!(arr.as_ref().centroid().is_err());
+        assert!(p_array().as_ref().centroid().is_ok());
+        assert_eq!(
+            arr.centroid_with_policy(SphericalEdgesPolicy::Warn)
+                .unwrap(),
+            p_array().centroid().unwrap()
+        );
+    }
+}
@@ -87,6 +87,9 @@ zero_impl!(PointArray);
 zero_impl!(LineStringArray);
 zero_impl!(MultiPointArray);
 zero_impl!(MultiLineStringArray);
+zero_impl!(LargeLineStringArray);
+zero_impl!(LargeMultiPointArray);
+zero_impl!(LargeMultiLineStringArray);
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
@@ -121,6 +124,11 @@ iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
 iter_geo_impl!(RectArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl ChamberlainDuquetteArea for &dyn NativeArray {
     type Output = Result<Float64Array>;
@@ -131,17 +139,36 @@ impl ChamberlainDuquetteArea for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().chamberlain_duquette_signed_area(),
             LineString(_, _) => self.as_line_string().chamberlain_duquette_signed_area(),
+            LargeLineString(_, _) => self
+                .as_large_line_string()
+                .chamberlain_duquette_signed_area(),
             Polygon(_, _) => self.as_polygon().chamberlain_duquette_signed_area(),
+            LargePolygon(_, _) => self.as_large_polygon().chamberlain_duquette_signed_area(),
             MultiPoint(_, _) => self.as_multi_point().chamberlain_duquette_signed_area(),
+            LargeMultiPoint(_, _) => self
+                .as_large_multi_point()
+                .chamberlain_duquette_signed_area(),
             MultiLineString(_, _) => self
                 .as_multi_line_string()
                 .chamberlain_duquette_signed_area(),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .chamberlain_duquette_signed_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().chamberlain_duquette_signed_area(),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .chamberlain_duquette_signed_area(),
+            Mixed(_, _) => self.as_mixed().chamberlain_duquette_signed_area(),
+            LargeMixed(_, _) => self.as_large_mixed().chamberlain_duquette_signed_area(),
             GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .chamberlain_duquette_signed_area(),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .chamberlain_duquette_signed_area(),
             Rect(_) => self.as_rect().chamberlain_duquette_signed_area(),
             Geometry(_) => self.as_geometry().chamberlain_duquette_signed_area(),
+            LargeGeometry(_) => self.as_large_geometry().chamberlain_duquette_signed_area(),
         };
         Ok(result)
     }
@@ -152,17 +179,38 @@ impl ChamberlainDuquetteArea for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().chamberlain_duquette_unsigned_area(),
             LineString(_, _) => self.as_line_string().chamberlain_duquette_unsigned_area(),
+            LargeLineString(_, _) => self
+                .as_large_line_string()
+                .chamberlain_duquette_unsigned_area(),
             Polygon(_, _) => self.as_polygon().chamberlain_duquette_unsigned_area(),
+            LargePolygon(_, _) => self.as_large_polygon().chamberlain_duquette_unsigned_area(),
             MultiPoint(_, _) => self.as_multi_point().chamberlain_duquette_unsigned_area(),
+            LargeMultiPoint(_, _) => self
+                .as_large_multi_point()
+                .chamberlain_duquette_unsigned_area(),
             MultiLineString(_, _) => self
                 .as_multi_line_string()
                 .chamberlain_duquette_unsigned_area(),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .chamberlain_duquette_unsigned_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().chamberlain_duquette_unsigned_area(),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .chamberlain_duquette_unsigned_area(),
+            Mixed(_, _) => self.as_mixed().chamberlain_duquette_unsigned_area(),
+            LargeMixed(_, _) => self.as_large_mixed().chamberlain_duquette_unsigned_area(),
             GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .chamberlain_duquette_unsigned_area(),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .chamberlain_duquette_unsigned_area(),
             Rect(_) => self.as_rect().chamberlain_duquette_unsigned_area(),
             Geometry(_) => self.as_geometry().chamberlain_duquette_unsigned_area(),
+            LargeGeometry(_) => self
+                .as_large_geometry()
+                .chamberlain_duquette_unsigned_area(),
         };
         Ok(result)
     }
@@ -199,17 +247,38 @@ impl ChamberlainDuquetteArea for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().chamberlain_duquette_signed_area(),
             LineString(_, _) => self.as_line_string().chamberlain_duquette_signed_area(),
+            LargeLineString(_, _) => self
+                .as_large_line_string()
+                .chamberlain_duquette_signed_area(),
             Polygon(_, _) => self.as_polygon().chamberlain_duquette_signed_area(),
+            LargePolygon(_, _) => self.as_large_polygon().chamberlain_duquette_signed_area(),
             MultiPoint(_, _) => self.as_multi_point().chamberlain_duquette_signed_area(),
+            LargeMultiPoint(_, _) => self
+                .as_large_multi_point()
+                .chamberlain_duquette_signed_area(),
             MultiLineString(_, _) => self
                 .as_multi_line_string()
                 .chamberlain_duquette_signed_area(),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .chamberlain_duquette_signed_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().chamberlain_duquette_signed_area(),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .chamberlain_duquette_signed_area(),
+            Mixed(_, _) => self.as_mixed().chamberlain_duquette_signed_area(),
+            LargeMixed(_, _) => self.as_large_mixed().chamberlain_duquette_signed_area(),
             GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .chamberlain_duquette_signed_area(),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .chamberlain_duquette_signed_area(),
             Rect(_) => self.as_rect().chamberlain_duquette_unsigned_area(),
             Geometry(_) => self.as_geometry().chamberlain_duquette_unsigned_area(),
+            LargeGeometry(_) => self
+                .as_large_geometry()
+                .chamberlain_duquette_unsigned_area(),
         }
     }
 
@@ -219,17 +288,38 @@ impl ChamberlainDuquetteArea for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().chamberlain_duquette_unsigned_area(),
             LineString(_, _) => self.as_line_string().chamberlain_duquette_unsigned_area(),
+            LargeLineString(_, _) => self
+                .as_large_line_string()
+                .chamberlain_duquette_unsigned_area(),
             Polygon(_, _) => self.as_polygon().chamberlain_duquette_unsigned_area(),
+            LargePolygon(_, _) => self.as_large_polygon().chamberlain_duquette_unsigned_area(),
             MultiPoint(_, _) => self.as_multi_point().chamberlain_duquette_unsigned_area(),
+            LargeMultiPoint(_, _) => self
+                .as_large_multi_point()
+                .chamberlain_duquette_unsigned_area(),
             MultiLineString(_, _) => self
                 .as_multi_line_string()
                 .chamberlain_duquette_unsigned_area(),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .chamberlain_duquette_unsigned_area(),
             MultiPolygon(_, _) => self.as_multi_polygon().chamberlain_duquette_unsigned_area(),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .chamberlain_duquette_unsigned_area(),
+            Mixed(_, _) => self.as_mixed().chamberlain_duquette_unsigned_area(),
+            LargeMixed(_, _) => self.as_large_mixed().chamberlain_duquette_unsigned_area(),
             GeometryCollection(_, _) => self
                 .as_geometry_collection()
                 .chamberlain_duquette_unsigned_area(),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .chamberlain_duquette_unsigned_area(),
             Rect(_) => self.as_rect().chamberlain_duquette_unsigned_area(),
             Geometry(_) => self.as_geometry().chamberlain_duquette_unsigned_area(),
+            LargeGeometry(_) => self
+                .as_large_geometry()
+                .chamberlain_duquette_unsigned_area(),
         }
     }
 }
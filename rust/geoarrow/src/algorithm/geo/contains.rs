@@ -1,10 +1,11 @@
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::algorithm::native::{Binary, Unary};
 use crate::array::*;
 use crate::datatypes::NativeType;
-use crate::error::GeoArrowError;
+use crate::error::{GeoArrowError, Result};
 use crate::io::geo::geometry_to_geo;
 use crate::trait_::NativeScalar;
-use crate::NativeArray;
+use crate::{ArrayBase, NativeArray};
 use arrow_array::BooleanArray;
 use geo::Contains as _Contains;
 use geo_traits::GeometryTrait;
@@ -18,6 +19,9 @@ use geo_traits::GeometryTrait;
 ///
 /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
 ///
+/// This is a planar operation. Input whose metadata declares spherical edges is handled according
+/// to the [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
+///
 /// # Examples
 ///
 /// ```
@@ -44,7 +48,14 @@ use geo_traits::GeometryTrait;
 /// assert!(polygon.contains(&point!(x: 1., y: 1.)));
 /// ```
 pub trait Contains<Rhs = Self> {
-    fn contains(&self, rhs: &Rhs) -> BooleanArray;
+    fn contains(&self, rhs: &Rhs) -> Result<BooleanArray> {
+        self.contains_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`contains`][Self::contains], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn contains_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy)
+        -> Result<BooleanArray>;
 }
 
 // ┌────────────────────────────────┐
@@ -55,11 +66,16 @@ pub trait Contains<Rhs = Self> {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a> Contains<$second> for $first {
-            fn contains(&self, rhs: &$second) -> BooleanArray {
+            fn contains_with_policy(
+                &self,
+                rhs: &$second,
+                policy: SphericalEdgesPolicy,
+            ) -> Result<BooleanArray> {
+                check_planar_edges("Contains", &self.metadata(), policy)?;
+                check_planar_edges("Contains", &rhs.metadata(), policy)?;
                 self.try_binary_boolean(rhs, |left, right| {
                     Ok(left.to_geo_geometry().contains(&right.to_geo_geometry()))
                 })
-                .unwrap()
             }
         }
     };
@@ -130,26 +146,37 @@ iter_geo_impl!(MultiPolygonArray, GeometryArray);
 // └─────────────────────────────────┘
 
 pub trait ContainsGeometry<Rhs> {
-    fn contains(&self, rhs: &Rhs) -> BooleanArray;
+    fn contains(&self, rhs: &Rhs) -> Result<BooleanArray> {
+        self.contains_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`contains`][Self::contains], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn contains_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy)
+        -> Result<BooleanArray>;
 }
 
 impl<G: GeometryTrait<T = f64>> ContainsGeometry<G> for PointArray {
-    fn contains(&self, rhs: &G) -> BooleanArray {
+    fn contains_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Result<BooleanArray> {
+        check_planar_edges("Contains", &self.metadata(), policy)?;
         let rhs = geometry_to_geo(rhs);
         self.try_unary_boolean::<_, GeoArrowError>(|geom| Ok(geom.to_geo().contains(&rhs)))
-            .unwrap()
     }
 }
 
 macro_rules! impl_contains_point {
     ($array:ty) => {
         impl<G: GeometryTrait<T = f64>> ContainsGeometry<G> for $array {
-            fn contains(&self, rhs: &G) -> BooleanArray {
+            fn contains_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Result<BooleanArray> {
+                check_planar_edges("Contains", &self.metadata(), policy)?;
                 let rhs = geometry_to_geo(rhs);
                 self.try_unary_boolean::<_, GeoArrowError>(|geom| {
                     Ok(geom.to_geo_geometry().contains(&rhs))
                 })
-                .unwrap()
             }
         }
     };
@@ -164,23 +191,108 @@ impl_contains_point!(MixedGeometryArray);
 impl_contains_point!(GeometryCollectionArray);
 impl_contains_point!(GeometryArray);
 impl_contains_point!(RectArray);
+impl_contains_point!(LargeLineStringArray);
+impl_contains_point!(LargePolygonArray);
+impl_contains_point!(LargeMultiPointArray);
+impl_contains_point!(LargeMultiLineStringArray);
+impl_contains_point!(LargeMultiPolygonArray);
+impl_contains_point!(LargeMixedGeometryArray);
+impl_contains_point!(LargeGeometryCollectionArray);
+impl_contains_point!(LargeGeometryArray);
 
 impl<G: GeometryTrait<T = f64>> ContainsGeometry<G> for &dyn NativeArray {
-    fn contains(&self, rhs: &G) -> BooleanArray {
+    fn contains_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Result<BooleanArray> {
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, _) => ContainsGeometry::contains(self.as_point(), rhs),
-            LineString(_, _) => ContainsGeometry::contains(self.as_line_string(), rhs),
-            Polygon(_, _) => ContainsGeometry::contains(self.as_polygon(), rhs),
-            MultiPoint(_, _) => ContainsGeometry::contains(self.as_multi_point(), rhs),
-            MultiLineString(_, _) => ContainsGeometry::contains(self.as_multi_line_string(), rhs),
-            MultiPolygon(_, _) => ContainsGeometry::contains(self.as_multi_polygon(), rhs),
+            Point(_, _) => ContainsGeometry::contains_with_policy(self.as_point(), rhs, policy),
+            LineString(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_line_string(), rhs, policy)
+            }
+            LargeLineString(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_large_line_string(), rhs, policy)
+            }
+            Polygon(_, _) => ContainsGeometry::contains_with_policy(self.as_polygon(), rhs, policy),
+            LargePolygon(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_large_polygon(), rhs, policy)
+            }
+            MultiPoint(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_multi_point(), rhs, policy)
+            }
+            LargeMultiPoint(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_large_multi_point(), rhs, policy)
+            }
+            MultiLineString(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_multi_line_string(), rhs, policy)
+            }
+            LargeMultiLineString(_, _) => ContainsGeometry::contains_with_policy(
+                self.as_large_multi_line_string(),
+                rhs,
+                policy,
+            ),
+            MultiPolygon(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_multi_polygon(), rhs, policy)
+            }
+            LargeMultiPolygon(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_large_multi_polygon(), rhs, policy)
+            }
+            Mixed(_, _) => ContainsGeometry::contains_with_policy(self.as_mixed(), rhs, policy),
+            LargeMixed(_, _) => {
+                ContainsGeometry::contains_with_policy(self.as_large_mixed(), rhs, policy)
+            }
             GeometryCollection(_, _) => {
-                ContainsGeometry::contains(self.as_geometry_collection(), rhs)
+                ContainsGeometry::contains_with_policy(self.as_geometry_collection(), rhs, policy)
+            }
+            LargeGeometryCollection(_, _) => ContainsGeometry::contains_with_policy(
+                self.as_large_geometry_collection(),
+                rhs,
+                policy,
+            ),
+            Rect(_) => ContainsGeometry::contains_with_policy(self.as_rect(), rhs, policy),
+            Geometry(_) => ContainsGeometry::contains_with_policy(self.as_geometry(), rhs, policy),
+            LargeGeometry(_) => {
+                ContainsGeometry::contains_with_policy(self.as_large_geometry(), rhs, policy)
             }
-            Rect(_) => ContainsGeometry::contains(self.as_rect(), rhs),
-            Geometry(_) => ContainsGeometry::contains(self.as_geometry(), rhs),
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::metadata::{ArrayMetadata, Edges};
+    use crate::datatypes::Dimension;
+    use crate::test::{point, polygon};
+
+    #[test]
+    fn contains_point() {
+        let arr = polygon::p_array();
+        let result = ContainsGeometry::contains(&arr, &geo::point!(x: -107., y: 43.)).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false]));
+    }
+
+    #[test]
+    fn spherical_edges_error() {
+        let metadata = Arc::new(ArrayMetadata::default().with_edges(Edges::Spherical));
+        let arr = PolygonBuilder::from_polygons(
+            &[polygon::p0(), polygon::p1()],
+            Dimension::XY,
+            CoordType::Interleaved,
+            metadata,
+        )
+        .finish();
+        assert!(ContainsGeometry::contains(&arr, &point::p0()).is_err());
+        assert!(ContainsGeometry::contains_with_policy(
+            &arr,
+            &point::p0(),
+            SphericalEdgesPolicy::Warn
+        )
+        .is_ok());
+
+        let points: PointArray = (vec![point::p0(), point::p1()].as_slice(), Dimension::XY).into();
+        assert!(Contains::contains(&points, &points).is_ok());
+        assert!(Contains::contains(&arr, &points).is_err());
+    }
+}
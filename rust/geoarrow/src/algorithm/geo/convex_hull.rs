@@ -1,3 +1,4 @@
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray, ChunkedPolygonArray};
 use crate::datatypes::{Dimension, NativeType};
@@ -13,6 +14,9 @@ use geo::Polygon;
 /// based on [Barber, C. Bradford; Dobkin, David P.; Huhdanpaa, Hannu (1 December 1996)](https://dx.doi.org/10.1145%2F235815.235821)
 /// Original paper here: <http://www.cs.princeton.edu/~dpd/Papers/BarberDobkinHuhdanpaa.pdf>
 ///
+/// The hull is built from straight segments between vertices. Input that declares spherical edges
+/// is handled according to the [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
+///
 /// # Examples
 ///
 /// ```
@@ -46,28 +50,35 @@ use geo::Polygon;
 pub trait ConvexHull {
     type Output;
 
-    fn convex_hull(&self) -> Self::Output;
+    fn convex_hull(&self) -> Self::Output {
+        self.convex_hull_with_policy(SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`convex_hull`][Self::convex_hull], but handles input with spherical edges according
+    /// to `policy` instead of returning an error.
+    fn convex_hull_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
     ($type:ty) => {
         impl ConvexHull for $type {
-            type Output = PolygonArray;
+            type Output = Result<PolygonArray>;
 
-            fn convex_hull(&self) -> Self::Output {
+            fn convex_hull_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
+                check_planar_edges("ConvexHull", &self.metadata(), policy)?;
                 let output_geoms: Vec<Option<Polygon>> = self
                     .iter_geo()
                     .map(|maybe_g| maybe_g.map(|geom| geom.convex_hull()))
                     .collect();
 
-                PolygonBuilder::from_nullable_polygons(
+                Ok(PolygonBuilder::from_nullable_polygons(
                     output_geoms.as_slice(),
                     Dimension::XY,
                     self.coord_type(),
                     self.metadata().clone(),
                 )
-                .finish()
+                .finish())
             }
         }
     };
@@ -83,33 +94,57 @@ iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(RectArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl ConvexHull for &dyn NativeArray {
     type Output = Result<PolygonArray>;
 
-    fn convex_hull(&self) -> Self::Output {
+    fn convex_hull_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
         use NativeType::*;
 
-        let result = match self.data_type() {
-            Point(_, _) => self.as_point().convex_hull(),
-            LineString(_, _) => self.as_line_string().convex_hull(),
-            Polygon(_, _) => self.as_polygon().convex_hull(),
-            MultiPoint(_, _) => self.as_multi_point().convex_hull(),
-            MultiLineString(_, _) => self.as_multi_line_string().convex_hull(),
-            MultiPolygon(_, _) => self.as_multi_polygon().convex_hull(),
-            GeometryCollection(_, _) => self.as_geometry_collection().convex_hull(),
-            Rect(_) => self.as_rect().convex_hull(),
-            Geometry(_) => self.as_geometry().convex_hull(),
-        };
-        Ok(result)
+        match self.data_type() {
+            Point(_, _) => self.as_point().convex_hull_with_policy(policy),
+            LineString(_, _) => self.as_line_string().convex_hull_with_policy(policy),
+            LargeLineString(_, _) => self.as_large_line_string().convex_hull_with_policy(policy),
+            Polygon(_, _) => self.as_polygon().convex_hull_with_policy(policy),
+            LargePolygon(_, _) => self.as_large_polygon().convex_hull_with_policy(policy),
+            MultiPoint(_, _) => self.as_multi_point().convex_hull_with_policy(policy),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().convex_hull_with_policy(policy),
+            MultiLineString(_, _) => self.as_multi_line_string().convex_hull_with_policy(policy),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .convex_hull_with_policy(policy),
+            MultiPolygon(_, _) => self.as_multi_polygon().convex_hull_with_policy(policy),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .convex_hull_with_policy(policy),
+            Mixed(_, _) => self.as_mixed().convex_hull_with_policy(policy),
+            LargeMixed(_, _) => self.as_large_mixed().convex_hull_with_policy(policy),
+            GeometryCollection(_, _) => self
+                .as_geometry_collection()
+                .convex_hull_with_policy(policy),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .convex_hull_with_policy(policy),
+            Rect(_) => self.as_rect().convex_hull_with_policy(policy),
+            Geometry(_) => self.as_geometry().convex_hull_with_policy(policy),
+            LargeGeometry(_) => self.as_large_geometry().convex_hull_with_policy(policy),
+        }
     }
 }
 
 impl<G: NativeArray> ConvexHull for ChunkedGeometryArray<G> {
     type Output = Result<ChunkedGeometryArray<PolygonArray>>;
 
-    fn convex_hull(&self) -> Self::Output {
-        self.try_map(|chunk| chunk.as_ref().convex_hull())?
+    fn convex_hull_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().convex_hull_with_policy(policy))?
             .try_into()
     }
 }
@@ -117,29 +152,52 @@ impl<G: NativeArray> ConvexHull for ChunkedGeometryArray<G> {
 impl ConvexHull for &dyn ChunkedNativeArray {
     type Output = Result<ChunkedPolygonArray>;
 
-    fn convex_hull(&self) -> Self::Output {
+    fn convex_hull_with_policy(&self, policy: SphericalEdgesPolicy) -> Self::Output {
         use NativeType::*;
 
         match self.data_type() {
-            Point(_, _) => self.as_point().convex_hull(),
-            LineString(_, _) => self.as_line_string().convex_hull(),
-            Polygon(_, _) => self.as_polygon().convex_hull(),
-            MultiPoint(_, _) => self.as_multi_point().convex_hull(),
-            MultiLineString(_, _) => self.as_multi_line_string().convex_hull(),
-            MultiPolygon(_, _) => self.as_multi_polygon().convex_hull(),
-            GeometryCollection(_, _) => self.as_geometry_collection().convex_hull(),
-            Rect(_) => self.as_rect().convex_hull(),
-            Geometry(_) => self.as_geometry().convex_hull(),
+            Point(_, _) => self.as_point().convex_hull_with_policy(policy),
+            LineString(_, _) => self.as_line_string().convex_hull_with_policy(policy),
+            LargeLineString(_, _) => self.as_large_line_string().convex_hull_with_policy(policy),
+            Polygon(_, _) => self.as_polygon().convex_hull_with_policy(policy),
+            LargePolygon(_, _) => self.as_large_polygon().convex_hull_with_policy(policy),
+            MultiPoint(_, _) => self.as_multi_point().convex_hull_with_policy(policy),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().convex_hull_with_policy(policy),
+            MultiLineString(_, _) => self.as_multi_line_string().convex_hull_with_policy(policy),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .convex_hull_with_policy(policy),
+            MultiPolygon(_, _) => self.as_multi_polygon().convex_hull_with_policy(policy),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .convex_hull_with_policy(policy),
+            Mixed(_, _) => self.as_mixed().convex_hull_with_policy(policy),
+            LargeMixed(_, _) => self.as_large_mixed().convex_hull_with_policy(policy),
+            GeometryCollection(_, _) => self
+                .as_geometry_collection()
+                .convex_hull_with_policy(policy),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .convex_hull_with_policy(policy),
+            Rect(_) => self.as_rect().convex_hull_with_policy(policy),
+            Geometry(_) => self.as_geometry().convex_hull_with_policy(policy),
+            LargeGeometry(_) => self.as_large_geometry().convex_hull_with_policy(policy),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use super::ConvexHull;
+    use crate::algorithm::geo::SphericalEdgesPolicy;
+    use crate::array::metadata::{ArrayMetadata, Edges};
     use crate::array::polygon::PolygonArray;
-    use crate::array::{LineStringArray, MultiPointArray};
+    use crate::array::{CoordType, LineStringArray, MultiPointArray, PolygonBuilder};
+    use crate::chunked_array::ChunkedGeometryArray;
     use crate::datatypes::Dimension;
+    use crate::test::polygon::{p0, p1};
     use crate::trait_::ArrayAccessor;
     use geo::{line_string, polygon, MultiPoint, Point};
 
@@ -159,7 +217,7 @@ mod tests {
         ]
         .into();
         let input_array: MultiPointArray = (vec![input_geom].as_slice(), Dimension::XY).into();
-        let result_array: PolygonArray = input_array.convex_hull();
+        let result_array: PolygonArray = input_array.convex_hull().unwrap();
 
         let expected = polygon![
             (x:0.0, y: -10.0),
@@ -187,7 +245,7 @@ mod tests {
         ];
 
         let input_array: LineStringArray = (vec![input_geom].as_slice(), Dimension::XY).into();
-        let result_array: PolygonArray = input_array.convex_hull();
+        let result_array: PolygonArray = input_array.convex_hull().unwrap();
 
         let expected = polygon![
             (x: 0.0, y: -10.0),
@@ -199,4 +257,26 @@ mod tests {
 
         assert_eq!(expected, result_array.get_as_geo(0).unwrap());
     }
+
+    #[test]
+    fn spherical_edges_policy() {
+        let metadata = Arc::new(ArrayMetadata::default().with_edges(Edges::Spherical));
+        let arr = PolygonBuilder::from_polygons(
+            &[p0(), p1()],
+            Dimension::XY,
+            CoordType::Interleaved,
+            metadata,
+        )
+        .finish();
+        let chunked = ChunkedGeometryArray::new(vec![arr.clone()]);
+
+        assert!(arr.convex_hull().is_err());
+        assert!(chunked.convex_hull().is_err());
+        assert!(arr
+            .convex_hull_with_policy(SphericalEdgesPolicy::Warn)
+            .is_ok());
+        assert!(chunked
+            .convex_hull_with_policy(SphericalEdgesPolicy::Warn)
+            .is_ok());
+    }
 }
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
@@ -14,31 +15,46 @@ use num_traits::FromPrimitive;
 /// a maximum distance of `max_distance` between them.
 ///
 /// Note: `max_distance` must be greater than 0.
+///
+/// New vertices are interpolated along straight segments, which would pull them off great-circle
+/// edges. Input that declares spherical edges is handled according to the
+/// [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
 pub trait Densify {
     type Output;
 
-    fn densify(&self, max_distance: f64) -> Self::Output;
+    fn densify(&self, max_distance: f64) -> Self::Output {
+        self.densify_with_policy(max_distance, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`densify`][Self::densify], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn densify_with_policy(&self, max_distance: f64, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 /// Implementation that iterates over geo objects
 macro_rules! iter_geo_impl {
     ($type:ty, $builder_type:ty, $method:ident, $geo_type:ty) => {
         impl Densify for $type {
-            type Output = $type;
-
-            fn densify(&self, max_distance: f64) -> Self::Output {
+            type Output = Result<$type>;
+
+            fn densify_with_policy(
+                &self,
+                max_distance: f64,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                check_planar_edges("Densify", &self.metadata(), policy)?;
                 let output_geoms: Vec<Option<$geo_type>> = self
                     .iter_geo()
                     .map(|maybe_g| maybe_g.map(|geom| geom.densify::<Euclidean>(max_distance)))
                     .collect();
 
-                <$builder_type>::$method(
+                Ok(<$builder_type>::$method(
                     output_geoms.as_slice(),
                     Dimension::XY,
                     self.coord_type(),
                     self.metadata.clone(),
                 )
-                .finish()
+                .finish())
             }
         }
     };
@@ -120,7 +136,8 @@ impl<F: geo::CoordFloat + FromPrimitive> geo::Densify<F> for GeometryDensifyWrap
 impl Densify for GeometryArray {
     type Output = Result<Self>;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
+    fn densify_with_policy(&self, max_distance: f64, policy: SphericalEdgesPolicy) -> Self::Output {
+        check_planar_edges("Densify", &self.metadata(), policy)?;
         let output_geoms: Vec<Option<geo::Geometry>> = self
             .iter_geo()
             .map(|maybe_g| {
@@ -141,15 +158,30 @@ impl Densify for GeometryArray {
 impl Densify for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
+    fn densify_with_policy(&self, max_distance: f64, policy: SphericalEdgesPolicy) -> Self::Output {
         use NativeType::*;
 
         let result: Arc<dyn NativeArray> = match self.data_type() {
-            LineString(_, _) => Arc::new(self.as_line_string().densify(max_distance)),
-            Polygon(_, _) => Arc::new(self.as_polygon().densify(max_distance)),
-            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().densify(max_distance)),
-            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().densify(max_distance)),
-            Geometry(_) => Arc::new(self.as_geometry().densify(max_distance)?),
+            LineString(_, _) => Arc::new(
+                self.as_line_string()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            Polygon(_, _) => Arc::new(
+                self.as_polygon()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            MultiLineString(_, _) => Arc::new(
+                self.as_multi_line_string()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            MultiPolygon(_, _) => Arc::new(
+                self.as_multi_polygon()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            Geometry(_) => Arc::new(
+                self.as_geometry()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
         Ok(result)
@@ -159,12 +191,15 @@ impl Densify for &dyn NativeArray {
 macro_rules! impl_chunked {
     ($struct_name:ty) => {
         impl Densify for $struct_name {
-            type Output = $struct_name;
-
-            fn densify(&self, max_distance: f64) -> Self::Output {
-                self.map(|chunk| chunk.densify(max_distance))
+            type Output = Result<$struct_name>;
+
+            fn densify_with_policy(
+                &self,
+                max_distance: f64,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                self.try_map(|chunk| chunk.densify_with_policy(max_distance, policy))?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -178,14 +213,26 @@ impl_chunked!(ChunkedMultiPolygonArray);
 impl Densify for &dyn ChunkedNativeArray {
     type Output = Result<Arc<dyn ChunkedNativeArray>>;
 
-    fn densify(&self, max_distance: f64) -> Self::Output {
+    fn densify_with_policy(&self, max_distance: f64, policy: SphericalEdgesPolicy) -> Self::Output {
         use NativeType::*;
 
         let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
-            LineString(_, _) => Arc::new(self.as_line_string().densify(max_distance)),
-            Polygon(_, _) => Arc::new(self.as_polygon().densify(max_distance)),
-            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().densify(max_distance)),
-            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().densify(max_distance)),
+            LineString(_, _) => Arc::new(
+                self.as_line_string()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            Polygon(_, _) => Arc::new(
+                self.as_polygon()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            MultiLineString(_, _) => Arc::new(
+                self.as_multi_line_string()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
+            MultiPolygon(_, _) => Arc::new(
+                self.as_multi_polygon()
+                    .densify_with_policy(max_distance, policy)?,
+            ),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
         Ok(result)
@@ -61,6 +61,14 @@ iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(RectArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl HasDimensions for &dyn NativeArray {
     type Output = Result<BooleanArray>;
@@ -71,13 +79,26 @@ impl HasDimensions for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => HasDimensions::is_empty(self.as_point()),
             LineString(_, _) => HasDimensions::is_empty(self.as_line_string()),
+            LargeLineString(_, _) => HasDimensions::is_empty(self.as_large_line_string()),
             Polygon(_, _) => HasDimensions::is_empty(self.as_polygon()),
+            LargePolygon(_, _) => HasDimensions::is_empty(self.as_large_polygon()),
             MultiPoint(_, _) => HasDimensions::is_empty(self.as_multi_point()),
+            LargeMultiPoint(_, _) => HasDimensions::is_empty(self.as_large_multi_point()),
             MultiLineString(_, _) => HasDimensions::is_empty(self.as_multi_line_string()),
+            LargeMultiLineString(_, _) => {
+                HasDimensions::is_empty(self.as_large_multi_line_string())
+            }
             MultiPolygon(_, _) => HasDimensions::is_empty(self.as_multi_polygon()),
+            LargeMultiPolygon(_, _) => HasDimensions::is_empty(self.as_large_multi_polygon()),
+            Mixed(_, _) => HasDimensions::is_empty(self.as_mixed()),
+            LargeMixed(_, _) => HasDimensions::is_empty(self.as_large_mixed()),
             GeometryCollection(_, _) => HasDimensions::is_empty(self.as_geometry_collection()),
+            LargeGeometryCollection(_, _) => {
+                HasDimensions::is_empty(self.as_large_geometry_collection())
+            }
             Rect(_) => HasDimensions::is_empty(self.as_rect()),
             Geometry(_) => HasDimensions::is_empty(self.as_geometry()),
+            LargeGeometry(_) => HasDimensions::is_empty(self.as_large_geometry()),
         };
         Ok(result)
     }
@@ -101,13 +122,26 @@ impl HasDimensions for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => HasDimensions::is_empty(self.as_point()),
             LineString(_, _) => HasDimensions::is_empty(self.as_line_string()),
+            LargeLineString(_, _) => HasDimensions::is_empty(self.as_large_line_string()),
             Polygon(_, _) => HasDimensions::is_empty(self.as_polygon()),
+            LargePolygon(_, _) => HasDimensions::is_empty(self.as_large_polygon()),
             MultiPoint(_, _) => HasDimensions::is_empty(self.as_multi_point()),
+            LargeMultiPoint(_, _) => HasDimensions::is_empty(self.as_large_multi_point()),
             MultiLineString(_, _) => HasDimensions::is_empty(self.as_multi_line_string()),
+            LargeMultiLineString(_, _) => {
+                HasDimensions::is_empty(self.as_large_multi_line_string())
+            }
             MultiPolygon(_, _) => HasDimensions::is_empty(self.as_multi_polygon()),
+            LargeMultiPolygon(_, _) => HasDimensions::is_empty(self.as_large_multi_polygon()),
+            Mixed(_, _) => HasDimensions::is_empty(self.as_mixed()),
+            LargeMixed(_, _) => HasDimensions::is_empty(self.as_large_mixed()),
             GeometryCollection(_, _) => HasDimensions::is_empty(self.as_geometry_collection()),
+            LargeGeometryCollection(_, _) => {
+                HasDimensions::is_empty(self.as_large_geometry_collection())
+            }
             Rect(_) => HasDimensions::is_empty(self.as_rect()),
             Geometry(_) => HasDimensions::is_empty(self.as_geometry()),
+            LargeGeometry(_) => HasDimensions::is_empty(self.as_large_geometry()),
         }
     }
 }
@@ -0,0 +1,46 @@
+use crate::array::metadata::{ArrayMetadata, Edges};
+use crate::error::{GeoArrowError, Result};
+
+/// What a planar-only operation does when its input declares [`Edges::Spherical`].
+///
+/// Planar-only operations error on spherical input by default; their `*_with_policy` variants
+/// take a policy to opt out per call. Operations with a geodesic counterpart, such as
+/// [`Area`][super::Area], switch to it for spherical input and don't take a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SphericalEdgesPolicy {
+    /// Return an error instead of a planar result.
+    #[default]
+    Error,
+
+    /// Log a warning with [`log::warn!`] and return the planar result.
+    Warn,
+}
+
+/// Whether `metadata` declares that edges follow a spherical path.
+pub(crate) fn has_spherical_edges(metadata: &ArrayMetadata) -> bool {
+    matches!(metadata.edges, Some(Edges::Spherical))
+}
+
+/// Check that `operation`, which only has a planar implementation, may run on an array with
+/// `metadata` under `policy`.
+pub(crate) fn check_planar_edges(
+    operation: &str,
+    metadata: &ArrayMetadata,
+    policy: SphericalEdgesPolicy,
+) -> Result<()> {
+    if !has_spherical_edges(metadata) {
+        return Ok(());
+    }
+
+    match policy {
+        SphericalEdgesPolicy::Error => Err(GeoArrowError::General(format!(
+            "{operation} is only implemented for planar edges, but the input has spherical edges"
+        ))),
+        SphericalEdgesPolicy::Warn => {
+            log::warn!(
+                "{operation} is only implemented for planar edges; ignoring spherical edges"
+            );
+            Ok(())
+        }
+    }
+}
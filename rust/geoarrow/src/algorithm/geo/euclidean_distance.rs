@@ -1,7 +1,10 @@
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::array::*;
+use crate::error::Result;
 use crate::scalar::*;
 use crate::trait_::ArrayAccessor;
 use crate::trait_::NativeScalar;
+use crate::ArrayBase;
 use arrow_array::builder::Float64Builder;
 use arrow_array::Float64Array;
 use geo::{Distance, Euclidean};
@@ -17,6 +20,9 @@ pub trait EuclideanDistance<Rhs> {
     ///
     /// The distance between a `Point` and an empty `LineString` is `0.0`
     ///
+    /// Distances are measured in the plane of the coordinates, so this errors if an input array
+    /// declares spherical edges.
+    ///
     /// # Examples
     ///
     /// `Point` to `Point`:
@@ -84,7 +90,7 @@ pub trait EuclideanDistance<Rhs> {
     ///
     /// assert_relative_eq!(distance, 1.1313708498984762);
     /// ```
-    fn euclidean_distance(&self, rhs: &Rhs) -> Float64Array;
+    fn euclidean_distance(&self, rhs: &Rhs) -> Result<Float64Array>;
 }
 
 // ┌────────────────────────────────┐
@@ -94,7 +100,17 @@ pub trait EuclideanDistance<Rhs> {
 // Note: this implementation is outside the macro because it is not generic over O
 impl EuclideanDistance<PointArray> for PointArray {
     /// Minimum distance between two Points
-    fn euclidean_distance(&self, other: &PointArray) -> Float64Array {
+    fn euclidean_distance(&self, other: &PointArray) -> Result<Float64Array> {
+        check_planar_edges(
+            "EuclideanDistance",
+            &self.metadata(),
+            SphericalEdgesPolicy::Error,
+        )?;
+        check_planar_edges(
+            "EuclideanDistance",
+            &other.metadata(),
+            SphericalEdgesPolicy::Error,
+        )?;
         assert_eq!(self.len(), other.len());
         let mut output_array = Float64Builder::with_capacity(self.len());
 
@@ -107,7 +123,7 @@ impl EuclideanDistance<PointArray> for PointArray {
                 _ => output_array.append_null(),
             });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -115,7 +131,17 @@ impl EuclideanDistance<PointArray> for PointArray {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a> EuclideanDistance<$second> for $first {
-            fn euclidean_distance(&self, other: &$second) -> Float64Array {
+            fn euclidean_distance(&self, other: &$second) -> Result<Float64Array> {
+                check_planar_edges(
+                    "EuclideanDistance",
+                    &self.metadata(),
+                    SphericalEdgesPolicy::Error,
+                )?;
+                check_planar_edges(
+                    "EuclideanDistance",
+                    &other.metadata(),
+                    SphericalEdgesPolicy::Error,
+                )?;
                 assert_eq!(self.len(), other.len());
                 let mut output_array = Float64Builder::with_capacity(self.len());
 
@@ -128,7 +154,7 @@ macro_rules! iter_geo_impl {
                         _ => output_array.append_null(),
                     });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -188,7 +214,12 @@ iter_geo_impl!(MultiPolygonArray, PointArray);
 // Note: this implementation is outside the macro because it is not generic over O
 impl<'a> EuclideanDistance<Point<'a>> for PointArray {
     /// Minimum distance between two Points
-    fn euclidean_distance(&self, other: &Point<'a>) -> Float64Array {
+    fn euclidean_distance(&self, other: &Point<'a>) -> Result<Float64Array> {
+        check_planar_edges(
+            "EuclideanDistance",
+            &self.metadata(),
+            SphericalEdgesPolicy::Error,
+        )?;
         let mut output_array = Float64Builder::with_capacity(self.len());
 
         self.iter_geo().for_each(|maybe_point| {
@@ -196,7 +227,7 @@ impl<'a> EuclideanDistance<Point<'a>> for PointArray {
             output_array.append_option(output)
         });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -204,7 +235,12 @@ impl<'a> EuclideanDistance<Point<'a>> for PointArray {
 macro_rules! iter_geo_impl_scalar {
     ($first:ty, $second:ty) => {
         impl<'a> EuclideanDistance<$second> for $first {
-            fn euclidean_distance(&self, other: &$second) -> Float64Array {
+            fn euclidean_distance(&self, other: &$second) -> Result<Float64Array> {
+                check_planar_edges(
+                    "EuclideanDistance",
+                    &self.metadata(),
+                    SphericalEdgesPolicy::Error,
+                )?;
                 let mut output_array = Float64Builder::with_capacity(self.len());
                 let other_geo = other.to_geo();
 
@@ -213,7 +249,7 @@ macro_rules! iter_geo_impl_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -1,3 +1,4 @@
+use crate::algorithm::geo::has_spherical_edges;
 use crate::algorithm::geo::utils::zeroes;
 use crate::algorithm::native::Unary;
 use crate::array::*;
@@ -7,13 +8,16 @@ use crate::error::{GeoArrowError, Result};
 use crate::trait_::NativeScalar;
 use crate::NativeArray;
 use arrow_array::Float64Array;
-use geo::{Euclidean, Length};
+use geo::{Euclidean, Geodesic, Length};
 
 pub trait EuclideanLength {
     type Output;
 
     /// Calculation of the length of a Line
     ///
+    /// Arrays that declare spherical edges are measured with
+    /// [`GeodesicLength`][super::GeodesicLength] instead, in meters.
+    ///
     /// # Examples
     ///
     /// ```
@@ -61,7 +65,11 @@ macro_rules! iter_geo_impl {
             type Output = Float64Array;
 
             fn euclidean_length(&self) -> Self::Output {
-                self.unary_primitive(|geom| geom.to_geo().length::<Euclidean>())
+                if has_spherical_edges(&self.metadata()) {
+                    self.unary_primitive(|geom| geom.to_geo().length::<Geodesic>())
+                } else {
+                    self.unary_primitive(|geom| geom.to_geo().length::<Euclidean>())
+                }
             }
         }
     };
@@ -146,8 +154,13 @@ impl EuclideanLength for &dyn ChunkedNativeArray {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use super::*;
+    use crate::algorithm::geo::GeodesicLength;
+    use crate::array::metadata::{ArrayMetadata, Edges};
     use crate::array::LineStringArray;
+    use crate::test::linestring::{ls0, ls1, ls_array};
     use arrow_array::Array;
     use geo::line_string;
 
@@ -168,4 +181,19 @@ mod tests {
         assert_eq!(expected, result_array.value(0).round());
         assert!(result_array.is_valid(0));
     }
+
+    #[test]
+    fn spherical_edges_use_geodesic_length() {
+        let metadata = Arc::new(ArrayMetadata::default().with_edges(Edges::Spherical));
+        let arr = LineStringBuilder::from_line_strings(
+            &[ls0(), ls1()],
+            Dimension::XY,
+            CoordType::Interleaved,
+            metadata,
+        )
+        .finish();
+
+        assert_eq!(arr.euclidean_length(), arr.geodesic_length());
+        assert_ne!(arr.euclidean_length(), ls_array().euclidean_length());
+    }
 }
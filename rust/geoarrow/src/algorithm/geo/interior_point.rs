@@ -84,6 +84,14 @@ iter_geo_impl!(MultiPolygonArray);
 iter_geo_impl!(MixedGeometryArray);
 iter_geo_impl!(GeometryCollectionArray);
 iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
 
 impl InteriorPoint for &dyn NativeArray {
     type Output = Result<PointArray>;
@@ -94,13 +102,22 @@ impl InteriorPoint for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().interior_point(),
             LineString(_, _) => self.as_line_string().interior_point(),
+            LargeLineString(_, _) => self.as_large_line_string().interior_point(),
             Polygon(_, _) => self.as_polygon().interior_point(),
+            LargePolygon(_, _) => self.as_large_polygon().interior_point(),
             MultiPoint(_, _) => self.as_multi_point().interior_point(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().interior_point(),
             MultiLineString(_, _) => self.as_multi_line_string().interior_point(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().interior_point(),
             MultiPolygon(_, _) => self.as_multi_polygon().interior_point(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().interior_point(),
+            Mixed(_, _) => self.as_mixed().interior_point(),
+            LargeMixed(_, _) => self.as_large_mixed().interior_point(),
             GeometryCollection(_, _) => self.as_geometry_collection().interior_point(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().interior_point(),
             Rect(_) => self.as_rect().interior_point(),
             Geometry(_) => self.as_geometry().interior_point(),
+            LargeGeometry(_) => self.as_large_geometry().interior_point(),
         };
         Ok(result)
     }
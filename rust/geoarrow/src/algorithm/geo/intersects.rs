@@ -1,8 +1,11 @@
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::chunked_array::ChunkedArray;
+use crate::error::Result;
 use crate::indexed::array::*;
 use crate::indexed::chunked::*;
 use crate::io::geo::{geometry_collection_to_geo, geometry_to_geo};
 use crate::trait_::NativeScalar;
+use crate::ArrayBase;
 use arrow_array::BooleanArray;
 use geo::{BoundingRect, Intersects as _Intersects};
 use geo_traits::to_geo::*;
@@ -22,6 +25,9 @@ use geo_traits::{
 ///
 /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
 ///
+/// This is a planar operation. Input whose metadata declares spherical edges is handled according
+/// to the [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
+///
 /// # Examples
 ///
 /// ```
@@ -49,18 +55,25 @@ use geo_traits::{
 pub trait Intersects<Rhs = Self> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl Intersects for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &Self) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &Self, policy: SphericalEdgesPolicy) -> Self::Output {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        check_planar_edges("Intersects", &rhs.array.metadata(), policy)?;
         self.try_binary_boolean(rhs, |left, right| {
             Ok(left.to_geo().intersects(&right.to_geo()))
         })
-        .unwrap()
     }
 }
 
@@ -68,13 +81,18 @@ impl Intersects for IndexedPointArray {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a> Intersects<$second> for $first {
-            type Output = BooleanArray;
-
-            fn intersects(&self, rhs: &$second) -> Self::Output {
+            type Output = Result<BooleanArray>;
+
+            fn intersects_with_policy(
+                &self,
+                rhs: &$second,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                check_planar_edges("Intersects", &rhs.array.metadata(), policy)?;
                 self.try_binary_boolean(rhs, |left, right| {
                     Ok(left.to_geo().intersects(&right.to_geo()))
                 })
-                .unwrap()
             }
         }
     };
@@ -146,26 +164,38 @@ iter_geo_impl!(IndexedMultiPolygonArray, IndexedGeometryCollectionArray);
 pub trait IntersectsPoint<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: PointTrait<T = f64>> IntersectsPoint<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_point();
-        self.unary_boolean(&rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs)))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: PointTrait<T = f64>> IntersectsPoint<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_point();
-                self.unary_boolean(&rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs))
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect(), |geom| geom.to_geo().intersects(&rhs)))
             }
         }
     };
@@ -180,26 +210,32 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: PointTrait<T = f64>> IntersectsPoint<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_point();
-        self.map(|chunk| IntersectsPoint::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsPoint::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: PointTrait<T = f64>> IntersectsPoint<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_point();
-                self.map(|chunk| IntersectsPoint::intersects(chunk, &rhs))
+                self.map(|chunk| IntersectsPoint::intersects_with_policy(chunk, &rhs, policy))
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -216,30 +252,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsLineString<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: LineStringTrait<T = f64>> IntersectsLineString<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_line_string();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: LineStringTrait<T = f64>> IntersectsLineString<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_line_string();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -254,26 +302,32 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: LineStringTrait<T = f64>> IntersectsLineString<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_line_string();
-        self.map(|chunk| IntersectsLineString::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsLineString::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: LineStringTrait<T = f64>> IntersectsLineString<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_line_string();
-                self.map(|chunk| IntersectsLineString::intersects(chunk, &rhs))
+                self.map(|chunk| IntersectsLineString::intersects_with_policy(chunk, &rhs, policy))
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -290,30 +344,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsPolygon<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: PolygonTrait<T = f64>> IntersectsPolygon<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_polygon();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: PolygonTrait<T = f64>> IntersectsPolygon<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_polygon();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -328,26 +394,32 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: PolygonTrait<T = f64>> IntersectsPolygon<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_polygon();
-        self.map(|chunk| IntersectsPolygon::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsPolygon::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: PolygonTrait<T = f64>> IntersectsPolygon<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_polygon();
-                self.map(|chunk| IntersectsPolygon::intersects(chunk, &rhs))
+                self.map(|chunk| IntersectsPolygon::intersects_with_policy(chunk, &rhs, policy))
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -364,30 +436,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsMultiPoint<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: MultiPointTrait<T = f64>> IntersectsMultiPoint<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_multi_point();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: MultiPointTrait<T = f64>> IntersectsMultiPoint<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_multi_point();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -402,26 +486,32 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: MultiPointTrait<T = f64>> IntersectsMultiPoint<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_multi_point();
-        self.map(|chunk| IntersectsMultiPoint::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsMultiPoint::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: MultiPointTrait<T = f64>> IntersectsMultiPoint<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_multi_point();
-                self.map(|chunk| IntersectsMultiPoint::intersects(chunk, &rhs))
+                self.map(|chunk| IntersectsMultiPoint::intersects_with_policy(chunk, &rhs, policy))
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -438,30 +528,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsMultiLineString<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: MultiLineStringTrait<T = f64>> IntersectsMultiLineString<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_multi_line_string();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: MultiLineStringTrait<T = f64>> IntersectsMultiLineString<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_multi_line_string();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -476,26 +578,34 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: MultiLineStringTrait<T = f64>> IntersectsMultiLineString<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_multi_line_string();
-        self.map(|chunk| IntersectsMultiLineString::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsMultiLineString::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: MultiLineStringTrait<T = f64>> IntersectsMultiLineString<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_multi_line_string();
-                self.map(|chunk| IntersectsMultiLineString::intersects(chunk, &rhs))
-                    .try_into()
-                    .unwrap()
+                self.map(|chunk| {
+                    IntersectsMultiLineString::intersects_with_policy(chunk, &rhs, policy)
+                })
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
             }
         }
     };
@@ -512,30 +622,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsMultiPolygon<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: MultiPolygonTrait<T = f64>> IntersectsMultiPolygon<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_multi_polygon();
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: MultiPolygonTrait<T = f64>> IntersectsMultiPolygon<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_multi_polygon();
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -550,26 +672,34 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: MultiPolygonTrait<T = f64>> IntersectsMultiPolygon<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = rhs.to_multi_polygon();
-        self.map(|chunk| IntersectsMultiPolygon::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsMultiPolygon::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: MultiPolygonTrait<T = f64>> IntersectsMultiPolygon<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = rhs.to_multi_polygon();
-                self.map(|chunk| IntersectsMultiPolygon::intersects(chunk, &rhs))
-                    .try_into()
-                    .unwrap()
+                self.map(|chunk| {
+                    IntersectsMultiPolygon::intersects_with_policy(chunk, &rhs, policy)
+                })
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
             }
         }
     };
@@ -586,30 +716,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsGeometry<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: GeometryTrait<T = f64>> IntersectsGeometry<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = geometry_to_geo(rhs);
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: GeometryTrait<T = f64>> IntersectsGeometry<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = geometry_to_geo(rhs);
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -624,26 +766,32 @@ impl_intersects!(IndexedMixedGeometryArray);
 impl_intersects!(IndexedGeometryCollectionArray);
 
 impl<G: GeometryTrait<T = f64>> IntersectsGeometry<G> for IndexedChunkedPointArray {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = geometry_to_geo(rhs);
-        self.map(|chunk| IntersectsGeometry::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsGeometry::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: GeometryTrait<T = f64>> IntersectsGeometry<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = geometry_to_geo(rhs);
-                self.map(|chunk| IntersectsGeometry::intersects(chunk, &rhs))
+                self.map(|chunk| IntersectsGeometry::intersects_with_policy(chunk, &rhs, policy))
+                    .into_iter()
+                    .collect::<Result<Vec<_>>>()?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -660,30 +808,42 @@ impl_intersects!(IndexedChunkedGeometryCollectionArray);
 pub trait IntersectsGeometryCollection<Rhs> {
     type Output;
 
-    fn intersects(&self, rhs: &Rhs) -> Self::Output;
+    fn intersects(&self, rhs: &Rhs) -> Self::Output {
+        self.intersects_with_policy(rhs, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`intersects`][Self::intersects], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn intersects_with_policy(&self, rhs: &Rhs, policy: SphericalEdgesPolicy) -> Self::Output;
 }
 
 impl<G: GeometryCollectionTrait<T = f64>> IntersectsGeometryCollection<G> for IndexedPointArray {
-    type Output = BooleanArray;
+    type Output = Result<BooleanArray>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = geometry_collection_to_geo(rhs);
-        self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+        check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+        Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
             geom.to_geo().intersects(&rhs)
-        })
+        }))
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: GeometryCollectionTrait<T = f64>> IntersectsGeometryCollection<G> for $struct_name {
-            type Output = BooleanArray;
+            type Output = Result<BooleanArray>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = geometry_collection_to_geo(rhs);
-                self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
+                check_planar_edges("Intersects", &self.array.metadata(), policy)?;
+                Ok(self.unary_boolean(&rhs.bounding_rect().unwrap(), |geom| {
                     geom.to_geo().intersects(&rhs)
-                })
+                }))
             }
         }
     };
@@ -700,26 +860,34 @@ impl_intersects!(IndexedGeometryCollectionArray);
 impl<G: GeometryCollectionTrait<T = f64>> IntersectsGeometryCollection<G>
     for IndexedChunkedPointArray
 {
-    type Output = ChunkedArray<BooleanArray>;
+    type Output = Result<ChunkedArray<BooleanArray>>;
 
-    fn intersects(&self, rhs: &G) -> Self::Output {
+    fn intersects_with_policy(&self, rhs: &G, policy: SphericalEdgesPolicy) -> Self::Output {
         let rhs = geometry_collection_to_geo(rhs);
-        self.map(|chunk| IntersectsGeometryCollection::intersects(chunk, &rhs))
+        self.map(|chunk| IntersectsGeometryCollection::intersects_with_policy(chunk, &rhs, policy))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
             .try_into()
-            .unwrap()
     }
 }
 
 macro_rules! impl_intersects {
     ($struct_name:ty) => {
         impl<G: GeometryCollectionTrait<T = f64>> IntersectsGeometryCollection<G> for $struct_name {
-            type Output = ChunkedArray<BooleanArray>;
+            type Output = Result<ChunkedArray<BooleanArray>>;
 
-            fn intersects(&self, rhs: &G) -> Self::Output {
+            fn intersects_with_policy(
+                &self,
+                rhs: &G,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
                 let rhs = geometry_collection_to_geo(rhs);
-                self.map(|chunk| IntersectsGeometryCollection::intersects(chunk, &rhs))
-                    .try_into()
-                    .unwrap()
+                self.map(|chunk| {
+                    IntersectsGeometryCollection::intersects_with_policy(chunk, &rhs, policy)
+                })
+                .into_iter()
+                .collect::<Result<Vec<_>>>()?
+                .try_into()
             }
         }
     };
@@ -0,0 +1,522 @@
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+use arrow_array::builder::{BooleanBuilder, StringBuilder};
+use arrow_array::{BooleanArray, StringArray};
+use geo::coordinate_position::{CoordPos, CoordinatePosition};
+use geo::line_intersection::{line_intersection, LineIntersection};
+use geo::{Coord, Line, Winding};
+use geo_traits::{
+    CoordTrait, GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait,
+    MultiPolygonTrait, PolygonTrait,
+};
+
+/// Check the validity of geometries, reporting why invalid geometries are invalid.
+///
+/// The checks performed are:
+///
+/// - all coordinates are finite (`Invalid Coordinate`)
+/// - no two consecutive coordinates of a line string or ring are equal (`Duplicate Point`)
+/// - line strings have at least two points and rings at least four (`Too few points`)
+/// - rings are closed (`Ring is not closed`)
+/// - rings do not intersect themselves, and the rings of a polygon do not cross each other
+///   (`Self-intersection`)
+/// - interior rings lie inside the exterior ring (`Hole lies outside shell`)
+/// - exterior rings are counter-clockwise and interior rings are clockwise, following
+///   [RFC 7946](https://www.rfc-editor.org/rfc/rfc7946#section-3.1.6) (`Incorrect ring
+///   orientation`)
+///
+/// Each reason is suffixed with the offending location, e.g. `Self-intersection[1 1]`. The
+/// polygons of a multi polygon are checked individually; overlap between them is not detected.
+///
+/// Use [`geos::IsValid`][crate::algorithm::geos::IsValid] for a full OGC validity check.
+/// Repairing invalid geometries also requires the `geos` feature, through
+/// [`MakeValid`][crate::algorithm::geos::MakeValid].
+pub trait IsValid {
+    type Output;
+
+    /// Returns whether each geometry is valid, together with the reason each invalid geometry is
+    /// invalid.
+    ///
+    /// The reason is null for valid geometries. Both arrays are null where the input is null.
+    fn is_valid(&self) -> Self::Output;
+}
+
+/// Implementation that iterates over geo objects
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl IsValid for $type {
+            type Output = (BooleanArray, StringArray);
+
+            fn is_valid(&self) -> Self::Output {
+                let mut valid_builder = BooleanBuilder::with_capacity(self.len());
+                let mut reason_builder = StringBuilder::with_capacity(self.len(), 0);
+                self.iter_geo().for_each(|maybe_g| match maybe_g {
+                    Some(g) => {
+                        let reason = g.invalid_reason();
+                        valid_builder.append_value(reason.is_none());
+                        reason_builder.append_option(reason);
+                    }
+                    None => {
+                        valid_builder.append_null();
+                        reason_builder.append_null();
+                    }
+                });
+                (valid_builder.finish(), reason_builder.finish())
+            }
+        }
+    };
+    // Arrays with polygon rings first check with `$unclosed_ring` that each ring is closed,
+    // because converting to geo closes rings.
+    ($type:ty, $unclosed_ring:ident) => {
+        impl IsValid for $type {
+            type Output = (BooleanArray, StringArray);
+
+            fn is_valid(&self) -> Self::Output {
+                let mut valid_builder = BooleanBuilder::with_capacity(self.len());
+                let mut reason_builder = StringBuilder::with_capacity(self.len(), 0);
+                self.iter()
+                    .zip(self.iter_geo())
+                    .for_each(|maybe_g| match maybe_g {
+                        (Some(scalar), Some(g)) => {
+                            let reason = $unclosed_ring(&scalar)
+                                .map(|coord| located("Ring is not closed", coord))
+                                .or_else(|| g.invalid_reason());
+                            valid_builder.append_value(reason.is_none());
+                            reason_builder.append_option(reason);
+                        }
+                        _ => {
+                            valid_builder.append_null();
+                            reason_builder.append_null();
+                        }
+                    });
+                (valid_builder.finish(), reason_builder.finish())
+            }
+        }
+    };
+}
+
+iter_geo_impl!(PointArray);
+iter_geo_impl!(LineStringArray);
+iter_geo_impl!(PolygonArray, polygon_unclosed_ring);
+iter_geo_impl!(MultiPointArray);
+iter_geo_impl!(MultiLineStringArray);
+iter_geo_impl!(MultiPolygonArray, multi_polygon_unclosed_ring);
+iter_geo_impl!(MixedGeometryArray, geometry_unclosed_ring);
+iter_geo_impl!(GeometryCollectionArray, geometry_collection_unclosed_ring);
+iter_geo_impl!(RectArray);
+iter_geo_impl!(GeometryArray, geometry_unclosed_ring);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray, polygon_unclosed_ring);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray, multi_polygon_unclosed_ring);
+iter_geo_impl!(LargeMixedGeometryArray, geometry_unclosed_ring);
+iter_geo_impl!(
+    LargeGeometryCollectionArray,
+    geometry_collection_unclosed_ring
+);
+iter_geo_impl!(LargeGeometryArray, geometry_unclosed_ring);
+
+impl IsValid for &dyn NativeArray {
+    type Output = Result<(BooleanArray, StringArray)>;
+
+    fn is_valid(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result = match self.data_type() {
+            Point(_, _) => IsValid::is_valid(self.as_point()),
+            LineString(_, _) => IsValid::is_valid(self.as_line_string()),
+            LargeLineString(_, _) => IsValid::is_valid(self.as_large_line_string()),
+            Polygon(_, _) => IsValid::is_valid(self.as_polygon()),
+            LargePolygon(_, _) => IsValid::is_valid(self.as_large_polygon()),
+            MultiPoint(_, _) => IsValid::is_valid(self.as_multi_point()),
+            LargeMultiPoint(_, _) => IsValid::is_valid(self.as_large_multi_point()),
+            MultiLineString(_, _) => IsValid::is_valid(self.as_multi_line_string()),
+            LargeMultiLineString(_, _) => IsValid::is_valid(self.as_large_multi_line_string()),
+            MultiPolygon(_, _) => IsValid::is_valid(self.as_multi_polygon()),
+            LargeMultiPolygon(_, _) => IsValid::is_valid(self.as_large_multi_polygon()),
+            Mixed(_, _) => IsValid::is_valid(self.as_mixed()),
+            LargeMixed(_, _) => IsValid::is_valid(self.as_large_mixed()),
+            GeometryCollection(_, _) => IsValid::is_valid(self.as_geometry_collection()),
+            LargeGeometryCollection(_, _) => IsValid::is_valid(self.as_large_geometry_collection()),
+            Rect(_) => IsValid::is_valid(self.as_rect()),
+            Geometry(_) => IsValid::is_valid(self.as_geometry()),
+            LargeGeometry(_) => IsValid::is_valid(self.as_large_geometry()),
+        };
+        Ok(result)
+    }
+}
+
+impl<G: NativeArray> IsValid for ChunkedGeometryArray<G> {
+    type Output = Result<(ChunkedArray<BooleanArray>, ChunkedArray<StringArray>)>;
+
+    fn is_valid(&self) -> Self::Output {
+        let (valid, reasons): (Vec<_>, Vec<_>) = self
+            .try_map(|chunk| IsValid::is_valid(&chunk.as_ref()))?
+            .into_iter()
+            .unzip();
+        Ok((ChunkedArray::new(valid), ChunkedArray::new(reasons)))
+    }
+}
+
+impl IsValid for &dyn ChunkedNativeArray {
+    type Output = Result<(ChunkedArray<BooleanArray>, ChunkedArray<StringArray>)>;
+
+    fn is_valid(&self) -> Self::Output {
+        let (valid, reasons): (Vec<_>, Vec<_>) = self
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| IsValid::is_valid(&chunk.as_ref()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        Ok((ChunkedArray::new(valid), ChunkedArray::new(reasons)))
+    }
+}
+
+/// The reason a geo geometry is invalid, or `None` if it is valid.
+trait InvalidReason {
+    fn invalid_reason(&self) -> Option<String>;
+}
+
+impl InvalidReason for geo::Point {
+    fn invalid_reason(&self) -> Option<String> {
+        check_coord(self.0)
+    }
+}
+
+impl InvalidReason for geo::LineString {
+    fn invalid_reason(&self) -> Option<String> {
+        if self.0.is_empty() {
+            return None;
+        }
+        if let Some(reason) = check_coords(&self.0) {
+            return Some(reason);
+        }
+        if self.0.len() < 2 {
+            return Some(located("Too few points", self.0[0]));
+        }
+        None
+    }
+}
+
+impl InvalidReason for geo::Polygon {
+    fn invalid_reason(&self) -> Option<String> {
+        let exterior = self.exterior();
+        if exterior.0.is_empty() {
+            return match self.interiors().first() {
+                Some(interior) if !interior.0.is_empty() => {
+                    Some(located("Hole lies outside shell", interior.0[0]))
+                }
+                _ => None,
+            };
+        }
+
+        for ring in std::iter::once(exterior).chain(self.interiors()) {
+            if let Some(reason) = check_ring(ring) {
+                return Some(reason);
+            }
+        }
+
+        let rings = std::iter::once(exterior)
+            .chain(self.interiors())
+            .collect::<Vec<_>>();
+        if let Some(coord) = find_intersection(&rings) {
+            return Some(located("Self-intersection", coord));
+        }
+
+        let shell = geo::Polygon::new(exterior.clone(), vec![]);
+        for interior in self.interiors() {
+            // The rings do not cross, so any vertex of the hole not on the shell boundary tells
+            // us which side of the shell the hole is on.
+            let outside = interior
+                .0
+                .iter()
+                .map(|coord| shell.coordinate_position(coord))
+                .find(|position| *position != CoordPos::OnBoundary);
+            if outside == Some(CoordPos::Outside) {
+                return Some(located("Hole lies outside shell", interior.0[0]));
+            }
+        }
+
+        if exterior.is_cw() {
+            return Some(located("Incorrect ring orientation", exterior.0[0]));
+        }
+        if let Some(interior) = self.interiors().iter().find(|ring| ring.is_ccw()) {
+            return Some(located("Incorrect ring orientation", interior.0[0]));
+        }
+
+        None
+    }
+}
+
+impl InvalidReason for geo::MultiPoint {
+    fn invalid_reason(&self) -> Option<String> {
+        self.iter().find_map(|point| point.invalid_reason())
+    }
+}
+
+impl InvalidReason for geo::MultiLineString {
+    fn invalid_reason(&self) -> Option<String> {
+        self.iter()
+            .find_map(|line_string| line_string.invalid_reason())
+    }
+}
+
+impl InvalidReason for geo::MultiPolygon {
+    fn invalid_reason(&self) -> Option<String> {
+        self.iter().find_map(|polygon| polygon.invalid_reason())
+    }
+}
+
+impl InvalidReason for geo::GeometryCollection {
+    fn invalid_reason(&self) -> Option<String> {
+        self.iter().find_map(|geometry| geometry.invalid_reason())
+    }
+}
+
+impl InvalidReason for geo::Rect {
+    fn invalid_reason(&self) -> Option<String> {
+        check_coord(self.min()).or_else(|| check_coord(self.max()))
+    }
+}
+
+impl InvalidReason for geo::Line {
+    fn invalid_reason(&self) -> Option<String> {
+        check_coord(self.start).or_else(|| check_coord(self.end))
+    }
+}
+
+impl InvalidReason for geo::Geometry {
+    fn invalid_reason(&self) -> Option<String> {
+        use geo::Geometry;
+
+        match self {
+            Geometry::Point(g) => g.invalid_reason(),
+            Geometry::Line(g) => g.invalid_reason(),
+            Geometry::LineString(g) => g.invalid_reason(),
+            Geometry::Polygon(g) => g.invalid_reason(),
+            Geometry::MultiPoint(g) => g.invalid_reason(),
+            Geometry::MultiLineString(g) => g.invalid_reason(),
+            Geometry::MultiPolygon(g) => g.invalid_reason(),
+            Geometry::GeometryCollection(g) => g.invalid_reason(),
+            Geometry::Rect(g) => g.invalid_reason(),
+            Geometry::Triangle(g) => g.to_polygon().invalid_reason(),
+        }
+    }
+}
+
+fn located(reason: &str, coord: Coord) -> String {
+    format!("{}[{} {}]", reason, coord.x, coord.y)
+}
+
+fn check_coord(coord: Coord) -> Option<String> {
+    if coord.x.is_finite() && coord.y.is_finite() {
+        None
+    } else {
+        Some(located("Invalid Coordinate", coord))
+    }
+}
+
+/// Check that all coordinates are finite and that no two consecutive coordinates are equal.
+fn check_coords(coords: &[Coord]) -> Option<String> {
+    coords
+        .iter()
+        .find_map(|coord| check_coord(*coord))
+        .or_else(|| {
+            coords
+                .windows(2)
+                .find(|pair| pair[0] == pair[1])
+                .map(|pair| located("Duplicate Point", pair[1]))
+        })
+}
+
+/// The first coordinate of the first ring of `polygon` that is not closed.
+fn polygon_unclosed_ring(polygon: &impl PolygonTrait<T = f64>) -> Option<Coord> {
+    polygon
+        .exterior()
+        .into_iter()
+        .chain(polygon.interiors())
+        .find_map(|ring| {
+            let first = ring.coords().next()?;
+            let last = ring.coords().next_back()?;
+            (first.x() != last.x() || first.y() != last.y()).then(|| Coord {
+                x: first.x(),
+                y: first.y(),
+            })
+        })
+}
+
+fn multi_polygon_unclosed_ring(multi_polygon: &impl MultiPolygonTrait<T = f64>) -> Option<Coord> {
+    multi_polygon
+        .polygons()
+        .find_map(|polygon| polygon_unclosed_ring(&polygon))
+}
+
+fn geometry_collection_unclosed_ring(
+    collection: &impl GeometryCollectionTrait<T = f64>,
+) -> Option<Coord> {
+    collection
+        .geometries()
+        .find_map(|geometry| geometry_unclosed_ring(&geometry))
+}
+
+fn geometry_unclosed_ring(geometry: &impl GeometryTrait<T = f64>) -> Option<Coord> {
+    match geometry.as_type() {
+        GeometryType::Polygon(g) => polygon_unclosed_ring(g),
+        GeometryType::MultiPolygon(g) => multi_polygon_unclosed_ring(g),
+        GeometryType::GeometryCollection(g) => geometry_collection_unclosed_ring(g),
+        _ => None,
+    }
+}
+
+fn check_ring(ring: &geo::LineString) -> Option<String> {
+    if ring.0.is_empty() {
+        return Some("Too few points".to_string());
+    }
+    if let Some(reason) = check_coords(&ring.0) {
+        return Some(reason);
+    }
+    if !ring.is_closed() {
+        return Some(located("Ring is not closed", ring.0[0]));
+    }
+    if ring.0.len() < 4 {
+        return Some(located("Too few points", ring.0[0]));
+    }
+    None
+}
+
+/// Find an invalid intersection between the segments of a set of closed rings.
+///
+/// Within a ring, segments may only meet their neighbours, and only at their shared vertex.
+/// Segments of different rings may touch but not cross or overlap.
+///
+/// This sweeps the segments in order of their minimum x coordinate, so only segments whose x
+/// ranges overlap are compared.
+fn find_intersection(rings: &[&geo::LineString]) -> Option<Coord> {
+    let mut segments = rings
+        .iter()
+        .enumerate()
+        .flat_map(|(ring_idx, ring)| {
+            ring.lines()
+                .enumerate()
+                .map(move |(segment_idx, line)| (ring_idx, segment_idx, line))
+        })
+        .collect::<Vec<_>>();
+    let min_x = |line: &Line| line.start.x.min(line.end.x);
+    segments.sort_by(|a, b| min_x(&a.2).total_cmp(&min_x(&b.2)));
+
+    for (i, (ring_a, idx_a, a)) in segments.iter().enumerate() {
+        let max_x = a.start.x.max(a.end.x);
+        for (ring_b, idx_b, b) in segments[i + 1..].iter() {
+            if min_x(b) > max_x {
+                break;
+            }
+            let Some(intersection) = line_intersection(*a, *b) else {
+                continue;
+            };
+            let allowed = match intersection {
+                LineIntersection::SinglePoint { is_proper, .. } if ring_a != ring_b => !is_proper,
+                LineIntersection::SinglePoint { .. } => {
+                    let num_segments = rings[*ring_a].0.len() - 1;
+                    let distance = idx_a.abs_diff(*idx_b);
+                    distance == 1 || distance == num_segments - 1
+                }
+                LineIntersection::Collinear { .. } => false,
+            };
+            if !allowed {
+                return Some(match intersection {
+                    LineIntersection::SinglePoint { intersection, .. } => intersection,
+                    LineIntersection::Collinear { intersection } => intersection.start,
+                });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use arrow_array::Array;
+    use arrow_buffer::OffsetBuffer;
+    use geo::polygon;
+
+    #[test]
+    fn polygon_reasons() {
+        let valid = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let bowtie = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let clockwise = polygon![
+            (x: 0., y: 0.),
+            (x: 0., y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 0.),
+            (x: 0., y: 0.),
+        ];
+        let duplicate = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 0., y: 0.),
+        ];
+        let arr: PolygonArray = (
+            vec![valid, bowtie, clockwise, duplicate].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let (valid, reasons) = IsValid::is_valid(&arr);
+        assert!(valid.value(0));
+        assert!(reasons.is_null(0));
+        assert!(!valid.value(1));
+        assert_eq!(reasons.value(1), "Self-intersection[1 1]");
+        assert_eq!(reasons.value(2), "Incorrect ring orientation[0 0]");
+        assert_eq!(reasons.value(3), "Duplicate Point[4 0]");
+    }
+
+    #[test]
+    fn unclosed_ring() {
+        let ring = geo::LineString::from(vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.)]);
+        assert_eq!(
+            check_ring(&ring).as_deref(),
+            Some("Ring is not closed[0 0]")
+        );
+    }
+
+    #[test]
+    fn unclosed_ring_in_array() {
+        let coords =
+            InterleavedCoordBuffer::new(vec![0., 0., 4., 0., 4., 4., 0., 4.].into(), Dimension::XY);
+        let arr = PolygonArray::new(
+            coords.into(),
+            OffsetBuffer::new(vec![0, 1].into()),
+            OffsetBuffer::new(vec![0, 4].into()),
+            None,
+            Default::default(),
+        );
+        let (valid, reasons) = IsValid::is_valid(&arr);
+        assert!(!valid.value(0));
+        assert_eq!(reasons.value(0), "Ring is not closed[0 0]");
+
+        let arr: &dyn NativeArray = &arr;
+        let (valid, _) = IsValid::is_valid(&arr).unwrap();
+        assert!(!valid.value(0));
+    }
+}
@@ -55,7 +55,12 @@ macro_rules! iter_geo_impl {
 
                 let capacity = PolygonCapacity::new(coord_capacity, ring_capacity, geom_capacity);
 
-                let mut output_array = PolygonBuilder::with_capacity(Dimension::XY, capacity);
+                let mut output_array = PolygonBuilder::with_capacity_and_options(
+                    Dimension::XY,
+                    capacity,
+                    self.coord_type(),
+                    self.metadata(),
+                );
 
                 self.iter_geo().for_each(|maybe_g| {
                     output_array
@@ -92,6 +97,7 @@ impl MinimumRotatedRect for &dyn NativeArray {
             MultiPoint(_, XY) => self.as_multi_point().minimum_rotated_rect(),
             MultiLineString(_, XY) => self.as_multi_line_string().minimum_rotated_rect(),
             MultiPolygon(_, XY) => self.as_multi_polygon().minimum_rotated_rect(),
+            Mixed(_, XY) => self.as_mixed().minimum_rotated_rect(),
             GeometryCollection(_, XY) => self.as_geometry_collection().minimum_rotated_rect(),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
         };
@@ -122,6 +128,7 @@ impl MinimumRotatedRect for &dyn ChunkedNativeArray {
             MultiPoint(_, XY) => self.as_multi_point().minimum_rotated_rect(),
             MultiLineString(_, XY) => self.as_multi_line_string().minimum_rotated_rect(),
             MultiPolygon(_, XY) => self.as_multi_polygon().minimum_rotated_rect(),
+            Mixed(_, XY) => self.as_mixed().minimum_rotated_rect(),
             GeometryCollection(_, XY) => self.as_geometry_collection().minimum_rotated_rect(),
             _ => Err(GeoArrowError::IncorrectType("".into())),
         }
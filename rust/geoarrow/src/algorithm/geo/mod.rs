@@ -14,6 +14,12 @@ pub use geo::AffineTransform;
 mod area;
 pub use area::Area;
 
+/// Boolean operations on polygonal geometries, such as intersection and union.
+mod bool_ops;
+pub(crate) use bool_ops::{finish_multi_polygons, to_multi_polygon};
+pub use bool_ops::{BooleanOps, BooleanOpsScalar};
+pub use geo::OpType;
+
 /// Calculate the bounding rectangle of geometries.
 mod bounding_rect;
 pub use bounding_rect::BoundingRect;
@@ -54,6 +60,11 @@ pub use densify::Densify;
 mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Handling of arrays whose metadata declares spherical edges.
+mod edges;
+pub use edges::SphericalEdgesPolicy;
+pub(crate) use edges::{check_planar_edges, has_spherical_edges};
+
 /// Calculate the length of a planar length of a
 /// [`LineStringArray`][crate::array::LineStringArray].
 mod euclidean_length;
@@ -86,6 +97,14 @@ pub use interior_point::InteriorPoint;
 mod intersects;
 pub use intersects::Intersects;
 
+/// Check the validity of geometries, with the reason invalid geometries are invalid.
+mod is_valid;
+pub use is_valid::IsValid;
+
+// There is no native repair: MakeValid is GEOS-backed and re-exported here next to IsValid
+#[cfg(feature = "geos")]
+pub use crate::algorithm::geos::{MakeValid, MakeValidMode};
+
 /// Interpolate a point along a `LineStringArray`.
 mod line_interpolate_point;
 pub use line_interpolate_point::LineInterpolatePoint;
@@ -41,8 +41,12 @@ macro_rules! iter_geo_impl {
             type Output = Self;
 
             fn remove_repeated_points(&self) -> Self::Output {
-                let mut output_array =
-                    <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
+                let mut output_array = <$builder_type>::with_capacity_and_options(
+                    Dimension::XY,
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
 
                 self.iter_geo().for_each(|maybe_g| {
                     output_array
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::algorithm::geo::{AffineOps, Center, Centroid};
+use crate::algorithm::geo::{AffineOps, Center, Centroid, SphericalEdgesPolicy};
 use crate::array::MultiPointArray;
 use crate::array::*;
 use crate::datatypes::{Dimension, NativeType};
@@ -93,6 +93,14 @@ pub trait Rotate<DegreesT> {
     fn rotate_around_point(&self, degrees: &DegreesT, point: geo::Point) -> Self::Output;
 }
 
+/// The centroids to rotate around. Rotation is an affine transform of the coordinates, so it
+/// pivots on the planar centroid, with a warning if the edges are declared spherical.
+fn pivot_centroids(array: &impl Centroid<Output = Result<PointArray>>) -> PointArray {
+    array
+        .centroid_with_policy(SphericalEdgesPolicy::Warn)
+        .expect("centroid only errors under SphericalEdgesPolicy::Error")
+}
+
 // ┌────────────────────────────────┐
 // │ Implementations for RHS arrays │
 // └────────────────────────────────┘
@@ -104,7 +112,7 @@ macro_rules! iter_geo_impl {
             type Output = Self;
 
             fn rotate_around_centroid(&self, degrees: &Float64Array) -> $type {
-                let centroids = self.centroid();
+                let centroids = pivot_centroids(self);
                 let transforms: Vec<AffineTransform> = centroids
                     .iter_geo_values()
                     .zip(degrees.values().iter())
@@ -151,7 +159,7 @@ impl Rotate<f64> for PointArray {
     type Output = Self;
 
     fn rotate_around_centroid(&self, degrees: &f64) -> Self {
-        let centroids = self.centroid();
+        let centroids = pivot_centroids(self);
         let transforms: Vec<AffineTransform> = centroids
             .iter_geo_values()
             .map(|point| AffineTransform::rotate(*degrees, point))
@@ -181,7 +189,7 @@ macro_rules! iter_geo_impl_scalar {
             type Output = Self;
 
             fn rotate_around_centroid(&self, degrees: &f64) -> $type {
-                let centroids = self.centroid();
+                let centroids = pivot_centroids(self);
                 let transforms: Vec<AffineTransform> = centroids
                     .iter_geo_values()
                     .map(|point| AffineTransform::rotate(*degrees, point))
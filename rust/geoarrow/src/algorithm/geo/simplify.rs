@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::algorithm::broadcasting::BroadcastablePrimitive;
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::array::*;
 use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
 use crate::datatypes::{Dimension, NativeType};
@@ -20,6 +21,9 @@ use geo::Simplify as _Simplify;
 /// Multi* objects are simplified by simplifying all their constituent geometries individually.
 ///
 /// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+///
+/// Deviations from the simplified line are measured in the plane. Input that declares spherical
+/// edges is handled according to the [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
 pub trait Simplify {
     type Output;
 
@@ -43,7 +47,7 @@ pub trait Simplify {
     /// ];
     /// let line_string_array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
     ///
-    /// let simplified_array = line_string_array.simplify(&1.0.into());
+    /// let simplified_array = line_string_array.simplify(&1.0.into()).unwrap();
     ///
     /// let expected = line_string![
     ///     (x: 0.0, y: 0.0),
@@ -54,7 +58,17 @@ pub trait Simplify {
     ///
     /// assert_eq!(expected, simplified_array.value_as_geo(0))
     /// ```
-    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output;
+    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+        self.simplify_with_policy(epsilon, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`simplify`][Self::simplify], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn simplify_with_policy(
+        &self,
+        epsilon: &BroadcastablePrimitive<Float64Type>,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output;
 }
 
 /// Implementation that returns the identity
@@ -63,7 +77,12 @@ macro_rules! identity_impl {
         impl Simplify for $type {
             type Output = Self;
 
-            fn simplify(&self, _epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
+            /// Points have no edges, so `policy` is ignored.
+            fn simplify_with_policy(
+                &self,
+                _epsilon: &BroadcastablePrimitive<Float64Type>,
+                _policy: SphericalEdgesPolicy,
+            ) -> Self {
                 self.clone()
             }
         }
@@ -77,9 +96,14 @@ identity_impl!(MultiPointArray);
 macro_rules! iter_geo_impl {
     ($type:ty, $builder_type:ty, $method:ident, $geo_type:ty) => {
         impl Simplify for $type {
-            type Output = Self;
+            type Output = Result<Self>;
 
-            fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
+            fn simplify_with_policy(
+                &self,
+                epsilon: &BroadcastablePrimitive<Float64Type>,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                check_planar_edges("Simplify", &self.metadata(), policy)?;
                 let output_geoms: Vec<Option<$geo_type>> = self
                     .iter_geo()
                     .zip(epsilon)
@@ -92,13 +116,13 @@ macro_rules! iter_geo_impl {
                     })
                     .collect();
 
-                <$builder_type>::$method(
+                Ok(<$builder_type>::$method(
                     output_geoms.as_slice(),
                     Dimension::XY,
                     self.coord_type(),
                     self.metadata.clone(),
                 )
-                .finish()
+                .finish())
             }
         }
     };
@@ -132,7 +156,12 @@ iter_geo_impl!(
 impl Simplify for GeometryArray {
     type Output = Result<Self>;
 
-    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+    fn simplify_with_policy(
+        &self,
+        epsilon: &BroadcastablePrimitive<Float64Type>,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
+        check_planar_edges("Simplify", &self.metadata(), policy)?;
         let output_geoms: Vec<Option<geo::Geometry>> = self
             .iter_geo()
             .zip(epsilon)
@@ -169,17 +198,32 @@ impl Simplify for GeometryArray {
 impl Simplify for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
-    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+    fn simplify_with_policy(
+        &self,
+        epsilon: &BroadcastablePrimitive<Float64Type>,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
         use NativeType::*;
 
         let result: Arc<dyn NativeArray> = match self.data_type() {
-            Point(_, _) => Arc::new(self.as_point().simplify(epsilon)),
-            LineString(_, _) => Arc::new(self.as_line_string().simplify(epsilon)),
-            Polygon(_, _) => Arc::new(self.as_polygon().simplify(epsilon)),
-            MultiPoint(_, _) => Arc::new(self.as_multi_point().simplify(epsilon)),
-            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().simplify(epsilon)),
-            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().simplify(epsilon)),
-            Geometry(_) => Arc::new(self.as_geometry().simplify(epsilon)?),
+            Point(_, _) => Arc::new(self.as_point().simplify_with_policy(epsilon, policy)),
+            LineString(_, _) => Arc::new(
+                self.as_line_string()
+                    .simplify_with_policy(epsilon, policy)?,
+            ),
+            Polygon(_, _) => Arc::new(self.as_polygon().simplify_with_policy(epsilon, policy)?),
+            MultiPoint(_, _) => {
+                Arc::new(self.as_multi_point().simplify_with_policy(epsilon, policy))
+            }
+            MultiLineString(_, _) => Arc::new(
+                self.as_multi_line_string()
+                    .simplify_with_policy(epsilon, policy)?,
+            ),
+            MultiPolygon(_, _) => Arc::new(
+                self.as_multi_polygon()
+                    .simplify_with_policy(epsilon, policy)?,
+            ),
+            Geometry(_) => Arc::new(self.as_geometry().simplify_with_policy(epsilon, policy)?),
             // Mixed(_,_) => self.as_mixed().simplify(epsilon),
             // GeometryCollection(_,_) => self.as_geometry_collection().simplify(),
             _ => return Err(GeoArrowError::IncorrectType("simplify".into())),
@@ -188,26 +232,41 @@ impl Simplify for &dyn NativeArray {
     }
 }
 
-impl Simplify for ChunkedGeometryArray<PointArray> {
-    type Output = Self;
+/// Implementation that returns the identity for each chunk
+macro_rules! chunked_identity_impl {
+    ($type:ty) => {
+        impl Simplify for $type {
+            type Output = Self;
 
-    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
-        self.map(|chunk| chunk.simplify(epsilon))
-            .try_into()
-            .unwrap()
-    }
+            fn simplify_with_policy(
+                &self,
+                epsilon: &BroadcastablePrimitive<Float64Type>,
+                policy: SphericalEdgesPolicy,
+            ) -> Self {
+                self.map(|chunk| chunk.simplify_with_policy(epsilon, policy))
+                    .try_into()
+                    .unwrap()
+            }
+        }
+    };
 }
 
+chunked_identity_impl!(ChunkedGeometryArray<PointArray>);
+chunked_identity_impl!(ChunkedGeometryArray<MultiPointArray>);
+
 /// Implementation that iterates over chunks
 macro_rules! chunked_impl {
     ($type:ty) => {
         impl Simplify for $type {
-            type Output = Self;
+            type Output = Result<Self>;
 
-            fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self {
-                self.map(|chunk| chunk.simplify(epsilon))
+            fn simplify_with_policy(
+                &self,
+                epsilon: &BroadcastablePrimitive<Float64Type>,
+                policy: SphericalEdgesPolicy,
+            ) -> Self::Output {
+                self.try_map(|chunk| chunk.simplify_with_policy(epsilon, policy))?
                     .try_into()
-                    .unwrap()
             }
         }
     };
@@ -215,23 +274,37 @@ macro_rules! chunked_impl {
 
 chunked_impl!(ChunkedGeometryArray<LineStringArray>);
 chunked_impl!(ChunkedGeometryArray<PolygonArray>);
-chunked_impl!(ChunkedGeometryArray<MultiPointArray>);
 chunked_impl!(ChunkedGeometryArray<MultiLineStringArray>);
 chunked_impl!(ChunkedGeometryArray<MultiPolygonArray>);
 
 impl Simplify for &dyn ChunkedNativeArray {
     type Output = Result<Arc<dyn ChunkedNativeArray>>;
 
-    fn simplify(&self, epsilon: &BroadcastablePrimitive<Float64Type>) -> Self::Output {
+    fn simplify_with_policy(
+        &self,
+        epsilon: &BroadcastablePrimitive<Float64Type>,
+        policy: SphericalEdgesPolicy,
+    ) -> Self::Output {
         use NativeType::*;
 
         let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
-            Point(_, _) => Arc::new(self.as_point().simplify(epsilon)),
-            LineString(_, _) => Arc::new(self.as_line_string().simplify(epsilon)),
-            Polygon(_, _) => Arc::new(self.as_polygon().simplify(epsilon)),
-            MultiPoint(_, _) => Arc::new(self.as_multi_point().simplify(epsilon)),
-            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().simplify(epsilon)),
-            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().simplify(epsilon)),
+            Point(_, _) => Arc::new(self.as_point().simplify_with_policy(epsilon, policy)),
+            LineString(_, _) => Arc::new(
+                self.as_line_string()
+                    .simplify_with_policy(epsilon, policy)?,
+            ),
+            Polygon(_, _) => Arc::new(self.as_polygon().simplify_with_policy(epsilon, policy)?),
+            MultiPoint(_, _) => {
+                Arc::new(self.as_multi_point().simplify_with_policy(epsilon, policy))
+            }
+            MultiLineString(_, _) => Arc::new(
+                self.as_multi_line_string()
+                    .simplify_with_policy(epsilon, policy)?,
+            ),
+            MultiPolygon(_, _) => Arc::new(
+                self.as_multi_polygon()
+                    .simplify_with_policy(epsilon, policy)?,
+            ),
             // Mixed(_,_) => self.as_mixed().simplify(epsilon),
             // GeometryCollection(_,_) => self.as_geometry_collection().simplify(),
             _ => return Err(GeoArrowError::IncorrectType("".into())),
@@ -257,7 +330,9 @@ mod tests {
             (x: 27.8, y: 0.1 ),
         ];
         let input_array: LineStringArray = (vec![input_geom].as_slice(), Dimension::XY).into();
-        let result_array = input_array.simplify(&BroadcastablePrimitive::Scalar(1.0));
+        let result_array = input_array
+            .simplify(&BroadcastablePrimitive::Scalar(1.0))
+            .unwrap();
 
         let expected = line_string![
             ( x: 0.0, y: 0.0 ),
@@ -280,7 +355,9 @@ mod tests {
             (x: 0., y: 0.),
         ];
         let input_array: PolygonArray = (vec![input_geom].as_slice(), Dimension::XY).into();
-        let result_array = input_array.simplify(&BroadcastablePrimitive::Scalar(2.0));
+        let result_array = input_array
+            .simplify(&BroadcastablePrimitive::Scalar(2.0))
+            .unwrap();
 
         let expected = polygon![
             (x: 0., y: 0.),
@@ -134,7 +134,12 @@ impl Skew for PointArray {
         x_factor: &BroadcastablePrimitive<Float64Type>,
         y_factor: &BroadcastablePrimitive<Float64Type>,
     ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
+        let mut output_array = PointBuilder::with_capacity_and_options(
+            Dimension::XY,
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
 
         self.iter_geo()
             .zip(x_factor)
@@ -156,7 +161,12 @@ impl Skew for PointArray {
         y_factor: &BroadcastablePrimitive<Float64Type>,
         origin: geo::Point,
     ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
+        let mut output_array = PointBuilder::with_capacity_and_options(
+            Dimension::XY,
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
 
         self.iter_geo()
             .zip(x_factor)
@@ -186,8 +196,12 @@ macro_rules! iter_geo_impl {
                 x_factor: &BroadcastablePrimitive<Float64Type>,
                 y_factor: &BroadcastablePrimitive<Float64Type>,
             ) -> Self {
-                let mut output_array =
-                    <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
+                let mut output_array = <$builder_type>::with_capacity_and_options(
+                    Dimension::XY,
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
 
                 self.iter_geo().zip(x_factor).zip(y_factor).for_each(
                     |((maybe_g, x_factor), y_factor)| {
@@ -210,8 +224,12 @@ macro_rules! iter_geo_impl {
                 y_factor: &BroadcastablePrimitive<Float64Type>,
                 origin: geo::Point,
             ) -> Self {
-                let mut output_array =
-                    <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
+                let mut output_array = <$builder_type>::with_capacity_and_options(
+                    Dimension::XY,
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
 
                 self.iter_geo().zip(x_factor).zip(y_factor).for_each(
                     |((maybe_g, x_factor), y_factor)| {
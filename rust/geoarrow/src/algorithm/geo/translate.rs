@@ -60,7 +60,12 @@ impl Translate for PointArray {
         x_offset: &BroadcastablePrimitive<Float64Type>,
         y_offset: &BroadcastablePrimitive<Float64Type>,
     ) -> Self {
-        let mut output_array = PointBuilder::with_capacity(Dimension::XY, self.buffer_lengths());
+        let mut output_array = PointBuilder::with_capacity_and_options(
+            Dimension::XY,
+            self.buffer_lengths(),
+            self.coord_type(),
+            self.metadata(),
+        );
 
         self.iter_geo()
             .zip(x_offset)
@@ -88,8 +93,12 @@ macro_rules! iter_geo_impl {
                 x_offset: &BroadcastablePrimitive<Float64Type>,
                 y_offset: &BroadcastablePrimitive<Float64Type>,
             ) -> Self {
-                let mut output_array =
-                    <$builder_type>::with_capacity(Dimension::XY, self.buffer_lengths());
+                let mut output_array = <$builder_type>::with_capacity_and_options(
+                    Dimension::XY,
+                    self.buffer_lengths(),
+                    self.coord_type(),
+                    self.metadata(),
+                );
 
                 self.iter_geo().zip(x_offset).zip(y_offset).for_each(
                     |((maybe_g, x_offset), y_offset)| {
@@ -1,7 +1,10 @@
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
 use crate::array::*;
+use crate::error::Result;
 use crate::scalar::*;
 use crate::trait_::ArrayAccessor;
 use crate::trait_::NativeScalar;
+use crate::ArrayBase;
 use arrow_array::builder::BooleanBuilder;
 use arrow_array::BooleanArray;
 use geo::Within as _Within;
@@ -40,8 +43,21 @@ use geo::Within as _Within;
 /// ```
 ///
 /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+///
+/// This is a planar operation. Input whose metadata declares spherical edges is handled according
+/// to the [`SphericalEdgesPolicy`][super::SphericalEdgesPolicy].
 pub trait Within<Other = Self> {
-    fn is_within(&self, b: &Other) -> BooleanArray;
+    fn is_within(&self, b: &Other) -> Result<BooleanArray> {
+        self.is_within_with_policy(b, SphericalEdgesPolicy::Error)
+    }
+
+    /// Like [`is_within`][Self::is_within], but handles input with spherical edges according to
+    /// `policy` instead of returning an error.
+    fn is_within_with_policy(
+        &self,
+        b: &Other,
+        policy: SphericalEdgesPolicy,
+    ) -> Result<BooleanArray>;
 }
 
 // ┌────────────────────────────────┐
@@ -50,7 +66,13 @@ pub trait Within<Other = Self> {
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl Within for PointArray {
-    fn is_within(&self, rhs: &Self) -> BooleanArray {
+    fn is_within_with_policy(
+        &self,
+        rhs: &Self,
+        policy: SphericalEdgesPolicy,
+    ) -> Result<BooleanArray> {
+        check_planar_edges("Within", &self.metadata(), policy)?;
+        check_planar_edges("Within", &rhs.metadata(), policy)?;
         assert_eq!(self.len(), rhs.len());
 
         let mut output_array = BooleanBuilder::with_capacity(self.len());
@@ -62,7 +84,7 @@ impl Within for PointArray {
                 _ => output_array.append_null(),
             });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -70,7 +92,13 @@ impl Within for PointArray {
 macro_rules! iter_geo_impl {
     ($first:ty, $second:ty) => {
         impl<'a> Within<$second> for $first {
-            fn is_within(&self, rhs: &$second) -> BooleanArray {
+            fn is_within_with_policy(
+                &self,
+                rhs: &$second,
+                policy: SphericalEdgesPolicy,
+            ) -> Result<BooleanArray> {
+                check_planar_edges("Within", &self.metadata(), policy)?;
+                check_planar_edges("Within", &rhs.metadata(), policy)?;
                 assert_eq!(self.len(), rhs.len());
 
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
@@ -84,7 +112,7 @@ macro_rules! iter_geo_impl {
                         _ => output_array.append_null(),
                     });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -143,7 +171,12 @@ iter_geo_impl!(MultiPolygonArray, MultiPolygonArray);
 
 // Note: this implementation is outside the macro because it is not generic over O
 impl<'a> Within<Point<'a>> for PointArray {
-    fn is_within(&self, rhs: &Point<'a>) -> BooleanArray {
+    fn is_within_with_policy(
+        &self,
+        rhs: &Point<'a>,
+        policy: SphericalEdgesPolicy,
+    ) -> Result<BooleanArray> {
+        check_planar_edges("Within", &self.metadata(), policy)?;
         let mut output_array = BooleanBuilder::with_capacity(self.len());
 
         self.iter_geo().for_each(|maybe_point| {
@@ -151,7 +184,7 @@ impl<'a> Within<Point<'a>> for PointArray {
             output_array.append_option(output)
         });
 
-        output_array.finish()
+        Ok(output_array.finish())
     }
 }
 
@@ -159,7 +192,12 @@ impl<'a> Within<Point<'a>> for PointArray {
 macro_rules! iter_geo_impl_geoarrow_scalar {
     ($first:ty, $second:ty) => {
         impl<'a> Within<$second> for $first {
-            fn is_within(&self, rhs: &$second) -> BooleanArray {
+            fn is_within_with_policy(
+                &self,
+                rhs: &$second,
+                policy: SphericalEdgesPolicy,
+            ) -> Result<BooleanArray> {
+                check_planar_edges("Within", &self.metadata(), policy)?;
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
                 let rhs_geo = rhs.to_geo();
 
@@ -168,7 +206,7 @@ macro_rules! iter_geo_impl_geoarrow_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -228,7 +266,12 @@ iter_geo_impl_geoarrow_scalar!(MultiPolygonArray, MultiPolygon<'a>);
 macro_rules! non_generic_iter_geo_impl_geo_scalar {
     ($first:ty, $second:ty) => {
         impl<'a> Within<$second> for $first {
-            fn is_within(&self, rhs: &$second) -> BooleanArray {
+            fn is_within_with_policy(
+                &self,
+                rhs: &$second,
+                policy: SphericalEdgesPolicy,
+            ) -> Result<BooleanArray> {
+                check_planar_edges("Within", &self.metadata(), policy)?;
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
 
                 self.iter_geo().for_each(|maybe_geom| {
@@ -236,7 +279,7 @@ macro_rules! non_generic_iter_geo_impl_geo_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -253,7 +296,12 @@ non_generic_iter_geo_impl_geo_scalar!(PointArray, geo::MultiPolygon);
 macro_rules! iter_geo_impl_geo_scalar {
     ($first:ty, $second:ty) => {
         impl<'a> Within<$second> for $first {
-            fn is_within(&self, rhs: &$second) -> BooleanArray {
+            fn is_within_with_policy(
+                &self,
+                rhs: &$second,
+                policy: SphericalEdgesPolicy,
+            ) -> Result<BooleanArray> {
+                check_planar_edges("Within", &self.metadata(), policy)?;
                 let mut output_array = BooleanBuilder::with_capacity(self.len());
 
                 self.iter_geo().for_each(|maybe_geom| {
@@ -261,7 +309,7 @@ macro_rules! iter_geo_impl_geo_scalar {
                     output_array.append_option(output)
                 });
 
-                output_array.finish()
+                Ok(output_array.finish())
             }
         }
     };
@@ -1,3 +1,10 @@
+mod nearest;
+mod overlay;
 pub mod rtree;
+mod spatial_join;
 
+pub use nearest::{nearest, sjoin_nearest, DistanceMetric, NearestOptions};
+pub use overlay::{overlay, OverlayType};
 pub use rtree::RTree;
+pub(crate) use spatial_join::geo_geometries;
+pub use spatial_join::{spatial_join, SpatialJoinPredicate, SpatialJoinType};
@@ -0,0 +1,597 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, UInt32Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use geo::{BoundingRect, Closest, Distance, Euclidean, Haversine, HaversineClosestPoint};
+use geo_index::kdtree::{KDTreeBuilder, KDTreeIndex, OwnedKDTree};
+use geo_index::rtree::{OwnedRTree, RTreeIndex};
+use geo_traits::{CoordTrait, PointTrait};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::spatial_join::{build_rtree, check_tables_crs, geo_geometries, take_table};
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::Table;
+use crate::NativeArray;
+
+/// The mean radius of the Earth in meters, as used by [`Haversine`].
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// How the distance between two geometries is measured in nearest-neighbor queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DistanceMetric {
+    /// Euclidean distance, in the units of the coordinates.
+    #[default]
+    Euclidean,
+
+    /// Great-circle distance in meters, with coordinates interpreted as longitude and latitude
+    /// in degrees. At least one of each pair of geometries must be a point.
+    Haversine,
+}
+
+impl DistanceMetric {
+    pub(crate) fn distance(&self, a: &geo::Geometry, b: &geo::Geometry) -> Result<Option<f64>> {
+        if a.bounding_rect().is_none() || b.bounding_rect().is_none() {
+            return Ok(None);
+        }
+
+        match self {
+            Self::Euclidean => Ok(Some(Euclidean::distance(a, b))),
+            Self::Haversine => {
+                let (geom, point) = match (a, b) {
+                    (_, geo::Geometry::Point(point)) => (a, point),
+                    (geo::Geometry::Point(point), _) => (b, point),
+                    _ => {
+                        return Err(GeoArrowError::NotYetImplemented(
+                            "Haversine distance between two non-point geometries".to_string(),
+                        ))
+                    }
+                };
+                match geom.haversine_closest_point(point) {
+                    Closest::Intersection(_) => Ok(Some(0.)),
+                    Closest::SinglePoint(closest) => Ok(Some(Haversine::distance(closest, *point))),
+                    Closest::Indeterminate => Ok(None),
+                }
+            }
+        }
+    }
+
+    /// A box that contains the bounding box of every geometry within `distance` of a geometry
+    /// with bounding box `rect`.
+    fn search_box(&self, rect: &geo::Rect, distance: f64) -> [f64; 4] {
+        match self {
+            Self::Euclidean => [
+                rect.min().x - distance,
+                rect.min().y - distance,
+                rect.max().x + distance,
+                rect.max().y + distance,
+            ],
+            Self::Haversine => {
+                let angle = distance / EARTH_RADIUS;
+                let dlat = angle.to_degrees();
+                let min_y = rect.min().y - dlat;
+                let max_y = rect.max().y + dlat;
+                let full_width = [-180., min_y.max(-90.), 180., max_y.min(90.)];
+                if min_y <= -90. || max_y >= 90. {
+                    return full_width;
+                }
+
+                // The longitude span of a circle is widest at the latitude furthest from the
+                // equator.
+                let lat = rect.min().y.abs().max(rect.max().y.abs()).to_radians();
+                let dlon = (angle.sin() / lat.cos()).asin().to_degrees();
+                let min_x = rect.min().x - dlon;
+                let max_x = rect.max().x + dlon;
+                if dlon.is_nan() || min_x < -180. || max_x > 180. {
+                    return full_width;
+                }
+                [min_x, min_y, max_x, max_y]
+            }
+        }
+    }
+
+    /// A first guess of the search distance for `k` neighbors among `num_items` geometries that
+    /// cover `extent`.
+    fn initial_distance(&self, extent: [f64; 4], num_items: usize, k: usize) -> f64 {
+        let size = (extent[2] - extent[0]).max(extent[3] - extent[1]);
+        let distance = size * (k as f64 / num_items as f64).sqrt();
+        let distance = match self {
+            Self::Euclidean => distance,
+            Self::Haversine => distance.to_radians() * EARTH_RADIUS,
+        };
+        if distance.is_finite() && distance > 0. {
+            distance
+        } else {
+            1.
+        }
+    }
+}
+
+/// Options for nearest-neighbor queries.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NearestOptions {
+    /// Geometries further away than this are never returned.
+    pub max_distance: Option<f64>,
+
+    /// How distances are measured.
+    pub metric: DistanceMetric,
+
+    /// What to do with input that declares spherical edges when `metric` is
+    /// [`DistanceMetric::Euclidean`]. Haversine distances are measured on the sphere already.
+    pub spherical_edges: SphericalEdgesPolicy,
+}
+
+impl NearestOptions {
+    fn validate(&self) -> Result<()> {
+        if let Some(max_distance) = self.max_distance {
+            if max_distance.is_nan() || max_distance < 0. {
+                return Err(GeoArrowError::General(format!(
+                    "max_distance must be non-negative, got {max_distance}"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Find the `k` items of a spatial index closest to `query`, as `(item, distance)` pairs ordered
+/// by distance and then by item.
+///
+/// The search box around `query` starts at a size estimated from `extent` and doubles until it
+/// holds `k` items within the searched distance, every item has been seen, or it reaches
+/// `max_distance`. `search` returns the items whose bounding boxes intersect a box and `distance`
+/// returns the distance from `query` to an item, or `None` for empty geometries.
+fn k_nearest(
+    query: &geo::Geometry,
+    k: usize,
+    options: &NearestOptions,
+    num_items: usize,
+    extent: [f64; 4],
+    search: impl Fn([f64; 4]) -> Vec<usize>,
+    distance: impl Fn(usize) -> Result<Option<f64>>,
+) -> Result<Vec<(usize, f64)>> {
+    options.validate()?;
+    let Some(rect) = query.bounding_rect() else {
+        return Ok(vec![]);
+    };
+    if k == 0 || num_items == 0 {
+        return Ok(vec![]);
+    }
+
+    let max_distance = options.max_distance.unwrap_or(f64::INFINITY);
+    let mut search_distance = options
+        .metric
+        .initial_distance(extent, num_items, k)
+        .min(max_distance);
+    loop {
+        let candidates = search(options.metric.search_box(&rect, search_distance));
+        let exhausted = candidates.len() >= num_items || search_distance >= max_distance;
+        // Items outside the search box may be closer than candidates beyond the searched
+        // distance, so those candidates only count once nothing is left outside.
+        let limit = if exhausted {
+            max_distance
+        } else {
+            search_distance
+        };
+
+        let mut found = vec![];
+        for item in candidates {
+            if let Some(d) = distance(item)? {
+                if d <= limit {
+                    found.push((item, d));
+                }
+            }
+        }
+
+        if exhausted || found.len() >= k {
+            found.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            found.truncate(k);
+            return Ok(found);
+        }
+        search_distance = (search_distance * 2.).min(max_distance);
+    }
+}
+
+/// The spatial index of one chunk: a KD-tree for points and an R-tree for everything else.
+enum NearestIndex {
+    KDTree(OwnedKDTree<f64>),
+    RTree(OwnedRTree<f64>),
+}
+
+impl NearestIndex {
+    fn search(&self, [min_x, min_y, max_x, max_y]: [f64; 4]) -> Vec<usize> {
+        match self {
+            Self::KDTree(tree) => tree.as_ref().range(min_x, min_y, max_x, max_y),
+            Self::RTree(tree) => tree.search(min_x, min_y, max_x, max_y),
+        }
+    }
+}
+
+/// One chunk of the right geometry column of [`sjoin_nearest`].
+struct NearestChunk {
+    /// Position of the first row of this chunk in the whole column.
+    offset: usize,
+    geometries: Vec<Option<geo::Geometry>>,
+    /// `None` if the chunk has no non-null, non-empty geometries.
+    index: Option<NearestIndex>,
+    /// Row within the chunk of each item inserted in `index`, in insertion order.
+    index_rows: Vec<usize>,
+    /// Bounding box of all indexed geometries.
+    extent: [f64; 4],
+}
+
+impl NearestChunk {
+    fn try_new(
+        array: &dyn NativeArray,
+        offset: usize,
+        options: &NearestOptions,
+        op: &str,
+    ) -> Result<Self> {
+        if options.metric == DistanceMetric::Euclidean {
+            check_planar_edges(op, &array.metadata(), options.spherical_edges)?;
+        }
+
+        let geometries = geo_geometries(array);
+        let (index_rows, boxes): (Vec<_>, Vec<_>) = geometries
+            .iter()
+            .enumerate()
+            .filter_map(|(row, geom)| Some((row, geom.as_ref()?.bounding_rect()?)))
+            .unzip();
+
+        let mut extent = [
+            f64::INFINITY,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+        ];
+        for rect in boxes.iter() {
+            extent[0] = extent[0].min(rect.min().x);
+            extent[1] = extent[1].min(rect.min().y);
+            extent[2] = extent[2].max(rect.max().x);
+            extent[3] = extent[3].max(rect.max().y);
+        }
+
+        let index = (!boxes.is_empty()).then(|| {
+            if matches!(array.data_type(), NativeType::Point(_, _)) {
+                let mut builder = KDTreeBuilder::<f64>::new(boxes.len());
+                for rect in boxes {
+                    builder.add(rect.min().x, rect.min().y);
+                }
+                NearestIndex::KDTree(builder.finish())
+            } else {
+                let boxes = boxes
+                    .iter()
+                    .map(|rect| [rect.min().x, rect.min().y, rect.max().x, rect.max().y])
+                    .collect::<Vec<_>>();
+                NearestIndex::RTree(build_rtree(&boxes))
+            }
+        });
+
+        Ok(Self {
+            offset,
+            geometries,
+            index,
+            index_rows,
+            extent,
+        })
+    }
+
+    /// The `k` rows of this chunk, relative to the whole column, closest to `query`.
+    fn nearest(
+        &self,
+        query: &geo::Geometry,
+        k: usize,
+        options: &NearestOptions,
+    ) -> Result<Vec<(u32, f64)>> {
+        let Some(index) = &self.index else {
+            return Ok(vec![]);
+        };
+
+        let found = k_nearest(
+            query,
+            k,
+            options,
+            self.index_rows.len(),
+            self.extent,
+            |search_box| index.search(search_box),
+            |item| match &self.geometries[self.index_rows[item]] {
+                Some(geom) => options.metric.distance(query, geom),
+                None => Ok(None),
+            },
+        )?;
+        Ok(found
+            .into_iter()
+            .map(|(item, d)| ((self.offset + self.index_rows[item]) as u32, d))
+            .collect())
+    }
+}
+
+/// Find the `k` geometries of `array` closest to `point`, as `(row, distance)` pairs ordered by
+/// distance and then by row.
+///
+/// `array` is indexed the same way as each chunk of the right table in [`sjoin_nearest`], which is
+/// the better choice for querying many points at once. Null and empty geometries are never
+/// returned.
+pub fn nearest(
+    array: &dyn NativeArray,
+    k: usize,
+    point: &impl PointTrait<T = f64>,
+    options: &NearestOptions,
+) -> Result<Vec<(usize, f64)>> {
+    options.validate()?;
+    let Some(coord) = point.coord() else {
+        return Ok(vec![]);
+    };
+    let query = geo::Geometry::Point(geo::Point::new(coord.x(), coord.y()));
+
+    let chunk = NearestChunk::try_new(array, 0, options, "nearest")?;
+    Ok(chunk
+        .nearest(&query, k, options)?
+        .into_iter()
+        .map(|(row, d)| (row as usize, d))
+        .collect())
+}
+
+/// For every row of `left`, join the `k` nearest rows of `right`.
+///
+/// The join is computed on the default geometry column of each table. Every chunk of `right` is
+/// indexed in a KD-tree if it holds points, or in an R-tree otherwise, and searched in boxes of
+/// increasing size around each left geometry until its `k` nearest neighbors are known. With the
+/// `rayon` feature, left chunks are processed in parallel.
+///
+/// The output has all columns of `left` followed by all columns of `right` and a `distance`
+/// column. Column names that exist on both sides get a `_left` or `_right` suffix. Neighbors of
+/// each left row are ordered by distance, with ties broken by right row. Left rows without any
+/// neighbor within [`NearestOptions::max_distance`], including null and empty geometries, are
+/// kept once with null right columns and a null distance.
+///
+/// Euclidean distances between geometries with spherical edges would measure chords instead of
+/// arcs, so such input is handled according to [`NearestOptions::spherical_edges`]. Use
+/// [`DistanceMetric::Haversine`] to search longitude/latitude data on the sphere instead.
+pub fn sjoin_nearest(
+    left: &Table,
+    right: &Table,
+    k: usize,
+    options: &NearestOptions,
+) -> Result<Table> {
+    options.validate()?;
+    check_tables_crs(left, right, "sjoin_nearest")?;
+
+    let right_chunks = {
+        let chunks = right.geometry_column(None)?.geometry_chunks();
+        let mut offset = 0;
+        chunks
+            .iter()
+            .map(|chunk| {
+                let indexed =
+                    NearestChunk::try_new(chunk.as_ref(), offset, options, "sjoin_nearest");
+                offset += chunk.len();
+                indexed
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    let left_chunks = left.geometry_column(None)?.geometry_chunks();
+    let mut left_offsets = Vec::with_capacity(left_chunks.len());
+    let mut offset = 0;
+    for chunk in left_chunks.iter() {
+        if options.metric == DistanceMetric::Euclidean {
+            check_planar_edges("sjoin_nearest", &chunk.metadata(), options.spherical_edges)?;
+        }
+        left_offsets.push(offset);
+        offset += chunk.len();
+    }
+
+    let nearest_rows = |(chunk, offset): (&Arc<dyn NativeArray>, &usize)| {
+        let mut rows = vec![];
+        for (row, geom) in geo_geometries(chunk.as_ref()).iter().enumerate() {
+            let left_row = (offset + row) as u32;
+            let mut neighbors = vec![];
+            if let Some(geom) = geom {
+                for right_chunk in right_chunks.iter() {
+                    neighbors.extend(right_chunk.nearest(geom, k, options)?);
+                }
+            }
+            if neighbors.is_empty() {
+                rows.push((left_row, None, None));
+                continue;
+            }
+
+            neighbors.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+            neighbors.truncate(k);
+            rows.extend(
+                neighbors
+                    .into_iter()
+                    .map(|(right_row, d)| (left_row, Some(right_row), Some(d))),
+            );
+        }
+        Ok::<_, GeoArrowError>(rows)
+    };
+
+    #[cfg(feature = "rayon")]
+    let rows = left_chunks
+        .par_iter()
+        .zip(left_offsets.par_iter())
+        .map(nearest_rows)
+        .collect::<Result<Vec<_>>>()?;
+    #[cfg(not(feature = "rayon"))]
+    let rows = left_chunks
+        .iter()
+        .zip(left_offsets.iter())
+        .map(nearest_rows)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut left_indices = vec![];
+    let mut right_indices = vec![];
+    let mut distances = vec![];
+    for (left_row, right_row, distance) in rows.into_iter().flatten() {
+        left_indices.push(left_row);
+        right_indices.push(right_row);
+        distances.push(distance);
+    }
+    let left_indices = UInt32Array::from(left_indices);
+    let right_indices = UInt32Array::from(right_indices);
+    let distances: ArrayRef = Arc::new(Float64Array::from(distances));
+
+    let left_names = left
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<HashSet<_>>();
+    let right_names = right
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<HashSet<_>>();
+
+    let (mut fields, mut columns) = take_table(left, &left_indices, |name| {
+        if right_names.contains(name) {
+            format!("{name}_left")
+        } else {
+            name.to_string()
+        }
+    })?;
+    let (right_fields, right_columns) = take_table(right, &right_indices, |name| {
+        if left_names.contains(name) {
+            format!("{name}_right")
+        } else {
+            name.to_string()
+        }
+    })?;
+    fields.extend(right_fields);
+    columns.extend(right_columns);
+    fields.push(Field::new(
+        "distance",
+        DataType::Float64,
+        distances.null_count() > 0,
+    ));
+    columns.push(distances);
+
+    let schema: SchemaRef = Arc::new(Schema::new_with_metadata(
+        fields,
+        left.schema().metadata().clone(),
+    ));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    Table::try_new(vec![batch], schema)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{Array, Int32Array};
+
+    use super::*;
+    use crate::array::{LineStringArray, PointArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::test::table::id_table;
+
+    fn points(coords: &[(f64, f64)]) -> PointArray {
+        let points = coords
+            .iter()
+            .map(|(x, y)| geo::point!(x: *x, y: *y))
+            .collect::<Vec<_>>();
+        (points.as_slice(), Dimension::XY).into()
+    }
+
+    fn column<T: Array + Clone + 'static>(table: &Table, name: &str) -> T {
+        let batch = &table.batches()[0];
+        let index = table.schema().index_of(name).unwrap();
+        batch
+            .column(index)
+            .as_any()
+            .downcast_ref::<T>()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn nearest_to_point() {
+        let array = points(&[(0., 0.), (10., 0.), (3., 0.), (4., 0.), (100., 100.)]);
+        let query = geo::point!(x: 3.4, y: 0.);
+
+        let found = nearest(&array, 2, &query, &NearestOptions::default()).unwrap();
+        assert_eq!(found.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [2, 3]);
+        assert!((found[0].1 - 0.4).abs() < 1e-9);
+
+        let options = NearestOptions {
+            max_distance: Some(1.),
+            ..Default::default()
+        };
+        let found = nearest(&array, 10, &query, &options).unwrap();
+        assert_eq!(found.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [2, 3]);
+    }
+
+    #[test]
+    fn nearest_in_single_polygon() {
+        let square = vec![geo::Rect::new((0., 0.), (2., 2.)).to_polygon()];
+        let array: PolygonArray = (square.as_slice(), Dimension::XY).into();
+        let query = geo::point!(x: 5., y: 1.);
+
+        let found = nearest(&array, 1, &query, &NearestOptions::default()).unwrap();
+        assert_eq!(found, [(0, 3.)]);
+    }
+
+    #[test]
+    fn nearest_join_points() {
+        let left = id_table(vec![1, 2], Arc::new(points(&[(0., 0.), (50., 50.)])));
+        let right = id_table(
+            vec![10, 20, 30],
+            Arc::new(points(&[(1., 0.), (0., 3.), (48., 50.)])),
+        );
+
+        let joined = sjoin_nearest(&left, &right, 2, &NearestOptions::default()).unwrap();
+        assert_eq!(joined.num_columns(), 5);
+        let left_ids = column::<Int32Array>(&joined, "id_left");
+        let right_ids = column::<Int32Array>(&joined, "id_right");
+        let distances = column::<Float64Array>(&joined, "distance");
+        assert_eq!(left_ids.values(), &[1, 1, 2, 2]);
+        assert_eq!(right_ids.values(), &[10, 20, 30, 20]);
+        assert_eq!(distances.value(0), 1.);
+        assert_eq!(distances.value(2), 2.);
+
+        let options = NearestOptions {
+            max_distance: Some(2.),
+            ..Default::default()
+        };
+        let joined = sjoin_nearest(&left, &right, 2, &options).unwrap();
+        let right_ids = column::<Int32Array>(&joined, "id_right");
+        assert_eq!(right_ids.iter().collect::<Vec<_>>(), [Some(10), Some(30)]);
+    }
+
+    #[test]
+    fn nearest_join_lines_and_haversine() {
+        let lines: LineStringArray = (
+            vec![
+                geo::LineString::from(vec![(0., 1.), (10., 1.)]),
+                geo::LineString::from(vec![(0., -5.), (10., -5.)]),
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let left = id_table(vec![1], Arc::new(points(&[(5., 0.)])));
+        let right = id_table(vec![10, 20], Arc::new(lines));
+
+        let joined = sjoin_nearest(&left, &right, 1, &NearestOptions::default()).unwrap();
+        assert_eq!(column::<Int32Array>(&joined, "id_right").values(), &[10]);
+        assert_eq!(column::<Float64Array>(&joined, "distance").value(0), 1.);
+
+        let options = NearestOptions {
+            metric: DistanceMetric::Haversine,
+            ..Default::default()
+        };
+        let left = id_table(vec![1], Arc::new(points(&[(0., 0.)])));
+        let right = id_table(vec![10, 20], Arc::new(points(&[(0., 1.), (179., 0.)])));
+        let joined = sjoin_nearest(&left, &right, 2, &options).unwrap();
+        assert_eq!(
+            column::<Int32Array>(&joined, "id_right").values(),
+            &[10, 20]
+        );
+        let distances = column::<Float64Array>(&joined, "distance");
+        assert!((distances.value(0) - 111_195.).abs() < 1.);
+    }
+}
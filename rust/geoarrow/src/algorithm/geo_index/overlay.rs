@@ -0,0 +1,419 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, RecordBatch, UInt32Array};
+use arrow_schema::{Field, Schema, SchemaRef};
+use geo::BooleanOps;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::spatial_join::{geo_geometries, matching_pairs, take_table, SpatialJoinPredicate};
+use crate::algorithm::geo::{finish_multi_polygons, to_multi_polygon, SphericalEdgesPolicy};
+use crate::array::MultiPolygonBuilder;
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::table::Table;
+use crate::trait_::NativeArrayRef;
+use crate::ArrayBase;
+
+/// Which pieces of the two layers are kept by [`overlay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverlayType {
+    /// The intersections of each pair of left and right geometries.
+    #[default]
+    Intersection,
+
+    /// The intersections, plus the parts of each left geometry and of each right geometry that
+    /// are not covered by the other layer.
+    Union,
+
+    /// The intersections, plus the parts of each left geometry that are not covered by the right
+    /// layer.
+    Identity,
+
+    /// The parts of each left geometry and of each right geometry that are not covered by the
+    /// other layer.
+    SymmetricDifference,
+
+    /// The parts of each left geometry that are not covered by the right layer. Only the left
+    /// attributes are kept.
+    Difference,
+}
+
+/// The chunks of the default geometry column of `table`, and its rows as one `MultiPolygon` each.
+fn multi_polygons(table: &Table) -> Result<(Vec<NativeArrayRef>, Vec<Option<geo::MultiPolygon>>)> {
+    let chunks = table.geometry_column(None)?.geometry_chunks();
+    let geometries = chunks
+        .iter()
+        .flat_map(|chunk| geo_geometries(chunk.as_ref()))
+        .map(|geom| geom.map(to_multi_polygon).transpose())
+        .collect::<Result<Vec<_>>>()?;
+    Ok((chunks, geometries))
+}
+
+/// For each row of `geometries`, the part that is not covered by the geometries of `others` at
+/// the rows listed in `partners`.
+fn remainders(
+    geometries: &[Option<geo::MultiPolygon>],
+    others: &[Option<geo::MultiPolygon>],
+    partners: &[Vec<u32>],
+) -> Vec<(u32, geo::MultiPolygon)> {
+    let rows = (0..geometries.len()).collect::<Vec<_>>();
+
+    #[cfg(feature = "rayon")]
+    let rows = rows.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let rows = rows.iter();
+
+    rows.filter_map(|row| {
+        let geometry = geometries[*row].as_ref()?;
+        let remainder = partners[*row]
+            .iter()
+            .filter_map(|other| others[*other as usize].as_ref())
+            .fold(geometry.clone(), |remainder, other| {
+                remainder.difference(other)
+            });
+        Some((*row as u32, remainder))
+    })
+    .collect()
+}
+
+/// Rows of `table`, except for its default geometry column, at `indices`.
+fn take_attributes(
+    table: &Table,
+    indices: Vec<Option<u32>>,
+    rename: impl Fn(&str) -> String,
+) -> Result<(Vec<Field>, Vec<ArrayRef>)> {
+    let geometry_index = table.default_geometry_column_idx()?;
+    let (mut fields, mut columns) = take_table(table, &UInt32Array::from(indices), rename)?;
+    fields.remove(geometry_index);
+    columns.remove(geometry_index);
+    Ok((fields, columns))
+}
+
+/// The names of all columns of `table` except its default geometry column.
+fn attribute_names(table: &Table) -> Result<HashSet<String>> {
+    let geometry_index = table.default_geometry_column_idx()?;
+    Ok(table
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != geometry_index)
+        .map(|(_, f)| f.name().clone())
+        .collect())
+}
+
+/// Split the polygons of two tables into the pieces formed by their overlaps, as in
+/// geopandas' `overlay`.
+///
+/// The overlay is computed on the default geometry column of each table, which must hold
+/// polygonal geometries. Pairs of geometries that may overlap are found with an R-tree, and the
+/// pieces are computed with [`geo::BooleanOps`]. Empty pieces, such as the intersection of
+/// polygons that only touch, are dropped, and null geometries do not take part.
+///
+/// Each output row is one piece, with the attributes of the left and right rows it comes from.
+/// Attributes of a side that does not contribute to a piece are null. Intersections come first,
+/// ordered by left and then right row, followed by the left-only pieces and then the right-only
+/// pieces, each ordered by row. The output has all non-geometry columns of `left` followed by
+/// those of `right`, or only those of `left` for [`OverlayType::Difference`], and then a
+/// `MultiPolygon` geometry column named after the left one. Column names that exist on both sides
+/// get a `_left` or `_right` suffix.
+///
+/// The geometry column has the dimension of the left one. Vertices of a piece that are also
+/// vertices of an input keep their Z and M values, while vertices created by the overlay get NaN.
+///
+/// Pieces are cut along straight segments between vertices. If either side declares spherical
+/// edges, `policy` decides whether the overlay fails or goes ahead with a warning, treating the
+/// edges as planar.
+pub fn overlay(
+    left: &Table,
+    right: &Table,
+    how: OverlayType,
+    policy: SphericalEdgesPolicy,
+) -> Result<Table> {
+    let (left_chunks, left_geometries) = multi_polygons(left)?;
+    let (right_chunks, right_geometries) = multi_polygons(right)?;
+
+    let mut pairs = matching_pairs(
+        left,
+        right,
+        SpatialJoinPredicate::Intersects,
+        "overlay",
+        policy,
+    )?;
+    pairs.sort_unstable();
+
+    let mut pieces: Vec<(Option<u32>, Option<u32>, geo::MultiPolygon)> = vec![];
+
+    if matches!(
+        how,
+        OverlayType::Intersection | OverlayType::Union | OverlayType::Identity
+    ) {
+        #[cfg(feature = "rayon")]
+        let pair_iter = pairs.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let pair_iter = pairs.iter();
+
+        let intersections = pair_iter
+            .filter_map(|(l, r)| {
+                let left_geometry = left_geometries[*l as usize].as_ref()?;
+                let right_geometry = right_geometries[*r as usize].as_ref()?;
+                Some((
+                    Some(*l),
+                    Some(*r),
+                    left_geometry.intersection(right_geometry),
+                ))
+            })
+            .collect::<Vec<_>>();
+        pieces.extend(intersections);
+    }
+
+    if how != OverlayType::Intersection {
+        let mut partners = vec![vec![]; left_geometries.len()];
+        for (l, r) in pairs.iter() {
+            partners[*l as usize].push(*r);
+        }
+        pieces.extend(
+            remainders(&left_geometries, &right_geometries, &partners)
+                .into_iter()
+                .map(|(l, geometry)| (Some(l), None, geometry)),
+        );
+    }
+
+    if matches!(how, OverlayType::Union | OverlayType::SymmetricDifference) {
+        let mut partners = vec![vec![]; right_geometries.len()];
+        for (l, r) in pairs.iter() {
+            partners[*r as usize].push(*l);
+        }
+        pieces.extend(
+            remainders(&right_geometries, &left_geometries, &partners)
+                .into_iter()
+                .map(|(r, geometry)| (None, Some(r), geometry)),
+        );
+    }
+
+    pieces.retain(|(_, _, geometry)| !geometry.0.is_empty());
+
+    let mut left_indices = Vec::with_capacity(pieces.len());
+    let mut right_indices = Vec::with_capacity(pieces.len());
+    let mut geometries = Vec::with_capacity(pieces.len());
+    for (l, r, geometry) in pieces {
+        left_indices.push(l);
+        right_indices.push(r);
+        geometries.push(geometry);
+    }
+
+    let (mut fields, mut columns) = if how == OverlayType::Difference {
+        take_attributes(left, left_indices, str::to_string)?
+    } else {
+        let left_names = attribute_names(left)?;
+        let right_names = attribute_names(right)?;
+        let (mut fields, mut columns) = take_attributes(left, left_indices, |name| {
+            if right_names.contains(name) {
+                format!("{name}_left")
+            } else {
+                name.to_string()
+            }
+        })?;
+        let (right_fields, right_columns) = take_attributes(right, right_indices, |name| {
+            if left_names.contains(name) {
+                format!("{name}_right")
+            } else {
+                name.to_string()
+            }
+        })?;
+        fields.extend(right_fields);
+        columns.extend(right_columns);
+        (fields, columns)
+    };
+
+    let geometry_name = left
+        .schema()
+        .field(left.default_geometry_column_idx()?)
+        .name()
+        .clone();
+    let empty: NativeArrayRef = Arc::new(MultiPolygonBuilder::new(Dimension::XY).finish());
+    let template = left_chunks.first().unwrap_or(&empty);
+    let inputs = left_chunks
+        .iter()
+        .chain(right_chunks.iter())
+        .map(|chunk| chunk.as_ref())
+        .collect::<Vec<_>>();
+    let geometry = finish_multi_polygons(
+        geometries.into_iter().map(Some).collect(),
+        template.as_ref(),
+        &inputs,
+    );
+    fields.push(
+        geometry
+            .extension_field()
+            .as_ref()
+            .clone()
+            .with_name(geometry_name),
+    );
+    columns.push(geometry.to_array_ref());
+
+    let schema: SchemaRef = Arc::new(Schema::new_with_metadata(
+        fields,
+        left.schema().metadata().clone(),
+    ));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    Table::try_new(vec![batch], schema)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{Array, Int32Array};
+    use arrow_buffer::OffsetBuffer;
+
+    use super::*;
+    use crate::algorithm::geo::Area;
+    use crate::array::{AsNativeArray, InterleavedCoordBuffer, PolygonArray};
+    use crate::test::table::id_table;
+    use crate::NativeArray;
+    use geo_traits::CoordTrait;
+
+    fn table(ids: Vec<i32>, rects: Vec<geo::Rect>) -> Table {
+        let polygons = rects.iter().map(|r| r.to_polygon()).collect::<Vec<_>>();
+        let geometry: PolygonArray = (polygons.as_slice(), Dimension::XY).into();
+        id_table(ids, Arc::new(geometry))
+    }
+
+    fn layers() -> (Table, Table) {
+        let left = table(
+            vec![1, 2],
+            vec![
+                geo::Rect::new((0., 0.), (2., 2.)),
+                geo::Rect::new((2., 0.), (4., 2.)),
+            ],
+        );
+        let right = table(
+            vec![10, 20],
+            vec![
+                geo::Rect::new((1., 0.), (3., 1.)),
+                geo::Rect::new((10., 10.), (11., 11.)),
+            ],
+        );
+        (left, right)
+    }
+
+    fn ids(table: &Table, column: usize) -> Vec<Option<i32>> {
+        let column = table.batches()[0].column(column);
+        let ids = column.as_any().downcast_ref::<Int32Array>().unwrap();
+        ids.iter().collect()
+    }
+
+    fn areas(table: &Table) -> Vec<f64> {
+        let geometry = table.geometry_column(None).unwrap().geometry_chunks()[0].clone();
+        geometry
+            .as_ref()
+            .as_multi_polygon()
+            .unsigned_area()
+            .values()
+            .to_vec()
+    }
+
+    #[test]
+    fn intersection_and_identity() {
+        let (left, right) = layers();
+
+        let pieces = overlay(
+            &left,
+            &right,
+            OverlayType::Intersection,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(pieces.num_columns(), 3);
+        assert_eq!(pieces.schema().field(0).name(), "id_left");
+        assert_eq!(ids(&pieces, 0), [Some(1), Some(2)]);
+        assert_eq!(ids(&pieces, 1), [Some(10), Some(10)]);
+        assert_eq!(areas(&pieces), [1., 1.]);
+
+        let pieces = overlay(
+            &left,
+            &right,
+            OverlayType::Identity,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(ids(&pieces, 0), [Some(1), Some(2), Some(1), Some(2)]);
+        assert_eq!(ids(&pieces, 1), [Some(10), Some(10), None, None]);
+        assert_eq!(areas(&pieces), [1., 1., 3., 3.]);
+    }
+
+    #[test]
+    fn union_and_differences() {
+        let (left, right) = layers();
+
+        let pieces = overlay(
+            &left,
+            &right,
+            OverlayType::Union,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(ids(&pieces, 1), [Some(10), Some(10), None, None, Some(20)]);
+        assert_eq!(areas(&pieces), [1., 1., 3., 3., 1.]);
+
+        let pieces = overlay(
+            &left,
+            &right,
+            OverlayType::SymmetricDifference,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(ids(&pieces, 0), [Some(1), Some(2), None]);
+        assert!(pieces.batches()[0].column(0).is_null(2));
+
+        let pieces = overlay(
+            &left,
+            &right,
+            OverlayType::Difference,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(pieces.num_columns(), 2);
+        assert_eq!(pieces.schema().field(0).name(), "id");
+        assert_eq!(areas(&pieces), [3., 3.]);
+    }
+
+    #[test]
+    fn keeps_dimension() {
+        let coords = InterleavedCoordBuffer::new(
+            vec![0., 0., 5., 2., 0., 5., 2., 2., 5., 0., 2., 5., 0., 0., 5.].into(),
+            Dimension::XYZ,
+        );
+        let geometry = PolygonArray::new(
+            coords.into(),
+            OffsetBuffer::new(vec![0, 1].into()),
+            OffsetBuffer::new(vec![0, 5].into()),
+            None,
+            Default::default(),
+        );
+        let left = id_table(vec![1], Arc::new(geometry));
+        let (_, right) = layers();
+
+        let pieces = overlay(
+            &left,
+            &right,
+            OverlayType::Intersection,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        let geometry = pieces.geometry_column(None).unwrap().geometry_chunks()[0].clone();
+        let geometry = geometry.as_ref().as_multi_polygon().clone();
+        assert_eq!(geometry.dimension(), Dimension::XYZ);
+        let z_values = (0..geometry.coords().len())
+            .map(|i| {
+                let coord = geometry.coords().value(i);
+                ((coord.x(), coord.y()), coord.nth_or_panic(2))
+            })
+            .collect::<Vec<_>>();
+        assert!(z_values.contains(&((2., 0.), 5.)));
+        assert!(z_values
+            .iter()
+            .any(|((x, y), z)| (*x, *y) == (1., 1.) && z.is_nan()));
+    }
+}
@@ -52,6 +52,17 @@ impl_rtree!(MixedGeometryArray, bounding_rect_geometry);
 impl_rtree!(GeometryCollectionArray, bounding_rect_geometry_collection);
 impl_rtree!(RectArray, bounding_rect_rect);
 impl_rtree!(GeometryArray, bounding_rect_geometry);
+impl_rtree!(LargeLineStringArray, bounding_rect_linestring);
+impl_rtree!(LargePolygonArray, bounding_rect_polygon);
+impl_rtree!(LargeMultiPointArray, bounding_rect_multipoint);
+impl_rtree!(LargeMultiLineStringArray, bounding_rect_multilinestring);
+impl_rtree!(LargeMultiPolygonArray, bounding_rect_multipolygon);
+impl_rtree!(LargeMixedGeometryArray, bounding_rect_geometry);
+impl_rtree!(
+    LargeGeometryCollectionArray,
+    bounding_rect_geometry_collection
+);
+impl_rtree!(LargeGeometryArray, bounding_rect_geometry);
 
 impl RTree for &dyn NativeArray {
     type Output = OwnedRTree<f64>;
@@ -68,13 +79,22 @@ impl RTree for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => impl_method!(as_point),
             LineString(_, _) => impl_method!(as_line_string),
+            LargeLineString(_, _) => impl_method!(as_large_line_string),
             Polygon(_, _) => impl_method!(as_polygon),
+            LargePolygon(_, _) => impl_method!(as_large_polygon),
             MultiPoint(_, _) => impl_method!(as_multi_point),
+            LargeMultiPoint(_, _) => impl_method!(as_large_multi_point),
             MultiLineString(_, _) => impl_method!(as_multi_line_string),
+            LargeMultiLineString(_, _) => impl_method!(as_large_multi_line_string),
             MultiPolygon(_, _) => impl_method!(as_multi_polygon),
+            LargeMultiPolygon(_, _) => impl_method!(as_large_multi_polygon),
+            Mixed(_, _) => impl_method!(as_mixed),
+            LargeMixed(_, _) => impl_method!(as_large_mixed),
             GeometryCollection(_, _) => impl_method!(as_geometry_collection),
+            LargeGeometryCollection(_, _) => impl_method!(as_large_geometry_collection),
             Rect(_) => impl_method!(as_rect),
             Geometry(_) => impl_method!(as_geometry),
+            LargeGeometry(_) => impl_method!(as_large_geometry),
         }
     }
 }
@@ -102,13 +122,22 @@ impl RTree for &dyn ChunkedNativeArray {
         let result = match self.data_type() {
             Point(_, _) => impl_method!(as_point),
             LineString(_, _) => impl_method!(as_line_string),
+            LargeLineString(_, _) => impl_method!(as_large_line_string),
             Polygon(_, _) => impl_method!(as_polygon),
+            LargePolygon(_, _) => impl_method!(as_large_polygon),
             MultiPoint(_, _) => impl_method!(as_multi_point),
+            LargeMultiPoint(_, _) => impl_method!(as_large_multi_point),
             MultiLineString(_, _) => impl_method!(as_multi_line_string),
+            LargeMultiLineString(_, _) => impl_method!(as_large_multi_line_string),
             MultiPolygon(_, _) => impl_method!(as_multi_polygon),
+            LargeMultiPolygon(_, _) => impl_method!(as_large_multi_polygon),
+            Mixed(_, _) => impl_method!(as_mixed),
+            LargeMixed(_, _) => impl_method!(as_large_mixed),
             GeometryCollection(_, _) => impl_method!(as_geometry_collection),
+            LargeGeometryCollection(_, _) => impl_method!(as_large_geometry_collection),
             Rect(_) => impl_method!(as_rect),
             Geometry(_) => impl_method!(as_geometry),
+            LargeGeometry(_) => impl_method!(as_large_geometry),
         };
         Ok(result)
     }
@@ -0,0 +1,530 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arrow::compute::{concat_batches, take};
+use arrow_array::{Array, RecordBatch, UInt32Array};
+use arrow_schema::{Field, Schema, SchemaRef};
+use geo::{BoundingRect, Contains, Distance, Euclidean, Intersects, Relate, Within};
+use geo_index::rtree::sort::HilbertSort;
+use geo_index::rtree::{OwnedRTree, RTreeBuilder, RTreeIndex};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::algorithm::geo::{check_planar_edges, SphericalEdgesPolicy};
+use crate::array::metadata::ArrayMetadata;
+use crate::array::AsNativeArray;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::table::Table;
+use crate::trait_::{ArrayAccessor, NativeScalar};
+use crate::NativeArray;
+
+/// The relationship between a left and a right geometry that makes a pair of rows match in
+/// [`spatial_join`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpatialJoinPredicate {
+    /// The left and right geometries share at least one point.
+    Intersects,
+
+    /// The left geometry completely contains the right geometry.
+    Contains,
+
+    /// The left geometry is completely within the right geometry.
+    Within,
+
+    /// The geometries share boundary points but their interiors do not intersect.
+    Touches,
+
+    /// The Euclidean distance between the geometries is at most the given value.
+    DWithin(f64),
+}
+
+impl SpatialJoinPredicate {
+    /// How far the bounding boxes of left geometries need to be expanded so that every matching
+    /// pair is a candidate in the tree query.
+    fn search_distance(&self) -> f64 {
+        match self {
+            Self::DWithin(distance) => *distance,
+            _ => 0.,
+        }
+    }
+
+    fn evaluate(&self, left: &geo::Geometry, right: &geo::Geometry) -> bool {
+        match self {
+            Self::Intersects => left.intersects(right),
+            Self::Contains => left.contains(right),
+            Self::Within => left.is_within(right),
+            Self::Touches => left.relate(right).is_touches(),
+            Self::DWithin(distance) => Euclidean::distance(left, right) <= *distance,
+        }
+    }
+}
+
+/// Which rows are kept in the output of [`spatial_join`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpatialJoinType {
+    /// Only rows that match on both sides, ordered by the left row.
+    #[default]
+    Inner,
+
+    /// Every left row, ordered by the left row. Right columns are null for left rows without a
+    /// match.
+    Left,
+
+    /// Every right row, ordered by the right row. Left columns are null for right rows without a
+    /// match.
+    Right,
+}
+
+/// One chunk of a geometry column, converted to [`geo`] geometries and indexed in an R-tree.
+struct IndexedChunk {
+    /// Position of the first row of this chunk in the whole column.
+    offset: usize,
+    geometries: Vec<Option<geo::Geometry>>,
+    /// `None` if the chunk has no non-null, non-empty geometries.
+    tree: Option<OwnedRTree<f64>>,
+    /// Row within the chunk of each item inserted in `tree`, in insertion order.
+    tree_rows: Vec<usize>,
+    /// The expanded bounding box of each item inserted in `tree`, in insertion order.
+    boxes: Vec<[f64; 4]>,
+}
+
+impl IndexedChunk {
+    /// Index `array` for `operation`, expanding every bounding box by `distance` on each side.
+    fn try_new(
+        array: &dyn NativeArray,
+        offset: usize,
+        distance: f64,
+        operation: &str,
+        policy: SphericalEdgesPolicy,
+    ) -> Result<Self> {
+        check_planar_edges(operation, &array.metadata(), policy)?;
+
+        let geometries = geo_geometries(array);
+        let (tree_rows, boxes): (Vec<_>, Vec<_>) = geometries
+            .iter()
+            .enumerate()
+            .filter_map(|(row, geom)| {
+                let rect = geom.as_ref()?.bounding_rect()?;
+                Some((
+                    row,
+                    [
+                        rect.min().x - distance,
+                        rect.min().y - distance,
+                        rect.max().x + distance,
+                        rect.max().y + distance,
+                    ],
+                ))
+            })
+            .unzip();
+
+        let tree = (!boxes.is_empty()).then(|| build_rtree(&boxes));
+
+        Ok(Self {
+            offset,
+            geometries,
+            tree,
+            tree_rows,
+            boxes,
+        })
+    }
+
+    /// The pairs of rows of `self` and `other`, relative to the whole column, that satisfy
+    /// `predicate`.
+    fn matches(&self, other: &IndexedChunk, predicate: SpatialJoinPredicate) -> Vec<(u32, u32)> {
+        let Some(right_tree) = &other.tree else {
+            return vec![];
+        };
+
+        self.tree_rows
+            .iter()
+            .zip(self.boxes.iter())
+            .flat_map(|(&left_row, &[min_x, min_y, max_x, max_y])| {
+                right_tree
+                    .search(min_x, min_y, max_x, max_y)
+                    .into_iter()
+                    .map(move |right_item| (left_row, other.tree_rows[right_item]))
+            })
+            .filter_map(|(left_row, right_row)| {
+                let left_geom = self.geometries[left_row].as_ref()?;
+                let right_geom = other.geometries[right_row].as_ref()?;
+                predicate.evaluate(left_geom, right_geom).then_some((
+                    (self.offset + left_row) as u32,
+                    (other.offset + right_row) as u32,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Build an R-tree of the non-empty `boxes`, in which item `i` is `boxes[i]`.
+///
+/// geo-index can't build a tree of a single item, so a lone box is followed by an inverted box
+/// that no search matches.
+pub(super) fn build_rtree(boxes: &[[f64; 4]]) -> OwnedRTree<f64> {
+    const NO_MATCH: [f64; 4] = [
+        f64::INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    let padding = if boxes.len() == 1 {
+        &[NO_MATCH][..]
+    } else {
+        &[]
+    };
+
+    let mut builder = RTreeBuilder::new_with_node_size(boxes.len() + padding.len(), 16);
+    for [min_x, min_y, max_x, max_y] in boxes.iter().chain(padding).copied() {
+        builder.add(min_x, min_y, max_x, max_y);
+    }
+    builder.finish::<HilbertSort>()
+}
+
+/// Convert every geometry of `array` to a [`geo::Geometry`].
+pub(crate) fn geo_geometries(array: &dyn NativeArray) -> Vec<Option<geo::Geometry>> {
+    use NativeType::*;
+
+    macro_rules! impl_geometries {
+        ($method:ident) => {
+            array
+                .$method()
+                .iter()
+                .map(|geom| geom.map(|geom| geom.to_geo_geometry()))
+                .collect()
+        };
+    }
+
+    match array.data_type() {
+        Point(_, _) => impl_geometries!(as_point),
+        LineString(_, _) => impl_geometries!(as_line_string),
+        LargeLineString(_, _) => impl_geometries!(as_large_line_string),
+        Polygon(_, _) => impl_geometries!(as_polygon),
+        LargePolygon(_, _) => impl_geometries!(as_large_polygon),
+        MultiPoint(_, _) => impl_geometries!(as_multi_point),
+        LargeMultiPoint(_, _) => impl_geometries!(as_large_multi_point),
+        MultiLineString(_, _) => impl_geometries!(as_multi_line_string),
+        LargeMultiLineString(_, _) => impl_geometries!(as_large_multi_line_string),
+        MultiPolygon(_, _) => impl_geometries!(as_multi_polygon),
+        LargeMultiPolygon(_, _) => impl_geometries!(as_large_multi_polygon),
+        Mixed(_, _) => impl_geometries!(as_mixed),
+        LargeMixed(_, _) => impl_geometries!(as_large_mixed),
+        GeometryCollection(_, _) => impl_geometries!(as_geometry_collection),
+        LargeGeometryCollection(_, _) => impl_geometries!(as_large_geometry_collection),
+        Rect(_) => impl_geometries!(as_rect),
+        Geometry(_) => impl_geometries!(as_geometry),
+        LargeGeometry(_) => impl_geometries!(as_large_geometry),
+    }
+}
+
+/// Index every chunk of the default geometry column of `table`.
+fn index_table(
+    table: &Table,
+    distance: f64,
+    operation: &str,
+    policy: SphericalEdgesPolicy,
+) -> Result<Vec<IndexedChunk>> {
+    let chunks = table.geometry_column(None)?.geometry_chunks();
+    let mut offset = 0;
+    chunks
+        .iter()
+        .map(|chunk| {
+            let indexed =
+                IndexedChunk::try_new(chunk.as_ref(), offset, distance, operation, policy);
+            offset += chunk.len();
+            indexed
+        })
+        .collect()
+}
+
+/// Check that the default geometry columns of `left` and `right` have compatible CRSs.
+pub(super) fn check_tables_crs(left: &Table, right: &Table, operation: &str) -> Result<()> {
+    let metadata = |table: &Table| -> Result<ArrayMetadata> {
+        let index = table.default_geometry_column_idx()?;
+        ArrayMetadata::try_from(table.schema().field(index))
+    };
+    metadata(left)?.check_crs_compatible(&metadata(right)?, operation)
+}
+
+/// The pairs of rows of `left` and `right`, in no particular order, whose default geometries
+/// satisfy `predicate`.
+pub(super) fn matching_pairs(
+    left: &Table,
+    right: &Table,
+    predicate: SpatialJoinPredicate,
+    operation: &str,
+    policy: SphericalEdgesPolicy,
+) -> Result<Vec<(u32, u32)>> {
+    check_tables_crs(left, right, operation)?;
+    let left_chunks = index_table(left, predicate.search_distance(), operation, policy)?;
+    let right_chunks = index_table(right, 0., operation, policy)?;
+
+    let chunk_pairs = (0..left_chunks.len())
+        .flat_map(|i| (0..right_chunks.len()).map(move |j| (i, j)))
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "rayon")]
+    let chunk_pairs = chunk_pairs.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let chunk_pairs = chunk_pairs.iter();
+
+    Ok(chunk_pairs
+        .flat_map(|(i, j)| left_chunks[*i].matches(&right_chunks[*j], predicate))
+        .collect())
+}
+
+/// Take `indices` from all batches of `table` at once, renaming columns with `rename`.
+///
+/// Null indices produce null rows, so the output fields are made nullable when `indices` has
+/// nulls.
+pub(super) fn take_table(
+    table: &Table,
+    indices: &UInt32Array,
+    rename: impl Fn(&str) -> String,
+) -> Result<(Vec<Field>, Vec<arrow_array::ArrayRef>)> {
+    let batch = concat_batches(table.schema(), table.batches())?;
+    let fields = table
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| {
+            field
+                .as_ref()
+                .clone()
+                .with_name(rename(field.name()))
+                .with_nullable(field.is_nullable() || indices.null_count() > 0)
+        })
+        .collect();
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| Ok(take(column.as_ref(), indices, None)?))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((fields, columns))
+}
+
+/// Sort `pairs` and add a `(row, None)` pair for every row in `0..len` that is not the first
+/// element of any pair.
+fn with_unmatched(pairs: Vec<(u32, u32)>, len: usize) -> Vec<(u32, Option<u32>)> {
+    let matched = pairs.iter().map(|(row, _)| *row).collect::<HashSet<_>>();
+    let mut pairs = pairs
+        .into_iter()
+        .map(|(row, other)| (row, Some(other)))
+        .chain(
+            (0..len as u32)
+                .filter(|row| !matched.contains(row))
+                .map(|row| (row, None)),
+        )
+        .collect::<Vec<_>>();
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Join the rows of two tables whose geometries satisfy `predicate`.
+///
+/// The join is computed on the default geometry column of each table. Every chunk of one side
+/// is indexed in an R-tree and queried against every chunk of the other, and `predicate` is only
+/// evaluated on pairs whose bounding boxes intersect. With the `rayon` feature, pairs of chunks
+/// are processed in parallel. Null and empty geometries never match.
+///
+/// The output has all columns of `left` followed by all columns of `right`, including both
+/// geometry columns. Column names that exist on both sides get a `_left` or `_right` suffix.
+///
+/// Predicates are evaluated on the raw coordinates, so geometries that declare spherical edges
+/// would be matched along straight segments rather than great circles. `policy` decides whether
+/// such input is rejected or joined anyway with a warning.
+pub fn spatial_join(
+    left: &Table,
+    right: &Table,
+    predicate: SpatialJoinPredicate,
+    how: SpatialJoinType,
+    policy: SphericalEdgesPolicy,
+) -> Result<Table> {
+    if let SpatialJoinPredicate::DWithin(distance) = predicate {
+        if distance.is_nan() || distance < 0. {
+            return Err(GeoArrowError::General(format!(
+                "DWithin distance must be non-negative, got {distance}"
+            )));
+        }
+    }
+
+    let mut pairs = matching_pairs(left, right, predicate, "spatial_join", policy)?;
+
+    let (left_indices, right_indices): (Vec<_>, Vec<_>) = match how {
+        SpatialJoinType::Inner => {
+            pairs.sort_unstable();
+            pairs.into_iter().map(|(l, r)| (Some(l), Some(r))).unzip()
+        }
+        SpatialJoinType::Left => {
+            let pairs = with_unmatched(pairs, left.len());
+            pairs.into_iter().map(|(l, r)| (Some(l), r)).unzip()
+        }
+        SpatialJoinType::Right => {
+            let pairs = with_unmatched(
+                pairs.into_iter().map(|(l, r)| (r, l)).collect(),
+                right.len(),
+            );
+            pairs.into_iter().map(|(r, l)| (l, Some(r))).unzip()
+        }
+    };
+
+    let left_indices = UInt32Array::from(left_indices);
+    let right_indices = UInt32Array::from(right_indices);
+
+    let left_names = left
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<HashSet<_>>();
+    let right_names = right
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<HashSet<_>>();
+
+    let (mut fields, mut columns) = take_table(left, &left_indices, |name| {
+        if right_names.contains(name) {
+            format!("{name}_left")
+        } else {
+            name.to_string()
+        }
+    })?;
+    let (right_fields, right_columns) = take_table(right, &right_indices, |name| {
+        if left_names.contains(name) {
+            format!("{name}_right")
+        } else {
+            name.to_string()
+        }
+    })?;
+    fields.extend(right_fields);
+    columns.extend(right_columns);
+
+    let schema: SchemaRef = Arc::new(Schema::new_with_metadata(
+        fields,
+        left.schema().metadata().clone(),
+    ));
+    let batch = RecordBatch::try_new(schema.clone(), columns)?;
+    Table::try_new(vec![batch], schema)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::{Array, Int32Array};
+
+    use super::*;
+    use crate::array::{PointArray, PolygonArray};
+    use crate::datatypes::Dimension;
+    use crate::test::table::id_table;
+
+    fn polygons() -> Table {
+        let squares = vec![
+            geo::Rect::new((0., 0.), (2., 2.)).to_polygon(),
+            geo::Rect::new((10., 10.), (12., 12.)).to_polygon(),
+        ];
+        let array: PolygonArray = (squares.as_slice(), Dimension::XY).into();
+        id_table(vec![1, 2], Arc::new(array))
+    }
+
+    fn points() -> Table {
+        let points = vec![
+            geo::point!(x: 1., y: 1.),
+            geo::point!(x: 5., y: 5.),
+            geo::point!(x: 11., y: 11.),
+            geo::point!(x: 2., y: 1.),
+        ];
+        let array: PointArray = (points.as_slice(), Dimension::XY).into();
+        id_table(vec![10, 20, 30, 40], Arc::new(array))
+    }
+
+    fn ids(table: &Table, column: usize) -> Vec<Option<i32>> {
+        let batch = &table.batches()[0];
+        let ids = batch
+            .column(column)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        ids.iter().collect()
+    }
+
+    #[test]
+    fn inner_contains() {
+        let joined = spatial_join(
+            &polygons(),
+            &points(),
+            SpatialJoinPredicate::Contains,
+            SpatialJoinType::Inner,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(joined.num_columns(), 4);
+        assert_eq!(joined.schema().field(0).name(), "id_left");
+        assert_eq!(joined.schema().field(2).name(), "id_right");
+        assert_eq!(ids(&joined, 0), vec![Some(1), Some(2)]);
+        assert_eq!(ids(&joined, 2), vec![Some(10), Some(30)]);
+    }
+
+    #[test]
+    fn single_row() {
+        let square = vec![geo::Rect::new((0., 0.), (2., 2.)).to_polygon()];
+        let array: PolygonArray = (square.as_slice(), Dimension::XY).into();
+        let polygon = id_table(vec![1], Arc::new(array));
+
+        let joined = spatial_join(
+            &points(),
+            &polygon,
+            SpatialJoinPredicate::Intersects,
+            SpatialJoinType::Inner,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(ids(&joined, 0), vec![Some(10), Some(40)]);
+        assert_eq!(ids(&joined, 2), vec![Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn left_intersects() {
+        let joined = spatial_join(
+            &points(),
+            &polygons(),
+            SpatialJoinPredicate::Intersects,
+            SpatialJoinType::Left,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(
+            ids(&joined, 0),
+            vec![Some(10), Some(20), Some(30), Some(40)]
+        );
+        assert_eq!(ids(&joined, 2), vec![Some(1), None, Some(2), Some(1)]);
+    }
+
+    #[test]
+    fn right_touches_and_dwithin() {
+        let joined = spatial_join(
+            &points(),
+            &polygons(),
+            SpatialJoinPredicate::Touches,
+            SpatialJoinType::Right,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(ids(&joined, 0), vec![Some(40), None]);
+        assert_eq!(ids(&joined, 2), vec![Some(1), Some(2)]);
+
+        let joined = spatial_join(
+            &points(),
+            &polygons(),
+            SpatialJoinPredicate::DWithin(5.),
+            SpatialJoinType::Inner,
+            SphericalEdgesPolicy::Error,
+        )
+        .unwrap();
+        assert_eq!(
+            ids(&joined, 0),
+            vec![Some(10), Some(20), Some(30), Some(40)]
+        );
+        assert_eq!(ids(&joined, 2), vec![Some(1), Some(1), Some(2), Some(1)]);
+    }
+}
@@ -4,8 +4,7 @@
 //! [library's documentation][geodesy] for how to construct the projection string to pass into
 //! `reproject`.
 
-// TODO: reimplement not based on old GeometryArray enum
-// mod reproject;
+mod reproject;
 
-// pub use geodesy::Direction;
-// pub use reproject::reproject;
+pub use geodesy::Direction;
+pub use reproject::{Reproject, ReprojectTable};
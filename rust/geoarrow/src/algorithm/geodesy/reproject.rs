@@ -1,41 +1,63 @@
-#![allow(deprecated)]
+use std::sync::Arc;
 
-use crate::array::geometry::GeometryArray;
-use crate::array::{CoordBuffer, InterleavedCoordBuffer, SeparatedCoordBuffer};
-use crate::error::Result;
-use crate::trait_::GeometryArraySelfMethods;
 use geodesy::prelude::*;
-use geodesy::Coor4D;
-use geodesy::Direction;
 
-/// Wrapper object for applying coordinate operations slices
-struct InterleavedCoordsGeodesy<'a>(&'a mut [f64]);
+use crate::algorithm::native::coord_transform::CoordTransform;
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::Dimension;
+use crate::error::Result;
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
+use crate::NativeArray;
+
+/// Wrapper object for applying coordinate operations to an interleaved coordinate slice.
+///
+/// Only the X, Y and (if present) Z values are exposed to geodesy. M values are left untouched.
+struct InterleavedCoordsGeodesy<'a> {
+    coords: &'a mut [f64],
+    dim: Dimension,
+}
 
 impl CoordinateSet for InterleavedCoordsGeodesy<'_> {
     fn len(&self) -> usize {
-        self.0.len() / 2
+        self.coords.len() / self.dim.size()
     }
 
-    fn dim(&self) -> geo_traits::Dimensions {
-        2
+    fn dim(&self) -> usize {
+        if self.dim.has_z() {
+            3
+        } else {
+            2
+        }
     }
 
     fn get_coord(&self, index: usize) -> Coor4D {
-        Coor4D([self.0[index * 2], self.0[index * 2 + 1], 0., 0.])
+        let offset = index * self.dim.size();
+        let z = if self.dim.has_z() {
+            self.coords[offset + 2]
+        } else {
+            0.
+        };
+        Coor4D([self.coords[offset], self.coords[offset + 1], z, f64::NAN])
     }
 
     fn set_coord(&mut self, index: usize, value: &Coor4D) {
-        let x = value[0];
-        let y = value[1];
-        self.0[index * 2] = x;
-        self.0[index * 2 + 1] = y;
+        let offset = index * self.dim.size();
+        self.coords[offset] = value[0];
+        self.coords[offset + 1] = value[1];
+        if self.dim.has_z() {
+            self.coords[offset + 2] = value[2];
+        }
     }
 }
 
-/// Wrapper object for applying coordinate operations slices
+/// Wrapper object for applying coordinate operations to separated coordinate slices.
 struct SeparatedCoordsGeodesy<'a> {
     x: &'a mut [f64],
     y: &'a mut [f64],
+    z: Option<&'a mut [f64]>,
 }
 
 impl CoordinateSet for SeparatedCoordsGeodesy<'_> {
@@ -43,103 +65,372 @@ impl CoordinateSet for SeparatedCoordsGeodesy<'_> {
         self.x.len()
     }
 
-    fn dim(&self) -> geo_traits::Dimensions {
-        2
+    fn dim(&self) -> usize {
+        if self.z.is_some() {
+            3
+        } else {
+            2
+        }
     }
 
     fn get_coord(&self, index: usize) -> Coor4D {
-        Coor4D([self.x[index], self.y[index], 0., 0.])
+        let z = self.z.as_ref().map(|z| z[index]).unwrap_or(0.);
+        Coor4D([self.x[index], self.y[index], z, f64::NAN])
     }
 
     fn set_coord(&mut self, index: usize, value: &Coor4D) {
         self.x[index] = value[0];
         self.y[index] = value[1];
+        if let Some(z) = self.z.as_mut() {
+            z[index] = value[2];
+        }
     }
 }
 
-fn reproject_coords(
-    coords: &CoordBuffer<2>,
-    definition: &str,
+/// A parsed geodesy operation, applied to every coordinate buffer of an array.
+struct Transformer<'a> {
+    context: Minimal,
+    op: OpHandle,
     direction: Direction,
-) -> Result<CoordBuffer<2>> {
-    let mut context = Minimal::new();
-    // TODO: fix error handling
-    let operation = context.op(definition).unwrap();
-
-    let new_coords = match coords {
-        CoordBuffer::Interleaved(coords) => {
-            let mut cloned_coords = coords.coords.to_vec();
+    crs: &'a ArrayMetadata,
+}
 
-            let mut geodesy_coords = InterleavedCoordsGeodesy(&mut cloned_coords);
-            context
-                .apply(operation, direction, &mut geodesy_coords)
-                .unwrap();
+impl<'a> Transformer<'a> {
+    fn try_new(definition: &str, direction: Direction, crs: &'a ArrayMetadata) -> Result<Self> {
+        let mut context = Minimal::new();
+        let op = context.op(definition)?;
+        Ok(Self {
+            context,
+            op,
+            direction,
+            crs,
+        })
+    }
 
-            CoordBuffer::Interleaved(InterleavedCoordBuffer::new(cloned_coords.into()))
-        }
-        CoordBuffer::Separated(separated_coords) => {
-            let mut x_coords = separated_coords.buffers[0].to_vec();
-            let mut y_coords = separated_coords.buffers[1].to_vec();
-
-            let mut geodesy_coords = SeparatedCoordsGeodesy {
-                x: &mut x_coords,
-                y: &mut y_coords,
-            };
-            context
-                .apply(operation, direction, &mut geodesy_coords)
-                .unwrap();
-            CoordBuffer::Separated(SeparatedCoordBuffer::new([
-                x_coords.into(),
-                y_coords.into(),
-            ]))
+    /// A copy of the direction of this operation, as [`Direction`] isn't `Copy`.
+    fn direction(&self) -> Direction {
+        match self.direction {
+            Direction::Fwd => Direction::Fwd,
+            Direction::Inv => Direction::Inv,
         }
-    };
+    }
+}
+
+impl CoordTransform for Transformer<'_> {
+    /// The input metadata with its CRS replaced by the target CRS.
+    fn metadata(&self, metadata: &ArrayMetadata) -> Arc<ArrayMetadata> {
+        Arc::new(ArrayMetadata {
+            crs: self.crs.crs.clone(),
+            crs_type: self.crs.crs_type.clone(),
+            ..metadata.clone()
+        })
+    }
 
-    Ok(new_coords)
+    fn coords(&self, coords: &CoordBuffer) -> Result<CoordBuffer> {
+        let new_coords = match coords {
+            CoordBuffer::Interleaved(coords) => {
+                let mut values = coords.coords.to_vec();
+                let mut geodesy_coords = InterleavedCoordsGeodesy {
+                    coords: &mut values,
+                    dim: coords.dim,
+                };
+                self.context
+                    .apply(self.op, self.direction(), &mut geodesy_coords)?;
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into(), coords.dim))
+            }
+            CoordBuffer::Separated(coords) => {
+                let [x, y, z, m] = coords.buffers.clone();
+                let mut x = x.to_vec();
+                let mut y = y.to_vec();
+                let mut z_values = coords.dim.has_z().then(|| z.to_vec());
+                let mut geodesy_coords = SeparatedCoordsGeodesy {
+                    x: &mut x,
+                    y: &mut y,
+                    z: z_values.as_deref_mut(),
+                };
+                self.context
+                    .apply(self.op, self.direction(), &mut geodesy_coords)?;
+                let z = z_values.map(|z| z.into()).unwrap_or(z);
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(
+                    [x.into(), y.into(), z, m],
+                    coords.dim,
+                ))
+            }
+        };
+        Ok(new_coords)
+    }
 }
 
-/// Reproject coordinates
+/// Reproject coordinates with a [`geodesy`] operation.
+///
+/// `definition` is a geodesy operator pipeline, such as `"utm zone=32"`, applied in the given
+/// `direction`. Geodesy expects angular coordinates in radians; when the input is in degrees,
+/// start the pipeline with an `adapt` step, such as `"adapt from=neuf_deg | utm zone=32"`.
 ///
+/// The X, Y and, for XYZ and XYZM arrays, Z values are transformed. M values are left untouched.
+/// Coordinates that cannot be transformed are set to `NaN` by geodesy.
+///
+/// The CRS of the output is taken from `crs`; the rest of the metadata (such as `edges`) is kept
+/// from the input.
+///
+/// Rect arrays are returned as Polygon arrays, because a reprojected rectangle is not in general
+/// axis-aligned anymore.
+pub trait Reproject {
+    type Output;
 
-// NOTE: In the future this should probably take care to _not_ reproject coordinates that are set to null via the arrow validity bitmask. That could probably lead to
-pub fn reproject<O: OffsetSizeTrait>(
-    array: &GeometryArray<O>,
-    definition: &str,
-    direction: Direction,
-) -> Result<GeometryArray<O>> {
-    match array {
-        GeometryArray::Point(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::Point(arr.clone().with_coords(new_coords)))
-        }
-        GeometryArray::LineString(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::LineString(
-                arr.clone().with_coords(new_coords),
-            ))
-        }
-        GeometryArray::Polygon(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::Polygon(arr.clone().with_coords(new_coords)))
-        }
-        GeometryArray::MultiPoint(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::MultiPoint(
-                arr.clone().with_coords(new_coords),
-            ))
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Self::Output;
+}
+
+macro_rules! impl_reproject {
+    ($type:ty, $method:ident) => {
+        impl Reproject for $type {
+            type Output = Result<$type>;
+
+            fn reproject(
+                &self,
+                definition: &str,
+                direction: Direction,
+                crs: &ArrayMetadata,
+            ) -> Self::Output {
+                Transformer::try_new(definition, direction, crs)?.$method(self)
+            }
         }
-        GeometryArray::MultiLineString(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::MultiLineString(
-                arr.clone().with_coords(new_coords),
-            ))
+
+        impl Reproject for ChunkedGeometryArray<$type> {
+            type Output = Result<ChunkedGeometryArray<$type>>;
+
+            fn reproject(
+                &self,
+                definition: &str,
+                direction: Direction,
+                crs: &ArrayMetadata,
+            ) -> Self::Output {
+                let transformer = Transformer::try_new(definition, direction, crs)?;
+                let chunks = self
+                    .chunks
+                    .iter()
+                    .map(|chunk| transformer.$method(chunk))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ChunkedGeometryArray::new(chunks))
+            }
         }
-        GeometryArray::MultiPolygon(arr) => {
-            let new_coords = reproject_coords(&arr.coords, definition, direction)?;
-            Ok(GeometryArray::MultiPolygon(
-                arr.clone().with_coords(new_coords),
-            ))
+    };
+}
+
+impl_reproject!(PointArray, point);
+impl_reproject!(LineStringArray, line_string);
+impl_reproject!(LargeLineStringArray, line_string);
+impl_reproject!(PolygonArray, polygon);
+impl_reproject!(LargePolygonArray, polygon);
+impl_reproject!(MultiPointArray, multi_point);
+impl_reproject!(LargeMultiPointArray, multi_point);
+impl_reproject!(MultiLineStringArray, multi_line_string);
+impl_reproject!(LargeMultiLineStringArray, multi_line_string);
+impl_reproject!(MultiPolygonArray, multi_polygon);
+impl_reproject!(LargeMultiPolygonArray, multi_polygon);
+impl_reproject!(MixedGeometryArray, mixed);
+impl_reproject!(LargeMixedGeometryArray, mixed);
+impl_reproject!(GeometryCollectionArray, geometry_collection);
+impl_reproject!(LargeGeometryCollectionArray, geometry_collection);
+impl_reproject!(GeometryArray, geometry);
+impl_reproject!(LargeGeometryArray, geometry);
+
+impl Reproject for RectArray {
+    type Output = Result<PolygonArray>;
+
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Self::Output {
+        Transformer::try_new(definition, direction, crs)?.rect(self)
+    }
+}
+
+impl Reproject for ChunkedRectArray {
+    type Output = Result<ChunkedPolygonArray>;
+
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Self::Output {
+        let transformer = Transformer::try_new(definition, direction, crs)?;
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|chunk| transformer.rect(chunk))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+impl Reproject for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Self::Output {
+        Transformer::try_new(definition, direction, crs)?.native(*self)
+    }
+}
+
+impl Reproject for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Self::Output {
+        Transformer::try_new(definition, direction, crs)?.chunked(*self)
+    }
+}
+
+pub trait ReprojectTable {
+    /// Reproject every geometry column of this table with a [`geodesy`] operation.
+    ///
+    /// See [`Reproject`] for the meaning of the parameters. The field metadata of each geometry
+    /// column is updated to the new CRS.
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Result<Table>;
+}
+
+impl ReprojectTable for Table {
+    fn reproject(
+        &self,
+        definition: &str,
+        direction: Direction,
+        crs: &ArrayMetadata,
+    ) -> Result<Table> {
+        let transformer = Transformer::try_new(definition, direction, crs)?;
+
+        let reprojected_columns = self
+            .schema()
+            .as_ref()
+            .geometry_columns()
+            .iter()
+            .map(|idx| {
+                let geometry = self.geometry_column(Some(*idx))?;
+                Ok((*idx, transformer.chunked(geometry.as_ref())?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut new_table = self.clone();
+
+        for (column_idx, column) in reprojected_columns.iter() {
+            let prev_field = self.schema().field(*column_idx);
+            let new_field = column
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name(prev_field.name())
+                .with_nullable(prev_field.is_nullable());
+            new_table.set_column(*column_idx, new_field.into(), column.array_refs())?;
         }
-        GeometryArray::Rect(_arr) => todo!(),
+
+        Ok(new_table)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+    use geo_traits::CoordTrait;
+
+    use super::*;
+    use crate::algorithm::native::BoundingRectArray;
+    use crate::array::metadata::CRSType;
+    use crate::datatypes::NativeType;
+    use crate::test::{point, polygon};
+    use crate::trait_::{ArrayAccessor, NativeScalar};
+    use crate::ArrayBase;
+
+    const SHIFT: &str = "helmert x=1 y=2 z=3";
+
+    fn target_crs() -> ArrayMetadata {
+        ArrayMetadata::from_authority_code("EPSG:3857".to_string())
+    }
+
+    #[test]
+    fn reproject_points_and_update_crs() {
+        let arr = point::point_array();
+        let crs = target_crs();
+        let reprojected = arr.reproject(SHIFT, Direction::Fwd, &crs).unwrap();
+
+        assert_eq!(reprojected.value_as_geo(1), geo::point!(x: 2., y: 4.));
+        assert_eq!(reprojected.metadata().crs, crs.crs);
+        assert_eq!(
+            reprojected.metadata().crs_type,
+            Some(CRSType::AuthorityCode)
+        );
+
+        let round_trip = reprojected.reproject(SHIFT, Direction::Inv, &crs).unwrap();
+        assert_eq!(round_trip.value_as_geo(1), point::p1());
+    }
+
+    #[test]
+    fn reproject_xyz() {
+        let arr = point::point_z_array();
+        let reprojected = arr.reproject(SHIFT, Direction::Fwd, &target_crs()).unwrap();
+
+        assert_eq!(reprojected.dimension(), Dimension::XYZ);
+        let coord = reprojected.coords.value(1);
+        assert_relative_eq!(coord.nth_or_panic(0), 4.);
+        assert_relative_eq!(coord.nth_or_panic(1), 6.);
+        assert_relative_eq!(coord.nth_or_panic(2), 8.);
+    }
+
+    #[test]
+    fn reproject_dyn_rect_to_polygon() {
+        let rects = polygon::p_array().bounding_rect();
+        let reprojected = (&rects as &dyn NativeArray)
+            .reproject(SHIFT, Direction::Fwd, &target_crs())
+            .unwrap();
+
+        assert!(matches!(reprojected.data_type(), NativeType::Polygon(_, _)));
+        let rect = rects.value(0).to_geo();
+        let expected = geo::Rect::new(
+            geo::coord! { x: rect.min().x + 1., y: rect.min().y + 2. },
+            geo::coord! { x: rect.max().x + 1., y: rect.max().y + 2. },
+        )
+        .to_polygon();
+        assert_eq!(
+            reprojected.as_ref().as_polygon().value(0).to_geo_geometry(),
+            geo::Geometry::Polygon(expected)
+        );
+    }
+
+    #[test]
+    fn reproject_table() {
+        let table = point::table();
+        let crs = target_crs();
+        let reprojected = table.reproject(SHIFT, Direction::Fwd, &crs).unwrap();
+
+        let geometry = reprojected.geometry_column(None).unwrap();
+        let chunk = geometry.geometry_chunks()[0].clone();
+        assert_eq!(
+            chunk.as_ref().as_point().value_as_geo(0),
+            geo::point!(x: 1., y: 3.)
+        );
+
+        let field = reprojected.schema().field(2);
+        let metadata = ArrayMetadata::try_from(field).unwrap();
+        assert_eq!(metadata.crs, crs.crs);
     }
 }
@@ -33,6 +33,9 @@ zero_impl!(PointArray);
 zero_impl!(LineStringArray);
 zero_impl!(MultiPointArray);
 zero_impl!(MultiLineStringArray);
+zero_impl!(LargeLineStringArray);
+zero_impl!(LargeMultiPointArray);
+zero_impl!(LargeMultiLineStringArray);
 
 macro_rules! iter_geos_impl {
     ($type:ty) => {
@@ -52,6 +55,11 @@ iter_geos_impl!(MixedGeometryArray);
 iter_geos_impl!(GeometryCollectionArray);
 iter_geos_impl!(RectArray);
 iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
 
 impl Area for &dyn NativeArray {
     type Output = Result<Float64Array>;
@@ -62,13 +70,22 @@ impl Area for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().area(),
             LineString(_, _) => self.as_line_string().area(),
+            LargeLineString(_, _) => self.as_large_line_string().area(),
             Polygon(_, _) => self.as_polygon().area(),
+            LargePolygon(_, _) => self.as_large_polygon().area(),
             MultiPoint(_, _) => self.as_multi_point().area(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().area(),
             MultiLineString(_, _) => self.as_multi_line_string().area(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().area(),
             MultiPolygon(_, _) => self.as_multi_polygon().area(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().area(),
+            Mixed(_, _) => self.as_mixed().area(),
+            LargeMixed(_, _) => self.as_large_mixed().area(),
             GeometryCollection(_, _) => self.as_geometry_collection().area(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().area(),
             Rect(_) => self.as_rect().area(),
             Geometry(_) => self.as_geometry().area(),
+            LargeGeometry(_) => self.as_large_geometry().area(),
         }
     }
 }
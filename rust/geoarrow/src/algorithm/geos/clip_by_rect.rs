@@ -0,0 +1,297 @@
+use geo_traits::{CoordTrait, RectTrait};
+use geos::Geom;
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geos::scalar::GEOSGeometry;
+use crate::trait_::{ArrayAccessor, NativeGEOSGeometryAccessor};
+use crate::NativeArray;
+
+/// Clip geometries to an axis-aligned rectangle.
+///
+/// This uses GEOS's rectangle intersection algorithm, which is specialized for rectangular clip
+/// windows and is much faster than a general [intersection][super::BooleanOps::intersection].
+/// Lines and the parts of multi-geometries are clipped individually. Points on the boundary of the
+/// rectangle are kept.
+///
+/// GEOS does not guarantee that clipped polygons are valid: parts of a concave polygon may be
+/// joined by a zero-width bridge along the boundary of the rectangle. Use
+/// [`native::ClipByRect`][crate::algorithm::native::ClipByRect] when the output needs to pass
+/// [`IsValid`][crate::algorithm::geo::IsValid].
+///
+/// Clipping can change the geometry type (a polygon may be split into a multi polygon), so the
+/// output is always a [`GeometryArray`]. Rows that fall entirely outside the rectangle are empty in
+/// the output, as are rows whose input geometry or rectangle is null.
+///
+/// Use [`ClipByRectScalar`] to clip every row to the same rectangle.
+pub trait ClipByRect<Rhs> {
+    type Output;
+
+    fn clip_by_rect(&self, rhs: Rhs) -> Self::Output;
+}
+
+/// Clip every geometry in an array to the same axis-aligned rectangle.
+///
+/// See [`ClipByRect`] for details.
+pub trait ClipByRectScalar<G> {
+    type Output;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output;
+}
+
+fn rect_bounds(rect: &impl RectTrait<T = f64>) -> [f64; 4] {
+    let (min, max) = (rect.min(), rect.max());
+    [min.x(), min.y(), max.x(), max.y()]
+}
+
+fn clip_geometries<'a>(
+    array: &'a dyn NativeGEOSGeometryAccessor<'a>,
+    bounds_at: impl Fn(usize) -> Option<[f64; 4]>,
+) -> Result<GeometryArray> {
+    let mut buffer = Vec::with_capacity(array.len());
+    for i in 0..array.len() {
+        let clipped = match bounds_at(i) {
+            Some([xmin, ymin, xmax, ymax]) if array.is_valid(i) => {
+                let clipped = array
+                    .value_as_geometry(i)?
+                    .clip_by_rect(xmin, ymin, xmax, ymax)?;
+                if clipped.is_empty()? {
+                    None
+                } else {
+                    Some(GEOSGeometry::new(clipped))
+                }
+            }
+            _ => None,
+        };
+        buffer.push(clipped);
+    }
+
+    Ok(GeometryBuilder::from_nullable_geometries(
+        buffer.as_slice(),
+        array.coord_type(),
+        array.metadata(),
+        true,
+    )?
+    .finish())
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl ClipByRect<&RectArray> for $type {
+            type Output = Result<GeometryArray>;
+
+            fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+                if self.len() != rhs.len() {
+                    return Err(GeoArrowError::General(
+                        "Cannot clip arrays of different length".to_string(),
+                    ));
+                }
+                self.metadata()
+                    .check_crs_compatible(&rhs.metadata(), "clip_by_rect")?;
+
+                clip_geometries(self, |i| rhs.get(i).map(|rect| rect_bounds(&rect)))
+            }
+        }
+
+        impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for $type {
+            type Output = Result<GeometryArray>;
+
+            fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+                let bounds = rect_bounds(rhs);
+                clip_geometries(self, |_| Some(bounds))
+            }
+        }
+    };
+}
+
+iter_geos_impl!(PointArray);
+iter_geos_impl!(LineStringArray);
+iter_geos_impl!(MultiPointArray);
+iter_geos_impl!(MultiLineStringArray);
+iter_geos_impl!(PolygonArray);
+iter_geos_impl!(MultiPolygonArray);
+iter_geos_impl!(MixedGeometryArray);
+iter_geos_impl!(GeometryCollectionArray);
+iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
+
+impl ClipByRect<&RectArray> for RectArray {
+    type Output = Result<GeometryArray>;
+
+    fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+        ClipByRect::clip_by_rect(&PolygonArray::from(self.clone()), rhs)
+    }
+}
+
+impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for RectArray {
+    type Output = Result<GeometryArray>;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+        ClipByRectScalar::clip_by_rect(&PolygonArray::from(self.clone()), rhs)
+    }
+}
+
+macro_rules! impl_dyn {
+    ($self:expr, $rhs:expr, $trait:ident) => {{
+        use NativeType::*;
+
+        let arr = $self;
+        match arr.data_type() {
+            Point(_, _) => $trait::clip_by_rect(arr.as_point(), $rhs),
+            LineString(_, _) => $trait::clip_by_rect(arr.as_line_string(), $rhs),
+            LargeLineString(_, _) => $trait::clip_by_rect(arr.as_large_line_string(), $rhs),
+            Polygon(_, _) => $trait::clip_by_rect(arr.as_polygon(), $rhs),
+            LargePolygon(_, _) => $trait::clip_by_rect(arr.as_large_polygon(), $rhs),
+            MultiPoint(_, _) => $trait::clip_by_rect(arr.as_multi_point(), $rhs),
+            LargeMultiPoint(_, _) => $trait::clip_by_rect(arr.as_large_multi_point(), $rhs),
+            MultiLineString(_, _) => $trait::clip_by_rect(arr.as_multi_line_string(), $rhs),
+            LargeMultiLineString(_, _) => {
+                $trait::clip_by_rect(arr.as_large_multi_line_string(), $rhs)
+            }
+            MultiPolygon(_, _) => $trait::clip_by_rect(arr.as_multi_polygon(), $rhs),
+            LargeMultiPolygon(_, _) => $trait::clip_by_rect(arr.as_large_multi_polygon(), $rhs),
+            Mixed(_, _) => $trait::clip_by_rect(arr.as_mixed(), $rhs),
+            LargeMixed(_, _) => $trait::clip_by_rect(arr.as_large_mixed(), $rhs),
+            GeometryCollection(_, _) => $trait::clip_by_rect(arr.as_geometry_collection(), $rhs),
+            LargeGeometryCollection(_, _) => {
+                $trait::clip_by_rect(arr.as_large_geometry_collection(), $rhs)
+            }
+            Rect(_) => $trait::clip_by_rect(arr.as_rect(), $rhs),
+            Geometry(_) => $trait::clip_by_rect(arr.as_geometry(), $rhs),
+            LargeGeometry(_) => $trait::clip_by_rect(arr.as_large_geometry(), $rhs),
+        }
+    }};
+}
+
+impl ClipByRect<&RectArray> for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+        impl_dyn!(*self, rhs, ClipByRect)
+    }
+}
+
+impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+        impl_dyn!(*self, rhs, ClipByRectScalar)
+    }
+}
+
+impl ClipByRect<&RectArray> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+        if self.len() != rhs.len() {
+            return Err(GeoArrowError::General(
+                "Cannot clip arrays of different length".to_string(),
+            ));
+        }
+
+        let mut offset = 0;
+        let mut chunks = vec![];
+        for chunk in self.geometry_chunks() {
+            let rects = rhs.slice(offset, chunk.len());
+            offset += chunk.len();
+            chunks.push(ClipByRect::clip_by_rect(&chunk.as_ref(), &rects)?);
+        }
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.geometry_chunks()
+                .iter()
+                .map(|chunk| ClipByRectScalar::clip_by_rect(&chunk.as_ref(), rhs))
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::test::polygon::p_array;
+    use geo::{line_string, polygon, HasDimensions};
+
+    #[test]
+    fn clip_polygons_to_scalar_rect() {
+        let arr = p_array();
+        let rect = geo::Rect::new((-110., 42.), (-100., 50.));
+        let clipped = ClipByRectScalar::clip_by_rect(&arr, &rect).unwrap();
+        assert_eq!(clipped.len(), arr.len());
+
+        let outside = geo::Rect::new((0., 0.), (1., 1.));
+        let clipped = ClipByRectScalar::clip_by_rect(&arr, &outside).unwrap();
+        assert!(clipped.iter_geo_values().all(|g| g.is_empty()));
+    }
+
+    #[test]
+    fn clip_broadcast_rects() {
+        let lines: LineStringArray = (
+            vec![
+                line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+                line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let rects: RectArray = (
+            vec![
+                geo::Rect::new((2., 0.), (4., 10.)),
+                geo::Rect::new((20., 20.), (30., 30.)),
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let clipped = ClipByRect::clip_by_rect(&lines, &rects).unwrap();
+        assert!(clipped.value_as_geo(1).is_empty());
+        assert_eq!(
+            clipped.value_as_geo(0),
+            geo::Geometry::MultiLineString(geo::MultiLineString::new(vec![
+                line_string![(x: 2., y: 5.), (x: 4., y: 5.)]
+            ]))
+        );
+
+        // Concave polygon clipped into two parts stays valid
+        let u_shape: PolygonArray = (
+            vec![polygon![
+                (x: 0., y: 0.),
+                (x: 3., y: 0.),
+                (x: 3., y: 3.),
+                (x: 2., y: 3.),
+                (x: 2., y: 1.),
+                (x: 1., y: 1.),
+                (x: 1., y: 3.),
+                (x: 0., y: 3.),
+                (x: 0., y: 0.),
+            ]]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let window = geo::Rect::new((0., 2.), (3., 3.));
+        let clipped = ClipByRectScalar::clip_by_rect(&u_shape, &window).unwrap();
+        assert!(matches!(
+            clipped.value_as_geo(0),
+            geo::Geometry::MultiPolygon(mp) if mp.0.len() == 2
+        ));
+    }
+}
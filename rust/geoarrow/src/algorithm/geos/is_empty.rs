@@ -37,6 +37,14 @@ iter_geos_impl!(MixedGeometryArray);
 iter_geos_impl!(GeometryCollectionArray);
 iter_geos_impl!(RectArray);
 iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
 
 impl IsEmpty for &dyn NativeArray {
     type Output = Result<BooleanArray>;
@@ -47,13 +55,22 @@ impl IsEmpty for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => IsEmpty::is_empty(self.as_point()),
             LineString(_, _) => IsEmpty::is_empty(self.as_line_string()),
+            LargeLineString(_, _) => IsEmpty::is_empty(self.as_large_line_string()),
             Polygon(_, _) => IsEmpty::is_empty(self.as_polygon()),
+            LargePolygon(_, _) => IsEmpty::is_empty(self.as_large_polygon()),
             MultiPoint(_, _) => IsEmpty::is_empty(self.as_multi_point()),
+            LargeMultiPoint(_, _) => IsEmpty::is_empty(self.as_large_multi_point()),
             MultiLineString(_, _) => IsEmpty::is_empty(self.as_multi_line_string()),
+            LargeMultiLineString(_, _) => IsEmpty::is_empty(self.as_large_multi_line_string()),
             MultiPolygon(_, _) => IsEmpty::is_empty(self.as_multi_polygon()),
+            LargeMultiPolygon(_, _) => IsEmpty::is_empty(self.as_large_multi_polygon()),
+            Mixed(_, _) => IsEmpty::is_empty(self.as_mixed()),
+            LargeMixed(_, _) => IsEmpty::is_empty(self.as_large_mixed()),
             GeometryCollection(_, _) => IsEmpty::is_empty(self.as_geometry_collection()),
+            LargeGeometryCollection(_, _) => IsEmpty::is_empty(self.as_large_geometry_collection()),
             Rect(_) => IsEmpty::is_empty(self.as_rect()),
             Geometry(_) => IsEmpty::is_empty(self.as_geometry()),
+            LargeGeometry(_) => IsEmpty::is_empty(self.as_large_geometry()),
         }
     }
 }
@@ -37,6 +37,14 @@ iter_geos_impl!(MixedGeometryArray);
 iter_geos_impl!(GeometryCollectionArray);
 iter_geos_impl!(RectArray);
 iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
 
 impl IsRing for &dyn NativeArray {
     type Output = Result<BooleanArray>;
@@ -47,13 +55,22 @@ impl IsRing for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().is_ring(),
             LineString(_, _) => self.as_line_string().is_ring(),
+            LargeLineString(_, _) => self.as_large_line_string().is_ring(),
             Polygon(_, _) => self.as_polygon().is_ring(),
+            LargePolygon(_, _) => self.as_large_polygon().is_ring(),
             MultiPoint(_, _) => self.as_multi_point().is_ring(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().is_ring(),
             MultiLineString(_, _) => self.as_multi_line_string().is_ring(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().is_ring(),
             MultiPolygon(_, _) => self.as_multi_polygon().is_ring(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().is_ring(),
+            Mixed(_, _) => self.as_mixed().is_ring(),
+            LargeMixed(_, _) => self.as_large_mixed().is_ring(),
             GeometryCollection(_, _) => self.as_geometry_collection().is_ring(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().is_ring(),
             Rect(_) => self.as_rect().is_ring(),
             Geometry(_) => self.as_geometry().is_ring(),
+            LargeGeometry(_) => self.as_large_geometry().is_ring(),
         }
     }
 }
@@ -37,6 +37,14 @@ iter_geos_impl!(MixedGeometryArray);
 iter_geos_impl!(GeometryCollectionArray);
 iter_geos_impl!(RectArray);
 iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
 
 impl IsSimple for &dyn NativeArray {
     type Output = Result<BooleanArray>;
@@ -47,13 +55,22 @@ impl IsSimple for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().is_simple(),
             LineString(_, _) => self.as_line_string().is_simple(),
+            LargeLineString(_, _) => self.as_large_line_string().is_simple(),
             Polygon(_, _) => self.as_polygon().is_simple(),
+            LargePolygon(_, _) => self.as_large_polygon().is_simple(),
             MultiPoint(_, _) => self.as_multi_point().is_simple(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().is_simple(),
             MultiLineString(_, _) => self.as_multi_line_string().is_simple(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().is_simple(),
             MultiPolygon(_, _) => self.as_multi_polygon().is_simple(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().is_simple(),
+            Mixed(_, _) => self.as_mixed().is_simple(),
+            LargeMixed(_, _) => self.as_large_mixed().is_simple(),
             GeometryCollection(_, _) => self.as_geometry_collection().is_simple(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().is_simple(),
             Rect(_) => self.as_rect().is_simple(),
             Geometry(_) => self.as_geometry().is_simple(),
+            LargeGeometry(_) => self.as_large_geometry().is_simple(),
         }
     }
 }
@@ -38,6 +38,14 @@ iter_geos_impl!(MixedGeometryArray);
 iter_geos_impl!(GeometryCollectionArray);
 iter_geos_impl!(RectArray);
 iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
 
 impl IsValid for &dyn NativeArray {
     type Output = Result<BooleanArray>;
@@ -48,13 +56,22 @@ impl IsValid for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => IsValid::is_valid(self.as_point()),
             LineString(_, _) => IsValid::is_valid(self.as_line_string()),
+            LargeLineString(_, _) => IsValid::is_valid(self.as_large_line_string()),
             Polygon(_, _) => IsValid::is_valid(self.as_polygon()),
+            LargePolygon(_, _) => IsValid::is_valid(self.as_large_polygon()),
             MultiPoint(_, _) => IsValid::is_valid(self.as_multi_point()),
+            LargeMultiPoint(_, _) => IsValid::is_valid(self.as_large_multi_point()),
             MultiLineString(_, _) => IsValid::is_valid(self.as_multi_line_string()),
+            LargeMultiLineString(_, _) => IsValid::is_valid(self.as_large_multi_line_string()),
             MultiPolygon(_, _) => IsValid::is_valid(self.as_multi_polygon()),
+            LargeMultiPolygon(_, _) => IsValid::is_valid(self.as_large_multi_polygon()),
+            Mixed(_, _) => IsValid::is_valid(self.as_mixed()),
+            LargeMixed(_, _) => IsValid::is_valid(self.as_large_mixed()),
             GeometryCollection(_, _) => IsValid::is_valid(self.as_geometry_collection()),
+            LargeGeometryCollection(_, _) => IsValid::is_valid(self.as_large_geometry_collection()),
             Rect(_) => IsValid::is_valid(self.as_rect()),
             Geometry(_) => IsValid::is_valid(self.as_geometry()),
+            LargeGeometry(_) => IsValid::is_valid(self.as_large_geometry()),
         }
     }
 }
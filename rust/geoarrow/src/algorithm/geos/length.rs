@@ -45,6 +45,14 @@ iter_geos_impl!(MixedGeometryArray);
 iter_geos_impl!(GeometryCollectionArray);
 iter_geos_impl!(RectArray);
 iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
 
 impl Length for &dyn NativeArray {
     type Output = Result<Float64Array>;
@@ -55,13 +63,22 @@ impl Length for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().length(),
             LineString(_, _) => self.as_line_string().length(),
+            LargeLineString(_, _) => self.as_large_line_string().length(),
             Polygon(_, _) => self.as_polygon().length(),
+            LargePolygon(_, _) => self.as_large_polygon().length(),
             MultiPoint(_, _) => self.as_multi_point().length(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().length(),
             MultiLineString(_, _) => self.as_multi_line_string().length(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().length(),
             MultiPolygon(_, _) => self.as_multi_polygon().length(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().length(),
+            Mixed(_, _) => self.as_mixed().length(),
+            LargeMixed(_, _) => self.as_large_mixed().length(),
             GeometryCollection(_, _) => self.as_geometry_collection().length(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().length(),
             Rect(_) => self.as_rect().length(),
             Geometry(_) => self.as_geometry().length(),
+            LargeGeometry(_) => self.as_large_geometry().length(),
         }
     }
 }
@@ -0,0 +1,84 @@
+use crate::algorithm::geos::util::try_unary_multi_line_string;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+use geos::Geom;
+
+/// Sews together the linework of each geometry, merging line strings that touch at their
+/// endpoints into the longest possible line strings.
+///
+/// This operates on each row independently. Line strings are only merged at nodes of degree two:
+/// where three or more lines meet, the lines are left as separate parts. Line direction is
+/// preserved where possible but lines may be reversed to join them.
+pub trait LineMerge {
+    type Output;
+
+    fn line_merge(&self) -> Self::Output;
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl LineMerge for $type {
+            type Output = Result<MultiLineStringArray>;
+
+            fn line_merge(&self) -> Self::Output {
+                try_unary_multi_line_string(self, |g| g.line_merge())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(MultiLineStringArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+
+impl LineMerge for &dyn NativeArray {
+    type Output = Result<MultiLineStringArray>;
+
+    fn line_merge(&self) -> Self::Output {
+        use NativeType::*;
+
+        match self.data_type() {
+            LineString(_, _) => self.as_line_string().line_merge(),
+            LargeLineString(_, _) => self.as_large_line_string().line_merge(),
+            MultiLineString(_, _) => self.as_multi_line_string().line_merge(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().line_merge(),
+            _ => Err(GeoArrowError::IncorrectType("line_merge".into())),
+        }
+    }
+}
+
+impl<G: NativeArray> LineMerge for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedGeometryArray<MultiLineStringArray>>;
+
+    fn line_merge(&self) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.as_ref().line_merge())?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::{line_string, MultiLineString};
+    use geo_traits::MultiLineStringTrait;
+
+    #[test]
+    fn merge_touching_lines() {
+        let geom = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 5., y: 5.), (x: 6., y: 5.)],
+        ]);
+        let arr: MultiLineStringArray = (vec![geom].as_slice(), Dimension::XY).into();
+        let merged = arr.line_merge().unwrap();
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged.value(0).num_line_strings(), 2);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::algorithm::geos::util::try_unary_geometry;
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::NativeArray;
+use geos::{Geom, MakeValidMethod, MakeValidParams};
+
+/// The strategy [`MakeValid`] uses to repair invalid geometries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MakeValidMode {
+    /// Build valid geometries by first extracting all lines, noding that linework together and
+    /// then building a valid output from that linework.
+    #[default]
+    Linework,
+
+    /// First make all rings valid, then merge shells and subtract holes from shells to generate a
+    /// valid result. This assumes that holes and shells are correctly categorized.
+    Structure,
+}
+
+impl From<MakeValidMode> for MakeValidMethod {
+    fn from(mode: MakeValidMode) -> Self {
+        match mode {
+            MakeValidMode::Linework => MakeValidMethod::Linework,
+            MakeValidMode::Structure => MakeValidMethod::Structure,
+        }
+    }
+}
+
+/// Repair invalid geometries without losing any of their vertices, using the strategy selected
+/// by [`MakeValidMode`].
+///
+/// With `keep_collapsed`, the structure method keeps components that collapsed to a lower
+/// dimension (e.g. a polygon collapsed to a line); otherwise they are dropped. It has no effect
+/// on the linework method.
+///
+/// Geometries that are already valid are returned unchanged. Because repairing can change the
+/// geometry type (a self-intersecting polygon may become a multi polygon, or a geometry
+/// collection when collapsed parts are kept), the output is always a [`GeometryArray`].
+///
+/// There is no pure-Rust implementation of this trait, so repair is only available with the
+/// `geos` feature. It is also exported from [`algorithm::geo`][crate::algorithm::geo], next to
+/// the native [`IsValid`][crate::algorithm::geo::IsValid].
+pub trait MakeValid {
+    type Output;
+
+    fn make_valid(&self, mode: MakeValidMode, keep_collapsed: bool) -> Self::Output;
+}
+
+fn make_valid_params(mode: MakeValidMode, keep_collapsed: bool) -> Result<MakeValidParams> {
+    let mut params = MakeValidParams::new()?;
+    params.set_method(mode.into())?;
+    params.set_keep_collapsed(keep_collapsed)?;
+    Ok(params)
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl MakeValid for $type {
+            type Output = Result<GeometryArray>;
+
+            fn make_valid(&self, mode: MakeValidMode, keep_collapsed: bool) -> Self::Output {
+                let params = make_valid_params(mode, keep_collapsed)?;
+                try_unary_geometry(self, |g| g.make_valid_with_params(&params))
+            }
+        }
+    };
+}
+
+iter_geos_impl!(PointArray);
+iter_geos_impl!(LineStringArray);
+iter_geos_impl!(MultiPointArray);
+iter_geos_impl!(MultiLineStringArray);
+iter_geos_impl!(PolygonArray);
+iter_geos_impl!(MultiPolygonArray);
+iter_geos_impl!(MixedGeometryArray);
+iter_geos_impl!(GeometryCollectionArray);
+iter_geos_impl!(GeometryArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(LargeMultiPointArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+iter_geos_impl!(LargePolygonArray);
+iter_geos_impl!(LargeMultiPolygonArray);
+iter_geos_impl!(LargeMixedGeometryArray);
+iter_geos_impl!(LargeGeometryCollectionArray);
+iter_geos_impl!(LargeGeometryArray);
+
+impl MakeValid for RectArray {
+    type Output = Result<GeometryArray>;
+
+    fn make_valid(&self, mode: MakeValidMode, keep_collapsed: bool) -> Self::Output {
+        PolygonArray::from(self.clone()).make_valid(mode, keep_collapsed)
+    }
+}
+
+impl MakeValid for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn make_valid(&self, mode: MakeValidMode, keep_collapsed: bool) -> Self::Output {
+        use NativeType::*;
+
+        match self.data_type() {
+            Point(_, _) => self.as_point().make_valid(mode, keep_collapsed),
+            LineString(_, _) => self.as_line_string().make_valid(mode, keep_collapsed),
+            LargeLineString(_, _) => self.as_large_line_string().make_valid(mode, keep_collapsed),
+            Polygon(_, _) => self.as_polygon().make_valid(mode, keep_collapsed),
+            LargePolygon(_, _) => self.as_large_polygon().make_valid(mode, keep_collapsed),
+            MultiPoint(_, _) => self.as_multi_point().make_valid(mode, keep_collapsed),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().make_valid(mode, keep_collapsed),
+            MultiLineString(_, _) => self.as_multi_line_string().make_valid(mode, keep_collapsed),
+            LargeMultiLineString(_, _) => self
+                .as_large_multi_line_string()
+                .make_valid(mode, keep_collapsed),
+            MultiPolygon(_, _) => self.as_multi_polygon().make_valid(mode, keep_collapsed),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .make_valid(mode, keep_collapsed),
+            Mixed(_, _) => self.as_mixed().make_valid(mode, keep_collapsed),
+            LargeMixed(_, _) => self.as_large_mixed().make_valid(mode, keep_collapsed),
+            GeometryCollection(_, _) => self
+                .as_geometry_collection()
+                .make_valid(mode, keep_collapsed),
+            LargeGeometryCollection(_, _) => self
+                .as_large_geometry_collection()
+                .make_valid(mode, keep_collapsed),
+            Rect(_) => self.as_rect().make_valid(mode, keep_collapsed),
+            Geometry(_) => self.as_geometry().make_valid(mode, keep_collapsed),
+            LargeGeometry(_) => self.as_large_geometry().make_valid(mode, keep_collapsed),
+        }
+    }
+}
+
+impl<G: NativeArray> MakeValid for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn make_valid(&self, mode: MakeValidMode, keep_collapsed: bool) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(self.try_map(|chunk| {
+            chunk.as_ref().make_valid(mode, keep_collapsed)
+        })?))
+    }
+}
+
+impl MakeValid for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn make_valid(&self, mode: MakeValidMode, keep_collapsed: bool) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.geometry_chunks()
+                .iter()
+                .map(|chunk| chunk.as_ref().make_valid(mode, keep_collapsed))
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::geos::IsValid;
+    use crate::datatypes::Dimension;
+    use geo::polygon;
+
+    #[test]
+    fn make_valid_bowtie() {
+        let bowtie = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 0.),
+            (x: 0., y: 2.),
+            (x: 0., y: 0.),
+        ];
+        let arr: PolygonArray = (vec![bowtie].as_slice(), Dimension::XY).into();
+        assert!(!IsValid::is_valid(&arr).unwrap().value(0));
+
+        for mode in [MakeValidMode::Linework, MakeValidMode::Structure] {
+            let repaired = arr.make_valid(mode, false).unwrap();
+            assert_eq!(repaired.len(), 1);
+            assert!(IsValid::is_valid(&repaired).unwrap().value(0));
+        }
+    }
+}
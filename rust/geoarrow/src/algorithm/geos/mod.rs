@@ -3,18 +3,28 @@
 mod area;
 mod bool_ops;
 mod buffer;
+mod clip_by_rect;
 mod is_empty;
 mod is_ring;
 mod is_simple;
 mod is_valid;
 mod length;
+mod line_merge;
+mod make_valid;
+mod node;
+mod polygonize;
 mod util;
 
 pub use area::Area;
 pub use bool_ops::{BooleanOps, BooleanOpsScalar};
 pub use buffer::Buffer;
+pub use clip_by_rect::{ClipByRect, ClipByRectScalar};
 pub use is_empty::IsEmpty;
 pub use is_ring::IsRing;
 pub use is_simple::IsSimple;
 pub use is_valid::IsValid;
 pub use length::Length;
+pub use line_merge::LineMerge;
+pub use make_valid::{MakeValid, MakeValidMode};
+pub use node::Node;
+pub use polygonize::Polygonize;
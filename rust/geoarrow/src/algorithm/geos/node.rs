@@ -0,0 +1,81 @@
+use crate::algorithm::geos::util::try_unary_multi_line_string;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::NativeArray;
+use geos::Geom;
+
+/// Nodes the linework of each geometry, splitting line strings wherever they intersect.
+///
+/// This operates on each row independently; the output for each row is a multi line string whose
+/// parts meet only at their endpoints. Duplicate segments are collapsed. To node an entire network
+/// stored across many rows, union the rows into a single geometry first.
+pub trait Node {
+    type Output;
+
+    fn node(&self) -> Self::Output;
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl Node for $type {
+            type Output = Result<MultiLineStringArray>;
+
+            fn node(&self) -> Self::Output {
+                try_unary_multi_line_string(self, |g| g.node())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(MultiLineStringArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+
+impl Node for &dyn NativeArray {
+    type Output = Result<MultiLineStringArray>;
+
+    fn node(&self) -> Self::Output {
+        use NativeType::*;
+
+        match self.data_type() {
+            LineString(_, _) => self.as_line_string().node(),
+            LargeLineString(_, _) => self.as_large_line_string().node(),
+            MultiLineString(_, _) => self.as_multi_line_string().node(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().node(),
+            _ => Err(GeoArrowError::IncorrectType("node".into())),
+        }
+    }
+}
+
+impl<G: NativeArray> Node for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedGeometryArray<MultiLineStringArray>>;
+
+    fn node(&self) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.as_ref().node())?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::trait_::ArrayAccessor;
+    use geo::{line_string, MultiLineString};
+    use geo_traits::MultiLineStringTrait;
+
+    #[test]
+    fn node_crossing_lines() {
+        let geom = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.)],
+            line_string![(x: 0., y: 2.), (x: 2., y: 0.)],
+        ]);
+        let arr: MultiLineStringArray = (vec![geom].as_slice(), Dimension::XY).into();
+        let noded = arr.node().unwrap();
+        assert_eq!(noded.value(0).num_line_strings(), 4);
+    }
+}
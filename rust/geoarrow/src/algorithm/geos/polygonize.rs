@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::chunked_array::ChunkedGeometryArray;
+use crate::datatypes::{Dimension, NativeType};
+use crate::error::{GeoArrowError, Result};
+use crate::io::geos::scalar::GEOSConstPolygon;
+use crate::trait_::NativeGEOSGeometryAccessor;
+use crate::NativeArray;
+use geos::Geom;
+
+/// Builds polygons from the linework of all geometries in the input.
+///
+/// Unlike most operations, this does not operate row-by-row: the linework of every non-null row
+/// is treated as the edges of a single planar graph and each face of that graph becomes one row
+/// of the output, so the output length is unrelated to the input length. The input linework must
+/// be correctly noded (see [`Node`][super::Node]); lines that do not form closed rings (dangles
+/// and cut edges) do not contribute to any polygon.
+pub trait Polygonize {
+    type Output;
+
+    fn polygonize(&self) -> Self::Output;
+}
+
+macro_rules! iter_geos_impl {
+    ($type:ty) => {
+        impl Polygonize for $type {
+            type Output = Result<PolygonArray>;
+
+            fn polygonize(&self) -> Self::Output {
+                let mut geoms = Vec::with_capacity(self.len());
+                collect_geometries(self, &mut geoms)?;
+                polygonize_geometries(&geoms, self.dimension(), self.coord_type(), self.metadata())
+            }
+        }
+    };
+}
+
+iter_geos_impl!(LineStringArray);
+iter_geos_impl!(LargeLineStringArray);
+iter_geos_impl!(MultiLineStringArray);
+iter_geos_impl!(LargeMultiLineStringArray);
+
+impl Polygonize for &dyn NativeArray {
+    type Output = Result<PolygonArray>;
+
+    fn polygonize(&self) -> Self::Output {
+        let mut geoms = Vec::with_capacity(self.len());
+        collect_geometries(as_lineal_accessor(*self)?, &mut geoms)?;
+        polygonize_geometries(&geoms, self.dimension(), self.coord_type(), self.metadata())
+    }
+}
+
+impl<G: NativeArray> Polygonize for ChunkedGeometryArray<G> {
+    type Output = Result<PolygonArray>;
+
+    /// Polygonizes the linework of all chunks together into a single array.
+    fn polygonize(&self) -> Self::Output {
+        let mut geoms = Vec::with_capacity(self.len());
+        for chunk in self.chunks.iter() {
+            collect_geometries(as_lineal_accessor(chunk.as_ref())?, &mut geoms)?;
+        }
+
+        let (dim, coord_type, metadata) = match self.chunks.first() {
+            Some(chunk) => (chunk.dimension(), chunk.coord_type(), chunk.metadata()),
+            None => (Dimension::XY, Default::default(), Default::default()),
+        };
+        polygonize_geometries(&geoms, dim, coord_type, metadata)
+    }
+}
+
+fn as_lineal_accessor<'a>(
+    array: &'a dyn NativeArray,
+) -> Result<&'a dyn NativeGEOSGeometryAccessor<'a>> {
+    use NativeType::*;
+
+    match array.data_type() {
+        LineString(_, _) => Ok(array.as_line_string()),
+        LargeLineString(_, _) => Ok(array.as_large_line_string()),
+        MultiLineString(_, _) => Ok(array.as_multi_line_string()),
+        LargeMultiLineString(_, _) => Ok(array.as_large_multi_line_string()),
+        _ => Err(GeoArrowError::IncorrectType("polygonize".into())),
+    }
+}
+
+fn collect_geometries<'a>(
+    array: &'a dyn NativeGEOSGeometryAccessor<'a>,
+    geoms: &mut Vec<geos::Geometry>,
+) -> Result<()> {
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            geoms.push(array.value_as_geometry(i)?);
+        }
+    }
+    Ok(())
+}
+
+fn polygonize_geometries(
+    geoms: &[geos::Geometry],
+    dim: Dimension,
+    coord_type: CoordType,
+    metadata: Arc<ArrayMetadata>,
+) -> Result<PolygonArray> {
+    let mut builder = PolygonBuilder::new_with_options(dim, coord_type, metadata);
+    if geoms.is_empty() {
+        return Ok(builder.finish());
+    }
+
+    let collection = geos::Geometry::polygonize(geoms)?;
+    for i in 0..collection.get_num_geometries()? {
+        let polygon = GEOSConstPolygon::new_unchecked(collection.get_geometry_n(i)?);
+        builder.push_polygon(Some(&polygon))?;
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::trait_::ArrayAccessor;
+    use geo::{line_string, LineString};
+    use geo_traits::{LineStringTrait, PolygonTrait};
+
+    #[test]
+    fn polygonize_square_from_edges() {
+        let edges: Vec<LineString> = vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 1., y: 1.), (x: 0., y: 1.)],
+            line_string![(x: 0., y: 1.), (x: 0., y: 0.)],
+            // A dangle that does not bound any face
+            line_string![(x: 1., y: 1.), (x: 2., y: 2.)],
+        ];
+        let arr: LineStringArray = (edges.as_slice(), Dimension::XY).into();
+        let polygons = arr.polygonize().unwrap();
+        assert_eq!(polygons.len(), 1);
+        let polygon = polygons.value(0);
+        assert_eq!(polygon.num_interiors(), 0);
+        assert_eq!(polygon.exterior().unwrap().num_coords(), 5);
+    }
+}
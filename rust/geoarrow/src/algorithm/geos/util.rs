@@ -1,11 +1,14 @@
 use arrow_array::{ArrowPrimitiveType, PrimitiveArray};
 use arrow_buffer::BufferBuilder;
 
-use crate::array::{GeometryArray, GeometryBuilder, PolygonArray};
+use crate::array::{
+    GeometryArray, GeometryBuilder, MultiLineStringArray, MultiLineStringBuilder, PolygonArray,
+};
 use crate::datatypes::Dimension;
 use crate::error::GeoArrowError;
 use crate::io::geos::scalar::{GEOSGeometry, GEOSPolygon};
 use crate::trait_::NativeGEOSGeometryAccessor;
+use geos::{Geom, GeometryTypes};
 
 // Note: This is derived from arrow-rs here:
 // https://github.com/apache/arrow-rs/blob/3ed7cc61d4157263ef2ab5c2d12bc7890a5315b3/arrow-array/src/array/primitive_array.rs#L806-L830
@@ -103,3 +106,46 @@ where
     )?
     .finish())
 }
+
+/// Apply a GEOS operation whose output is linework to each geometry, collecting the results into
+/// a [`MultiLineStringArray`].
+///
+/// Empty outputs (GEOS returns `GEOMETRYCOLLECTION EMPTY` when no linework remains) are stored as
+/// empty multi line strings.
+pub(super) fn try_unary_multi_line_string<'a, F>(
+    array: &'a dyn NativeGEOSGeometryAccessor<'a>,
+    op: F,
+) -> std::result::Result<MultiLineStringArray, GeoArrowError>
+where
+    F: Fn(geos::Geometry) -> std::result::Result<geos::Geometry, geos::Error>,
+{
+    let len = array.len();
+
+    let mut buffer = vec![None; len];
+
+    let f = |idx| {
+        let geom = op(unsafe { array.value_as_geometry_unchecked(idx)? })?;
+        let geom = if geom.is_empty()?
+            && !matches!(geom.geometry_type(), GeometryTypes::MultiLineString)
+        {
+            geos::Geometry::create_empty_collection(GeometryTypes::MultiLineString)?
+        } else {
+            geom
+        };
+        buffer[idx] = Some(GEOSGeometry::new(geom));
+        Ok::<_, geos::Error>(())
+    };
+
+    match array.nulls() {
+        Some(nulls) => nulls.try_for_each_valid_idx(f)?,
+        None => (0..len).try_for_each(f)?,
+    }
+
+    Ok(MultiLineStringBuilder::from_nullable_geometries(
+        buffer.as_slice(),
+        array.dimension(),
+        array.coord_type(),
+        array.metadata().clone(),
+    )?
+    .finish())
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use arrow_array::UInt64Array;
+use geo::{coord, LineString, Point, Polygon};
+use h3o::{CellIndex, LatLng};
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::{PointArray, PointBuilder, PolygonArray, PolygonBuilder};
+use crate::chunked_array::{
+    ChunkedArray, ChunkedGeometryArray, ChunkedPointArray, ChunkedPolygonArray,
+};
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+
+/// The boundaries of H3 cells as polygons.
+///
+/// Boundaries are longitude/latitude in degrees. Cells crossing the antimeridian are not split,
+/// so their polygons span the whole range of longitudes. Null cells give null polygons and
+/// invalid cell indices are an error.
+pub trait H3CellBoundary {
+    type Output;
+
+    fn h3_cell_boundary(&self) -> Self::Output;
+}
+
+/// The centers of H3 cells as points.
+///
+/// Centers are longitude/latitude in degrees. Null cells give null points and invalid cell
+/// indices are an error.
+pub trait H3CellCentroid {
+    type Output;
+
+    fn h3_cell_centroid(&self) -> Self::Output;
+}
+
+fn wgs84_metadata() -> Arc<ArrayMetadata> {
+    Arc::new(ArrayMetadata::from_authority_code("EPSG:4326".to_string()))
+}
+
+fn parse_cells<T>(array: &UInt64Array, op: impl Fn(CellIndex) -> T) -> Result<Vec<Option<T>>> {
+    array
+        .iter()
+        .map(|maybe_cell| {
+            maybe_cell
+                .map(|cell| {
+                    let cell = CellIndex::try_from(cell)
+                        .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                    Ok(op(cell))
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+fn to_coord(lat_lng: LatLng) -> geo::Coord {
+    coord! { x: lat_lng.lng(), y: lat_lng.lat() }
+}
+
+impl H3CellBoundary for UInt64Array {
+    type Output = Result<PolygonArray>;
+
+    fn h3_cell_boundary(&self) -> Self::Output {
+        let polygons = parse_cells(self, |cell| {
+            let exterior = cell.boundary().iter().copied().map(to_coord).collect();
+            Polygon::new(LineString::new(exterior), vec![])
+        })?;
+        Ok(PolygonBuilder::from_nullable_polygons(
+            &polygons,
+            Dimension::XY,
+            Default::default(),
+            wgs84_metadata(),
+        )
+        .finish())
+    }
+}
+
+impl H3CellBoundary for ChunkedArray<UInt64Array> {
+    type Output = Result<ChunkedPolygonArray>;
+
+    fn h3_cell_boundary(&self) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.h3_cell_boundary())?,
+        ))
+    }
+}
+
+impl H3CellCentroid for UInt64Array {
+    type Output = Result<PointArray>;
+
+    fn h3_cell_centroid(&self) -> Self::Output {
+        let points = parse_cells(self, |cell| Point(to_coord(LatLng::from(cell))))?;
+        Ok(PointBuilder::from_nullable_points(
+            points.iter().map(Option::as_ref),
+            Dimension::XY,
+            Default::default(),
+            wgs84_metadata(),
+        )
+        .finish())
+    }
+}
+
+impl H3CellCentroid for ChunkedArray<UInt64Array> {
+    type Output = Result<ChunkedPointArray>;
+
+    fn h3_cell_centroid(&self) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.try_map(|chunk| chunk.h3_cell_centroid())?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::h3::{Resolution, ToH3Cell};
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+
+    #[test]
+    fn cell_boundary_and_centroid() {
+        let cells = UInt64Array::from(vec![Some(0x872830828ffffff), None]);
+
+        let boundaries = cells.h3_cell_boundary().unwrap();
+        assert!(boundaries.is_null(1));
+        // Hexagons have six vertices, plus the closing coordinate
+        assert_eq!(boundaries.value_as_geo(0).exterior().0.len(), 7);
+
+        let centroids = cells.h3_cell_centroid().unwrap();
+        assert!(centroids.is_null(1));
+        let round_trip = centroids.to_h3_cell(Resolution::Seven).unwrap();
+        assert_eq!(round_trip.value(0), cells.value(0));
+
+        assert!(UInt64Array::from(vec![1]).h3_cell_boundary().is_err());
+    }
+}
@@ -0,0 +1,14 @@
+//! Index geometries with [H3](https://h3geo.org/) cells.
+//!
+//! This binds to [h3o], a pure-Rust implementation of H3. Cells are represented as their 64-bit
+//! indices in a `UInt64Array`. Geometries must be longitude/latitude in degrees.
+
+mod cell;
+mod polyfill;
+mod to_cell;
+
+pub use cell::{H3CellBoundary, H3CellCentroid};
+pub use h3o::geom::ContainmentMode;
+pub use h3o::Resolution;
+pub use polyfill::H3Polyfill;
+pub use to_cell::ToH3Cell;
@@ -0,0 +1,172 @@
+use arrow_array::builder::{ListBuilder, UInt64Builder};
+use arrow_array::ListArray;
+use h3o::geom::{ContainmentMode, TilerBuilder};
+use h3o::Resolution;
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Fill polygons with the H3 cells that cover them.
+///
+/// Each row of the output is a list of the cell indices covering the polygon (or all parts of
+/// the multi polygon) in that row. `containment` decides which cells along the boundary are
+/// included. Polygons must be longitude/latitude in degrees. Null geometries give null lists.
+pub trait H3Polyfill {
+    type Output;
+
+    fn h3_polyfill(&self, resolution: Resolution, containment: ContainmentMode) -> Self::Output;
+}
+
+fn polyfill<P: IntoIterator<Item = geo::Polygon>>(
+    geometries: impl Iterator<Item = Option<P>>,
+    resolution: Resolution,
+    containment: ContainmentMode,
+) -> Result<ListArray> {
+    let mut builder = ListBuilder::new(UInt64Builder::new());
+    for geometry in geometries {
+        let Some(polygons) = geometry else {
+            builder.append_null();
+            continue;
+        };
+
+        let mut tiler = TilerBuilder::new(resolution)
+            .containment_mode(containment)
+            .build();
+        for polygon in polygons {
+            tiler
+                .add(polygon)
+                .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        }
+        for cell in tiler.into_coverage() {
+            builder.values().append_value(u64::from(cell));
+        }
+        builder.append(true);
+    }
+    Ok(builder.finish())
+}
+
+macro_rules! impl_polygon {
+    ($type:ty) => {
+        impl H3Polyfill for $type {
+            type Output = Result<ListArray>;
+
+            fn h3_polyfill(
+                &self,
+                resolution: Resolution,
+                containment: ContainmentMode,
+            ) -> Self::Output {
+                polyfill(
+                    self.iter_geo()
+                        .map(|maybe_polygon| maybe_polygon.map(|polygon| [polygon])),
+                    resolution,
+                    containment,
+                )
+            }
+        }
+    };
+}
+
+impl_polygon!(PolygonArray);
+impl_polygon!(LargePolygonArray);
+
+macro_rules! impl_multi_polygon {
+    ($type:ty) => {
+        impl H3Polyfill for $type {
+            type Output = Result<ListArray>;
+
+            fn h3_polyfill(
+                &self,
+                resolution: Resolution,
+                containment: ContainmentMode,
+            ) -> Self::Output {
+                polyfill(
+                    self.iter_geo()
+                        .map(|maybe_multi_polygon| maybe_multi_polygon.map(|polygons| polygons.0)),
+                    resolution,
+                    containment,
+                )
+            }
+        }
+    };
+}
+
+impl_multi_polygon!(MultiPolygonArray);
+impl_multi_polygon!(LargeMultiPolygonArray);
+
+impl H3Polyfill for RectArray {
+    type Output = Result<ListArray>;
+
+    fn h3_polyfill(&self, resolution: Resolution, containment: ContainmentMode) -> Self::Output {
+        polyfill(
+            self.iter_geo()
+                .map(|maybe_rect| maybe_rect.map(|rect| [rect.to_polygon()])),
+            resolution,
+            containment,
+        )
+    }
+}
+
+impl H3Polyfill for &dyn NativeArray {
+    type Output = Result<ListArray>;
+
+    fn h3_polyfill(&self, resolution: Resolution, containment: ContainmentMode) -> Self::Output {
+        use NativeType::*;
+
+        match self.data_type() {
+            Polygon(_, _) => self.as_polygon().h3_polyfill(resolution, containment),
+            LargePolygon(_, _) => self.as_large_polygon().h3_polyfill(resolution, containment),
+            MultiPolygon(_, _) => self.as_multi_polygon().h3_polyfill(resolution, containment),
+            LargeMultiPolygon(_, _) => self
+                .as_large_multi_polygon()
+                .h3_polyfill(resolution, containment),
+            Rect(_) => self.as_rect().h3_polyfill(resolution, containment),
+            _ => Err(GeoArrowError::IncorrectType(
+                "H3 polyfill is only supported for polygonal arrays".into(),
+            )),
+        }
+    }
+}
+
+impl<G: NativeArray> H3Polyfill for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<ListArray>>;
+
+    fn h3_polyfill(&self, resolution: Resolution, containment: ContainmentMode) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().h3_polyfill(resolution, containment))?
+            .try_into()
+    }
+}
+
+impl H3Polyfill for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedArray<ListArray>>;
+
+    fn h3_polyfill(&self, resolution: Resolution, containment: ContainmentMode) -> Self::Output {
+        self.geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.as_ref().h3_polyfill(resolution, containment))
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::polygon::p_array;
+    use arrow_array::Array;
+
+    #[test]
+    fn polyfill_polygons() {
+        let cells = p_array()
+            .h3_polyfill(Resolution::Three, ContainmentMode::ContainsCentroid)
+            .unwrap();
+        assert_eq!(cells.len(), 2);
+        let (shell, with_hole) = (cells.value(0), cells.value(1));
+        assert!(!shell.is_empty());
+        // The second polygon has the same shell as the first, with a hole
+        assert!(with_hole.len() < shell.len());
+    }
+}
@@ -0,0 +1,89 @@
+use arrow_array::UInt64Array;
+use h3o::{LatLng, Resolution};
+
+use crate::array::{AsChunkedNativeArray, AsNativeArray, PointArray};
+use crate::chunked_array::{ChunkedArray, ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// Index points into the H3 cells that contain them.
+///
+/// Points must be longitude/latitude in degrees. Null points give null cells.
+pub trait ToH3Cell {
+    type Output;
+
+    fn to_h3_cell(&self, resolution: Resolution) -> Self::Output;
+}
+
+impl ToH3Cell for PointArray {
+    type Output = Result<UInt64Array>;
+
+    fn to_h3_cell(&self, resolution: Resolution) -> Self::Output {
+        self.iter_geo()
+            .map(|maybe_point| {
+                maybe_point
+                    .map(|point| {
+                        let lat_lng = LatLng::new(point.y(), point.x())
+                            .map_err(|err| GeoArrowError::General(err.to_string()))?;
+                        Ok(u64::from(lat_lng.to_cell(resolution)))
+                    })
+                    .transpose()
+            })
+            .collect()
+    }
+}
+
+impl ToH3Cell for &dyn NativeArray {
+    type Output = Result<UInt64Array>;
+
+    fn to_h3_cell(&self, resolution: Resolution) -> Self::Output {
+        match self.data_type() {
+            NativeType::Point(_, _) => self.as_point().to_h3_cell(resolution),
+            _ => Err(GeoArrowError::IncorrectType(
+                "H3 cells can only be computed for points".into(),
+            )),
+        }
+    }
+}
+
+impl<G: NativeArray> ToH3Cell for ChunkedGeometryArray<G> {
+    type Output = Result<ChunkedArray<UInt64Array>>;
+
+    fn to_h3_cell(&self, resolution: Resolution) -> Self::Output {
+        self.try_map(|chunk| chunk.as_ref().to_h3_cell(resolution))?
+            .try_into()
+    }
+}
+
+impl ToH3Cell for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedArray<UInt64Array>>;
+
+    fn to_h3_cell(&self, resolution: Resolution) -> Self::Output {
+        match self.data_type() {
+            NativeType::Point(_, _) => self.as_point().to_h3_cell(resolution),
+            _ => Err(GeoArrowError::IncorrectType(
+                "H3 cells can only be computed for points".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use geo::point;
+
+    #[test]
+    fn point_to_cell() {
+        let points: PointArray = (
+            vec![point!(x: -122.0553238, y: 37.3615593)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let cells = points.to_h3_cell(Resolution::Five).unwrap();
+        assert_eq!(cells.value(0), 0x85283473fffffff);
+    }
+}
@@ -3,10 +3,15 @@
 #![allow(missing_docs)] // FIXME
 
 pub mod broadcasting;
+pub mod dissolve;
 pub mod geo;
 pub mod geo_index;
+#[cfg(feature = "geodesy")]
+pub mod geodesy;
 #[cfg(feature = "geos")]
 pub mod geos;
+#[cfg(feature = "h3")]
+pub mod h3;
 pub mod native;
 #[cfg(feature = "polylabel")]
 pub mod polylabel;
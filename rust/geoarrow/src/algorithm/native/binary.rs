@@ -11,7 +11,56 @@ use crate::error::{GeoArrowError, Result};
 use crate::trait_::ArrayAccessor;
 
 pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeArray {
+    /// Check that `self` and `rhs` have equivalent CRSs.
+    ///
+    /// Inputs without a CRS are compatible with any CRS. The binary operations below call this
+    /// first; their `_unchecked` variants skip it.
+    fn check_crs(&'a self, rhs: &'a Rhs) -> Result<()> {
+        self.metadata()
+            .check_crs_compatible(&rhs.metadata(), "Binary operation")
+    }
+
     fn binary_boolean<F>(&'a self, rhs: &'a Rhs, op: F) -> Result<BooleanArray>
+    where
+        F: Fn(Self::Item, Rhs::Item) -> bool,
+    {
+        self.check_crs(rhs)?;
+        self.binary_boolean_unchecked(rhs, op)
+    }
+
+    fn try_binary_boolean<F>(&'a self, rhs: &'a Rhs, op: F) -> Result<BooleanArray>
+    where
+        F: Fn(Self::Item, Rhs::Item) -> Result<bool>,
+    {
+        self.check_crs(rhs)?;
+        self.try_binary_boolean_unchecked(rhs, op)
+    }
+
+    fn try_binary_primitive<F, O>(&'a self, rhs: &'a Rhs, op: F) -> Result<PrimitiveArray<O>>
+    where
+        O: ArrowPrimitiveType,
+        F: Fn(Self::Item, Rhs::Item) -> Result<O::Native>,
+    {
+        self.check_crs(rhs)?;
+        self.try_binary_primitive_unchecked(rhs, op)
+    }
+
+    fn try_binary_geometry<F, G>(
+        &'a self,
+        rhs: &'a Rhs,
+        op: F,
+        prefer_multi: bool,
+    ) -> Result<GeometryArray>
+    where
+        G: GeometryTrait<T = f64>,
+        F: Fn(Self::Item, Rhs::Item) -> Result<G>,
+    {
+        self.check_crs(rhs)?;
+        self.try_binary_geometry_unchecked(rhs, op, prefer_multi)
+    }
+
+    /// [`Self::binary_boolean`] without comparing the CRSs of the inputs.
+    fn binary_boolean_unchecked<F>(&'a self, rhs: &'a Rhs, op: F) -> Result<BooleanArray>
     where
         F: Fn(Self::Item, Rhs::Item) -> bool,
     {
@@ -20,7 +69,6 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
                 "Cannot perform binary operation on arrays of different length".to_string(),
             ));
         }
-
         if self.is_empty() {
             return Ok(BooleanBuilder::new().finish());
         }
@@ -33,7 +81,8 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
         Ok(BooleanArray::new(builder.finish(), nulls))
     }
 
-    fn try_binary_boolean<F>(&'a self, rhs: &'a Rhs, op: F) -> Result<BooleanArray>
+    /// [`Self::try_binary_boolean`] without comparing the CRSs of the inputs.
+    fn try_binary_boolean_unchecked<F>(&'a self, rhs: &'a Rhs, op: F) -> Result<BooleanArray>
     where
         F: Fn(Self::Item, Rhs::Item) -> Result<bool>,
     {
@@ -42,7 +91,6 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
                 "Cannot perform binary operation on arrays of different length".to_string(),
             ));
         }
-
         if self.is_empty() {
             return Ok(BooleanBuilder::new().finish());
         }
@@ -73,7 +121,12 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
         }
     }
 
-    fn try_binary_primitive<F, O>(&'a self, rhs: &'a Rhs, op: F) -> Result<PrimitiveArray<O>>
+    /// [`Self::try_binary_primitive`] without comparing the CRSs of the inputs.
+    fn try_binary_primitive_unchecked<F, O>(
+        &'a self,
+        rhs: &'a Rhs,
+        op: F,
+    ) -> Result<PrimitiveArray<O>>
     where
         O: ArrowPrimitiveType,
         F: Fn(Self::Item, Rhs::Item) -> Result<O::Native>,
@@ -83,7 +136,6 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
                 "Cannot perform binary operation on arrays of different length".to_string(),
             ));
         }
-
         if self.is_empty() {
             return Ok(PrimitiveArray::from(ArrayData::new_empty(&O::DATA_TYPE)));
         }
@@ -118,7 +170,8 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
         }
     }
 
-    fn try_binary_geometry<F, G>(
+    /// [`Self::try_binary_geometry`] without comparing the CRSs of the inputs.
+    fn try_binary_geometry_unchecked<F, G>(
         &'a self,
         rhs: &'a Rhs,
         op: F,
@@ -133,7 +186,6 @@ pub trait Binary<'a, Rhs: ArrayAccessor<'a> = Self>: ArrayAccessor<'a> + NativeA
                 "Cannot perform binary operation on arrays of different length".to_string(),
             ));
         }
-
         let mut builder = GeometryBuilder::with_capacity_and_options(
             Default::default(),
             self.coord_type(),
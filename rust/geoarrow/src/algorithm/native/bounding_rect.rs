@@ -349,6 +349,17 @@ array_impl!(MultiPolygonArray, bounding_rect_multipolygon);
 array_impl!(MixedGeometryArray, bounding_rect_geometry);
 array_impl!(GeometryCollectionArray, bounding_rect_geometry_collection);
 array_impl!(GeometryArray, bounding_rect_geometry);
+array_impl!(LargeLineStringArray, bounding_rect_linestring);
+array_impl!(LargePolygonArray, bounding_rect_polygon);
+array_impl!(LargeMultiPointArray, bounding_rect_multipoint);
+array_impl!(LargeMultiLineStringArray, bounding_rect_multilinestring);
+array_impl!(LargeMultiPolygonArray, bounding_rect_multipolygon);
+array_impl!(LargeMixedGeometryArray, bounding_rect_geometry);
+array_impl!(
+    LargeGeometryCollectionArray,
+    bounding_rect_geometry_collection
+);
+array_impl!(LargeGeometryArray, bounding_rect_geometry);
 
 impl BoundingRectArray for RectArray {
     type Output = RectArray;
@@ -367,12 +378,21 @@ impl BoundingRectArray for &dyn NativeArray {
         let result = match self.data_type() {
             Point(_, _) => self.as_point().bounding_rect(),
             LineString(_, _) => self.as_line_string().bounding_rect(),
+            LargeLineString(_, _) => self.as_large_line_string().bounding_rect(),
             Polygon(_, _) => self.as_polygon().bounding_rect(),
+            LargePolygon(_, _) => self.as_large_polygon().bounding_rect(),
             MultiPoint(_, _) => self.as_multi_point().bounding_rect(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().bounding_rect(),
             MultiLineString(_, _) => self.as_multi_line_string().bounding_rect(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().bounding_rect(),
             MultiPolygon(_, _) => self.as_multi_polygon().bounding_rect(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().bounding_rect(),
+            Mixed(_, _) => self.as_mixed().bounding_rect(),
+            LargeMixed(_, _) => self.as_large_mixed().bounding_rect(),
             GeometryCollection(_, _) => self.as_geometry_collection().bounding_rect(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().bounding_rect(),
             Geometry(_) => self.as_geometry().bounding_rect(),
+            LargeGeometry(_) => self.as_large_geometry().bounding_rect(),
             Rect(_) => self.as_rect().bounding_rect(),
         };
         Ok(result)
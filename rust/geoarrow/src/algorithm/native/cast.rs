@@ -1,28 +1,70 @@
-//! Note: In the future, it may be possible to optimize some of these casts, e.g. from Point to
-//! MultiPoint by only constructing a new offsets array, but you have to check that the coordinate
-//! type is not casted!
+//! Cast geometry arrays between [`NativeType`]s.
 //!
-//! todo: have a set of "fast cast" functions, where you first try to fast cast and fall back to
-//! slower copies if necessary. Can check that the coord type of the input and output is the same.
-
+//! Casts are attempted in order of cost:
+//!
+//! 1. "Fast casts" that only build new offset buffers and reuse the existing coordinates, such as
+//!    Point to MultiPoint. These only apply when the coordinate type and dimension of the input
+//!    already match the target type.
+//! 2. Full casts that copy coordinates, e.g. when the coordinate type changes.
+//! 3. For casts that may not be representable for every geometry, such as MultiPoint to Point,
+//!    a row-by-row cast that either errors or produces nulls, depending on [`CastOptions::safe`].
+
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
-use crate::NativeArray;
+use crate::trait_::NativeGeometryAccessor;
+use crate::{ArrayBase, NativeArray};
 
 /// CastOptions provides a way to override the default cast behaviors
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CastOptions {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
+    ///
+    /// This applies to geometries that cannot be represented in the target type, such as a
+    /// MultiPoint with more than one point when casting to Point.
     pub safe: bool,
+
+    /// The Z value to use for every coordinate when casting from XY to XYZ.
+    pub z_fill_value: FillValue,
 }
 
 impl Default for CastOptions {
     fn default() -> Self {
-        Self { safe: true }
+        Self {
+            safe: true,
+            z_fill_value: FillValue(0.0),
+        }
+    }
+}
+
+/// A coordinate value used to fill a missing dimension.
+///
+/// Values are compared and hashed by their bit pattern, so that [`CastOptions`] can be `Eq` and
+/// `Hash`. This means that `0.0` and `-0.0` are different fill values, while `NaN` equals itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FillValue(pub f64);
+
+impl From<f64> for FillValue {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq for FillValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for FillValue {}
+
+impl Hash for FillValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state)
     }
 }
 
@@ -50,207 +92,635 @@ fn can_cast_types(from_type: NativeType, to_type: NativeType) -> bool {
 pub trait Cast {
     type Output;
 
-    /// Note: **does not currently implement dimension casts**
-    fn cast(&self, to_type: NativeType) -> Self::Output;
+    /// Cast this array to `to_type` with the default [`CastOptions`].
+    fn cast(&self, to_type: NativeType) -> Self::Output {
+        self.cast_with_options(to_type, &CastOptions::default())
+    }
+
+    /// Cast this array to `to_type`.
+    ///
+    /// If the dimension of `to_type` differs from the input, the coordinates are cast first.
+    /// Casting from XYZ to XY drops the Z values and casting from XY to XYZ fills them with
+    /// [`CastOptions::z_fill_value`]. Other dimension casts are not yet implemented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{Cast, CastOptions, FillValue};
+    /// use geoarrow::array::{CoordType, PointArray};
+    /// use geoarrow::datatypes::{Dimension, NativeType};
+    /// use geoarrow::NativeArray;
+    ///
+    /// let point = geo::point!(x: 1., y: 2.);
+    /// let array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+    ///
+    /// let options = CastOptions {
+    ///     z_fill_value: FillValue(10.),
+    ///     ..Default::default()
+    /// };
+    /// let to_type = NativeType::MultiPoint(CoordType::Interleaved, Dimension::XYZ);
+    /// let cast = array.cast_with_options(to_type, &options).unwrap();
+    /// assert_eq!(cast.data_type(), to_type);
+    /// ```
+    fn cast_with_options(&self, to_type: NativeType, options: &CastOptions) -> Self::Output;
 }
 
-impl Cast for PointArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+/// Cast `array` to `dim`, keeping its geometry type.
+///
+/// Only casts between XY and XYZ are supported.
+fn cast_dimension(
+    array: &dyn NativeArray,
+    dim: Dimension,
+    z_fill_value: f64,
+) -> Result<Arc<dyn NativeArray>> {
+    use Dimension::*;
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
-
-        let array = self.to_coord_type(to_type.coord_type());
-        match to_type {
-            Point(_, _) => Ok(Arc::new(array)),
-            MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::from(array))),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
-        }
+    let from_dim = array.dimension();
+    if !matches!((from_dim, dim), (XY, XYZ) | (XYZ, XY)) {
+        return Err(GeoArrowError::NotYetImplemented(format!(
+            "Cast from dimension {from_dim:?} to {dim:?}"
+        )));
     }
+
+    let coords = |coords: &CoordBuffer| coords.clone().into_dimension(dim, z_fill_value);
+
+    let result: Arc<dyn NativeArray> = match array.data_type() {
+        Point(_, _) => {
+            let arr = array.as_point();
+            Arc::new(PointArray::new(
+                coords(&arr.coords),
+                arr.validity.clone(),
+                arr.metadata.clone(),
+            ))
+        }
+        LineString(_, _) => {
+            let arr = array.as_line_string();
+            Arc::new(LineStringArray::new(
+                coords(&arr.coords),
+                arr.geom_offsets.clone(),
+                arr.validity.clone(),
+                arr.metadata.clone(),
+            ))
+        }
+        Polygon(_, _) => {
+            let arr = array.as_polygon();
+            Arc::new(PolygonArray::new(
+                coords(&arr.coords),
+                arr.geom_offsets.clone(),
+                arr.ring_offsets.clone(),
+                arr.validity.clone(),
+                arr.metadata.clone(),
+            ))
+        }
+        MultiPoint(_, _) => {
+            let arr = array.as_multi_point();
+            Arc::new(MultiPointArray::new(
+                coords(&arr.coords),
+                arr.geom_offsets.clone(),
+                arr.validity.clone(),
+                arr.metadata.clone(),
+            ))
+        }
+        MultiLineString(_, _) => {
+            let arr = array.as_multi_line_string();
+            Arc::new(MultiLineStringArray::new(
+                coords(&arr.coords),
+                arr.geom_offsets.clone(),
+                arr.ring_offsets.clone(),
+                arr.validity.clone(),
+                arr.metadata.clone(),
+            ))
+        }
+        MultiPolygon(_, _) => {
+            let arr = array.as_multi_polygon();
+            Arc::new(MultiPolygonArray::new(
+                coords(&arr.coords),
+                arr.geom_offsets.clone(),
+                arr.polygon_offsets.clone(),
+                arr.ring_offsets.clone(),
+                arr.validity.clone(),
+                arr.metadata.clone(),
+            ))
+        }
+        Mixed(_, _) => Arc::new(cast_mixed_dimension(array.as_mixed(), dim, z_fill_value)?),
+        GeometryCollection(_, _) => {
+            let arr = array.as_geometry_collection();
+            Arc::new(GeometryCollectionArray::new(
+                cast_mixed_dimension(&arr.array, dim, z_fill_value)?,
+                arr.geom_offsets.clone(),
+                arr.validity.clone(),
+                arr.metadata(),
+            ))
+        }
+        dt => {
+            return Err(GeoArrowError::NotYetImplemented(format!(
+                "Dimension cast of {dt:?}"
+            )))
+        }
+    };
+    Ok(result)
 }
 
-impl Cast for LineStringArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_mixed_dimension(
+    array: &MixedGeometryArray,
+    dim: Dimension,
+    z_fill_value: f64,
+) -> Result<MixedGeometryArray> {
+    let child = |child: &dyn NativeArray| cast_dimension(child, dim, z_fill_value);
+
+    // The union type ids encode the dimension as well as the geometry type
+    let type_ids = array
+        .type_ids
+        .iter()
+        .map(|type_id| type_id % 10 + 10 * dim.order() as i8)
+        .collect();
+
+    Ok(MixedGeometryArray::new(
+        type_ids,
+        array.offsets.clone(),
+        Some(child(&array.points)?.as_ref().as_point().clone()),
+        Some(
+            child(&array.line_strings)?
+                .as_ref()
+                .as_line_string()
+                .clone(),
+        ),
+        Some(child(&array.polygons)?.as_ref().as_polygon().clone()),
+        Some(
+            child(&array.multi_points)?
+                .as_ref()
+                .as_multi_point()
+                .clone(),
+        ),
+        Some(
+            child(&array.multi_line_strings)?
+                .as_ref()
+                .as_multi_line_string()
+                .clone(),
+        ),
+        Some(
+            child(&array.multi_polygons)?
+                .as_ref()
+                .as_multi_polygon()
+                .clone(),
+        ),
+        array.metadata.clone(),
+    ))
+}
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
-
-        let array = self.to_coord_type(to_type.coord_type());
-
-        match to_type {
-            LineString(_, _) => Ok(Arc::new(array)),
-            MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::from(array))),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+/// Cast `array` to `to_type` without copying coordinates, if possible.
+///
+/// Returns `None` if the cast needs to copy coordinates, e.g. because the coordinate type or
+/// dimension differs.
+fn fast_cast(array: &dyn NativeArray, to_type: NativeType) -> Option<Arc<dyn NativeArray>> {
+    use NativeType::*;
+
+    let from_type = array.data_type();
+    if from_type.coord_type() != to_type.coord_type()
+        || from_type.dimension() != to_type.dimension()
+    {
+        return None;
+    }
+
+    match (from_type, to_type) {
+        (from_type, to_type) if from_type == to_type => Some(array.slice(0, array.len())),
+        (Point(_, _), MultiPoint(_, _)) => {
+            Some(Arc::new(MultiPointArray::from(array.as_point().clone())))
         }
+        (LineString(_, _), MultiLineString(_, _)) => Some(Arc::new(MultiLineStringArray::from(
+            array.as_line_string().clone(),
+        ))),
+        (Polygon(_, _), MultiPolygon(_, _)) => Some(Arc::new(MultiPolygonArray::from(
+            array.as_polygon().clone(),
+        ))),
+        _ => None,
     }
 }
 
-impl Cast for PolygonArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+/// Whether a cast from `from_type` to `to_type` can fail for some geometries but not others.
+fn is_lossy_cast(from_type: NativeType, to_type: NativeType) -> bool {
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
-
-        let array = self.to_coord_type(to_type.coord_type());
-
-        match to_type {
-            Polygon(_, _) => Ok(Arc::new(array)),
-            MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::from(array))),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
+    matches!(
+        (from_type, to_type),
+        (MultiPoint(_, _), Point(_, _))
+            | (MultiLineString(_, _), LineString(_, _))
+            | (MultiPolygon(_, _), Polygon(_, _))
+            | (
+                Mixed(_, _) | GeometryCollection(_, _) | Geometry(_),
+                Point(_, _)
+                    | LineString(_, _)
+                    | Polygon(_, _)
+                    | MultiPoint(_, _)
+                    | MultiLineString(_, _)
+                    | MultiPolygon(_, _)
+            )
+    )
+}
+
+/// Cast `array` to `to_type` one geometry at a time, setting geometries that cannot be
+/// represented in `to_type` to null.
+fn cast_nulling_failures(
+    array: &dyn NativeArray,
+    to_type: NativeType,
+) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
+
+    macro_rules! impl_cast {
+        ($array:expr, $builder:ty) => {{
+            let array = $array;
+            let mut builder = <$builder>::new_with_options(
+                to_type.dimension().unwrap(),
+                to_type.coord_type(),
+                array.metadata(),
+            );
+            for i in 0..array.len() {
+                let geom = array.get_as_geometry(i);
+                if builder.push_geometry(geom.as_ref()).is_err() {
+                    builder.push_null();
+                }
+            }
+            Arc::new(builder.finish()) as Arc<dyn NativeArray>
+        }};
+    }
+
+    macro_rules! impl_from {
+        ($array:expr) => {
+            match to_type {
+                Point(_, _) => impl_cast!($array, PointBuilder),
+                LineString(_, _) => impl_cast!($array, LineStringBuilder),
+                Polygon(_, _) => impl_cast!($array, PolygonBuilder),
+                MultiPoint(_, _) => impl_cast!($array, MultiPointBuilder),
+                MultiLineString(_, _) => impl_cast!($array, MultiLineStringBuilder),
+                MultiPolygon(_, _) => impl_cast!($array, MultiPolygonBuilder),
+                dt => {
+                    return Err(GeoArrowError::General(format!(
+                        "invalid cast to type {dt:?}"
+                    )))
+                }
+            }
+        };
+    }
+
+    let result = match array.data_type() {
+        MultiPoint(_, _) => impl_from!(array.as_multi_point()),
+        MultiLineString(_, _) => impl_from!(array.as_multi_line_string()),
+        MultiPolygon(_, _) => impl_from!(array.as_multi_polygon()),
+        Mixed(_, _) => impl_from!(array.as_mixed()),
+        GeometryCollection(_, _) => impl_from!(array.as_geometry_collection()),
+        Geometry(_) => impl_from!(array.as_geometry()),
+        dt => {
+            return Err(GeoArrowError::General(format!(
+                "invalid cast from type {dt:?}"
+            )))
         }
+    };
+    Ok(result)
+}
+
+fn cast_point(array: &PointArray, to_type: NativeType) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
+
+    let array = array.to_coord_type(to_type.coord_type());
+    match to_type {
+        Point(_, _) => Ok(Arc::new(array)),
+        MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::from(array))),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
     }
 }
 
-impl Cast for MultiPointArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_line_string(array: &LineStringArray, to_type: NativeType) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
-
-        let array = self.to_coord_type(to_type.coord_type());
-
-        match to_type {
-            Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
-            MultiPoint(_, _) => Ok(Arc::new(array)),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
-        }
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        LineString(_, _) => Ok(Arc::new(array)),
+        MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::from(array))),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
     }
 }
 
-impl Cast for MultiLineStringArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_polygon(array: &PolygonArray, to_type: NativeType) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        Polygon(_, _) => Ok(Arc::new(array)),
+        MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::from(array))),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
+    }
+}
 
-        let array = self.to_coord_type(to_type.coord_type());
+fn cast_multi_point(array: &MultiPointArray, to_type: NativeType) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-        match to_type {
-            LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
-        }
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
+        MultiPoint(_, _) => Ok(Arc::new(array)),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
     }
 }
 
-impl Cast for MultiPolygonArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_multi_line_string(
+    array: &MultiLineStringArray,
+    to_type: NativeType,
+) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
+        MultiLineString(_, _) => Ok(Arc::new(array)),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
+    }
+}
 
-        let array = self.to_coord_type(to_type.coord_type());
+fn cast_multi_polygon(
+    array: &MultiPolygonArray,
+    to_type: NativeType,
+) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-        match to_type {
-            Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
-        }
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
+        MultiPolygon(_, _) => Ok(Arc::new(array)),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::from(array))),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
     }
 }
 
-impl Cast for MixedGeometryArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_mixed(array: &MixedGeometryArray, to_type: NativeType) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
-
-        let array = self.to_coord_type(to_type.coord_type());
-
-        match to_type {
-            Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
-            LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
-            Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
-            MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::try_from(array)?)),
-            MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::try_from(array)?)),
-            MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::try_from(array)?)),
-            GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
-        }
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
+        LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
+        Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
+        MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::try_from(array)?)),
+        MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::try_from(array)?)),
+        MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::try_from(array)?)),
+        Mixed(_, _) => Ok(Arc::new(array)),
+        GeometryCollection(_, _) => Ok(Arc::new(GeometryCollectionArray::from(array))),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
     }
 }
 
-impl Cast for GeometryCollectionArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_geometry_collection(
+    array: &GeometryCollectionArray,
+    to_type: NativeType,
+) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        use NativeType::*;
-
-        let array = self.to_coord_type(to_type.coord_type());
-
-        match to_type {
-            Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
-            LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
-            Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
-            MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::try_from(array)?)),
-            MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::try_from(array)?)),
-            MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::try_from(array)?)),
-            GeometryCollection(_, _) => Ok(Arc::new(array)),
-            Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
-            dt => Err(GeoArrowError::General(format!(
-                "invalid cast to type {dt:?}"
-            ))),
-        }
+    let array = array.to_coord_type(to_type.coord_type());
+
+    match to_type {
+        Point(_, _) => Ok(Arc::new(PointArray::try_from(array)?)),
+        LineString(_, _) => Ok(Arc::new(LineStringArray::try_from(array)?)),
+        Polygon(_, _) => Ok(Arc::new(PolygonArray::try_from(array)?)),
+        MultiPoint(_, _) => Ok(Arc::new(MultiPointArray::try_from(array)?)),
+        MultiLineString(_, _) => Ok(Arc::new(MultiLineStringArray::try_from(array)?)),
+        MultiPolygon(_, _) => Ok(Arc::new(MultiPolygonArray::try_from(array)?)),
+        Mixed(_, _) => Ok(Arc::new(MixedGeometryArray::try_from(array)?)),
+        GeometryCollection(_, _) => Ok(Arc::new(array)),
+        Geometry(_) => Ok(Arc::new(GeometryArray::from(array))),
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast to type {dt:?}"
+        ))),
     }
 }
 
-impl Cast for GeometryArray {
-    type Output = Result<Arc<dyn NativeArray>>;
+fn cast_native(
+    array: &dyn NativeArray,
+    to_type: NativeType,
+    options: &CastOptions,
+) -> Result<Arc<dyn NativeArray>> {
+    if array.data_type().is_large() {
+        return cast_native(narrow_offsets(array)?.as_ref(), to_type, options);
+    }
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        // TODO: validate dimension
-        let array = self.to_coord_type(to_type.coord_type());
-        let mixed_array = MixedGeometryArray::try_from(array)?;
-        mixed_array.cast(to_type)
+    if to_type.is_large() {
+        let array = cast_native(array, small_offset_type(to_type), options)?;
+        return Ok(widen_offsets(array));
+    }
+
+    use NativeType::*;
+
+    // Geometry arrays have no single dimension, so they are first cast to the mixed array of the
+    // dimension they contain.
+    if let Geometry(_) = array.data_type() {
+        if to_type.dimension().is_some() {
+            let array = array.as_geometry().to_coord_type(to_type.coord_type());
+            let mixed = MixedGeometryArray::try_from(array)?;
+            return cast_native(&mixed, to_type, options);
+        }
+    }
+
+    if let Some(dim) = to_type.dimension() {
+        if array.data_type().dimension() != Some(dim) {
+            let array = cast_dimension(array, dim, options.z_fill_value.0)?;
+            return cast_native(array.as_ref(), to_type, options);
+        }
+    }
+
+    if let Some(result) = fast_cast(array, to_type) {
+        return Ok(result);
+    }
+
+    let result = match array.data_type() {
+        Point(_, _) => cast_point(array.as_point(), to_type),
+        LineString(_, _) => cast_line_string(array.as_line_string(), to_type),
+        Polygon(_, _) => cast_polygon(array.as_polygon(), to_type),
+        MultiPoint(_, _) => cast_multi_point(array.as_multi_point(), to_type),
+        MultiLineString(_, _) => cast_multi_line_string(array.as_multi_line_string(), to_type),
+        MultiPolygon(_, _) => cast_multi_polygon(array.as_multi_polygon(), to_type),
+        Mixed(_, _) => cast_mixed(array.as_mixed(), to_type),
+        GeometryCollection(_, _) => {
+            cast_geometry_collection(array.as_geometry_collection(), to_type)
+        }
+        Geometry(_) => {
+            let array = array.as_geometry().to_coord_type(to_type.coord_type());
+            let mixed_array = MixedGeometryArray::try_from(array)?;
+            cast_mixed(&mixed_array, to_type)
+        }
+        dt => Err(GeoArrowError::General(format!(
+            "invalid cast from type {dt:?}"
+        ))),
+    };
+
+    match result {
+        Err(_) if options.safe && is_lossy_cast(array.data_type(), to_type) => {
+            cast_nulling_failures(array, to_type)
+        }
+        result => result,
     }
 }
 
+macro_rules! impl_cast {
+    ($array:ty) => {
+        impl Cast for $array {
+            type Output = Result<Arc<dyn NativeArray>>;
+
+            fn cast_with_options(
+                &self,
+                to_type: NativeType,
+                options: &CastOptions,
+            ) -> Self::Output {
+                cast_native(self, to_type, options)
+            }
+        }
+    };
+}
+
+impl_cast!(PointArray);
+impl_cast!(LineStringArray);
+impl_cast!(PolygonArray);
+impl_cast!(MultiPointArray);
+impl_cast!(MultiLineStringArray);
+impl_cast!(MultiPolygonArray);
+impl_cast!(MixedGeometryArray);
+impl_cast!(GeometryCollectionArray);
+impl_cast!(GeometryArray);
+impl_cast!(LargeLineStringArray);
+impl_cast!(LargePolygonArray);
+impl_cast!(LargeMultiPointArray);
+impl_cast!(LargeMultiLineStringArray);
+impl_cast!(LargeMultiPolygonArray);
+impl_cast!(LargeMixedGeometryArray);
+impl_cast!(LargeGeometryCollectionArray);
+impl_cast!(LargeGeometryArray);
+
 impl Cast for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
-    fn cast(&self, to_type: NativeType) -> Self::Output {
-        // TODO: not working :/
-        // if self.data_type() == to_type {
-        //     return Ok(Arc::new(self.to_owned()));
-        // }
-
-        use NativeType::*;
-
-        match self.data_type() {
-            Point(_, _) => self.as_ref().as_point().cast(to_type),
-            LineString(_, _) => self.as_ref().as_line_string().cast(to_type),
-            Polygon(_, _) => self.as_ref().as_polygon().cast(to_type),
-            MultiPoint(_, _) => self.as_ref().as_multi_point().cast(to_type),
-            MultiLineString(_, _) => self.as_ref().as_multi_line_string().cast(to_type),
-            MultiPolygon(_, _) => self.as_ref().as_multi_polygon().cast(to_type),
-            GeometryCollection(_, _) => self.as_ref().as_geometry_collection().cast(to_type),
-            Geometry(_) => self.as_ref().as_geometry().cast(to_type),
-            _ => todo!(),
-        }
+    fn cast_with_options(&self, to_type: NativeType, options: &CastOptions) -> Self::Output {
+        cast_native(*self, to_type, options)
+    }
+}
+
+/// The `i32`-offset counterpart of `data_type`.
+///
+/// Types that have no offsets, or that already use `i32` offsets, are returned unchanged.
+pub(crate) fn small_offset_type(data_type: NativeType) -> NativeType {
+    use NativeType::*;
+
+    match data_type {
+        LargeLineString(ct, dim) => LineString(ct, dim),
+        LargePolygon(ct, dim) => Polygon(ct, dim),
+        LargeMultiPoint(ct, dim) => MultiPoint(ct, dim),
+        LargeMultiLineString(ct, dim) => MultiLineString(ct, dim),
+        LargeMultiPolygon(ct, dim) => MultiPolygon(ct, dim),
+        LargeMixed(ct, dim) => Mixed(ct, dim),
+        LargeGeometryCollection(ct, dim) => GeometryCollection(ct, dim),
+        LargeGeometry(ct) => Geometry(ct),
+        dt => dt,
+    }
+}
+
+/// Convert a large-offset array to the equivalent array with `i32` offsets.
+///
+/// Returns an error if the offsets of `array` do not fit in an `i32`. Arrays that are not
+/// large-offset arrays are returned unchanged.
+pub(crate) fn narrow_offsets(array: &dyn NativeArray) -> Result<Arc<dyn NativeArray>> {
+    use NativeType::*;
+
+    let result: Arc<dyn NativeArray> = match array.data_type() {
+        LargeLineString(_, _) => Arc::new(LineStringArray::try_from(
+            array.as_large_line_string().clone(),
+        )?),
+        LargePolygon(_, _) => Arc::new(PolygonArray::try_from(array.as_large_polygon().clone())?),
+        LargeMultiPoint(_, _) => Arc::new(MultiPointArray::try_from(
+            array.as_large_multi_point().clone(),
+        )?),
+        LargeMultiLineString(_, _) => Arc::new(MultiLineStringArray::try_from(
+            array.as_large_multi_line_string().clone(),
+        )?),
+        LargeMultiPolygon(_, _) => Arc::new(MultiPolygonArray::try_from(
+            array.as_large_multi_polygon().clone(),
+        )?),
+        LargeMixed(_, _) => Arc::new(MixedGeometryArray::try_from(
+            array.as_large_mixed().clone(),
+        )?),
+        LargeGeometryCollection(_, _) => Arc::new(GeometryCollectionArray::try_from(
+            array.as_large_geometry_collection().clone(),
+        )?),
+        LargeGeometry(_) => Arc::new(GeometryArray::try_from(array.as_large_geometry().clone())?),
+        _ => array.slice(0, array.len()),
+    };
+    Ok(result)
+}
+
+/// Convert an array with `i32` offsets to the equivalent large-offset array.
+///
+/// Arrays without offsets are returned unchanged.
+pub(crate) fn widen_offsets(array: Arc<dyn NativeArray>) -> Arc<dyn NativeArray> {
+    use NativeType::*;
+
+    match array.data_type() {
+        LineString(_, _) => Arc::new(LargeLineStringArray::from(
+            array.as_ref().as_line_string().clone(),
+        )),
+        Polygon(_, _) => Arc::new(LargePolygonArray::from(array.as_ref().as_polygon().clone())),
+        MultiPoint(_, _) => Arc::new(LargeMultiPointArray::from(
+            array.as_ref().as_multi_point().clone(),
+        )),
+        MultiLineString(_, _) => Arc::new(LargeMultiLineStringArray::from(
+            array.as_ref().as_multi_line_string().clone(),
+        )),
+        MultiPolygon(_, _) => Arc::new(LargeMultiPolygonArray::from(
+            array.as_ref().as_multi_polygon().clone(),
+        )),
+        Mixed(_, _) => Arc::new(LargeMixedGeometryArray::from(
+            array.as_ref().as_mixed().clone(),
+        )),
+        GeometryCollection(_, _) => Arc::new(LargeGeometryCollectionArray::from(
+            array.as_ref().as_geometry_collection().clone(),
+        )),
+        Geometry(_) => Arc::new(LargeGeometryArray::from(
+            array.as_ref().as_geometry().clone(),
+        )),
+        _ => array,
     }
 }
 
@@ -259,14 +729,23 @@ macro_rules! impl_chunked_cast {
         impl Cast for $chunked_array {
             type Output = Result<Arc<dyn ChunkedNativeArray>>;
 
-            fn cast(&self, to_type: NativeType) -> Self::Output {
+            fn cast_with_options(
+                &self,
+                to_type: NativeType,
+                options: &CastOptions,
+            ) -> Self::Output {
                 macro_rules! impl_cast {
                     ($method:ident) => {
                         Arc::new(ChunkedGeometryArray::new(
                             self.geometry_chunks()
                                 .iter()
                                 .map(|chunk| {
-                                    Ok(chunk.as_ref().cast(to_type)?.as_ref().$method().clone())
+                                    Ok(chunk
+                                        .as_ref()
+                                        .cast_with_options(to_type, options)?
+                                        .as_ref()
+                                        .$method()
+                                        .clone())
                                 })
                                 .collect::<Result<Vec<_>>>()?,
                         ))
@@ -282,9 +761,18 @@ macro_rules! impl_chunked_cast {
                     MultiPoint(_, _) => impl_cast!(as_multi_point),
                     MultiLineString(_, _) => impl_cast!(as_multi_line_string),
                     MultiPolygon(_, _) => impl_cast!(as_multi_polygon),
+                    Mixed(_, _) => impl_cast!(as_mixed),
                     GeometryCollection(_, _) => impl_cast!(as_geometry_collection),
                     Rect(_) => impl_cast!(as_rect),
                     Geometry(_) => impl_cast!(as_geometry),
+                    LargeLineString(_, _) => impl_cast!(as_large_line_string),
+                    LargePolygon(_, _) => impl_cast!(as_large_polygon),
+                    LargeMultiPoint(_, _) => impl_cast!(as_large_multi_point),
+                    LargeMultiLineString(_, _) => impl_cast!(as_large_multi_line_string),
+                    LargeMultiPolygon(_, _) => impl_cast!(as_large_multi_polygon),
+                    LargeMixed(_, _) => impl_cast!(as_large_mixed),
+                    LargeGeometryCollection(_, _) => impl_cast!(as_large_geometry_collection),
+                    LargeGeometry(_) => impl_cast!(as_large_geometry),
                 };
                 Ok(result)
             }
@@ -303,3 +791,70 @@ impl_chunked_cast!(ChunkedMultiPolygonArray);
 impl_chunked_cast!(ChunkedMixedGeometryArray);
 impl_chunked_cast!(ChunkedGeometryCollectionArray);
 impl_chunked_cast!(ChunkedUnknownGeometryArray);
+impl_chunked_cast!(ChunkedLargeLineStringArray);
+impl_chunked_cast!(ChunkedLargePolygonArray);
+impl_chunked_cast!(ChunkedLargeMultiPointArray);
+impl_chunked_cast!(ChunkedLargeMultiLineStringArray);
+impl_chunked_cast!(ChunkedLargeMultiPolygonArray);
+impl_chunked_cast!(ChunkedLargeMixedGeometryArray);
+impl_chunked_cast!(ChunkedLargeGeometryCollectionArray);
+impl_chunked_cast!(ChunkedLargeUnknownGeometryArray);
+
+#[cfg(test)]
+mod test {
+    use geo_traits::CoordTrait;
+
+    use super::*;
+    use crate::test::{linestring, multipoint, point};
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn fast_cast_line_string() {
+        let arr = linestring::ls_array();
+        let to_type = NativeType::MultiLineString(arr.coord_type(), Dimension::XY);
+        let cast = arr.cast(to_type).unwrap();
+        let cast = cast.as_ref().as_multi_line_string().clone();
+
+        assert_eq!(cast.len(), arr.len());
+        assert_eq!(
+            cast.value_as_geo(1),
+            geo::MultiLineString::new(vec![linestring::ls1()])
+        );
+    }
+
+    #[test]
+    fn cast_xy_to_xyz_and_back() {
+        let arr = point::point_array();
+        let options = CastOptions {
+            z_fill_value: FillValue(5.),
+            ..Default::default()
+        };
+        let to_type = NativeType::Point(arr.coord_type(), Dimension::XYZ);
+        let xyz = arr.cast_with_options(to_type, &options).unwrap();
+        let xyz = xyz.as_ref().as_point().clone();
+
+        assert_eq!(xyz.dimension(), Dimension::XYZ);
+        assert_eq!(xyz.coords.value(1).nth_or_panic(2), 5.);
+
+        let xy = xyz
+            .cast(NativeType::Point(arr.coord_type(), Dimension::XY))
+            .unwrap();
+        assert_eq!(xy.as_ref().as_point().value_as_geo(1), point::p1());
+    }
+
+    #[test]
+    fn lossy_cast_safe_and_unsafe() {
+        let arr = multipoint::mp_array();
+        let to_type = NativeType::Point(arr.coord_type(), Dimension::XY);
+
+        let cast = arr.cast(to_type).unwrap();
+        assert_eq!(cast.len(), 2);
+        assert_eq!(cast.null_count(), 2);
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        assert!(arr.cast_with_options(to_type, &options).is_err());
+    }
+}
@@ -0,0 +1,815 @@
+use geo::coordinate_position::{CoordPos, CoordinatePosition};
+use geo::{Coord, LineString, MultiLineString, MultiPoint, MultiPolygon, Polygon, Winding};
+use geo_traits::{CoordTrait, RectTrait};
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::{GeoArrowError, Result};
+use crate::trait_::{ArrayAccessor, NativeScalar};
+use crate::NativeArray;
+
+/// Clip geometries to an axis-aligned rectangle, without linking GEOS.
+///
+/// Points on the boundary of the rectangle are kept. Lines are clipped segment by segment with
+/// the Liang–Barsky algorithm, so a line that leaves and re-enters the rectangle becomes several
+/// lines. Polygon rings are clipped the same way, and the pieces inside the rectangle are joined
+/// by walking counter-clockwise along the boundary of the rectangle. This keeps clipped polygons
+/// valid: a concave polygon that the rectangle cuts in two becomes a multi polygon rather than a
+/// single polygon with a zero-width bridge along the boundary.
+///
+/// Clipping can change the geometry type, so the output is always a [`GeometryArray`] of multi
+/// geometries. Rows that fall entirely outside the rectangle are empty in the output, as are rows
+/// whose input geometry or rectangle is null.
+///
+/// Clipping is two-dimensional: Z and M values are dropped, and the output is always XY.
+///
+/// Use [`ClipByRectScalar`] to clip every row to the same rectangle.
+///
+/// # Examples
+///
+/// ```
+/// use geo::line_string;
+/// use geoarrow::algorithm::native::ClipByRectScalar;
+/// use geoarrow::array::LineStringArray;
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::trait_::ArrayAccessor;
+///
+/// let line = line_string![(x: 0., y: 5.), (x: 10., y: 5.)];
+/// let array: LineStringArray = (vec![line].as_slice(), Dimension::XY).into();
+/// let rect = geo::Rect::new((2., 0.), (4., 10.));
+///
+/// let clipped = array.clip_by_rect(&rect).unwrap();
+/// assert_eq!(
+///     clipped.value_as_geo(0),
+///     geo::Geometry::MultiLineString(geo::MultiLineString::new(vec![
+///         line_string![(x: 2., y: 5.), (x: 4., y: 5.)]
+///     ]))
+/// );
+/// ```
+pub trait ClipByRect<Rhs> {
+    type Output;
+
+    fn clip_by_rect(&self, rhs: Rhs) -> Self::Output;
+}
+
+/// Clip every geometry in an array to the same axis-aligned rectangle, without linking GEOS.
+///
+/// See [`ClipByRect`] for details.
+pub trait ClipByRectScalar<G> {
+    type Output;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output;
+}
+
+/// An axis-aligned clipping window.
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    xmin: f64,
+    ymin: f64,
+    xmax: f64,
+    ymax: f64,
+}
+
+impl Window {
+    fn new(rect: &impl RectTrait<T = f64>) -> Self {
+        let (min, max) = (rect.min(), rect.max());
+        Self {
+            xmin: min.x(),
+            ymin: min.y(),
+            xmax: max.x(),
+            ymax: max.y(),
+        }
+    }
+
+    fn width(&self) -> f64 {
+        self.xmax - self.xmin
+    }
+
+    fn height(&self) -> f64 {
+        self.ymax - self.ymin
+    }
+
+    fn perimeter(&self) -> f64 {
+        2. * (self.width() + self.height())
+    }
+
+    fn contains(&self, coord: Coord) -> bool {
+        (self.xmin..=self.xmax).contains(&coord.x) && (self.ymin..=self.ymax).contains(&coord.y)
+    }
+
+    fn center(&self) -> Coord {
+        Coord {
+            x: (self.xmin + self.xmax) / 2.,
+            y: (self.ymin + self.ymax) / 2.,
+        }
+    }
+
+    /// The corners in counter-clockwise order, starting from the lower left.
+    fn corners(&self) -> [Coord; 4] {
+        [
+            Coord {
+                x: self.xmin,
+                y: self.ymin,
+            },
+            Coord {
+                x: self.xmax,
+                y: self.ymin,
+            },
+            Coord {
+                x: self.xmax,
+                y: self.ymax,
+            },
+            Coord {
+                x: self.xmin,
+                y: self.ymax,
+            },
+        ]
+    }
+
+    fn ring(&self) -> LineString {
+        let [a, b, c, d] = self.corners();
+        LineString::new(vec![a, b, c, d, a])
+    }
+
+    /// The distance from the lower left corner to `coord`, walking counter-clockwise along the
+    /// boundary. `coord` must be on the boundary.
+    fn boundary_position(&self, coord: Coord) -> f64 {
+        let (w, h) = (self.width(), self.height());
+        if coord.y == self.ymin && coord.x < self.xmax {
+            coord.x - self.xmin
+        } else if coord.x == self.xmax && coord.y < self.ymax {
+            w + coord.y - self.ymin
+        } else if coord.y == self.ymax && coord.x > self.xmin {
+            w + h + self.xmax - coord.x
+        } else {
+            2. * w + h + self.ymax - coord.y
+        }
+    }
+
+    /// Whether the segment from `a` to `b` runs along one edge of the boundary.
+    fn is_boundary_segment(&self, a: Coord, b: Coord) -> bool {
+        (a.x == b.x && (a.x == self.xmin || a.x == self.xmax))
+            || (a.y == b.y && (a.y == self.ymin || a.y == self.ymax))
+    }
+
+    /// Clip the segment from `a` to `b` with the Liang–Barsky algorithm, returning the part
+    /// inside the window and whether the segment had to be shortened.
+    ///
+    /// Endpoints that were moved onto the boundary are clamped to it exactly.
+    fn clip_segment(&self, a: Coord, b: Coord) -> Option<(Coord, Coord, bool)> {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let mut t0: f64 = 0.;
+        let mut t1: f64 = 1.;
+        for (p, q) in [
+            (-dx, a.x - self.xmin),
+            (dx, self.xmax - a.x),
+            (-dy, a.y - self.ymin),
+            (dy, self.ymax - a.y),
+        ] {
+            if p == 0. {
+                if q < 0. {
+                    return None;
+                }
+            } else {
+                let r = q / p;
+                if p < 0. {
+                    t0 = t0.max(r);
+                } else {
+                    t1 = t1.min(r);
+                }
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+
+        let at = |t: f64| {
+            if t == 0. {
+                a
+            } else if t == 1. {
+                b
+            } else {
+                Coord {
+                    x: (a.x + t * dx).clamp(self.xmin, self.xmax),
+                    y: (a.y + t * dy).clamp(self.ymin, self.ymax),
+                }
+            }
+        };
+        Some((at(t0), at(t1), t0 > 0. || t1 < 1.))
+    }
+}
+
+/// Move the current part to `parts` if it has at least two coordinates.
+fn finish_part(current: &mut Vec<Coord>, parts: &mut Vec<Vec<Coord>>) {
+    if current.len() > 1 {
+        parts.push(std::mem::take(current));
+    } else {
+        current.clear();
+    }
+}
+
+/// Clip a sequence of coordinates to `window`, returning the parts inside it and whether anything
+/// was removed.
+///
+/// With `split_on_boundary`, segments running along the boundary are removed too, which is what
+/// polygon rings need.
+fn clip_coords(
+    coords: &[Coord],
+    window: &Window,
+    split_on_boundary: bool,
+) -> (Vec<Vec<Coord>>, bool) {
+    let mut parts = vec![];
+    let mut current: Vec<Coord> = vec![];
+    let mut modified = false;
+    for segment in coords.windows(2) {
+        let (a, b) = (segment[0], segment[1]);
+        if a == b {
+            continue;
+        }
+        match window.clip_segment(a, b) {
+            Some((start, end, shortened))
+                if start != end
+                    && !(split_on_boundary && window.is_boundary_segment(start, end)) =>
+            {
+                if current.last() != Some(&start) {
+                    finish_part(&mut current, &mut parts);
+                    current.push(start);
+                }
+                current.push(end);
+                if shortened {
+                    modified = true;
+                    if end != b {
+                        finish_part(&mut current, &mut parts);
+                    }
+                }
+            }
+            _ => {
+                modified = true;
+                finish_part(&mut current, &mut parts);
+            }
+        }
+    }
+    finish_part(&mut current, &mut parts);
+    (parts, modified)
+}
+
+fn clip_line_string(line_string: &LineString, window: &Window) -> Vec<LineString> {
+    clip_coords(&line_string.0, window, false)
+        .0
+        .into_iter()
+        .map(LineString::new)
+        .collect()
+}
+
+/// How a ring relates to the clipping window.
+enum ClippedRing {
+    /// The ring does not enter the interior of the window.
+    Outside,
+    /// The ring lies entirely within the window.
+    Inside,
+    /// The parts of the ring inside the window, each starting and ending on its boundary.
+    Pieces(Vec<Vec<Coord>>),
+}
+
+fn clip_ring(ring: &LineString, window: &Window) -> ClippedRing {
+    let (mut pieces, modified) = clip_coords(&ring.0, window, true);
+    if pieces.is_empty() {
+        return ClippedRing::Outside;
+    }
+    if !modified {
+        return ClippedRing::Inside;
+    }
+
+    // A ring that starts inside the window is split at its start; rejoin the first and last
+    // pieces so that every piece starts and ends on the boundary.
+    let start = ring.0[0];
+    let on_boundary = start.x == window.xmin
+        || start.x == window.xmax
+        || start.y == window.ymin
+        || start.y == window.ymax;
+    if window.contains(start) && !on_boundary && pieces.len() > 1 {
+        let first = pieces.remove(0);
+        pieces.last_mut().unwrap().extend_from_slice(&first[1..]);
+    }
+    ClippedRing::Pieces(pieces)
+}
+
+/// Join ring pieces into closed rings by walking counter-clockwise along the window boundary from
+/// the end of each piece to the next piece start.
+///
+/// Exterior pieces must be counter-clockwise and interior pieces clockwise, so that the clipped
+/// polygon is always to the left of both the pieces and the walk.
+fn join_pieces(pieces: Vec<Vec<Coord>>, window: &Window) -> Vec<LineString> {
+    let perimeter = window.perimeter();
+    let ahead = |from: f64, to: f64| (to - from).rem_euclid(perimeter);
+    let corners = window
+        .corners()
+        .map(|corner| (window.boundary_position(corner), corner));
+    let starts = pieces
+        .iter()
+        .map(|piece| window.boundary_position(piece[0]))
+        .collect::<Vec<_>>();
+
+    let mut used = vec![false; pieces.len()];
+    let mut rings = vec![];
+    for first in 0..pieces.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+        let mut ring = pieces[first].clone();
+        loop {
+            let end = window.boundary_position(*ring.last().unwrap());
+            let next = (0..pieces.len())
+                .filter(|i| *i == first || !used[*i])
+                .min_by(|a, b| ahead(end, starts[*a]).total_cmp(&ahead(end, starts[*b])))
+                .unwrap();
+
+            let distance = ahead(end, starts[next]);
+            let mut passed = corners
+                .iter()
+                .filter(|(position, _)| {
+                    let to_corner = ahead(end, *position);
+                    to_corner > 0. && to_corner < distance
+                })
+                .collect::<Vec<_>>();
+            passed.sort_by(|a, b| ahead(end, a.0).total_cmp(&ahead(end, b.0)));
+            ring.extend(passed.into_iter().map(|(_, corner)| *corner));
+
+            if next == first {
+                ring.push(ring[0]);
+                break;
+            }
+            used[next] = true;
+            let piece = &pieces[next];
+            let skip = usize::from(ring.last() == Some(&piece[0]));
+            ring.extend_from_slice(&piece[skip..]);
+        }
+
+        ring.dedup();
+        let ring = LineString::new(ring);
+        if ring.0.len() >= 4 && signed_area(&ring) > 0. {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+/// Twice the signed area of a closed ring, positive for counter-clockwise rings.
+fn signed_area(ring: &LineString) -> f64 {
+    ring.lines()
+        .map(|line| line.start.x * line.end.y - line.end.x * line.start.y)
+        .sum()
+}
+
+/// Whether `ring` contains `hole`, judged by the first vertex of the hole not on the ring.
+fn ring_contains(ring: &LineString, hole: &LineString) -> bool {
+    let shell = Polygon::new(ring.clone(), vec![]);
+    hole.0
+        .iter()
+        .map(|coord| shell.coordinate_position(coord))
+        .find(|position| *position != CoordPos::OnBoundary)
+        == Some(CoordPos::Inside)
+}
+
+/// Whether the closed `ring` contains the center of the window.
+fn ring_covers(ring: &LineString, window: &Window) -> bool {
+    Polygon::new(ring.clone(), vec![]).coordinate_position(&window.center()) == CoordPos::Inside
+}
+
+fn clip_polygon(polygon: &Polygon, window: &Window) -> Vec<Polygon> {
+    let mut exterior = polygon.exterior().clone();
+    exterior.make_ccw_winding();
+
+    let mut pieces = vec![];
+    let shell = match clip_ring(&exterior, window) {
+        ClippedRing::Inside => Some(exterior),
+        ClippedRing::Outside if ring_covers(&exterior, window) => None,
+        ClippedRing::Outside => return vec![],
+        ClippedRing::Pieces(exterior_pieces) => {
+            pieces.extend(exterior_pieces);
+            None
+        }
+    };
+
+    let mut holes = vec![];
+    for interior in polygon.interiors() {
+        let mut interior = interior.clone();
+        interior.make_cw_winding();
+        match clip_ring(&interior, window) {
+            ClippedRing::Inside => holes.push(interior),
+            ClippedRing::Outside if ring_covers(&interior, window) => return vec![],
+            ClippedRing::Outside => {}
+            ClippedRing::Pieces(interior_pieces) => pieces.extend(interior_pieces),
+        }
+    }
+
+    let shells = match shell {
+        Some(shell) => vec![shell],
+        None if pieces.is_empty() => vec![window.ring()],
+        None => join_pieces(pieces, window),
+    };
+
+    let mut polygons = shells
+        .into_iter()
+        .map(|shell| (shell, vec![]))
+        .collect::<Vec<_>>();
+    for hole in holes {
+        if let Some((_, shell_holes)) = polygons
+            .iter_mut()
+            .find(|(shell, _)| ring_contains(shell, &hole))
+        {
+            shell_holes.push(hole);
+        }
+    }
+    polygons
+        .into_iter()
+        .map(|(shell, holes)| Polygon::new(shell, holes))
+        .collect()
+}
+
+fn clip_geometry(geometry: &geo::Geometry, window: &Window) -> Option<geo::Geometry> {
+    use geo::Geometry;
+
+    let clipped = match geometry {
+        Geometry::Point(g) => window
+            .contains(g.0)
+            .then(|| Geometry::MultiPoint(MultiPoint::new(vec![*g])))?,
+        Geometry::MultiPoint(g) => {
+            let points = g.iter().filter(|p| window.contains(p.0)).copied().collect();
+            Geometry::MultiPoint(MultiPoint::new(points))
+        }
+        Geometry::Line(g) => Geometry::MultiLineString(MultiLineString::new(clip_line_string(
+            &LineString::from(*g),
+            window,
+        ))),
+        Geometry::LineString(g) => {
+            Geometry::MultiLineString(MultiLineString::new(clip_line_string(g, window)))
+        }
+        Geometry::MultiLineString(g) => Geometry::MultiLineString(MultiLineString::new(
+            g.iter()
+                .flat_map(|line_string| clip_line_string(line_string, window))
+                .collect(),
+        )),
+        Geometry::Polygon(g) => Geometry::MultiPolygon(MultiPolygon::new(clip_polygon(g, window))),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(MultiPolygon::new(
+            g.iter()
+                .flat_map(|polygon| clip_polygon(polygon, window))
+                .collect(),
+        )),
+        Geometry::Rect(g) => {
+            Geometry::MultiPolygon(MultiPolygon::new(clip_polygon(&g.to_polygon(), window)))
+        }
+        Geometry::Triangle(g) => {
+            Geometry::MultiPolygon(MultiPolygon::new(clip_polygon(&g.to_polygon(), window)))
+        }
+        Geometry::GeometryCollection(g) => {
+            Geometry::GeometryCollection(geo::GeometryCollection::new_from(
+                g.iter()
+                    .filter_map(|geometry| clip_geometry(geometry, window))
+                    .collect(),
+            ))
+        }
+    };
+
+    let is_empty = match &clipped {
+        Geometry::MultiPoint(g) => g.0.is_empty(),
+        Geometry::MultiLineString(g) => g.0.is_empty(),
+        Geometry::MultiPolygon(g) => g.0.is_empty(),
+        Geometry::GeometryCollection(g) => g.0.is_empty(),
+        _ => false,
+    };
+    (!is_empty).then_some(clipped)
+}
+
+fn clip_geometries<'a, A>(
+    array: &'a A,
+    window_at: impl Fn(usize) -> Option<Window>,
+) -> Result<GeometryArray>
+where
+    A: ArrayAccessor<'a> + NativeArray,
+{
+    let geometries = array
+        .iter()
+        .enumerate()
+        .map(|(i, geometry)| {
+            let window = window_at(i)?;
+            clip_geometry(&geometry?.to_geo_geometry(), &window)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(GeometryBuilder::from_nullable_geometries(
+        geometries.as_slice(),
+        array.coord_type(),
+        array.metadata(),
+        true,
+    )?
+    .finish())
+}
+
+macro_rules! iter_geo_impl {
+    ($type:ty) => {
+        impl ClipByRect<&RectArray> for $type {
+            type Output = Result<GeometryArray>;
+
+            fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+                if self.len() != rhs.len() {
+                    return Err(GeoArrowError::General(
+                        "Cannot clip arrays of different length".to_string(),
+                    ));
+                }
+                self.metadata()
+                    .check_crs_compatible(&rhs.metadata(), "clip_by_rect")?;
+
+                clip_geometries(self, |i| rhs.get(i).map(|rect| Window::new(&rect)))
+            }
+        }
+
+        impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for $type {
+            type Output = Result<GeometryArray>;
+
+            fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+                let window = Window::new(rhs);
+                clip_geometries(self, |_| Some(window))
+            }
+        }
+    };
+}
+
+iter_geo_impl!(PointArray);
+iter_geo_impl!(LineStringArray);
+iter_geo_impl!(PolygonArray);
+iter_geo_impl!(MultiPointArray);
+iter_geo_impl!(MultiLineStringArray);
+iter_geo_impl!(MultiPolygonArray);
+iter_geo_impl!(MixedGeometryArray);
+iter_geo_impl!(GeometryCollectionArray);
+iter_geo_impl!(RectArray);
+iter_geo_impl!(GeometryArray);
+iter_geo_impl!(LargeLineStringArray);
+iter_geo_impl!(LargePolygonArray);
+iter_geo_impl!(LargeMultiPointArray);
+iter_geo_impl!(LargeMultiLineStringArray);
+iter_geo_impl!(LargeMultiPolygonArray);
+iter_geo_impl!(LargeMixedGeometryArray);
+iter_geo_impl!(LargeGeometryCollectionArray);
+iter_geo_impl!(LargeGeometryArray);
+
+macro_rules! impl_dyn {
+    ($self:expr, $rhs:expr, $trait:ident) => {{
+        use NativeType::*;
+
+        let arr = $self;
+        match arr.data_type() {
+            Point(_, _) => $trait::clip_by_rect(arr.as_point(), $rhs),
+            LineString(_, _) => $trait::clip_by_rect(arr.as_line_string(), $rhs),
+            LargeLineString(_, _) => $trait::clip_by_rect(arr.as_large_line_string(), $rhs),
+            Polygon(_, _) => $trait::clip_by_rect(arr.as_polygon(), $rhs),
+            LargePolygon(_, _) => $trait::clip_by_rect(arr.as_large_polygon(), $rhs),
+            MultiPoint(_, _) => $trait::clip_by_rect(arr.as_multi_point(), $rhs),
+            LargeMultiPoint(_, _) => $trait::clip_by_rect(arr.as_large_multi_point(), $rhs),
+            MultiLineString(_, _) => $trait::clip_by_rect(arr.as_multi_line_string(), $rhs),
+            LargeMultiLineString(_, _) => {
+                $trait::clip_by_rect(arr.as_large_multi_line_string(), $rhs)
+            }
+            MultiPolygon(_, _) => $trait::clip_by_rect(arr.as_multi_polygon(), $rhs),
+            LargeMultiPolygon(_, _) => $trait::clip_by_rect(arr.as_large_multi_polygon(), $rhs),
+            Mixed(_, _) => $trait::clip_by_rect(arr.as_mixed(), $rhs),
+            LargeMixed(_, _) => $trait::clip_by_rect(arr.as_large_mixed(), $rhs),
+            GeometryCollection(_, _) => $trait::clip_by_rect(arr.as_geometry_collection(), $rhs),
+            LargeGeometryCollection(_, _) => {
+                $trait::clip_by_rect(arr.as_large_geometry_collection(), $rhs)
+            }
+            Rect(_) => $trait::clip_by_rect(arr.as_rect(), $rhs),
+            Geometry(_) => $trait::clip_by_rect(arr.as_geometry(), $rhs),
+            LargeGeometry(_) => $trait::clip_by_rect(arr.as_large_geometry(), $rhs),
+        }
+    }};
+}
+
+impl ClipByRect<&RectArray> for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+        impl_dyn!(*self, rhs, ClipByRect)
+    }
+}
+
+impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for &dyn NativeArray {
+    type Output = Result<GeometryArray>;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+        impl_dyn!(*self, rhs, ClipByRectScalar)
+    }
+}
+
+impl ClipByRect<&RectArray> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn clip_by_rect(&self, rhs: &RectArray) -> Self::Output {
+        if self.len() != rhs.len() {
+            return Err(GeoArrowError::General(
+                "Cannot clip arrays of different length".to_string(),
+            ));
+        }
+
+        let mut offset = 0;
+        let mut chunks = vec![];
+        for chunk in self.geometry_chunks() {
+            let rects = rhs.slice(offset, chunk.len());
+            offset += chunk.len();
+            chunks.push(ClipByRect::clip_by_rect(&chunk.as_ref(), &rects)?);
+        }
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+impl<G: RectTrait<T = f64>> ClipByRectScalar<G> for &dyn ChunkedNativeArray {
+    type Output = Result<ChunkedGeometryArray<GeometryArray>>;
+
+    fn clip_by_rect(&self, rhs: &G) -> Self::Output {
+        Ok(ChunkedGeometryArray::new(
+            self.geometry_chunks()
+                .iter()
+                .map(|chunk| ClipByRectScalar::clip_by_rect(&chunk.as_ref(), rhs))
+                .collect::<Result<Vec<_>>>()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use geo::{line_string, polygon, Area, HasDimensions};
+
+    use super::*;
+    use crate::algorithm::geo::IsValid;
+    use crate::datatypes::Dimension;
+
+    fn u_shape() -> Polygon {
+        polygon![
+            (x: 0., y: 0.),
+            (x: 3., y: 0.),
+            (x: 3., y: 3.),
+            (x: 2., y: 3.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 3.),
+            (x: 0., y: 3.),
+            (x: 0., y: 0.),
+        ]
+    }
+
+    #[test]
+    fn clip_lines() {
+        let lines: LineStringArray = (
+            vec![
+                line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+                line_string![(x: 0., y: 0.), (x: 3., y: 5.), (x: 6., y: 0.)],
+                line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let rects: RectArray = (
+            vec![
+                geo::Rect::new((2., 0.), (4., 10.)),
+                geo::Rect::new((0., 1.), (6., 4.)),
+                geo::Rect::new((20., 20.), (30., 30.)),
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+
+        let clipped = ClipByRect::clip_by_rect(&lines, &rects).unwrap();
+        assert_eq!(
+            clipped.value_as_geo(0),
+            geo::Geometry::MultiLineString(MultiLineString::new(vec![
+                line_string![(x: 2., y: 5.), (x: 4., y: 5.)]
+            ]))
+        );
+        let geo::Geometry::MultiLineString(parts) = clipped.value_as_geo(1) else {
+            panic!("expected a multi line string");
+        };
+        assert_eq!(parts.0.len(), 2);
+        assert!(clipped.value_as_geo(2).is_empty());
+    }
+
+    #[test]
+    fn clip_concave_polygon_stays_valid() {
+        let arr: PolygonArray = (vec![u_shape()].as_slice(), Dimension::XY).into();
+        let window = geo::Rect::new((0., 2.), (3., 3.));
+
+        let clipped = ClipByRectScalar::clip_by_rect(&arr, &window).unwrap();
+        let geo::Geometry::MultiPolygon(parts) = clipped.value_as_geo(0) else {
+            panic!("expected a multi polygon");
+        };
+        assert_eq!(parts.0.len(), 2);
+        assert_eq!(parts.unsigned_area(), 2.);
+
+        let (valid, reasons) = IsValid::is_valid(&clipped);
+        assert!(valid.value(0), "{}", reasons.value(0));
+    }
+
+    #[test]
+    fn clip_polygon_with_holes() {
+        let square = polygon!(
+            exterior: [
+                (x: 0., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 10.),
+                (x: 0., y: 10.),
+                (x: 0., y: 0.),
+            ],
+            interiors: [
+                [
+                    (x: 1., y: 1.),
+                    (x: 1., y: 2.),
+                    (x: 2., y: 2.),
+                    (x: 2., y: 1.),
+                    (x: 1., y: 1.),
+                ],
+                [
+                    (x: 4., y: 4.),
+                    (x: 4., y: 6.),
+                    (x: 6., y: 6.),
+                    (x: 6., y: 4.),
+                    (x: 4., y: 4.),
+                ],
+            ],
+        );
+        let arr: PolygonArray = (vec![square].as_slice(), Dimension::XY).into();
+
+        // The window lies inside the shell and cuts through the second hole.
+        let window = geo::Rect::new((0.5, 0.5), (5., 5.));
+        let clipped = ClipByRectScalar::clip_by_rect(&arr, &window).unwrap();
+        let geo::Geometry::MultiPolygon(parts) = clipped.value_as_geo(0) else {
+            panic!("expected a multi polygon");
+        };
+        assert_eq!(parts.0.len(), 1);
+        assert_eq!(parts.0[0].interiors().len(), 1);
+        assert_eq!(parts.unsigned_area(), 4.5 * 4.5 - 1. - 1.);
+        let (valid, reasons) = IsValid::is_valid(&clipped);
+        assert!(valid.value(0), "{}", reasons.value(0));
+
+        // The window lies inside a hole.
+        let window = geo::Rect::new((4.5, 4.5), (5.5, 5.5));
+        let clipped = ClipByRectScalar::clip_by_rect(&arr, &window).unwrap();
+        assert!(clipped.value_as_geo(0).is_empty());
+
+        // The window lies inside the shell, away from the holes.
+        let window = geo::Rect::new((7., 7.), (8., 8.));
+        let clipped = ClipByRectScalar::clip_by_rect(&arr, &window).unwrap();
+        assert_eq!(
+            clipped.value_as_geo(0),
+            geo::Geometry::MultiPolygon(MultiPolygon::new(vec![polygon![
+                (x: 7., y: 7.),
+                (x: 8., y: 7.),
+                (x: 8., y: 8.),
+                (x: 7., y: 8.),
+                (x: 7., y: 7.),
+            ]]))
+        );
+    }
+
+    #[test]
+    fn clip_chunked_to_rect_array() {
+        let polygons: PolygonArray = (
+            vec![u_shape(), u_shape(), u_shape()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let chunked = ChunkedGeometryArray::new(vec![polygons.slice(0, 2), polygons.slice(2, 1)]);
+        let rects: RectArray = (
+            vec![
+                geo::Rect::new((0., 2.), (3., 3.)),
+                geo::Rect::new((0., 0.), (3., 1.)),
+                geo::Rect::new((10., 10.), (11., 11.)),
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .into();
+
+        let chunked = &chunked as &dyn ChunkedNativeArray;
+        let clipped = ClipByRect::clip_by_rect(&chunked, &rects).unwrap();
+        assert_eq!(clipped.chunks().len(), 2);
+        assert!(clipped.chunks()[0].iter_geo_values().all(|g| !g.is_empty()));
+        assert!(clipped.chunks()[1].value_as_geo(0).is_empty());
+    }
+
+    #[test]
+    fn clip_drops_z() {
+        let points = crate::test::point::point_z_array();
+        let clipped =
+            ClipByRectScalar::clip_by_rect(&points, &geo::Rect::new((0., 0.), (4., 4.))).unwrap();
+        assert!(clipped.has_only_dimension(Dimension::XY));
+        assert_eq!(
+            clipped.value_as_geo(1),
+            geo::Geometry::MultiPoint(vec![geo::point!(x: 3., y: 4.)].into())
+        );
+        assert!(clipped.value_as_geo(2).is_empty());
+    }
+}
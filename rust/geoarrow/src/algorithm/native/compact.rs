@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use arrow::compute::take_record_batch;
+use arrow_array::UInt32Array;
+
+use crate::algorithm::native::filter::{take_native, take_storage};
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray, ChunkedNativeArrayDyn};
+use crate::error::Result;
+use crate::table::Table;
+use crate::{ArrayBase, NativeArray};
+
+/// Copy the data of an array into buffers sized to fit it exactly.
+///
+/// A slice of an array shares the buffers of the array it was sliced from: its offsets don't start
+/// at zero and its coordinate buffer still holds the coordinates outside of the slice. Compacting
+/// rewrites the slice with zero-based offsets and only the coordinates it references, which avoids
+/// exporting or keeping alive the full buffers, e.g. before sending the data over FFI or IPC.
+pub trait Compact {
+    type Output;
+
+    /// Rewrite this array into new buffers that hold only its own data.
+    ///
+    /// This always copies, even if the array is not a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Compact;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::Dimension;
+    /// use geoarrow::ArrayBase;
+    ///
+    /// let points = vec![
+    ///     geo::point!(x: 0., y: 0.),
+    ///     geo::point!(x: 1., y: 1.),
+    ///     geo::point!(x: 2., y: 2.),
+    /// ];
+    /// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+    ///
+    /// let sliced = array.slice(1, 1);
+    /// let compacted = sliced.compact().unwrap();
+    /// assert!(compacted.get_buffer_memory_size() < sliced.get_buffer_memory_size());
+    /// ```
+    fn compact(&self) -> Self::Output;
+}
+
+fn all_indices(len: usize) -> UInt32Array {
+    UInt32Array::from_iter_values(0..len as u32)
+}
+
+macro_rules! compact_impl {
+    ($type:ty) => {
+        impl Compact for $type {
+            type Output = Result<Self>;
+
+            fn compact(&self) -> Self::Output {
+                let taken = take_storage(self, &all_indices(self.len()))?;
+                Self::try_from((taken.as_ref(), self.extension_field().as_ref()))
+            }
+        }
+
+        impl Compact for ChunkedGeometryArray<$type> {
+            type Output = Result<Self>;
+
+            fn compact(&self) -> Self::Output {
+                let chunks = self
+                    .chunks
+                    .iter()
+                    .map(|chunk| chunk.compact())
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ChunkedGeometryArray::new(chunks))
+            }
+        }
+    };
+}
+
+compact_impl!(PointArray);
+compact_impl!(LineStringArray);
+compact_impl!(PolygonArray);
+compact_impl!(MultiPointArray);
+compact_impl!(MultiLineStringArray);
+compact_impl!(MultiPolygonArray);
+compact_impl!(MixedGeometryArray);
+compact_impl!(GeometryCollectionArray);
+compact_impl!(RectArray);
+compact_impl!(GeometryArray);
+compact_impl!(LargeLineStringArray);
+compact_impl!(LargePolygonArray);
+compact_impl!(LargeMultiPointArray);
+compact_impl!(LargeMultiLineStringArray);
+compact_impl!(LargeMultiPolygonArray);
+compact_impl!(LargeMixedGeometryArray);
+compact_impl!(LargeGeometryCollectionArray);
+compact_impl!(LargeGeometryArray);
+
+impl Compact for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn compact(&self) -> Self::Output {
+        take_native(*self, &all_indices(self.len()))
+    }
+}
+
+impl Compact for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn compact(&self) -> Self::Output {
+        let chunks = self
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.as_ref().compact())
+            .collect::<Result<Vec<_>>>()?;
+        let chunk_refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(&chunk_refs)?.into_inner())
+    }
+}
+
+impl Compact for Table {
+    type Output = Result<Table>;
+
+    fn compact(&self) -> Self::Output {
+        let batches = self
+            .batches()
+            .iter()
+            .map(|batch| Ok(take_record_batch(batch, &all_indices(batch.num_rows()))?))
+            .collect::<Result<Vec<_>>>()?;
+        Table::try_new(batches, self.schema().clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::test::linestring;
+    use crate::trait_::ArrayAccessor;
+
+    fn line_strings() -> LineStringArray {
+        (
+            vec![
+                Some(linestring::ls0()),
+                Some(linestring::ls1()),
+                None,
+                Some(linestring::ls0()),
+            ],
+            Dimension::XY,
+        )
+            .into()
+    }
+
+    #[test]
+    fn compact_sliced_line_string() {
+        let arr = line_strings();
+        let sliced = arr.slice(1, 2);
+        assert_eq!(
+            sliced.get_buffer_memory_size(),
+            arr.get_buffer_memory_size()
+        );
+
+        let compacted = sliced.compact().unwrap();
+        assert_eq!(compacted.geom_offsets[0], 0);
+        assert_eq!(compacted.coords.len(), linestring::ls1().0.len());
+        assert!(compacted.get_buffer_memory_size() < sliced.get_buffer_memory_size());
+
+        assert_eq!(compacted.len(), 2);
+        assert_eq!(compacted.value_as_geo(0), linestring::ls1());
+        assert!(compacted.is_null(1));
+        assert_eq!(compacted.metadata(), arr.metadata());
+    }
+
+    #[test]
+    fn chunked_memory_counts_shared_buffers_once() {
+        let arr = line_strings();
+        let chunked = ChunkedGeometryArray::new(vec![arr.slice(0, 2), arr.slice(2, 2)]);
+        let chunked_ref = &chunked as &dyn ChunkedNativeArray;
+        assert_eq!(
+            chunked_ref.get_buffer_memory_size(),
+            arr.get_buffer_memory_size()
+        );
+
+        let compacted = chunked.compact().unwrap();
+        assert_eq!(compacted.len(), 4);
+        assert_eq!(compacted.chunks()[1].value_as_geo(1), linestring::ls0());
+    }
+}
@@ -1,25 +1,46 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 
+use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::Dimension;
 use crate::error::Result;
-use crate::trait_::ArrayAccessor;
+use crate::trait_::{ArrayAccessor, GeometryArrayBuilder};
 
 pub trait Concatenate: Sized {
     type Output;
 
-    fn concatenate(&self) -> Self::Output;
+    /// Concatenate the arrays, returning an error if their CRSs are not equivalent.
+    ///
+    /// Inputs without a CRS are compatible with any CRS.
+    fn concatenate(&self) -> Self::Output {
+        self.concatenate_with_crs_check(true)
+    }
+
+    /// Concatenate the arrays without comparing their CRSs.
+    ///
+    /// The output has the CRS of the first input that has one.
+    fn concatenate_unchecked(&self) -> Self::Output {
+        self.concatenate_with_crs_check(false)
+    }
+
+    /// Concatenate the arrays, comparing their CRSs only if `check_crs` is `true`.
+    fn concatenate_with_crs_check(&self, check_crs: bool) -> Self::Output;
 }
 
 impl Concatenate for &[PointArray] {
     type Output = Result<PointArray>;
 
-    fn concatenate(&self) -> Self::Output {
+    fn concatenate_with_crs_check(&self, check_crs: bool) -> Self::Output {
         let common_dimension = infer_common_dimension(self.iter().map(|arr| arr.dimension()));
 
         let output_capacity = self.iter().fold(0, |sum, val| sum + val.buffer_lengths());
         let mut builder = PointBuilder::with_capacity(common_dimension, output_capacity);
+        builder.set_metadata(common_metadata(
+            self.iter().map(|arr| arr.metadata()),
+            check_crs,
+        )?);
         self.iter()
             .for_each(|chunk| chunk.iter().for_each(|p| builder.push_point(p.as_ref())));
         Ok(builder.finish())
@@ -31,7 +52,7 @@ macro_rules! impl_concatenate {
         impl Concatenate for &[$array] {
             type Output = Result<$array>;
 
-            fn concatenate(&self) -> Self::Output {
+            fn concatenate_with_crs_check(&self, check_crs: bool) -> Self::Output {
                 let common_dimension =
                     infer_common_dimension(self.iter().map(|arr| arr.dimension()));
 
@@ -39,6 +60,10 @@ macro_rules! impl_concatenate {
                     sum + val.buffer_lengths()
                 });
                 let mut builder = <$builder>::with_capacity(common_dimension, output_capacity);
+                builder.set_metadata(common_metadata(
+                    self.iter().map(|arr| arr.metadata()),
+                    check_crs,
+                )?);
                 for chunk in self.iter() {
                     for geom in chunk.iter() {
                         builder.$push_func(geom.as_ref())?;
@@ -94,11 +119,34 @@ fn infer_common_dimension(dimensions: impl Iterator<Item = Dimension>) -> Dimens
     dimensions.into_iter().next().unwrap()
 }
 
+/// The metadata of the concatenated array, checking that the CRSs of all inputs are compatible if
+/// `check_crs` is `true`.
+///
+/// This is the metadata of the first input with a CRS, or of the first input if none has one.
+fn common_metadata(
+    metadata: impl Iterator<Item = Arc<ArrayMetadata>>,
+    check_crs: bool,
+) -> Result<Arc<ArrayMetadata>> {
+    let metadata = metadata.collect::<Vec<_>>();
+    let common = metadata
+        .iter()
+        .find(|meta| meta.crs.is_some())
+        .or(metadata.first())
+        .cloned()
+        .unwrap_or_default();
+    if check_crs {
+        for meta in metadata.iter() {
+            common.check_crs_compatible(meta, "Concatenate")?;
+        }
+    }
+    Ok(common)
+}
+
 impl Concatenate for ChunkedPointArray {
     type Output = Result<PointArray>;
 
-    fn concatenate(&self) -> Self::Output {
-        self.chunks.as_slice().concatenate()
+    fn concatenate_with_crs_check(&self, check_crs: bool) -> Self::Output {
+        self.chunks.as_slice().concatenate_with_crs_check(check_crs)
     }
 }
 
@@ -107,8 +155,8 @@ macro_rules! impl_chunked_concatenate {
         impl Concatenate for $chunked_array {
             type Output = Result<$output_array>;
 
-            fn concatenate(&self) -> Self::Output {
-                self.chunks.as_slice().concatenate()
+            fn concatenate_with_crs_check(&self, check_crs: bool) -> Self::Output {
+                self.chunks.as_slice().concatenate_with_crs_check(check_crs)
             }
         }
     };
@@ -121,3 +169,32 @@ impl_chunked_concatenate!(ChunkedMultiLineStringArray, MultiLineStringArray);
 impl_chunked_concatenate!(ChunkedMultiPolygonArray, MultiPolygonArray);
 impl_chunked_concatenate!(ChunkedMixedGeometryArray, MixedGeometryArray);
 impl_chunked_concatenate!(ChunkedGeometryCollectionArray, GeometryCollectionArray);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    #[test]
+    fn concatenate_checks_crs() {
+        let wgs84 = Arc::new(ArrayMetadata::from_authority_code("EPSG:4326".to_string()));
+        let web_mercator = Arc::new(ArrayMetadata::from_authority_code("EPSG:3857".to_string()));
+
+        let left = point::point_array().with_metadata(wgs84.clone());
+        let right = point::point_array();
+        let chunks = [left.as_ref().as_point().clone(), right];
+        let concatenated = chunks.as_slice().concatenate().unwrap();
+        assert_eq!(concatenated.len(), 6);
+        assert_eq!(concatenated.metadata(), wgs84);
+
+        let right = point::point_array().with_metadata(web_mercator);
+        let chunks = [
+            left.as_ref().as_point().clone(),
+            right.as_ref().as_point().clone(),
+        ];
+        assert!(chunks.as_slice().concatenate().is_err());
+
+        let concatenated = chunks.as_slice().concatenate_unchecked().unwrap();
+        assert_eq!(concatenated.metadata(), wgs84);
+    }
+}
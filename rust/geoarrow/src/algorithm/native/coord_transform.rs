@@ -0,0 +1,212 @@
+//! Rebuild geometry arrays after transforming their coordinate buffers.
+//!
+//! This is the shared machinery for coordinate operations that keep the geometry structure
+//! as-is, such as reprojection: offsets and validity are reused and only the coordinates and the
+//! metadata change.
+
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::*;
+use crate::chunked_array::*;
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::{ArrayBase, NativeArray};
+
+/// A transformation of coordinate buffers, applied to every geometry array type.
+///
+/// Implementors define how a single [`CoordBuffer`] and the array metadata are transformed; the
+/// provided methods rebuild each array type around the transformed coordinates.
+pub(crate) trait CoordTransform {
+    /// Transform one coordinate buffer. The output must have the same number of coordinates.
+    fn coords(&self, coords: &CoordBuffer) -> Result<CoordBuffer>;
+
+    /// The metadata of an output array, given the metadata of the input array.
+    fn metadata(&self, metadata: &ArrayMetadata) -> Arc<ArrayMetadata>;
+
+    fn point(&self, array: &PointArray) -> Result<PointArray> {
+        Ok(PointArray::new(
+            self.coords(&array.coords)?,
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn line_string<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericLineStringArray<O>,
+    ) -> Result<GenericLineStringArray<O>> {
+        Ok(GenericLineStringArray::new(
+            self.coords(&array.coords)?,
+            array.geom_offsets.clone(),
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn polygon<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericPolygonArray<O>,
+    ) -> Result<GenericPolygonArray<O>> {
+        Ok(GenericPolygonArray::new(
+            self.coords(&array.coords)?,
+            array.geom_offsets.clone(),
+            array.ring_offsets.clone(),
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn multi_point<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericMultiPointArray<O>,
+    ) -> Result<GenericMultiPointArray<O>> {
+        Ok(GenericMultiPointArray::new(
+            self.coords(&array.coords)?,
+            array.geom_offsets.clone(),
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn multi_line_string<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericMultiLineStringArray<O>,
+    ) -> Result<GenericMultiLineStringArray<O>> {
+        Ok(GenericMultiLineStringArray::new(
+            self.coords(&array.coords)?,
+            array.geom_offsets.clone(),
+            array.ring_offsets.clone(),
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn multi_polygon<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericMultiPolygonArray<O>,
+    ) -> Result<GenericMultiPolygonArray<O>> {
+        Ok(GenericMultiPolygonArray::new(
+            self.coords(&array.coords)?,
+            array.geom_offsets.clone(),
+            array.polygon_offsets.clone(),
+            array.ring_offsets.clone(),
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn mixed<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericMixedGeometryArray<O>,
+    ) -> Result<GenericMixedGeometryArray<O>> {
+        Ok(GenericMixedGeometryArray::new(
+            array.type_ids.clone(),
+            array.offsets.clone(),
+            Some(self.point(&array.points)?),
+            Some(self.line_string(&array.line_strings)?),
+            Some(self.polygon(&array.polygons)?),
+            Some(self.multi_point(&array.multi_points)?),
+            Some(self.multi_line_string(&array.multi_line_strings)?),
+            Some(self.multi_polygon(&array.multi_polygons)?),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn geometry_collection<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericGeometryCollectionArray<O>,
+    ) -> Result<GenericGeometryCollectionArray<O>> {
+        Ok(GenericGeometryCollectionArray::new(
+            self.mixed(&array.array)?,
+            array.geom_offsets.clone(),
+            array.validity.clone(),
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    fn geometry<O: OffsetSizeTrait>(
+        &self,
+        array: &GenericGeometryArray<O>,
+    ) -> Result<GenericGeometryArray<O>> {
+        Ok(GenericGeometryArray::new(
+            array.type_ids.clone(),
+            array.offsets.clone(),
+            each(&array.points, |arr| self.point(arr))?,
+            each(&array.line_strings, |arr| self.line_string(arr))?,
+            each(&array.polygons, |arr| self.polygon(arr))?,
+            each(&array.mpoints, |arr| self.multi_point(arr))?,
+            each(&array.mline_strings, |arr| self.multi_line_string(arr))?,
+            each(&array.mpolygons, |arr| self.multi_polygon(arr))?,
+            each(&array.gcs, |arr| self.geometry_collection(arr))?,
+            self.metadata(&array.metadata()),
+        ))
+    }
+
+    /// A rectangle is in general not axis-aligned anymore after the transformation, so rects are
+    /// converted to polygons first.
+    fn rect(&self, array: &RectArray) -> Result<PolygonArray> {
+        self.polygon(&PolygonArray::from(array.clone()))
+    }
+
+    fn native(&self, array: &dyn NativeArray) -> Result<Arc<dyn NativeArray>> {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match array.data_type() {
+            Point(_, _) => Arc::new(self.point(array.as_point())?),
+            LineString(_, _) => Arc::new(self.line_string(array.as_line_string())?),
+            LargeLineString(_, _) => Arc::new(self.line_string(array.as_large_line_string())?),
+            Polygon(_, _) => Arc::new(self.polygon(array.as_polygon())?),
+            LargePolygon(_, _) => Arc::new(self.polygon(array.as_large_polygon())?),
+            MultiPoint(_, _) => Arc::new(self.multi_point(array.as_multi_point())?),
+            LargeMultiPoint(_, _) => Arc::new(self.multi_point(array.as_large_multi_point())?),
+            MultiLineString(_, _) => {
+                Arc::new(self.multi_line_string(array.as_multi_line_string())?)
+            }
+            LargeMultiLineString(_, _) => {
+                Arc::new(self.multi_line_string(array.as_large_multi_line_string())?)
+            }
+            MultiPolygon(_, _) => Arc::new(self.multi_polygon(array.as_multi_polygon())?),
+            LargeMultiPolygon(_, _) => {
+                Arc::new(self.multi_polygon(array.as_large_multi_polygon())?)
+            }
+            Mixed(_, _) => Arc::new(self.mixed(array.as_mixed())?),
+            LargeMixed(_, _) => Arc::new(self.mixed(array.as_large_mixed())?),
+            GeometryCollection(_, _) => {
+                Arc::new(self.geometry_collection(array.as_geometry_collection())?)
+            }
+            LargeGeometryCollection(_, _) => {
+                Arc::new(self.geometry_collection(array.as_large_geometry_collection())?)
+            }
+            Rect(_) => Arc::new(self.rect(array.as_rect())?),
+            Geometry(_) => Arc::new(self.geometry(array.as_geometry())?),
+            LargeGeometry(_) => Arc::new(self.geometry(array.as_large_geometry())?),
+        };
+        Ok(result)
+    }
+
+    fn chunked(&self, array: &dyn ChunkedNativeArray) -> Result<Arc<dyn ChunkedNativeArray>> {
+        let chunks = array
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| self.native(chunk.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        let chunk_refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(&chunk_refs)?.into_inner())
+    }
+}
+
+/// Apply `f` to each of the per-dimension child arrays of a [`GeometryArray`].
+fn each<T, U>(arrays: &[T; 4], f: impl Fn(&T) -> Result<U>) -> Result<[U; 4]> {
+    Ok([
+        f(&arrays[0])?,
+        f(&arrays[1])?,
+        f(&arrays[2])?,
+        f(&arrays[3])?,
+    ])
+}
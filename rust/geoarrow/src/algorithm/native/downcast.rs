@@ -6,7 +6,7 @@ use std::sync::Arc;
 use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 
-use crate::algorithm::native::cast::Cast;
+use crate::algorithm::native::cast::{narrow_offsets, Cast};
 use crate::array::*;
 use crate::chunked_array::*;
 use crate::datatypes::{Dimension, NativeType};
@@ -263,6 +263,39 @@ impl Downcast for GeometryArray {
     }
 }
 
+// Large-offset arrays are first converted to `i32` offsets if the offsets would not overflow,
+// and then downcast as usual.
+macro_rules! impl_large_downcast {
+    ($array:ty) => {
+        impl Downcast for $array {
+            type Output = Result<Arc<dyn NativeArray>>;
+
+            fn downcasted_data_type(&self) -> NativeType {
+                match narrow_offsets(self) {
+                    Ok(array) => array.as_ref().downcasted_data_type(),
+                    Err(_) => self.data_type(),
+                }
+            }
+
+            fn downcast(&self) -> Self::Output {
+                match narrow_offsets(self) {
+                    Ok(array) => array.as_ref().downcast(),
+                    Err(_) => Ok(Arc::new(self.clone())),
+                }
+            }
+        }
+    };
+}
+
+impl_large_downcast!(LargeLineStringArray);
+impl_large_downcast!(LargePolygonArray);
+impl_large_downcast!(LargeMultiPointArray);
+impl_large_downcast!(LargeMultiLineStringArray);
+impl_large_downcast!(LargeMultiPolygonArray);
+impl_large_downcast!(LargeMixedGeometryArray);
+impl_large_downcast!(LargeGeometryCollectionArray);
+impl_large_downcast!(LargeGeometryArray);
+
 impl Downcast for &dyn NativeArray {
     type Output = Result<Arc<dyn NativeArray>>;
 
@@ -276,9 +309,20 @@ impl Downcast for &dyn NativeArray {
             MultiPoint(_, _) => self.as_multi_point().downcasted_data_type(),
             MultiLineString(_, _) => self.as_multi_line_string().downcasted_data_type(),
             MultiPolygon(_, _) => self.as_multi_polygon().downcasted_data_type(),
+            Mixed(_, _) => self.as_mixed().downcasted_data_type(),
             GeometryCollection(_, _) => self.as_geometry_collection().downcasted_data_type(),
             Rect(_) => self.as_rect().downcasted_data_type(),
             Geometry(_) => self.as_geometry().downcasted_data_type(),
+            LargeLineString(_, _) => self.as_large_line_string().downcasted_data_type(),
+            LargePolygon(_, _) => self.as_large_polygon().downcasted_data_type(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().downcasted_data_type(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().downcasted_data_type(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().downcasted_data_type(),
+            LargeMixed(_, _) => self.as_large_mixed().downcasted_data_type(),
+            LargeGeometryCollection(_, _) => {
+                self.as_large_geometry_collection().downcasted_data_type()
+            }
+            LargeGeometry(_) => self.as_large_geometry().downcasted_data_type(),
         }
     }
 
@@ -292,9 +336,18 @@ impl Downcast for &dyn NativeArray {
             MultiPoint(_, _) => Ok(self.as_multi_point().downcast()),
             MultiLineString(_, _) => Ok(self.as_multi_line_string().downcast()),
             MultiPolygon(_, _) => Ok(self.as_multi_polygon().downcast()),
+            Mixed(_, _) => self.as_mixed().downcast(),
             GeometryCollection(_, _) => self.as_geometry_collection().downcast(),
             Rect(_) => Ok(self.as_rect().downcast()),
             Geometry(_) => self.as_geometry().downcast(),
+            LargeLineString(_, _) => self.as_large_line_string().downcast(),
+            LargePolygon(_, _) => self.as_large_polygon().downcast(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().downcast(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().downcast(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().downcast(),
+            LargeMixed(_, _) => self.as_large_mixed().downcast(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().downcast(),
+            LargeGeometry(_) => self.as_large_geometry().downcast(),
         }
     }
 }
@@ -404,6 +457,14 @@ impl_chunked_downcast!(ChunkedMultiPolygonArray);
 impl_chunked_downcast!(ChunkedMixedGeometryArray);
 impl_chunked_downcast!(ChunkedGeometryCollectionArray);
 impl_chunked_downcast!(ChunkedUnknownGeometryArray);
+impl_chunked_downcast!(ChunkedLargeLineStringArray);
+impl_chunked_downcast!(ChunkedLargePolygonArray);
+impl_chunked_downcast!(ChunkedLargeMultiPointArray);
+impl_chunked_downcast!(ChunkedLargeMultiLineStringArray);
+impl_chunked_downcast!(ChunkedLargeMultiPolygonArray);
+impl_chunked_downcast!(ChunkedLargeMixedGeometryArray);
+impl_chunked_downcast!(ChunkedLargeGeometryCollectionArray);
+impl_chunked_downcast!(ChunkedLargeUnknownGeometryArray);
 
 impl Downcast for ChunkedRectArray {
     type Output = Arc<dyn ChunkedNativeArray>;
@@ -429,9 +490,20 @@ impl Downcast for &dyn ChunkedNativeArray {
             MultiPoint(_, _) => self.as_multi_point().downcasted_data_type(),
             MultiLineString(_, _) => self.as_multi_line_string().downcasted_data_type(),
             MultiPolygon(_, _) => self.as_multi_polygon().downcasted_data_type(),
+            Mixed(_, _) => self.as_mixed().downcasted_data_type(),
             GeometryCollection(_, _) => self.as_geometry_collection().downcasted_data_type(),
             Rect(_) => self.as_rect().downcasted_data_type(),
             Geometry(_) => self.as_geometry().downcasted_data_type(),
+            LargeLineString(_, _) => self.as_large_line_string().downcasted_data_type(),
+            LargePolygon(_, _) => self.as_large_polygon().downcasted_data_type(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().downcasted_data_type(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().downcasted_data_type(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().downcasted_data_type(),
+            LargeMixed(_, _) => self.as_large_mixed().downcasted_data_type(),
+            LargeGeometryCollection(_, _) => {
+                self.as_large_geometry_collection().downcasted_data_type()
+            }
+            LargeGeometry(_) => self.as_large_geometry().downcasted_data_type(),
         }
     }
 
@@ -445,9 +517,18 @@ impl Downcast for &dyn ChunkedNativeArray {
             MultiPoint(_, _) => self.as_multi_point().downcast(),
             MultiLineString(_, _) => self.as_multi_line_string().downcast(),
             MultiPolygon(_, _) => self.as_multi_polygon().downcast(),
+            Mixed(_, _) => self.as_mixed().downcast(),
             GeometryCollection(_, _) => self.as_geometry_collection().downcast(),
             Rect(_) => self.as_rect().downcast(),
             Geometry(_) => self.as_geometry().downcast(),
+            LargeLineString(_, _) => self.as_large_line_string().downcast(),
+            LargePolygon(_, _) => self.as_large_polygon().downcast(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().downcast(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().downcast(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().downcast(),
+            LargeMixed(_, _) => self.as_large_mixed().downcast(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().downcast(),
+            LargeGeometry(_) => self.as_large_geometry().downcast(),
         }
     }
 }
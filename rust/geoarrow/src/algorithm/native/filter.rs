@@ -0,0 +1,273 @@
+use std::sync::Arc;
+
+use arrow::compute::{take, take_record_batch};
+use arrow_array::{ArrayRef, BooleanArray, UInt32Array};
+
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray, ChunkedNativeArrayDyn};
+use crate::error::{GeoArrowError, Result};
+use crate::table::Table;
+use crate::{ArrayBase, NativeArray};
+
+/// Filter a geometry array, chunked array or table by a boolean mask.
+pub trait Filter {
+    type Output;
+
+    /// Keep the rows where `predicate` is `true`.
+    ///
+    /// Rows where the predicate is null are dropped. For chunked arrays and tables, `predicate`
+    /// spans the full length of the input, not a single chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::BooleanArray;
+    /// use geoarrow::algorithm::native::Filter;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::Dimension;
+    /// use geoarrow::ArrayBase;
+    ///
+    /// let points = vec![
+    ///     geo::point!(x: 0., y: 0.),
+    ///     geo::point!(x: 1., y: 1.),
+    ///     geo::point!(x: 2., y: 2.),
+    /// ];
+    /// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+    ///
+    /// let predicate = BooleanArray::from(vec![true, false, true]);
+    /// let filtered = array.filter(&predicate).unwrap();
+    /// assert_eq!(filtered.len(), 2);
+    /// ```
+    fn filter(&self, predicate: &BooleanArray) -> Self::Output;
+}
+
+/// The indices of the rows selected by `predicate`.
+pub(crate) fn filter_indices(len: usize, predicate: &BooleanArray) -> Result<UInt32Array> {
+    if predicate.len() != len {
+        return Err(GeoArrowError::General(format!(
+            "Filter predicate has length {} but the input has length {len}",
+            predicate.len()
+        )));
+    }
+
+    Ok(predicate
+        .iter()
+        .enumerate()
+        .filter_map(|(i, keep)| keep.unwrap_or(false).then_some(i as u32))
+        .collect())
+}
+
+/// Take `indices` from the Arrow storage of `array`.
+///
+/// The result is still the storage array; wrap it with the array's extension field to get back a
+/// geometry array with the same metadata.
+pub(crate) fn take_storage(array: &dyn NativeArray, indices: &UInt32Array) -> Result<ArrayRef> {
+    Ok(take(array.to_array_ref().as_ref(), indices, None)?)
+}
+
+/// Take `indices` from `array`, preserving its type and metadata.
+pub(crate) fn take_native(
+    array: &dyn NativeArray,
+    indices: &UInt32Array,
+) -> Result<Arc<dyn NativeArray>> {
+    let taken = take_storage(array, indices)?;
+    Ok(NativeArrayDyn::from_arrow_array(&taken, &array.extension_field())?.into_inner())
+}
+
+macro_rules! filter_impl {
+    ($type:ty) => {
+        impl Filter for $type {
+            type Output = Result<Self>;
+
+            fn filter(&self, predicate: &BooleanArray) -> Self::Output {
+                let indices = filter_indices(self.len(), predicate)?;
+                let taken = take_storage(self, &indices)?;
+                Self::try_from((taken.as_ref(), self.extension_field().as_ref()))
+            }
+        }
+
+        impl Filter for ChunkedGeometryArray<$type> {
+            type Output = Result<Self>;
+
+            fn filter(&self, predicate: &BooleanArray) -> Self::Output {
+                if predicate.len() != self.len() {
+                    return Err(GeoArrowError::General(format!(
+                        "Filter predicate has length {} but the input has length {}",
+                        predicate.len(),
+                        self.len()
+                    )));
+                }
+
+                let mut offset = 0;
+                let chunks = self
+                    .chunks
+                    .iter()
+                    .map(|chunk| {
+                        let chunk_predicate = predicate.slice(offset, chunk.len());
+                        offset += chunk.len();
+                        chunk.filter(&chunk_predicate)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ChunkedGeometryArray::new(chunks))
+            }
+        }
+    };
+}
+
+filter_impl!(PointArray);
+filter_impl!(LineStringArray);
+filter_impl!(PolygonArray);
+filter_impl!(MultiPointArray);
+filter_impl!(MultiLineStringArray);
+filter_impl!(MultiPolygonArray);
+filter_impl!(MixedGeometryArray);
+filter_impl!(GeometryCollectionArray);
+filter_impl!(RectArray);
+filter_impl!(GeometryArray);
+filter_impl!(LargeLineStringArray);
+filter_impl!(LargePolygonArray);
+filter_impl!(LargeMultiPointArray);
+filter_impl!(LargeMultiLineStringArray);
+filter_impl!(LargeMultiPolygonArray);
+filter_impl!(LargeMixedGeometryArray);
+filter_impl!(LargeGeometryCollectionArray);
+filter_impl!(LargeGeometryArray);
+
+impl Filter for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn filter(&self, predicate: &BooleanArray) -> Self::Output {
+        let indices = filter_indices(self.len(), predicate)?;
+        take_native(*self, &indices)
+    }
+}
+
+impl Filter for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn filter(&self, predicate: &BooleanArray) -> Self::Output {
+        if predicate.len() != self.len() {
+            return Err(GeoArrowError::General(format!(
+                "Filter predicate has length {} but the input has length {}",
+                predicate.len(),
+                self.len()
+            )));
+        }
+
+        let mut offset = 0;
+        let chunks = self
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| {
+                let chunk_predicate = predicate.slice(offset, chunk.len());
+                offset += chunk.len();
+                chunk.as_ref().filter(&chunk_predicate)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let chunk_refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(&chunk_refs)?.into_inner())
+    }
+}
+
+impl Filter for Table {
+    type Output = Result<Table>;
+
+    fn filter(&self, predicate: &BooleanArray) -> Self::Output {
+        if predicate.len() != self.len() {
+            return Err(GeoArrowError::General(format!(
+                "Filter predicate has length {} but the table has length {}",
+                predicate.len(),
+                self.len()
+            )));
+        }
+
+        let mut offset = 0;
+        let batches = self
+            .batches()
+            .iter()
+            .map(|batch| {
+                let batch_predicate = predicate.slice(offset, batch.num_rows());
+                offset += batch.num_rows();
+                let indices = filter_indices(batch.num_rows(), &batch_predicate)?;
+                Ok(take_record_batch(batch, &indices)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Table::try_new(batches, self.schema().clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::native::eq::geometry_eq;
+    use crate::datatypes::Dimension;
+    use crate::test::{linestring, point, polygon};
+    use crate::trait_::ArrayAccessor;
+
+    #[test]
+    fn filter_line_string() {
+        let arr: LineStringArray = (
+            vec![Some(linestring::ls0()), None, Some(linestring::ls1())],
+            Dimension::XY,
+        )
+            .into();
+        let predicate = BooleanArray::from(vec![Some(false), Some(true), Some(true)]);
+        let filtered = arr.filter(&predicate).unwrap();
+
+        assert_eq!(filtered.len(), 2);
+        assert!(filtered.is_null(0));
+        assert_eq!(filtered.value_as_geo(1), linestring::ls1());
+        assert_eq!(filtered.metadata(), arr.metadata());
+    }
+
+    #[test]
+    fn filter_null_predicate() {
+        let arr = point::point_array();
+        let predicate = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        let filtered = arr.filter(&predicate).unwrap();
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered.value_as_geo(1), point::p2());
+    }
+
+    #[test]
+    fn filter_mixed() {
+        let arr: MixedGeometryArray = (
+            vec![
+                geo::Geometry::Point(point::p0()),
+                geo::Geometry::Polygon(polygon::p0()),
+                geo::Geometry::LineString(linestring::ls0()),
+            ]
+            .as_slice(),
+            Dimension::XY,
+        )
+            .try_into()
+            .unwrap();
+        let predicate = BooleanArray::from(vec![false, true, true]);
+        let filtered = arr.filter(&predicate).unwrap();
+
+        assert_eq!(filtered.len(), 2);
+        assert!(geometry_eq(&filtered.value(0), &arr.value(1)));
+        assert!(geometry_eq(&filtered.value(1), &arr.value(2)));
+    }
+
+    #[test]
+    fn filter_chunked() {
+        let chunked = ChunkedGeometryArray::new(vec![point::point_array(), point::point_array()]);
+        let predicate = BooleanArray::from(vec![true, false, false, false, true, true]);
+        let filtered = chunked.filter(&predicate).unwrap();
+
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(filtered.chunks()[0].len(), 1);
+        assert_eq!(filtered.chunks()[1].len(), 2);
+    }
+
+    #[test]
+    fn filter_wrong_length() {
+        let arr = point::point_array();
+        let predicate = BooleanArray::from(vec![true]);
+        assert!(arr.filter(&predicate).is_err());
+    }
+}
@@ -5,29 +5,43 @@
 
 mod binary;
 pub mod bounding_rect;
-mod cast;
+pub(crate) mod cast;
+mod clip_by_rect;
+mod compact;
 mod concatenate;
+#[cfg(any(feature = "geodesy", feature = "proj"))]
+pub(crate) mod coord_transform;
 pub(crate) mod downcast;
 pub(crate) mod eq;
 mod explode;
+pub(crate) mod filter;
 mod map_chunks;
 mod map_coords;
 mod rechunk;
+mod sort;
+mod spatial_sort;
 mod take;
 mod total_bounds;
 pub(crate) mod type_id;
 mod unary;
+mod unique;
 
 pub use binary::Binary;
 pub use bounding_rect::BoundingRectArray;
-pub use cast::Cast;
+pub use cast::{Cast, CastOptions, FillValue};
+pub use clip_by_rect::{ClipByRect, ClipByRectScalar};
+pub use compact::Compact;
 pub use concatenate::Concatenate;
 pub use downcast::{Downcast, DowncastTable};
 pub use explode::{Explode, ExplodeTable};
+pub use filter::Filter;
 pub use map_chunks::MapChunks;
 pub use map_coords::MapCoords;
 pub use rechunk::Rechunk;
+pub use sort::{SortKey, SortToIndices, SortToIndicesTable};
+pub use spatial_sort::{SpaceFillingCurve, SpatialSort, SpatialSortTable};
 pub use take::Take;
 pub use total_bounds::TotalBounds;
 pub use type_id::TypeIds;
 pub use unary::{Unary, UnaryPoint};
+pub use unique::Unique;
@@ -0,0 +1,228 @@
+use arrow::compute::{concat, sort_to_indices};
+use arrow_array::{Array, Float64Array, UInt32Array};
+use arrow_schema::SortOptions;
+use geo_traits::{CoordTrait, RectTrait};
+
+use crate::algorithm::geo::{Area, EuclideanLength};
+use crate::algorithm::native::BoundingRectArray;
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::error::Result;
+use crate::table::Table;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// The per-geometry value used to order geometries in [`SortToIndices`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortKey {
+    /// Unsigned area, as computed by [`Area`].
+    Area,
+
+    /// Euclidean length.
+    Length,
+
+    /// Minimum x value of the bounding box.
+    MinX,
+
+    /// Minimum y value of the bounding box.
+    MinY,
+
+    /// Maximum x value of the bounding box.
+    MaxX,
+
+    /// Maximum y value of the bounding box.
+    MaxY,
+}
+
+/// Compute the indices that would sort a geometry array.
+///
+/// Null geometries are ordered according to [`SortOptions::nulls_first`].
+pub trait SortToIndices {
+    type Output;
+
+    /// Returns the indices that sort the geometries by `key`. Pass the result to
+    /// [`Take`][crate::algorithm::native::Take] or
+    /// [`take`][arrow::compute::take] to reorder the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{SortKey, SortToIndices};
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let points = vec![
+    ///     geo::point!(x: 2., y: 0.),
+    ///     geo::point!(x: 0., y: 1.),
+    ///     geo::point!(x: 1., y: 2.),
+    /// ];
+    /// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+    ///
+    /// let indices = array.sort_to_indices(SortKey::MinX, None).unwrap();
+    /// assert_eq!(indices.values(), &[1, 2, 0]);
+    /// ```
+    fn sort_to_indices(&self, key: SortKey, options: Option<SortOptions>) -> Self::Output;
+}
+
+/// Compute the value of `key` for every geometry in `array`.
+pub(crate) fn sort_key_values(array: &dyn NativeArray, key: SortKey) -> Result<Float64Array> {
+    let values = match key {
+        SortKey::Area => array.unsigned_area()?,
+        SortKey::Length => array.euclidean_length()?,
+        SortKey::MinX | SortKey::MinY | SortKey::MaxX | SortKey::MaxY => {
+            let rects = array.bounding_rect()?;
+            rects
+                .iter()
+                .map(|rect| {
+                    rect.map(|rect| match key {
+                        SortKey::MinX => rect.min().x(),
+                        SortKey::MinY => rect.min().y(),
+                        SortKey::MaxX => rect.max().x(),
+                        _ => rect.max().y(),
+                    })
+                })
+                .collect()
+        }
+    };
+    Ok(values)
+}
+
+fn sort_chunks_to_indices<'a>(
+    chunks: impl Iterator<Item = &'a dyn NativeArray>,
+    key: SortKey,
+    options: Option<SortOptions>,
+) -> Result<UInt32Array> {
+    let values = chunks
+        .map(|chunk| sort_key_values(chunk, key))
+        .collect::<Result<Vec<_>>>()?;
+    let values = values.iter().map(|v| v as &dyn Array).collect::<Vec<_>>();
+    Ok(sort_to_indices(concat(&values)?.as_ref(), options, None)?)
+}
+
+macro_rules! sort_impl {
+    ($type:ty) => {
+        impl SortToIndices for $type {
+            type Output = Result<UInt32Array>;
+
+            fn sort_to_indices(&self, key: SortKey, options: Option<SortOptions>) -> Self::Output {
+                let values = sort_key_values(self, key)?;
+                Ok(sort_to_indices(&values, options, None)?)
+            }
+        }
+
+        impl SortToIndices for ChunkedGeometryArray<$type> {
+            type Output = Result<UInt32Array>;
+
+            fn sort_to_indices(&self, key: SortKey, options: Option<SortOptions>) -> Self::Output {
+                sort_chunks_to_indices(
+                    self.chunks.iter().map(|chunk| chunk as &dyn NativeArray),
+                    key,
+                    options,
+                )
+            }
+        }
+    };
+}
+
+sort_impl!(PointArray);
+sort_impl!(LineStringArray);
+sort_impl!(PolygonArray);
+sort_impl!(MultiPointArray);
+sort_impl!(MultiLineStringArray);
+sort_impl!(MultiPolygonArray);
+sort_impl!(MixedGeometryArray);
+sort_impl!(GeometryCollectionArray);
+sort_impl!(RectArray);
+sort_impl!(GeometryArray);
+sort_impl!(LargeLineStringArray);
+sort_impl!(LargePolygonArray);
+sort_impl!(LargeMultiPointArray);
+sort_impl!(LargeMultiLineStringArray);
+sort_impl!(LargeMultiPolygonArray);
+sort_impl!(LargeMixedGeometryArray);
+sort_impl!(LargeGeometryCollectionArray);
+sort_impl!(LargeGeometryArray);
+
+impl SortToIndices for &dyn NativeArray {
+    type Output = Result<UInt32Array>;
+
+    fn sort_to_indices(&self, key: SortKey, options: Option<SortOptions>) -> Self::Output {
+        let values = sort_key_values(*self, key)?;
+        Ok(sort_to_indices(&values, options, None)?)
+    }
+}
+
+impl SortToIndices for &dyn ChunkedNativeArray {
+    type Output = Result<UInt32Array>;
+
+    fn sort_to_indices(&self, key: SortKey, options: Option<SortOptions>) -> Self::Output {
+        let chunks = self.geometry_chunks();
+        sort_chunks_to_indices(chunks.iter().map(|chunk| chunk.as_ref()), key, options)
+    }
+}
+
+/// Compute the indices that would sort a [`Table`] by one of its geometry columns.
+pub trait SortToIndicesTable {
+    /// Returns the indices that sort the table by `key`, computed on the geometry column at
+    /// `index`, or the default geometry column if `None`.
+    ///
+    /// The indices refer to rows of the whole table, across all of its batches.
+    fn sort_to_indices(
+        &self,
+        index: Option<usize>,
+        key: SortKey,
+        options: Option<SortOptions>,
+    ) -> Result<UInt32Array>;
+}
+
+impl SortToIndicesTable for Table {
+    fn sort_to_indices(
+        &self,
+        index: Option<usize>,
+        key: SortKey,
+        options: Option<SortOptions>,
+    ) -> Result<UInt32Array> {
+        let geometry_column = self.geometry_column(index)?;
+        geometry_column.as_ref().sort_to_indices(key, options)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+
+    #[test]
+    fn sort_by_area() {
+        let small = geo::Rect::new((0., 0.), (1., 1.)).to_polygon();
+        let large = geo::Rect::new((0., 0.), (2., 2.)).to_polygon();
+        let arr: PolygonArray = (vec![Some(large), None, Some(small)], Dimension::XY).into();
+
+        let indices = arr.sort_to_indices(SortKey::Area, None).unwrap();
+        assert_eq!(indices.values(), &[1, 2, 0]);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let indices = arr.sort_to_indices(SortKey::Area, Some(options)).unwrap();
+        assert_eq!(indices.values(), &[0, 2, 1]);
+    }
+
+    #[test]
+    fn sort_chunked_by_bbox() {
+        let first: PointArray = (
+            vec![geo::point!(x: 2., y: 0.), geo::point!(x: 0., y: 1.)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let second: PointArray = (vec![geo::point!(x: 1., y: 2.)].as_slice(), Dimension::XY).into();
+        let chunked = ChunkedGeometryArray::new(vec![first, second]);
+
+        let indices = chunked.sort_to_indices(SortKey::MinX, None).unwrap();
+        assert_eq!(indices.values(), &[1, 2, 0]);
+
+        let indices = chunked.sort_to_indices(SortKey::MaxY, None).unwrap();
+        assert_eq!(indices.values(), &[0, 1, 2]);
+    }
+}
@@ -0,0 +1,300 @@
+use arrow::compute::sort_to_indices;
+use arrow_array::UInt32Array;
+use arrow_schema::SortOptions;
+use geo_traits::{CoordTrait, RectTrait};
+
+use crate::algorithm::native::BoundingRectArray;
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::error::Result;
+use crate::table::Table;
+use crate::trait_::ArrayAccessor;
+use crate::NativeArray;
+
+/// The largest grid coordinate on either axis when mapping geometries onto a space-filling curve.
+const CURVE_MAX: f64 = u16::MAX as f64;
+
+/// A space-filling curve used to order geometries by [`SpatialSort`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SpaceFillingCurve {
+    /// The Hilbert curve, as used by packed R-trees such as FlatGeobuf's index.
+    #[default]
+    Hilbert,
+
+    /// The Z-order (Morton) curve. Cheaper to compute than Hilbert but with worse locality.
+    ZOrder,
+}
+
+impl SpaceFillingCurve {
+    /// The position along the curve of a cell in a 2^16 by 2^16 grid.
+    fn index(&self, x: u32, y: u32) -> u32 {
+        match self {
+            Self::Hilbert => hilbert(x, y),
+            Self::ZOrder => interleave(x) | (interleave(y) << 1),
+        }
+    }
+}
+
+/// Spread the lower 16 bits of `v` so that there is a zero bit between each of them.
+fn interleave(mut v: u32) -> u32 {
+    v = (v | (v << 8)) & 0x00FF00FF;
+    v = (v | (v << 4)) & 0x0F0F0F0F;
+    v = (v | (v << 2)) & 0x33333333;
+    (v | (v << 1)) & 0x55555555
+}
+
+// Fast Hilbert curve algorithm by http://threadlocalmutex.com/
+// Ported from C++ https://github.com/rawrunprotected/hilbert_curves (public domain)
+fn hilbert(x: u32, y: u32) -> u32 {
+    let mut a_1 = x ^ y;
+    let mut b_1 = 0xFFFF ^ a_1;
+    let mut c_1 = 0xFFFF ^ (x | y);
+    let mut d_1 = x & (y ^ 0xFFFF);
+
+    let mut a_2 = a_1 | (b_1 >> 1);
+    let mut b_2 = (a_1 >> 1) ^ a_1;
+    let mut c_2 = ((c_1 >> 1) ^ (b_1 & (d_1 >> 1))) ^ c_1;
+    let mut d_2 = ((a_1 & (c_1 >> 1)) ^ (d_1 >> 1)) ^ d_1;
+
+    a_1 = a_2;
+    b_1 = b_2;
+    c_1 = c_2;
+    d_1 = d_2;
+    a_2 = (a_1 & (a_1 >> 2)) ^ (b_1 & (b_1 >> 2));
+    b_2 = (a_1 & (b_1 >> 2)) ^ (b_1 & ((a_1 ^ b_1) >> 2));
+    c_2 ^= (a_1 & (c_1 >> 2)) ^ (b_1 & (d_1 >> 2));
+    d_2 ^= (b_1 & (c_1 >> 2)) ^ ((a_1 ^ b_1) & (d_1 >> 2));
+
+    a_1 = a_2;
+    b_1 = b_2;
+    c_1 = c_2;
+    d_1 = d_2;
+    a_2 = (a_1 & (a_1 >> 4)) ^ (b_1 & (b_1 >> 4));
+    b_2 = (a_1 & (b_1 >> 4)) ^ (b_1 & ((a_1 ^ b_1) >> 4));
+    c_2 ^= (a_1 & (c_1 >> 4)) ^ (b_1 & (d_1 >> 4));
+    d_2 ^= (b_1 & (c_1 >> 4)) ^ ((a_1 ^ b_1) & (d_1 >> 4));
+
+    a_1 = a_2;
+    b_1 = b_2;
+    c_1 = c_2;
+    d_1 = d_2;
+    c_2 ^= (a_1 & (c_1 >> 8)) ^ (b_1 & (d_1 >> 8));
+    d_2 ^= (b_1 & (c_1 >> 8)) ^ ((a_1 ^ b_1) & (d_1 >> 8));
+
+    a_1 = c_2 ^ (c_2 >> 1);
+    b_1 = d_2 ^ (d_2 >> 1);
+
+    let i0 = x ^ y;
+    let i1 = b_1 | (0xFFFF ^ (i0 | a_1));
+
+    (interleave(i1) << 1) | interleave(i0)
+}
+
+/// Compute the permutation that orders geometries along a space-filling curve.
+///
+/// Each geometry is placed on the curve by the center of its bounding box, scaled to the extent
+/// of all geometries in the input. Spatially sorting data before writing it out makes row group
+/// statistics in GeoParquet and page-level filtering in FlatGeobuf far more selective.
+pub trait SpatialSort {
+    type Output;
+
+    /// Returns the indices that order the geometries along `curve`. Null and empty geometries are
+    /// placed last. Pass the result to [`take`][arrow::compute::take] to reorder the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::{SpaceFillingCurve, SpatialSort};
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let points = vec![
+    ///     geo::point!(x: 0., y: 0.),
+    ///     geo::point!(x: 1., y: 0.),
+    ///     geo::point!(x: 0., y: 1.),
+    ///     geo::point!(x: 1., y: 1.),
+    /// ];
+    /// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+    ///
+    /// let indices = array
+    ///     .spatial_sort_to_indices(SpaceFillingCurve::Hilbert)
+    ///     .unwrap();
+    /// assert_eq!(indices.values(), &[0, 2, 3, 1]);
+    /// ```
+    fn spatial_sort_to_indices(&self, curve: SpaceFillingCurve) -> Self::Output;
+}
+
+fn spatial_sort_chunks<'a>(
+    chunks: impl Iterator<Item = &'a dyn NativeArray>,
+    curve: SpaceFillingCurve,
+) -> Result<UInt32Array> {
+    let mut centers = vec![];
+    for chunk in chunks {
+        let rects = chunk.bounding_rect()?;
+        centers.extend(rects.iter().map(|rect| {
+            rect.and_then(|rect| {
+                let x = (rect.min().x() + rect.max().x()) / 2.;
+                let y = (rect.min().y() + rect.max().y()) / 2.;
+                (x.is_finite() && y.is_finite()).then_some((x, y))
+            })
+        }));
+    }
+
+    let (mut min_x, mut min_y) = (f64::INFINITY, f64::INFINITY);
+    let (mut max_x, mut max_y) = (f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for (x, y) in centers.iter().flatten() {
+        min_x = min_x.min(*x);
+        min_y = min_y.min(*y);
+        max_x = max_x.max(*x);
+        max_y = max_y.max(*y);
+    }
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+
+    let scale = |value: f64, min: f64, extent: f64| {
+        if extent > 0. {
+            (CURVE_MAX * (value - min) / extent).floor() as u32
+        } else {
+            0
+        }
+    };
+    let curve_indices: UInt32Array = centers
+        .iter()
+        .map(|center| {
+            center.map(|(x, y)| curve.index(scale(x, min_x, width), scale(y, min_y, height)))
+        })
+        .collect();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    Ok(sort_to_indices(&curve_indices, Some(options), None)?)
+}
+
+macro_rules! spatial_sort_impl {
+    ($type:ty) => {
+        impl SpatialSort for $type {
+            type Output = Result<UInt32Array>;
+
+            fn spatial_sort_to_indices(&self, curve: SpaceFillingCurve) -> Self::Output {
+                spatial_sort_chunks(std::iter::once(self as &dyn NativeArray), curve)
+            }
+        }
+    };
+}
+
+spatial_sort_impl!(PointArray);
+spatial_sort_impl!(LineStringArray);
+spatial_sort_impl!(PolygonArray);
+spatial_sort_impl!(MultiPointArray);
+spatial_sort_impl!(MultiLineStringArray);
+spatial_sort_impl!(MultiPolygonArray);
+spatial_sort_impl!(MixedGeometryArray);
+spatial_sort_impl!(GeometryCollectionArray);
+spatial_sort_impl!(RectArray);
+spatial_sort_impl!(GeometryArray);
+spatial_sort_impl!(LargeLineStringArray);
+spatial_sort_impl!(LargePolygonArray);
+spatial_sort_impl!(LargeMultiPointArray);
+spatial_sort_impl!(LargeMultiLineStringArray);
+spatial_sort_impl!(LargeMultiPolygonArray);
+spatial_sort_impl!(LargeMixedGeometryArray);
+spatial_sort_impl!(LargeGeometryCollectionArray);
+spatial_sort_impl!(LargeGeometryArray);
+
+impl SpatialSort for &dyn NativeArray {
+    type Output = Result<UInt32Array>;
+
+    fn spatial_sort_to_indices(&self, curve: SpaceFillingCurve) -> Self::Output {
+        spatial_sort_chunks(std::iter::once(*self), curve)
+    }
+}
+
+impl<G: NativeArray> SpatialSort for ChunkedGeometryArray<G> {
+    type Output = Result<UInt32Array>;
+
+    fn spatial_sort_to_indices(&self, curve: SpaceFillingCurve) -> Self::Output {
+        spatial_sort_chunks(
+            self.chunks.iter().map(|chunk| chunk as &dyn NativeArray),
+            curve,
+        )
+    }
+}
+
+impl SpatialSort for &dyn ChunkedNativeArray {
+    type Output = Result<UInt32Array>;
+
+    fn spatial_sort_to_indices(&self, curve: SpaceFillingCurve) -> Self::Output {
+        let chunks = self.geometry_chunks();
+        spatial_sort_chunks(chunks.iter().map(|chunk| chunk.as_ref()), curve)
+    }
+}
+
+/// Compute the permutation that orders the rows of a [`Table`] along a space-filling curve.
+pub trait SpatialSortTable {
+    /// Returns the indices that order the table along `curve`, computed on the geometry column
+    /// at `index`, or the default geometry column if `None`.
+    ///
+    /// The indices refer to rows of the whole table, across all of its batches.
+    fn spatial_sort_to_indices(
+        &self,
+        index: Option<usize>,
+        curve: SpaceFillingCurve,
+    ) -> Result<UInt32Array>;
+}
+
+impl SpatialSortTable for Table {
+    fn spatial_sort_to_indices(
+        &self,
+        index: Option<usize>,
+        curve: SpaceFillingCurve,
+    ) -> Result<UInt32Array> {
+        let geometry_column = self.geometry_column(index)?;
+        geometry_column.as_ref().spatial_sort_to_indices(curve)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+
+    fn corners() -> Vec<Option<geo::Point>> {
+        vec![
+            Some(geo::point!(x: 0., y: 0.)),
+            Some(geo::point!(x: 1., y: 0.)),
+            None,
+            Some(geo::point!(x: 0., y: 1.)),
+            Some(geo::point!(x: 1., y: 1.)),
+        ]
+    }
+
+    #[test]
+    fn hilbert_corners() {
+        let arr: PointArray = (corners(), Dimension::XY).into();
+        let indices = arr
+            .spatial_sort_to_indices(SpaceFillingCurve::Hilbert)
+            .unwrap();
+        assert_eq!(indices.values(), &[0, 3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn z_order_corners() {
+        let arr: PointArray = (corners(), Dimension::XY).into();
+        let indices = arr
+            .spatial_sort_to_indices(SpaceFillingCurve::ZOrder)
+            .unwrap();
+        assert_eq!(indices.values(), &[0, 1, 3, 4, 2]);
+    }
+
+    #[test]
+    fn chunked_uses_global_extent() {
+        let arr: PointArray = (corners(), Dimension::XY).into();
+        let chunked = ChunkedGeometryArray::new(vec![arr.slice(0, 2), arr.slice(2, 3)]);
+        let indices = chunked
+            .spatial_sort_to_indices(SpaceFillingCurve::ZOrder)
+            .unwrap();
+        assert_eq!(indices.values(), &[0, 1, 3, 4, 2]);
+    }
+}
@@ -52,6 +52,14 @@ impl_array!(MultiPolygonArray, add_multi_polygon);
 impl_array!(MixedGeometryArray, add_geometry);
 impl_array!(GeometryCollectionArray, add_geometry_collection);
 impl_array!(GeometryArray, add_geometry);
+impl_array!(LargeLineStringArray, add_line_string);
+impl_array!(LargePolygonArray, add_polygon);
+impl_array!(LargeMultiPointArray, add_multi_point);
+impl_array!(LargeMultiLineStringArray, add_multi_line_string);
+impl_array!(LargeMultiPolygonArray, add_multi_polygon);
+impl_array!(LargeMixedGeometryArray, add_geometry);
+impl_array!(LargeGeometryCollectionArray, add_geometry_collection);
+impl_array!(LargeGeometryArray, add_geometry);
 
 // impl<O: OffsetSizeTrait> TotalBounds for WKBArray<O> {
 //     fn total_bounds(&self) -> BoundingRect {
@@ -70,13 +78,22 @@ impl TotalBounds for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().total_bounds(),
             LineString(_, _) => self.as_line_string().total_bounds(),
+            LargeLineString(_, _) => self.as_large_line_string().total_bounds(),
             Polygon(_, _) => self.as_polygon().total_bounds(),
+            LargePolygon(_, _) => self.as_large_polygon().total_bounds(),
             MultiPoint(_, _) => self.as_multi_point().total_bounds(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().total_bounds(),
             MultiLineString(_, _) => self.as_multi_line_string().total_bounds(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().total_bounds(),
             MultiPolygon(_, _) => self.as_multi_polygon().total_bounds(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().total_bounds(),
+            Mixed(_, _) => self.as_mixed().total_bounds(),
+            LargeMixed(_, _) => self.as_large_mixed().total_bounds(),
             GeometryCollection(_, _) => self.as_geometry_collection().total_bounds(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().total_bounds(),
             Rect(_) => self.as_rect().total_bounds(),
             Geometry(_) => self.as_geometry().total_bounds(),
+            LargeGeometry(_) => self.as_large_geometry().total_bounds(),
             // WKB => self.as_wkb().total_bounds(),
             // LargeWKB => self.as_large_wkb().total_bounds(),
         }
@@ -99,13 +116,22 @@ impl TotalBounds for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => self.as_point().total_bounds(),
             LineString(_, _) => self.as_line_string().total_bounds(),
+            LargeLineString(_, _) => self.as_large_line_string().total_bounds(),
             Polygon(_, _) => self.as_polygon().total_bounds(),
+            LargePolygon(_, _) => self.as_large_polygon().total_bounds(),
             MultiPoint(_, _) => self.as_multi_point().total_bounds(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().total_bounds(),
             MultiLineString(_, _) => self.as_multi_line_string().total_bounds(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().total_bounds(),
             MultiPolygon(_, _) => self.as_multi_polygon().total_bounds(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().total_bounds(),
+            Mixed(_, _) => self.as_mixed().total_bounds(),
+            LargeMixed(_, _) => self.as_large_mixed().total_bounds(),
             GeometryCollection(_, _) => self.as_geometry_collection().total_bounds(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().total_bounds(),
             Rect(_) => self.as_rect().total_bounds(),
             Geometry(_) => self.as_geometry().total_bounds(),
+            LargeGeometry(_) => self.as_large_geometry().total_bounds(),
         }
     }
 }
@@ -1,5 +1,5 @@
 use arrow_array::types::ArrowPrimitiveType;
-use arrow_array::{BooleanArray, PrimitiveArray};
+use arrow_array::{BooleanArray, OffsetSizeTrait, PrimitiveArray};
 use arrow_buffer::{BooleanBufferBuilder, BufferBuilder};
 
 use crate::array::*;
@@ -115,15 +115,15 @@ pub trait Unary<'a>: ArrayAccessor<'a> + NativeArray {
 }
 
 impl Unary<'_> for PointArray {}
-impl Unary<'_> for LineStringArray {}
-impl Unary<'_> for PolygonArray {}
-impl Unary<'_> for MultiPointArray {}
-impl Unary<'_> for MultiLineStringArray {}
-impl Unary<'_> for MultiPolygonArray {}
-impl Unary<'_> for MixedGeometryArray {}
-impl Unary<'_> for GeometryCollectionArray {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericLineStringArray<O> {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericPolygonArray<O> {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericMultiPointArray<O> {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericMultiLineStringArray<O> {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericMultiPolygonArray<O> {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericMixedGeometryArray<O> {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericGeometryCollectionArray<O> {}
 impl Unary<'_> for RectArray {}
-impl Unary<'_> for GeometryArray {}
+impl<O: OffsetSizeTrait> Unary<'_> for GenericGeometryArray<O> {}
 // impl<O: OffsetSizeTrait> Unary<'_> for WKBArray<O> {}
 
 #[allow(dead_code)]
@@ -175,12 +175,12 @@ pub trait UnaryPoint<'a>: ArrayAccessor<'a> + NativeArray {
 }
 
 impl UnaryPoint<'_> for PointArray {}
-impl UnaryPoint<'_> for LineStringArray {}
-impl UnaryPoint<'_> for PolygonArray {}
-impl UnaryPoint<'_> for MultiPointArray {}
-impl UnaryPoint<'_> for MultiLineStringArray {}
-impl UnaryPoint<'_> for MultiPolygonArray {}
-impl UnaryPoint<'_> for MixedGeometryArray {}
-impl UnaryPoint<'_> for GeometryCollectionArray {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericLineStringArray<O> {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericPolygonArray<O> {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericMultiPointArray<O> {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericMultiLineStringArray<O> {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericMultiPolygonArray<O> {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericMixedGeometryArray<O> {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericGeometryCollectionArray<O> {}
 impl UnaryPoint<'_> for RectArray {}
-impl UnaryPoint<'_> for GeometryArray {}
+impl<O: OffsetSizeTrait> UnaryPoint<'_> for GenericGeometryArray<O> {}
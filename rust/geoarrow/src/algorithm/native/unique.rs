@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::UInt32Array;
+
+use crate::algorithm::native::bounding_rect::{
+    bounding_rect_geometry, bounding_rect_geometry_collection, bounding_rect_linestring,
+    bounding_rect_multilinestring, bounding_rect_multipoint, bounding_rect_multipolygon,
+    bounding_rect_point, bounding_rect_polygon, bounding_rect_rect,
+};
+use crate::algorithm::native::eq::{
+    geometry_collection_eq, geometry_eq, line_string_eq, multi_line_string_eq, multi_point_eq,
+    multi_polygon_eq, point_eq, polygon_eq, rect_eq,
+};
+use crate::algorithm::native::filter::take_storage;
+use crate::array::*;
+use crate::chunked_array::{ChunkedGeometryArray, ChunkedNativeArray};
+use crate::datatypes::NativeType;
+use crate::error::Result;
+use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
+
+/// Remove duplicate geometries.
+pub trait Unique {
+    type Output;
+
+    /// Keep the first occurrence of each distinct geometry, in their original order.
+    ///
+    /// Geometries are compared exactly, coordinate by coordinate, with the same semantics as
+    /// [`PartialEq`] on geometry arrays. All null geometries are collapsed into the first one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::algorithm::native::Unique;
+    /// use geoarrow::array::PointArray;
+    /// use geoarrow::datatypes::Dimension;
+    /// use geoarrow::ArrayBase;
+    ///
+    /// let points = vec![
+    ///     geo::point!(x: 0., y: 0.),
+    ///     geo::point!(x: 1., y: 1.),
+    ///     geo::point!(x: 0., y: 0.),
+    /// ];
+    /// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+    /// assert_eq!(array.unique().unwrap().len(), 2);
+    /// ```
+    fn unique(&self) -> Self::Output;
+}
+
+/// Bucket key for one bounding box value, such that `0.0` and `-0.0` fall in the same bucket.
+fn bucket_key(value: f64) -> u64 {
+    if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// The positions of the first occurrence of each distinct value.
+///
+/// Values are bucketed by their bounding box so that `eq` only runs on plausible duplicates.
+fn unique_indices<G>(
+    values: impl Iterator<Item = Option<G>>,
+    bounding_rect: impl Fn(&G) -> ([f64; 2], [f64; 2]),
+    eq: impl Fn(&G, &G) -> bool,
+) -> UInt32Array {
+    let mut seen_null = false;
+    let mut kept: Vec<G> = vec![];
+    let mut buckets: HashMap<[u64; 4], Vec<usize>> = HashMap::new();
+    let mut indices = vec![];
+
+    for (i, value) in values.enumerate() {
+        let Some(value) = value else {
+            if !seen_null {
+                seen_null = true;
+                indices.push(i as u32);
+            }
+            continue;
+        };
+
+        let ([minx, miny], [maxx, maxy]) = bounding_rect(&value);
+        let bucket = buckets
+            .entry([minx, miny, maxx, maxy].map(bucket_key))
+            .or_default();
+        if bucket.iter().any(|idx| eq(&kept[*idx], &value)) {
+            continue;
+        }
+
+        bucket.push(kept.len());
+        kept.push(value);
+        indices.push(i as u32);
+    }
+
+    UInt32Array::from(indices)
+}
+
+/// Split sorted global `indices` into indices local to each chunk.
+fn split_indices(indices: &UInt32Array, chunk_lengths: &[usize]) -> Vec<UInt32Array> {
+    let mut values = indices.values().iter().map(|idx| *idx as usize).peekable();
+    let mut offset = 0;
+    chunk_lengths
+        .iter()
+        .map(|len| {
+            let mut chunk_indices = vec![];
+            while let Some(idx) = values.next_if(|idx| *idx < offset + len) {
+                chunk_indices.push((idx - offset) as u32);
+            }
+            offset += len;
+            UInt32Array::from(chunk_indices)
+        })
+        .collect()
+}
+
+macro_rules! unique_impl {
+    ($type:ty, $eq:path, $bounding_rect:path) => {
+        impl Unique for $type {
+            type Output = Result<Self>;
+
+            fn unique(&self) -> Self::Output {
+                let indices = unique_indices(self.iter(), |g| $bounding_rect(g), |l, r| $eq(l, r));
+                let taken = take_storage(self, &indices)?;
+                Self::try_from((taken.as_ref(), self.extension_field().as_ref()))
+            }
+        }
+
+        impl Unique for ChunkedGeometryArray<$type> {
+            type Output = Result<Self>;
+
+            fn unique(&self) -> Self::Output {
+                let indices = unique_indices(
+                    self.chunks.iter().flat_map(|chunk| chunk.iter()),
+                    |g| $bounding_rect(g),
+                    |l, r| $eq(l, r),
+                );
+                let chunk_lengths = self.chunks.iter().map(|c| c.len()).collect::<Vec<_>>();
+                let chunks = self
+                    .chunks
+                    .iter()
+                    .zip(split_indices(&indices, &chunk_lengths))
+                    .map(|(chunk, chunk_indices)| {
+                        let taken = take_storage(chunk, &chunk_indices)?;
+                        <$type>::try_from((taken.as_ref(), chunk.extension_field().as_ref()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ChunkedGeometryArray::new(chunks))
+            }
+        }
+    };
+}
+
+unique_impl!(PointArray, point_eq, bounding_rect_point);
+unique_impl!(LineStringArray, line_string_eq, bounding_rect_linestring);
+unique_impl!(PolygonArray, polygon_eq, bounding_rect_polygon);
+unique_impl!(MultiPointArray, multi_point_eq, bounding_rect_multipoint);
+unique_impl!(
+    MultiLineStringArray,
+    multi_line_string_eq,
+    bounding_rect_multilinestring
+);
+unique_impl!(
+    MultiPolygonArray,
+    multi_polygon_eq,
+    bounding_rect_multipolygon
+);
+unique_impl!(MixedGeometryArray, geometry_eq, bounding_rect_geometry);
+unique_impl!(
+    GeometryCollectionArray,
+    geometry_collection_eq,
+    bounding_rect_geometry_collection
+);
+unique_impl!(RectArray, rect_eq, bounding_rect_rect);
+unique_impl!(GeometryArray, geometry_eq, bounding_rect_geometry);
+unique_impl!(
+    LargeLineStringArray,
+    line_string_eq,
+    bounding_rect_linestring
+);
+unique_impl!(LargePolygonArray, polygon_eq, bounding_rect_polygon);
+unique_impl!(
+    LargeMultiPointArray,
+    multi_point_eq,
+    bounding_rect_multipoint
+);
+unique_impl!(
+    LargeMultiLineStringArray,
+    multi_line_string_eq,
+    bounding_rect_multilinestring
+);
+unique_impl!(
+    LargeMultiPolygonArray,
+    multi_polygon_eq,
+    bounding_rect_multipolygon
+);
+unique_impl!(LargeMixedGeometryArray, geometry_eq, bounding_rect_geometry);
+unique_impl!(
+    LargeGeometryCollectionArray,
+    geometry_collection_eq,
+    bounding_rect_geometry_collection
+);
+unique_impl!(LargeGeometryArray, geometry_eq, bounding_rect_geometry);
+
+impl Unique for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn unique(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn NativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().unique()?),
+            LineString(_, _) => Arc::new(self.as_line_string().unique()?),
+            LargeLineString(_, _) => Arc::new(self.as_large_line_string().unique()?),
+            Polygon(_, _) => Arc::new(self.as_polygon().unique()?),
+            LargePolygon(_, _) => Arc::new(self.as_large_polygon().unique()?),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().unique()?),
+            LargeMultiPoint(_, _) => Arc::new(self.as_large_multi_point().unique()?),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().unique()?),
+            LargeMultiLineString(_, _) => Arc::new(self.as_large_multi_line_string().unique()?),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().unique()?),
+            LargeMultiPolygon(_, _) => Arc::new(self.as_large_multi_polygon().unique()?),
+            Mixed(_, _) => Arc::new(self.as_mixed().unique()?),
+            LargeMixed(_, _) => Arc::new(self.as_large_mixed().unique()?),
+            GeometryCollection(_, _) => Arc::new(self.as_geometry_collection().unique()?),
+            LargeGeometryCollection(_, _) => {
+                Arc::new(self.as_large_geometry_collection().unique()?)
+            }
+            Rect(_) => Arc::new(self.as_rect().unique()?),
+            Geometry(_) => Arc::new(self.as_geometry().unique()?),
+            LargeGeometry(_) => Arc::new(self.as_large_geometry().unique()?),
+        };
+        Ok(result)
+    }
+}
+
+impl Unique for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn unique(&self) -> Self::Output {
+        use NativeType::*;
+
+        let result: Arc<dyn ChunkedNativeArray> = match self.data_type() {
+            Point(_, _) => Arc::new(self.as_point().unique()?),
+            LineString(_, _) => Arc::new(self.as_line_string().unique()?),
+            LargeLineString(_, _) => Arc::new(self.as_large_line_string().unique()?),
+            Polygon(_, _) => Arc::new(self.as_polygon().unique()?),
+            LargePolygon(_, _) => Arc::new(self.as_large_polygon().unique()?),
+            MultiPoint(_, _) => Arc::new(self.as_multi_point().unique()?),
+            LargeMultiPoint(_, _) => Arc::new(self.as_large_multi_point().unique()?),
+            MultiLineString(_, _) => Arc::new(self.as_multi_line_string().unique()?),
+            LargeMultiLineString(_, _) => Arc::new(self.as_large_multi_line_string().unique()?),
+            MultiPolygon(_, _) => Arc::new(self.as_multi_polygon().unique()?),
+            LargeMultiPolygon(_, _) => Arc::new(self.as_large_multi_polygon().unique()?),
+            Mixed(_, _) => Arc::new(self.as_mixed().unique()?),
+            LargeMixed(_, _) => Arc::new(self.as_large_mixed().unique()?),
+            GeometryCollection(_, _) => Arc::new(self.as_geometry_collection().unique()?),
+            LargeGeometryCollection(_, _) => {
+                Arc::new(self.as_large_geometry_collection().unique()?)
+            }
+            Rect(_) => Arc::new(self.as_rect().unique()?),
+            Geometry(_) => Arc::new(self.as_geometry().unique()?),
+            LargeGeometry(_) => Arc::new(self.as_large_geometry().unique()?),
+        };
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::test::{linestring, point};
+
+    #[test]
+    fn unique_points() {
+        let arr: PointArray = (
+            vec![
+                Some(point::p0()),
+                None,
+                Some(point::p1()),
+                Some(point::p0()),
+                None,
+            ],
+            Dimension::XY,
+        )
+            .into();
+        let unique = arr.unique().unwrap();
+        assert_eq!(unique.len(), 3);
+        assert_eq!(unique.value_as_geo(0), point::p0());
+        assert!(unique.is_null(1));
+        assert_eq!(unique.value_as_geo(2), point::p1());
+    }
+
+    #[test]
+    fn unique_signed_zero() {
+        let arr: PointArray = (
+            vec![geo::point!(x: 0., y: 1.), geo::point!(x: -0., y: 1.)].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        assert_eq!(arr.unique().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn unique_chunked() {
+        let first: LineStringArray = (
+            vec![linestring::ls0(), linestring::ls1()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let second: LineStringArray = (
+            vec![linestring::ls1(), linestring::ls0()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let chunked = ChunkedGeometryArray::new(vec![first, second]);
+        let unique = chunked.unique().unwrap();
+
+        assert_eq!(unique.len(), 2);
+        assert_eq!(unique.chunks()[0].len(), 2);
+        assert_eq!(unique.chunks()[1].len(), 0);
+    }
+
+    #[test]
+    fn unique_dyn() {
+        let arr = point::point_array();
+        let arr = arr.slice(0, 2);
+        let arr: &dyn NativeArray = &arr;
+        assert_eq!(arr.unique().unwrap().len(), 2);
+    }
+}
@@ -1,9 +1,18 @@
 //! Bindings to the [`proj`] crate for coordinate reprojection.
 
+use std::sync::Arc;
+
+use crate::algorithm::native::coord_transform::CoordTransform;
+use crate::array::metadata::ArrayMetadata;
 use crate::array::*;
+use crate::chunked_array::*;
 use crate::datatypes::Dimension;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
+use crate::io::crs::proj_definition;
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
 use crate::trait_::ArrayAccessor;
+use crate::{ArrayBase, NativeArray};
 use proj::{Proj, Transform};
 
 /// Reproject an array using PROJ
@@ -64,10 +73,273 @@ iter_geo_impl!(
 );
 iter_geo_impl!(MultiPolygonArray, MultiPolygonBuilder, push_multi_polygon);
 
+/// The definition of the CRS stored in `metadata`, in a form that PROJ accepts.
+fn crs_definition(metadata: &ArrayMetadata) -> Result<String> {
+    proj_definition(metadata)?.ok_or_else(|| {
+        GeoArrowError::General("Reprojection requires a CRS in the array metadata".to_string())
+    })
+}
+
+/// A PROJ transformation from the CRS of one array to a target CRS.
+struct ProjTransformer<'a> {
+    proj: Proj,
+    target_crs: &'a ArrayMetadata,
+}
+
+impl<'a> ProjTransformer<'a> {
+    fn try_new(source_crs: &ArrayMetadata, target_crs: &'a ArrayMetadata) -> Result<Self> {
+        let proj = Proj::new_known_crs(
+            &crs_definition(source_crs)?,
+            &crs_definition(target_crs)?,
+            None,
+        )
+        .map_err(|err| GeoArrowError::General(err.to_string()))?;
+        Ok(Self { proj, target_crs })
+    }
+
+    /// Transform `len` coordinates, reading them with `get` and writing the result with `set`.
+    ///
+    /// The Z value is only passed to PROJ if `has_z` is true.
+    fn convert(
+        &self,
+        len: usize,
+        has_z: bool,
+        get: impl Fn(usize) -> (f64, f64, f64),
+        mut set: impl FnMut(usize, (f64, f64, f64)),
+    ) -> Result<()> {
+        if has_z {
+            let mut points = (0..len).map(get).collect::<Vec<_>>();
+            self.proj.convert_array(&mut points)?;
+            points
+                .into_iter()
+                .enumerate()
+                .for_each(|(i, point)| set(i, point));
+        } else {
+            let mut points = (0..len)
+                .map(|i| {
+                    let (x, y, _) = get(i);
+                    (x, y)
+                })
+                .collect::<Vec<_>>();
+            self.proj.convert_array(&mut points)?;
+            points
+                .into_iter()
+                .enumerate()
+                .for_each(|(i, (x, y))| set(i, (x, y, 0.)));
+        }
+        Ok(())
+    }
+}
+
+impl CoordTransform for ProjTransformer<'_> {
+    fn coords(&self, coords: &CoordBuffer) -> Result<CoordBuffer> {
+        let has_z = coords.dim().has_z();
+        let new_coords = match coords {
+            CoordBuffer::Interleaved(coords) => {
+                let stride = coords.dim.size();
+                let source = &coords.coords;
+                let mut values = source.to_vec();
+                self.convert(
+                    coords.len(),
+                    has_z,
+                    |i| {
+                        let z = if has_z { source[i * stride + 2] } else { 0. };
+                        (source[i * stride], source[i * stride + 1], z)
+                    },
+                    |i, (x, y, z)| {
+                        values[i * stride] = x;
+                        values[i * stride + 1] = y;
+                        if has_z {
+                            values[i * stride + 2] = z;
+                        }
+                    },
+                )?;
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into(), coords.dim))
+            }
+            CoordBuffer::Separated(coords) => {
+                let [source_x, source_y, source_z, m] = &coords.buffers;
+                let mut x = source_x.to_vec();
+                let mut y = source_y.to_vec();
+                let mut z = source_z.to_vec();
+                self.convert(
+                    coords.len(),
+                    has_z,
+                    |i| {
+                        let z = if has_z { source_z[i] } else { 0. };
+                        (source_x[i], source_y[i], z)
+                    },
+                    |i, point| {
+                        x[i] = point.0;
+                        y[i] = point.1;
+                        if has_z {
+                            z[i] = point.2;
+                        }
+                    },
+                )?;
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(
+                    [x.into(), y.into(), z.into(), m.clone()],
+                    coords.dim,
+                ))
+            }
+        };
+        Ok(new_coords)
+    }
+
+    /// The input metadata with its CRS replaced by the target CRS.
+    fn metadata(&self, metadata: &ArrayMetadata) -> Arc<ArrayMetadata> {
+        Arc::new(ArrayMetadata {
+            crs: self.target_crs.crs.clone(),
+            crs_type: self.target_crs.crs_type.clone(),
+            ..metadata.clone()
+        })
+    }
+}
+
+/// Reproject an array to a target CRS using PROJ, reading the source CRS from its metadata.
+///
+/// The source CRS may be stored as PROJJSON, WKT2:2019 or an `AUTHORITY:CODE` string; an error is
+/// returned if the array has no CRS or only an opaque SRID. Coordinates are always interpreted in
+/// (longitude, latitude) or (easting, northing) order, as GeoArrow requires.
+///
+/// The X, Y and, for XYZ and XYZM arrays, Z values are transformed. M values are left untouched.
+/// The CRS of the output is taken from `target_crs`; the rest of the metadata (such as `edges`)
+/// is kept from the input.
+///
+/// Rect arrays are returned as Polygon arrays, because a reprojected rectangle is not in general
+/// axis-aligned anymore.
+pub trait ReprojectTo {
+    type Output;
+
+    fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output;
+}
+
+macro_rules! impl_reproject_to {
+    ($type:ty, $method:ident) => {
+        impl ReprojectTo for $type {
+            type Output = Result<$type>;
+
+            fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+                ProjTransformer::try_new(&self.metadata(), target_crs)?.$method(self)
+            }
+        }
+
+        impl ReprojectTo for ChunkedGeometryArray<$type> {
+            type Output = Result<ChunkedGeometryArray<$type>>;
+
+            fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+                let chunks = self
+                    .chunks
+                    .iter()
+                    .map(|chunk| chunk.reproject_to(target_crs))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ChunkedGeometryArray::new(chunks))
+            }
+        }
+    };
+}
+
+impl_reproject_to!(PointArray, point);
+impl_reproject_to!(LineStringArray, line_string);
+impl_reproject_to!(LargeLineStringArray, line_string);
+impl_reproject_to!(PolygonArray, polygon);
+impl_reproject_to!(LargePolygonArray, polygon);
+impl_reproject_to!(MultiPointArray, multi_point);
+impl_reproject_to!(LargeMultiPointArray, multi_point);
+impl_reproject_to!(MultiLineStringArray, multi_line_string);
+impl_reproject_to!(LargeMultiLineStringArray, multi_line_string);
+impl_reproject_to!(MultiPolygonArray, multi_polygon);
+impl_reproject_to!(LargeMultiPolygonArray, multi_polygon);
+impl_reproject_to!(MixedGeometryArray, mixed);
+impl_reproject_to!(LargeMixedGeometryArray, mixed);
+impl_reproject_to!(GeometryCollectionArray, geometry_collection);
+impl_reproject_to!(LargeGeometryCollectionArray, geometry_collection);
+impl_reproject_to!(GeometryArray, geometry);
+impl_reproject_to!(LargeGeometryArray, geometry);
+
+impl ReprojectTo for RectArray {
+    type Output = Result<PolygonArray>;
+
+    fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+        ProjTransformer::try_new(&self.metadata(), target_crs)?.rect(self)
+    }
+}
+
+impl ReprojectTo for ChunkedRectArray {
+    type Output = Result<ChunkedPolygonArray>;
+
+    fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|chunk| chunk.reproject_to(target_crs))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ChunkedGeometryArray::new(chunks))
+    }
+}
+
+impl ReprojectTo for &dyn NativeArray {
+    type Output = Result<Arc<dyn NativeArray>>;
+
+    fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+        ProjTransformer::try_new(&self.metadata(), target_crs)?.native(*self)
+    }
+}
+
+impl ReprojectTo for &dyn ChunkedNativeArray {
+    type Output = Result<Arc<dyn ChunkedNativeArray>>;
+
+    fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+        let chunks = self
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.as_ref().reproject_to(target_crs))
+            .collect::<Result<Vec<_>>>()?;
+        let chunk_refs = chunks
+            .iter()
+            .map(|chunk| chunk.as_ref())
+            .collect::<Vec<_>>();
+        Ok(ChunkedNativeArrayDyn::from_geoarrow_chunks(&chunk_refs)?.into_inner())
+    }
+}
+
+impl ReprojectTo for Table {
+    type Output = Result<Table>;
+
+    /// Reproject every geometry column of this table, updating the CRS in the field metadata.
+    fn reproject_to(&self, target_crs: &ArrayMetadata) -> Self::Output {
+        let reprojected_columns = self
+            .schema()
+            .as_ref()
+            .geometry_columns()
+            .iter()
+            .map(|idx| {
+                let geometry = self.geometry_column(Some(*idx))?;
+                Ok((*idx, geometry.as_ref().reproject_to(target_crs)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut new_table = self.clone();
+
+        for (column_idx, column) in reprojected_columns.iter() {
+            let prev_field = self.schema().field(*column_idx);
+            let new_field = column
+                .extension_field()
+                .as_ref()
+                .clone()
+                .with_name(prev_field.name())
+                .with_nullable(prev_field.is_nullable());
+            new_table.set_column(*column_idx, new_field.into(), column.array_refs())?;
+        }
+
+        Ok(new_table)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::trait_::ArrayAccessor;
     use approx::assert_relative_eq;
+    use geo_traits::CoordTrait;
 
     use super::*;
     use crate::test::point::{p0, p1, p2};
@@ -87,4 +359,68 @@ mod test {
         assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
         dbg!(out);
     }
+
+    fn wgs84() -> ArrayMetadata {
+        ArrayMetadata::from_authority_code("EPSG:4326".to_string())
+    }
+
+    fn web_mercator() -> ArrayMetadata {
+        ArrayMetadata::from_authority_code("EPSG:3857".to_string())
+    }
+
+    #[test]
+    fn reproject_to_from_metadata() {
+        let point_array = crate::test::point::point_array();
+        let point_array = point_array.with_metadata(Arc::new(wgs84()));
+
+        let out = point_array.as_ref().reproject_to(&web_mercator()).unwrap();
+        let out = out.as_point();
+        assert_eq!(out.value_as_geo(0).x(), 0.0);
+        assert_relative_eq!(out.value_as_geo(0).y(), 111325.1428663851);
+        assert_eq!(out.metadata().as_ref(), &web_mercator());
+    }
+
+    #[test]
+    fn reproject_to_xyz_keeps_z() {
+        let point_array = crate::test::point::point_z_array();
+        let point_array = point_array.with_metadata(Arc::new(wgs84()));
+
+        let out = point_array.as_ref().reproject_to(&web_mercator()).unwrap();
+        let out = out.as_point();
+        assert_eq!(out.dimension(), Dimension::XYZ);
+        assert_relative_eq!(out.coords.value(0).nth_or_panic(1), 111325.1428663851);
+        assert_relative_eq!(out.coords.value(0).nth_or_panic(2), 2.);
+    }
+
+    #[test]
+    fn reproject_to_requires_source_crs() {
+        let point_array = crate::test::point::point_array();
+        assert!(point_array.reproject_to(&web_mercator()).is_err());
+    }
+
+    #[test]
+    fn reproject_table_to() {
+        let mut table = crate::test::point::table();
+        let field = table.schema().field(2).clone();
+        let geometry = table.geometry_column(None).unwrap();
+        let field = geometry.data_type().to_field_with_metadata(
+            field.name(),
+            field.is_nullable(),
+            &wgs84(),
+        );
+        let chunks = geometry
+            .geometry_chunks()
+            .iter()
+            .map(|chunk| chunk.with_metadata(Arc::new(wgs84())).to_array_ref())
+            .collect();
+        table.set_column(2, field.into(), chunks).unwrap();
+
+        let out = table.reproject_to(&web_mercator()).unwrap();
+        let metadata = ArrayMetadata::try_from(out.schema().field(2)).unwrap();
+        assert_eq!(metadata, web_mercator());
+
+        let geometry = out.geometry_column(None).unwrap();
+        let chunk = geometry.geometry_chunks()[0].clone();
+        assert_relative_eq!(chunk.as_point().value_as_geo(0).y(), 111325.1428663851);
+    }
 }
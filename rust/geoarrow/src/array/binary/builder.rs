@@ -5,9 +5,10 @@ use crate::array::metadata::ArrayMetadata;
 use crate::error::{GeoArrowError, Result};
 use arrow_array::builder::GenericBinaryBuilder;
 use arrow_array::OffsetSizeTrait;
+use geo_traits::to_geo::ToGeoRect;
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
-    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
 };
 use wkb::writer::{
     write_geometry_collection, write_line_string, write_multi_line_string, write_multi_point,
@@ -145,6 +146,12 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
         }
     }
 
+    /// Push a Rect onto the end of this builder, as a Polygon
+    #[inline]
+    pub fn push_rect(&mut self, geom: Option<&impl RectTrait<T = f64>>) {
+        self.push_polygon(geom.map(|rect| rect.to_rect().to_polygon()).as_ref())
+    }
+
     /// Push a Geometry onto the end of this builder
     #[inline]
     pub fn push_geometry(&mut self, geom: Option<&impl GeometryTrait<T = f64>>) {
@@ -164,7 +171,8 @@ impl<O: OffsetSizeTrait> WKBBuilder<O> {
                 GeometryCollection(geometry_collection) => {
                     self.push_geometry_collection(Some(geometry_collection))
                 }
-                Rect(_) | Line(_) | Triangle(_) => todo!(),
+                Rect(rect) => self.push_rect(Some(rect)),
+                Line(_) | Triangle(_) => todo!(),
             }
         } else {
             self.0.append_null()
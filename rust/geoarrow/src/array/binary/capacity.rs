@@ -2,9 +2,10 @@ use std::ops::Add;
 
 use arrow_array::OffsetSizeTrait;
 
+use geo_traits::to_geo::ToGeoRect;
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
-    MultiPolygonTrait, PointTrait, PolygonTrait,
+    MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
 };
 use wkb::writer::{
     geometry_collection_wkb_size, line_string_wkb_size, multi_line_string_wkb_size,
@@ -79,6 +80,15 @@ impl WKBCapacity {
         self.offsets_capacity += 1;
     }
 
+    /// Add a Rect to this capacity counter. Rects are written to WKB as Polygons.
+    #[inline]
+    pub fn add_rect<'a>(&mut self, rect: Option<&'a (impl RectTrait<T = f64> + 'a)>) {
+        if let Some(rect) = rect {
+            self.buffer_capacity += polygon_wkb_size(&rect.to_rect().to_polygon());
+        }
+        self.offsets_capacity += 1;
+    }
+
     /// Add a MultiPoint to this capacity counter.
     #[inline]
     pub fn add_multi_point<'a>(
@@ -129,7 +139,8 @@ impl WKBCapacity {
                 MultiLineString(p) => self.add_multi_line_string(Some(p)),
                 MultiPolygon(p) => self.add_multi_polygon(Some(p)),
                 GeometryCollection(p) => self.add_geometry_collection(Some(p)),
-                Rect(_) | Line(_) | Triangle(_) => todo!(),
+                Rect(r) => self.add_rect(Some(r)),
+                Line(_) | Triangle(_) => todo!(),
             }
         } else {
             self.offsets_capacity += 1;
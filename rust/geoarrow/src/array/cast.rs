@@ -59,6 +59,16 @@ pub trait AsNativeArray {
         self.as_multi_polygon_opt().unwrap()
     }
 
+    /// Downcast this to a [`MixedGeometryArray`] with `i32` offsets returning `None` if not
+    /// possible
+    fn as_mixed_opt(&self) -> Option<&MixedGeometryArray>;
+
+    /// Downcast this to a [`MixedGeometryArray`] with `i32` offsets panicking if not possible
+    #[inline]
+    fn as_mixed(&self) -> &MixedGeometryArray {
+        self.as_mixed_opt().unwrap()
+    }
+
     /// Downcast this to a [`GeometryCollectionArray`] with `i32` offsets returning `None` if not
     /// possible
     fn as_geometry_collection_opt(&self) -> Option<&GeometryCollectionArray>;
@@ -86,6 +96,78 @@ pub trait AsNativeArray {
     fn as_geometry(&self) -> &GeometryArray {
         self.as_geometry_opt().unwrap()
     }
+
+    /// Downcast this to a [`LargeLineStringArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_line_string_opt(&self) -> Option<&LargeLineStringArray>;
+
+    /// Downcast this to a [`LargeLineStringArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_line_string(&self) -> &LargeLineStringArray {
+        self.as_large_line_string_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargePolygonArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_polygon_opt(&self) -> Option<&LargePolygonArray>;
+
+    /// Downcast this to a [`LargePolygonArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_polygon(&self) -> &LargePolygonArray {
+        self.as_large_polygon_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargeMultiPointArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_multi_point_opt(&self) -> Option<&LargeMultiPointArray>;
+
+    /// Downcast this to a [`LargeMultiPointArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_multi_point(&self) -> &LargeMultiPointArray {
+        self.as_large_multi_point_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargeMultiLineStringArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_multi_line_string_opt(&self) -> Option<&LargeMultiLineStringArray>;
+
+    /// Downcast this to a [`LargeMultiLineStringArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_multi_line_string(&self) -> &LargeMultiLineStringArray {
+        self.as_large_multi_line_string_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargeMultiPolygonArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_multi_polygon_opt(&self) -> Option<&LargeMultiPolygonArray>;
+
+    /// Downcast this to a [`LargeMultiPolygonArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_multi_polygon(&self) -> &LargeMultiPolygonArray {
+        self.as_large_multi_polygon_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargeMixedGeometryArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_mixed_opt(&self) -> Option<&LargeMixedGeometryArray>;
+
+    /// Downcast this to a [`LargeMixedGeometryArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_mixed(&self) -> &LargeMixedGeometryArray {
+        self.as_large_mixed_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargeGeometryCollectionArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_geometry_collection_opt(&self) -> Option<&LargeGeometryCollectionArray>;
+
+    /// Downcast this to a [`LargeGeometryCollectionArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_geometry_collection(&self) -> &LargeGeometryCollectionArray {
+        self.as_large_geometry_collection_opt().unwrap()
+    }
+
+    /// Downcast this to a [`LargeGeometryArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_geometry_opt(&self) -> Option<&LargeGeometryArray>;
+
+    /// Downcast this to a [`LargeGeometryArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_geometry(&self) -> &LargeGeometryArray {
+        self.as_large_geometry_opt().unwrap()
+    }
 }
 
 impl AsNativeArray for &dyn NativeArray {
@@ -119,6 +201,11 @@ impl AsNativeArray for &dyn NativeArray {
         self.as_any().downcast_ref::<MultiPolygonArray>()
     }
 
+    #[inline]
+    fn as_mixed_opt(&self) -> Option<&MixedGeometryArray> {
+        self.as_any().downcast_ref::<MixedGeometryArray>()
+    }
+
     #[inline]
     fn as_geometry_collection_opt(&self) -> Option<&GeometryCollectionArray> {
         self.as_any().downcast_ref::<GeometryCollectionArray>()
@@ -133,6 +220,46 @@ impl AsNativeArray for &dyn NativeArray {
     fn as_geometry_opt(&self) -> Option<&GeometryArray> {
         self.as_any().downcast_ref::<GeometryArray>()
     }
+
+    #[inline]
+    fn as_large_line_string_opt(&self) -> Option<&LargeLineStringArray> {
+        self.as_any().downcast_ref::<LargeLineStringArray>()
+    }
+
+    #[inline]
+    fn as_large_polygon_opt(&self) -> Option<&LargePolygonArray> {
+        self.as_any().downcast_ref::<LargePolygonArray>()
+    }
+
+    #[inline]
+    fn as_large_multi_point_opt(&self) -> Option<&LargeMultiPointArray> {
+        self.as_any().downcast_ref::<LargeMultiPointArray>()
+    }
+
+    #[inline]
+    fn as_large_multi_line_string_opt(&self) -> Option<&LargeMultiLineStringArray> {
+        self.as_any().downcast_ref::<LargeMultiLineStringArray>()
+    }
+
+    #[inline]
+    fn as_large_multi_polygon_opt(&self) -> Option<&LargeMultiPolygonArray> {
+        self.as_any().downcast_ref::<LargeMultiPolygonArray>()
+    }
+
+    #[inline]
+    fn as_large_mixed_opt(&self) -> Option<&LargeMixedGeometryArray> {
+        self.as_any().downcast_ref::<LargeMixedGeometryArray>()
+    }
+
+    #[inline]
+    fn as_large_geometry_collection_opt(&self) -> Option<&LargeGeometryCollectionArray> {
+        self.as_any().downcast_ref::<LargeGeometryCollectionArray>()
+    }
+
+    #[inline]
+    fn as_large_geometry_opt(&self) -> Option<&LargeGeometryArray> {
+        self.as_any().downcast_ref::<LargeGeometryArray>()
+    }
 }
 
 /// Trait to downcast an Arrow array to a serialized array
@@ -226,6 +353,16 @@ pub trait AsChunkedNativeArray {
         self.as_multi_polygon_opt().unwrap()
     }
 
+    /// Downcast this to a [`ChunkedMixedGeometryArray`] with `i32` offsets returning `None` if not
+    /// possible
+    fn as_mixed_opt(&self) -> Option<&ChunkedMixedGeometryArray>;
+
+    /// Downcast this to a [`ChunkedMixedGeometryArray`] with `i32` offsets panicking if not possible
+    #[inline]
+    fn as_mixed(&self) -> &ChunkedMixedGeometryArray {
+        self.as_mixed_opt().unwrap()
+    }
+
     /// Downcast this to a [`ChunkedGeometryCollectionArray`] with `i32` offsets returning `None` if not
     /// possible
     fn as_geometry_collection_opt(&self) -> Option<&ChunkedGeometryCollectionArray>;
@@ -253,6 +390,78 @@ pub trait AsChunkedNativeArray {
     fn as_geometry(&self) -> &ChunkedUnknownGeometryArray {
         self.as_geometry_opt().unwrap()
     }
+
+    /// Downcast this to a [`ChunkedLargeLineStringArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_line_string_opt(&self) -> Option<&ChunkedLargeLineStringArray>;
+
+    /// Downcast this to a [`ChunkedLargeLineStringArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_line_string(&self) -> &ChunkedLargeLineStringArray {
+        self.as_large_line_string_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargePolygonArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_polygon_opt(&self) -> Option<&ChunkedLargePolygonArray>;
+
+    /// Downcast this to a [`ChunkedLargePolygonArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_polygon(&self) -> &ChunkedLargePolygonArray {
+        self.as_large_polygon_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargeMultiPointArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_multi_point_opt(&self) -> Option<&ChunkedLargeMultiPointArray>;
+
+    /// Downcast this to a [`ChunkedLargeMultiPointArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_multi_point(&self) -> &ChunkedLargeMultiPointArray {
+        self.as_large_multi_point_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargeMultiLineStringArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_multi_line_string_opt(&self) -> Option<&ChunkedLargeMultiLineStringArray>;
+
+    /// Downcast this to a [`ChunkedLargeMultiLineStringArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_multi_line_string(&self) -> &ChunkedLargeMultiLineStringArray {
+        self.as_large_multi_line_string_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargeMultiPolygonArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_multi_polygon_opt(&self) -> Option<&ChunkedLargeMultiPolygonArray>;
+
+    /// Downcast this to a [`ChunkedLargeMultiPolygonArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_multi_polygon(&self) -> &ChunkedLargeMultiPolygonArray {
+        self.as_large_multi_polygon_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargeMixedGeometryArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_mixed_opt(&self) -> Option<&ChunkedLargeMixedGeometryArray>;
+
+    /// Downcast this to a [`ChunkedLargeMixedGeometryArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_mixed(&self) -> &ChunkedLargeMixedGeometryArray {
+        self.as_large_mixed_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargeGeometryCollectionArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_geometry_collection_opt(&self) -> Option<&ChunkedLargeGeometryCollectionArray>;
+
+    /// Downcast this to a [`ChunkedLargeGeometryCollectionArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_geometry_collection(&self) -> &ChunkedLargeGeometryCollectionArray {
+        self.as_large_geometry_collection_opt().unwrap()
+    }
+
+    /// Downcast this to a [`ChunkedLargeUnknownGeometryArray`] with `i64` offsets returning `None` if not possible
+    fn as_large_geometry_opt(&self) -> Option<&ChunkedLargeUnknownGeometryArray>;
+
+    /// Downcast this to a [`ChunkedLargeUnknownGeometryArray`] with `i64` offsets panicking if not possible
+    #[inline]
+    fn as_large_geometry(&self) -> &ChunkedLargeUnknownGeometryArray {
+        self.as_large_geometry_opt().unwrap()
+    }
 }
 
 impl AsChunkedNativeArray for &dyn ChunkedNativeArray {
@@ -286,6 +495,11 @@ impl AsChunkedNativeArray for &dyn ChunkedNativeArray {
         self.as_any().downcast_ref::<ChunkedMultiPolygonArray>()
     }
 
+    #[inline]
+    fn as_mixed_opt(&self) -> Option<&ChunkedMixedGeometryArray> {
+        self.as_any().downcast_ref::<ChunkedMixedGeometryArray>()
+    }
+
     #[inline]
     fn as_geometry_collection_opt(&self) -> Option<&ChunkedGeometryCollectionArray> {
         self.as_any()
@@ -301,6 +515,51 @@ impl AsChunkedNativeArray for &dyn ChunkedNativeArray {
     fn as_geometry_opt(&self) -> Option<&ChunkedUnknownGeometryArray> {
         self.as_any().downcast_ref::<ChunkedUnknownGeometryArray>()
     }
+
+    #[inline]
+    fn as_large_line_string_opt(&self) -> Option<&ChunkedLargeLineStringArray> {
+        self.as_any().downcast_ref::<ChunkedLargeLineStringArray>()
+    }
+
+    #[inline]
+    fn as_large_polygon_opt(&self) -> Option<&ChunkedLargePolygonArray> {
+        self.as_any().downcast_ref::<ChunkedLargePolygonArray>()
+    }
+
+    #[inline]
+    fn as_large_multi_point_opt(&self) -> Option<&ChunkedLargeMultiPointArray> {
+        self.as_any().downcast_ref::<ChunkedLargeMultiPointArray>()
+    }
+
+    #[inline]
+    fn as_large_multi_line_string_opt(&self) -> Option<&ChunkedLargeMultiLineStringArray> {
+        self.as_any()
+            .downcast_ref::<ChunkedLargeMultiLineStringArray>()
+    }
+
+    #[inline]
+    fn as_large_multi_polygon_opt(&self) -> Option<&ChunkedLargeMultiPolygonArray> {
+        self.as_any()
+            .downcast_ref::<ChunkedLargeMultiPolygonArray>()
+    }
+
+    #[inline]
+    fn as_large_mixed_opt(&self) -> Option<&ChunkedLargeMixedGeometryArray> {
+        self.as_any()
+            .downcast_ref::<ChunkedLargeMixedGeometryArray>()
+    }
+
+    #[inline]
+    fn as_large_geometry_collection_opt(&self) -> Option<&ChunkedLargeGeometryCollectionArray> {
+        self.as_any()
+            .downcast_ref::<ChunkedLargeGeometryCollectionArray>()
+    }
+
+    #[inline]
+    fn as_large_geometry_opt(&self) -> Option<&ChunkedLargeUnknownGeometryArray> {
+        self.as_any()
+            .downcast_ref::<ChunkedLargeUnknownGeometryArray>()
+    }
 }
 
 #[allow(dead_code)]
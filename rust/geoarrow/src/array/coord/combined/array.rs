@@ -4,11 +4,12 @@ use crate::array::{
     CoordType, InterleavedCoordBuffer, InterleavedCoordBufferBuilder, SeparatedCoordBuffer,
     SeparatedCoordBufferBuilder,
 };
-use crate::datatypes::Dimension;
+use crate::datatypes::{dimension_names, Dimension};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::Coord;
 use crate::trait_::IntoArrow;
 use arrow_array::{Array, ArrayRef, FixedSizeListArray, StructArray};
+use arrow_buffer::ScalarBuffer;
 use arrow_schema::DataType;
 
 /// An Arrow representation of an array of coordinates.
@@ -122,6 +123,46 @@ impl CoordBuffer {
         }
     }
 
+    /// Convert this coordinate array into the given [Dimension].
+    ///
+    /// Ordinates that exist in both dimensions are kept, ordinates that exist only in `dim` are
+    /// set to `fill_value`, and all others are dropped. For separated coordinates, the buffers of
+    /// kept ordinates are reused without copying.
+    pub(crate) fn into_dimension(self, dim: Dimension, fill_value: f64) -> Self {
+        let from_names = dimension_names(self.dim());
+        let source_index = |name: &&str| from_names.iter().position(|n| n == name);
+
+        match self {
+            CoordBuffer::Interleaved(cb) => {
+                let from_size = cb.dim.size();
+                let sources = dimension_names(dim)
+                    .iter()
+                    .map(source_index)
+                    .collect::<Vec<_>>();
+                let mut values = Vec::with_capacity(cb.len() * dim.size());
+                for i in 0..cb.len() {
+                    for source in sources.iter() {
+                        values.push(match source {
+                            Some(j) => cb.coords[i * from_size + j],
+                            None => fill_value,
+                        });
+                    }
+                }
+                CoordBuffer::Interleaved(InterleavedCoordBuffer::new(values.into(), dim))
+            }
+            CoordBuffer::Separated(cb) => {
+                let to_names = dimension_names(dim);
+                let buffers: [ScalarBuffer<f64>; 4] =
+                    std::array::from_fn(|i| match to_names.get(i).map(source_index) {
+                        Some(Some(j)) => cb.buffers[j].clone(),
+                        Some(None) => vec![fill_value; cb.len()].into(),
+                        None => Vec::new().into(),
+                    });
+                CoordBuffer::Separated(SeparatedCoordBuffer::new(buffers, dim))
+            }
+        }
+    }
+
     pub(crate) fn from_arrow(value: &dyn Array, dim: Dimension) -> Result<Self> {
         match value.data_type() {
             DataType::Struct(_) => {
@@ -210,6 +251,7 @@ impl From<SeparatedCoordBuffer> for CoordBuffer {
 
 #[cfg(test)]
 mod test {
+    use crate::array::CoordBufferBuilder;
     use crate::error::Result;
 
     use super::*;
@@ -261,4 +303,22 @@ mod test {
         assert_eq!(buf1, buf2);
         Ok(())
     }
+
+    #[test]
+    fn push_coord_checks_dimension() -> Result<()> {
+        let xyz = CoordBuffer::Interleaved(InterleavedCoordBuffer::from_vec(
+            vec![0., 1., 2.],
+            Dimension::XYZ,
+        )?);
+        for interleaved in [true, false] {
+            let mut builder = CoordBufferBuilder::initialize(0, interleaved, Dimension::XYM);
+            assert!(builder.try_push_coord(&xyz.value(0)).is_err());
+            assert!(builder.is_empty());
+
+            let mut builder = CoordBufferBuilder::initialize(0, interleaved, Dimension::XYZ);
+            builder.try_push_coord(&xyz.value(0))?;
+            assert_eq!(builder.len(), 1);
+        }
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::array::{CoordType, InterleavedCoordBufferBuilder};
-use crate::datatypes::{coord_type_to_data_type, Dimension};
+use crate::datatypes::{coord_type_to_data_type, dimension_names, Dimension};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::InterleavedCoord;
 use crate::trait_::IntoArrow;
@@ -77,10 +77,7 @@ impl InterleavedCoordBuffer {
     }
 
     pub(crate) fn values_field(&self) -> Field {
-        match self.dim {
-            Dimension::XY => Field::new("xy", DataType::Float64, false),
-            Dimension::XYZ => Field::new("xyz", DataType::Float64, false),
-        }
+        Field::new(dimension_names(self.dim).concat(), DataType::Float64, false)
     }
 
     pub(crate) fn slice(&self, offset: usize, length: usize) -> Self {
@@ -1,8 +1,9 @@
 use core::f64;
 
+use crate::array::coord::check_coord_dim;
 use crate::array::InterleavedCoordBuffer;
 use crate::datatypes::Dimension;
-use crate::error::{GeoArrowError, Result};
+use crate::error::Result;
 use geo_traits::{CoordTrait, PointTrait};
 
 /// The GeoArrow equivalent to `Vec<Coord>`: a mutable collection of coordinates.
@@ -92,18 +93,13 @@ impl InterleavedCoordBufferBuilder {
     ///
     /// - If the added coordinate does not have the same dimension as the coordinate buffer.
     pub fn try_push_coord(&mut self, coord: &impl CoordTrait<T = f64>) -> Result<()> {
-        // TODO: should check xyz/zym
-        if coord.dim().size() != self.dim.size() {
-            return Err(GeoArrowError::General(
-                "coord dimension must match coord buffer dimension.".into(),
-            ));
-        }
+        check_coord_dim(coord.dim(), self.dim)?;
 
         self.coords.push(coord.x());
         self.coords.push(coord.y());
-        if let Some(z) = coord.nth(2) {
-            self.coords.push(z);
-        };
+        for i in 2..self.dim.size() {
+            self.coords.push(coord.nth_or_panic(i));
+        }
         Ok(())
     }
 
@@ -12,6 +12,29 @@ pub use combined::{CoordBuffer, CoordBufferBuilder};
 pub use interleaved::{InterleavedCoordBuffer, InterleavedCoordBufferBuilder};
 pub use separated::{SeparatedCoordBuffer, SeparatedCoordBufferBuilder};
 
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+
+/// Check that a coordinate with dimensions `coord_dim` can be stored in a buffer of dimension
+/// `dim`.
+///
+/// XYZ and XYM coordinates have the same size but are not interchangeable. A coordinate of unknown
+/// dimension only needs to have the same size.
+pub(crate) fn check_coord_dim(coord_dim: geo_traits::Dimensions, dim: Dimension) -> Result<()> {
+    let matches = match coord_dim {
+        geo_traits::Dimensions::Unknown(size) => size == dim.size(),
+        coord_dim => Dimension::try_from(coord_dim).is_ok_and(|coord_dim| coord_dim == dim),
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(GeoArrowError::General(format!(
+            "coord dimension {:?} must match coord buffer dimension {:?}.",
+            coord_dim, dim
+        )))
+    }
+}
+
 /// The permitted GeoArrow coordinate representations.
 ///
 /// GeoArrow permits coordinate types to either be `Interleaved`, where the X and Y coordinates are
@@ -7,7 +7,7 @@ use arrow_buffer::ScalarBuffer;
 use arrow_schema::{DataType, Field};
 
 use crate::array::{CoordType, SeparatedCoordBufferBuilder};
-use crate::datatypes::{coord_type_to_data_type, Dimension};
+use crate::datatypes::{coord_type_to_data_type, dimension_names, Dimension};
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::SeparatedCoord;
 use crate::trait_::IntoArrow;
@@ -26,12 +26,9 @@ pub struct SeparatedCoordBuffer {
 }
 
 fn check(buffers: &[ScalarBuffer<f64>; 4], dim: Dimension) -> Result<()> {
-    let all_same_length = match dim {
-        Dimension::XY => buffers[0].len() == buffers[1].len(),
-        Dimension::XYZ => {
-            buffers[0].len() == buffers[1].len() && buffers[1].len() == buffers[2].len()
-        }
-    };
+    let all_same_length = buffers[..dim.size()]
+        .iter()
+        .all(|buffer| buffer.len() == buffers[0].len());
 
     if !all_same_length {
         return Err(GeoArrowError::General(
@@ -75,18 +72,7 @@ impl SeparatedCoordBuffer {
     ///
     /// In comparison to raw_buffers, all of the returned buffers are valid.
     pub fn buffers(&self) -> Vec<ScalarBuffer<f64>> {
-        match self.dim {
-            Dimension::XY => {
-                vec![self.buffers[0].clone(), self.buffers[1].clone()]
-            }
-            Dimension::XYZ => {
-                vec![
-                    self.buffers[0].clone(),
-                    self.buffers[1].clone(),
-                    self.buffers[2].clone(),
-                ]
-            }
-        }
+        self.buffers[..self.dim.size()].to_vec()
     }
 
     /// The dimension of this coordinate buffer
@@ -95,39 +81,17 @@ impl SeparatedCoordBuffer {
     }
 
     pub(crate) fn values_array(&self) -> Vec<ArrayRef> {
-        match self.dim {
-            Dimension::XY => {
-                vec![
-                    Arc::new(Float64Array::new(self.buffers[0].clone(), None)),
-                    Arc::new(Float64Array::new(self.buffers[1].clone(), None)),
-                ]
-            }
-            Dimension::XYZ => {
-                vec![
-                    Arc::new(Float64Array::new(self.buffers[0].clone(), None)),
-                    Arc::new(Float64Array::new(self.buffers[1].clone(), None)),
-                    Arc::new(Float64Array::new(self.buffers[2].clone(), None)),
-                ]
-            }
-        }
+        self.buffers[..self.dim.size()]
+            .iter()
+            .map(|buffer| Arc::new(Float64Array::new(buffer.clone(), None)) as ArrayRef)
+            .collect()
     }
 
     pub(crate) fn values_field(&self) -> Vec<Field> {
-        match self.dim {
-            Dimension::XY => {
-                vec![
-                    Field::new("x", DataType::Float64, false),
-                    Field::new("y", DataType::Float64, false),
-                ]
-            }
-            Dimension::XYZ => {
-                vec![
-                    Field::new("x", DataType::Float64, false),
-                    Field::new("y", DataType::Float64, false),
-                    Field::new("z", DataType::Float64, false),
-                ]
-            }
-        }
+        dimension_names(self.dim)
+            .iter()
+            .map(|name| Field::new(*name, DataType::Float64, false))
+            .collect()
     }
 
     pub(crate) fn slice(&self, offset: usize, length: usize) -> Self {
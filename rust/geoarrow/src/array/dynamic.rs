@@ -38,11 +38,24 @@ impl NativeArrayDyn {
             MultiPoint(_, _) => Arc::new(MultiPointArray::try_from((array, field))?),
             MultiLineString(_, _) => Arc::new(MultiLineStringArray::try_from((array, field))?),
             MultiPolygon(_, _) => Arc::new(MultiPolygonArray::try_from((array, field))?),
+            Mixed(_, _) => Arc::new(MixedGeometryArray::try_from((array, field))?),
             GeometryCollection(_, _) => {
                 Arc::new(GeometryCollectionArray::try_from((array, field))?)
             }
+            LargeLineString(_, _) => Arc::new(LargeLineStringArray::try_from((array, field))?),
+            LargePolygon(_, _) => Arc::new(LargePolygonArray::try_from((array, field))?),
+            LargeMultiPoint(_, _) => Arc::new(LargeMultiPointArray::try_from((array, field))?),
+            LargeMultiLineString(_, _) => {
+                Arc::new(LargeMultiLineStringArray::try_from((array, field))?)
+            }
+            LargeMultiPolygon(_, _) => Arc::new(LargeMultiPolygonArray::try_from((array, field))?),
+            LargeMixed(_, _) => Arc::new(LargeMixedGeometryArray::try_from((array, field))?),
+            LargeGeometryCollection(_, _) => {
+                Arc::new(LargeGeometryCollectionArray::try_from((array, field))?)
+            }
             Rect(_) => Arc::new(RectArray::try_from((array, field))?),
             Geometry(_) => Arc::new(GeometryArray::try_from((array, field))?),
+            LargeGeometry(_) => Arc::new(LargeGeometryArray::try_from((array, field))?),
         };
 
         Ok(Self(geo_arr))
@@ -134,6 +147,14 @@ impl NativeArray for NativeArrayDyn {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         self.0.slice(offset, length)
     }
+
+    fn validate(&self) -> Result<()> {
+        self.0.validate()
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.0.validate_full()
+    }
 }
 
 impl Display for NativeArrayDyn {
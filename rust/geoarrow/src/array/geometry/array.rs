@@ -2,12 +2,13 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_array::{Array, ArrayRef, OffsetSizeTrait, UnionArray};
-use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_buffer::{NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field, UnionMode};
 
 use crate::array::geometry::GeometryBuilder;
 use crate::array::geometry::GeometryCapacity;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::validate::validate_union;
 use crate::array::*;
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -49,9 +50,13 @@ use geo_traits::GeometryTrait;
 /// - 35: MultiLineString ZM
 /// - 36: MultiPolygon ZM
 /// - 37: GeometryCollection ZM
+///
+/// The offset type `O` applies to the list offsets of the child arrays; it is `i32` for
+/// [`GeometryArray`] and `i64` for [`LargeGeometryArray`]. The union offsets are always `i32`, as
+/// required by Arrow.
 #[derive(Debug, Clone, PartialEq)]
-pub struct GeometryArray {
-    /// Always NativeType::Unknown
+pub struct GenericGeometryArray<O: OffsetSizeTrait> {
+    /// Always NativeType::Geometry or NativeType::LargeGeometry
     data_type: NativeType,
 
     pub(crate) metadata: Arc<ArrayMetadata>,
@@ -63,26 +68,28 @@ pub struct GeometryArray {
     /// Invariant: `offsets.len() == type_ids.len()`
     pub(crate) offsets: ScalarBuffer<i32>,
 
-    // In the future we'll additionally have xym, xyzm array variants.
-    pub(crate) point_xy: PointArray,
-    pub(crate) line_string_xy: LineStringArray,
-    pub(crate) polygon_xy: PolygonArray,
-    pub(crate) mpoint_xy: MultiPointArray,
-    pub(crate) mline_string_xy: MultiLineStringArray,
-    pub(crate) mpolygon_xy: MultiPolygonArray,
-    pub(crate) gc_xy: GeometryCollectionArray,
-
-    pub(crate) point_xyz: PointArray,
-    pub(crate) line_string_xyz: LineStringArray,
-    pub(crate) polygon_xyz: PolygonArray,
-    pub(crate) mpoint_xyz: MultiPointArray,
-    pub(crate) mline_string_xyz: MultiLineStringArray,
-    pub(crate) mpolygon_xyz: MultiPolygonArray,
-    pub(crate) gc_xyz: GeometryCollectionArray,
+    /// Child arrays for each geometry type, indexed by dimension in the order XY, XYZ, XYM,
+    /// XYZM.
+    pub(crate) points: [PointArray; 4],
+    pub(crate) line_strings: [GenericLineStringArray<O>; 4],
+    pub(crate) polygons: [GenericPolygonArray<O>; 4],
+    pub(crate) mpoints: [GenericMultiPointArray<O>; 4],
+    pub(crate) mline_strings: [GenericMultiLineStringArray<O>; 4],
+    pub(crate) mpolygons: [GenericMultiPolygonArray<O>; 4],
+    pub(crate) gcs: [GenericGeometryCollectionArray<O>; 4],
 }
 
-impl GeometryArray {
-    /// Create a new MixedGeometryArray from parts
+/// An array of geometries with `i32` offsets in its child arrays.
+pub type GeometryArray = GenericGeometryArray<i32>;
+
+/// An array of geometries with `i64` offsets in its child arrays.
+pub type LargeGeometryArray = GenericGeometryArray<i64>;
+
+impl<O: OffsetSizeTrait> GenericGeometryArray<O> {
+    /// Create a new GeometryArray from parts
+    ///
+    /// Each child array is passed as an array of four, one for each dimension in the order XY,
+    /// XYZ, XYM, XYZM.
     ///
     /// # Implementation
     ///
@@ -92,369 +99,172 @@ impl GeometryArray {
     ///
     /// - if the validity is not `None` and its length is different from the number of geometries
     /// - if the largest geometry offset does not match the number of coordinates
+    /// - if the child arrays do not all share the same coordinate type
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         type_ids: ScalarBuffer<i8>,
         offsets: ScalarBuffer<i32>,
-        point_xy: Option<PointArray>,
-        line_string_xy: Option<LineStringArray>,
-        polygon_xy: Option<PolygonArray>,
-        mpoint_xy: Option<MultiPointArray>,
-        mline_string_xy: Option<MultiLineStringArray>,
-        mpolygon_xy: Option<MultiPolygonArray>,
-        gc_xy: Option<GeometryCollectionArray>,
-        point_xyz: Option<PointArray>,
-        line_string_xyz: Option<LineStringArray>,
-        polygon_xyz: Option<PolygonArray>,
-        mpoint_xyz: Option<MultiPointArray>,
-        mline_string_xyz: Option<MultiLineStringArray>,
-        mpolygon_xyz: Option<MultiPolygonArray>,
-        gc_xyz: Option<GeometryCollectionArray>,
+        points: [PointArray; 4],
+        line_strings: [GenericLineStringArray<O>; 4],
+        polygons: [GenericPolygonArray<O>; 4],
+        mpoints: [GenericMultiPointArray<O>; 4],
+        mline_strings: [GenericMultiLineStringArray<O>; 4],
+        mpolygons: [GenericMultiPolygonArray<O>; 4],
+        gcs: [GenericGeometryCollectionArray<O>; 4],
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
         let mut coord_types = HashSet::new();
-        if let Some(point_xy) = &point_xy {
-            coord_types.insert(point_xy.coord_type());
-        }
-        if let Some(line_string_xy) = &line_string_xy {
-            coord_types.insert(line_string_xy.coord_type());
-        }
-        if let Some(polygon_xy) = &polygon_xy {
-            coord_types.insert(polygon_xy.coord_type());
-        }
-        if let Some(mpoint_xy) = &mpoint_xy {
-            coord_types.insert(mpoint_xy.coord_type());
-        }
-        if let Some(mline_string_xy) = &mline_string_xy {
-            coord_types.insert(mline_string_xy.coord_type());
-        }
-        if let Some(mpolygon_xy) = &mpolygon_xy {
-            coord_types.insert(mpolygon_xy.coord_type());
-        }
-        if let Some(gc_xy) = &gc_xy {
-            coord_types.insert(gc_xy.coord_type());
-        }
-
-        if let Some(point_xyz) = &point_xyz {
-            coord_types.insert(point_xyz.coord_type());
-        }
-        if let Some(line_string_xyz) = &line_string_xyz {
-            coord_types.insert(line_string_xyz.coord_type());
-        }
-        if let Some(polygon_xyz) = &polygon_xyz {
-            coord_types.insert(polygon_xyz.coord_type());
-        }
-        if let Some(mpoint_xyz) = &mpoint_xyz {
-            coord_types.insert(mpoint_xyz.coord_type());
-        }
-        if let Some(mline_string_xyz) = &mline_string_xyz {
-            coord_types.insert(mline_string_xyz.coord_type());
-        }
-        if let Some(mpolygon_xyz) = &mpolygon_xyz {
-            coord_types.insert(mpolygon_xyz.coord_type());
-        }
-        if let Some(gc_xyz) = &gc_xyz {
-            coord_types.insert(gc_xyz.coord_type());
-        }
+        points.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
+        line_strings.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
+        polygons.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
+        mpoints.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
+        mline_strings.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
+        mpolygons.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
+        gcs.iter().for_each(|arr| {
+            coord_types.insert(arr.coord_type());
+        });
         assert!(coord_types.len() <= 1);
 
         let coord_type = coord_types.into_iter().next().unwrap_or_default();
 
-        let data_type = NativeType::Geometry(coord_type);
+        let data_type = if O::IS_LARGE {
+            NativeType::LargeGeometry(coord_type)
+        } else {
+            NativeType::Geometry(coord_type)
+        };
 
-        use Dimension::*;
         Self {
             data_type,
             type_ids,
             offsets,
-            point_xy: point_xy.unwrap_or(
-                PointBuilder::new_with_options(XY, coord_type, Default::default()).finish(),
-            ),
-            line_string_xy: line_string_xy.unwrap_or(
-                LineStringBuilder::new_with_options(XY, coord_type, Default::default()).finish(),
-            ),
-            polygon_xy: polygon_xy.unwrap_or(
-                PolygonBuilder::new_with_options(XY, coord_type, Default::default()).finish(),
-            ),
-            mpoint_xy: mpoint_xy.unwrap_or(
-                MultiPointBuilder::new_with_options(XY, coord_type, Default::default()).finish(),
-            ),
-            mline_string_xy: mline_string_xy.unwrap_or(
-                MultiLineStringBuilder::new_with_options(XY, coord_type, Default::default())
-                    .finish(),
-            ),
-            mpolygon_xy: mpolygon_xy.unwrap_or(
-                MultiPolygonBuilder::new_with_options(XY, coord_type, Default::default()).finish(),
-            ),
-            gc_xy: gc_xy.unwrap_or(
-                GeometryCollectionBuilder::new_with_options(
-                    XY,
-                    coord_type,
-                    Default::default(),
-                    false,
-                )
-                .finish(),
-            ),
-            point_xyz: point_xyz.unwrap_or(
-                PointBuilder::new_with_options(XYZ, coord_type, Default::default()).finish(),
-            ),
-            line_string_xyz: line_string_xyz.unwrap_or(
-                LineStringBuilder::new_with_options(XYZ, coord_type, Default::default()).finish(),
-            ),
-            polygon_xyz: polygon_xyz.unwrap_or(
-                PolygonBuilder::new_with_options(XYZ, coord_type, Default::default()).finish(),
-            ),
-            mpoint_xyz: mpoint_xyz.unwrap_or(
-                MultiPointBuilder::new_with_options(XYZ, coord_type, Default::default()).finish(),
-            ),
-            mline_string_xyz: mline_string_xyz.unwrap_or(
-                MultiLineStringBuilder::new_with_options(XYZ, coord_type, Default::default())
-                    .finish(),
-            ),
-            mpolygon_xyz: mpolygon_xyz.unwrap_or(
-                MultiPolygonBuilder::new_with_options(XYZ, coord_type, Default::default()).finish(),
-            ),
-            gc_xyz: gc_xyz.unwrap_or(
-                GeometryCollectionBuilder::new_with_options(
-                    XYZ,
-                    coord_type,
+            points,
+            line_strings,
+            polygons,
+            mpoints,
+            mline_strings,
+            mpolygons,
+            gcs,
+            metadata,
+        }
+    }
+
+    /// Create a new GeometryArray with the given coordinate type and no geometries.
+    fn new_empty(coord_type: CoordType, metadata: Arc<ArrayMetadata>) -> Self {
+        // An empty mixed array per dimension provides empty children of every geometry type
+        let mixed = Dimension::ALL.map(|dim| {
+            GenericMixedGeometryArray::<O>::new(
+                ScalarBuffer::from(vec![]),
+                ScalarBuffer::from(vec![]),
+                Some(PointBuilder::new_with_options(dim, coord_type, Default::default()).finish()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Default::default(),
+            )
+        });
+        Self::new(
+            ScalarBuffer::from(vec![]),
+            ScalarBuffer::from(vec![]),
+            mixed.clone().map(|arr| arr.points),
+            mixed.clone().map(|arr| arr.line_strings),
+            mixed.clone().map(|arr| arr.polygons),
+            mixed.clone().map(|arr| arr.multi_points),
+            mixed.clone().map(|arr| arr.multi_line_strings),
+            mixed.clone().map(|arr| arr.multi_polygons),
+            mixed.map(|arr| {
+                GenericGeometryCollectionArray::new(
+                    arr,
+                    OffsetBuffer::new_empty(),
+                    None,
                     Default::default(),
-                    false,
                 )
-                .finish(),
-            ),
+            }),
             metadata,
-        }
+        )
     }
 
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> GeometryCapacity {
         GeometryCapacity::new(
             0,
-            self.point_xy.buffer_lengths(),
-            self.line_string_xy.buffer_lengths(),
-            self.polygon_xy.buffer_lengths(),
-            self.mpoint_xy.buffer_lengths(),
-            self.mline_string_xy.buffer_lengths(),
-            self.mpolygon_xy.buffer_lengths(),
-            self.gc_xy.buffer_lengths(),
-            self.point_xyz.buffer_lengths(),
-            self.line_string_xyz.buffer_lengths(),
-            self.polygon_xyz.buffer_lengths(),
-            self.mpoint_xyz.buffer_lengths(),
-            self.mline_string_xyz.buffer_lengths(),
-            self.mpolygon_xyz.buffer_lengths(),
-            self.gc_xyz.buffer_lengths(),
+            core::array::from_fn(|i| self.points[i].buffer_lengths()),
+            core::array::from_fn(|i| self.line_strings[i].buffer_lengths()),
+            core::array::from_fn(|i| self.polygons[i].buffer_lengths()),
+            core::array::from_fn(|i| self.mpoints[i].buffer_lengths()),
+            core::array::from_fn(|i| self.mline_strings[i].buffer_lengths()),
+            core::array::from_fn(|i| self.mpolygons[i].buffer_lengths()),
+            core::array::from_fn(|i| self.gcs[i].buffer_lengths()),
             false,
         )
     }
 
     // TODO: handle slicing
     pub(crate) fn has_points(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.point_xy.is_empty(),
-            Dimension::XYZ => !self.point_xyz.is_empty(),
-        }
+        !self.points[dim.order()].is_empty()
     }
 
     pub(crate) fn has_line_strings(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.line_string_xy.is_empty(),
-            Dimension::XYZ => !self.line_string_xyz.is_empty(),
-        }
+        !self.line_strings[dim.order()].is_empty()
     }
 
     pub(crate) fn has_polygons(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.polygon_xy.is_empty(),
-            Dimension::XYZ => !self.polygon_xyz.is_empty(),
-        }
+        !self.polygons[dim.order()].is_empty()
     }
 
     pub(crate) fn has_multi_points(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.mpoint_xy.is_empty(),
-            Dimension::XYZ => !self.mpoint_xyz.is_empty(),
-        }
+        !self.mpoints[dim.order()].is_empty()
     }
 
     pub(crate) fn has_multi_line_strings(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.mline_string_xy.is_empty(),
-            Dimension::XYZ => !self.mline_string_xyz.is_empty(),
-        }
+        !self.mline_strings[dim.order()].is_empty()
     }
 
     pub(crate) fn has_multi_polygons(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.mpolygon_xy.is_empty(),
-            Dimension::XYZ => !self.mpolygon_xyz.is_empty(),
-        }
+        !self.mpolygons[dim.order()].is_empty()
     }
 
     pub(crate) fn has_geometry_collections(&self, dim: Dimension) -> bool {
-        match dim {
-            Dimension::XY => !self.gc_xy.is_empty(),
-            Dimension::XYZ => !self.gc_xyz.is_empty(),
-        }
+        !self.gcs[dim.order()].is_empty()
     }
 
     /// Return `true` if this array holds at least one geometry array of the given dimension
     pub fn has_dimension(&self, dim: Dimension) -> bool {
-        use Dimension::*;
-        match dim {
-            XY => {
-                self.has_points(XY)
-                    || self.has_line_strings(XY)
-                    || self.has_polygons(XY)
-                    || self.has_multi_points(XY)
-                    || self.has_multi_line_strings(XY)
-                    || self.has_multi_polygons(XY)
-            }
-            XYZ => {
-                self.has_points(XYZ)
-                    || self.has_line_strings(XYZ)
-                    || self.has_polygons(XYZ)
-                    || self.has_multi_points(XYZ)
-                    || self.has_multi_line_strings(XYZ)
-                    || self.has_multi_polygons(XYZ)
-            }
-        }
+        self.has_points(dim)
+            || self.has_line_strings(dim)
+            || self.has_polygons(dim)
+            || self.has_multi_points(dim)
+            || self.has_multi_line_strings(dim)
+            || self.has_multi_polygons(dim)
+            || self.has_geometry_collections(dim)
     }
 
     /// Return `true` if this array holds at least one geometry array of the given dimension and no
     /// arrays of any other dimension.
     pub fn has_only_dimension(&self, dim: Dimension) -> bool {
-        use Dimension::*;
-        match dim {
-            XY => self.has_dimension(XY) && !self.has_dimension(XYZ),
-            XYZ => self.has_dimension(XYZ) && !self.has_dimension(XY),
-        }
+        self.has_dimension(dim)
+            && Dimension::ALL
+                .iter()
+                .filter(|other| **other != dim)
+                .all(|other| !self.has_dimension(*other))
     }
 
     // Handle sliced data before downcasting.
     // pub fn compact_children()
 
-    // /// The number of non-empty child arrays
-    // fn num_non_empty_children(&self) -> usize {
-    //     let mut count = 0;
-
-    //     if !self.point_xy.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.line_string_xy.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.polygon_xy.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.mpoint_xy.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.mline_string_xy.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.mpolygon_xy.is_empty() {
-    //         count += 1
-    //     };
-
-    //     if !self.point_xyz.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.line_string_xyz.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.polygon_xyz.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.mpoint_xyz.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.mline_string_xyz.is_empty() {
-    //         count += 1
-    //     };
-    //     if !self.mpolygon_xyz.is_empty() {
-    //         count += 1
-    //     };
-
-    //     count
-    // }
-
-    // TODO: restore to enable downcasting
-
-    // pub fn has_only_type(&self, typ: NativeType) -> bool {
-    //     use Dimension::*;
-
-    //     if self.num_non_empty_children() == 0 {
-    //         // Empty array
-    //         false
-    //     }
-
-    //     if self.num_non_empty_children() > 1 {}
-
-    //     match typ {
-    //         NativeType::Point(_, dim)
-    //     }
-
-    //     self.has_points(XY)
-    //         && !self.has_line_strings(XY)
-    //         && !self.has_polygons(XY)
-    //         && !self.has_multi_points(XY)
-    //         && !self.has_multi_line_strings(XY)
-    //         && !self.has_multi_polygons(XY)
-    //         && !self.has_points(XYZ)
-    //         && !self.has_line_strings(XYZ)
-    //         && !self.has_polygons(XYZ)
-    //         && !self.has_multi_points(XYZ)
-    //         && !self.has_multi_line_strings(XYZ)
-    //         && !self.has_multi_polygons(XYZ)
-    // }
-
-    // pub fn has_only_line_strings(&self) -> bool {
-    //     !self.has_points()
-    //         && self.has_line_strings()
-    //         && !self.has_polygons()
-    //         && !self.has_multi_points()
-    //         && !self.has_multi_line_strings()
-    //         && !self.has_multi_polygons()
-    // }
-
-    // pub fn has_only_polygons(&self) -> bool {
-    //     !self.has_points()
-    //         && !self.has_line_strings()
-    //         && self.has_polygons()
-    //         && !self.has_multi_points()
-    //         && !self.has_multi_line_strings()
-    //         && !self.has_multi_polygons()
-    // }
-
-    // pub fn has_only_multi_points(&self) -> bool {
-    //     !self.has_points()
-    //         && !self.has_line_strings()
-    //         && !self.has_polygons()
-    //         && self.has_multi_points()
-    //         && !self.has_multi_line_strings()
-    //         && !self.has_multi_polygons()
-    // }
-
-    // pub fn has_only_multi_line_strings(&self) -> bool {
-    //     !self.has_points()
-    //         && !self.has_line_strings()
-    //         && !self.has_polygons()
-    //         && !self.has_multi_points()
-    //         && self.has_multi_line_strings()
-    //         && !self.has_multi_polygons()
-    // }
-
-    // pub fn has_only_multi_polygons(&self) -> bool {
-    //     !self.has_points()
-    //         && !self.has_line_strings()
-    //         && !self.has_polygons()
-    //         && !self.has_multi_points()
-    //         && !self.has_multi_line_strings()
-    //         && self.has_multi_polygons()
-    // }
-
     /// The number of bytes occupied by this array.
     pub fn num_bytes(&self) -> usize {
         self.buffer_lengths().num_bytes()
@@ -480,21 +290,13 @@ impl GeometryArray {
             type_ids: self.type_ids.slice(offset, length),
             offsets: self.offsets.slice(offset, length),
 
-            point_xy: self.point_xy.clone(),
-            line_string_xy: self.line_string_xy.clone(),
-            polygon_xy: self.polygon_xy.clone(),
-            mpoint_xy: self.mpoint_xy.clone(),
-            mline_string_xy: self.mline_string_xy.clone(),
-            mpolygon_xy: self.mpolygon_xy.clone(),
-            gc_xy: self.gc_xy.clone(),
-
-            point_xyz: self.point_xyz.clone(),
-            line_string_xyz: self.line_string_xyz.clone(),
-            polygon_xyz: self.polygon_xyz.clone(),
-            mpoint_xyz: self.mpoint_xyz.clone(),
-            mline_string_xyz: self.mline_string_xyz.clone(),
-            mpolygon_xyz: self.mpolygon_xyz.clone(),
-            gc_xyz: self.gc_xyz.clone(),
+            points: self.points.clone(),
+            line_strings: self.line_strings.clone(),
+            polygons: self.polygons.clone(),
+            mpoints: self.mpoints.clone(),
+            mline_strings: self.mline_strings.clone(),
+            mpolygons: self.mpolygons.clone(),
+            gcs: self.gcs.clone(),
 
             metadata: self.metadata.clone(),
         }
@@ -510,20 +312,14 @@ impl GeometryArray {
         Self::new(
             self.type_ids,
             self.offsets,
-            Some(self.point_xy.into_coord_type(coord_type)),
-            Some(self.line_string_xy.into_coord_type(coord_type)),
-            Some(self.polygon_xy.into_coord_type(coord_type)),
-            Some(self.mpoint_xy.into_coord_type(coord_type)),
-            Some(self.mline_string_xy.into_coord_type(coord_type)),
-            Some(self.mpolygon_xy.into_coord_type(coord_type)),
-            Some(self.gc_xy.into_coord_type(coord_type)),
-            Some(self.point_xyz.into_coord_type(coord_type)),
-            Some(self.line_string_xyz.into_coord_type(coord_type)),
-            Some(self.polygon_xyz.into_coord_type(coord_type)),
-            Some(self.mpoint_xyz.into_coord_type(coord_type)),
-            Some(self.mline_string_xyz.into_coord_type(coord_type)),
-            Some(self.mpolygon_xyz.into_coord_type(coord_type)),
-            Some(self.gc_xyz.into_coord_type(coord_type)),
+            self.points.map(|arr| arr.into_coord_type(coord_type)),
+            self.line_strings.map(|arr| arr.into_coord_type(coord_type)),
+            self.polygons.map(|arr| arr.into_coord_type(coord_type)),
+            self.mpoints.map(|arr| arr.into_coord_type(coord_type)),
+            self.mline_strings
+                .map(|arr| arr.into_coord_type(coord_type)),
+            self.mpolygons.map(|arr| arr.into_coord_type(coord_type)),
+            self.gcs.map(|arr| arr.into_coord_type(coord_type)),
             self.metadata,
         )
     }
@@ -532,55 +328,36 @@ impl GeometryArray {
     #[allow(dead_code)]
     pub(crate) fn contained_types(&self) -> HashSet<NativeType> {
         let mut types = HashSet::new();
-        if self.has_points(Dimension::XY) {
-            types.insert(self.point_xy.data_type());
-        }
-        if self.has_line_strings(Dimension::XY) {
-            types.insert(self.line_string_xy.data_type());
-        }
-        if self.has_polygons(Dimension::XY) {
-            types.insert(self.polygon_xy.data_type());
-        }
-        if self.has_multi_points(Dimension::XY) {
-            types.insert(self.mpoint_xy.data_type());
-        }
-        if self.has_multi_line_strings(Dimension::XY) {
-            types.insert(self.mline_string_xy.data_type());
-        }
-        if self.has_multi_polygons(Dimension::XY) {
-            types.insert(self.mpolygon_xy.data_type());
-        }
-        if self.has_geometry_collections(Dimension::XY) {
-            types.insert(self.gc_xy.data_type());
-        }
-
-        if self.has_points(Dimension::XYZ) {
-            types.insert(self.point_xyz.data_type());
-        }
-        if self.has_line_strings(Dimension::XYZ) {
-            types.insert(self.line_string_xyz.data_type());
-        }
-        if self.has_polygons(Dimension::XYZ) {
-            types.insert(self.polygon_xyz.data_type());
-        }
-        if self.has_multi_points(Dimension::XYZ) {
-            types.insert(self.mpoint_xyz.data_type());
-        }
-        if self.has_multi_line_strings(Dimension::XYZ) {
-            types.insert(self.mline_string_xyz.data_type());
-        }
-        if self.has_multi_polygons(Dimension::XYZ) {
-            types.insert(self.mpolygon_xyz.data_type());
-        }
-        if self.has_geometry_collections(Dimension::XYZ) {
-            types.insert(self.gc_xyz.data_type());
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            if self.has_points(dim) {
+                types.insert(self.points[i].data_type());
+            }
+            if self.has_line_strings(dim) {
+                types.insert(self.line_strings[i].data_type());
+            }
+            if self.has_polygons(dim) {
+                types.insert(self.polygons[i].data_type());
+            }
+            if self.has_multi_points(dim) {
+                types.insert(self.mpoints[i].data_type());
+            }
+            if self.has_multi_line_strings(dim) {
+                types.insert(self.mline_strings[i].data_type());
+            }
+            if self.has_multi_polygons(dim) {
+                types.insert(self.mpolygons[i].data_type());
+            }
+            if self.has_geometry_collections(dim) {
+                types.insert(self.gcs[i].data_type());
+            }
         }
 
         types
     }
 }
 
-impl ArrayBase for GeometryArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericGeometryArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -626,7 +403,7 @@ impl ArrayBase for GeometryArray {
     }
 }
 
-impl NativeArray for GeometryArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericGeometryArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -652,9 +429,50 @@ impl NativeArray for GeometryArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        for dim in 0..4 {
+            self.points[dim].validate()?;
+            self.line_strings[dim].validate()?;
+            self.polygons[dim].validate()?;
+            self.mpoints[dim].validate()?;
+            self.mline_strings[dim].validate()?;
+            self.mpolygons[dim].validate()?;
+            self.gcs[dim].validate()?;
+        }
+
+        // Note: type ids are the geometry type id plus 10 times the dimension order
+        validate_union("Geometry", &self.type_ids, &self.offsets, |type_id| {
+            let dim = usize::try_from(type_id / 10).ok().filter(|dim| *dim < 4)?;
+            match type_id % 10 {
+                1 => Some(self.points[dim].len()),
+                2 => Some(self.line_strings[dim].len()),
+                3 => Some(self.polygons[dim].len()),
+                4 => Some(self.mpoints[dim].len()),
+                5 => Some(self.mline_strings[dim].len()),
+                6 => Some(self.mpolygons[dim].len()),
+                7 => Some(self.gcs[dim].len()),
+                _ => None,
+            }
+        })
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        for dim in 0..4 {
+            self.points[dim].validate_full()?;
+            self.line_strings[dim].validate_full()?;
+            self.polygons[dim].validate_full()?;
+            self.mpoints[dim].validate_full()?;
+            self.mline_strings[dim].validate_full()?;
+            self.mpolygons[dim].validate_full()?;
+            self.gcs[dim].validate_full()?;
+        }
+        Ok(())
+    }
 }
 
-impl GeometryArraySelfMethods for GeometryArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericGeometryArray<O> {
     fn with_coords(self, _coords: crate::array::CoordBuffer) -> Self {
         todo!();
     }
@@ -669,66 +487,60 @@ impl NativeGeometryAccessor for GeometryArray {
         let type_id = self.type_ids[index];
         let offset = self.offsets[index] as usize;
 
-        match type_id {
-            1 => Geometry::Point(self.point_xy.value(offset)),
-            2 => Geometry::LineString(self.line_string_xy.value(offset)),
-            3 => Geometry::Polygon(self.polygon_xy.value(offset)),
-            4 => Geometry::MultiPoint(self.mpoint_xy.value(offset)),
-            5 => Geometry::MultiLineString(self.mline_string_xy.value(offset)),
-            6 => Geometry::MultiPolygon(self.mpolygon_xy.value(offset)),
-            7 => Geometry::GeometryCollection(self.gc_xy.value(offset)),
-            11 => Geometry::Point(self.point_xyz.value(offset)),
-            12 => Geometry::LineString(self.line_string_xyz.value(offset)),
-            13 => Geometry::Polygon(self.polygon_xyz.value(offset)),
-            14 => Geometry::MultiPoint(self.mpoint_xyz.value(offset)),
-            15 => Geometry::MultiLineString(self.mline_string_xyz.value(offset)),
-            16 => Geometry::MultiPolygon(self.mpolygon_xyz.value(offset)),
-            17 => Geometry::GeometryCollection(self.gc_xyz.value(offset)),
+        // Note: type ids are the geometry type id plus 10 times the dimension order
+        let dim = (type_id / 10) as usize;
+
+        match type_id % 10 {
+            1 => Geometry::Point(self.points[dim].value(offset)),
+            2 => Geometry::LineString(self.line_strings[dim].value(offset)),
+            3 => Geometry::Polygon(self.polygons[dim].value(offset)),
+            4 => Geometry::MultiPoint(self.mpoints[dim].value(offset)),
+            5 => Geometry::MultiLineString(self.mline_strings[dim].value(offset)),
+            6 => Geometry::MultiPolygon(self.mpolygons[dim].value(offset)),
+            7 => Geometry::GeometryCollection(self.gcs[dim].value(offset)),
             _ => panic!("unknown type_id {}", type_id),
         }
     }
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for GeometryArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericGeometryArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
     ) -> std::result::Result<geos::Geometry, geos::Error> {
-        let geom = NativeGeometryAccessor::value_as_geometry_unchecked(self, index);
+        let geom = ArrayAccessor::value_unchecked(self, index);
         (&geom).try_into()
     }
 }
 
-impl<'a> ArrayAccessor<'a> for GeometryArray {
-    type Item = Geometry<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericGeometryArray<O> {
+    type Item = Geometry<'a, O>;
     type ItemGeo = geo::Geometry;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
         let type_id = self.type_ids[index];
         let offset = self.offsets[index] as usize;
 
-        match type_id {
-            1 => Geometry::Point(self.point_xy.value(offset)),
-            2 => Geometry::LineString(self.line_string_xy.value(offset)),
-            3 => Geometry::Polygon(self.polygon_xy.value(offset)),
-            4 => Geometry::MultiPoint(self.mpoint_xy.value(offset)),
-            5 => Geometry::MultiLineString(self.mline_string_xy.value(offset)),
-            6 => Geometry::MultiPolygon(self.mpolygon_xy.value(offset)),
-            7 => Geometry::GeometryCollection(self.gc_xy.value(offset)),
-            11 => Geometry::Point(self.point_xyz.value(offset)),
-            12 => Geometry::LineString(self.line_string_xyz.value(offset)),
-            13 => Geometry::Polygon(self.polygon_xyz.value(offset)),
-            14 => Geometry::MultiPoint(self.mpoint_xyz.value(offset)),
-            15 => Geometry::MultiLineString(self.mline_string_xyz.value(offset)),
-            16 => Geometry::MultiPolygon(self.mpolygon_xyz.value(offset)),
-            17 => Geometry::GeometryCollection(self.gc_xyz.value(offset)),
+        // Note: type ids are the geometry type id plus 10 times the dimension order
+        let dim = (type_id / 10) as usize;
+
+        match type_id % 10 {
+            1 => Geometry::Point(self.points[dim].value(offset)),
+            2 => Geometry::LineString(self.line_strings[dim].value(offset)),
+            3 => Geometry::Polygon(self.polygons[dim].value(offset)),
+            4 => Geometry::MultiPoint(self.mpoints[dim].value(offset)),
+            5 => Geometry::MultiLineString(self.mline_strings[dim].value(offset)),
+            6 => Geometry::MultiPolygon(self.mpolygons[dim].value(offset)),
+            7 => Geometry::GeometryCollection(self.gcs[dim].value(offset)),
             _ => panic!("unknown type_id {}", type_id),
         }
     }
 }
 
-impl IntoArrow for GeometryArray {
+impl<O: OffsetSizeTrait> IntoArrow for GenericGeometryArray<O> {
     type ArrowArray = UnionArray;
 
     fn into_arrow(self) -> Self::ArrowArray {
@@ -737,22 +549,17 @@ impl IntoArrow for GeometryArray {
             _ => unreachable!(),
         };
 
-        let child_arrays = vec![
-            self.point_xy.into_array_ref(),
-            self.line_string_xy.into_array_ref(),
-            self.polygon_xy.into_array_ref(),
-            self.mpoint_xy.into_array_ref(),
-            self.mline_string_xy.into_array_ref(),
-            self.mpolygon_xy.into_array_ref(),
-            self.gc_xy.into_array_ref(),
-            self.point_xyz.into_array_ref(),
-            self.line_string_xyz.into_array_ref(),
-            self.polygon_xyz.into_array_ref(),
-            self.mpoint_xyz.into_array_ref(),
-            self.mline_string_xyz.into_array_ref(),
-            self.mpolygon_xyz.into_array_ref(),
-            self.gc_xyz.into_array_ref(),
-        ];
+        let mut child_arrays = vec![];
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            child_arrays.push(self.points[i].to_array_ref());
+            child_arrays.push(self.line_strings[i].to_array_ref());
+            child_arrays.push(self.polygons[i].to_array_ref());
+            child_arrays.push(self.mpoints[i].to_array_ref());
+            child_arrays.push(self.mline_strings[i].to_array_ref());
+            child_arrays.push(self.mpolygons[i].to_array_ref());
+            child_arrays.push(self.gcs[i].to_array_ref());
+        }
 
         UnionArray::try_new(
             union_fields,
@@ -764,25 +571,19 @@ impl IntoArrow for GeometryArray {
     }
 }
 
-impl TryFrom<&UnionArray> for GeometryArray {
+impl<O: OffsetSizeTrait> TryFrom<&UnionArray> for GenericGeometryArray<O> {
     type Error = GeoArrowError;
 
     fn try_from(value: &UnionArray) -> std::result::Result<Self, Self::Error> {
-        let mut point_xy: Option<PointArray> = None;
-        let mut line_string_xy: Option<LineStringArray> = None;
-        let mut polygon_xy: Option<PolygonArray> = None;
-        let mut mpoint_xy: Option<MultiPointArray> = None;
-        let mut mline_string_xy: Option<MultiLineStringArray> = None;
-        let mut mpolygon_xy: Option<MultiPolygonArray> = None;
-        let mut gc_xy: Option<GeometryCollectionArray> = None;
-
-        let mut point_xyz: Option<PointArray> = None;
-        let mut line_string_xyz: Option<LineStringArray> = None;
-        let mut polygon_xyz: Option<PolygonArray> = None;
-        let mut mpoint_xyz: Option<MultiPointArray> = None;
-        let mut mline_string_xyz: Option<MultiLineStringArray> = None;
-        let mut mpolygon_xyz: Option<MultiPolygonArray> = None;
-        let mut gc_xyz: Option<GeometryCollectionArray> = None;
+        let mut points: [Option<PointArray>; 4] = Default::default();
+        let mut line_strings: [Option<GenericLineStringArray<O>>; 4] = Default::default();
+        let mut polygons: [Option<GenericPolygonArray<O>>; 4] = Default::default();
+        let mut mpoints: [Option<GenericMultiPointArray<O>>; 4] = Default::default();
+        let mut mline_strings: [Option<GenericMultiLineStringArray<O>>; 4] = Default::default();
+        let mut mpolygons: [Option<GenericMultiPolygonArray<O>>; 4] = Default::default();
+        let mut gcs: [Option<GenericGeometryCollectionArray<O>>; 4] = Default::default();
+
+        let mut coord_types = HashSet::new();
 
         match value.data_type() {
             DataType::Union(fields, mode) => {
@@ -791,115 +592,45 @@ impl TryFrom<&UnionArray> for GeometryArray {
                 }
 
                 for (type_id, _field) in fields.iter() {
-                    let dimension = if type_id < 10 {
-                        Dimension::XY
-                    } else if type_id < 20 {
-                        Dimension::XYZ
-                    } else {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported type_id: {}",
-                            type_id
-                        )));
-                    };
-
-                    match type_id {
+                    let dim = Dimension::from_order((type_id / 10) as usize)?;
+                    let i = dim.order();
+                    let child = value.child(type_id).as_ref();
+
+                    match type_id % 10 {
                         1 => {
-                            point_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = PointArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            points[i] = Some(arr);
                         }
                         2 => {
-                            line_string_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = GenericLineStringArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            line_strings[i] = Some(arr);
                         }
                         3 => {
-                            polygon_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = GenericPolygonArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            polygons[i] = Some(arr);
                         }
                         4 => {
-                            mpoint_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = GenericMultiPointArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            mpoints[i] = Some(arr);
                         }
                         5 => {
-                            mline_string_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = GenericMultiLineStringArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            mline_strings[i] = Some(arr);
                         }
                         6 => {
-                            mpolygon_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = GenericMultiPolygonArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            mpolygons[i] = Some(arr);
                         }
                         7 => {
-                            gc_xy = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        11 => {
-                            point_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        12 => {
-                            line_string_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        13 => {
-                            polygon_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        14 => {
-                            mpoint_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        15 => {
-                            mline_string_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        16 => {
-                            mpolygon_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
-                        }
-                        17 => {
-                            gc_xyz = Some(
-                                (value.child(type_id).as_ref(), dimension)
-                                    .try_into()
-                                    .unwrap(),
-                            );
+                            let arr = GenericGeometryCollectionArray::try_from((child, dim))?;
+                            coord_types.insert(arr.coord_type());
+                            gcs[i] = Some(arr);
                         }
                         _ => {
                             return Err(GeoArrowError::General(format!(
@@ -913,33 +644,44 @@ impl TryFrom<&UnionArray> for GeometryArray {
             _ => panic!("expected union type"),
         };
 
+        if coord_types.len() > 1 {
+            return Err(GeoArrowError::General(
+                "Multi coord types in union".to_string(),
+            ));
+        }
+        let coord_type = coord_types.into_iter().next().unwrap_or_default();
+
         let type_ids = value.type_ids().clone();
         // This is after checking for dense union
         let offsets = value.offsets().unwrap().clone();
 
+        // Fill in any missing children with empty arrays
+        let empty = Self::new_empty(coord_type, Default::default());
         Ok(Self::new(
             type_ids,
             offsets,
-            point_xy,
-            line_string_xy,
-            polygon_xy,
-            mpoint_xy,
-            mline_string_xy,
-            mpolygon_xy,
-            gc_xy,
-            point_xyz,
-            line_string_xyz,
-            polygon_xyz,
-            mpoint_xyz,
-            mline_string_xyz,
-            mpolygon_xyz,
-            gc_xyz,
+            fill_missing(points, empty.points),
+            fill_missing(line_strings, empty.line_strings),
+            fill_missing(polygons, empty.polygons),
+            fill_missing(mpoints, empty.mpoints),
+            fill_missing(mline_strings, empty.mline_strings),
+            fill_missing(mpolygons, empty.mpolygons),
+            fill_missing(gcs, empty.gcs),
             Default::default(),
         ))
     }
 }
 
-impl TryFrom<&dyn Array> for GeometryArray {
+/// Replace any `None` children with the corresponding empty child.
+fn fill_missing<T>(children: [Option<T>; 4], empty: [T; 4]) -> [T; 4] {
+    let mut filled = children
+        .into_iter()
+        .zip(empty)
+        .map(|(child, empty)| child.unwrap_or(empty));
+    core::array::from_fn(|_| filled.next().unwrap())
+}
+
+impl<O: OffsetSizeTrait> TryFrom<&dyn Array> for GenericGeometryArray<O> {
     type Error = GeoArrowError;
 
     fn try_from(value: &dyn Array) -> Result<Self> {
@@ -956,7 +698,7 @@ impl TryFrom<&dyn Array> for GeometryArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for GeometryArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericGeometryArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -993,503 +735,153 @@ impl<O: OffsetSizeTrait> TryFrom<WKBArray<O>> for GeometryArray {
     }
 }
 
-impl From<PointArray> for GeometryArray {
-    fn from(value: PointArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![1; value.len()],
-            Dimension::XYZ => vec![11; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
+macro_rules! impl_from_child {
+    ($array:ty, $field:ident, $geom_type:expr) => {
+        impl<O: OffsetSizeTrait> From<$array> for GenericGeometryArray<O> {
+            fn from(value: $array) -> Self {
+                let dim = value.dimension();
+                let type_id = $geom_type + 10 * dim.order() as i8;
+                let mut arr = Self::new_empty(value.coord_type(), value.metadata());
+                arr.type_ids = vec![type_id; value.len()].into();
+                arr.offsets = ScalarBuffer::from_iter(0..value.len() as i32);
+                arr.$field[dim.order()] = value;
+                arr
+            }
         }
-    }
+    };
 }
 
-impl From<LineStringArray> for GeometryArray {
-    fn from(value: LineStringArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![2; value.len()],
-            Dimension::XYZ => vec![12; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-        }
-    }
-}
+impl_from_child!(PointArray, points, 1);
+impl_from_child!(GenericLineStringArray<O>, line_strings, 2);
+impl_from_child!(GenericPolygonArray<O>, polygons, 3);
+impl_from_child!(GenericMultiPointArray<O>, mpoints, 4);
+impl_from_child!(GenericMultiLineStringArray<O>, mline_strings, 5);
+impl_from_child!(GenericMultiPolygonArray<O>, mpolygons, 6);
+impl_from_child!(GenericGeometryCollectionArray<O>, gcs, 7);
 
-impl From<PolygonArray> for GeometryArray {
-    fn from(value: PolygonArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![3; value.len()],
-            Dimension::XYZ => vec![13; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-        }
+impl<O: OffsetSizeTrait> From<GenericMixedGeometryArray<O>> for GenericGeometryArray<O> {
+    fn from(value: GenericMixedGeometryArray<O>) -> Self {
+        // TODO: include slice offset
+        let i = value.dimension().order();
+        let mut arr = Self::new_empty(value.coord_type(), value.metadata.clone());
+        arr.type_ids = value.type_ids;
+        arr.offsets = value.offsets;
+        arr.points[i] = value.points;
+        arr.line_strings[i] = value.line_strings;
+        arr.polygons[i] = value.polygons;
+        arr.mpoints[i] = value.multi_points;
+        arr.mline_strings[i] = value.multi_line_strings;
+        arr.mpolygons[i] = value.multi_polygons;
+        arr
     }
 }
 
-impl From<MultiPointArray> for GeometryArray {
-    fn from(value: MultiPointArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![4; value.len()],
-            Dimension::XYZ => vec![14; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                metadata,
-            ),
-        }
-    }
-}
+impl<O: OffsetSizeTrait> TryFrom<GenericGeometryArray<O>> for GenericMixedGeometryArray<O> {
+    type Error = GeoArrowError;
 
-impl From<MultiLineStringArray> for GeometryArray {
-    fn from(value: MultiLineStringArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![5; value.len()],
-            Dimension::XYZ => vec![15; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                metadata,
-            ),
+    /// Will error if:
+    ///
+    /// - the contained geometries are not all of the same dimension
+    /// - any geometry collection child exists
+    fn try_from(value: GenericGeometryArray<O>) -> std::result::Result<Self, Self::Error> {
+        let dim = Dimension::ALL
+            .into_iter()
+            .find(|dim| value.has_only_dimension(*dim))
+            .ok_or_else(|| {
+                GeoArrowError::General(
+                    "Cannot cast to MixedGeometryArray when GeometryArray contains multiple dimensions"
+                        .to_string(),
+                )
+            })?;
+
+        if value.has_geometry_collections(dim) {
+            return Err(GeoArrowError::General(
+                "Cannot cast to MixedGeometryArray with non-empty GeometryCollection child."
+                    .to_string(),
+            ));
         }
+
+        let i = dim.order();
+        Ok(GenericMixedGeometryArray::new(
+            value.type_ids,
+            value.offsets,
+            Some(value.points[i].clone()),
+            Some(value.line_strings[i].clone()),
+            Some(value.polygons[i].clone()),
+            Some(value.mpoints[i].clone()),
+            Some(value.mline_strings[i].clone()),
+            Some(value.mpolygons[i].clone()),
+            value.metadata,
+        ))
     }
 }
 
-impl From<MultiPolygonArray> for GeometryArray {
-    fn from(value: MultiPolygonArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![6; value.len()],
-            Dimension::XYZ => vec![16; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                metadata,
-            ),
-        }
+/// Default to an empty array
+impl Default for GeometryArray {
+    fn default() -> Self {
+        GeometryBuilder::default().into()
     }
 }
 
-impl From<GeometryCollectionArray> for GeometryArray {
-    fn from(value: GeometryCollectionArray) -> Self {
-        let dim = value.dimension();
-        let type_ids = match dim {
-            Dimension::XY => vec![7; value.len()],
-            Dimension::XYZ => vec![17; value.len()],
-        }
-        .into();
-        let offsets = ScalarBuffer::from_iter(0..value.len() as i32);
-        let metadata = value.metadata().clone();
-        match dim {
-            Dimension::XY => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                metadata,
-            ),
-            Dimension::XYZ => Self::new(
-                type_ids,
-                offsets,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                Some(value),
-                metadata,
-            ),
-        }
+/// Default to an empty array
+impl Default for LargeGeometryArray {
+    fn default() -> Self {
+        GeometryArray::default().into()
     }
 }
 
-impl From<MixedGeometryArray> for GeometryArray {
-    fn from(value: MixedGeometryArray) -> Self {
-        use Dimension::*;
-
-        // TODO: include slice offset
-        let mut point_xy: Option<PointArray> = None;
-        let mut line_string_xy: Option<LineStringArray> = None;
-        let mut polygon_xy: Option<PolygonArray> = None;
-        let mut mpoint_xy: Option<MultiPointArray> = None;
-        let mut mline_string_xy: Option<MultiLineStringArray> = None;
-        let mut mpolygon_xy: Option<MultiPolygonArray> = None;
-
-        let mut point_xyz: Option<PointArray> = None;
-        let mut line_string_xyz: Option<LineStringArray> = None;
-        let mut polygon_xyz: Option<PolygonArray> = None;
-        let mut mpoint_xyz: Option<MultiPointArray> = None;
-        let mut mline_string_xyz: Option<MultiLineStringArray> = None;
-        let mut mpolygon_xyz: Option<MultiPolygonArray> = None;
-
-        match value.dimension() {
-            XY => {
-                point_xy = Some(value.points);
-                line_string_xy = Some(value.line_strings);
-                polygon_xy = Some(value.polygons);
-                mpoint_xy = Some(value.multi_points);
-                mline_string_xy = Some(value.multi_line_strings);
-                mpolygon_xy = Some(value.multi_polygons);
-            }
-            XYZ => {
-                point_xyz = Some(value.points);
-                line_string_xyz = Some(value.line_strings);
-                polygon_xyz = Some(value.polygons);
-                mpoint_xyz = Some(value.multi_points);
-                mline_string_xyz = Some(value.multi_line_strings);
-                mpolygon_xyz = Some(value.multi_polygons);
-            }
-        }
-
+impl From<GeometryArray> for LargeGeometryArray {
+    fn from(value: GeometryArray) -> Self {
         Self::new(
             value.type_ids,
             value.offsets,
-            point_xy,
-            line_string_xy,
-            polygon_xy,
-            mpoint_xy,
-            mline_string_xy,
-            mpolygon_xy,
-            None,
-            point_xyz,
-            line_string_xyz,
-            polygon_xyz,
-            mpoint_xyz,
-            mline_string_xyz,
-            mpolygon_xyz,
-            None,
+            value.points,
+            value.line_strings.map(Into::into),
+            value.polygons.map(Into::into),
+            value.mpoints.map(Into::into),
+            value.mline_strings.map(Into::into),
+            value.mpolygons.map(Into::into),
+            value.gcs.map(Into::into),
             value.metadata,
         )
     }
 }
 
-impl TryFrom<GeometryArray> for MixedGeometryArray {
+impl TryFrom<LargeGeometryArray> for GeometryArray {
     type Error = GeoArrowError;
 
-    /// Will error if:
-    ///
-    /// - the contained geometries are not all of the same dimension
-    /// - any geometry collection child exists
-    fn try_from(value: GeometryArray) -> std::result::Result<Self, Self::Error> {
-        if value.has_only_dimension(Dimension::XY) {
-            if value.gc_xy.is_empty() {
-                Ok(MixedGeometryArray::new(
-                    value.type_ids,
-                    value.offsets,
-                    Some(value.point_xy),
-                    Some(value.line_string_xy),
-                    Some(value.polygon_xy),
-                    Some(value.mpoint_xy),
-                    Some(value.mline_string_xy),
-                    Some(value.mpolygon_xy),
-                    value.metadata,
-                ))
-            } else {
-                Err(GeoArrowError::General(
-                    "Cannot cast to MixedGeometryArray with non-empty GeometryCollection child."
-                        .to_string(),
-                ))
-            }
-        } else if value.has_only_dimension(Dimension::XYZ) {
-            if value.gc_xyz.is_empty() {
-                Ok(MixedGeometryArray::new(
-                    value.type_ids,
-                    value.offsets,
-                    Some(value.point_xyz),
-                    Some(value.line_string_xyz),
-                    Some(value.polygon_xyz),
-                    Some(value.mpoint_xyz),
-                    Some(value.mline_string_xyz),
-                    Some(value.mpolygon_xyz),
-                    value.metadata,
-                ))
-            } else {
-                Err(GeoArrowError::General(
-                    "Cannot cast to MixedGeometryArray with non-empty GeometryCollection child."
-                        .to_string(),
-                ))
-            }
-        } else {
-            Err(GeoArrowError::General(
-                "Cannot cast to MixedGeometryArray when GeometryArray contains multiple dimensions"
-                    .to_string(),
-            ))
-        }
+    fn try_from(value: LargeGeometryArray) -> Result<Self> {
+        Ok(Self::new(
+            value.type_ids,
+            value.offsets,
+            value.points,
+            try_narrow(value.line_strings)?,
+            try_narrow(value.polygons)?,
+            try_narrow(value.mpoints)?,
+            try_narrow(value.mline_strings)?,
+            try_narrow(value.mpolygons)?,
+            try_narrow(value.gcs)?,
+            value.metadata,
+        ))
     }
 }
 
-/// Default to an empty array
-impl Default for GeometryArray {
-    fn default() -> Self {
-        GeometryBuilder::default().into()
-    }
+/// Convert each of the four per-dimension children to `i32` offsets.
+fn try_narrow<T, U: TryFrom<T, Error = GeoArrowError>>(children: [T; 4]) -> Result<[U; 4]> {
+    let [xy, xyz, xym, xyzm] = children;
+    Ok([
+        xy.try_into()?,
+        xyz.try_into()?,
+        xym.try_into()?,
+        xyzm.try_into()?,
+    ])
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
+    use crate::trait_::GeometryArrayBuilder;
 
     #[test]
     fn geo_roundtrip_accurate_points() {
@@ -1513,6 +905,36 @@ mod test {
         assert_eq!(arr.value_as_geo(2), geo::Geometry::Point(point::p2()));
     }
 
+    #[test]
+    fn all_null_keeps_length() {
+        for prefer_multi in [true, false] {
+            let mut builder = GeometryBuilder::new_with_options(
+                Default::default(),
+                Default::default(),
+                prefer_multi,
+            );
+            builder.push_null();
+            builder.push_null();
+            let arr = builder.finish();
+            assert_eq!(arr.len(), 2);
+            if prefer_multi {
+                assert!(arr.has_multi_points(Dimension::XY));
+            } else {
+                assert!(arr.has_points(Dimension::XY));
+            }
+        }
+    }
+
+    #[test]
+    fn builder_reports_nulls() {
+        let mut builder = GeometryBuilder::new();
+        builder.push_null();
+        builder.push_point(Some(&point::p0())).unwrap();
+        builder.push_geometry(None::<&geo::Geometry>).unwrap();
+        assert_eq!(builder.nulls().len(), 3);
+        assert_eq!(builder.nulls().as_slice(), Some([0b010].as_slice()));
+    }
+
     #[test]
     fn geo_roundtrip_accurate_multi_points() {
         let geoms: Vec<geo::Geometry> = vec![
@@ -13,10 +13,17 @@ use crate::scalar::WKB;
 use crate::trait_::{ArrayAccessor, GeometryArrayBuilder, IntoArrow};
 use crate::{ArrayBase, NativeArray};
 use arrow_array::{OffsetSizeTrait, UnionArray};
+use arrow_buffer::NullBufferBuilder;
 use geo_traits::*;
 
 pub(crate) const DEFAULT_PREFER_MULTI: bool = false;
 
+/// The union type id for the given geometry type id and dimension.
+#[inline]
+fn type_id(geom_type: i8, dim: Dimension) -> i8 {
+    geom_type + 10 * dim.order() as i8
+}
+
 /// The GeoArrow equivalent to a `Vec<Option<Geometry>>`: a mutable collection of Geometries.
 ///
 /// Each Geometry can have a different dimension. All geometries must have the same coordinate
@@ -37,22 +44,15 @@ pub struct GeometryBuilder {
     // Invariant: every item in `types` is `> 0 && < fields.len()`
     types: Vec<i8>,
 
-    // In the future we'll additionally have xym, xyzm array variants.
-    point_xy: PointBuilder,
-    line_string_xy: LineStringBuilder,
-    polygon_xy: PolygonBuilder,
-    mpoint_xy: MultiPointBuilder,
-    mline_string_xy: MultiLineStringBuilder,
-    mpolygon_xy: MultiPolygonBuilder,
-    gc_xy: GeometryCollectionBuilder,
-
-    point_xyz: PointBuilder,
-    line_string_xyz: LineStringBuilder,
-    polygon_xyz: PolygonBuilder,
-    mpoint_xyz: MultiPointBuilder,
-    mline_string_xyz: MultiLineStringBuilder,
-    mpolygon_xyz: MultiPolygonBuilder,
-    gc_xyz: GeometryCollectionBuilder,
+    /// Child builders for each geometry type, indexed by dimension in the order XY, XYZ, XYM,
+    /// XYZM.
+    points: [PointBuilder; 4],
+    line_strings: [LineStringBuilder; 4],
+    polygons: [PolygonBuilder; 4],
+    mpoints: [MultiPointBuilder; 4],
+    mline_strings: [MultiLineStringBuilder; 4],
+    mpolygons: [MultiPolygonBuilder; 4],
+    gcs: [GeometryCollectionBuilder; 4],
 
     // Invariant: `offsets.len() == types.len()`
     offsets: Vec<i32>,
@@ -81,6 +81,12 @@ pub struct GeometryBuilder {
     /// push the null to. This `deferred_nulls` is the number of initial null values that haven't
     /// yet been written to an array, because we don't know which array to write them to.
     deferred_nulls: usize,
+
+    /// Which geometries are valid, in the order they were pushed.
+    ///
+    /// The union array has no validity of its own, so this is only used to report
+    /// [`GeometryArrayBuilder::nulls`].
+    validity: NullBufferBuilder,
 }
 
 impl<'a> GeometryBuilder {
@@ -115,101 +121,73 @@ impl<'a> GeometryBuilder {
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
     ) -> Self {
-        use Dimension::*;
+        let dim = |i: usize| Dimension::from_order(i).unwrap();
 
         // Don't store array metadata on child arrays
         Self {
             metadata,
             types: vec![],
-            point_xy: PointBuilder::with_capacity_and_options(
-                XY,
-                capacity.point_xy(),
-                coord_type,
-                Default::default(),
-            ),
-            line_string_xy: LineStringBuilder::with_capacity_and_options(
-                XY,
-                capacity.line_string_xy(),
-                coord_type,
-                Default::default(),
-            ),
-            polygon_xy: PolygonBuilder::with_capacity_and_options(
-                XY,
-                capacity.polygon_xy(),
-                coord_type,
-                Default::default(),
-            ),
-            mpoint_xy: MultiPointBuilder::with_capacity_and_options(
-                XY,
-                capacity.mpoint_xy(),
-                coord_type,
-                Default::default(),
-            ),
-            mline_string_xy: MultiLineStringBuilder::with_capacity_and_options(
-                XY,
-                capacity.mline_string_xy(),
-                coord_type,
-                Default::default(),
-            ),
-            mpolygon_xy: MultiPolygonBuilder::with_capacity_and_options(
-                XY,
-                capacity.mpolygon_xy(),
-                coord_type,
-                Default::default(),
-            ),
-            gc_xy: GeometryCollectionBuilder::with_capacity_and_options(
-                XY,
-                capacity.gc_xy(),
-                coord_type,
-                Default::default(),
-                prefer_multi,
-            ),
-            point_xyz: PointBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.point_xyz(),
-                coord_type,
-                Default::default(),
-            ),
-            line_string_xyz: LineStringBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.line_string_xyz(),
-                coord_type,
-                Default::default(),
-            ),
-            polygon_xyz: PolygonBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.polygon_xyz(),
-                coord_type,
-                Default::default(),
-            ),
-            mpoint_xyz: MultiPointBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.mpoint_xyz(),
-                coord_type,
-                Default::default(),
-            ),
-            mline_string_xyz: MultiLineStringBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.mline_string_xyz(),
-                coord_type,
-                Default::default(),
-            ),
-            mpolygon_xyz: MultiPolygonBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.mpolygon_xyz(),
-                coord_type,
-                Default::default(),
-            ),
-            gc_xyz: GeometryCollectionBuilder::with_capacity_and_options(
-                XYZ,
-                capacity.gc_xyz(),
-                coord_type,
-                Default::default(),
-                prefer_multi,
-            ),
+            points: core::array::from_fn(|i| {
+                PointBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.point(dim(i)),
+                    coord_type,
+                    Default::default(),
+                )
+            }),
+            line_strings: core::array::from_fn(|i| {
+                LineStringBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.line_string(dim(i)),
+                    coord_type,
+                    Default::default(),
+                )
+            }),
+            polygons: core::array::from_fn(|i| {
+                PolygonBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.polygon(dim(i)),
+                    coord_type,
+                    Default::default(),
+                )
+            }),
+            mpoints: core::array::from_fn(|i| {
+                MultiPointBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.multi_point(dim(i)),
+                    coord_type,
+                    Default::default(),
+                )
+            }),
+            mline_strings: core::array::from_fn(|i| {
+                MultiLineStringBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.multi_line_string(dim(i)),
+                    coord_type,
+                    Default::default(),
+                )
+            }),
+            mpolygons: core::array::from_fn(|i| {
+                MultiPolygonBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.multi_polygon(dim(i)),
+                    coord_type,
+                    Default::default(),
+                )
+            }),
+            gcs: core::array::from_fn(|i| {
+                GeometryCollectionBuilder::with_capacity_and_options(
+                    dim(i),
+                    capacity.geometry_collection(dim(i)),
+                    coord_type,
+                    Default::default(),
+                    prefer_multi,
+                )
+            }),
             offsets: vec![],
             prefer_multi,
             deferred_nulls: 0,
+            validity: NullBufferBuilder::new(capacity.total_num_geoms()),
         }
     }
 
@@ -223,21 +201,16 @@ impl<'a> GeometryBuilder {
         self.types.reserve(total_num_geoms);
         self.offsets.reserve(total_num_geoms);
 
-        self.point_xy.reserve(capacity.point_xy());
-        self.line_string_xy.reserve(capacity.line_string_xy());
-        self.polygon_xy.reserve(capacity.polygon_xy());
-        self.mpoint_xy.reserve(capacity.mpoint_xy());
-        self.mline_string_xy.reserve(capacity.mline_string_xy());
-        self.mpolygon_xy.reserve(capacity.mpolygon_xy());
-        self.gc_xy.reserve(capacity.gc_xy());
-
-        self.point_xyz.reserve(capacity.point_xyz());
-        self.line_string_xyz.reserve(capacity.line_string_xyz());
-        self.polygon_xyz.reserve(capacity.polygon_xyz());
-        self.mpoint_xyz.reserve(capacity.mpoint_xyz());
-        self.mline_string_xyz.reserve(capacity.mline_string_xyz());
-        self.mpolygon_xyz.reserve(capacity.mpolygon_xyz());
-        self.gc_xyz.reserve(capacity.gc_xyz());
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            self.points[i].reserve(capacity.point(dim));
+            self.line_strings[i].reserve(capacity.line_string(dim));
+            self.polygons[i].reserve(capacity.polygon(dim));
+            self.mpoints[i].reserve(capacity.multi_point(dim));
+            self.mline_strings[i].reserve(capacity.multi_line_string(dim));
+            self.mpolygons[i].reserve(capacity.multi_polygon(dim));
+            self.gcs[i].reserve(capacity.geometry_collection(dim));
+        }
     }
 
     /// Reserves the minimum capacity for at least `additional` more Geometries.
@@ -257,24 +230,16 @@ impl<'a> GeometryBuilder {
         self.types.reserve_exact(total_num_geoms);
         self.offsets.reserve_exact(total_num_geoms);
 
-        self.point_xy.reserve_exact(capacity.point_xy());
-        self.line_string_xy.reserve_exact(capacity.line_string_xy());
-        self.polygon_xy.reserve_exact(capacity.polygon_xy());
-        self.mpoint_xy.reserve_exact(capacity.mpoint_xy());
-        self.mline_string_xy
-            .reserve_exact(capacity.mline_string_xy());
-        self.mpolygon_xy.reserve_exact(capacity.mpolygon_xy());
-        self.gc_xy.reserve_exact(capacity.gc_xy());
-
-        self.point_xyz.reserve_exact(capacity.point_xyz());
-        self.line_string_xyz
-            .reserve_exact(capacity.line_string_xyz());
-        self.polygon_xyz.reserve_exact(capacity.polygon_xyz());
-        self.mpoint_xyz.reserve_exact(capacity.mpoint_xyz());
-        self.mline_string_xyz
-            .reserve_exact(capacity.mline_string_xyz());
-        self.mpolygon_xyz.reserve_exact(capacity.mpolygon_xyz());
-        self.gc_xyz.reserve_exact(capacity.gc_xyz());
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            self.points[i].reserve_exact(capacity.point(dim));
+            self.line_strings[i].reserve_exact(capacity.line_string(dim));
+            self.polygons[i].reserve_exact(capacity.polygon(dim));
+            self.mpoints[i].reserve_exact(capacity.multi_point(dim));
+            self.mline_strings[i].reserve_exact(capacity.multi_line_string(dim));
+            self.mpolygons[i].reserve_exact(capacity.multi_polygon(dim));
+            self.gcs[i].reserve_exact(capacity.geometry_collection(dim));
+        }
     }
 
     // /// The canonical method to create a [`MixedGeometryBuilder`] out of its internal
@@ -371,53 +336,32 @@ impl<'a> GeometryBuilder {
     #[inline]
     pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) -> Result<()> {
         if let Some(point) = value {
+            let dim: Dimension = point.dim().try_into()?;
+            let i = dim.order();
             if self.prefer_multi {
-                self.add_multi_point_type(point.dim().try_into().unwrap());
-                match point.dim() {
-                    Dimensions::Xy | Dimensions::Unknown(2) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.mpoint_xy.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.mpoint_xy.push_point(Some(point))?;
-                    }
-                    Dimensions::Xyz | Dimensions::Unknown(3) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.mpoint_xyz.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.mpoint_xyz.push_point(Some(point))?;
-                    }
-                    dim => {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported dimension {dim:?}"
-                        )))
-                    }
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_multi_point_type(dim);
+                    self.mpoints[i].push_null();
                 }
-            } else {
-                self.add_point_type(point.dim().try_into().unwrap());
-                match point.dim() {
-                    Dimensions::Xy | Dimensions::Unknown(2) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.point_xy.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.point_xy.push_point(Some(point));
-                    }
-                    Dimensions::Xyz | Dimensions::Unknown(3) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.point_xyz.push_null());
-                        self.deferred_nulls = 0;
+                self.deferred_nulls = 0;
 
-                        self.point_xyz.push_point(Some(point));
-                    }
-                    dim => {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported dimension {dim:?}"
-                        )))
-                    }
+                self.add_multi_point_type(dim);
+
+                self.mpoints[i].push_point(Some(point))?;
+            } else {
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_point_type(dim);
+                    self.points[i].push_null();
                 }
+                self.deferred_nulls = 0;
+
+                self.add_point_type(dim);
+
+                self.points[i].push_point(Some(point));
             }
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -427,16 +371,9 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_point_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets.push(self.point_xy.len().try_into().unwrap());
-                self.types.push(1)
-            }
-            Dimension::XYZ => {
-                self.offsets.push(self.point_xyz.len().try_into().unwrap());
-                self.types.push(11)
-            }
-        }
+        let i = dim.order();
+        self.offsets.push(self.points[i].len().try_into().unwrap());
+        self.types.push(type_id(1, dim))
     }
 
     /// Add a new LineString to the end of this array.
@@ -453,53 +390,32 @@ impl<'a> GeometryBuilder {
         value: Option<&impl LineStringTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(line_string) = value {
+            let dim: Dimension = line_string.dim().try_into()?;
+            let i = dim.order();
             if self.prefer_multi {
-                self.add_multi_line_string_type(line_string.dim().try_into().unwrap());
-                match line_string.dim() {
-                    Dimensions::Xy | Dimensions::Unknown(2) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.mline_string_xy.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.mline_string_xy.push_line_string(Some(line_string))?;
-                    }
-                    Dimensions::Xyz | Dimensions::Unknown(3) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.mline_string_xyz.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.mline_string_xyz.push_line_string(Some(line_string))?;
-                    }
-                    dim => {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported dimension {dim:?}"
-                        )))
-                    }
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_multi_line_string_type(dim);
+                    self.mline_strings[i].push_null();
                 }
-            } else {
-                self.add_line_string_type(line_string.dim().try_into().unwrap());
-                match line_string.dim() {
-                    Dimensions::Xy | Dimensions::Unknown(2) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.line_string_xy.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.line_string_xy.push_line_string(Some(line_string))?;
-                    }
-                    Dimensions::Xyz | Dimensions::Unknown(3) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.line_string_xyz.push_null());
-                        self.deferred_nulls = 0;
+                self.deferred_nulls = 0;
 
-                        self.line_string_xyz.push_line_string(Some(line_string))?;
-                    }
-                    dim => {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported dimension {dim:?}"
-                        )))
-                    }
+                self.add_multi_line_string_type(dim);
+
+                self.mline_strings[i].push_line_string(Some(line_string))?;
+            } else {
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_line_string_type(dim);
+                    self.line_strings[i].push_null();
                 }
+                self.deferred_nulls = 0;
+
+                self.add_line_string_type(dim);
+
+                self.line_strings[i].push_line_string(Some(line_string))?;
             }
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -509,18 +425,10 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_line_string_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets
-                    .push(self.line_string_xy.len().try_into().unwrap());
-                self.types.push(2)
-            }
-            Dimension::XYZ => {
-                self.offsets
-                    .push(self.line_string_xyz.len().try_into().unwrap());
-                self.types.push(12)
-            }
-        }
+        let i = dim.order();
+        self.offsets
+            .push(self.line_strings[i].len().try_into().unwrap());
+        self.types.push(type_id(2, dim))
     }
 
     /// Add a new Polygon to the end of this array.
@@ -534,53 +442,73 @@ impl<'a> GeometryBuilder {
     #[inline]
     pub fn push_polygon(&mut self, value: Option<&impl PolygonTrait<T = f64>>) -> Result<()> {
         if let Some(polygon) = value {
+            let dim: Dimension = polygon.dim().try_into()?;
+            let i = dim.order();
             if self.prefer_multi {
-                self.add_multi_polygon_type(polygon.dim().try_into().unwrap());
-                match polygon.dim() {
-                    Dimensions::Xy | Dimensions::Unknown(2) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.mpolygon_xy.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.mpolygon_xy.push_polygon(Some(polygon))?;
-                    }
-                    Dimensions::Xyz | Dimensions::Unknown(3) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.mpolygon_xyz.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.mpolygon_xyz.push_polygon(Some(polygon))?;
-                    }
-                    dim => {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported dimension {dim:?}"
-                        )))
-                    }
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_multi_polygon_type(dim);
+                    self.mpolygons[i].push_null();
                 }
+                self.deferred_nulls = 0;
+
+                self.add_multi_polygon_type(dim);
+
+                self.mpolygons[i].push_polygon(Some(polygon))?;
             } else {
-                self.add_polygon_type(polygon.dim().try_into().unwrap());
-                match polygon.dim() {
-                    Dimensions::Xy | Dimensions::Unknown(2) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.polygon_xy.push_null());
-                        self.deferred_nulls = 0;
-
-                        self.polygon_xy.push_polygon(Some(polygon))?;
-                    }
-                    Dimensions::Xyz | Dimensions::Unknown(3) => {
-                        // Flush deferred nulls
-                        (0..self.deferred_nulls).for_each(|_| self.polygon_xyz.push_null());
-                        self.deferred_nulls = 0;
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_polygon_type(dim);
+                    self.polygons[i].push_null();
+                }
+                self.deferred_nulls = 0;
 
-                        self.polygon_xyz.push_polygon(Some(polygon))?;
-                    }
-                    dim => {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported dimension {dim:?}"
-                        )))
-                    }
+                self.add_polygon_type(dim);
+
+                self.polygons[i].push_polygon(Some(polygon))?;
+            }
+            self.validity.append_non_null();
+        } else {
+            self.push_null();
+        };
+
+        Ok(())
+    }
+
+    /// Add a new Rect to the end of this array, as a Polygon.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    #[inline]
+    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = f64>>) -> Result<()> {
+        if let Some(rect) = value {
+            let dim: Dimension = rect.dim().try_into()?;
+            let i = dim.order();
+            if self.prefer_multi {
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_multi_polygon_type(dim);
+                    self.mpolygons[i].push_null();
+                }
+                self.deferred_nulls = 0;
+
+                self.add_multi_polygon_type(dim);
+
+                self.mpolygons[i].push_rect(Some(rect))?;
+            } else {
+                // Flush deferred nulls
+                for _ in 0..self.deferred_nulls {
+                    self.add_polygon_type(dim);
+                    self.polygons[i].push_null();
                 }
+                self.deferred_nulls = 0;
+
+                self.add_polygon_type(dim);
+
+                self.polygons[i].push_rect(Some(rect))?;
             }
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -590,17 +518,10 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_polygon_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets.push(self.polygon_xy.len().try_into().unwrap());
-                self.types.push(3)
-            }
-            Dimension::XYZ => {
-                self.offsets
-                    .push(self.polygon_xyz.len().try_into().unwrap());
-                self.types.push(13)
-            }
-        }
+        let i = dim.order();
+        self.offsets
+            .push(self.polygons[i].len().try_into().unwrap());
+        self.types.push(type_id(3, dim))
     }
 
     /// Add a new MultiPoint to the end of this array.
@@ -614,28 +535,19 @@ impl<'a> GeometryBuilder {
         value: Option<&impl MultiPointTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(multi_point) = value {
-            self.add_multi_point_type(multi_point.dim().try_into().unwrap());
-            match multi_point.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.mpoint_xy.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.mpoint_xy.push_multi_point(Some(multi_point))?;
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.mpoint_xyz.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.mpoint_xyz.push_multi_point(Some(multi_point))?;
-                }
-                dim => {
-                    return Err(GeoArrowError::General(format!(
-                        "Unsupported dimension {dim:?}"
-                    )))
-                }
+            let dim: Dimension = multi_point.dim().try_into()?;
+            let i = dim.order();
+            // Flush deferred nulls
+            for _ in 0..self.deferred_nulls {
+                self.add_multi_point_type(dim);
+                self.mpoints[i].push_null();
             }
+            self.deferred_nulls = 0;
+
+            self.add_multi_point_type(dim);
+
+            self.mpoints[i].push_multi_point(Some(multi_point))?;
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -645,16 +557,9 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_multi_point_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets.push(self.mpoint_xy.len().try_into().unwrap());
-                self.types.push(4)
-            }
-            Dimension::XYZ => {
-                self.offsets.push(self.mpoint_xyz.len().try_into().unwrap());
-                self.types.push(14)
-            }
-        }
+        let i = dim.order();
+        self.offsets.push(self.mpoints[i].len().try_into().unwrap());
+        self.types.push(type_id(4, dim))
     }
 
     /// Add a new MultiLineString to the end of this array.
@@ -668,30 +573,19 @@ impl<'a> GeometryBuilder {
         value: Option<&impl MultiLineStringTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(multi_line_string) = value {
-            self.add_multi_line_string_type(multi_line_string.dim().try_into().unwrap());
-            match multi_line_string.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.mline_string_xy.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.mline_string_xy
-                        .push_multi_line_string(Some(multi_line_string))?;
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.mline_string_xyz.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.mline_string_xyz
-                        .push_multi_line_string(Some(multi_line_string))?;
-                }
-                dim => {
-                    return Err(GeoArrowError::General(format!(
-                        "Unsupported dimension {dim:?}"
-                    )))
-                }
+            let dim: Dimension = multi_line_string.dim().try_into()?;
+            let i = dim.order();
+            // Flush deferred nulls
+            for _ in 0..self.deferred_nulls {
+                self.add_multi_line_string_type(dim);
+                self.mline_strings[i].push_null();
             }
+            self.deferred_nulls = 0;
+
+            self.add_multi_line_string_type(dim);
+
+            self.mline_strings[i].push_multi_line_string(Some(multi_line_string))?;
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -701,18 +595,10 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_multi_line_string_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets
-                    .push(self.mline_string_xy.len().try_into().unwrap());
-                self.types.push(5)
-            }
-            Dimension::XYZ => {
-                self.offsets
-                    .push(self.mline_string_xyz.len().try_into().unwrap());
-                self.types.push(15)
-            }
-        }
+        let i = dim.order();
+        self.offsets
+            .push(self.mline_strings[i].len().try_into().unwrap());
+        self.types.push(type_id(5, dim))
     }
 
     /// Add a new MultiPolygon to the end of this array.
@@ -726,28 +612,19 @@ impl<'a> GeometryBuilder {
         value: Option<&impl MultiPolygonTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(multi_polygon) = value {
-            self.add_multi_polygon_type(multi_polygon.dim().try_into().unwrap());
-            match multi_polygon.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.mpolygon_xy.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.mpolygon_xy.push_multi_polygon(Some(multi_polygon))?;
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.mpolygon_xyz.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.mpolygon_xyz.push_multi_polygon(Some(multi_polygon))?;
-                }
-                dim => {
-                    return Err(GeoArrowError::General(format!(
-                        "Unsupported dimension {dim:?}"
-                    )))
-                }
+            let dim: Dimension = multi_polygon.dim().try_into()?;
+            let i = dim.order();
+            // Flush deferred nulls
+            for _ in 0..self.deferred_nulls {
+                self.add_multi_polygon_type(dim);
+                self.mpolygons[i].push_null();
             }
+            self.deferred_nulls = 0;
+
+            self.add_multi_polygon_type(dim);
+
+            self.mpolygons[i].push_multi_polygon(Some(multi_polygon))?;
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -757,18 +634,10 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_multi_polygon_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets
-                    .push(self.mpolygon_xy.len().try_into().unwrap());
-                self.types.push(6)
-            }
-            Dimension::XYZ => {
-                self.offsets
-                    .push(self.mpolygon_xyz.len().try_into().unwrap());
-                self.types.push(16)
-            }
-        }
+        let i = dim.order();
+        self.offsets
+            .push(self.mpolygons[i].len().try_into().unwrap());
+        self.types.push(type_id(6, dim))
     }
 
     /// Add a new geometry to this builder
@@ -797,7 +666,8 @@ impl<'a> GeometryBuilder {
                         self.push_geometry_collection(Some(gc))?
                     }
                 }
-                Rect(_) | Triangle(_) | Line(_) => todo!(),
+                Rect(g) => self.push_rect(Some(g))?,
+                Triangle(_) | Line(_) => todo!(),
             };
         } else {
             self.push_null();
@@ -816,28 +686,19 @@ impl<'a> GeometryBuilder {
         value: Option<&impl GeometryCollectionTrait<T = f64>>,
     ) -> Result<()> {
         if let Some(gc) = value {
-            self.add_geometry_collection_type(gc.dim().try_into().unwrap());
-            match gc.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.gc_xy.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.gc_xy.push_geometry_collection(Some(gc))?;
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    // Flush deferred nulls
-                    (0..self.deferred_nulls).for_each(|_| self.gc_xyz.push_null());
-                    self.deferred_nulls = 0;
-
-                    self.gc_xyz.push_geometry_collection(Some(gc))?;
-                }
-                dim => {
-                    return Err(GeoArrowError::General(format!(
-                        "Unsupported dimension {dim:?}"
-                    )))
-                }
+            let dim: Dimension = gc.dim().try_into()?;
+            let i = dim.order();
+            // Flush deferred nulls
+            for _ in 0..self.deferred_nulls {
+                self.add_geometry_collection_type(dim);
+                self.gcs[i].push_null();
             }
+            self.deferred_nulls = 0;
+
+            self.add_geometry_collection_type(dim);
+
+            self.gcs[i].push_geometry_collection(Some(gc))?;
+            self.validity.append_non_null();
         } else {
             self.push_null();
         };
@@ -847,16 +708,9 @@ impl<'a> GeometryBuilder {
 
     #[inline]
     fn add_geometry_collection_type(&mut self, dim: Dimension) {
-        match dim {
-            Dimension::XY => {
-                self.offsets.push(self.gc_xy.len().try_into().unwrap());
-                self.types.push(7)
-            }
-            Dimension::XYZ => {
-                self.offsets.push(self.gc_xyz.len().try_into().unwrap());
-                self.types.push(17)
-            }
-        }
+        let i = dim.order();
+        self.offsets.push(self.gcs[i].len().try_into().unwrap());
+        self.types.push(type_id(7, dim))
     }
 
     /// Push a null to this builder
@@ -864,33 +718,52 @@ impl<'a> GeometryBuilder {
     /// Nulls will be pushed to one of the underlying non-empty arrays, to simplify downcasting.
     #[inline]
     pub fn push_null(&mut self) {
-        if !self.point_xy.is_empty() {
-            self.point_xy.push_null();
-        } else if !self.line_string_xy.is_empty() {
-            self.line_string_xy.push_null();
-        } else if !self.polygon_xy.is_empty() {
-            self.polygon_xy.push_null();
-        } else if !self.mpoint_xy.is_empty() {
-            self.mpoint_xy.push_null();
-        } else if !self.mline_string_xy.is_empty() {
-            self.mline_string_xy.push_null();
-        } else if !self.mpolygon_xy.is_empty() {
-            self.mpolygon_xy.push_null();
-        } else if !self.point_xyz.is_empty() {
-            self.point_xyz.push_null();
-        } else if !self.line_string_xyz.is_empty() {
-            self.line_string_xyz.push_null();
-        } else if !self.polygon_xyz.is_empty() {
-            self.polygon_xyz.push_null();
-        } else if !self.mpoint_xyz.is_empty() {
-            self.mpoint_xyz.push_null();
-        } else if !self.mline_string_xyz.is_empty() {
-            self.mline_string_xyz.push_null();
-        } else if !self.mpolygon_xyz.is_empty() {
-            self.mpolygon_xyz.push_null();
-        } else {
-            self.deferred_nulls += 1;
+        self.validity.append_null();
+        // Iterate through each dimension, then iterate through each child type. If a child exists,
+        // push a null to it.
+        //
+        // Note that we must **also** call `add_*_type` so that the offsets are correct to point
+        // the union array to the null.
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            if !self.points[i].is_empty() {
+                self.add_point_type(dim);
+                self.points[i].push_null();
+                return;
+            }
+            if !self.line_strings[i].is_empty() {
+                self.add_line_string_type(dim);
+                self.line_strings[i].push_null();
+                return;
+            }
+            if !self.polygons[i].is_empty() {
+                self.add_polygon_type(dim);
+                self.polygons[i].push_null();
+                return;
+            }
+            if !self.mpoints[i].is_empty() {
+                self.add_multi_point_type(dim);
+                self.mpoints[i].push_null();
+                return;
+            }
+            if !self.mline_strings[i].is_empty() {
+                self.add_multi_line_string_type(dim);
+                self.mline_strings[i].push_null();
+                return;
+            }
+            if !self.mpolygons[i].is_empty() {
+                self.add_multi_polygon_type(dim);
+                self.mpolygons[i].push_null();
+                return;
+            }
+            if !self.gcs[i].is_empty() {
+                self.add_geometry_collection_type(dim);
+                self.gcs[i].push_null();
+                return;
+            }
         }
+
+        self.deferred_nulls += 1;
     }
 
     /// Extend this builder with the given geometries
@@ -962,29 +835,35 @@ impl IntoArrow for GeometryBuilder {
     type ArrowArray = UnionArray;
 
     fn into_arrow(self) -> Self::ArrowArray {
-        todo!()
+        self.finish().into_arrow()
     }
 }
 
 impl From<GeometryBuilder> for GeometryArray {
-    fn from(other: GeometryBuilder) -> Self {
+    fn from(mut other: GeometryBuilder) -> Self {
+        // If every value was null, the nulls are still deferred; write them to the XY point child
+        // so that the array keeps its length.
+        let i = Dimension::XY.order();
+        for _ in 0..std::mem::take(&mut other.deferred_nulls) {
+            if other.prefer_multi {
+                other.add_multi_point_type(Dimension::XY);
+                other.mpoints[i].push_null();
+            } else {
+                other.add_point_type(Dimension::XY);
+                other.points[i].push_null();
+            }
+        }
+
         Self::new(
             other.types.into(),
             other.offsets.into(),
-            Some(other.point_xy.into()),
-            Some(other.line_string_xy.into()),
-            Some(other.polygon_xy.into()),
-            Some(other.mpoint_xy.into()),
-            Some(other.mline_string_xy.into()),
-            Some(other.mpolygon_xy.into()),
-            Some(other.gc_xy.into()),
-            Some(other.point_xyz.into()),
-            Some(other.line_string_xyz.into()),
-            Some(other.polygon_xyz.into()),
-            Some(other.mpoint_xyz.into()),
-            Some(other.mline_string_xyz.into()),
-            Some(other.mpolygon_xyz.into()),
-            Some(other.gc_xyz.into()),
+            other.points.map(|x| x.into()),
+            other.line_strings.map(|x| x.into()),
+            other.polygons.map(|x| x.into()),
+            other.mpoints.map(|x| x.into()),
+            other.mline_strings.map(|x| x.into()),
+            other.mpolygons.map(|x| x.into()),
+            other.gcs.map(|x| x.into()),
             other.metadata,
         )
     }
@@ -1027,9 +906,8 @@ impl GeometryArrayBuilder for GeometryBuilder {
         self.types.len()
     }
 
-    fn nulls(&self) -> &arrow_buffer::NullBufferBuilder {
-        // Take this method off trait
-        todo!()
+    fn nulls(&self) -> &NullBufferBuilder {
+        &self.validity
     }
 
     fn new(_dim: Dimension) -> Self {
@@ -1064,7 +942,7 @@ impl GeometryArrayBuilder for GeometryBuilder {
     }
 
     fn coord_type(&self) -> CoordType {
-        self.point_xy.coord_type()
+        self.points[0].coord_type()
     }
 
     fn set_metadata(&mut self, metadata: Arc<ArrayMetadata>) {
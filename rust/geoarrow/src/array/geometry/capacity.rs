@@ -6,12 +6,15 @@ use crate::array::multipoint::MultiPointCapacity;
 use crate::array::multipolygon::MultiPolygonCapacity;
 use crate::array::polygon::PolygonCapacity;
 use crate::array::GeometryCollectionCapacity;
+use crate::datatypes::Dimension;
 use crate::error::Result;
 use geo_traits::*;
 
 /// A counter for the buffer sizes of a [`GeometryArray`][crate::array::GeometryArray].
 ///
 /// This can be used to reduce allocations by allocating once for exactly the array size you need.
+///
+/// Each child capacity is stored per dimension, indexed in the order XY, XYZ, XYM, XYZM.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct GeometryCapacity {
     /// The number of null geometries. Ideally the builder will assign these to any array that has
@@ -19,21 +22,13 @@ pub struct GeometryCapacity {
     nulls: usize,
 
     /// Simple: just the total number of points, nulls included
-    point_xy: usize,
-    line_string_xy: LineStringCapacity,
-    polygon_xy: PolygonCapacity,
-    mpoint_xy: MultiPointCapacity,
-    mline_string_xy: MultiLineStringCapacity,
-    mpolygon_xy: MultiPolygonCapacity,
-    gc_xy: GeometryCollectionCapacity,
-
-    point_xyz: usize,
-    line_string_xyz: LineStringCapacity,
-    polygon_xyz: PolygonCapacity,
-    mpoint_xyz: MultiPointCapacity,
-    mline_string_xyz: MultiLineStringCapacity,
-    mpolygon_xyz: MultiPolygonCapacity,
-    gc_xyz: GeometryCollectionCapacity,
+    points: [usize; 4],
+    line_strings: [LineStringCapacity; 4],
+    polygons: [PolygonCapacity; 4],
+    mpoints: [MultiPointCapacity; 4],
+    mline_strings: [MultiLineStringCapacity; 4],
+    mpolygons: [MultiPolygonCapacity; 4],
+    gcs: [GeometryCollectionCapacity; 4],
 
     /// Whether to prefer multi or single arrays for new geometries.
     prefer_multi: bool,
@@ -41,42 +36,29 @@ pub struct GeometryCapacity {
 
 impl GeometryCapacity {
     /// Create a new capacity with known sizes.
+    ///
+    /// Each array holds the capacity for one dimension, in the order XY, XYZ, XYM, XYZM.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         nulls: usize,
-        point_xy: usize,
-        line_string_xy: LineStringCapacity,
-        polygon_xy: PolygonCapacity,
-        mpoint_xy: MultiPointCapacity,
-        mline_string_xy: MultiLineStringCapacity,
-        mpolygon_xy: MultiPolygonCapacity,
-        gc_xy: GeometryCollectionCapacity,
-
-        point_xyz: usize,
-        line_string_xyz: LineStringCapacity,
-        polygon_xyz: PolygonCapacity,
-        mpoint_xyz: MultiPointCapacity,
-        mline_string_xyz: MultiLineStringCapacity,
-        mpolygon_xyz: MultiPolygonCapacity,
-        gc_xyz: GeometryCollectionCapacity,
+        points: [usize; 4],
+        line_strings: [LineStringCapacity; 4],
+        polygons: [PolygonCapacity; 4],
+        mpoints: [MultiPointCapacity; 4],
+        mline_strings: [MultiLineStringCapacity; 4],
+        mpolygons: [MultiPolygonCapacity; 4],
+        gcs: [GeometryCollectionCapacity; 4],
         prefer_multi: bool,
     ) -> Self {
         Self {
             nulls,
-            point_xy,
-            line_string_xy,
-            polygon_xy,
-            mpoint_xy,
-            mline_string_xy,
-            mpolygon_xy,
-            gc_xy,
-            point_xyz,
-            line_string_xyz,
-            polygon_xyz,
-            mpoint_xyz,
-            mline_string_xyz,
-            mpolygon_xyz,
-            gc_xyz,
+            points,
+            line_strings,
+            polygons,
+            mpoints,
+            mline_strings,
+            mpolygons,
+            gcs,
             prefer_multi,
         }
     }
@@ -85,20 +67,13 @@ impl GeometryCapacity {
     pub fn new_empty(prefer_multi: bool) -> Self {
         Self {
             nulls: 0,
-            point_xy: 0,
-            line_string_xy: LineStringCapacity::new_empty(),
-            polygon_xy: PolygonCapacity::new_empty(),
-            mpoint_xy: MultiPointCapacity::new_empty(),
-            mline_string_xy: MultiLineStringCapacity::new_empty(),
-            mpolygon_xy: MultiPolygonCapacity::new_empty(),
-            gc_xy: GeometryCollectionCapacity::new_empty(),
-            point_xyz: 0,
-            line_string_xyz: LineStringCapacity::new_empty(),
-            polygon_xyz: PolygonCapacity::new_empty(),
-            mpoint_xyz: MultiPointCapacity::new_empty(),
-            mline_string_xyz: MultiLineStringCapacity::new_empty(),
-            mpolygon_xyz: MultiPolygonCapacity::new_empty(),
-            gc_xyz: GeometryCollectionCapacity::new_empty(),
+            points: [0; 4],
+            line_strings: [LineStringCapacity::new_empty(); 4],
+            polygons: [PolygonCapacity::new_empty(); 4],
+            mpoints: [MultiPointCapacity::new_empty(); 4],
+            mline_strings: [MultiLineStringCapacity::new_empty(); 4],
+            mpolygons: [MultiPolygonCapacity::new_empty(); 4],
+            gcs: [GeometryCollectionCapacity::new_empty(); 4],
             prefer_multi,
         }
     }
@@ -112,106 +87,62 @@ impl GeometryCapacity {
 
     /// Return `true` if the capacity is empty.
     pub fn is_empty(&self) -> bool {
-        self.point_xy == 0
-            && self.line_string_xy.is_empty()
-            && self.polygon_xy.is_empty()
-            && self.mpoint_xy.is_empty()
-            && self.mline_string_xy.is_empty()
-            && self.mpolygon_xy.is_empty()
-            && self.point_xyz == 0
-            && self.line_string_xyz.is_empty()
-            && self.polygon_xyz.is_empty()
-            && self.mpoint_xyz.is_empty()
-            && self.mline_string_xyz.is_empty()
-            && self.mpolygon_xyz.is_empty()
+        self.points.iter().all(|c| *c == 0)
+            && self.line_strings.iter().all(|c| c.is_empty())
+            && self.polygons.iter().all(|c| c.is_empty())
+            && self.mpoints.iter().all(|c| c.is_empty())
+            && self.mline_strings.iter().all(|c| c.is_empty())
+            && self.mpolygons.iter().all(|c| c.is_empty())
     }
 
     /// The total number of geometries across all geometry types.
     pub fn total_num_geoms(&self) -> usize {
         let mut total = 0;
-        total += self.point_xy;
-        total += self.line_string_xy.geom_capacity();
-        total += self.polygon_xy.geom_capacity();
-        total += self.mpoint_xy.geom_capacity();
-        total += self.mline_string_xy.geom_capacity();
-        total += self.mpolygon_xy.geom_capacity();
-        total += self.point_xyz;
-        total += self.line_string_xyz.geom_capacity();
-        total += self.polygon_xyz.geom_capacity();
-        total += self.mpoint_xyz.geom_capacity();
-        total += self.mline_string_xyz.geom_capacity();
-        total += self.mpolygon_xyz.geom_capacity();
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            total += self.points[i];
+            total += self.line_strings[i].geom_capacity();
+            total += self.polygons[i].geom_capacity();
+            total += self.mpoints[i].geom_capacity();
+            total += self.mline_strings[i].geom_capacity();
+            total += self.mpolygons[i].geom_capacity();
+        }
         total
     }
 
-    /// The number of point_xy geometries
-    pub fn point_xy(&self) -> usize {
-        self.point_xy
-    }
-
-    /// The number of line_string_xy geometries
-    pub fn line_string_xy(&self) -> LineStringCapacity {
-        self.line_string_xy
-    }
-
-    /// The number of polygon_xy geometries
-    pub fn polygon_xy(&self) -> PolygonCapacity {
-        self.polygon_xy
-    }
-
-    /// The number of multi point_xy geometries
-    pub fn mpoint_xy(&self) -> MultiPointCapacity {
-        self.mpoint_xy
-    }
-
-    /// The number of multi line_string_xy geometries
-    pub fn mline_string_xy(&self) -> MultiLineStringCapacity {
-        self.mline_string_xy
-    }
-
-    /// The number of multi polygon_xy geometries
-    pub fn mpolygon_xy(&self) -> MultiPolygonCapacity {
-        self.mpolygon_xy
-    }
-
-    /// The number of gc_xy geometries
-    pub fn gc_xy(&self) -> GeometryCollectionCapacity {
-        self.gc_xy
-    }
-
-    /// The number of point_xyz geometries
-    pub fn point_xyz(&self) -> usize {
-        self.point_xyz
+    /// The number of point geometries of the given dimension
+    pub fn point(&self, dim: Dimension) -> usize {
+        self.points[dim.order()]
     }
 
-    /// The number of line_string_xyz geometries
-    pub fn line_string_xyz(&self) -> LineStringCapacity {
-        self.line_string_xyz
+    /// The capacity of line string geometries of the given dimension
+    pub fn line_string(&self, dim: Dimension) -> LineStringCapacity {
+        self.line_strings[dim.order()]
     }
 
-    /// The number of polygon_xyz geometries
-    pub fn polygon_xyz(&self) -> PolygonCapacity {
-        self.polygon_xyz
+    /// The capacity of polygon geometries of the given dimension
+    pub fn polygon(&self, dim: Dimension) -> PolygonCapacity {
+        self.polygons[dim.order()]
     }
 
-    /// The number of multi point_xyz geometries
-    pub fn mpoint_xyz(&self) -> MultiPointCapacity {
-        self.mpoint_xyz
+    /// The capacity of multi point geometries of the given dimension
+    pub fn multi_point(&self, dim: Dimension) -> MultiPointCapacity {
+        self.mpoints[dim.order()]
     }
 
-    /// The number of multi line_string_xyz geometries
-    pub fn mline_string_xyz(&self) -> MultiLineStringCapacity {
-        self.mline_string_xyz
+    /// The capacity of multi line string geometries of the given dimension
+    pub fn multi_line_string(&self, dim: Dimension) -> MultiLineStringCapacity {
+        self.mline_strings[dim.order()]
     }
 
-    /// The number of multi polygon_xyz geometries
-    pub fn mpolygon_xyz(&self) -> MultiPolygonCapacity {
-        self.mpolygon_xyz
+    /// The capacity of multi polygon geometries of the given dimension
+    pub fn multi_polygon(&self, dim: Dimension) -> MultiPolygonCapacity {
+        self.mpolygons[dim.order()]
     }
 
-    /// The number of gc_xyz geometries
-    pub fn gc_xyz(&self) -> GeometryCollectionCapacity {
-        self.gc_xyz
+    /// The capacity of geometry collection geometries of the given dimension
+    pub fn geometry_collection(&self, dim: Dimension) -> GeometryCollectionCapacity {
+        self.gcs[dim.order()]
     }
 
     // pub fn point_compatible(&self) -> bool {
@@ -263,22 +194,11 @@ impl GeometryCapacity {
     #[inline]
     pub fn add_point(&mut self, point: Option<&impl PointTrait>) {
         if let Some(point) = point {
-            match point.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    if self.prefer_multi {
-                        self.mpoint_xy.add_point_capacity(1);
-                    } else {
-                        self.point_xy += 1;
-                    }
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    if self.prefer_multi {
-                        self.mpoint_xyz.add_point_capacity(1);
-                    } else {
-                        self.point_xyz += 1;
-                    }
-                }
-                _ => todo!(),
+            let i = dim_order(point.dim());
+            if self.prefer_multi {
+                self.mpoints[i].add_point_capacity(1);
+            } else {
+                self.points[i] += 1;
             }
         } else {
             self.nulls += 1;
@@ -289,22 +209,11 @@ impl GeometryCapacity {
     #[inline]
     pub fn add_line_string(&mut self, line_string: Option<&impl LineStringTrait>) {
         if let Some(line_string) = line_string {
-            match line_string.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    if self.prefer_multi {
-                        self.mline_string_xy.add_line_string(Some(line_string));
-                    } else {
-                        self.line_string_xy.add_line_string(Some(line_string));
-                    }
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    if self.prefer_multi {
-                        self.mline_string_xyz.add_line_string(Some(line_string));
-                    } else {
-                        self.line_string_xyz.add_line_string(Some(line_string));
-                    }
-                }
-                _ => todo!(),
+            let i = dim_order(line_string.dim());
+            if self.prefer_multi {
+                self.mline_strings[i].add_line_string(Some(line_string));
+            } else {
+                self.line_strings[i].add_line_string(Some(line_string));
             }
         } else {
             self.nulls += 1;
@@ -315,22 +224,26 @@ impl GeometryCapacity {
     #[inline]
     pub fn add_polygon(&mut self, polygon: Option<&impl PolygonTrait>) {
         if let Some(polygon) = polygon {
-            match polygon.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    if self.prefer_multi {
-                        self.mpolygon_xy.add_polygon(Some(polygon));
-                    } else {
-                        self.polygon_xy.add_polygon(Some(polygon));
-                    }
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    if self.prefer_multi {
-                        self.mpolygon_xyz.add_polygon(Some(polygon));
-                    } else {
-                        self.polygon_xyz.add_polygon(Some(polygon));
-                    }
-                }
-                _ => todo!(),
+            let i = dim_order(polygon.dim());
+            if self.prefer_multi {
+                self.mpolygons[i].add_polygon(Some(polygon));
+            } else {
+                self.polygons[i].add_polygon(Some(polygon));
+            }
+        } else {
+            self.nulls += 1;
+        }
+    }
+
+    /// Add the capacity of the given Rect
+    #[inline]
+    pub fn add_rect(&mut self, rect: Option<&impl RectTrait>) {
+        if let Some(rect) = rect {
+            let i = dim_order(rect.dim());
+            if self.prefer_multi {
+                self.mpolygons[i].add_rect(Some(rect));
+            } else {
+                self.polygons[i].add_rect(Some(rect));
             }
         } else {
             self.nulls += 1;
@@ -341,15 +254,7 @@ impl GeometryCapacity {
     #[inline]
     pub fn add_multi_point(&mut self, multi_point: Option<&impl MultiPointTrait>) {
         if let Some(multi_point) = multi_point {
-            match multi_point.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    self.mpoint_xy.add_multi_point(Some(multi_point));
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    self.mpoint_xyz.add_multi_point(Some(multi_point));
-                }
-                _ => todo!(),
-            }
+            self.mpoints[dim_order(multi_point.dim())].add_multi_point(Some(multi_point));
         } else {
             self.nulls += 1;
         }
@@ -359,17 +264,8 @@ impl GeometryCapacity {
     #[inline]
     pub fn add_multi_line_string(&mut self, multi_line_string: Option<&impl MultiLineStringTrait>) {
         if let Some(multi_line_string) = multi_line_string {
-            match multi_line_string.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    self.mline_string_xy
-                        .add_multi_line_string(Some(multi_line_string));
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    self.mline_string_xyz
-                        .add_multi_line_string(Some(multi_line_string));
-                }
-                _ => todo!(),
-            }
+            self.mline_strings[dim_order(multi_line_string.dim())]
+                .add_multi_line_string(Some(multi_line_string));
         } else {
             self.nulls += 1;
         }
@@ -379,15 +275,7 @@ impl GeometryCapacity {
     #[inline]
     pub fn add_multi_polygon(&mut self, multi_polygon: Option<&impl MultiPolygonTrait>) {
         if let Some(multi_polygon) = multi_polygon {
-            match multi_polygon.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    self.mpolygon_xy.add_multi_polygon(Some(multi_polygon));
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    self.mpolygon_xyz.add_multi_polygon(Some(multi_polygon));
-                }
-                _ => todo!(),
-            }
+            self.mpolygons[dim_order(multi_polygon.dim())].add_multi_polygon(Some(multi_polygon));
         } else {
             self.nulls += 1;
         }
@@ -407,6 +295,7 @@ impl GeometryCapacity {
                 geo_traits::GeometryType::GeometryCollection(p) => {
                     self.add_geometry_collection(Some(p))?
                 }
+                geo_traits::GeometryType::Rect(r) => self.add_rect(Some(r)),
                 _ => todo!(),
             };
         } else {
@@ -422,15 +311,7 @@ impl GeometryCapacity {
         gc: Option<&impl GeometryCollectionTrait>,
     ) -> Result<()> {
         if let Some(gc) = gc {
-            match gc.dim() {
-                Dimensions::Xy | Dimensions::Unknown(2) => {
-                    self.gc_xy.add_geometry_collection(Some(gc))?;
-                }
-                Dimensions::Xyz | Dimensions::Unknown(3) => {
-                    self.gc_xyz.add_geometry_collection(Some(gc))?;
-                }
-                _ => todo!(),
-            }
+            self.gcs[dim_order(gc.dim())].add_geometry_collection(Some(gc))?;
         } else {
             self.nulls += 1;
         };
@@ -465,45 +346,41 @@ impl GeometryCapacity {
     pub fn num_bytes(&self) -> usize {
         let mut count = 0;
 
-        count += self.point_xy * 2 * 8;
-        count += self.line_string_xy.num_bytes();
-        count += self.polygon_xy.num_bytes();
-        count += self.mpoint_xy.num_bytes();
-        count += self.mline_string_xy.num_bytes();
-        count += self.mpolygon_xy.num_bytes();
-        count += self.gc_xy.num_bytes();
-
-        count += self.point_xyz * 3 * 8;
-        count += self.line_string_xyz.num_bytes();
-        count += self.polygon_xyz.num_bytes();
-        count += self.mpoint_xyz.num_bytes();
-        count += self.mline_string_xyz.num_bytes();
-        count += self.mpolygon_xyz.num_bytes();
-        count += self.gc_xyz.num_bytes();
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            count += self.points[i] * dim.size() * 8;
+            count += self.line_strings[i].num_bytes();
+            count += self.polygons[i].num_bytes();
+            count += self.mpoints[i].num_bytes();
+            count += self.mline_strings[i].num_bytes();
+            count += self.mpolygons[i].num_bytes();
+            count += self.gcs[i].num_bytes();
+        }
 
         count
     }
 }
 
+/// The index of the per-dimension child capacity for a geometry with the given dimension.
+fn dim_order(dim: geo_traits::Dimensions) -> usize {
+    let dim: Dimension = dim.try_into().unwrap();
+    dim.order()
+}
+
 impl AddAssign for GeometryCapacity {
     fn add_assign(&mut self, rhs: Self) {
         self.nulls += rhs.nulls;
 
         // TODO: implement AddAssign on all of these and switch to using add assign
-        self.point_xy = self.point_xy + rhs.point_xy;
-        self.line_string_xy = self.line_string_xy + rhs.line_string_xy;
-        self.polygon_xy = self.polygon_xy + rhs.polygon_xy;
-        self.mpoint_xy = self.mpoint_xy + rhs.mpoint_xy;
-        self.mline_string_xy = self.mline_string_xy + rhs.mline_string_xy;
-        self.mpolygon_xy = self.mpolygon_xy + rhs.mpolygon_xy;
-        self.gc_xy = self.gc_xy + rhs.gc_xy;
-
-        self.point_xyz = self.point_xyz + rhs.point_xyz;
-        self.line_string_xyz = self.line_string_xyz + rhs.line_string_xyz;
-        self.polygon_xyz = self.polygon_xyz + rhs.polygon_xyz;
-        self.mpoint_xyz = self.mpoint_xyz + rhs.mpoint_xyz;
-        self.mline_string_xyz = self.mline_string_xyz + rhs.mline_string_xyz;
-        self.mpolygon_xyz = self.mpolygon_xyz + rhs.mpolygon_xyz;
-        self.gc_xyz = self.gc_xyz + rhs.gc_xyz;
+        for dim in Dimension::ALL {
+            let i = dim.order();
+            self.points[i] += rhs.points[i];
+            self.line_strings[i] = self.line_strings[i] + rhs.line_strings[i];
+            self.polygons[i] = self.polygons[i] + rhs.polygons[i];
+            self.mpoints[i] = self.mpoints[i] + rhs.mpoints[i];
+            self.mline_strings[i] = self.mline_strings[i] + rhs.mline_strings[i];
+            self.mpolygons[i] = self.mpolygons[i] + rhs.mpolygons[i];
+            self.gcs[i] = self.gcs[i] + rhs.gcs[i];
+        }
     }
 }
@@ -2,6 +2,6 @@ mod array;
 mod builder;
 mod capacity;
 
-pub use array::GeometryArray;
+pub use array::{GenericGeometryArray, GeometryArray, LargeGeometryArray};
 pub use builder::GeometryBuilder;
 pub use capacity::GeometryCapacity;
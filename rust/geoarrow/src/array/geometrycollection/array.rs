@@ -8,10 +8,14 @@ use arrow_schema::{DataType, Field};
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::geometrycollection::{GeometryCollectionBuilder, GeometryCollectionCapacity};
 use crate::array::metadata::ArrayMetadata;
-use crate::array::util::offsets_buffer_i64_to_i32;
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, try_cast_offsets, OffsetBufferUtils,
+};
+use crate::array::validate::{invalid_row, validate_nulls, validate_offsets};
 use crate::array::{
-    CoordBuffer, CoordType, LineStringArray, MixedGeometryArray, MultiLineStringArray,
-    MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, WKBArray,
+    CoordBuffer, CoordType, GenericLineStringArray, GenericMixedGeometryArray,
+    GenericMultiLineStringArray, GenericMultiPointArray, GenericMultiPolygonArray,
+    GenericPolygonArray, PointArray, WKBArray,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -24,36 +28,50 @@ use geo_traits::GeometryCollectionTrait;
 ///
 /// This is semantically equivalent to `Vec<Option<GeometryCollection>>` due to the internal
 /// validity bitmap.
+///
+/// The offset type `O` is `i32` for [`GeometryCollectionArray`] and `i64` for
+/// [`LargeGeometryCollectionArray`]. It applies both to the geometry offsets and to the child
+/// arrays of the contained [`GenericMixedGeometryArray`].
 #[derive(Debug, Clone)]
-pub struct GeometryCollectionArray {
-    // Always NativeType::GeometryCollection
+pub struct GenericGeometryCollectionArray<O: OffsetSizeTrait> {
+    // Always NativeType::GeometryCollection or NativeType::LargeGeometryCollection
     data_type: NativeType,
 
     metadata: Arc<ArrayMetadata>,
 
-    pub(crate) array: MixedGeometryArray,
+    pub(crate) array: GenericMixedGeometryArray<O>,
 
     /// Offsets into the mixed geometry array where each geometry starts
-    pub(crate) geom_offsets: OffsetBuffer<i32>,
+    pub(crate) geom_offsets: OffsetBuffer<O>,
 
     /// Validity bitmap
     pub(crate) validity: Option<NullBuffer>,
 }
 
-impl GeometryCollectionArray {
+/// An array of GeometryCollection geometries with `i32` offsets.
+pub type GeometryCollectionArray = GenericGeometryCollectionArray<i32>;
+
+/// An array of GeometryCollection geometries with `i64` offsets.
+pub type LargeGeometryCollectionArray = GenericGeometryCollectionArray<i64>;
+
+impl<O: OffsetSizeTrait> GenericGeometryCollectionArray<O> {
     /// Create a new GeometryCollectionArray from parts
     ///
     /// # Implementation
     ///
     /// This function is `O(1)`.
     pub fn new(
-        array: MixedGeometryArray,
-        geom_offsets: OffsetBuffer<i32>,
+        array: GenericMixedGeometryArray<O>,
+        geom_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
         let coord_type = array.coord_type();
-        let data_type = NativeType::GeometryCollection(coord_type, array.dimension());
+        let data_type = if O::IS_LARGE {
+            NativeType::LargeGeometryCollection(coord_type, array.dimension())
+        } else {
+            NativeType::GeometryCollection(coord_type, array.dimension())
+        };
         Self {
             data_type,
             array,
@@ -71,7 +89,7 @@ impl GeometryCollectionArray {
     pub fn buffer_lengths(&self) -> GeometryCollectionCapacity {
         GeometryCollectionCapacity::new(
             self.array.buffer_lengths(),
-            *self.geom_offsets.last().unwrap() as usize,
+            self.geom_offsets.last().as_usize(),
         )
     }
 
@@ -131,7 +149,7 @@ impl GeometryCollectionArray {
     }
 }
 
-impl ArrayBase for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericGeometryCollectionArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -176,7 +194,7 @@ impl ArrayBase for GeometryCollectionArray {
     }
 }
 
-impl NativeArray for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericGeometryCollectionArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -202,9 +220,26 @@ impl NativeArray for GeometryCollectionArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("GeometryCollection", self.validity.as_ref(), self.len())?;
+        self.array.validate()?;
+        validate_offsets(&self.geom_offsets, self.array.len()).map_err(|row| {
+            invalid_row(
+                "GeometryCollection",
+                row,
+                "geometry offsets are out of bounds",
+            )
+        })
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        self.array.validate_full()
+    }
 }
 
-impl GeometryArraySelfMethods for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericGeometryCollectionArray<O> {
     fn with_coords(self, _coords: CoordBuffer) -> Self {
         todo!()
     }
@@ -225,7 +260,9 @@ impl NativeGeometryAccessor for GeometryCollectionArray {
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for GeometryCollectionArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericGeometryCollectionArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
@@ -235,8 +272,8 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for GeometryCollectionArr
     }
 }
 
-impl<'a> ArrayAccessor<'a> for GeometryCollectionArray {
-    type Item = GeometryCollection<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericGeometryCollectionArray<O> {
+    type Item = GeometryCollection<'a, O>;
     type ItemGeo = geo::GeometryCollection;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
@@ -244,8 +281,8 @@ impl<'a> ArrayAccessor<'a> for GeometryCollectionArray {
     }
 }
 
-impl IntoArrow for GeometryCollectionArray {
-    type ArrowArray = GenericListArray<i32>;
+impl<O: OffsetSizeTrait> IntoArrow for GenericGeometryCollectionArray<O> {
+    type ArrowArray = GenericListArray<O>;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let geometries_field = self.geometries_field();
@@ -255,29 +292,14 @@ impl IntoArrow for GeometryCollectionArray {
     }
 }
 
-impl TryFrom<(&GenericListArray<i32>, Dimension)> for GeometryCollectionArray {
-    type Error = GeoArrowError;
-
-    fn try_from((value, dim): (&GenericListArray<i32>, Dimension)) -> Result<Self> {
-        let geoms: MixedGeometryArray = (value.values().as_ref(), dim).try_into()?;
-        let geom_offsets = value.offsets();
-        let validity = value.nulls();
-
-        Ok(Self::new(
-            geoms,
-            geom_offsets.clone(),
-            validity.cloned(),
-            Default::default(),
-        ))
-    }
-}
-
-impl TryFrom<(&GenericListArray<i64>, Dimension)> for GeometryCollectionArray {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> TryFrom<(&GenericListArray<A>, Dimension)>
+    for GenericGeometryCollectionArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from((value, dim): (&GenericListArray<i64>, Dimension)) -> Result<Self> {
-        let geoms: MixedGeometryArray = (value.values().as_ref(), dim).try_into()?;
-        let geom_offsets = offsets_buffer_i64_to_i32(value.offsets())?;
+    fn try_from((value, dim): (&GenericListArray<A>, Dimension)) -> Result<Self> {
+        let geoms: GenericMixedGeometryArray<O> = (value.values().as_ref(), dim).try_into()?;
+        let geom_offsets = try_cast_offsets(value.offsets())?;
         let validity = value.nulls();
 
         Ok(Self::new(
@@ -289,7 +311,7 @@ impl TryFrom<(&GenericListArray<i64>, Dimension)> for GeometryCollectionArray {
     }
 }
 
-impl TryFrom<(&dyn Array, Dimension)> for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericGeometryCollectionArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -310,7 +332,7 @@ impl TryFrom<(&dyn Array, Dimension)> for GeometryCollectionArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericGeometryCollectionArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -356,7 +378,7 @@ impl Default for GeometryCollectionArray {
     }
 }
 
-impl PartialEq for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> PartialEq for GenericGeometryCollectionArray<O> {
     fn eq(&self, other: &Self) -> bool {
         if self.validity != other.validity {
             return false;
@@ -374,44 +396,80 @@ impl PartialEq for GeometryCollectionArray {
     }
 }
 
-impl From<PointArray> for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> From<PointArray> for GenericGeometryCollectionArray<O> {
     fn from(value: PointArray) -> Self {
-        MixedGeometryArray::from(value).into()
+        GenericMixedGeometryArray::<O>::from(value).into()
     }
 }
 
-impl From<LineStringArray> for GeometryCollectionArray {
-    fn from(value: LineStringArray) -> Self {
-        MixedGeometryArray::from(value).into()
+impl<O: OffsetSizeTrait> From<GenericLineStringArray<O>> for GenericGeometryCollectionArray<O> {
+    fn from(value: GenericLineStringArray<O>) -> Self {
+        GenericMixedGeometryArray::from(value).into()
     }
 }
 
-impl From<PolygonArray> for GeometryCollectionArray {
-    fn from(value: PolygonArray) -> Self {
-        MixedGeometryArray::from(value).into()
+impl<O: OffsetSizeTrait> From<GenericPolygonArray<O>> for GenericGeometryCollectionArray<O> {
+    fn from(value: GenericPolygonArray<O>) -> Self {
+        GenericMixedGeometryArray::from(value).into()
     }
 }
-impl From<MultiPointArray> for GeometryCollectionArray {
-    fn from(value: MultiPointArray) -> Self {
-        MixedGeometryArray::from(value).into()
+
+impl<O: OffsetSizeTrait> From<GenericMultiPointArray<O>> for GenericGeometryCollectionArray<O> {
+    fn from(value: GenericMultiPointArray<O>) -> Self {
+        GenericMixedGeometryArray::from(value).into()
     }
 }
-impl From<MultiLineStringArray> for GeometryCollectionArray {
-    fn from(value: MultiLineStringArray) -> Self {
-        MixedGeometryArray::from(value).into()
+
+impl<O: OffsetSizeTrait> From<GenericMultiLineStringArray<O>>
+    for GenericGeometryCollectionArray<O>
+{
+    fn from(value: GenericMultiLineStringArray<O>) -> Self {
+        GenericMixedGeometryArray::from(value).into()
     }
 }
-impl From<MultiPolygonArray> for GeometryCollectionArray {
-    fn from(value: MultiPolygonArray) -> Self {
-        MixedGeometryArray::from(value).into()
+
+impl<O: OffsetSizeTrait> From<GenericMultiPolygonArray<O>> for GenericGeometryCollectionArray<O> {
+    fn from(value: GenericMultiPolygonArray<O>) -> Self {
+        GenericMixedGeometryArray::from(value).into()
     }
 }
 
-impl From<MixedGeometryArray> for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> From<GenericMixedGeometryArray<O>> for GenericGeometryCollectionArray<O> {
     // TODO: We should construct the correct validity buffer from the union's underlying arrays.
-    fn from(value: MixedGeometryArray) -> Self {
+    fn from(value: GenericMixedGeometryArray<O>) -> Self {
         let metadata = value.metadata.clone();
         let geom_offsets = OffsetBuffer::from_lengths(vec![1; value.len()]);
-        GeometryCollectionArray::new(value, geom_offsets, None, metadata)
+        Self::new(value, geom_offsets, None, metadata)
+    }
+}
+
+/// Default to an empty array
+impl Default for LargeGeometryCollectionArray {
+    fn default() -> Self {
+        GeometryCollectionArray::default().into()
+    }
+}
+
+impl From<GeometryCollectionArray> for LargeGeometryCollectionArray {
+    fn from(value: GeometryCollectionArray) -> Self {
+        Self::new(
+            value.array.into(),
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            value.validity,
+            value.metadata,
+        )
+    }
+}
+
+impl TryFrom<LargeGeometryCollectionArray> for GeometryCollectionArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargeGeometryCollectionArray) -> Result<Self> {
+        Ok(Self::new(
+            value.array.try_into()?,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            value.validity,
+            value.metadata,
+        ))
     }
 }
@@ -14,7 +14,7 @@ use crate::scalar::WKB;
 use crate::trait_::{ArrayAccessor, GeometryArrayBuilder, IntoArrow};
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
-    MultiPolygonTrait, PointTrait, PolygonTrait,
+    MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
 };
 
 /// The GeoArrow equivalent to `Vec<Option<GeometryCollection>>`: a mutable collection of
@@ -203,6 +203,15 @@ impl<'a> GeometryCollectionBuilder {
         Ok(())
     }
 
+    /// Push a Rect onto the end of this builder, as a Polygon
+    #[inline]
+    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = f64>>) -> Result<()> {
+        self.geoms.push_rect(value)?;
+        self.geom_offsets.try_push_usize(1)?;
+        self.validity.append(value.is_some());
+        Ok(())
+    }
+
     /// Push a MultiPoint onto the end of this builder
     #[inline]
     pub fn push_multi_point(
@@ -255,7 +264,8 @@ impl<'a> GeometryCollectionBuilder {
                 MultiLineString(p) => self.push_multi_line_string(Some(p))?,
                 MultiPolygon(p) => self.push_multi_polygon(Some(p))?,
                 GeometryCollection(p) => self.push_geometry_collection(Some(p))?,
-                Rect(_) | Triangle(_) | Line(_) => todo!(),
+                Rect(p) => self.push_rect(Some(p))?,
+                Triangle(_) | Line(_) => todo!(),
             }
         } else {
             self.push_null();
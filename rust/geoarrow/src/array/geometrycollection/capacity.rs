@@ -4,7 +4,7 @@ use crate::array::mixed::MixedCapacity;
 use crate::error::Result;
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
-    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait,
+    MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait,
 };
 
 /// A counter for the buffer sizes of a
@@ -51,6 +51,11 @@ impl GeometryCollectionCapacity {
         self.mixed_capacity.add_polygon(Some(geom));
     }
 
+    #[inline]
+    fn add_valid_rect(&mut self, geom: &impl RectTrait) {
+        self.mixed_capacity.add_rect(Some(geom));
+    }
+
     #[inline]
     fn add_valid_multi_point(&mut self, geom: &impl MultiPointTrait) {
         self.mixed_capacity.add_multi_point(Some(geom));
@@ -87,7 +92,8 @@ impl GeometryCollectionCapacity {
                 MultiLineString(p) => self.add_valid_multi_line_string(p),
                 MultiPolygon(p) => self.add_valid_multi_polygon(p),
                 GeometryCollection(p) => self.add_valid_geometry_collection(p)?,
-                Rect(_) | Line(_) | Triangle(_) => todo!(),
+                Rect(p) => self.add_valid_rect(p),
+                Line(_) | Triangle(_) => todo!(),
             }
         };
         Ok(())
@@ -2,6 +2,8 @@ mod array;
 mod builder;
 mod capacity;
 
-pub use array::GeometryCollectionArray;
+pub use array::{
+    GenericGeometryCollectionArray, GeometryCollectionArray, LargeGeometryCollectionArray,
+};
 pub use builder::GeometryCollectionBuilder;
 pub use capacity::GeometryCollectionCapacity;
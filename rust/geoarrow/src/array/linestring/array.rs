@@ -5,10 +5,13 @@ use crate::algorithm::native::downcast::can_downcast_multi;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::linestring::LineStringCapacity;
 use crate::array::metadata::ArrayMetadata;
-use crate::array::util::{offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, try_cast_offsets, OffsetBufferUtils,
+};
+use crate::array::validate::{invalid_row, validate_line_string, validate_nulls, validate_offsets};
 use crate::array::{
-    CoordBuffer, CoordType, GeometryCollectionArray, MixedGeometryArray, MultiLineStringArray,
-    MultiPointArray, WKBArray,
+    CoordBuffer, CoordType, GenericMultiLineStringArray, GenericMultiPointArray,
+    GeometryCollectionArray, MixedGeometryArray, WKBArray,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -27,9 +30,11 @@ use super::LineStringBuilder;
 ///
 /// This is semantically equivalent to `Vec<Option<LineString>>` due to the internal validity
 /// bitmap.
+///
+/// The offset type `O` is `i32` for [`LineStringArray`] and `i64` for [`LargeLineStringArray`].
 #[derive(Debug, Clone)]
-pub struct LineStringArray {
-    // Always NativeType::LineString
+pub struct GenericLineStringArray<O: OffsetSizeTrait> {
+    // Always NativeType::LineString or NativeType::LargeLineString
     data_type: NativeType,
 
     pub(crate) metadata: Arc<ArrayMetadata>,
@@ -37,16 +42,22 @@ pub struct LineStringArray {
     pub(crate) coords: CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
-    pub(crate) geom_offsets: OffsetBuffer<i32>,
+    pub(crate) geom_offsets: OffsetBuffer<O>,
 
     /// Validity bitmap
     pub(crate) validity: Option<NullBuffer>,
 }
 
-pub(super) fn check(
+/// An array of LineString geometries with `i32` offsets.
+pub type LineStringArray = GenericLineStringArray<i32>;
+
+/// An array of LineString geometries with `i64` offsets.
+pub type LargeLineStringArray = GenericLineStringArray<i64>;
+
+pub(super) fn check<O: OffsetSizeTrait>(
     coords: &CoordBuffer,
     validity_len: Option<usize>,
-    geom_offsets: &OffsetBuffer<i32>,
+    geom_offsets: &OffsetBuffer<O>,
 ) -> Result<()> {
     if validity_len.is_some_and(|len| len != geom_offsets.len_proxy()) {
         return Err(GeoArrowError::General(
@@ -54,7 +65,7 @@ pub(super) fn check(
         ));
     }
 
-    if *geom_offsets.last() as usize != coords.len() {
+    if geom_offsets.last().as_usize() != coords.len() {
         return Err(GeoArrowError::General(
             "largest geometry offset must match coords length".to_string(),
         ));
@@ -63,7 +74,7 @@ pub(super) fn check(
     Ok(())
 }
 
-impl LineStringArray {
+impl<O: OffsetSizeTrait> GenericLineStringArray<O> {
     /// Create a new LineStringArray from parts
     ///
     /// # Implementation
@@ -76,7 +87,7 @@ impl LineStringArray {
     /// - if the largest geometry offset does not match the number of coordinates
     pub fn new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -95,12 +106,16 @@ impl LineStringArray {
     /// - if the geometry offsets do not match the number of coordinates
     pub fn try_new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Result<Self> {
         check(&coords, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
-        let data_type = NativeType::LineString(coords.coord_type(), coords.dim());
+        let data_type = if O::IS_LARGE {
+            NativeType::LargeLineString(coords.coord_type(), coords.dim())
+        } else {
+            NativeType::LineString(coords.coord_type(), coords.dim())
+        };
         Ok(Self {
             data_type,
             coords,
@@ -120,18 +135,18 @@ impl LineStringArray {
     }
 
     #[allow(dead_code)]
-    pub(crate) fn into_inner(self) -> (CoordBuffer, OffsetBuffer<i32>, Option<NullBuffer>) {
+    pub(crate) fn into_inner(self) -> (CoordBuffer, OffsetBuffer<O>, Option<NullBuffer>) {
         (self.coords, self.geom_offsets, self.validity)
     }
 
     /// Access the underlying geometry offsets buffer
-    pub fn geom_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn geom_offsets(&self) -> &OffsetBuffer<O> {
         &self.geom_offsets
     }
 
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> LineStringCapacity {
-        LineStringCapacity::new(*self.geom_offsets.last() as usize, self.len())
+        LineStringCapacity::new(self.geom_offsets.last().as_usize(), self.len())
     }
 
     /// The number of bytes occupied by this array.
@@ -190,7 +205,7 @@ impl LineStringArray {
     }
 }
 
-impl ArrayBase for LineStringArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericLineStringArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -243,7 +258,7 @@ impl ArrayBase for LineStringArray {
     }
 }
 
-impl NativeArray for LineStringArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericLineStringArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -269,9 +284,26 @@ impl NativeArray for LineStringArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("LineString", self.validity.as_ref(), self.len())?;
+        validate_offsets(&self.geom_offsets, self.coords.len())
+            .map_err(|row| invalid_row("LineString", row, "geometry offsets are out of bounds"))
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        for row in 0..self.len() {
+            if self.is_valid(row) {
+                validate_line_string(&self.geom_offsets, row)
+                    .map_err(|reason| invalid_row("LineString", row, reason))?;
+            }
+        }
+        Ok(())
+    }
 }
 
-impl GeometryArraySelfMethods for LineStringArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericLineStringArray<O> {
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(coords, self.geom_offsets, self.validity, self.metadata)
@@ -294,7 +326,9 @@ impl NativeGeometryAccessor for LineStringArray {
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for LineStringArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericLineStringArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
@@ -304,8 +338,8 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for LineStringArray {
     }
 }
 
-impl<'a> ArrayAccessor<'a> for LineStringArray {
-    type Item = LineString<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericLineStringArray<O> {
+    type Item = LineString<'a, O>;
     type ItemGeo = geo::LineString;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
@@ -313,8 +347,8 @@ impl<'a> ArrayAccessor<'a> for LineStringArray {
     }
 }
 
-impl IntoArrow for LineStringArray {
-    type ArrowArray = GenericListArray<i32>;
+impl<O: OffsetSizeTrait> IntoArrow for GenericLineStringArray<O> {
+    type ArrowArray = GenericListArray<O>;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let vertices_field = self.vertices_field();
@@ -324,29 +358,14 @@ impl IntoArrow for LineStringArray {
     }
 }
 
-impl TryFrom<(&GenericListArray<i32>, Dimension)> for LineStringArray {
-    type Error = GeoArrowError;
-
-    fn try_from((value, dim): (&GenericListArray<i32>, Dimension)) -> Result<Self> {
-        let coords = CoordBuffer::from_arrow(value.values().as_ref(), dim)?;
-        let geom_offsets = value.offsets();
-        let validity = value.nulls();
-
-        Ok(Self::new(
-            coords,
-            geom_offsets.clone(),
-            validity.cloned(),
-            Default::default(),
-        ))
-    }
-}
-
-impl TryFrom<(&GenericListArray<i64>, Dimension)> for LineStringArray {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> TryFrom<(&GenericListArray<A>, Dimension)>
+    for GenericLineStringArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from((value, dim): (&GenericListArray<i64>, Dimension)) -> Result<Self> {
+    fn try_from((value, dim): (&GenericListArray<A>, Dimension)) -> Result<Self> {
         let coords = CoordBuffer::from_arrow(value.values().as_ref(), dim)?;
-        let geom_offsets = offsets_buffer_i64_to_i32(value.offsets())?;
+        let geom_offsets = try_cast_offsets(value.offsets())?;
         let validity = value.nulls();
 
         Ok(Self::new(
@@ -357,7 +376,8 @@ impl TryFrom<(&GenericListArray<i64>, Dimension)> for LineStringArray {
         ))
     }
 }
-impl TryFrom<(&dyn Array, Dimension)> for LineStringArray {
+
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericLineStringArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -378,7 +398,7 @@ impl TryFrom<(&dyn Array, Dimension)> for LineStringArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for LineStringArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericLineStringArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -408,8 +428,8 @@ impl<G: LineStringTrait<T = f64>> From<(&[G], Dimension)> for LineStringArray {
 
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
 /// the semantic type
-impl From<LineStringArray> for MultiPointArray {
-    fn from(value: LineStringArray) -> Self {
+impl<O: OffsetSizeTrait> From<GenericLineStringArray<O>> for GenericMultiPointArray<O> {
+    fn from(value: GenericLineStringArray<O>) -> Self {
         Self::new(
             value.coords,
             value.geom_offsets,
@@ -435,7 +455,38 @@ impl Default for LineStringArray {
     }
 }
 
-impl PartialEq for LineStringArray {
+/// Default to an empty array
+impl Default for LargeLineStringArray {
+    fn default() -> Self {
+        LineStringArray::default().into()
+    }
+}
+
+impl From<LineStringArray> for LargeLineStringArray {
+    fn from(value: LineStringArray) -> Self {
+        Self::new(
+            value.coords,
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            value.validity,
+            value.metadata,
+        )
+    }
+}
+
+impl TryFrom<LargeLineStringArray> for LineStringArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargeLineStringArray) -> Result<Self> {
+        Ok(Self::new(
+            value.coords,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            value.validity,
+            value.metadata,
+        ))
+    }
+}
+
+impl<O: OffsetSizeTrait> PartialEq for GenericLineStringArray<O> {
     fn eq(&self, other: &Self) -> bool {
         if self.validity != other.validity {
             return false;
@@ -453,15 +504,15 @@ impl PartialEq for LineStringArray {
     }
 }
 
-impl TryFrom<MultiLineStringArray> for LineStringArray {
+impl<O: OffsetSizeTrait> TryFrom<GenericMultiLineStringArray<O>> for GenericLineStringArray<O> {
     type Error = GeoArrowError;
 
-    fn try_from(value: MultiLineStringArray) -> Result<Self> {
+    fn try_from(value: GenericMultiLineStringArray<O>) -> Result<Self> {
         if !can_downcast_multi(&value.geom_offsets) {
             return Err(GeoArrowError::General("Unable to cast".to_string()));
         }
 
-        Ok(LineStringArray::new(
+        Ok(Self::new(
             value.coords,
             value.ring_offsets,
             value.validity,
@@ -565,6 +616,27 @@ mod test {
         assert_eq!(sliced.get_as_geo(0), Some(ls1()));
     }
 
+    #[test]
+    fn large_list_roundtrip() {
+        let arr: LineStringArray = (vec![Some(ls0()), None, Some(ls1())], Dimension::XY).into();
+        let large_arr = LargeLineStringArray::from(arr.clone());
+        assert_eq!(
+            large_arr.data_type(),
+            NativeType::LargeLineString(CoordType::default(), Dimension::XY)
+        );
+        assert!(matches!(large_arr.storage_type(), DataType::LargeList(_)));
+        assert_eq!(large_arr.get_as_geo(2), Some(ls1()));
+
+        let field = large_arr.extension_field();
+        let parsed =
+            LargeLineStringArray::try_from((large_arr.to_array_ref().as_ref(), field.as_ref()))
+                .unwrap();
+        assert_eq!(parsed, large_arr);
+        assert_eq!(parsed.null_count(), 1);
+
+        assert_eq!(LineStringArray::try_from(large_arr).unwrap(), arr);
+    }
+
     #[test]
     fn parse_wkb_geoarrow_interleaved_example() {
         let linestring_arr = example_linestring_interleaved();
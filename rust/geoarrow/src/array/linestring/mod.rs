@@ -1,7 +1,7 @@
 //! Contains the [`LineStringArray`] and [`LineStringBuilder`] for arrays of LineString
 //! geometries.
 
-pub use array::LineStringArray;
+pub use array::{GenericLineStringArray, LargeLineStringArray, LineStringArray};
 pub use builder::LineStringBuilder;
 pub use capacity::LineStringCapacity;
 
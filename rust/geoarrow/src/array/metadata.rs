@@ -4,7 +4,7 @@
 
 use arrow_schema::Field;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
 use crate::error::GeoArrowError;
 
@@ -152,6 +152,176 @@ impl ArrayMetadata {
         self.edges = Some(edges);
         self
     }
+
+    /// Whether `self` and `other` describe the same CRS, or both have no CRS.
+    ///
+    /// This compares CRSs across representations where possible: an `AUTHORITY:CODE` string
+    /// matches a PROJJSON object or WKT string with the same top-level identifier (`id` in
+    /// PROJJSON, `ID` or `AUTHORITY` in WKT). WKT strings without identifiers are compared after
+    /// removing whitespace and normalizing the case of keywords. A bare numeric SRID is taken to
+    /// be an EPSG code.
+    pub fn crs_equals(&self, other: &ArrayMetadata) -> bool {
+        self.crs_key() == other.crs_key()
+    }
+
+    /// Check that `operation` may combine arrays with the CRSs of `self` and `other`.
+    ///
+    /// Inputs without a CRS are compatible with any CRS.
+    pub(crate) fn check_crs_compatible(
+        &self,
+        other: &ArrayMetadata,
+        operation: &str,
+    ) -> crate::error::Result<()> {
+        if self.crs.is_none() || other.crs.is_none() || self.crs_equals(other) {
+            return Ok(());
+        }
+
+        Err(GeoArrowError::General(format!(
+            "{operation} on inputs with different CRSs: {} and {}. Reproject one of the inputs.",
+            self.crs_summary(),
+            other.crs_summary()
+        )))
+    }
+
+    /// A comparable form of the CRS.
+    fn crs_key(&self) -> Option<CrsKey> {
+        let key = match self.crs.as_ref()? {
+            Value::Object(object) => projjson_id(object)
+                .unwrap_or_else(|| CrsKey::Projjson(Value::Object(object.clone()))),
+            Value::String(crs) => match self.crs_type {
+                Some(CRSType::AuthorityCode) => {
+                    authority_code(crs).unwrap_or_else(|| CrsKey::Text(crs.trim().to_string()))
+                }
+                Some(CRSType::Srid) => srid(crs.trim()),
+                _ => {
+                    let wkt = normalize_wkt(crs);
+                    wkt_id(&wkt)
+                        .or_else(|| authority_code(crs))
+                        .unwrap_or(CrsKey::Text(wkt))
+                }
+            },
+            Value::Number(code) if self.crs_type == Some(CRSType::Srid) => srid(&code.to_string()),
+            other => CrsKey::Projjson(other.clone()),
+        };
+        Some(key)
+    }
+
+    /// A short description of the CRS for error messages.
+    fn crs_summary(&self) -> String {
+        match (self.crs_key(), &self.crs) {
+            (Some(CrsKey::Authority(authority, code)), _) => format!("{authority}:{code}"),
+            (Some(CrsKey::Srid(srid)), _) => format!("SRID {srid}"),
+            (_, Some(Value::Object(object))) => object
+                .get("name")
+                .and_then(Value::as_str)
+                .unwrap_or("unnamed PROJJSON CRS")
+                .to_string(),
+            (_, Some(crs)) => crs.to_string().chars().take(80).collect(),
+            (_, None) => "no CRS".to_string(),
+        }
+    }
+}
+
+/// A normalized CRS used to compare CRSs stored in different representations.
+#[derive(Debug, PartialEq)]
+enum CrsKey {
+    /// An authority (uppercased) and a code.
+    Authority(String, String),
+    Projjson(Value),
+    Srid(String),
+    /// Normalized WKT, or an opaque string.
+    Text(String),
+}
+
+/// Parse an `AUTHORITY:CODE` string.
+fn authority_code(crs: &str) -> Option<CrsKey> {
+    let (authority, code) = crs.trim().split_once(':')?;
+    if authority.is_empty() || code.is_empty() || code.contains(['[', '(', ' ']) {
+        return None;
+    }
+    Some(CrsKey::Authority(
+        authority.to_uppercase(),
+        code.to_string(),
+    ))
+}
+
+/// Interpret an SRID. Bare numbers are EPSG codes, as in PostGIS; anything else is opaque unless
+/// it is an `AUTHORITY:CODE` string.
+fn srid(srid: &str) -> CrsKey {
+    if !srid.is_empty() && srid.bytes().all(|b| b.is_ascii_digit()) {
+        CrsKey::Authority("EPSG".to_string(), srid.to_string())
+    } else {
+        authority_code(srid).unwrap_or_else(|| CrsKey::Srid(srid.to_string()))
+    }
+}
+
+/// The top-level identifier of a PROJJSON object, from `id` or the first entry of `ids`.
+fn projjson_id(object: &Map<String, Value>) -> Option<CrsKey> {
+    let id = match object.get("id") {
+        Some(id) => id,
+        None => object.get("ids")?.as_array()?.first()?,
+    };
+    let authority = id.get("authority")?.as_str()?;
+    let code = match id.get("code")? {
+        Value::String(code) => code.clone(),
+        Value::Number(code) => code.to_string(),
+        _ => return None,
+    };
+    Some(CrsKey::Authority(authority.to_uppercase(), code))
+}
+
+/// Remove whitespace and uppercase everything outside of quoted strings.
+fn normalize_wkt(wkt: &str) -> String {
+    let mut normalized = String::with_capacity(wkt.len());
+    let mut in_quotes = false;
+    for c in wkt.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        if in_quotes || c == '"' {
+            normalized.push(c);
+        } else if !c.is_whitespace() {
+            normalized.extend(c.to_uppercase());
+        }
+    }
+    normalized
+}
+
+/// The identifier of the root object of normalized WKT, i.e. its last direct `ID` (WKT2) or
+/// `AUTHORITY` (WKT1) child.
+fn wkt_id(wkt: &str) -> Option<CrsKey> {
+    let mut depth = 0;
+    let mut in_quotes = false;
+    let mut id_start = None;
+    for (i, c) in wkt.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            _ if in_quotes => {}
+            '[' | '(' => depth += 1,
+            ']' | ')' => depth -= 1,
+            ',' if depth == 1 => {
+                let rest = &wkt[i + 1..];
+                if let Some(rest) = rest
+                    .strip_prefix("ID[")
+                    .or_else(|| rest.strip_prefix("AUTHORITY["))
+                {
+                    id_start = Some(rest);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut parts = id_start?.splitn(3, [',', ']']);
+    let authority = parts.next()?.trim_matches('"');
+    let code = parts.next()?.trim_matches('"');
+    if authority.is_empty() || code.is_empty() {
+        return None;
+    }
+    Some(CrsKey::Authority(
+        authority.to_uppercase(),
+        code.to_string(),
+    ))
 }
 
 impl TryFrom<&Field> for ArrayMetadata {
@@ -165,3 +335,72 @@ impl TryFrom<&Field> for ArrayMetadata {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const WGS84_WKT: &str = r#"GEOGCRS["WGS 84",
+    DATUM["World Geodetic System 1984",
+        ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],
+    CS[ellipsoidal,2],
+        AXIS["geodetic latitude (Lat)",north],
+        AXIS["geodetic longitude (Lon)",east],
+    ID["EPSG",4326]]"#;
+
+    #[test]
+    fn crs_equals_across_representations() {
+        let authority_code = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let projjson = ArrayMetadata::from_projjson(serde_json::json!({
+            "type": "GeographicCRS",
+            "name": "WGS 84",
+            "id": {"authority": "EPSG", "code": 4326}
+        }));
+        let wkt = ArrayMetadata::from_wkt2_2019(WGS84_WKT.to_string());
+
+        assert!(authority_code.crs_equals(&projjson));
+        assert!(authority_code.crs_equals(&wkt));
+        assert!(projjson.crs_equals(&wkt));
+        assert!(ArrayMetadata::default().crs_equals(&ArrayMetadata::default()));
+
+        let web_mercator = ArrayMetadata::from_authority_code("EPSG:3857".to_string());
+        assert!(!authority_code.crs_equals(&web_mercator));
+        assert!(!authority_code.crs_equals(&ArrayMetadata::default()));
+    }
+
+    #[test]
+    fn crs_equals_numeric_srid() {
+        let srid = |crs: Value| ArrayMetadata {
+            crs: Some(crs),
+            crs_type: Some(CRSType::Srid),
+            ..Default::default()
+        };
+        let authority_code = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+
+        assert!(srid(Value::from("4326")).crs_equals(&authority_code));
+        assert!(srid(Value::from(4326)).crs_equals(&authority_code));
+        assert!(srid(Value::from("4326")).crs_equals(&srid(Value::from(4326))));
+        assert!(!srid(Value::from("3857")).crs_equals(&authority_code));
+        assert!(!srid(Value::from("my_srid")).crs_equals(&authority_code));
+    }
+
+    #[test]
+    fn crs_equals_normalizes_wkt() {
+        let wkt = WGS84_WKT.replace(",\n    ID[\"EPSG\",4326]", "");
+        let compact = wkt.split_whitespace().collect::<Vec<_>>().join(" ");
+        let left = ArrayMetadata::from_wkt2_2019(wkt.replace("GEOGCRS", "geogcrs"));
+        let right = ArrayMetadata::from_wkt2_2019(compact);
+        assert!(left.crs_equals(&right));
+    }
+
+    #[test]
+    fn check_crs_compatible() {
+        let wgs84 = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let web_mercator = ArrayMetadata::from_authority_code("EPSG:3857".to_string());
+
+        assert!(wgs84.check_crs_compatible(&web_mercator, "test").is_err());
+        assert!(wgs84
+            .check_crs_compatible(&ArrayMetadata::default(), "test")
+            .is_ok());
+    }
+}
@@ -2,17 +2,18 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use arrow_array::{Array, ArrayRef, OffsetSizeTrait, UnionArray};
-use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_buffer::{NullBuffer, NullBufferBuilder, OffsetBuffer, ScalarBuffer};
 use arrow_schema::{DataType, Field, UnionMode};
 
 use crate::algorithm::native::downcast::can_downcast_multi;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::mixed::builder::MixedGeometryBuilder;
 use crate::array::mixed::MixedCapacity;
+use crate::array::validate::{validate_nulls, validate_union};
 use crate::array::{
-    CoordType, GeometryCollectionArray, LineStringArray, LineStringBuilder, MultiLineStringArray,
-    MultiLineStringBuilder, MultiPointArray, MultiPointBuilder, MultiPolygonArray,
-    MultiPolygonBuilder, PointArray, PointBuilder, PolygonArray, PolygonBuilder, WKBArray,
+    CoordBuffer, CoordBufferBuilder, CoordType, GenericGeometryCollectionArray,
+    GenericLineStringArray, GenericMultiLineStringArray, GenericMultiPointArray,
+    GenericMultiPolygonArray, GenericPolygonArray, PointArray, PointBuilder, WKBArray,
 };
 use crate::datatypes::{mixed_data_type, Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -21,6 +22,19 @@ use crate::trait_::{ArrayAccessor, GeometryArraySelfMethods, IntoArrow, NativeGe
 use crate::{ArrayBase, NativeArray};
 use geo_traits::GeometryTrait;
 
+/// An immutable array of geometries of any primitive geometry type, all of which have the same
+/// dimension.
+///
+/// This is stored as an Arrow dense union with one child per geometry type. It does not support
+/// geometry collections; use a [`GeometryArray`][crate::array::GeometryArray] for arrays that
+/// contain geometry collections or mix dimensions.
+///
+/// Null geometries are stored as nulls in one of the child arrays.
+///
+/// The offset type `O` applies to the list offsets of the child arrays; it is `i32` for
+/// [`MixedGeometryArray`] and `i64` for [`LargeMixedGeometryArray`]. The union offsets are always
+/// `i32`, as required by Arrow.
+///
 /// # Invariants
 ///
 /// - All arrays must have the same dimension
@@ -55,9 +69,7 @@ use geo_traits::GeometryTrait;
 /// - 36: MultiPolygon ZM
 /// - 37: GeometryCollection ZM
 #[derive(Debug, Clone, PartialEq)]
-pub struct MixedGeometryArray {
-    // We store the coord type and dimension separately because there's no NativeType::Mixed
-    // variant
+pub struct GenericMixedGeometryArray<O: OffsetSizeTrait> {
     coord_type: CoordType,
     dim: Dimension,
 
@@ -71,18 +83,32 @@ pub struct MixedGeometryArray {
 
     /// Invariant: Any of these arrays that are `Some()` must have length >0
     pub(crate) points: PointArray,
-    pub(crate) line_strings: LineStringArray,
-    pub(crate) polygons: PolygonArray,
-    pub(crate) multi_points: MultiPointArray,
-    pub(crate) multi_line_strings: MultiLineStringArray,
-    pub(crate) multi_polygons: MultiPolygonArray,
+    pub(crate) line_strings: GenericLineStringArray<O>,
+    pub(crate) polygons: GenericPolygonArray<O>,
+    pub(crate) multi_points: GenericMultiPointArray<O>,
+    pub(crate) multi_line_strings: GenericMultiLineStringArray<O>,
+    pub(crate) multi_polygons: GenericMultiPolygonArray<O>,
+
+    /// The logical validity of this array, derived from the validity of the child arrays.
+    nulls: Option<NullBuffer>,
 
     /// We don't need a separate slice_length, because that's the length of the full
     /// MixedGeometryArray
     slice_offset: usize,
 }
 
-impl MixedGeometryArray {
+/// An array of mixed geometries with `i32` offsets in its child arrays.
+pub type MixedGeometryArray = GenericMixedGeometryArray<i32>;
+
+/// An array of mixed geometries with `i64` offsets in its child arrays.
+pub type LargeMixedGeometryArray = GenericMixedGeometryArray<i64>;
+
+/// An empty coordinate buffer, used to fill in child arrays that were not provided.
+fn empty_coords(coord_type: CoordType, dim: Dimension) -> CoordBuffer {
+    CoordBufferBuilder::initialize(0, coord_type == CoordType::Interleaved, dim).into()
+}
+
+impl<O: OffsetSizeTrait> GenericMixedGeometryArray<O> {
     /// Create a new MixedGeometryArray from parts
     ///
     /// # Implementation
@@ -98,11 +124,11 @@ impl MixedGeometryArray {
         type_ids: ScalarBuffer<i8>,
         offsets: ScalarBuffer<i32>,
         points: Option<PointArray>,
-        line_strings: Option<LineStringArray>,
-        polygons: Option<PolygonArray>,
-        multi_points: Option<MultiPointArray>,
-        multi_line_strings: Option<MultiLineStringArray>,
-        multi_polygons: Option<MultiPolygonArray>,
+        line_strings: Option<GenericLineStringArray<O>>,
+        polygons: Option<GenericPolygonArray<O>>,
+        multi_points: Option<GenericMultiPointArray<O>>,
+        multi_line_strings: Option<GenericMultiLineStringArray<O>>,
+        multi_polygons: Option<GenericMultiPolygonArray<O>>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
         let mut coord_types = HashSet::new();
@@ -149,7 +175,7 @@ impl MixedGeometryArray {
         assert_eq!(dimensions.len(), 1);
         let dim = dimensions.into_iter().next().unwrap();
 
-        Self {
+        let mut array = Self {
             coord_type,
             dim,
             type_ids,
@@ -157,25 +183,85 @@ impl MixedGeometryArray {
             points: points.unwrap_or(
                 PointBuilder::new_with_options(dim, coord_type, Default::default()).finish(),
             ),
-            line_strings: line_strings.unwrap_or(
-                LineStringBuilder::new_with_options(dim, coord_type, Default::default()).finish(),
-            ),
-            polygons: polygons.unwrap_or(
-                PolygonBuilder::new_with_options(dim, coord_type, Default::default()).finish(),
-            ),
-            multi_points: multi_points.unwrap_or(
-                MultiPointBuilder::new_with_options(dim, coord_type, Default::default()).finish(),
-            ),
-            multi_line_strings: multi_line_strings.unwrap_or(
-                MultiLineStringBuilder::new_with_options(dim, coord_type, Default::default())
-                    .finish(),
-            ),
-            multi_polygons: multi_polygons.unwrap_or(
-                MultiPolygonBuilder::new_with_options(dim, coord_type, Default::default()).finish(),
-            ),
+            line_strings: line_strings.unwrap_or_else(|| {
+                GenericLineStringArray::new(
+                    empty_coords(coord_type, dim),
+                    OffsetBuffer::new_empty(),
+                    None,
+                    Default::default(),
+                )
+            }),
+            polygons: polygons.unwrap_or_else(|| {
+                GenericPolygonArray::new(
+                    empty_coords(coord_type, dim),
+                    OffsetBuffer::new_empty(),
+                    OffsetBuffer::new_empty(),
+                    None,
+                    Default::default(),
+                )
+            }),
+            multi_points: multi_points.unwrap_or_else(|| {
+                GenericMultiPointArray::new(
+                    empty_coords(coord_type, dim),
+                    OffsetBuffer::new_empty(),
+                    None,
+                    Default::default(),
+                )
+            }),
+            multi_line_strings: multi_line_strings.unwrap_or_else(|| {
+                GenericMultiLineStringArray::new(
+                    empty_coords(coord_type, dim),
+                    OffsetBuffer::new_empty(),
+                    OffsetBuffer::new_empty(),
+                    None,
+                    Default::default(),
+                )
+            }),
+            multi_polygons: multi_polygons.unwrap_or_else(|| {
+                GenericMultiPolygonArray::new(
+                    empty_coords(coord_type, dim),
+                    OffsetBuffer::new_empty(),
+                    OffsetBuffer::new_empty(),
+                    OffsetBuffer::new_empty(),
+                    None,
+                    Default::default(),
+                )
+            }),
             metadata,
+            nulls: None,
             slice_offset: 0,
+        };
+        array.nulls = array.compute_nulls();
+        array
+    }
+
+    /// Compute the logical validity of this array from the validity of its children.
+    fn compute_nulls(&self) -> Option<NullBuffer> {
+        if self.points.null_count() == 0
+            && self.line_strings.null_count() == 0
+            && self.polygons.null_count() == 0
+            && self.multi_points.null_count() == 0
+            && self.multi_line_strings.null_count() == 0
+            && self.multi_polygons.null_count() == 0
+        {
+            return None;
         }
+
+        let mut builder = NullBufferBuilder::new(self.len());
+        for (type_id, offset) in self.type_ids.iter().zip(self.offsets.iter()) {
+            let offset = *offset as usize;
+            let is_null = match type_id % 10 {
+                1 => self.points.is_null(offset),
+                2 => self.line_strings.is_null(offset),
+                3 => self.polygons.is_null(offset),
+                4 => self.multi_points.is_null(offset),
+                5 => self.multi_line_strings.is_null(offset),
+                6 => self.multi_polygons.is_null(offset),
+                _ => unreachable!("unknown type_id {}", type_id),
+            };
+            builder.append(!is_null);
+        }
+        builder.finish()
     }
 
     /// The lengths of each buffer contained in this array.
@@ -215,6 +301,7 @@ impl MixedGeometryArray {
         (self.slice_offset, self.len())
     }
 
+    /// Returns `true` if this array contains at least one point geometry.
     pub fn has_points(&self) -> bool {
         if self.points.is_empty() {
             return false;
@@ -234,6 +321,7 @@ impl MixedGeometryArray {
         true
     }
 
+    /// Returns `true` if this array contains at least one line string geometry.
     pub fn has_line_strings(&self) -> bool {
         if self.line_strings.is_empty() {
             return false;
@@ -253,6 +341,7 @@ impl MixedGeometryArray {
         true
     }
 
+    /// Returns `true` if this array contains at least one polygon geometry.
     pub fn has_polygons(&self) -> bool {
         if self.polygons.is_empty() {
             return false;
@@ -272,6 +361,7 @@ impl MixedGeometryArray {
         true
     }
 
+    /// Returns `true` if this array contains at least one multi point geometry.
     pub fn has_multi_points(&self) -> bool {
         if self.multi_points.is_empty() {
             return false;
@@ -291,6 +381,7 @@ impl MixedGeometryArray {
         true
     }
 
+    /// Returns `true` if this array contains at least one multi line string geometry.
     pub fn has_multi_line_strings(&self) -> bool {
         if self.multi_line_strings.is_empty() {
             return false;
@@ -310,6 +401,7 @@ impl MixedGeometryArray {
         true
     }
 
+    /// Returns `true` if this array contains at least one multi polygon geometry.
     pub fn has_multi_polygons(&self) -> bool {
         if self.multi_polygons.is_empty() {
             return false;
@@ -329,6 +421,7 @@ impl MixedGeometryArray {
         true
     }
 
+    /// Returns `true` if this array contains only point geometries.
     pub fn has_only_points(&self) -> bool {
         self.has_points()
             && !self.has_line_strings()
@@ -338,6 +431,7 @@ impl MixedGeometryArray {
             && !self.has_multi_polygons()
     }
 
+    /// Returns `true` if this array contains only line string geometries.
     pub fn has_only_line_strings(&self) -> bool {
         !self.has_points()
             && self.has_line_strings()
@@ -347,6 +441,7 @@ impl MixedGeometryArray {
             && !self.has_multi_polygons()
     }
 
+    /// Returns `true` if this array contains only polygon geometries.
     pub fn has_only_polygons(&self) -> bool {
         !self.has_points()
             && !self.has_line_strings()
@@ -356,6 +451,7 @@ impl MixedGeometryArray {
             && !self.has_multi_polygons()
     }
 
+    /// Returns `true` if this array contains only multi point geometries.
     pub fn has_only_multi_points(&self) -> bool {
         !self.has_points()
             && !self.has_line_strings()
@@ -365,6 +461,7 @@ impl MixedGeometryArray {
             && !self.has_multi_polygons()
     }
 
+    /// Returns `true` if this array contains only multi line string geometries.
     pub fn has_only_multi_line_strings(&self) -> bool {
         !self.has_points()
             && !self.has_line_strings()
@@ -374,6 +471,7 @@ impl MixedGeometryArray {
             && !self.has_multi_polygons()
     }
 
+    /// Returns `true` if this array contains only multi polygon geometries.
     pub fn has_only_multi_polygons(&self) -> bool {
         !self.has_points()
             && !self.has_line_strings()
@@ -415,14 +513,17 @@ impl MixedGeometryArray {
             multi_line_strings: self.multi_line_strings.clone(),
             multi_polygons: self.multi_polygons.clone(),
             metadata: self.metadata.clone(),
+            nulls: self.nulls.as_ref().map(|nulls| nulls.slice(offset, length)),
             slice_offset: self.slice_offset + offset,
         }
     }
 
+    /// Convert this array to the provided [`CoordType`], cloning the underlying data.
     pub fn to_coord_type(&self, coord_type: CoordType) -> Self {
         self.clone().into_coord_type(coord_type)
     }
 
+    /// Convert this array to the provided [`CoordType`].
     pub fn into_coord_type(self, coord_type: CoordType) -> Self {
         Self::new(
             self.type_ids,
@@ -437,6 +538,7 @@ impl MixedGeometryArray {
         )
     }
 
+    /// The set of geometry types contained in this array.
     pub fn contained_types(&self) -> HashSet<NativeType> {
         let mut types = HashSet::new();
         if self.has_points() {
@@ -462,13 +564,13 @@ impl MixedGeometryArray {
     }
 }
 
-impl ArrayBase for MixedGeometryArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericMixedGeometryArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
     fn storage_type(&self) -> DataType {
-        mixed_data_type(self.coord_type, self.dim)
+        mixed_data_type::<O>(self.coord_type, self.dim)
     }
 
     fn extension_field(&self) -> Arc<Field> {
@@ -519,14 +621,17 @@ impl ArrayBase for MixedGeometryArray {
     /// Returns the optional validity.
     #[inline]
     fn nulls(&self) -> Option<&NullBuffer> {
-        None
+        self.nulls.as_ref()
     }
 }
 
-impl NativeArray for MixedGeometryArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericMixedGeometryArray<O> {
     fn data_type(&self) -> NativeType {
-        // self.data_type
-        todo!("mixed array does not have native type")
+        if O::IS_LARGE {
+            NativeType::LargeMixed(self.coord_type, self.dim)
+        } else {
+            NativeType::Mixed(self.coord_type, self.dim)
+        }
     }
 
     fn dimension(&self) -> Dimension {
@@ -554,15 +659,82 @@ impl NativeArray for MixedGeometryArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("Mixed", self.nulls.as_ref(), self.len())?;
+        self.points.validate()?;
+        self.line_strings.validate()?;
+        self.polygons.validate()?;
+        self.multi_points.validate()?;
+        self.multi_line_strings.validate()?;
+        self.multi_polygons.validate()?;
+
+        // Note: type ids are the geometry type id plus 10 times the dimension order
+        let dim_order = self.dim.order() as i8;
+        validate_union("Mixed", &self.type_ids, &self.offsets, |type_id| {
+            if type_id / 10 != dim_order {
+                return None;
+            }
+            match type_id % 10 {
+                1 => Some(self.points.len()),
+                2 => Some(self.line_strings.len()),
+                3 => Some(self.polygons.len()),
+                4 => Some(self.multi_points.len()),
+                5 => Some(self.multi_line_strings.len()),
+                6 => Some(self.multi_polygons.len()),
+                _ => None,
+            }
+        })
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        self.points.validate_full()?;
+        self.line_strings.validate_full()?;
+        self.polygons.validate_full()?;
+        self.multi_points.validate_full()?;
+        self.multi_line_strings.validate_full()?;
+        self.multi_polygons.validate_full()
+    }
 }
 
-impl GeometryArraySelfMethods for MixedGeometryArray {
-    fn with_coords(self, _coords: crate::array::CoordBuffer) -> Self {
-        todo!();
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericMixedGeometryArray<O> {
+    /// The coordinates are assigned to the child arrays in order: points, line strings, polygons,
+    /// multi points, multi line strings and then multi polygons.
+    fn with_coords(self, coords: crate::array::CoordBuffer) -> Self {
+        let child_lens = [
+            self.points.coords().len(),
+            self.line_strings.coords().len(),
+            self.polygons.coords().len(),
+            self.multi_points.coords().len(),
+            self.multi_line_strings.coords().len(),
+            self.multi_polygons.coords().len(),
+        ];
+        assert_eq!(coords.len(), child_lens.iter().sum::<usize>());
+
+        let mut start = 0;
+        let [points, line_strings, polygons, multi_points, multi_line_strings, multi_polygons] =
+            child_lens.map(|len| {
+                let child_coords = coords.slice(start, len);
+                start += len;
+                child_coords
+            });
+
+        Self::new(
+            self.type_ids,
+            self.offsets,
+            Some(self.points.with_coords(points)),
+            Some(self.line_strings.with_coords(line_strings)),
+            Some(self.polygons.with_coords(polygons)),
+            Some(self.multi_points.with_coords(multi_points)),
+            Some(self.multi_line_strings.with_coords(multi_line_strings)),
+            Some(self.multi_polygons.with_coords(multi_polygons)),
+            self.metadata,
+        )
     }
 
-    fn into_coord_type(self, _coord_type: crate::array::CoordType) -> Self {
-        todo!();
+    fn into_coord_type(self, coord_type: crate::array::CoordType) -> Self {
+        GenericMixedGeometryArray::into_coord_type(self, coord_type)
     }
 }
 
@@ -571,7 +743,7 @@ impl NativeGeometryAccessor for MixedGeometryArray {
         let type_id = self.type_ids[index];
         let offset = self.offsets[index] as usize;
 
-        match type_id {
+        match type_id % 10 {
             1 => Geometry::Point(self.points.value(offset)),
             2 => Geometry::LineString(self.line_strings.value(offset)),
             3 => Geometry::Polygon(self.polygons.value(offset)),
@@ -581,40 +753,33 @@ impl NativeGeometryAccessor for MixedGeometryArray {
             7 => {
                 panic!("nested geometry collections not supported")
             }
-            11 => Geometry::Point(self.points.value(offset)),
-            12 => Geometry::LineString(self.line_strings.value(offset)),
-            13 => Geometry::Polygon(self.polygons.value(offset)),
-            14 => Geometry::MultiPoint(self.multi_points.value(offset)),
-            15 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
-            16 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
-            17 => {
-                panic!("nested geometry collections not supported")
-            }
             _ => panic!("unknown type_id {}", type_id),
         }
     }
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MixedGeometryArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericMixedGeometryArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
     ) -> std::result::Result<geos::Geometry, geos::Error> {
-        let geom = NativeGeometryAccessor::value_as_geometry_unchecked(self, index);
+        let geom = ArrayAccessor::value_unchecked(self, index);
         (&geom).try_into()
     }
 }
 
-impl<'a> ArrayAccessor<'a> for MixedGeometryArray {
-    type Item = Geometry<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericMixedGeometryArray<O> {
+    type Item = Geometry<'a, O>;
     type ItemGeo = geo::Geometry;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
         let type_id = self.type_ids[index];
         let offset = self.offsets[index] as usize;
 
-        match type_id {
+        match type_id % 10 {
             1 => Geometry::Point(self.points.value(offset)),
             2 => Geometry::LineString(self.line_strings.value(offset)),
             3 => Geometry::Polygon(self.polygons.value(offset)),
@@ -624,25 +789,16 @@ impl<'a> ArrayAccessor<'a> for MixedGeometryArray {
             7 => {
                 panic!("nested geometry collections not supported")
             }
-            11 => Geometry::Point(self.points.value(offset)),
-            12 => Geometry::LineString(self.line_strings.value(offset)),
-            13 => Geometry::Polygon(self.polygons.value(offset)),
-            14 => Geometry::MultiPoint(self.multi_points.value(offset)),
-            15 => Geometry::MultiLineString(self.multi_line_strings.value(offset)),
-            16 => Geometry::MultiPolygon(self.multi_polygons.value(offset)),
-            17 => {
-                panic!("nested geometry collections not supported")
-            }
             _ => panic!("unknown type_id {}", type_id),
         }
     }
 }
 
-impl IntoArrow for MixedGeometryArray {
+impl<O: OffsetSizeTrait> IntoArrow for GenericMixedGeometryArray<O> {
     type ArrowArray = UnionArray;
 
     fn into_arrow(self) -> Self::ArrowArray {
-        let union_fields = match mixed_data_type(self.coord_type, self.dim) {
+        let union_fields = match mixed_data_type::<O>(self.coord_type, self.dim) {
             DataType::Union(union_fields, _) => union_fields,
             _ => unreachable!(),
         };
@@ -666,16 +822,16 @@ impl IntoArrow for MixedGeometryArray {
     }
 }
 
-impl TryFrom<(&UnionArray, Dimension)> for MixedGeometryArray {
+impl<O: OffsetSizeTrait> TryFrom<(&UnionArray, Dimension)> for GenericMixedGeometryArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&UnionArray, Dimension)) -> std::result::Result<Self, Self::Error> {
         let mut points: Option<PointArray> = None;
-        let mut line_strings: Option<LineStringArray> = None;
-        let mut polygons: Option<PolygonArray> = None;
-        let mut multi_points: Option<MultiPointArray> = None;
-        let mut multi_line_strings: Option<MultiLineStringArray> = None;
-        let mut multi_polygons: Option<MultiPolygonArray> = None;
+        let mut line_strings: Option<GenericLineStringArray<O>> = None;
+        let mut polygons: Option<GenericPolygonArray<O>> = None;
+        let mut multi_points: Option<GenericMultiPointArray<O>> = None;
+        let mut multi_line_strings: Option<GenericMultiLineStringArray<O>> = None;
+        let mut multi_polygons: Option<GenericMultiPolygonArray<O>> = None;
 
         match value.data_type() {
             DataType::Union(fields, mode) => {
@@ -684,42 +840,33 @@ impl TryFrom<(&UnionArray, Dimension)> for MixedGeometryArray {
                 }
 
                 for (type_id, _field) in fields.iter() {
-                    let found_dimension = if type_id < 10 {
-                        Dimension::XY
-                    } else if type_id < 20 {
-                        Dimension::XYZ
-                    } else {
-                        return Err(GeoArrowError::General(format!(
-                            "Unsupported type_id: {}",
-                            type_id
-                        )));
-                    };
+                    let found_dimension = Dimension::from_order((type_id / 10) as usize)?;
 
                     if dim != found_dimension {
                         return Err(  GeoArrowError::General(format!("expected dimension: {:?}, found child array with dimension {:?} and type_id: {}", dim, found_dimension, type_id )));
                     }
 
-                    match type_id {
-                        1 | 11 => {
+                    match type_id % 10 {
+                        1 => {
                             points = Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        2 | 12 => {
+                        2 => {
                             line_strings =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        3 | 13 => {
+                        3 => {
                             polygons =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        4 | 14 => {
+                        4 => {
                             multi_points =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        5 | 15 => {
+                        5 => {
                             multi_line_strings =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
-                        6 | 16 => {
+                        6 => {
                             multi_polygons =
                                 Some((value.child(type_id).as_ref(), dim).try_into().unwrap());
                         }
@@ -753,7 +900,7 @@ impl TryFrom<(&UnionArray, Dimension)> for MixedGeometryArray {
     }
 }
 
-impl TryFrom<(&dyn Array, Dimension)> for MixedGeometryArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericMixedGeometryArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -770,8 +917,7 @@ impl TryFrom<(&dyn Array, Dimension)> for MixedGeometryArray {
     }
 }
 
-// TODO:, thinking all geoarrow.geometry will go through primary dimensionless geometry array
-impl TryFrom<(&dyn Array, &Field)> for MixedGeometryArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericMixedGeometryArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -812,12 +958,9 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for MixedGeometryArra
     }
 }
 
-impl From<PointArray> for MixedGeometryArray {
+impl<O: OffsetSizeTrait> From<PointArray> for GenericMixedGeometryArray<O> {
     fn from(value: PointArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![1; value.len()],
-            Dimension::XYZ => vec![11; value.len()],
-        };
+        let type_ids = vec![1 + 10 * value.dimension().order() as i8; value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -833,12 +976,9 @@ impl From<PointArray> for MixedGeometryArray {
     }
 }
 
-impl From<LineStringArray> for MixedGeometryArray {
-    fn from(value: LineStringArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![2; value.len()],
-            Dimension::XYZ => vec![12; value.len()],
-        };
+impl<O: OffsetSizeTrait> From<GenericLineStringArray<O>> for GenericMixedGeometryArray<O> {
+    fn from(value: GenericLineStringArray<O>) -> Self {
+        let type_ids = vec![2 + 10 * value.dimension().order() as i8; value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -854,12 +994,9 @@ impl From<LineStringArray> for MixedGeometryArray {
     }
 }
 
-impl From<PolygonArray> for MixedGeometryArray {
-    fn from(value: PolygonArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![3; value.len()],
-            Dimension::XYZ => vec![13; value.len()],
-        };
+impl<O: OffsetSizeTrait> From<GenericPolygonArray<O>> for GenericMixedGeometryArray<O> {
+    fn from(value: GenericPolygonArray<O>) -> Self {
+        let type_ids = vec![3 + 10 * value.dimension().order() as i8; value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -875,12 +1012,9 @@ impl From<PolygonArray> for MixedGeometryArray {
     }
 }
 
-impl From<MultiPointArray> for MixedGeometryArray {
-    fn from(value: MultiPointArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![4; value.len()],
-            Dimension::XYZ => vec![14; value.len()],
-        };
+impl<O: OffsetSizeTrait> From<GenericMultiPointArray<O>> for GenericMixedGeometryArray<O> {
+    fn from(value: GenericMultiPointArray<O>) -> Self {
+        let type_ids = vec![4 + 10 * value.dimension().order() as i8; value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -896,12 +1030,9 @@ impl From<MultiPointArray> for MixedGeometryArray {
     }
 }
 
-impl From<MultiLineStringArray> for MixedGeometryArray {
-    fn from(value: MultiLineStringArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![5; value.len()],
-            Dimension::XYZ => vec![15; value.len()],
-        };
+impl<O: OffsetSizeTrait> From<GenericMultiLineStringArray<O>> for GenericMixedGeometryArray<O> {
+    fn from(value: GenericMultiLineStringArray<O>) -> Self {
+        let type_ids = vec![5 + 10 * value.dimension().order() as i8; value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -917,12 +1048,9 @@ impl From<MultiLineStringArray> for MixedGeometryArray {
     }
 }
 
-impl From<MultiPolygonArray> for MixedGeometryArray {
-    fn from(value: MultiPolygonArray) -> Self {
-        let type_ids = match value.dimension() {
-            Dimension::XY => vec![6; value.len()],
-            Dimension::XYZ => vec![16; value.len()],
-        };
+impl<O: OffsetSizeTrait> From<GenericMultiPolygonArray<O>> for GenericMixedGeometryArray<O> {
+    fn from(value: GenericMultiPolygonArray<O>) -> Self {
+        let type_ids = vec![6 + 10 * value.dimension().order() as i8; value.len()];
         let metadata = value.metadata.clone();
         Self::new(
             ScalarBuffer::from(type_ids),
@@ -938,10 +1066,14 @@ impl From<MultiPolygonArray> for MixedGeometryArray {
     }
 }
 
-impl TryFrom<GeometryCollectionArray> for MixedGeometryArray {
+impl<O: OffsetSizeTrait> TryFrom<GenericGeometryCollectionArray<O>>
+    for GenericMixedGeometryArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from(value: GeometryCollectionArray) -> std::result::Result<Self, Self::Error> {
+    fn try_from(
+        value: GenericGeometryCollectionArray<O>,
+    ) -> std::result::Result<Self, Self::Error> {
         if !can_downcast_multi(&value.geom_offsets) {
             return Err(GeoArrowError::General("Unable to cast".to_string()));
         }
@@ -963,11 +1095,71 @@ impl Default for MixedGeometryArray {
     }
 }
 
+/// Default to an empty array
+impl Default for LargeMixedGeometryArray {
+    fn default() -> Self {
+        MixedGeometryArray::default().into()
+    }
+}
+
+impl From<MixedGeometryArray> for LargeMixedGeometryArray {
+    fn from(value: MixedGeometryArray) -> Self {
+        Self {
+            coord_type: value.coord_type,
+            dim: value.dim,
+            metadata: value.metadata,
+            type_ids: value.type_ids,
+            offsets: value.offsets,
+            points: value.points,
+            line_strings: value.line_strings.into(),
+            polygons: value.polygons.into(),
+            multi_points: value.multi_points.into(),
+            multi_line_strings: value.multi_line_strings.into(),
+            multi_polygons: value.multi_polygons.into(),
+            nulls: value.nulls,
+            slice_offset: value.slice_offset,
+        }
+    }
+}
+
+impl TryFrom<LargeMixedGeometryArray> for MixedGeometryArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargeMixedGeometryArray) -> Result<Self> {
+        Ok(Self {
+            coord_type: value.coord_type,
+            dim: value.dim,
+            metadata: value.metadata,
+            type_ids: value.type_ids,
+            offsets: value.offsets,
+            points: value.points,
+            line_strings: value.line_strings.try_into()?,
+            polygons: value.polygons.try_into()?,
+            multi_points: value.multi_points.try_into()?,
+            multi_line_strings: value.multi_line_strings.try_into()?,
+            multi_polygons: value.multi_polygons.try_into()?,
+            nulls: value.nulls,
+            slice_offset: value.slice_offset,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::array::MixedGeometryArray;
+    use crate::array::{InterleavedCoordBuffer, LargeMixedGeometryArray, MixedGeometryArray};
     use crate::test::{linestring, multilinestring, multipoint, multipolygon, point, polygon};
+    use crate::trait_::GeometryArrayBuilder;
+
+    #[test]
+    fn builder_reports_nulls() {
+        let mut builder = MixedGeometryBuilder::new(Dimension::XY);
+        builder.push_null();
+        builder.push_point(Some(&point::p0())).unwrap();
+        builder.push_geometry(None::<&geo::Geometry>).unwrap();
+        assert_eq!(builder.nulls().len(), 3);
+        assert_eq!(builder.nulls().as_slice(), Some([0b010].as_slice()));
+    }
 
     #[test]
     fn geo_roundtrip_accurate_points() {
@@ -1021,6 +1213,56 @@ mod test {
         assert_eq!(arr.value_as_geo(5), geoms[5]);
     }
 
+    #[test]
+    fn geo_roundtrip_rect() {
+        let rect = geo::Rect::new((0., 5.), (10., 15.));
+        let geoms: Vec<geo::Geometry> =
+            vec![geo::Geometry::Rect(rect), geo::Geometry::Point(point::p0())];
+        let arr: MixedGeometryArray = (geoms.as_slice(), Dimension::XY).try_into().unwrap();
+
+        assert_eq!(
+            arr.value_as_geo(0),
+            geo::Geometry::MultiPolygon(geo::MultiPolygon(vec![rect.to_polygon()]))
+        );
+        assert_eq!(
+            arr.value_as_geo(1),
+            geo::Geometry::MultiPoint(geo::MultiPoint(vec![point::p0()]))
+        );
+    }
+
+    #[test]
+    fn with_coords() {
+        use geo::{CoordsIter, Translate};
+
+        let geoms: Vec<geo::Geometry> = vec![
+            geo::Geometry::LineString(linestring::ls0()),
+            geo::Geometry::Point(point::p0()),
+        ];
+        let arr: MixedGeometryArray = (geoms.as_slice(), Dimension::XY).try_into().unwrap();
+
+        // Coordinates are ordered by child array, so the point comes before the line string.
+        let translated = geoms
+            .iter()
+            .map(|geom| geom.translate(10., 20.))
+            .collect::<Vec<_>>();
+        let values = translated[1]
+            .coords_iter()
+            .chain(translated[0].coords_iter())
+            .flat_map(|coord| [coord.x, coord.y])
+            .collect::<Vec<_>>();
+        let coords = InterleavedCoordBuffer::new(values.into(), Dimension::XY).into();
+
+        let arr = arr.with_coords(coords);
+        assert_eq!(
+            arr.value_as_geo(0).coords_iter().collect::<Vec<_>>(),
+            translated[0].coords_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            arr.value_as_geo(1).coords_iter().collect::<Vec<_>>(),
+            translated[1].coords_iter().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn arrow_roundtrip() {
         let geoms: Vec<geo::Geometry> = vec![
@@ -1087,4 +1329,66 @@ mod test {
         assert_eq!(round_trip_arr.value_as_geo(0), geoms[0]);
         assert_eq!(round_trip_arr.value_as_geo(1), geoms[1]);
     }
+
+    #[test]
+    fn nullable_roundtrip() {
+        let geoms: Vec<Option<geo::Geometry>> = vec![
+            None,
+            Some(geo::Geometry::Polygon(polygon::p0())),
+            None,
+            Some(geo::Geometry::MultiPolygon(multipolygon::mp0())),
+        ];
+        let arr: MixedGeometryArray = (geoms.clone(), Dimension::XY).try_into().unwrap();
+
+        assert!(matches!(
+            arr.data_type(),
+            NativeType::Mixed(_, Dimension::XY)
+        ));
+        assert_eq!(arr.null_count(), 2);
+        assert!(arr.is_null(0));
+        assert!(arr.is_null(2));
+        assert_eq!(
+            arr.value_as_geo(1),
+            geo::Geometry::MultiPolygon(geo::MultiPolygon(vec![polygon::p0()]))
+        );
+        assert_eq!(arr.get_as_geo(3), geoms[3]);
+
+        // Round trip through an extension field
+        let field = arr.extension_field();
+        assert_eq!(
+            NativeType::try_from(field.as_ref()).unwrap(),
+            arr.data_type()
+        );
+        let round_trip_arr =
+            MixedGeometryArray::try_from((arr.to_array_ref().as_ref(), field.as_ref())).unwrap();
+        assert_eq!(round_trip_arr.null_count(), 2);
+        assert!(round_trip_arr.is_null(0));
+        assert_eq!(round_trip_arr.get_as_geo(3), geoms[3]);
+    }
+
+    #[test]
+    fn large_offsets_roundtrip() {
+        let geoms: Vec<Option<geo::Geometry>> = vec![
+            Some(geo::Geometry::LineString(linestring::ls0())),
+            None,
+            Some(geo::Geometry::MultiPolygon(multipolygon::mp0())),
+        ];
+        let arr: MixedGeometryArray = (geoms.clone(), Dimension::XY).try_into().unwrap();
+        let large_arr = LargeMixedGeometryArray::from(arr.clone());
+
+        assert!(matches!(
+            large_arr.data_type(),
+            NativeType::LargeMixed(_, Dimension::XY)
+        ));
+        assert_eq!(large_arr.null_count(), 1);
+        assert_eq!(large_arr.get_as_geo(2), geoms[2]);
+
+        let field = large_arr.extension_field();
+        let round_trip_arr =
+            LargeMixedGeometryArray::try_from((large_arr.to_array_ref().as_ref(), field.as_ref()))
+                .unwrap();
+        assert_eq!(round_trip_arr, large_arr);
+
+        assert_eq!(MixedGeometryArray::try_from(large_arr).unwrap(), arr);
+    }
 }
@@ -10,8 +10,9 @@ use crate::datatypes::Dimension;
 use crate::error::{GeoArrowError, Result};
 use crate::scalar::WKB;
 use crate::trait_::{ArrayAccessor, GeometryArrayBuilder, IntoArrow};
-use crate::{ArrayBase, NativeArray};
+use crate::NativeArray;
 use arrow_array::{OffsetSizeTrait, UnionArray};
+use arrow_buffer::NullBufferBuilder;
 use geo_traits::*;
 
 pub(crate) const DEFAULT_PREFER_MULTI: bool = false;
@@ -58,6 +59,19 @@ pub struct MixedGeometryBuilder {
     ///
     /// The idea is that always adding multi-geometries will make it easier to downcast later.
     pub(crate) prefer_multi: bool,
+
+    /// The number of nulls that have been deferred and are still to be written.
+    ///
+    /// Nulls are pushed to the same child array as the valid geometries so that the array can be
+    /// easily downcasted later. Nulls pushed before any valid geometry are deferred until we
+    /// know which child array to push them to.
+    deferred_nulls: usize,
+
+    /// Which geometries are valid, in the order they were pushed.
+    ///
+    /// The union array has no validity of its own, so this is only used to report
+    /// [`GeometryArrayBuilder::nulls`].
+    validity: NullBufferBuilder,
 }
 
 impl<'a> MixedGeometryBuilder {
@@ -71,6 +85,7 @@ impl<'a> MixedGeometryBuilder {
         )
     }
 
+    /// Creates a new empty [`MixedGeometryBuilder`] with the provided options.
     pub fn new_with_options(
         dim: Dimension,
         coord_type: CoordType,
@@ -91,6 +106,7 @@ impl<'a> MixedGeometryBuilder {
         )
     }
 
+    /// Creates a new [`MixedGeometryBuilder`] with the provided capacity and options.
     pub fn with_capacity_and_options(
         dim: Dimension,
         capacity: MixedCapacity,
@@ -98,6 +114,7 @@ impl<'a> MixedGeometryBuilder {
         metadata: Arc<ArrayMetadata>,
         prefer_multi: bool,
     ) -> Self {
+        let validity = NullBufferBuilder::new(capacity.total_num_geoms());
         // Don't store array metadata on child arrays
         Self {
             metadata,
@@ -141,9 +158,14 @@ impl<'a> MixedGeometryBuilder {
             ),
             offsets: vec![],
             prefer_multi,
+            deferred_nulls: 0,
+            validity,
         }
     }
 
+    /// Reserves capacity for at least `capacity` more geometries.
+    ///
+    /// The collection may reserve more space to speculatively avoid frequent reallocations.
     pub fn reserve(&mut self, capacity: MixedCapacity) {
         let total_num_geoms = capacity.total_num_geoms();
         self.types.reserve(total_num_geoms);
@@ -156,6 +178,7 @@ impl<'a> MixedGeometryBuilder {
         self.multi_polygons.reserve(capacity.multi_polygon);
     }
 
+    /// Reserves the minimum capacity for at least `capacity` more geometries.
     pub fn reserve_exact(&mut self, capacity: MixedCapacity) {
         let total_num_geoms = capacity.total_num_geoms();
         self.types.reserve_exact(total_num_geoms);
@@ -198,10 +221,12 @@ impl<'a> MixedGeometryBuilder {
     //     })
     // }
 
+    /// Consume the builder and convert to an immutable [`MixedGeometryArray`]
     pub fn finish(self) -> MixedGeometryArray {
         self.into()
     }
 
+    /// Creates a new builder with a capacity inferred by the provided iterator.
     pub fn with_capacity_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
         dim: Dimension,
@@ -215,6 +240,8 @@ impl<'a> MixedGeometryBuilder {
         )
     }
 
+    /// Creates a new builder with the provided options and a capacity inferred by the provided
+    /// iterator.
     pub fn with_capacity_and_options_from_iter(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
         dim: Dimension,
@@ -232,6 +259,8 @@ impl<'a> MixedGeometryBuilder {
         ))
     }
 
+    /// Reserve more space in the underlying buffers with the capacity inferred from the provided
+    /// geometries.
     pub fn reserve_from_iter(
         &mut self,
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
@@ -241,6 +270,8 @@ impl<'a> MixedGeometryBuilder {
         Ok(())
     }
 
+    /// Reserve more space in the underlying buffers with the capacity inferred from the provided
+    /// geometries.
     pub fn reserve_exact_from_iter(
         &mut self,
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
@@ -256,10 +287,18 @@ impl<'a> MixedGeometryBuilder {
     /// array. Otherwise, it will be stored in the `PointBuilder` child array.
     #[inline]
     pub fn push_point(&mut self, value: Option<&impl PointTrait<T = f64>>) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
         if self.prefer_multi {
+            self.flush_deferred_nulls(4);
             self.add_multi_point_type();
             self.multi_points.push_point(value)
         } else {
+            self.flush_deferred_nulls(1);
             self.add_point_type();
             self.points.push_point(value);
             Ok(())
@@ -269,10 +308,7 @@ impl<'a> MixedGeometryBuilder {
     #[inline]
     fn add_point_type(&mut self) {
         self.offsets.push(self.points.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(1),
-            Dimension::XYZ => self.types.push(11),
-        }
+        self.types.push(1 + 10 * self.dim.order() as i8);
     }
 
     /// Add a new LineString to the end of this array.
@@ -288,10 +324,18 @@ impl<'a> MixedGeometryBuilder {
         &mut self,
         value: Option<&impl LineStringTrait<T = f64>>,
     ) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
         if self.prefer_multi {
+            self.flush_deferred_nulls(5);
             self.add_multi_line_string_type();
             self.multi_line_strings.push_line_string(value)
         } else {
+            self.flush_deferred_nulls(2);
             self.add_line_string_type();
             self.line_strings.push_line_string(value)
         }
@@ -301,10 +345,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_line_string_type(&mut self) {
         self.offsets
             .push(self.line_strings.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(2),
-            Dimension::XYZ => self.types.push(12),
-        }
+        self.types.push(2 + 10 * self.dim.order() as i8);
     }
 
     /// Add a new Polygon to the end of this array.
@@ -317,22 +358,54 @@ impl<'a> MixedGeometryBuilder {
     /// This function errors iff the new last item is larger than what O supports.
     #[inline]
     pub fn push_polygon(&mut self, value: Option<&impl PolygonTrait<T = f64>>) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
         if self.prefer_multi {
+            self.flush_deferred_nulls(6);
             self.add_multi_polygon_type();
             self.multi_polygons.push_polygon(value)
         } else {
+            self.flush_deferred_nulls(3);
             self.add_polygon_type();
             self.polygons.push_polygon(value)
         }
     }
 
+    /// Add a new Rect to the end of this array, as a Polygon.
+    ///
+    /// If `self.prefer_multi` is `true`, it will be stored in the `MultiPolygonBuilder` child
+    /// array. Otherwise, it will be stored in the `PolygonBuilder` child array.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
+    #[inline]
+    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = f64>>) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
+        if self.prefer_multi {
+            self.flush_deferred_nulls(6);
+            self.add_multi_polygon_type();
+            self.multi_polygons.push_rect(value)
+        } else {
+            self.flush_deferred_nulls(3);
+            self.add_polygon_type();
+            self.polygons.push_rect(value)
+        }
+    }
+
     #[inline]
     fn add_polygon_type(&mut self) {
         self.offsets.push(self.polygons.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(3),
-            Dimension::XYZ => self.types.push(13),
-        }
+        self.types.push(3 + 10 * self.dim.order() as i8);
     }
 
     /// Add a new MultiPoint to the end of this array.
@@ -345,6 +418,13 @@ impl<'a> MixedGeometryBuilder {
         &mut self,
         value: Option<&impl MultiPointTrait<T = f64>>,
     ) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
+        self.flush_deferred_nulls(4);
         self.add_multi_point_type();
         self.multi_points.push_multi_point(value)
     }
@@ -353,10 +433,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_multi_point_type(&mut self) {
         self.offsets
             .push(self.multi_points.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(4),
-            Dimension::XYZ => self.types.push(14),
-        }
+        self.types.push(4 + 10 * self.dim.order() as i8);
     }
 
     /// Add a new MultiLineString to the end of this array.
@@ -369,6 +446,13 @@ impl<'a> MixedGeometryBuilder {
         &mut self,
         value: Option<&impl MultiLineStringTrait<T = f64>>,
     ) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
+        self.flush_deferred_nulls(5);
         self.add_multi_line_string_type();
         self.multi_line_strings.push_multi_line_string(value)
     }
@@ -377,10 +461,7 @@ impl<'a> MixedGeometryBuilder {
     fn add_multi_line_string_type(&mut self) {
         self.offsets
             .push(self.multi_line_strings.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(5),
-            Dimension::XYZ => self.types.push(15),
-        }
+        self.types.push(5 + 10 * self.dim.order() as i8);
     }
 
     /// Add a new MultiPolygon to the end of this array.
@@ -393,6 +474,13 @@ impl<'a> MixedGeometryBuilder {
         &mut self,
         value: Option<&impl MultiPolygonTrait<T = f64>>,
     ) -> Result<()> {
+        if value.is_none() {
+            self.push_null();
+            return Ok(());
+        }
+        self.validity.append_non_null();
+
+        self.flush_deferred_nulls(6);
         self.add_multi_polygon_type();
         self.multi_polygons.push_multi_polygon(value)
     }
@@ -401,12 +489,14 @@ impl<'a> MixedGeometryBuilder {
     fn add_multi_polygon_type(&mut self) {
         self.offsets
             .push(self.multi_polygons.len().try_into().unwrap());
-        match self.dim {
-            Dimension::XY => self.types.push(6),
-            Dimension::XYZ => self.types.push(16),
-        }
+        self.types.push(6 + 10 * self.dim.order() as i8);
     }
 
+    /// Add a new geometry to the end of this array.
+    ///
+    /// # Errors
+    ///
+    /// This function errors iff the new last item is larger than what O supports.
     #[inline]
     pub fn push_geometry(&mut self, value: Option<&'a impl GeometryTrait<T = f64>>) -> Result<()> {
         use geo_traits::GeometryType::*;
@@ -434,7 +524,13 @@ impl<'a> MixedGeometryBuilder {
                         ));
                     }
                 }
-                Rect(_) | Triangle(_) | Line(_) => todo!(),
+                Rect(g) => self.push_rect(Some(g))?,
+                Triangle(_) | Line(_) => {
+                    return Err(GeoArrowError::General(
+                        "Triangle and Line geometries are not supported in a mixed array"
+                            .to_string(),
+                    ))
+                }
             };
         } else {
             self.push_null();
@@ -442,9 +538,69 @@ impl<'a> MixedGeometryBuilder {
         Ok(())
     }
 
+    /// Push a null to this builder.
+    ///
+    /// Nulls will be pushed to one of the underlying non-empty arrays, to simplify downcasting.
     #[inline]
     pub fn push_null(&mut self) {
-        todo!("push null geometry")
+        self.validity.append_null();
+        // Note that we must **also** call `add_*_type` so that the offsets are correct to point
+        // the union array to the null.
+        if !self.points.is_empty() {
+            self.add_point_type();
+            self.points.push_null();
+        } else if !self.line_strings.is_empty() {
+            self.add_line_string_type();
+            self.line_strings.push_null();
+        } else if !self.polygons.is_empty() {
+            self.add_polygon_type();
+            self.polygons.push_null();
+        } else if !self.multi_points.is_empty() {
+            self.add_multi_point_type();
+            self.multi_points.push_null();
+        } else if !self.multi_line_strings.is_empty() {
+            self.add_multi_line_string_type();
+            self.multi_line_strings.push_null();
+        } else if !self.multi_polygons.is_empty() {
+            self.add_multi_polygon_type();
+            self.multi_polygons.push_null();
+        } else {
+            self.deferred_nulls += 1;
+        }
+    }
+
+    /// Write any deferred nulls to the child array with the given geometry type id.
+    fn flush_deferred_nulls(&mut self, geom_type: i8) {
+        for _ in 0..self.deferred_nulls {
+            match geom_type {
+                1 => {
+                    self.add_point_type();
+                    self.points.push_null();
+                }
+                2 => {
+                    self.add_line_string_type();
+                    self.line_strings.push_null();
+                }
+                3 => {
+                    self.add_polygon_type();
+                    self.polygons.push_null();
+                }
+                4 => {
+                    self.add_multi_point_type();
+                    self.multi_points.push_null();
+                }
+                5 => {
+                    self.add_multi_line_string_type();
+                    self.multi_line_strings.push_null();
+                }
+                6 => {
+                    self.add_multi_polygon_type();
+                    self.multi_polygons.push_null();
+                }
+                _ => unreachable!(),
+            }
+        }
+        self.deferred_nulls = 0;
     }
 
     /// Extend this builder with the given geometries
@@ -521,12 +677,19 @@ impl IntoArrow for MixedGeometryBuilder {
     type ArrowArray = UnionArray;
 
     fn into_arrow(self) -> Self::ArrowArray {
-        todo!()
+        self.finish().into_arrow()
     }
 }
 
 impl From<MixedGeometryBuilder> for MixedGeometryArray {
-    fn from(other: MixedGeometryBuilder) -> Self {
+    fn from(mut other: MixedGeometryBuilder) -> Self {
+        // If only nulls were pushed, they're still deferred
+        if other.prefer_multi {
+            other.flush_deferred_nulls(4);
+        } else {
+            other.flush_deferred_nulls(1);
+        }
+
         Self::new(
             other.types.into(),
             other.offsets.into(),
@@ -561,12 +724,6 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for MixedGeometryBuil
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (WKBArray<O>, Dimension)) -> std::result::Result<Self, Self::Error> {
-        assert_eq!(
-            value.nulls().map_or(0, |validity| validity.null_count()),
-            0,
-            "Parsing a WKBArray with null elements not supported",
-        );
-
         let metadata = value.metadata.clone();
         let wkb_objects: Vec<Option<WKB<'_, O>>> = value.iter().collect();
         Self::from_wkb(&wkb_objects, dim, Default::default(), metadata, true)
@@ -578,9 +735,8 @@ impl GeometryArrayBuilder for MixedGeometryBuilder {
         self.types.len()
     }
 
-    fn nulls(&self) -> &arrow_buffer::NullBufferBuilder {
-        // Take this method off trait
-        todo!()
+    fn nulls(&self) -> &NullBufferBuilder {
+        &self.validity
     }
 
     fn new(dim: Dimension) -> Self {
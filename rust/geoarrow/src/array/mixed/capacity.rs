@@ -5,7 +5,7 @@ use crate::array::multilinestring::MultiLineStringCapacity;
 use crate::array::multipoint::MultiPointCapacity;
 use crate::array::multipolygon::MultiPolygonCapacity;
 use crate::array::polygon::PolygonCapacity;
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 use geo_traits::*;
 
 /// A counter for the buffer sizes of a [`MixedGeometryArray`][crate::array::MixedGeometryArray].
@@ -64,6 +64,7 @@ impl MixedCapacity {
             && self.multi_polygon.is_empty()
     }
 
+    /// The total number of geometries across all child arrays.
     pub fn total_num_geoms(&self) -> usize {
         let mut total = 0;
         total += self.point;
@@ -75,30 +76,37 @@ impl MixedCapacity {
         total
     }
 
+    /// The capacity of the point child array.
     pub fn point_capacity(&self) -> usize {
         self.point
     }
 
+    /// The capacity of the line string child array.
     pub fn line_string_capacity(&self) -> LineStringCapacity {
         self.line_string
     }
 
+    /// The capacity of the polygon child array.
     pub fn polygon_capacity(&self) -> PolygonCapacity {
         self.polygon
     }
 
+    /// The capacity of the multi point child array.
     pub fn multi_point_capacity(&self) -> MultiPointCapacity {
         self.multi_point
     }
 
+    /// The capacity of the multi line string child array.
     pub fn multi_line_string_capacity(&self) -> MultiLineStringCapacity {
         self.multi_line_string
     }
 
+    /// The capacity of the multi polygon child array.
     pub fn multi_polygon_capacity(&self) -> MultiPolygonCapacity {
         self.multi_polygon
     }
 
+    /// Whether this capacity only holds geometries that can be stored in a point array.
     pub fn point_compatible(&self) -> bool {
         self.line_string.is_empty()
             && self.polygon.is_empty()
@@ -107,6 +115,7 @@ impl MixedCapacity {
             && self.multi_polygon.is_empty()
     }
 
+    /// Whether this capacity only holds geometries that can be stored in a line string array.
     pub fn line_string_compatible(&self) -> bool {
         self.point == 0
             && self.polygon.is_empty()
@@ -115,6 +124,7 @@ impl MixedCapacity {
             && self.multi_polygon.is_empty()
     }
 
+    /// Whether this capacity only holds geometries that can be stored in a polygon array.
     pub fn polygon_compatible(&self) -> bool {
         self.point == 0
             && self.line_string.is_empty()
@@ -123,6 +133,7 @@ impl MixedCapacity {
             && self.multi_polygon.is_empty()
     }
 
+    /// Whether this capacity only holds geometries that can be stored in a multi point array.
     pub fn multi_point_compatible(&self) -> bool {
         self.line_string.is_empty()
             && self.polygon.is_empty()
@@ -130,6 +141,8 @@ impl MixedCapacity {
             && self.multi_polygon.is_empty()
     }
 
+    /// Whether this capacity only holds geometries that can be stored in a multi line string
+    /// array.
     pub fn multi_line_string_compatible(&self) -> bool {
         self.point == 0
             && self.polygon.is_empty()
@@ -137,6 +150,7 @@ impl MixedCapacity {
             && self.multi_polygon.is_empty()
     }
 
+    /// Whether this capacity only holds geometries that can be stored in a multi polygon array.
     pub fn multi_polygon_compatible(&self) -> bool {
         self.point == 0
             && self.line_string.is_empty()
@@ -144,41 +158,52 @@ impl MixedCapacity {
             && self.multi_line_string.is_empty()
     }
 
+    /// Add the capacity of a point
     #[inline]
     pub fn add_point(&mut self) {
         self.point += 1;
     }
 
+    /// Add the capacity of the given LineString
     #[inline]
     pub fn add_line_string(&mut self, line_string: Option<&impl LineStringTrait>) {
         self.line_string.add_line_string(line_string);
     }
 
+    /// Add the capacity of the given Polygon
     #[inline]
     pub fn add_polygon(&mut self, polygon: Option<&impl PolygonTrait>) {
         self.polygon.add_polygon(polygon);
     }
 
+    /// Add the capacity of the given Rect
+    #[inline]
+    pub fn add_rect(&mut self, rect: Option<&impl RectTrait>) {
+        self.polygon.add_rect(rect);
+    }
+
+    /// Add the capacity of the given MultiPoint
     #[inline]
     pub fn add_multi_point(&mut self, multi_point: Option<&impl MultiPointTrait>) {
         self.multi_point.add_multi_point(multi_point);
     }
 
+    /// Add the capacity of the given MultiLineString
     #[inline]
     pub fn add_multi_line_string(&mut self, multi_line_string: Option<&impl MultiLineStringTrait>) {
         self.multi_line_string
             .add_multi_line_string(multi_line_string);
     }
 
+    /// Add the capacity of the given MultiPolygon
     #[inline]
     pub fn add_multi_polygon(&mut self, multi_polygon: Option<&impl MultiPolygonTrait>) {
         self.multi_polygon.add_multi_polygon(multi_polygon);
     }
 
+    /// Add the capacity of the given Geometry
     #[inline]
     pub fn add_geometry(&mut self, geom: Option<&impl GeometryTrait>) -> Result<()> {
-        // TODO: what to do about null geometries? We don't know which type they have
-        assert!(geom.is_some());
         if let Some(geom) = geom {
             match geom.as_type() {
                 geo_traits::GeometryType::Point(_) => self.add_point(),
@@ -190,12 +215,23 @@ impl MixedCapacity {
                 geo_traits::GeometryType::GeometryCollection(_) => {
                     panic!("nested geometry collections not supported")
                 }
-                _ => todo!(), // geo_traits::GeometryType::Rect(_) => todo!(),
+                geo_traits::GeometryType::Rect(g) => self.add_rect(Some(g)),
+                geo_traits::GeometryType::Triangle(_) | geo_traits::GeometryType::Line(_) => {
+                    return Err(GeoArrowError::General(
+                        "Triangle and Line geometries are not supported in a mixed array"
+                            .to_string(),
+                    ))
+                }
             };
+        } else {
+            // We don't know which child array a null will be stored in, so we count it as a point,
+            // which is the cheapest to store.
+            self.add_point();
         };
         Ok(())
     }
 
+    /// Construct a new counter pre-filled with the given geometries
     pub fn from_geometries<'a>(
         geoms: impl Iterator<Item = Option<&'a (impl GeometryTrait + 'a)>>,
     ) -> Result<Self> {
@@ -206,6 +242,7 @@ impl MixedCapacity {
         Ok(counter)
     }
 
+    /// Construct a new counter pre-filled with the given geometries
     pub fn from_owned_geometries<'a>(
         geoms: impl Iterator<Item = Option<(impl GeometryTrait + 'a)>>,
     ) -> Result<Self> {
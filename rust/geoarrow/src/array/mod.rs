@@ -19,10 +19,16 @@
 //! - [`MultiPointArray`]
 //! - [`MultiLineStringArray`]
 //! - [`MultiPolygonArray`]
+//! - [`MixedGeometryArray`]
 //! - [`GeometryArray`]
 //! - [`GeometryCollectionArray`]
 //! - [`RectArray`]
 //!
+//! Every array type with list offsets is generic over its offset size: e.g.
+//! [`GenericLineStringArray`] has the aliases [`LineStringArray`] with `i32` offsets and
+//! [`LargeLineStringArray`] with `i64` offsets, which correspond to Arrow `List` and `LargeList`
+//! storage respectively.
+//!
 //! ## Builders
 //!
 //! Builders are designed to make it easier
@@ -36,6 +42,7 @@
 //! - [`MultiPointBuilder`]
 //! - [`MultiLineStringBuilder`]
 //! - [`MultiPolygonBuilder`]
+//! - [`MixedGeometryBuilder`]
 //! - [`GeometryBuilder`]
 //! - [`GeometryCollectionBuilder`]
 //! - [`RectBuilder`]
@@ -60,19 +67,39 @@ pub use coord::{
     InterleavedCoordBufferBuilder, SeparatedCoordBuffer, SeparatedCoordBufferBuilder,
 };
 pub use dynamic::{NativeArrayDyn, SerializedArrayDyn};
-pub use geometry::{GeometryArray, GeometryBuilder, GeometryCapacity};
+pub use geometry::{
+    GenericGeometryArray, GeometryArray, GeometryBuilder, GeometryCapacity, LargeGeometryArray,
+};
 pub use geometrycollection::{
-    GeometryCollectionArray, GeometryCollectionBuilder, GeometryCollectionCapacity,
+    GenericGeometryCollectionArray, GeometryCollectionArray, GeometryCollectionBuilder,
+    GeometryCollectionCapacity, LargeGeometryCollectionArray,
+};
+pub use linestring::{
+    GenericLineStringArray, LargeLineStringArray, LineStringArray, LineStringBuilder,
+    LineStringCapacity,
+};
+pub use mixed::{
+    GenericMixedGeometryArray, LargeMixedGeometryArray, MixedCapacity, MixedGeometryArray,
+    MixedGeometryBuilder,
+};
+pub use multilinestring::{
+    GenericMultiLineStringArray, LargeMultiLineStringArray, MultiLineStringArray,
+    MultiLineStringBuilder, MultiLineStringCapacity,
+};
+pub use multipoint::{
+    GenericMultiPointArray, LargeMultiPointArray, MultiPointArray, MultiPointBuilder,
+    MultiPointCapacity,
+};
+pub use multipolygon::{
+    GenericMultiPolygonArray, LargeMultiPolygonArray, MultiPolygonArray, MultiPolygonBuilder,
+    MultiPolygonCapacity,
 };
-pub use linestring::{LineStringArray, LineStringBuilder, LineStringCapacity};
-// Don't expose in the public API. Prefer GeometryArray
-pub(crate) use mixed::{MixedCapacity, MixedGeometryArray, MixedGeometryBuilder};
-pub use multilinestring::{MultiLineStringArray, MultiLineStringBuilder, MultiLineStringCapacity};
-pub use multipoint::{MultiPointArray, MultiPointBuilder, MultiPointCapacity};
-pub use multipolygon::{MultiPolygonArray, MultiPolygonBuilder, MultiPolygonCapacity};
 pub use point::{PointArray, PointBuilder};
-pub use polygon::{PolygonArray, PolygonBuilder, PolygonCapacity};
+pub use polygon::{
+    GenericPolygonArray, LargePolygonArray, PolygonArray, PolygonBuilder, PolygonCapacity,
+};
 pub use rect::{RectArray, RectBuilder};
+pub use validate::ValidationLevel;
 pub use wkt::WKTArray;
 
 pub use crate::trait_::{ArrayBase, NativeArray, SerializedArray};
@@ -94,6 +121,7 @@ pub(crate) mod point;
 pub(crate) mod polygon;
 pub(crate) mod rect;
 pub(crate) mod util;
+pub(crate) mod validate;
 pub(crate) mod wkt;
 
 use std::sync::Arc;
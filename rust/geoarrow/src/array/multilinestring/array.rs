@@ -3,10 +3,15 @@ use std::sync::Arc;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multilinestring::MultiLineStringCapacity;
-use crate::array::util::{offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, try_cast_offsets, OffsetBufferUtils,
+};
+use crate::array::validate::{
+    invalid_row, owning_row, validate_line_string, validate_nulls, validate_offsets,
+};
 use crate::array::{
-    CoordBuffer, CoordType, GeometryCollectionArray, LineStringArray, MixedGeometryArray,
-    PolygonArray, WKBArray,
+    CoordBuffer, CoordType, GenericLineStringArray, GenericPolygonArray, GeometryCollectionArray,
+    MixedGeometryArray, WKBArray,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -25,9 +30,12 @@ use super::MultiLineStringBuilder;
 ///
 /// This is semantically equivalent to `Vec<Option<MultiLineString>>` due to the internal validity
 /// bitmap.
+///
+/// The offset type `O` is `i32` for [`MultiLineStringArray`] and `i64` for
+/// [`LargeMultiLineStringArray`].
 #[derive(Debug, Clone)]
-pub struct MultiLineStringArray {
-    // Always NativeType::MultiLineString
+pub struct GenericMultiLineStringArray<O: OffsetSizeTrait> {
+    // Always NativeType::MultiLineString or NativeType::LargeMultiLineString
     data_type: NativeType,
 
     pub(crate) metadata: Arc<ArrayMetadata>,
@@ -35,19 +43,25 @@ pub struct MultiLineStringArray {
     pub(crate) coords: CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
-    pub(crate) geom_offsets: OffsetBuffer<i32>,
+    pub(crate) geom_offsets: OffsetBuffer<O>,
 
     /// Offsets into the coordinate array where each ring starts
-    pub(crate) ring_offsets: OffsetBuffer<i32>,
+    pub(crate) ring_offsets: OffsetBuffer<O>,
 
     /// Validity bitmap
     pub(crate) validity: Option<NullBuffer>,
 }
 
-pub(super) fn check(
+/// An array of MultiLineString geometries with `i32` offsets.
+pub type MultiLineStringArray = GenericMultiLineStringArray<i32>;
+
+/// An array of MultiLineString geometries with `i64` offsets.
+pub type LargeMultiLineStringArray = GenericMultiLineStringArray<i64>;
+
+pub(super) fn check<O: OffsetSizeTrait>(
     coords: &CoordBuffer,
-    geom_offsets: &OffsetBuffer<i32>,
-    ring_offsets: &OffsetBuffer<i32>,
+    geom_offsets: &OffsetBuffer<O>,
+    ring_offsets: &OffsetBuffer<O>,
     validity_len: Option<usize>,
 ) -> Result<()> {
     if validity_len.is_some_and(|len| len != geom_offsets.len_proxy()) {
@@ -56,13 +70,13 @@ pub(super) fn check(
         ));
     }
 
-    if *ring_offsets.last() as usize != coords.len() {
+    if ring_offsets.last().as_usize() != coords.len() {
         return Err(GeoArrowError::General(
             "largest ring offset must match coords length".to_string(),
         ));
     }
 
-    if *geom_offsets.last() as usize != ring_offsets.len_proxy() {
+    if geom_offsets.last().as_usize() != ring_offsets.len_proxy() {
         return Err(GeoArrowError::General(
             "largest geometry offset must match ring offsets length".to_string(),
         ));
@@ -71,7 +85,7 @@ pub(super) fn check(
     Ok(())
 }
 
-impl MultiLineStringArray {
+impl<O: OffsetSizeTrait> GenericMultiLineStringArray<O> {
     /// Create a new MultiLineStringArray from parts
     ///
     /// # Implementation
@@ -85,8 +99,8 @@ impl MultiLineStringArray {
     /// - if the largest geometry offset does not match the size of ring offsets
     pub fn new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
-        ring_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -106,8 +120,8 @@ impl MultiLineStringArray {
     /// - if the largest geometry offset does not match the size of ring offsets
     pub fn try_new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
-        ring_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Result<Self> {
@@ -117,7 +131,11 @@ impl MultiLineStringArray {
             &ring_offsets,
             validity.as_ref().map(|v| v.len()),
         )?;
-        let data_type = NativeType::MultiLineString(coords.coord_type(), coords.dim());
+        let data_type = if O::IS_LARGE {
+            NativeType::LargeMultiLineString(coords.coord_type(), coords.dim())
+        } else {
+            NativeType::MultiLineString(coords.coord_type(), coords.dim())
+        };
         Ok(Self {
             data_type,
             coords,
@@ -133,7 +151,8 @@ impl MultiLineStringArray {
     }
 
     fn linestrings_field(&self) -> Arc<Field> {
-        Field::new_list("linestrings", self.vertices_field(), false).into()
+        let data_type = GenericListArray::<O>::DATA_TYPE_CONSTRUCTOR(self.vertices_field());
+        Field::new("linestrings", data_type, false).into()
     }
 
     /// Access the underlying coordinate buffer
@@ -142,20 +161,20 @@ impl MultiLineStringArray {
     }
 
     /// Access the underlying geometry offsets buffer
-    pub fn geom_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn geom_offsets(&self) -> &OffsetBuffer<O> {
         &self.geom_offsets
     }
 
     /// Access the underlying ring offsets buffer
-    pub fn ring_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn ring_offsets(&self) -> &OffsetBuffer<O> {
         &self.ring_offsets
     }
 
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> MultiLineStringCapacity {
         MultiLineStringCapacity::new(
-            *self.ring_offsets.last() as usize,
-            *self.geom_offsets.last() as usize,
+            self.ring_offsets.last().as_usize(),
+            self.geom_offsets.last().as_usize(),
             self.len(),
         )
     }
@@ -204,7 +223,7 @@ impl MultiLineStringArray {
     }
 }
 
-impl ArrayBase for MultiLineStringArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericMultiLineStringArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -248,7 +267,7 @@ impl ArrayBase for MultiLineStringArray {
     }
 }
 
-impl NativeArray for MultiLineStringArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericMultiLineStringArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -274,9 +293,38 @@ impl NativeArray for MultiLineStringArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("MultiLineString", self.validity.as_ref(), self.len())?;
+        validate_offsets(&self.geom_offsets, self.ring_offsets.len_proxy()).map_err(|row| {
+            invalid_row("MultiLineString", row, "geometry offsets are out of bounds")
+        })?;
+        validate_offsets(&self.ring_offsets, self.coords.len()).map_err(|line_idx| {
+            let row = owning_row(&self.geom_offsets, line_idx);
+            invalid_row(
+                "MultiLineString",
+                row,
+                "line string offsets are out of bounds",
+            )
+        })
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        for row in 0..self.len() {
+            if self.is_valid(row) {
+                let (start, end) = self.geom_offsets.start_end(row);
+                for line_idx in start..end {
+                    validate_line_string(&self.ring_offsets, line_idx)
+                        .map_err(|reason| invalid_row("MultiLineString", row, reason))?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
-impl GeometryArraySelfMethods for MultiLineStringArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericMultiLineStringArray<O> {
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(
@@ -311,7 +359,9 @@ impl NativeGeometryAccessor for MultiLineStringArray {
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MultiLineStringArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericMultiLineStringArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
@@ -322,8 +372,8 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MultiLineStringArray
     }
 }
 
-impl<'a> ArrayAccessor<'a> for MultiLineStringArray {
-    type Item = MultiLineString<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericMultiLineStringArray<O> {
+    type Item = MultiLineString<'a, O>;
     type ItemGeo = geo::MultiLineString;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
@@ -331,8 +381,8 @@ impl<'a> ArrayAccessor<'a> for MultiLineStringArray {
     }
 }
 
-impl IntoArrow for MultiLineStringArray {
-    type ArrowArray = GenericListArray<i32>;
+impl<O: OffsetSizeTrait> IntoArrow for GenericMultiLineStringArray<O> {
+    type ArrowArray = GenericListArray<O>;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let vertices_field = self.vertices_field();
@@ -349,53 +399,32 @@ impl IntoArrow for MultiLineStringArray {
     }
 }
 
-impl TryFrom<(&GenericListArray<i32>, Dimension)> for MultiLineStringArray {
-    type Error = GeoArrowError;
-
-    fn try_from((geom_array, dim): (&GenericListArray<i32>, Dimension)) -> Result<Self> {
-        let geom_offsets = geom_array.offsets();
-        let validity = geom_array.nulls();
-
-        let rings_dyn_array = geom_array.values();
-        let rings_array = rings_dyn_array.as_list::<i32>();
-
-        let ring_offsets = rings_array.offsets();
-        let coords = CoordBuffer::from_arrow(rings_array.values().as_ref(), dim)?;
-
-        Ok(Self::new(
-            coords,
-            geom_offsets.clone(),
-            ring_offsets.clone(),
-            validity.cloned(),
-            Default::default(),
-        ))
-    }
-}
-
-impl TryFrom<(&GenericListArray<i64>, Dimension)> for MultiLineStringArray {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> TryFrom<(&GenericListArray<A>, Dimension)>
+    for GenericMultiLineStringArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from((geom_array, dim): (&GenericListArray<i64>, Dimension)) -> Result<Self> {
-        let geom_offsets = offsets_buffer_i64_to_i32(geom_array.offsets())?;
+    fn try_from((geom_array, dim): (&GenericListArray<A>, Dimension)) -> Result<Self> {
+        let geom_offsets = try_cast_offsets(geom_array.offsets())?;
         let validity = geom_array.nulls();
 
         let rings_dyn_array = geom_array.values();
-        let rings_array = rings_dyn_array.as_list::<i64>();
+        let rings_array = rings_dyn_array.as_list::<A>();
 
-        let ring_offsets = offsets_buffer_i64_to_i32(rings_array.offsets())?;
+        let ring_offsets = try_cast_offsets(rings_array.offsets())?;
         let coords = CoordBuffer::from_arrow(rings_array.values().as_ref(), dim)?;
 
         Ok(Self::new(
             coords,
-            geom_offsets.clone(),
-            ring_offsets.clone(),
+            geom_offsets,
+            ring_offsets,
             validity.cloned(),
             Default::default(),
         ))
     }
 }
 
-impl TryFrom<(&dyn Array, Dimension)> for MultiLineStringArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericMultiLineStringArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -416,7 +445,7 @@ impl TryFrom<(&dyn Array, Dimension)> for MultiLineStringArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for MultiLineStringArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericMultiLineStringArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -446,8 +475,8 @@ impl<G: MultiLineStringTrait<T = f64>> From<(&[G], Dimension)> for MultiLineStri
 
 /// Polygon and MultiLineString have the same layout, so enable conversions between the two to
 /// change the semantic type
-impl From<MultiLineStringArray> for PolygonArray {
-    fn from(value: MultiLineStringArray) -> Self {
+impl<O: OffsetSizeTrait> From<GenericMultiLineStringArray<O>> for GenericPolygonArray<O> {
+    fn from(value: GenericMultiLineStringArray<O>) -> Self {
         Self::new(
             value.coords,
             value.geom_offsets,
@@ -467,10 +496,10 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for MultiLineStringAr
     }
 }
 
-impl From<LineStringArray> for MultiLineStringArray {
-    fn from(value: LineStringArray) -> Self {
+impl<O: OffsetSizeTrait> From<GenericLineStringArray<O>> for GenericMultiLineStringArray<O> {
+    fn from(value: GenericLineStringArray<O>) -> Self {
+        let geom_offsets = OffsetBuffer::from_lengths(vec![1; value.len()]);
         let coords = value.coords;
-        let geom_offsets = OffsetBuffer::from_lengths(vec![1; coords.len()]);
         let ring_offsets = value.geom_offsets;
         let validity = value.validity;
         Self::new(coords, geom_offsets, ring_offsets, validity, value.metadata)
@@ -484,7 +513,40 @@ impl Default for MultiLineStringArray {
     }
 }
 
-impl PartialEq for MultiLineStringArray {
+/// Default to an empty array
+impl Default for LargeMultiLineStringArray {
+    fn default() -> Self {
+        MultiLineStringArray::default().into()
+    }
+}
+
+impl From<MultiLineStringArray> for LargeMultiLineStringArray {
+    fn from(value: MultiLineStringArray) -> Self {
+        Self::new(
+            value.coords,
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            offsets_buffer_i32_to_i64(&value.ring_offsets),
+            value.validity,
+            value.metadata,
+        )
+    }
+}
+
+impl TryFrom<LargeMultiLineStringArray> for MultiLineStringArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargeMultiLineStringArray) -> Result<Self> {
+        Ok(Self::new(
+            value.coords,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            offsets_buffer_i64_to_i32(&value.ring_offsets)?,
+            value.validity,
+            value.metadata,
+        ))
+    }
+}
+
+impl<O: OffsetSizeTrait> PartialEq for GenericMultiLineStringArray<O> {
     fn eq(&self, other: &Self) -> bool {
         if self.validity != other.validity {
             return false;
@@ -1,7 +1,7 @@
 //! Contains the [`MultiLineStringArray`] and [`MultiLineStringBuilder`] for arrays of
 //! MultiLineString geometries.
 
-pub use array::MultiLineStringArray;
+pub use array::{GenericMultiLineStringArray, LargeMultiLineStringArray, MultiLineStringArray};
 pub use builder::MultiLineStringBuilder;
 pub use capacity::MultiLineStringCapacity;
 
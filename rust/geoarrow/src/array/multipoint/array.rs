@@ -4,9 +4,12 @@ use super::MultiPointBuilder;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipoint::MultiPointCapacity;
-use crate::array::util::{offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, try_cast_offsets, OffsetBufferUtils,
+};
+use crate::array::validate::{invalid_row, validate_nulls, validate_offsets};
 use crate::array::{
-    CoordBuffer, CoordType, GeometryCollectionArray, LineStringArray, MixedGeometryArray,
+    CoordBuffer, CoordType, GenericLineStringArray, GeometryCollectionArray, MixedGeometryArray,
     PointArray, WKBArray,
 };
 use crate::datatypes::{Dimension, NativeType};
@@ -24,9 +27,11 @@ use geo_traits::MultiPointTrait;
 ///
 /// This is semantically equivalent to `Vec<Option<MultiPoint>>` due to the internal validity
 /// bitmap.
+///
+/// The offset type `O` is `i32` for [`MultiPointArray`] and `i64` for [`LargeMultiPointArray`].
 #[derive(Debug, Clone)]
-pub struct MultiPointArray {
-    // Always NativeType::MultiPoint
+pub struct GenericMultiPointArray<O: OffsetSizeTrait> {
+    // Always NativeType::MultiPoint or NativeType::LargeMultiPoint
     data_type: NativeType,
 
     pub(crate) metadata: Arc<ArrayMetadata>,
@@ -34,16 +39,22 @@ pub struct MultiPointArray {
     pub(crate) coords: CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
-    pub(crate) geom_offsets: OffsetBuffer<i32>,
+    pub(crate) geom_offsets: OffsetBuffer<O>,
 
     /// Validity bitmap
     pub(crate) validity: Option<NullBuffer>,
 }
 
-pub(super) fn check(
+/// An array of MultiPoint geometries with `i32` offsets.
+pub type MultiPointArray = GenericMultiPointArray<i32>;
+
+/// An array of MultiPoint geometries with `i64` offsets.
+pub type LargeMultiPointArray = GenericMultiPointArray<i64>;
+
+pub(super) fn check<O: OffsetSizeTrait>(
     coords: &CoordBuffer,
     validity_len: Option<usize>,
-    geom_offsets: &OffsetBuffer<i32>,
+    geom_offsets: &OffsetBuffer<O>,
 ) -> Result<()> {
     if validity_len.is_some_and(|len| len != geom_offsets.len_proxy()) {
         return Err(GeoArrowError::General(
@@ -51,7 +62,7 @@ pub(super) fn check(
         ));
     }
 
-    if *geom_offsets.last() as usize != coords.len() {
+    if geom_offsets.last().as_usize() != coords.len() {
         return Err(GeoArrowError::General(
             "largest geometry offset must match coords length".to_string(),
         ));
@@ -60,7 +71,7 @@ pub(super) fn check(
     Ok(())
 }
 
-impl MultiPointArray {
+impl<O: OffsetSizeTrait> GenericMultiPointArray<O> {
     /// Create a new MultiPointArray from parts
     ///
     /// # Implementation
@@ -73,7 +84,7 @@ impl MultiPointArray {
     /// - if the largest geometry offset does not match the number of coordinates
     pub fn new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -92,12 +103,16 @@ impl MultiPointArray {
     /// - if the geometry offsets do not match the number of coordinates
     pub fn try_new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Result<Self> {
         check(&coords, validity.as_ref().map(|v| v.len()), &geom_offsets)?;
-        let data_type = NativeType::MultiPoint(coords.coord_type(), coords.dim());
+        let data_type = if O::IS_LARGE {
+            NativeType::LargeMultiPoint(coords.coord_type(), coords.dim())
+        } else {
+            NativeType::MultiPoint(coords.coord_type(), coords.dim())
+        };
         Ok(Self {
             data_type,
             coords,
@@ -117,18 +132,18 @@ impl MultiPointArray {
     }
 
     #[allow(dead_code)]
-    pub(crate) fn into_inner(self) -> (CoordBuffer, OffsetBuffer<i32>, Option<NullBuffer>) {
+    pub(crate) fn into_inner(self) -> (CoordBuffer, OffsetBuffer<O>, Option<NullBuffer>) {
         (self.coords, self.geom_offsets, self.validity)
     }
 
     /// Access the underlying geometry offsets buffer
-    pub fn geom_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn geom_offsets(&self) -> &OffsetBuffer<O> {
         &self.geom_offsets
     }
 
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> MultiPointCapacity {
-        MultiPointCapacity::new(*self.geom_offsets.last() as usize, self.len())
+        MultiPointCapacity::new(self.geom_offsets.last().as_usize(), self.len())
     }
 
     /// The number of bytes occupied by this array.
@@ -186,7 +201,7 @@ impl MultiPointArray {
     }
 }
 
-impl ArrayBase for MultiPointArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericMultiPointArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -230,7 +245,7 @@ impl ArrayBase for MultiPointArray {
     }
 }
 
-impl NativeArray for MultiPointArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericMultiPointArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -256,9 +271,19 @@ impl NativeArray for MultiPointArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("MultiPoint", self.validity.as_ref(), self.len())?;
+        validate_offsets(&self.geom_offsets, self.coords.len())
+            .map_err(|row| invalid_row("MultiPoint", row, "geometry offsets are out of bounds"))
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()
+    }
 }
 
-impl GeometryArraySelfMethods for MultiPointArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericMultiPointArray<O> {
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(coords, self.geom_offsets, self.validity, self.metadata)
@@ -281,7 +306,9 @@ impl NativeGeometryAccessor for MultiPointArray {
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MultiPointArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericMultiPointArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
@@ -291,8 +318,8 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MultiPointArray {
     }
 }
 
-impl<'a> ArrayAccessor<'a> for MultiPointArray {
-    type Item = MultiPoint<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericMultiPointArray<O> {
+    type Item = MultiPoint<'a, O>;
     type ItemGeo = geo::MultiPoint;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
@@ -300,8 +327,8 @@ impl<'a> ArrayAccessor<'a> for MultiPointArray {
     }
 }
 
-impl IntoArrow for MultiPointArray {
-    type ArrowArray = GenericListArray<i32>;
+impl<O: OffsetSizeTrait> IntoArrow for GenericMultiPointArray<O> {
+    type ArrowArray = GenericListArray<O>;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let vertices_field = self.vertices_field();
@@ -311,29 +338,14 @@ impl IntoArrow for MultiPointArray {
     }
 }
 
-impl TryFrom<(&GenericListArray<i32>, Dimension)> for MultiPointArray {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> TryFrom<(&GenericListArray<A>, Dimension)>
+    for GenericMultiPointArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from((value, dim): (&GenericListArray<i32>, Dimension)) -> Result<Self> {
+    fn try_from((value, dim): (&GenericListArray<A>, Dimension)) -> Result<Self> {
         let coords = CoordBuffer::from_arrow(value.values().as_ref(), dim)?;
-        let geom_offsets = value.offsets();
-        let validity = value.nulls();
-
-        Ok(Self::new(
-            coords,
-            geom_offsets.clone(),
-            validity.cloned(),
-            Default::default(),
-        ))
-    }
-}
-
-impl TryFrom<(&GenericListArray<i64>, Dimension)> for MultiPointArray {
-    type Error = GeoArrowError;
-
-    fn try_from((value, dim): (&GenericListArray<i64>, Dimension)) -> Result<Self> {
-        let coords = CoordBuffer::from_arrow(value.values().as_ref(), dim)?;
-        let geom_offsets = offsets_buffer_i64_to_i32(value.offsets())?;
+        let geom_offsets = try_cast_offsets(value.offsets())?;
         let validity = value.nulls();
 
         Ok(Self::new(
@@ -345,7 +357,7 @@ impl TryFrom<(&GenericListArray<i64>, Dimension)> for MultiPointArray {
     }
 }
 
-impl TryFrom<(&dyn Array, Dimension)> for MultiPointArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericMultiPointArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -366,7 +378,7 @@ impl TryFrom<(&dyn Array, Dimension)> for MultiPointArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for MultiPointArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericMultiPointArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -405,8 +417,8 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for MultiPointArray {
 
 /// LineString and MultiPoint have the same layout, so enable conversions between the two to change
 /// the semantic type
-impl From<MultiPointArray> for LineStringArray {
-    fn from(value: MultiPointArray) -> Self {
+impl<O: OffsetSizeTrait> From<GenericMultiPointArray<O>> for GenericLineStringArray<O> {
+    fn from(value: GenericMultiPointArray<O>) -> Self {
         Self::new(
             value.coords,
             value.geom_offsets,
@@ -432,7 +444,38 @@ impl Default for MultiPointArray {
     }
 }
 
-impl PartialEq for MultiPointArray {
+/// Default to an empty array
+impl Default for LargeMultiPointArray {
+    fn default() -> Self {
+        MultiPointArray::default().into()
+    }
+}
+
+impl From<MultiPointArray> for LargeMultiPointArray {
+    fn from(value: MultiPointArray) -> Self {
+        Self::new(
+            value.coords,
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            value.validity,
+            value.metadata,
+        )
+    }
+}
+
+impl TryFrom<LargeMultiPointArray> for MultiPointArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargeMultiPointArray) -> Result<Self> {
+        Ok(Self::new(
+            value.coords,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            value.validity,
+            value.metadata,
+        ))
+    }
+}
+
+impl<O: OffsetSizeTrait> PartialEq for GenericMultiPointArray<O> {
     fn eq(&self, other: &Self) -> bool {
         if self.validity != other.validity {
             return false;
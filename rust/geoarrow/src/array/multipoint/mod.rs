@@ -1,7 +1,7 @@
 //! Contains the [`MultiPointArray`] and [`MultiPointBuilder`] for arrays of MultiPoint
 //! geometries.
 
-pub use array::MultiPointArray;
+pub use array::{GenericMultiPointArray, LargeMultiPointArray, MultiPointArray};
 pub use builder::MultiPointBuilder;
 pub use capacity::MultiPointCapacity;
 
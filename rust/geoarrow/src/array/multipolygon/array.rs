@@ -3,9 +3,15 @@ use std::sync::Arc;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::multipolygon::MultiPolygonCapacity;
-use crate::array::util::{offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, try_cast_offsets, OffsetBufferUtils,
+};
+use crate::array::validate::{
+    invalid_row, owning_row, validate_nulls, validate_offsets, validate_ring,
+};
 use crate::array::{
-    CoordBuffer, CoordType, GeometryCollectionArray, MixedGeometryArray, PolygonArray, WKBArray,
+    CoordBuffer, CoordType, GenericPolygonArray, GeometryCollectionArray, MixedGeometryArray,
+    WKBArray,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -25,9 +31,12 @@ use super::MultiPolygonBuilder;
 ///
 /// This is semantically equivalent to `Vec<Option<MultiPolygon>>` due to the internal validity
 /// bitmap.
+///
+/// The offset type `O` is `i32` for [`MultiPolygonArray`] and `i64` for
+/// [`LargeMultiPolygonArray`].
 #[derive(Debug, Clone)]
-pub struct MultiPolygonArray {
-    // Always NativeType::MultiPolygon
+pub struct GenericMultiPolygonArray<O: OffsetSizeTrait> {
+    // Always NativeType::MultiPolygon or NativeType::LargeMultiPolygon
     data_type: NativeType,
 
     pub(crate) metadata: Arc<ArrayMetadata>,
@@ -35,23 +44,29 @@ pub struct MultiPolygonArray {
     pub(crate) coords: CoordBuffer,
 
     /// Offsets into the polygon array where each geometry starts
-    pub(crate) geom_offsets: OffsetBuffer<i32>,
+    pub(crate) geom_offsets: OffsetBuffer<O>,
 
     /// Offsets into the ring array where each polygon starts
-    pub(crate) polygon_offsets: OffsetBuffer<i32>,
+    pub(crate) polygon_offsets: OffsetBuffer<O>,
 
     /// Offsets into the coordinate array where each ring starts
-    pub(crate) ring_offsets: OffsetBuffer<i32>,
+    pub(crate) ring_offsets: OffsetBuffer<O>,
 
     /// Validity bitmap
     pub(crate) validity: Option<NullBuffer>,
 }
 
-pub(super) fn check(
+/// An array of MultiPolygon geometries with `i32` offsets.
+pub type MultiPolygonArray = GenericMultiPolygonArray<i32>;
+
+/// An array of MultiPolygon geometries with `i64` offsets.
+pub type LargeMultiPolygonArray = GenericMultiPolygonArray<i64>;
+
+pub(super) fn check<O: OffsetSizeTrait>(
     coords: &CoordBuffer,
-    geom_offsets: &OffsetBuffer<i32>,
-    polygon_offsets: &OffsetBuffer<i32>,
-    ring_offsets: &OffsetBuffer<i32>,
+    geom_offsets: &OffsetBuffer<O>,
+    polygon_offsets: &OffsetBuffer<O>,
+    ring_offsets: &OffsetBuffer<O>,
     validity_len: Option<usize>,
 ) -> Result<()> {
     if validity_len.is_some_and(|len| len != geom_offsets.len_proxy()) {
@@ -59,19 +74,19 @@ pub(super) fn check(
             "validity mask length must match the number of values".to_string(),
         ));
     }
-    if *ring_offsets.last() as usize != coords.len() {
+    if ring_offsets.last().as_usize() != coords.len() {
         return Err(GeoArrowError::General(
             "largest ring offset must match coords length".to_string(),
         ));
     }
 
-    if *polygon_offsets.last() as usize != ring_offsets.len_proxy() {
+    if polygon_offsets.last().as_usize() != ring_offsets.len_proxy() {
         return Err(GeoArrowError::General(
             "largest polygon offset must match ring offsets length".to_string(),
         ));
     }
 
-    if *geom_offsets.last() as usize != polygon_offsets.len_proxy() {
+    if geom_offsets.last().as_usize() != polygon_offsets.len_proxy() {
         return Err(GeoArrowError::General(
             "largest geometry offset must match polygon offsets length".to_string(),
         ));
@@ -80,7 +95,7 @@ pub(super) fn check(
     Ok(())
 }
 
-impl MultiPolygonArray {
+impl<O: OffsetSizeTrait> GenericMultiPolygonArray<O> {
     /// Create a new MultiPolygonArray from parts
     ///
     /// # Implementation
@@ -95,9 +110,9 @@ impl MultiPolygonArray {
     /// - if the largest geometry offset does not match the size of polygon offsets
     pub fn new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
-        polygon_offsets: OffsetBuffer<i32>,
-        ring_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
+        polygon_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -126,9 +141,9 @@ impl MultiPolygonArray {
     /// - if the largest geometry offset does not match the size of polygon offsets
     pub fn try_new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
-        polygon_offsets: OffsetBuffer<i32>,
-        ring_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
+        polygon_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Result<Self> {
@@ -139,7 +154,11 @@ impl MultiPolygonArray {
             &ring_offsets,
             validity.as_ref().map(|v| v.len()),
         )?;
-        let data_type = NativeType::MultiPolygon(coords.coord_type(), coords.dim());
+        let data_type = if O::IS_LARGE {
+            NativeType::LargeMultiPolygon(coords.coord_type(), coords.dim())
+        } else {
+            NativeType::MultiPolygon(coords.coord_type(), coords.dim())
+        };
         Ok(Self {
             data_type,
             coords,
@@ -157,12 +176,14 @@ impl MultiPolygonArray {
 
     fn rings_field(&self) -> Arc<Field> {
         let name = "rings";
-        Field::new_list(name, self.vertices_field(), false).into()
+        let data_type = GenericListArray::<O>::DATA_TYPE_CONSTRUCTOR(self.vertices_field());
+        Field::new(name, data_type, false).into()
     }
 
     fn polygons_field(&self) -> Arc<Field> {
         let name = "polygons";
-        Field::new_list(name, self.rings_field(), false).into()
+        let data_type = GenericListArray::<O>::DATA_TYPE_CONSTRUCTOR(self.rings_field());
+        Field::new(name, data_type, false).into()
     }
 
     /// Access the underlying coordinate buffer
@@ -175,9 +196,9 @@ impl MultiPolygonArray {
         self,
     ) -> (
         CoordBuffer,
-        OffsetBuffer<i32>,
-        OffsetBuffer<i32>,
-        OffsetBuffer<i32>,
+        OffsetBuffer<O>,
+        OffsetBuffer<O>,
+        OffsetBuffer<O>,
     ) {
         (
             self.coords,
@@ -188,26 +209,26 @@ impl MultiPolygonArray {
     }
 
     /// Access the underlying geometry offsets buffer
-    pub fn geom_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn geom_offsets(&self) -> &OffsetBuffer<O> {
         &self.geom_offsets
     }
 
     /// Access the underlying polygon offsets buffer
-    pub fn polygon_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn polygon_offsets(&self) -> &OffsetBuffer<O> {
         &self.polygon_offsets
     }
 
     /// Access the underlying ring offsets buffer
-    pub fn ring_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn ring_offsets(&self) -> &OffsetBuffer<O> {
         &self.ring_offsets
     }
 
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> MultiPolygonCapacity {
         MultiPolygonCapacity::new(
-            *self.ring_offsets.last() as usize,
-            *self.polygon_offsets.last() as usize,
-            *self.geom_offsets.last() as usize,
+            self.ring_offsets.last().as_usize(),
+            self.polygon_offsets.last().as_usize(),
+            self.geom_offsets.last().as_usize(),
             self.len(),
         )
     }
@@ -258,7 +279,7 @@ impl MultiPolygonArray {
     }
 }
 
-impl ArrayBase for MultiPolygonArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericMultiPolygonArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -302,7 +323,7 @@ impl ArrayBase for MultiPolygonArray {
     }
 }
 
-impl NativeArray for MultiPolygonArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericMultiPolygonArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -328,9 +349,44 @@ impl NativeArray for MultiPolygonArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("MultiPolygon", self.validity.as_ref(), self.len())?;
+        validate_offsets(&self.geom_offsets, self.polygon_offsets.len_proxy()).map_err(|row| {
+            invalid_row("MultiPolygon", row, "geometry offsets are out of bounds")
+        })?;
+        validate_offsets(&self.polygon_offsets, self.ring_offsets.len_proxy()).map_err(
+            |polygon_idx| {
+                let row = owning_row(&self.geom_offsets, polygon_idx);
+                invalid_row("MultiPolygon", row, "polygon offsets are out of bounds")
+            },
+        )?;
+        validate_offsets(&self.ring_offsets, self.coords.len()).map_err(|ring_idx| {
+            let polygon_idx = owning_row(&self.polygon_offsets, ring_idx);
+            let row = owning_row(&self.geom_offsets, polygon_idx);
+            invalid_row("MultiPolygon", row, "ring offsets are out of bounds")
+        })
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        for row in 0..self.len() {
+            if self.is_valid(row) {
+                let (start, end) = self.geom_offsets.start_end(row);
+                for polygon_idx in start..end {
+                    let (ring_start, ring_end) = self.polygon_offsets.start_end(polygon_idx);
+                    for ring_idx in ring_start..ring_end {
+                        validate_ring(&self.coords, &self.ring_offsets, ring_idx)
+                            .map_err(|reason| invalid_row("MultiPolygon", row, reason))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
-impl GeometryArraySelfMethods for MultiPolygonArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericMultiPolygonArray<O> {
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(
@@ -368,7 +424,9 @@ impl NativeGeometryAccessor for MultiPolygonArray {
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MultiPolygonArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericMultiPolygonArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
@@ -384,8 +442,8 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for MultiPolygonArray {
     }
 }
 
-impl<'a> ArrayAccessor<'a> for MultiPolygonArray {
-    type Item = MultiPolygon<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericMultiPolygonArray<O> {
+    type Item = MultiPolygon<'a, O>;
     type ItemGeo = geo::MultiPolygon;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
@@ -399,8 +457,8 @@ impl<'a> ArrayAccessor<'a> for MultiPolygonArray {
     }
 }
 
-impl IntoArrow for MultiPolygonArray {
-    type ArrowArray = GenericListArray<i32>;
+impl<O: OffsetSizeTrait> IntoArrow for GenericMultiPolygonArray<O> {
+    type ArrowArray = GenericListArray<O>;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let vertices_field = self.vertices_field();
@@ -425,49 +483,23 @@ impl IntoArrow for MultiPolygonArray {
     }
 }
 
-impl TryFrom<(&GenericListArray<i32>, Dimension)> for MultiPolygonArray {
-    type Error = GeoArrowError;
-
-    fn try_from((geom_array, dim): (&GenericListArray<i32>, Dimension)) -> Result<Self> {
-        let geom_offsets = geom_array.offsets();
-        let validity = geom_array.nulls();
-
-        let polygons_dyn_array = geom_array.values();
-        let polygons_array = polygons_dyn_array.as_list::<i32>();
-
-        let polygon_offsets = polygons_array.offsets();
-        let rings_dyn_array = polygons_array.values();
-        let rings_array = rings_dyn_array.as_list::<i32>();
-
-        let ring_offsets = rings_array.offsets();
-        let coords = CoordBuffer::from_arrow(rings_array.values().as_ref(), dim)?;
-
-        Ok(Self::new(
-            coords,
-            geom_offsets.clone(),
-            polygon_offsets.clone(),
-            ring_offsets.clone(),
-            validity.cloned(),
-            Default::default(),
-        ))
-    }
-}
-
-impl TryFrom<(&GenericListArray<i64>, Dimension)> for MultiPolygonArray {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> TryFrom<(&GenericListArray<A>, Dimension)>
+    for GenericMultiPolygonArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from((geom_array, dim): (&GenericListArray<i64>, Dimension)) -> Result<Self> {
-        let geom_offsets = offsets_buffer_i64_to_i32(geom_array.offsets())?;
+    fn try_from((geom_array, dim): (&GenericListArray<A>, Dimension)) -> Result<Self> {
+        let geom_offsets = try_cast_offsets(geom_array.offsets())?;
         let validity = geom_array.nulls();
 
         let polygons_dyn_array = geom_array.values();
-        let polygons_array = polygons_dyn_array.as_list::<i64>();
+        let polygons_array = polygons_dyn_array.as_list::<A>();
 
-        let polygon_offsets = offsets_buffer_i64_to_i32(polygons_array.offsets())?;
+        let polygon_offsets = try_cast_offsets(polygons_array.offsets())?;
         let rings_dyn_array = polygons_array.values();
-        let rings_array = rings_dyn_array.as_list::<i64>();
+        let rings_array = rings_dyn_array.as_list::<A>();
 
-        let ring_offsets = offsets_buffer_i64_to_i32(rings_array.offsets())?;
+        let ring_offsets = try_cast_offsets(rings_array.offsets())?;
         let coords = CoordBuffer::from_arrow(rings_array.values().as_ref(), dim)?;
 
         Ok(Self::new(
@@ -481,7 +513,7 @@ impl TryFrom<(&GenericListArray<i64>, Dimension)> for MultiPolygonArray {
     }
 }
 
-impl TryFrom<(&dyn Array, Dimension)> for MultiPolygonArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericMultiPolygonArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -502,7 +534,7 @@ impl TryFrom<(&dyn Array, Dimension)> for MultiPolygonArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for MultiPolygonArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericMultiPolygonArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -539,10 +571,10 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for MultiPolygonArray
     }
 }
 
-impl From<PolygonArray> for MultiPolygonArray {
-    fn from(value: PolygonArray) -> Self {
+impl<O: OffsetSizeTrait> From<GenericPolygonArray<O>> for GenericMultiPolygonArray<O> {
+    fn from(value: GenericPolygonArray<O>) -> Self {
+        let geom_offsets = OffsetBuffer::from_lengths(vec![1; value.len()]);
         let coords = value.coords;
-        let geom_offsets = OffsetBuffer::from_lengths(vec![1; coords.len()]);
         let ring_offsets = value.ring_offsets;
         let polygon_offsets = value.geom_offsets;
         let validity = value.validity;
@@ -564,7 +596,42 @@ impl Default for MultiPolygonArray {
     }
 }
 
-impl PartialEq for MultiPolygonArray {
+/// Default to an empty array
+impl Default for LargeMultiPolygonArray {
+    fn default() -> Self {
+        MultiPolygonArray::default().into()
+    }
+}
+
+impl From<MultiPolygonArray> for LargeMultiPolygonArray {
+    fn from(value: MultiPolygonArray) -> Self {
+        Self::new(
+            value.coords,
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            offsets_buffer_i32_to_i64(&value.polygon_offsets),
+            offsets_buffer_i32_to_i64(&value.ring_offsets),
+            value.validity,
+            value.metadata,
+        )
+    }
+}
+
+impl TryFrom<LargeMultiPolygonArray> for MultiPolygonArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargeMultiPolygonArray) -> Result<Self> {
+        Ok(Self::new(
+            value.coords,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            offsets_buffer_i64_to_i32(&value.polygon_offsets)?,
+            offsets_buffer_i64_to_i32(&value.ring_offsets)?,
+            value.validity,
+            value.metadata,
+        ))
+    }
+}
+
+impl<O: OffsetSizeTrait> PartialEq for GenericMultiPolygonArray<O> {
     fn eq(&self, other: &Self) -> bool {
         if self.validity != other.validity {
             return false;
@@ -16,6 +16,7 @@ use arrow_array::{ArrayRef, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::{NullBufferBuilder, OffsetBuffer};
 use geo_traits::{
     CoordTrait, GeometryTrait, GeometryType, LineStringTrait, MultiPolygonTrait, PolygonTrait,
+    RectTrait,
 };
 
 pub type MutableMultiPolygonParts = (
@@ -266,12 +267,43 @@ impl MultiPolygonBuilder {
                     self.coords.push_coord(&coord);
                 }
             }
+
+            self.validity.append(true);
         } else {
             self.push_null();
         };
         Ok(())
     }
 
+    /// Add a new Rect to this builder, as a MultiPolygon with a single polygon
+    #[inline]
+    pub fn push_rect(&mut self, value: Option<&impl RectTrait<T = f64>>) -> Result<()> {
+        if let Some(rect) = value {
+            // Only one polygon with only one ring
+            self.geom_offsets.try_push_usize(1)?;
+            self.polygon_offsets.try_push_usize(1)?;
+            // ring has 5 coords
+            self.ring_offsets.try_push_usize(5)?;
+
+            let lower = rect.min();
+            let upper = rect.max();
+            for (x, y) in [
+                (lower.x(), lower.y()),
+                (lower.x(), upper.y()),
+                (upper.x(), upper.y()),
+                (upper.x(), lower.y()),
+                (lower.x(), lower.y()),
+            ] {
+                self.coords.push_coord(&geo::Coord { x, y });
+            }
+
+            self.validity.append(true);
+        } else {
+            self.push_null();
+        }
+        Ok(())
+    }
+
     /// Add a new MultiPolygon to the end of this array.
     ///
     /// # Errors
@@ -331,7 +363,7 @@ impl MultiPolygonBuilder {
             match value.as_type() {
                 GeometryType::Polygon(g) => self.push_polygon(Some(g))?,
                 GeometryType::MultiPolygon(g) => self.push_multi_polygon(Some(g))?,
-                // TODO: support rect
+                GeometryType::Rect(g) => self.push_rect(Some(g))?,
                 _ => return Err(GeoArrowError::General("Incorrect type".to_string())),
             }
         } else {
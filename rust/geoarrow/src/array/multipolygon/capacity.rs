@@ -2,7 +2,9 @@ use std::ops::{Add, AddAssign};
 
 use crate::array::polygon::PolygonCapacity;
 use crate::error::{GeoArrowError, Result};
-use geo_traits::{GeometryTrait, GeometryType, LineStringTrait, MultiPolygonTrait, PolygonTrait};
+use geo_traits::{
+    GeometryTrait, GeometryType, LineStringTrait, MultiPolygonTrait, PolygonTrait, RectTrait,
+};
 
 /// A counter for the buffer sizes of a [`MultiPolygonArray`][crate::array::MultiPolygonArray].
 ///
@@ -87,6 +89,19 @@ impl MultiPolygonCapacity {
         }
     }
 
+    /// Add the capacity of the given Rect
+    #[inline]
+    pub fn add_rect<'a>(&mut self, rect: Option<&'a (impl RectTrait + 'a)>) {
+        self.geom_capacity += 1;
+        if let Some(_rect) = rect {
+            // A rect is a single polygon with only one ring
+            self.polygon_capacity += 1;
+            self.ring_capacity += 1;
+            // A rect is a closed polygon with 5 coordinates
+            self.coord_capacity += 5;
+        }
+    }
+
     /// Add the capacity of the given MultiPolygon
     #[inline]
     pub fn add_multi_polygon<'a>(
@@ -125,6 +140,7 @@ impl MultiPolygonCapacity {
             match geom.as_type() {
                 GeometryType::Polygon(g) => self.add_polygon(Some(g)),
                 GeometryType::MultiPolygon(g) => self.add_multi_polygon(Some(g)),
+                GeometryType::Rect(g) => self.add_rect(Some(g)),
                 _ => return Err(GeoArrowError::General("Incorrect type".to_string())),
             }
         } else {
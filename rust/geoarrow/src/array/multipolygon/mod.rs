@@ -1,7 +1,7 @@
 //! Contains the [`MultiPolygonArray`] and [`MultiPolygonBuilder`] for arrays of MultiPolygon
 //! geometries.
 
-pub use array::MultiPolygonArray;
+pub use array::{GenericMultiPolygonArray, LargeMultiPolygonArray, MultiPolygonArray};
 pub use builder::MultiPolygonBuilder;
 pub use capacity::MultiPolygonCapacity;
 
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::algorithm::native::downcast::can_downcast_multi;
 use crate::algorithm::native::eq::point_eq;
 use crate::array::metadata::ArrayMetadata;
+use crate::array::validate::validate_nulls;
 use crate::array::{
     CoordBuffer, CoordType, GeometryCollectionArray, InterleavedCoordBuffer, MixedGeometryArray,
     MultiPointArray, PointBuilder, SeparatedCoordBuffer, WKBArray,
@@ -210,6 +211,14 @@ impl NativeArray for PointArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("Point", self.validity.as_ref(), self.coords.len())
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()
+    }
 }
 
 impl GeometryArraySelfMethods for PointArray {
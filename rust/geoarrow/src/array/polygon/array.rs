@@ -4,10 +4,15 @@ use crate::algorithm::native::downcast::can_downcast_multi;
 use crate::algorithm::native::eq::offset_buffer_eq;
 use crate::array::metadata::ArrayMetadata;
 use crate::array::polygon::PolygonCapacity;
-use crate::array::util::{offsets_buffer_i64_to_i32, OffsetBufferUtils};
+use crate::array::util::{
+    offsets_buffer_i32_to_i64, offsets_buffer_i64_to_i32, try_cast_offsets, OffsetBufferUtils,
+};
+use crate::array::validate::{
+    invalid_row, owning_row, validate_nulls, validate_offsets, validate_ring,
+};
 use crate::array::{
-    CoordBuffer, CoordType, GeometryCollectionArray, MixedGeometryArray, MultiLineStringArray,
-    MultiPolygonArray, RectArray, WKBArray,
+    CoordBuffer, CoordType, GenericMultiLineStringArray, GenericMultiPolygonArray,
+    GeometryCollectionArray, MixedGeometryArray, RectArray, WKBArray,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -27,10 +32,12 @@ use super::PolygonBuilder;
 /// An immutable array of Polygon geometries using GeoArrow's in-memory representation.
 ///
 /// This is semantically equivalent to `Vec<Option<Polygon>>` due to the internal validity bitmap.
+///
+/// The offset type `O` is `i32` for [`PolygonArray`] and `i64` for [`LargePolygonArray`].
 #[derive(Debug, Clone)]
 // #[derive(Debug, Clone, PartialEq)]
-pub struct PolygonArray {
-    // Always NativeType::Polygon
+pub struct GenericPolygonArray<O: OffsetSizeTrait> {
+    // Always NativeType::Polygon or NativeType::LargePolygon
     data_type: NativeType,
 
     pub(crate) metadata: Arc<ArrayMetadata>,
@@ -38,19 +45,25 @@ pub struct PolygonArray {
     pub(crate) coords: CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
-    pub(crate) geom_offsets: OffsetBuffer<i32>,
+    pub(crate) geom_offsets: OffsetBuffer<O>,
 
     /// Offsets into the coordinate array where each ring starts
-    pub(crate) ring_offsets: OffsetBuffer<i32>,
+    pub(crate) ring_offsets: OffsetBuffer<O>,
 
     /// Validity bitmap
     pub(crate) validity: Option<NullBuffer>,
 }
 
-pub(super) fn check(
+/// An array of Polygon geometries with `i32` offsets.
+pub type PolygonArray = GenericPolygonArray<i32>;
+
+/// An array of Polygon geometries with `i64` offsets.
+pub type LargePolygonArray = GenericPolygonArray<i64>;
+
+pub(super) fn check<O: OffsetSizeTrait>(
     coords: &CoordBuffer,
-    geom_offsets: &OffsetBuffer<i32>,
-    ring_offsets: &OffsetBuffer<i32>,
+    geom_offsets: &OffsetBuffer<O>,
+    ring_offsets: &OffsetBuffer<O>,
     validity_len: Option<usize>,
 ) -> Result<()> {
     if validity_len.is_some_and(|len| len != geom_offsets.len_proxy()) {
@@ -59,13 +72,13 @@ pub(super) fn check(
         ));
     }
 
-    if *ring_offsets.last() as usize != coords.len() {
+    if ring_offsets.last().as_usize() != coords.len() {
         return Err(GeoArrowError::General(
             "largest ring offset must match coords length".to_string(),
         ));
     }
 
-    if *geom_offsets.last() as usize != ring_offsets.len_proxy() {
+    if geom_offsets.last().as_usize() != ring_offsets.len_proxy() {
         return Err(GeoArrowError::General(
             "largest geometry offset must match ring offsets length".to_string(),
         ));
@@ -74,7 +87,7 @@ pub(super) fn check(
     Ok(())
 }
 
-impl PolygonArray {
+impl<O: OffsetSizeTrait> GenericPolygonArray<O> {
     /// Create a new PolygonArray from parts
     ///
     /// # Implementation
@@ -88,8 +101,8 @@ impl PolygonArray {
     /// - if the largest geometry offset does not match the size of ring offsets
     pub fn new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
-        ring_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Self {
@@ -109,8 +122,8 @@ impl PolygonArray {
     /// - if the largest geometry offset does not match the size of ring offsets
     pub fn try_new(
         coords: CoordBuffer,
-        geom_offsets: OffsetBuffer<i32>,
-        ring_offsets: OffsetBuffer<i32>,
+        geom_offsets: OffsetBuffer<O>,
+        ring_offsets: OffsetBuffer<O>,
         validity: Option<NullBuffer>,
         metadata: Arc<ArrayMetadata>,
     ) -> Result<Self> {
@@ -120,7 +133,11 @@ impl PolygonArray {
             &ring_offsets,
             validity.as_ref().map(|v| v.len()),
         )?;
-        let data_type = NativeType::Polygon(coords.coord_type(), coords.dim());
+        let data_type = if O::IS_LARGE {
+            NativeType::LargePolygon(coords.coord_type(), coords.dim())
+        } else {
+            NativeType::Polygon(coords.coord_type(), coords.dim())
+        };
         Ok(Self {
             data_type,
             coords,
@@ -137,7 +154,8 @@ impl PolygonArray {
 
     fn rings_field(&self) -> Arc<Field> {
         let name = "rings";
-        Field::new_list(name, self.vertices_field(), false).into()
+        let data_type = GenericListArray::<O>::DATA_TYPE_CONSTRUCTOR(self.vertices_field());
+        Field::new(name, data_type, false).into()
     }
 
     /// Access the underlying coordinate buffer
@@ -146,20 +164,20 @@ impl PolygonArray {
     }
 
     /// Access the underlying geometry offsets buffer
-    pub fn geom_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn geom_offsets(&self) -> &OffsetBuffer<O> {
         &self.geom_offsets
     }
 
     /// Access the underlying ring offsets buffer
-    pub fn ring_offsets(&self) -> &OffsetBuffer<i32> {
+    pub fn ring_offsets(&self) -> &OffsetBuffer<O> {
         &self.ring_offsets
     }
 
     /// The lengths of each buffer contained in this array.
     pub fn buffer_lengths(&self) -> PolygonCapacity {
         PolygonCapacity::new(
-            *self.ring_offsets.last() as usize,
-            *self.geom_offsets.last() as usize,
+            self.ring_offsets.last().as_usize(),
+            self.geom_offsets.last().as_usize(),
             self.len(),
         )
     }
@@ -208,7 +226,7 @@ impl PolygonArray {
     }
 }
 
-impl ArrayBase for PolygonArray {
+impl<O: OffsetSizeTrait> ArrayBase for GenericPolygonArray<O> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -252,7 +270,7 @@ impl ArrayBase for PolygonArray {
     }
 }
 
-impl NativeArray for PolygonArray {
+impl<O: OffsetSizeTrait> NativeArray for GenericPolygonArray<O> {
     fn data_type(&self) -> NativeType {
         self.data_type
     }
@@ -278,9 +296,33 @@ impl NativeArray for PolygonArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> Result<()> {
+        validate_nulls("Polygon", self.validity.as_ref(), self.len())?;
+        validate_offsets(&self.geom_offsets, self.ring_offsets.len_proxy())
+            .map_err(|row| invalid_row("Polygon", row, "geometry offsets are out of bounds"))?;
+        validate_offsets(&self.ring_offsets, self.coords.len()).map_err(|ring_idx| {
+            let row = owning_row(&self.geom_offsets, ring_idx);
+            invalid_row("Polygon", row, "ring offsets are out of bounds")
+        })
+    }
+
+    fn validate_full(&self) -> Result<()> {
+        self.validate()?;
+        for row in 0..self.len() {
+            if self.is_valid(row) {
+                let (start, end) = self.geom_offsets.start_end(row);
+                for ring_idx in start..end {
+                    validate_ring(&self.coords, &self.ring_offsets, ring_idx)
+                        .map_err(|reason| invalid_row("Polygon", row, reason))?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
-impl GeometryArraySelfMethods for PolygonArray {
+impl<O: OffsetSizeTrait> GeometryArraySelfMethods for GenericPolygonArray<O> {
     fn with_coords(self, coords: CoordBuffer) -> Self {
         assert_eq!(coords.len(), self.coords.len());
         Self::new(
@@ -315,7 +357,9 @@ impl NativeGeometryAccessor for PolygonArray {
 }
 
 #[cfg(feature = "geos")]
-impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for PolygonArray {
+impl<'a, O: OffsetSizeTrait> crate::trait_::NativeGEOSGeometryAccessor<'a>
+    for GenericPolygonArray<O>
+{
     unsafe fn value_as_geometry_unchecked(
         &'a self,
         index: usize,
@@ -325,8 +369,8 @@ impl<'a> crate::trait_::NativeGEOSGeometryAccessor<'a> for PolygonArray {
     }
 }
 
-impl<'a> ArrayAccessor<'a> for PolygonArray {
-    type Item = Polygon<'a>;
+impl<'a, O: OffsetSizeTrait> ArrayAccessor<'a> for GenericPolygonArray<O> {
+    type Item = Polygon<'a, O>;
     type ItemGeo = geo::Polygon;
 
     unsafe fn value_unchecked(&'a self, index: usize) -> Self::Item {
@@ -334,8 +378,8 @@ impl<'a> ArrayAccessor<'a> for PolygonArray {
     }
 }
 
-impl IntoArrow for PolygonArray {
-    type ArrowArray = GenericListArray<i32>;
+impl<O: OffsetSizeTrait> IntoArrow for GenericPolygonArray<O> {
+    type ArrowArray = GenericListArray<O>;
 
     fn into_arrow(self) -> Self::ArrowArray {
         let vertices_field = self.vertices_field();
@@ -352,40 +396,19 @@ impl IntoArrow for PolygonArray {
     }
 }
 
-impl TryFrom<(&GenericListArray<i32>, Dimension)> for PolygonArray {
-    type Error = GeoArrowError;
-
-    fn try_from((geom_array, dim): (&GenericListArray<i32>, Dimension)) -> Result<Self> {
-        let geom_offsets = geom_array.offsets();
-        let validity = geom_array.nulls();
-
-        let rings_dyn_array = geom_array.values();
-        let rings_array = rings_dyn_array.as_list::<i32>();
-
-        let ring_offsets = rings_array.offsets();
-        let coords = CoordBuffer::from_arrow(rings_array.values().as_ref(), dim)?;
-
-        Ok(Self::new(
-            coords,
-            geom_offsets.clone(),
-            ring_offsets.clone(),
-            validity.cloned(),
-            Default::default(),
-        ))
-    }
-}
-
-impl TryFrom<(&GenericListArray<i64>, Dimension)> for PolygonArray {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> TryFrom<(&GenericListArray<A>, Dimension)>
+    for GenericPolygonArray<O>
+{
     type Error = GeoArrowError;
 
-    fn try_from((geom_array, dim): (&GenericListArray<i64>, Dimension)) -> Result<Self> {
-        let geom_offsets = offsets_buffer_i64_to_i32(geom_array.offsets())?;
+    fn try_from((geom_array, dim): (&GenericListArray<A>, Dimension)) -> Result<Self> {
+        let geom_offsets = try_cast_offsets(geom_array.offsets())?;
         let validity = geom_array.nulls();
 
         let rings_dyn_array = geom_array.values();
-        let rings_array = rings_dyn_array.as_list::<i64>();
+        let rings_array = rings_dyn_array.as_list::<A>();
 
-        let ring_offsets = offsets_buffer_i64_to_i32(rings_array.offsets())?;
+        let ring_offsets = try_cast_offsets(rings_array.offsets())?;
         let coords = CoordBuffer::from_arrow(rings_array.values().as_ref(), dim)?;
 
         Ok(Self::new(
@@ -397,7 +420,8 @@ impl TryFrom<(&GenericListArray<i64>, Dimension)> for PolygonArray {
         ))
     }
 }
-impl TryFrom<(&dyn Array, Dimension)> for PolygonArray {
+
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, Dimension)> for GenericPolygonArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((value, dim): (&dyn Array, Dimension)) -> Result<Self> {
@@ -418,7 +442,7 @@ impl TryFrom<(&dyn Array, Dimension)> for PolygonArray {
     }
 }
 
-impl TryFrom<(&dyn Array, &Field)> for PolygonArray {
+impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field)> for GenericPolygonArray<O> {
     type Error = GeoArrowError;
 
     fn try_from((arr, field): (&dyn Array, &Field)) -> Result<Self> {
@@ -457,8 +481,8 @@ impl<O: OffsetSizeTrait> TryFrom<(WKBArray<O>, Dimension)> for PolygonArray {
 
 /// Polygon and MultiLineString have the same layout, so enable conversions between the two to
 /// change the semantic type
-impl From<PolygonArray> for MultiLineStringArray {
-    fn from(value: PolygonArray) -> Self {
+impl<O: OffsetSizeTrait> From<GenericPolygonArray<O>> for GenericMultiLineStringArray<O> {
+    fn from(value: GenericPolygonArray<O>) -> Self {
         Self::new(
             value.coords,
             value.geom_offsets,
@@ -503,7 +527,40 @@ impl Default for PolygonArray {
     }
 }
 
-impl PartialEq for PolygonArray {
+/// Default to an empty array
+impl Default for LargePolygonArray {
+    fn default() -> Self {
+        PolygonArray::default().into()
+    }
+}
+
+impl From<PolygonArray> for LargePolygonArray {
+    fn from(value: PolygonArray) -> Self {
+        Self::new(
+            value.coords,
+            offsets_buffer_i32_to_i64(&value.geom_offsets),
+            offsets_buffer_i32_to_i64(&value.ring_offsets),
+            value.validity,
+            value.metadata,
+        )
+    }
+}
+
+impl TryFrom<LargePolygonArray> for PolygonArray {
+    type Error = GeoArrowError;
+
+    fn try_from(value: LargePolygonArray) -> Result<Self> {
+        Ok(Self::new(
+            value.coords,
+            offsets_buffer_i64_to_i32(&value.geom_offsets)?,
+            offsets_buffer_i64_to_i32(&value.ring_offsets)?,
+            value.validity,
+            value.metadata,
+        ))
+    }
+}
+
+impl<O: OffsetSizeTrait> PartialEq for GenericPolygonArray<O> {
     fn eq(&self, other: &Self) -> bool {
         if self.validity != other.validity {
             return false;
@@ -525,15 +582,15 @@ impl PartialEq for PolygonArray {
     }
 }
 
-impl TryFrom<MultiPolygonArray> for PolygonArray {
+impl<O: OffsetSizeTrait> TryFrom<GenericMultiPolygonArray<O>> for GenericPolygonArray<O> {
     type Error = GeoArrowError;
 
-    fn try_from(value: MultiPolygonArray) -> Result<Self> {
+    fn try_from(value: GenericMultiPolygonArray<O>) -> Result<Self> {
         if !can_downcast_multi(&value.geom_offsets) {
             return Err(GeoArrowError::General("Unable to cast".to_string()));
         }
 
-        Ok(PolygonArray::new(
+        Ok(Self::new(
             value.coords,
             value.polygon_offsets,
             value.ring_offsets,
@@ -1,6 +1,6 @@
 //! Contains the [`PolygonArray`] and [`PolygonBuilder`] for arrays of Polygon geometries.
 
-pub use array::PolygonArray;
+pub use array::{GenericPolygonArray, LargePolygonArray, PolygonArray};
 pub use builder::PolygonBuilder;
 pub use capacity::PolygonCapacity;
 
@@ -8,6 +8,7 @@ use arrow_schema::{DataType, Field};
 
 use crate::array::metadata::ArrayMetadata;
 use crate::array::rect::RectBuilder;
+use crate::array::validate::validate_nulls;
 use crate::array::{CoordBuffer, CoordType, SeparatedCoordBuffer};
 use crate::datatypes::{rect_fields, Dimension, NativeType};
 use crate::error::GeoArrowError;
@@ -162,6 +163,21 @@ impl NativeArray for RectArray {
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray> {
         Arc::new(self.slice(offset, length))
     }
+
+    fn validate(&self) -> crate::error::Result<()> {
+        if self.lower.len() != self.upper.len() {
+            return Err(GeoArrowError::General(format!(
+                "Invalid Rect array: {} lower coordinates but {} upper coordinates",
+                self.lower.len(),
+                self.upper.len()
+            )));
+        }
+        validate_nulls("Rect", self.validity.as_ref(), self.lower.len())
+    }
+
+    fn validate_full(&self) -> crate::error::Result<()> {
+        self.validate()
+    }
 }
 
 impl GeometryArraySelfMethods for RectArray {
@@ -1,9 +1,12 @@
 //! Note: This entire mod is a candidate to upstream into arrow-rs.
 
+use std::collections::HashSet;
+
 use arrow_array::OffsetSizeTrait;
-use arrow_buffer::OffsetBuffer;
+use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+use arrow_data::ArrayData;
 
-use crate::error::Result;
+use crate::error::{GeoArrowError, Result};
 
 pub(crate) fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBuffer<i64> {
     let i64_offsets = offsets.iter().map(|x| *x as i64).collect::<Vec<_>>();
@@ -11,9 +14,7 @@ pub(crate) fn offsets_buffer_i32_to_i64(offsets: &OffsetBuffer<i32>) -> OffsetBu
 }
 
 pub(crate) fn offsets_buffer_i64_to_i32(offsets: &OffsetBuffer<i64>) -> Result<OffsetBuffer<i32>> {
-    // TODO: raise nicer error. Ref:
-    // https://github.com/jorgecarleitao/arrow2/blob/6a4b53169a48cbd234cecde6ab6a98f84146fca2/src/offset.rs#L492
-    i32::try_from(*offsets.last()).unwrap();
+    i32::try_from(*offsets.last()).map_err(|_| GeoArrowError::Overflow)?;
 
     let i32_offsets = offsets.iter().map(|x| *x as i32).collect::<Vec<_>>();
     Ok(unsafe { OffsetBuffer::new_unchecked(i32_offsets.into()) })
@@ -45,6 +46,52 @@ pub(crate) fn offsets_buffer_to_i32<O: OffsetSizeTrait>(
     Ok(unsafe { OffsetBuffer::new_unchecked(i32_offsets.into()) })
 }
 
+/// Convert an offsets buffer to another offset size.
+///
+/// This is zero-copy when the input and output offset sizes are the same.
+///
+/// # Errors
+///
+/// - If the offsets do not fit in the output offset size.
+pub(crate) fn try_cast_offsets<I: OffsetSizeTrait, O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<I>,
+) -> Result<OffsetBuffer<O>> {
+    if I::IS_LARGE == O::IS_LARGE {
+        // The physical types are identical, so we can reinterpret the existing buffer
+        let buffer = ScalarBuffer::<O>::from(offsets.inner().inner().clone());
+        return Ok(unsafe { OffsetBuffer::new_unchecked(buffer) });
+    }
+
+    O::from_usize(offsets.last().as_usize()).ok_or(GeoArrowError::Overflow)?;
+    let new_offsets = offsets
+        .iter()
+        .map(|x| O::usize_as(x.as_usize()))
+        .collect::<Vec<_>>();
+    Ok(unsafe { OffsetBuffer::new_unchecked(new_offsets.into()) })
+}
+
+/// The total capacity, in bytes, of the distinct buffers referenced by `arrays` and their children.
+///
+/// Buffers are identified by their underlying allocation, so a buffer that is shared between
+/// several arrays, or between several slices of the same array, is only counted once.
+pub(crate) fn distinct_buffer_memory_size<'a>(
+    arrays: impl IntoIterator<Item = &'a ArrayData>,
+) -> usize {
+    let mut seen = HashSet::new();
+    let mut size = 0;
+    let mut stack = arrays.into_iter().collect::<Vec<_>>();
+    while let Some(data) = stack.pop() {
+        let nulls = data.nulls().map(|nulls| nulls.buffer());
+        for buffer in data.buffers().iter().chain(nulls) {
+            if seen.insert(buffer.data_ptr()) {
+                size += buffer.capacity();
+            }
+        }
+        stack.extend(data.child_data());
+    }
+    size
+}
+
 /// Offsets utils that I miss from arrow2
 pub(crate) trait OffsetBufferUtils<O: OffsetSizeTrait> {
     /// Returns the length an array with these offsets would be.
@@ -0,0 +1,332 @@
+//! Helpers for validating the structure of GeoArrow arrays.
+//!
+//! See [`NativeArray::validate`] and [`NativeArray::validate_full`].
+
+use arrow_array::{Array, OffsetSizeTrait};
+use arrow_buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::Field;
+
+use crate::algorithm::native::eq::coord_eq;
+use crate::array::util::OffsetBufferUtils;
+use crate::array::*;
+use crate::error::{GeoArrowError, Result};
+
+/// How thoroughly to validate an array imported from Arrow.
+///
+/// Every native array implements `TryFrom<(&dyn Array, &Field, ValidationLevel)>`, which converts
+/// like `TryFrom<(&dyn Array, &Field)>` and then runs [`NativeArray::validate`] or
+/// [`NativeArray::validate_full`] on the result.
+///
+/// ```
+/// use geo::line_string;
+/// use geoarrow::array::{LineStringArray, ValidationLevel};
+/// use geoarrow::datatypes::Dimension;
+/// use geoarrow::ArrayBase;
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 1.)];
+/// let array: LineStringArray = (vec![line_string].as_slice(), Dimension::XY).into();
+/// let field = array.extension_field();
+/// let array_ref = array.to_array_ref();
+///
+/// let validated =
+///     LineStringArray::try_from((array_ref.as_ref(), field.as_ref(), ValidationLevel::Full))
+///         .unwrap();
+/// assert_eq!(validated, array);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ValidationLevel {
+    /// Only run the cheap structural checks of [`NativeArray::validate`].
+    #[default]
+    Structural,
+
+    /// Additionally run the per-geometry checks of [`NativeArray::validate_full`].
+    Full,
+}
+
+/// An error describing why the value at `row` of an array is invalid.
+pub(crate) fn invalid_row(
+    type_name: &str,
+    row: usize,
+    reason: impl std::fmt::Display,
+) -> GeoArrowError {
+    GeoArrowError::General(format!("Invalid {type_name} array at row {row}: {reason}"))
+}
+
+/// Check that the validity buffer, if any, has one entry per geometry.
+pub(crate) fn validate_nulls(
+    type_name: &str,
+    nulls: Option<&NullBuffer>,
+    len: usize,
+) -> Result<()> {
+    match nulls {
+        Some(nulls) if nulls.len() != len => Err(GeoArrowError::General(format!(
+            "Invalid {type_name} array: validity buffer has length {} but the array has length {len}",
+            nulls.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check that `offsets` are non-decreasing and point into a child of length `child_len`.
+///
+/// On failure, returns the index of the first list element with an invalid offset.
+pub(crate) fn validate_offsets<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    child_len: usize,
+) -> std::result::Result<(), usize> {
+    let mut prev = 0;
+    for (i, offset) in offsets.iter().enumerate() {
+        match offset.to_usize() {
+            Some(offset) if offset <= child_len && (i == 0 || offset >= prev) => prev = offset,
+            _ => return Err(i.saturating_sub(1)),
+        }
+    }
+    Ok(())
+}
+
+/// The index of the list element in `offsets` that contains the child at `child_idx`.
+///
+/// `offsets` must already have been checked with [`validate_offsets`].
+pub(crate) fn owning_row<O: OffsetSizeTrait>(offsets: &OffsetBuffer<O>, child_idx: usize) -> usize {
+    let row = offsets.partition_point(|offset| offset.as_usize() <= child_idx);
+    row.saturating_sub(1)
+        .min(offsets.len_proxy().saturating_sub(1))
+}
+
+/// Check that the line string at `line_idx` has either zero or at least two coordinates.
+pub(crate) fn validate_line_string<O: OffsetSizeTrait>(
+    offsets: &OffsetBuffer<O>,
+    line_idx: usize,
+) -> std::result::Result<(), String> {
+    let (start, end) = offsets.start_end(line_idx);
+    if end - start == 1 {
+        return Err("line string has exactly one coordinate".to_string());
+    }
+    Ok(())
+}
+
+/// Check that the ring at `ring_idx` is either empty or closed with at least four coordinates.
+pub(crate) fn validate_ring<O: OffsetSizeTrait>(
+    coords: &CoordBuffer,
+    ring_offsets: &OffsetBuffer<O>,
+    ring_idx: usize,
+) -> std::result::Result<(), String> {
+    let (start, end) = ring_offsets.start_end(ring_idx);
+    if start == end {
+        return Ok(());
+    }
+    if end - start < 4 {
+        return Err(format!(
+            "ring has {} coordinates, expected zero or at least 4",
+            end - start
+        ));
+    }
+    if !coord_eq(&coords.value(start), &coords.value(end - 1)) {
+        return Err("ring is not closed".to_string());
+    }
+    Ok(())
+}
+
+/// Check that union `type_ids` and `offsets` agree with each other.
+///
+/// `child_len` returns the length of the child array for a type id, or `None` if the type id is
+/// not valid for this array.
+pub(crate) fn validate_union(
+    type_name: &str,
+    type_ids: &[i8],
+    offsets: &[i32],
+    child_len: impl Fn(i8) -> Option<usize>,
+) -> Result<()> {
+    if type_ids.len() != offsets.len() {
+        return Err(GeoArrowError::General(format!(
+            "Invalid {type_name} array: {} type ids but {} offsets",
+            type_ids.len(),
+            offsets.len()
+        )));
+    }
+
+    for (row, (type_id, offset)) in type_ids.iter().zip(offsets).enumerate() {
+        let Some(len) = child_len(*type_id) else {
+            return Err(invalid_row(
+                type_name,
+                row,
+                format!("unknown type id {type_id}"),
+            ));
+        };
+        if *offset < 0 || *offset as usize >= len {
+            return Err(invalid_row(
+                type_name,
+                row,
+                format!("offset {offset} is out of bounds for child of length {len} (type id {type_id})"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+macro_rules! impl_try_from_validated {
+    ($array:ident<O>) => {
+        impl<O: OffsetSizeTrait> TryFrom<(&dyn Array, &Field, ValidationLevel)> for $array<O> {
+            type Error = GeoArrowError;
+
+            fn try_from(
+                (arr, field, level): (&dyn Array, &Field, ValidationLevel),
+            ) -> Result<Self> {
+                let array = Self::try_from((arr, field))?;
+                match level {
+                    ValidationLevel::Structural => array.validate()?,
+                    ValidationLevel::Full => array.validate_full()?,
+                }
+                Ok(array)
+            }
+        }
+    };
+    ($array:ty) => {
+        impl TryFrom<(&dyn Array, &Field, ValidationLevel)> for $array {
+            type Error = GeoArrowError;
+
+            fn try_from(
+                (arr, field, level): (&dyn Array, &Field, ValidationLevel),
+            ) -> Result<Self> {
+                let array = Self::try_from((arr, field))?;
+                match level {
+                    ValidationLevel::Structural => array.validate()?,
+                    ValidationLevel::Full => array.validate_full()?,
+                }
+                Ok(array)
+            }
+        }
+    };
+}
+
+impl_try_from_validated!(PointArray);
+impl_try_from_validated!(RectArray);
+impl_try_from_validated!(GenericLineStringArray<O>);
+impl_try_from_validated!(GenericPolygonArray<O>);
+impl_try_from_validated!(GenericMultiPointArray<O>);
+impl_try_from_validated!(GenericMultiLineStringArray<O>);
+impl_try_from_validated!(GenericMultiPolygonArray<O>);
+impl_try_from_validated!(GenericMixedGeometryArray<O>);
+impl_try_from_validated!(GenericGeometryCollectionArray<O>);
+impl_try_from_validated!(GenericGeometryArray<O>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::datatypes::Dimension;
+    use crate::test::{linestring, polygon};
+    use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+
+    fn coords(values: Vec<f64>) -> CoordBuffer {
+        InterleavedCoordBuffer::new(values.into(), Dimension::XY).into()
+    }
+
+    #[test]
+    fn valid_arrays() {
+        let arr: LineStringArray = (
+            vec![Some(linestring::ls0()), None, Some(linestring::ls1())],
+            Dimension::XY,
+        )
+            .into();
+        arr.validate().unwrap();
+        arr.validate_full().unwrap();
+
+        let arr: PolygonArray =
+            (vec![polygon::p0(), polygon::p1()].as_slice(), Dimension::XY).into();
+        arr.validate().unwrap();
+        arr.validate_full().unwrap();
+        arr.slice(1, 1).validate_full().unwrap();
+    }
+
+    #[test]
+    fn single_coord_line_string() {
+        let arr = LineStringArray::new(
+            coords(vec![0., 0., 1., 1., 2., 2.]),
+            OffsetBuffer::new(vec![0, 2, 3].into()),
+            None,
+            Default::default(),
+        );
+        arr.validate().unwrap();
+        let err = arr.validate_full().unwrap_err().to_string();
+        assert!(err.contains("row 1"), "{err}");
+    }
+
+    #[test]
+    fn malformed_offsets() {
+        let arr: LineStringArray = (
+            vec![linestring::ls0(), linestring::ls1()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let field = arr.extension_field();
+        let array_ref = arr.to_array_ref();
+
+        // Arrays imported over FFI are not checked by arrow, so the offsets may decrease.
+        let coords = array_ref.to_data().child_data()[0].clone();
+        let offsets = arrow_buffer::Buffer::from_slice_ref([0i32, 3, 2, coords.len() as i32]);
+        let data = unsafe {
+            arrow_data::ArrayData::builder(field.data_type().clone())
+                .len(3)
+                .add_buffer(offsets)
+                .add_child_data(coords)
+                .build_unchecked()
+        };
+        let malformed = arrow_array::make_array(data);
+
+        let err = LineStringArray::try_from((
+            malformed.as_ref(),
+            field.as_ref(),
+            ValidationLevel::Structural,
+        ))
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("row 1") && err.contains("offsets"), "{err}");
+    }
+
+    #[test]
+    fn unclosed_ring() {
+        let arr = PolygonArray::new(
+            coords(vec![0., 0., 1., 0., 1., 1., 0., 1.]),
+            OffsetBuffer::new(vec![0, 0, 1].into()),
+            OffsetBuffer::new(vec![0, 4].into()),
+            None,
+            Default::default(),
+        );
+        let field = arr.extension_field();
+        let array_ref = arr.to_array_ref();
+
+        PolygonArray::try_from((
+            array_ref.as_ref(),
+            field.as_ref(),
+            ValidationLevel::Structural,
+        ))
+        .unwrap();
+        let err =
+            PolygonArray::try_from((array_ref.as_ref(), field.as_ref(), ValidationLevel::Full))
+                .unwrap_err()
+                .to_string();
+        assert!(err.contains("row 1") && err.contains("not closed"), "{err}");
+    }
+
+    #[test]
+    fn union_offset_out_of_bounds() {
+        let line_strings: LineStringArray = (
+            vec![linestring::ls0(), linestring::ls1()].as_slice(),
+            Dimension::XY,
+        )
+            .into();
+        let arr = MixedGeometryArray::new(
+            ScalarBuffer::from(vec![2, 2]),
+            ScalarBuffer::from(vec![0, 2]),
+            None,
+            Some(line_strings),
+            None,
+            None,
+            None,
+            None,
+            Default::default(),
+        );
+        let err = arr.validate().unwrap_err().to_string();
+        assert!(err.contains("row 1"), "{err}");
+    }
+}
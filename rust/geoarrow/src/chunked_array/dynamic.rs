@@ -64,11 +64,22 @@ impl ChunkedNativeArrayDyn {
             MultiPoint(_, _) => impl_downcast!(MultiPointArray),
             MultiLineString(_, _) => impl_downcast!(MultiLineStringArray),
             MultiPolygon(_, _) => impl_downcast!(MultiPolygonArray),
+            Mixed(_, _) => impl_downcast!(MixedGeometryArray),
             GeometryCollection(_, _) => {
                 impl_downcast!(GeometryCollectionArray)
             }
+            LargeLineString(_, _) => impl_downcast!(LargeLineStringArray),
+            LargePolygon(_, _) => impl_downcast!(LargePolygonArray),
+            LargeMultiPoint(_, _) => impl_downcast!(LargeMultiPointArray),
+            LargeMultiLineString(_, _) => impl_downcast!(LargeMultiLineStringArray),
+            LargeMultiPolygon(_, _) => impl_downcast!(LargeMultiPolygonArray),
+            LargeMixed(_, _) => impl_downcast!(LargeMixedGeometryArray),
+            LargeGeometryCollection(_, _) => {
+                impl_downcast!(LargeGeometryCollectionArray)
+            }
             Rect(_) => impl_downcast!(RectArray),
             Geometry(_) => impl_downcast!(GeometryArray),
+            LargeGeometry(_) => impl_downcast!(LargeGeometryArray),
         };
         Ok(Self(ca))
     }
@@ -119,9 +130,18 @@ impl ChunkedNativeArrayDyn {
                 MultiPoint(_, _) => impl_downcast!(as_multi_point),
                 MultiLineString(_, _) => impl_downcast!(as_multi_line_string),
                 MultiPolygon(_, _) => impl_downcast!(as_multi_polygon),
+                Mixed(_, _) => impl_downcast!(as_mixed),
                 GeometryCollection(_, _) => impl_downcast!(as_geometry_collection),
+                LargeLineString(_, _) => impl_downcast!(as_large_line_string),
+                LargePolygon(_, _) => impl_downcast!(as_large_polygon),
+                LargeMultiPoint(_, _) => impl_downcast!(as_large_multi_point),
+                LargeMultiLineString(_, _) => impl_downcast!(as_large_multi_line_string),
+                LargeMultiPolygon(_, _) => impl_downcast!(as_large_multi_polygon),
+                LargeMixed(_, _) => impl_downcast!(as_large_mixed),
+                LargeGeometryCollection(_, _) => impl_downcast!(as_large_geometry_collection),
                 Rect(_) => impl_downcast!(as_rect),
                 Geometry(_) => impl_downcast!(as_geometry),
+                LargeGeometry(_) => impl_downcast!(as_large_geometry),
             };
             Ok(Self(result))
         } else {
@@ -22,6 +22,7 @@ use arrow_schema::{DataType, Field};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
+use crate::array::util::distinct_buffer_memory_size;
 use crate::array::*;
 use crate::datatypes::NativeType;
 use crate::error::{GeoArrowError, Result};
@@ -592,13 +593,29 @@ pub type ChunkedMultiLineStringArray = ChunkedGeometryArray<MultiLineStringArray
 /// A chunked multi-polygon array.
 pub type ChunkedMultiPolygonArray = ChunkedGeometryArray<MultiPolygonArray>;
 /// A chunked mixed geometry array.
-pub(crate) type ChunkedMixedGeometryArray = ChunkedGeometryArray<MixedGeometryArray>;
+pub type ChunkedMixedGeometryArray = ChunkedGeometryArray<MixedGeometryArray>;
 /// A chunked geometry collection array.
 pub type ChunkedGeometryCollectionArray = ChunkedGeometryArray<GeometryCollectionArray>;
 /// A chunked rect array.
 pub type ChunkedRectArray = ChunkedGeometryArray<RectArray>;
 /// A chunked unknown geometry array.
 pub type ChunkedUnknownGeometryArray = ChunkedGeometryArray<GeometryArray>;
+/// A chunked line string array with `i64` offsets.
+pub type ChunkedLargeLineStringArray = ChunkedGeometryArray<LargeLineStringArray>;
+/// A chunked polygon array with `i64` offsets.
+pub type ChunkedLargePolygonArray = ChunkedGeometryArray<LargePolygonArray>;
+/// A chunked multi-point array with `i64` offsets.
+pub type ChunkedLargeMultiPointArray = ChunkedGeometryArray<LargeMultiPointArray>;
+/// A chunked multi-line string array with `i64` offsets.
+pub type ChunkedLargeMultiLineStringArray = ChunkedGeometryArray<LargeMultiLineStringArray>;
+/// A chunked multi-polygon array with `i64` offsets.
+pub type ChunkedLargeMultiPolygonArray = ChunkedGeometryArray<LargeMultiPolygonArray>;
+/// A chunked mixed geometry array with `i64` offsets.
+pub type ChunkedLargeMixedGeometryArray = ChunkedGeometryArray<LargeMixedGeometryArray>;
+/// A chunked geometry collection array with `i64` offsets.
+pub type ChunkedLargeGeometryCollectionArray = ChunkedGeometryArray<LargeGeometryCollectionArray>;
+/// A chunked unknown geometry array with `i64` offsets.
+pub type ChunkedLargeUnknownGeometryArray = ChunkedGeometryArray<LargeGeometryArray>;
 
 /// A chunked WKB array.
 pub type ChunkedWKBArray<O> = ChunkedGeometryArray<WKBArray<O>>;
@@ -713,6 +730,53 @@ pub trait ChunkedArrayBase: std::fmt::Debug + Send + Sync {
     /// let arrays = chunked_array.array_refs();
     /// ```
     fn array_refs(&self) -> Vec<ArrayRef>;
+
+    /// Returns the total number of bytes of memory pointed to by the chunks of this array.
+    ///
+    /// This counts the full capacity of every buffer the chunks reference. Buffers shared between
+    /// chunks, such as when the chunks are slices of one larger array, are only counted once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{
+    ///     chunked_array::{ChunkedGeometryArray, ChunkedArrayBase},
+    ///     array::PointArray,
+    ///     ArrayBase,
+    /// };
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let points = vec![geo::point!(x: 1., y: 2.), geo::point!(x: 3., y: 4.)];
+    /// let array: PointArray = (points.as_slice(), Dimension::XY).into();
+    /// let chunked_array = ChunkedGeometryArray::new(vec![array.slice(0, 1), array.slice(1, 1)]);
+    /// assert_eq!(
+    ///     chunked_array.get_buffer_memory_size(),
+    ///     array.get_buffer_memory_size()
+    /// );
+    /// ```
+    fn get_buffer_memory_size(&self) -> usize {
+        let data = self
+            .array_refs()
+            .iter()
+            .map(|array| array.to_data())
+            .collect::<Vec<_>>();
+        distinct_buffer_memory_size(&data)
+    }
+
+    /// Returns the total number of bytes of memory occupied by this chunked array, including its
+    /// buffers and the array structs of each chunk.
+    fn get_array_memory_size(&self) -> usize {
+        let struct_size = self
+            .array_refs()
+            .iter()
+            .map(|array| {
+                array
+                    .get_array_memory_size()
+                    .saturating_sub(array.get_buffer_memory_size())
+            })
+            .sum::<usize>();
+        self.get_buffer_memory_size() + struct_size + std::mem::size_of_val(self)
+    }
 }
 
 /// A trait implemented by all chunked geometry arrays.
@@ -956,6 +1020,14 @@ impl_trait!(ChunkedMultiPolygonArray);
 impl_trait!(ChunkedMixedGeometryArray);
 impl_trait!(ChunkedGeometryCollectionArray);
 impl_trait!(ChunkedUnknownGeometryArray);
+impl_trait!(ChunkedLargeLineStringArray);
+impl_trait!(ChunkedLargePolygonArray);
+impl_trait!(ChunkedLargeMultiPointArray);
+impl_trait!(ChunkedLargeMultiLineStringArray);
+impl_trait!(ChunkedLargeMultiPolygonArray);
+impl_trait!(ChunkedLargeMixedGeometryArray);
+impl_trait!(ChunkedLargeGeometryCollectionArray);
+impl_trait!(ChunkedLargeUnknownGeometryArray);
 
 impl ChunkedArrayBase for ChunkedRectArray {
     fn as_any(&self) -> &dyn Any {
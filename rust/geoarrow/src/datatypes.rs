@@ -27,6 +27,12 @@ pub enum Dimension {
 
     /// Three-dimensional.
     XYZ,
+
+    /// XYM (2D with measure).
+    XYM,
+
+    /// XYZM (3D with measure).
+    XYZM,
 }
 
 impl Dimension {
@@ -39,15 +45,65 @@ impl Dimension {
     ///
     /// assert_eq!(Dimension::XY.size(), 2);
     /// assert_eq!(Dimension::XYZ.size(), 3);
+    /// assert_eq!(Dimension::XYM.size(), 3);
+    /// assert_eq!(Dimension::XYZM.size(), 4);
     /// ```
     pub fn size(&self) -> usize {
         match self {
             Dimension::XY => 2,
             Dimension::XYZ => 3,
+            Dimension::XYM => 3,
+            Dimension::XYZM => 4,
         }
     }
+
+    /// Returns `true` if this dimension has a Z ordinate.
+    pub fn has_z(&self) -> bool {
+        matches!(self, Dimension::XYZ | Dimension::XYZM)
+    }
+
+    /// Returns `true` if this dimension has an M ordinate.
+    pub fn has_m(&self) -> bool {
+        matches!(self, Dimension::XYM | Dimension::XYZM)
+    }
+
+    /// The position of this dimension in the GeoArrow union type id scheme.
+    ///
+    /// Union type ids are the geometry type id plus 10 times this value, so that e.g. an XYM
+    /// Point has type id 21.
+    pub(crate) fn order(&self) -> usize {
+        match self {
+            Dimension::XY => 0,
+            Dimension::XYZ => 1,
+            Dimension::XYM => 2,
+            Dimension::XYZM => 3,
+        }
+    }
+
+    /// The inverse of [`Dimension::order`].
+    pub(crate) fn from_order(order: usize) -> Result<Self> {
+        match order {
+            0 => Ok(Dimension::XY),
+            1 => Ok(Dimension::XYZ),
+            2 => Ok(Dimension::XYM),
+            3 => Ok(Dimension::XYZM),
+            _ => Err(GeoArrowError::General(format!(
+                "Unexpected dimension order {order}"
+            ))),
+        }
+    }
+
+    /// All dimensions, ordered by [`Dimension::order`].
+    pub(crate) const ALL: [Dimension; 4] = [
+        Dimension::XY,
+        Dimension::XYZ,
+        Dimension::XYM,
+        Dimension::XYZM,
+    ];
 }
 
+/// Note that a size of `3` is always interpreted as [`Dimension::XYZ`]; use [`Dimension::XYM`]
+/// explicitly when the third ordinate is a measure.
 impl TryFrom<usize> for Dimension {
     type Error = GeoArrowError;
 
@@ -55,6 +111,7 @@ impl TryFrom<usize> for Dimension {
         match value {
             2 => Ok(Dimension::XY),
             3 => Ok(Dimension::XYZ),
+            4 => Ok(Dimension::XYZM),
             v => Err(GeoArrowError::General(format!("Unexpected array size {v}"))),
         }
     }
@@ -75,6 +132,8 @@ impl From<Dimension> for geo_traits::Dimensions {
         match value {
             Dimension::XY => geo_traits::Dimensions::Xy,
             Dimension::XYZ => geo_traits::Dimensions::Xyz,
+            Dimension::XYM => geo_traits::Dimensions::Xym,
+            Dimension::XYZM => geo_traits::Dimensions::Xyzm,
         }
     }
 }
@@ -86,6 +145,10 @@ impl TryFrom<geo_traits::Dimensions> for Dimension {
         match value {
             geo_traits::Dimensions::Xy | geo_traits::Dimensions::Unknown(2) => Ok(Dimension::XY),
             geo_traits::Dimensions::Xyz | geo_traits::Dimensions::Unknown(3) => Ok(Dimension::XYZ),
+            geo_traits::Dimensions::Xym => Ok(Dimension::XYM),
+            geo_traits::Dimensions::Xyzm | geo_traits::Dimensions::Unknown(4) => {
+                Ok(Dimension::XYZM)
+            }
             _ => Err(GeoArrowError::General(format!(
                 "Unsupported dimension {:?}",
                 value
@@ -111,39 +174,78 @@ pub enum NativeType {
     /// [ChunkedLineStringArray][crate::chunked_array::ChunkedLineStringArray] with `i32` offsets.
     LineString(CoordType, Dimension),
 
+    /// Represents a [LargeLineStringArray][crate::array::LargeLineStringArray] or
+    /// [ChunkedLargeLineStringArray][crate::chunked_array::ChunkedLargeLineStringArray] with `i64`
+    /// offsets.
+    LargeLineString(CoordType, Dimension),
+
     /// Represents a [PolygonArray][crate::array::PolygonArray] or
     /// [ChunkedPolygonArray][crate::chunked_array::ChunkedPolygonArray] with `i32` offsets.
     Polygon(CoordType, Dimension),
 
+    /// Represents a [LargePolygonArray][crate::array::LargePolygonArray] or
+    /// [ChunkedLargePolygonArray][crate::chunked_array::ChunkedLargePolygonArray] with `i64`
+    /// offsets.
+    LargePolygon(CoordType, Dimension),
+
     /// Represents a [MultiPointArray][crate::array::MultiPointArray] or
     /// [ChunkedMultiPointArray][crate::chunked_array::ChunkedMultiPointArray] with `i32` offsets.
     MultiPoint(CoordType, Dimension),
 
+    /// Represents a [LargeMultiPointArray][crate::array::LargeMultiPointArray] or
+    /// [ChunkedLargeMultiPointArray][crate::chunked_array::ChunkedLargeMultiPointArray] with `i64`
+    /// offsets.
+    LargeMultiPoint(CoordType, Dimension),
+
     /// Represents a [MultiLineStringArray][crate::array::MultiLineStringArray] or
     /// [ChunkedMultiLineStringArray][crate::chunked_array::ChunkedMultiLineStringArray] with `i32`
     /// offsets.
     MultiLineString(CoordType, Dimension),
 
+    /// Represents a [LargeMultiLineStringArray][crate::array::LargeMultiLineStringArray] or
+    /// [ChunkedLargeMultiLineStringArray][crate::chunked_array::ChunkedLargeMultiLineStringArray]
+    /// with `i64` offsets.
+    LargeMultiLineString(CoordType, Dimension),
+
     /// Represents a [MultiPolygonArray][crate::array::MultiPolygonArray] or
     /// [ChunkedMultiPolygonArray][crate::chunked_array::ChunkedMultiPolygonArray] with `i32`
     /// offsets.
     MultiPolygon(CoordType, Dimension),
 
-    // Represents a [MixedGeometryArray][crate::array::MixedGeometryArray] or
-    // [ChunkedMixedGeometryArray][crate::chunked_array::ChunkedMixedGeometryArray] with `i32`
-    // offsets.
-    // Mixed(CoordType, Dimension),
+    /// Represents a [LargeMultiPolygonArray][crate::array::LargeMultiPolygonArray] or
+    /// [ChunkedLargeMultiPolygonArray][crate::chunked_array::ChunkedLargeMultiPolygonArray] with
+    /// `i64` offsets.
+    LargeMultiPolygon(CoordType, Dimension),
+
+    /// Represents a [MixedGeometryArray][crate::array::MixedGeometryArray] or
+    /// [ChunkedMixedGeometryArray][crate::chunked_array::ChunkedMixedGeometryArray] with `i32`
+    /// offsets.
+    Mixed(CoordType, Dimension),
+
+    /// Represents a [LargeMixedGeometryArray][crate::array::LargeMixedGeometryArray] or
+    /// [ChunkedLargeMixedGeometryArray][crate::chunked_array::ChunkedLargeMixedGeometryArray] with
+    /// `i64` offsets.
+    LargeMixed(CoordType, Dimension),
+
     /// Represents a [GeometryCollectionArray][crate::array::GeometryCollectionArray] or
     /// [ChunkedGeometryCollectionArray][crate::chunked_array::ChunkedGeometryCollectionArray] with
     /// `i32` offsets.
     GeometryCollection(CoordType, Dimension),
 
+    /// Represents a [LargeGeometryCollectionArray][crate::array::LargeGeometryCollectionArray] or
+    /// [ChunkedLargeGeometryCollectionArray][crate::chunked_array::ChunkedLargeGeometryCollectionArray]
+    /// with `i64` offsets.
+    LargeGeometryCollection(CoordType, Dimension),
+
     /// Represents a [RectArray][crate::array::RectArray] or
     /// [ChunkedRectArray][crate::chunked_array::ChunkedRectArray].
     Rect(Dimension),
 
-    /// Represents a mixed geometry array of unknown types or dimensions
+    /// Represents a mixed geometry array of unknown types or dimensions with `i32` offsets.
     Geometry(CoordType),
+
+    /// Represents a mixed geometry array of unknown types or dimensions with `i64` offsets.
+    LargeGeometry(CoordType),
 }
 
 impl From<NativeType> for DataType {
@@ -183,29 +285,27 @@ pub enum AnyType {
     Serialized(SerializedType),
 }
 
+/// The names of the ordinates of a coordinate with the given dimension.
+pub(crate) fn dimension_names(dim: Dimension) -> &'static [&'static str] {
+    match dim {
+        Dimension::XY => &["x", "y"],
+        Dimension::XYZ => &["x", "y", "z"],
+        Dimension::XYM => &["x", "y", "m"],
+        Dimension::XYZM => &["x", "y", "z", "m"],
+    }
+}
+
 pub(crate) fn coord_type_to_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
-    match (coord_type, dim) {
-        (CoordType::Interleaved, Dimension::XY) => {
-            let values_field = Field::new("xy", DataType::Float64, false);
-            DataType::FixedSizeList(Arc::new(values_field), 2)
-        }
-        (CoordType::Interleaved, Dimension::XYZ) => {
-            let values_field = Field::new("xyz", DataType::Float64, false);
-            DataType::FixedSizeList(Arc::new(values_field), 3)
+    match coord_type {
+        CoordType::Interleaved => {
+            let values_field = Field::new(dimension_names(dim).concat(), DataType::Float64, false);
+            DataType::FixedSizeList(Arc::new(values_field), dim.size() as i32)
         }
-        (CoordType::Separated, Dimension::XY) => {
-            let values_fields = vec![
-                Field::new("x", DataType::Float64, false),
-                Field::new("y", DataType::Float64, false),
-            ];
-            DataType::Struct(values_fields.into())
-        }
-        (CoordType::Separated, Dimension::XYZ) => {
-            let values_fields = vec![
-                Field::new("x", DataType::Float64, false),
-                Field::new("y", DataType::Float64, false),
-                Field::new("z", DataType::Float64, false),
-            ];
+        CoordType::Separated => {
+            let values_fields = dimension_names(dim)
+                .iter()
+                .map(|name| Field::new(*name, DataType::Float64, false))
+                .collect::<Vec<_>>();
             DataType::Struct(values_fields.into())
         }
     }
@@ -215,48 +315,63 @@ fn point_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
     coord_type_to_data_type(coord_type, dim)
 }
 
-fn line_string_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
+/// A `List` or `LargeList` data type, depending on the offset size `O`.
+fn list_data_type<O: OffsetSizeTrait>(field: Field) -> DataType {
+    match O::IS_LARGE {
+        true => DataType::LargeList(field.into()),
+        false => DataType::List(field.into()),
+    }
+}
+
+/// A `List` or `LargeList` field, depending on the offset size `O`.
+fn list_field<O: OffsetSizeTrait>(name: &str, value: Field) -> Field {
+    Field::new(name, list_data_type::<O>(value), false)
+}
+
+fn line_string_data_type<O: OffsetSizeTrait>(coord_type: CoordType, dim: Dimension) -> DataType {
     let coords_type = coord_type_to_data_type(coord_type, dim);
-    let vertices_field = Field::new("vertices", coords_type, false).into();
-    DataType::List(vertices_field)
+    let vertices_field = Field::new("vertices", coords_type, false);
+    list_data_type::<O>(vertices_field)
 }
 
-fn polygon_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
+fn polygon_data_type<O: OffsetSizeTrait>(coord_type: CoordType, dim: Dimension) -> DataType {
     let coords_type = coord_type_to_data_type(coord_type, dim);
     let vertices_field = Field::new("vertices", coords_type, false);
-    let rings_field = Field::new_list("rings", vertices_field, false).into();
-    DataType::List(rings_field)
+    let rings_field = list_field::<O>("rings", vertices_field);
+    list_data_type::<O>(rings_field)
 }
 
-fn multi_point_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
+fn multi_point_data_type<O: OffsetSizeTrait>(coord_type: CoordType, dim: Dimension) -> DataType {
     let coords_type = coord_type_to_data_type(coord_type, dim);
-    let vertices_field = Field::new("points", coords_type, false).into();
-    DataType::List(vertices_field)
+    let vertices_field = Field::new("points", coords_type, false);
+    list_data_type::<O>(vertices_field)
 }
 
-fn multi_line_string_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
+fn multi_line_string_data_type<O: OffsetSizeTrait>(
+    coord_type: CoordType,
+    dim: Dimension,
+) -> DataType {
     let coords_type = coord_type_to_data_type(coord_type, dim);
     let vertices_field = Field::new("vertices", coords_type, false);
-    let linestrings_field = Field::new_list("linestrings", vertices_field, false).into();
-    DataType::List(linestrings_field)
+    let linestrings_field = list_field::<O>("linestrings", vertices_field);
+    list_data_type::<O>(linestrings_field)
 }
 
-fn multi_polygon_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
+fn multi_polygon_data_type<O: OffsetSizeTrait>(coord_type: CoordType, dim: Dimension) -> DataType {
     let coords_type = coord_type_to_data_type(coord_type, dim);
     let vertices_field = Field::new("vertices", coords_type, false);
-    let rings_field = Field::new_list("rings", vertices_field, false);
-    let polygons_field = Field::new_list("polygons", rings_field, false).into();
-    DataType::List(polygons_field)
+    let rings_field = list_field::<O>("rings", vertices_field);
+    let polygons_field = list_field::<O>("polygons", rings_field);
+    list_data_type::<O>(polygons_field)
 }
 
-pub(crate) fn mixed_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
+pub(crate) fn mixed_data_type<O: OffsetSizeTrait>(
+    coord_type: CoordType,
+    dim: Dimension,
+) -> DataType {
     let mut fields = vec![];
-    let mut type_ids = vec![];
-
-    match dim {
-        Dimension::XY => type_ids.extend([1, 2, 3, 4, 5, 6]),
-        Dimension::XYZ => type_ids.extend([11, 12, 13, 14, 15, 16]),
-    }
+    let offset = dim.order() as i8 * 10;
+    let type_ids = [1, 2, 3, 4, 5, 6].map(|id| id + offset);
 
     // Note: we manually construct the fields because these fields shouldn't have their own
     // GeoArrow extension metadata
@@ -266,28 +381,42 @@ pub(crate) fn mixed_data_type(coord_type: CoordType, dim: Dimension) -> DataType
         true,
     ));
 
-    let linestring = NativeType::LineString(coord_type, dim);
-    fields.push(Field::new("", linestring.to_data_type(), true));
-
-    let polygon = NativeType::Polygon(coord_type, dim);
-    fields.push(Field::new("", polygon.to_data_type(), true));
-
-    let multi_point = NativeType::MultiPoint(coord_type, dim);
-    fields.push(Field::new("", multi_point.to_data_type(), true));
-
-    let multi_line_string = NativeType::MultiLineString(coord_type, dim);
-    fields.push(Field::new("", multi_line_string.to_data_type(), true));
-
-    let multi_polygon = NativeType::MultiPolygon(coord_type, dim);
-    fields.push(Field::new("", multi_polygon.to_data_type(), true));
+    fields.push(Field::new(
+        "",
+        line_string_data_type::<O>(coord_type, dim),
+        true,
+    ));
+    fields.push(Field::new(
+        "",
+        polygon_data_type::<O>(coord_type, dim),
+        true,
+    ));
+    fields.push(Field::new(
+        "",
+        multi_point_data_type::<O>(coord_type, dim),
+        true,
+    ));
+    fields.push(Field::new(
+        "",
+        multi_line_string_data_type::<O>(coord_type, dim),
+        true,
+    ));
+    fields.push(Field::new(
+        "",
+        multi_polygon_data_type::<O>(coord_type, dim),
+        true,
+    ));
 
     let union_fields = UnionFields::new(type_ids, fields);
     DataType::Union(union_fields, UnionMode::Dense)
 }
 
-fn geometry_collection_data_type(coord_type: CoordType, dim: Dimension) -> DataType {
-    let geometries_field = Field::new("geometries", mixed_data_type(coord_type, dim), false).into();
-    DataType::List(geometries_field)
+fn geometry_collection_data_type<O: OffsetSizeTrait>(
+    coord_type: CoordType,
+    dim: Dimension,
+) -> DataType {
+    let geometries_field = Field::new("geometries", mixed_data_type::<O>(coord_type, dim), false);
+    list_data_type::<O>(geometries_field)
 }
 
 fn wkb_data_type<O: OffsetSizeTrait>() -> DataType {
@@ -305,26 +434,16 @@ fn wkt_data_type<O: OffsetSizeTrait>() -> DataType {
 }
 
 pub(crate) fn rect_fields(dim: Dimension) -> Fields {
-    let values_fields = match dim {
-        Dimension::XY => {
-            vec![
-                Field::new("xmin", DataType::Float64, false),
-                Field::new("ymin", DataType::Float64, false),
-                Field::new("xmax", DataType::Float64, false),
-                Field::new("ymax", DataType::Float64, false),
-            ]
-        }
-        Dimension::XYZ => {
-            vec![
-                Field::new("xmin", DataType::Float64, false),
-                Field::new("ymin", DataType::Float64, false),
-                Field::new("zmin", DataType::Float64, false),
-                Field::new("xmax", DataType::Float64, false),
-                Field::new("ymax", DataType::Float64, false),
-                Field::new("zmax", DataType::Float64, false),
-            ]
-        }
-    };
+    let names = dimension_names(dim);
+    let values_fields = names
+        .iter()
+        .map(|name| Field::new(format!("{name}min"), DataType::Float64, false))
+        .chain(
+            names
+                .iter()
+                .map(|name| Field::new(format!("{name}max"), DataType::Float64, false)),
+        )
+        .collect::<Vec<_>>();
 
     values_fields.into()
 }
@@ -333,83 +452,28 @@ fn rect_data_type(dim: Dimension) -> DataType {
     DataType::Struct(rect_fields(dim))
 }
 
-fn geometry_data_type(coord_type: CoordType) -> DataType {
+fn geometry_data_type<O: OffsetSizeTrait>(coord_type: CoordType) -> DataType {
     let mut fields = vec![];
-    let type_ids = vec![1, 2, 3, 4, 5, 6, 7, 11, 12, 13, 14, 15, 16, 17];
+    let mut type_ids = vec![];
 
     // Note: we manually construct the fields because these fields shouldn't have their own
     // GeoArrow extension metadata
-    fields.push(Field::new(
-        "",
-        NativeType::Point(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::LineString(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::Polygon(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPoint(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiLineString(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPolygon(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::GeometryCollection(coord_type, Dimension::XY).to_data_type(),
-        true,
-    ));
-
-    fields.push(Field::new(
-        "",
-        NativeType::Point(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::LineString(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::Polygon(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPoint(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiLineString(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::MultiPolygon(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
-    fields.push(Field::new(
-        "",
-        NativeType::GeometryCollection(coord_type, Dimension::XYZ).to_data_type(),
-        true,
-    ));
+    for dim in Dimension::ALL {
+        let offset = dim.order() as i8 * 10;
+        let child_types = [
+            point_data_type(coord_type, dim),
+            line_string_data_type::<O>(coord_type, dim),
+            polygon_data_type::<O>(coord_type, dim),
+            multi_point_data_type::<O>(coord_type, dim),
+            multi_line_string_data_type::<O>(coord_type, dim),
+            multi_polygon_data_type::<O>(coord_type, dim),
+            geometry_collection_data_type::<O>(coord_type, dim),
+        ];
+        for (i, child_type) in child_types.into_iter().enumerate() {
+            type_ids.push(offset + i as i8 + 1);
+            fields.push(Field::new("", child_type, true));
+        }
+    }
 
     let union_fields = UnionFields::new(type_ids, fields);
     DataType::Union(union_fields, UnionMode::Dense)
@@ -421,14 +485,15 @@ impl NativeType {
         use NativeType::*;
         match self {
             Point(ct, _) => *ct,
-            LineString(ct, _) => *ct,
-            Polygon(ct, _) => *ct,
-            MultiPoint(ct, _) => *ct,
-            MultiLineString(ct, _) => *ct,
-            MultiPolygon(ct, _) => *ct,
-            GeometryCollection(ct, _) => *ct,
+            LineString(ct, _) | LargeLineString(ct, _) => *ct,
+            Polygon(ct, _) | LargePolygon(ct, _) => *ct,
+            MultiPoint(ct, _) | LargeMultiPoint(ct, _) => *ct,
+            MultiLineString(ct, _) | LargeMultiLineString(ct, _) => *ct,
+            MultiPolygon(ct, _) | LargeMultiPolygon(ct, _) => *ct,
+            Mixed(ct, _) | LargeMixed(ct, _) => *ct,
+            GeometryCollection(ct, _) | LargeGeometryCollection(ct, _) => *ct,
             Rect(_) => CoordType::Separated,
-            Geometry(ct) => *ct,
+            Geometry(ct) | LargeGeometry(ct) => *ct,
         }
     }
 
@@ -439,14 +504,15 @@ impl NativeType {
         use NativeType::*;
         match self {
             Point(_, dim) => Some(*dim),
-            LineString(_, dim) => Some(*dim),
-            Polygon(_, dim) => Some(*dim),
-            MultiPoint(_, dim) => Some(*dim),
-            MultiLineString(_, dim) => Some(*dim),
-            MultiPolygon(_, dim) => Some(*dim),
-            GeometryCollection(_, dim) => Some(*dim),
+            LineString(_, dim) | LargeLineString(_, dim) => Some(*dim),
+            Polygon(_, dim) | LargePolygon(_, dim) => Some(*dim),
+            MultiPoint(_, dim) | LargeMultiPoint(_, dim) => Some(*dim),
+            MultiLineString(_, dim) | LargeMultiLineString(_, dim) => Some(*dim),
+            MultiPolygon(_, dim) | LargeMultiPolygon(_, dim) => Some(*dim),
+            Mixed(_, dim) | LargeMixed(_, dim) => Some(*dim),
+            GeometryCollection(_, dim) | LargeGeometryCollection(_, dim) => Some(*dim),
             Rect(dim) => Some(*dim),
-            Geometry(_) => None,
+            Geometry(_) | LargeGeometry(_) => None,
         }
     }
 
@@ -468,14 +534,31 @@ impl NativeType {
         use NativeType::*;
         match self {
             Point(coord_type, dim) => point_data_type(*coord_type, *dim),
-            LineString(coord_type, dim) => line_string_data_type(*coord_type, *dim),
-            Polygon(coord_type, dim) => polygon_data_type(*coord_type, *dim),
-            MultiPoint(coord_type, dim) => multi_point_data_type(*coord_type, *dim),
-            MultiLineString(coord_type, dim) => multi_line_string_data_type(*coord_type, *dim),
-            MultiPolygon(coord_type, dim) => multi_polygon_data_type(*coord_type, *dim),
-            GeometryCollection(coord_type, dim) => geometry_collection_data_type(*coord_type, *dim),
+            LineString(coord_type, dim) => line_string_data_type::<i32>(*coord_type, *dim),
+            LargeLineString(coord_type, dim) => line_string_data_type::<i64>(*coord_type, *dim),
+            Polygon(coord_type, dim) => polygon_data_type::<i32>(*coord_type, *dim),
+            LargePolygon(coord_type, dim) => polygon_data_type::<i64>(*coord_type, *dim),
+            MultiPoint(coord_type, dim) => multi_point_data_type::<i32>(*coord_type, *dim),
+            LargeMultiPoint(coord_type, dim) => multi_point_data_type::<i64>(*coord_type, *dim),
+            MultiLineString(coord_type, dim) => {
+                multi_line_string_data_type::<i32>(*coord_type, *dim)
+            }
+            LargeMultiLineString(coord_type, dim) => {
+                multi_line_string_data_type::<i64>(*coord_type, *dim)
+            }
+            MultiPolygon(coord_type, dim) => multi_polygon_data_type::<i32>(*coord_type, *dim),
+            LargeMultiPolygon(coord_type, dim) => multi_polygon_data_type::<i64>(*coord_type, *dim),
+            Mixed(coord_type, dim) => mixed_data_type::<i32>(*coord_type, *dim),
+            LargeMixed(coord_type, dim) => mixed_data_type::<i64>(*coord_type, *dim),
+            GeometryCollection(coord_type, dim) => {
+                geometry_collection_data_type::<i32>(*coord_type, *dim)
+            }
+            LargeGeometryCollection(coord_type, dim) => {
+                geometry_collection_data_type::<i64>(*coord_type, *dim)
+            }
             Rect(dim) => rect_data_type(*dim),
-            Geometry(coord_type) => geometry_data_type(*coord_type),
+            Geometry(coord_type) => geometry_data_type::<i32>(*coord_type),
+            LargeGeometry(coord_type) => geometry_data_type::<i64>(*coord_type),
         }
     }
 
@@ -493,14 +576,17 @@ impl NativeType {
         use NativeType::*;
         match self {
             Point(_, _) => "geoarrow.point",
-            LineString(_, _) => "geoarrow.linestring",
-            Polygon(_, _) => "geoarrow.polygon",
-            MultiPoint(_, _) => "geoarrow.multipoint",
-            MultiLineString(_, _) => "geoarrow.multilinestring",
-            MultiPolygon(_, _) => "geoarrow.multipolygon",
-            GeometryCollection(_, _) => "geoarrow.geometrycollection",
+            LineString(_, _) | LargeLineString(_, _) => "geoarrow.linestring",
+            Polygon(_, _) | LargePolygon(_, _) => "geoarrow.polygon",
+            MultiPoint(_, _) | LargeMultiPoint(_, _) => "geoarrow.multipoint",
+            MultiLineString(_, _) | LargeMultiLineString(_, _) => "geoarrow.multilinestring",
+            MultiPolygon(_, _) | LargeMultiPolygon(_, _) => "geoarrow.multipolygon",
+            Mixed(_, _) | LargeMixed(_, _) => "geoarrow.geometry",
+            GeometryCollection(_, _) | LargeGeometryCollection(_, _) => {
+                "geoarrow.geometrycollection"
+            }
             Rect(_) => "geoarrow.box",
-            Geometry(_) => "geoarrow.geometry",
+            Geometry(_) | LargeGeometry(_) => "geoarrow.geometry",
         }
     }
 
@@ -578,13 +664,22 @@ impl NativeType {
         match self {
             Point(_, dim) => Point(coord_type, dim),
             LineString(_, dim) => LineString(coord_type, dim),
+            LargeLineString(_, dim) => LargeLineString(coord_type, dim),
             Polygon(_, dim) => Polygon(coord_type, dim),
+            LargePolygon(_, dim) => LargePolygon(coord_type, dim),
             MultiPoint(_, dim) => MultiPoint(coord_type, dim),
+            LargeMultiPoint(_, dim) => LargeMultiPoint(coord_type, dim),
             MultiLineString(_, dim) => MultiLineString(coord_type, dim),
+            LargeMultiLineString(_, dim) => LargeMultiLineString(coord_type, dim),
             MultiPolygon(_, dim) => MultiPolygon(coord_type, dim),
+            LargeMultiPolygon(_, dim) => LargeMultiPolygon(coord_type, dim),
+            Mixed(_, dim) => Mixed(coord_type, dim),
+            LargeMixed(_, dim) => LargeMixed(coord_type, dim),
             GeometryCollection(_, dim) => GeometryCollection(coord_type, dim),
+            LargeGeometryCollection(_, dim) => LargeGeometryCollection(coord_type, dim),
             Rect(dim) => Rect(dim),
             Geometry(_) => Geometry(coord_type),
+            LargeGeometry(_) => LargeGeometry(coord_type),
         }
     }
 
@@ -603,15 +698,49 @@ impl NativeType {
         match self {
             Point(coord_type, _) => Point(coord_type, dim),
             LineString(coord_type, _) => LineString(coord_type, dim),
+            LargeLineString(coord_type, _) => LargeLineString(coord_type, dim),
             Polygon(coord_type, _) => Polygon(coord_type, dim),
+            LargePolygon(coord_type, _) => LargePolygon(coord_type, dim),
             MultiPoint(coord_type, _) => MultiPoint(coord_type, dim),
+            LargeMultiPoint(coord_type, _) => LargeMultiPoint(coord_type, dim),
             MultiLineString(coord_type, _) => MultiLineString(coord_type, dim),
+            LargeMultiLineString(coord_type, _) => LargeMultiLineString(coord_type, dim),
             MultiPolygon(coord_type, _) => MultiPolygon(coord_type, dim),
+            LargeMultiPolygon(coord_type, _) => LargeMultiPolygon(coord_type, dim),
+            Mixed(coord_type, _) => Mixed(coord_type, dim),
+            LargeMixed(coord_type, _) => LargeMixed(coord_type, dim),
             GeometryCollection(coord_type, _) => GeometryCollection(coord_type, dim),
+            LargeGeometryCollection(coord_type, _) => LargeGeometryCollection(coord_type, dim),
             Rect(_) => Rect(dim),
             Geometry(coord_type) => Geometry(coord_type),
+            LargeGeometry(coord_type) => LargeGeometry(coord_type),
         }
     }
+
+    /// Returns `true` if this data type uses `i64` offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::datatypes::{Dimension, NativeType};
+    ///
+    /// assert!(NativeType::LargeLineString(Default::default(), Dimension::XY).is_large());
+    /// assert!(!NativeType::LineString(Default::default(), Dimension::XY).is_large());
+    /// ```
+    pub fn is_large(&self) -> bool {
+        use NativeType::*;
+        matches!(
+            self,
+            LargeLineString(_, _)
+                | LargePolygon(_, _)
+                | LargeMultiPoint(_, _)
+                | LargeMultiLineString(_, _)
+                | LargeMultiPolygon(_, _)
+                | LargeMixed(_, _)
+                | LargeGeometryCollection(_, _)
+                | LargeGeometry(_)
+        )
+    }
 }
 
 impl SerializedType {
@@ -718,11 +847,20 @@ impl AnyType {
 
 fn parse_data_type(data_type: &DataType) -> Result<(CoordType, Dimension)> {
     match data_type {
-        DataType::FixedSizeList(_, list_size) => {
-            Ok((CoordType::Interleaved, (*list_size).try_into()?))
+        DataType::FixedSizeList(inner_field, list_size) => {
+            let dim = match (*list_size, inner_field.name().as_str()) {
+                (3, "xym") => Dimension::XYM,
+                (list_size, _) => list_size.try_into()?,
+            };
+            Ok((CoordType::Interleaved, dim))
         }
         DataType::Struct(struct_fields) => {
-            Ok((CoordType::Separated, struct_fields.len().try_into()?))
+            let has_m = struct_fields.iter().any(|f| f.name() == "m");
+            let dim = match (struct_fields.len(), has_m) {
+                (3, true) => Dimension::XYM,
+                (num_fields, _) => num_fields.try_into()?,
+            };
+            Ok((CoordType::Separated, dim))
         }
         dt => Err(GeoArrowError::General(format!("Unexpected data type {dt}"))),
     }
@@ -733,211 +871,158 @@ fn parse_point(field: &Field) -> Result<NativeType> {
     Ok(NativeType::Point(ct, dim))
 }
 
-fn parse_linestring(field: &Field) -> Result<NativeType> {
-    match field.data_type() {
-        DataType::List(inner_field) | DataType::LargeList(inner_field) => {
-            let (ct, dim) = parse_data_type(inner_field.data_type())?;
-            Ok(NativeType::LineString(ct, dim))
+/// Unwrap a `List` or `LargeList` data type, returning its child field and whether it uses `i64`
+/// offsets.
+fn parse_list(data_type: &DataType) -> Result<(&Field, bool)> {
+    match data_type {
+        DataType::List(inner) => Ok((inner, false)),
+        DataType::LargeList(inner) => Ok((inner, true)),
+        dt => Err(GeoArrowError::General(format!(
+            "Expected List or LargeList, got {dt}"
+        ))),
+    }
+}
+
+/// Unwrap `depth` levels of nested `List` or `LargeList` data types, returning the coord type and
+/// dimension of the innermost coordinates and whether the lists use `i64` offsets.
+///
+/// All levels of nesting must use the same offset size.
+fn parse_nested_list(data_type: &DataType, depth: usize) -> Result<(CoordType, Dimension, bool)> {
+    let (mut inner, large) = parse_list(data_type)?;
+    for _ in 1..depth {
+        let (next, next_large) = parse_list(inner.data_type())?;
+        if next_large != large {
+            return Err(GeoArrowError::General(
+                "Nested lists must all use the same offset size".to_string(),
+            ));
         }
-        dt => Err(GeoArrowError::General(format!("Unexpected data type {dt}"))),
+        inner = next;
+    }
+    let (ct, dim) = parse_data_type(inner.data_type())?;
+    Ok((ct, dim, large))
+}
+
+fn parse_linestring(field: &Field) -> Result<NativeType> {
+    let (ct, dim, large) = parse_nested_list(field.data_type(), 1)?;
+    if large {
+        Ok(NativeType::LargeLineString(ct, dim))
+    } else {
+        Ok(NativeType::LineString(ct, dim))
     }
 }
 
 fn parse_polygon(field: &Field) -> Result<NativeType> {
-    match field.data_type() {
-        DataType::List(inner1) => match inner1.data_type() {
-            DataType::List(inner2) => {
-                let (ct, dim) = parse_data_type(inner2.data_type())?;
-                Ok(NativeType::Polygon(ct, dim))
-            }
-            _ => panic!(),
-        },
-        DataType::LargeList(inner1) => match inner1.data_type() {
-            DataType::LargeList(inner2) => {
-                let (ct, dim) = parse_data_type(inner2.data_type())?;
-                Ok(NativeType::Polygon(ct, dim))
-            }
-            _ => panic!(),
-        },
-        _ => panic!(),
+    let (ct, dim, large) = parse_nested_list(field.data_type(), 2)?;
+    if large {
+        Ok(NativeType::LargePolygon(ct, dim))
+    } else {
+        Ok(NativeType::Polygon(ct, dim))
     }
 }
 
 fn parse_multi_point(field: &Field) -> Result<NativeType> {
-    match field.data_type() {
-        DataType::List(inner_field) => {
-            let (ct, dim) = parse_data_type(inner_field.data_type())?;
-            Ok(NativeType::MultiPoint(ct, dim))
-        }
-        DataType::LargeList(inner_field) => {
-            let (ct, dim) = parse_data_type(inner_field.data_type())?;
-            Ok(NativeType::MultiPoint(ct, dim))
-        }
-        _ => panic!(),
+    let (ct, dim, large) = parse_nested_list(field.data_type(), 1)?;
+    if large {
+        Ok(NativeType::LargeMultiPoint(ct, dim))
+    } else {
+        Ok(NativeType::MultiPoint(ct, dim))
     }
 }
 
 fn parse_multi_linestring(field: &Field) -> Result<NativeType> {
-    match field.data_type() {
-        DataType::List(inner1) => match inner1.data_type() {
-            DataType::List(inner2) => {
-                let (ct, dim) = parse_data_type(inner2.data_type())?;
-                Ok(NativeType::MultiLineString(ct, dim))
-            }
-            _ => panic!(),
-        },
-        DataType::LargeList(inner1) => match inner1.data_type() {
-            DataType::LargeList(inner2) => {
-                let (ct, dim) = parse_data_type(inner2.data_type())?;
-                Ok(NativeType::MultiLineString(ct, dim))
-            }
-            _ => panic!(),
-        },
-        _ => panic!(),
+    let (ct, dim, large) = parse_nested_list(field.data_type(), 2)?;
+    if large {
+        Ok(NativeType::LargeMultiLineString(ct, dim))
+    } else {
+        Ok(NativeType::MultiLineString(ct, dim))
     }
 }
 
 fn parse_multi_polygon(field: &Field) -> Result<NativeType> {
-    match field.data_type() {
-        DataType::List(inner1) => match inner1.data_type() {
-            DataType::List(inner2) => match inner2.data_type() {
-                DataType::List(inner3) => {
-                    let (ct, dim) = parse_data_type(inner3.data_type())?;
-                    Ok(NativeType::MultiPolygon(ct, dim))
-                }
-                _ => panic!(),
-            },
-            _ => panic!(),
-        },
-        DataType::LargeList(inner1) => match inner1.data_type() {
-            DataType::LargeList(inner2) => match inner2.data_type() {
-                DataType::LargeList(inner3) => {
-                    let (ct, dim) = parse_data_type(inner3.data_type())?;
-                    Ok(NativeType::MultiPolygon(ct, dim))
-                }
-                _ => panic!(),
-            },
-            _ => panic!(),
-        },
-        _ => panic!(),
+    let (ct, dim, large) = parse_nested_list(field.data_type(), 3)?;
+    if large {
+        Ok(NativeType::LargeMultiPolygon(ct, dim))
+    } else {
+        Ok(NativeType::MultiPolygon(ct, dim))
+    }
+}
+
+/// Parse a single child of a union data type, returning its coord type, dimension, and whether
+/// it uses `i64` offsets. Point children never have offsets and are reported as not large.
+///
+/// The type id encodes both the geometry type (`type_id % 10`) and the dimension
+/// (`type_id / 10`).
+fn parse_union_child(type_id: i8, field: &Field) -> Result<(CoordType, Dimension, bool)> {
+    let expected_dim = Dimension::from_order((type_id / 10) as usize)?;
+    let native_type = match type_id % 10 {
+        1 => parse_point(field)?,
+        2 => parse_linestring(field)?,
+        3 => parse_polygon(field)?,
+        4 => parse_multi_point(field)?,
+        5 => parse_multi_linestring(field)?,
+        6 => parse_multi_polygon(field)?,
+        7 => parse_geometry_collection(field)?,
+        _ => {
+            return Err(GeoArrowError::General(format!(
+                "Unexpected type id {type_id}"
+            )))
+        }
+    };
+    let dim = native_type.dimension().unwrap();
+    if dim != expected_dim {
+        return Err(GeoArrowError::General(format!(
+            "Union child with type id {type_id} has dimension {dim:?}, expected {expected_dim:?}"
+        )));
     }
+    Ok((native_type.coord_type(), dim, native_type.is_large()))
 }
 
-fn parse_mixed(field: &Field) -> Result<(CoordType, Dimension)> {
+/// The coord types, dimensions, offset size and presence of a geometry collection child found in
+/// the children of a union data type.
+type UnionChildren = (HashSet<CoordType>, HashSet<Dimension>, Option<bool>, bool);
+
+/// Parse the children of a union data type, returning the set of coord types and dimensions
+/// found, whether the children use `i64` offsets (`None` if only point children are present), and
+/// whether there's a geometry collection child.
+fn parse_union_children(fields: &UnionFields) -> Result<UnionChildren> {
+    let mut coord_types: HashSet<CoordType> = HashSet::new();
+    let mut dimensions: HashSet<Dimension> = HashSet::new();
+    let mut large_offsets: HashSet<bool> = HashSet::new();
+    let mut has_geometry_collection = false;
+
+    fields.iter().try_for_each(|(type_id, field)| {
+        let (ct, dim, large) = parse_union_child(type_id, field)?;
+        coord_types.insert(ct);
+        dimensions.insert(dim);
+        // Point children have no offsets, so they're compatible with either offset size
+        if type_id % 10 != 1 {
+            large_offsets.insert(large);
+        }
+        if type_id % 10 == 7 {
+            has_geometry_collection = true;
+        }
+        Ok::<_, GeoArrowError>(())
+    })?;
+
+    if coord_types.len() > 1 {
+        return Err(GeoArrowError::General(
+            "Multi coord types in union".to_string(),
+        ));
+    }
+    if large_offsets.len() > 1 {
+        return Err(GeoArrowError::General(
+            "Union children must all use the same offset size".to_string(),
+        ));
+    }
+
+    let large = large_offsets.drain().next();
+    Ok((coord_types, dimensions, large, has_geometry_collection))
+}
+
+fn parse_mixed(field: &Field) -> Result<(CoordType, Dimension, Option<bool>)> {
     match field.data_type() {
         DataType::Union(fields, _) => {
-            let mut coord_types: HashSet<CoordType> = HashSet::new();
-            let mut dimensions: HashSet<Dimension> = HashSet::new();
-            fields.iter().try_for_each(|(type_id, field)| {
-                match type_id {
-                    1 => match parse_point(field)? {
-                        NativeType::Point(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    2 => match parse_linestring(field)? {
-                        NativeType::LineString(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    3 => match parse_polygon(field)? {
-                        NativeType::Polygon(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    4 => match parse_multi_point(field)? {
-                        NativeType::MultiPoint(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    5 => match parse_multi_linestring(field)? {
-                        NativeType::MultiLineString(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    6 => match parse_multi_polygon(field)? {
-                        NativeType::MultiPolygon(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    7 => match parse_geometry_collection(field)? {
-                        NativeType::GeometryCollection(ct, Dimension::XY) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XY);
-                        }
-                        _ => unreachable!(),
-                    },
-                    11 => match parse_point(field)? {
-                        NativeType::Point(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    12 => match parse_linestring(field)? {
-                        NativeType::LineString(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    13 => match parse_polygon(field)? {
-                        NativeType::Polygon(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    14 => match parse_multi_point(field)? {
-                        NativeType::MultiPoint(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    15 => match parse_multi_linestring(field)? {
-                        NativeType::MultiLineString(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    16 => match parse_multi_polygon(field)? {
-                        NativeType::MultiPolygon(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    17 => match parse_geometry_collection(field)? {
-                        NativeType::GeometryCollection(ct, Dimension::XYZ) => {
-                            coord_types.insert(ct);
-                            dimensions.insert(Dimension::XYZ);
-                        }
-                        _ => unreachable!(),
-                    },
-                    id => panic!("unexpected type id {}", id),
-                };
-                Ok::<_, GeoArrowError>(())
-            })?;
-
-            if coord_types.len() > 1 {
-                return Err(GeoArrowError::General(
-                    "Multi coord types in union".to_string(),
-                ));
-            }
+            let (mut coord_types, mut dimensions, large, _) = parse_union_children(fields)?;
             if dimensions.len() > 1 {
                 return Err(GeoArrowError::General(
                     "Multi dimensions types in union".to_string(),
@@ -946,21 +1031,28 @@ fn parse_mixed(field: &Field) -> Result<(CoordType, Dimension)> {
 
             let coord_type = coord_types.drain().next().unwrap();
             let dimension = dimensions.drain().next().unwrap();
-            Ok((coord_type, dimension))
+            Ok((coord_type, dimension, large))
         }
-        _ => panic!("Unexpected data type"),
+        dt => Err(GeoArrowError::General(format!(
+            "Expected union data type, got {dt}"
+        ))),
     }
 }
 
 fn parse_geometry_collection(field: &Field) -> Result<NativeType> {
     // We need to parse the _inner_ type of the geometry collection as a union so that we can check
     // what coordinate type it's using.
-    match field.data_type() {
-        DataType::List(inner_field) | DataType::LargeList(inner_field) => {
-            let (coord_type, dim) = parse_mixed(inner_field)?;
-            Ok(NativeType::GeometryCollection(coord_type, dim))
-        }
-        _ => panic!(),
+    let (inner_field, large) = parse_list(field.data_type())?;
+    let (coord_type, dim, inner_large) = parse_mixed(inner_field)?;
+    if inner_large.is_some_and(|inner_large| inner_large != large) {
+        return Err(GeoArrowError::General(
+            "Geometry collection and its children must use the same offset size".to_string(),
+        ));
+    }
+    if large {
+        Ok(NativeType::LargeGeometryCollection(coord_type, dim))
+    } else {
+        Ok(NativeType::GeometryCollection(coord_type, dim))
     }
 }
 
@@ -982,118 +1074,47 @@ fn parse_wkt(field: &Field) -> SerializedType {
 
 fn parse_rect(field: &Field) -> NativeType {
     match field.data_type() {
-        DataType::Struct(struct_fields) => match struct_fields.len() {
-            4 => NativeType::Rect(Dimension::XY),
-            6 => NativeType::Rect(Dimension::XYZ),
-            _ => panic!("unexpected number of struct fields"),
-        },
+        DataType::Struct(struct_fields) => {
+            let has_m = struct_fields.iter().any(|f| f.name() == "mmin");
+            match (struct_fields.len(), has_m) {
+                (4, _) => NativeType::Rect(Dimension::XY),
+                (6, false) => NativeType::Rect(Dimension::XYZ),
+                (6, true) => NativeType::Rect(Dimension::XYM),
+                (8, _) => NativeType::Rect(Dimension::XYZM),
+                _ => panic!("unexpected number of struct fields"),
+            }
+        }
         _ => panic!("unexpected data type parsing rect"),
     }
 }
 
+/// Parse a `geoarrow.geometry` field.
+///
+/// A union whose children all share a single dimension and which has no geometry collection
+/// child is parsed as [`NativeType::Mixed`]. Any other union is parsed as
+/// [`NativeType::Geometry`]. If the union's children use `LargeList` offsets, the `Large` variant
+/// of each is returned instead.
 fn parse_geometry(field: &Field) -> Result<NativeType> {
     if let DataType::Union(fields, _mode) = field.data_type() {
-        let mut coord_types: HashSet<CoordType> = HashSet::new();
-
-        fields.iter().try_for_each(|(type_id, field)| {
-            match type_id {
-                1 => match parse_point(field)? {
-                    NativeType::Point(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                2 => match parse_linestring(field)? {
-                    NativeType::LineString(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                3 => match parse_polygon(field)? {
-                    NativeType::Polygon(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                4 => match parse_multi_point(field)? {
-                    NativeType::MultiPoint(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                5 => match parse_multi_linestring(field)? {
-                    NativeType::MultiLineString(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                6 => match parse_multi_polygon(field)? {
-                    NativeType::MultiPolygon(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                7 => match parse_geometry_collection(field)? {
-                    NativeType::GeometryCollection(ct, Dimension::XY) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                11 => match parse_point(field)? {
-                    NativeType::Point(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                12 => match parse_linestring(field)? {
-                    NativeType::LineString(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                13 => match parse_polygon(field)? {
-                    NativeType::Polygon(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                14 => match parse_multi_point(field)? {
-                    NativeType::MultiPoint(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                15 => match parse_multi_linestring(field)? {
-                    NativeType::MultiLineString(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                16 => match parse_multi_polygon(field)? {
-                    NativeType::MultiPolygon(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                17 => match parse_geometry_collection(field)? {
-                    NativeType::GeometryCollection(ct, Dimension::XYZ) => {
-                        coord_types.insert(ct);
-                    }
-                    _ => unreachable!(),
-                },
-                id => panic!("unexpected type id {}", id),
-            };
-            Ok::<_, GeoArrowError>(())
-        })?;
-
-        if coord_types.len() > 1 {
-            return Err(GeoArrowError::General(
-                "Multi coord types in union".to_string(),
-            ));
-        }
+        let (mut coord_types, mut dimensions, large, has_geometry_collection) =
+            parse_union_children(fields)?;
 
         let coord_type = coord_types.drain().next().unwrap();
-        Ok(NativeType::Geometry(coord_type))
+        match (
+            dimensions.len() == 1 && !has_geometry_collection,
+            large.unwrap_or(false),
+        ) {
+            (true, false) => Ok(NativeType::Mixed(
+                coord_type,
+                dimensions.drain().next().unwrap(),
+            )),
+            (true, true) => Ok(NativeType::LargeMixed(
+                coord_type,
+                dimensions.drain().next().unwrap(),
+            )),
+            (false, false) => Ok(NativeType::Geometry(coord_type)),
+            (false, true) => Ok(NativeType::LargeGeometry(coord_type)),
+        }
     } else {
         Err(GeoArrowError::General("Expected union type".to_string()))
     }
@@ -1114,8 +1135,6 @@ impl TryFrom<&Field> for NativeType {
                 "geoarrow.geometrycollection" => parse_geometry_collection(field)?,
                 "geoarrow.box" => parse_rect(field),
                 "geoarrow.geometry" => parse_geometry(field)?,
-                // We always parse geoarrow.geometry to a GeometryArray
-                // "geoarrow.geometry" => parse_mixed(field)?,
                 name => return Err(GeoArrowError::General(format!("Expected GeoArrow native type, got '{}'.\nIf you're passing a serialized GeoArrow type like 'geoarrow.wkb' or 'geoarrow.wkt', you need to parse to a native representation.", name))),
             };
             Ok(data_type)
@@ -1124,12 +1143,10 @@ impl TryFrom<&Field> for NativeType {
             // metadata should use TryFrom for a specific geometry type directly, instead of using
             // GeometryArray
             let data_type = match field.data_type() {
-                DataType::Struct(struct_fields) => match struct_fields.len() {
-                    2 => NativeType::Point(CoordType::Separated, Dimension::XY),
-                    3 => NativeType::Point(CoordType::Separated, Dimension::XYZ),
-                    l => return Err(GeoArrowError::General(format!("incorrect number of struct fields {l}"))),
-                },
-                DataType::FixedSizeList(_, list_size) => NativeType::Point(CoordType::Interleaved, (*list_size as usize).try_into()?),
+                dt @ (DataType::Struct(_) | DataType::FixedSizeList(_, _)) => {
+                    let (coord_type, dim) = parse_data_type(dt)?;
+                    NativeType::Point(coord_type, dim)
+                }
                 _ => return Err(GeoArrowError::General("Only FixedSizeList and Struct arrays are unambigously typed for a GeoArrow native type and can be used without extension metadata.".to_string())),
             };
             Ok(data_type)
@@ -1214,4 +1231,44 @@ mod test {
         let data_type: NativeType = field.as_ref().try_into().unwrap();
         assert_eq!(geom_array.data_type(), data_type);
     }
+
+    #[test]
+    fn native_type_round_trip_all_dimensions() {
+        for coord_type in [CoordType::Interleaved, CoordType::Separated] {
+            for dim in Dimension::ALL {
+                let types = [
+                    NativeType::Point(coord_type, dim),
+                    NativeType::LineString(coord_type, dim),
+                    NativeType::Polygon(coord_type, dim),
+                    NativeType::MultiPoint(coord_type, dim),
+                    NativeType::MultiLineString(coord_type, dim),
+                    NativeType::MultiPolygon(coord_type, dim),
+                    NativeType::Mixed(coord_type, dim),
+                    NativeType::GeometryCollection(coord_type, dim),
+                    NativeType::Rect(dim),
+                    NativeType::LargeLineString(coord_type, dim),
+                    NativeType::LargePolygon(coord_type, dim),
+                    NativeType::LargeMultiPoint(coord_type, dim),
+                    NativeType::LargeMultiLineString(coord_type, dim),
+                    NativeType::LargeMultiPolygon(coord_type, dim),
+                    NativeType::LargeMixed(coord_type, dim),
+                    NativeType::LargeGeometryCollection(coord_type, dim),
+                ];
+                for typ in types {
+                    let field = typ.to_field("geometry", true);
+                    let data_type: NativeType = (&field).try_into().unwrap();
+                    assert_eq!(typ, data_type);
+                }
+            }
+
+            for typ in [
+                NativeType::Geometry(coord_type),
+                NativeType::LargeGeometry(coord_type),
+            ] {
+                let field = typ.to_field("geometry", true);
+                let data_type: NativeType = (&field).try_into().unwrap();
+                assert_eq!(typ, data_type);
+            }
+        }
+    }
 }
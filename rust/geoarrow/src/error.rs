@@ -40,6 +40,11 @@ pub enum GeoArrowError {
     #[error(transparent)]
     GdalError(#[from] gdal::errors::GdalError),
 
+    /// [geodesy::Error]
+    #[cfg(feature = "geodesy")]
+    #[error(transparent)]
+    GeodesyError(#[from] geodesy::Error),
+
     /// [geozero::error::GeozeroError]
     #[error(transparent)]
     GeozeroError(#[from] geozero::error::GeozeroError),
@@ -50,7 +55,7 @@ pub enum GeoArrowError {
     GeosError(#[from] geos::Error),
 
     /// [object_store::Error]
-    #[cfg(feature = "flatgeobuf_async")]
+    #[cfg(any(feature = "flatgeobuf_async", feature = "pmtiles_async"))]
     #[error(transparent)]
     ObjectStoreError(#[from] object_store::Error),
 
@@ -75,3 +75,223 @@ impl CRSTransform for DefaultCRSTransform {
         Ok(None)
     }
 }
+
+/// The [CRSTransform] used by writers when none is provided.
+///
+/// This is [ProjCRSTransform] when the `proj` feature is enabled and [DefaultCRSTransform]
+/// otherwise.
+pub fn default_crs_transform() -> Box<dyn CRSTransform> {
+    #[cfg(feature = "proj")]
+    {
+        Box::new(ProjCRSTransform::default())
+    }
+
+    #[cfg(not(feature = "proj"))]
+    {
+        Box::new(DefaultCRSTransform::default())
+    }
+}
+
+/// The definition of the CRS stored in `meta`, in a form that PROJ can parse.
+///
+/// PROJ accepts PROJJSON, WKT and `AUTHORITY:CODE` strings alike, so this only needs to serialize
+/// PROJJSON objects back to a string. An SRID is resolved as an EPSG code unless it already names
+/// its authority. Returns `None` if there is no CRS.
+#[cfg(feature = "proj")]
+pub(crate) fn proj_definition(meta: &ArrayMetadata) -> Result<Option<String>> {
+    match (&meta.crs, &meta.crs_type) {
+        (None, _) => Ok(None),
+        (Some(Value::String(srid)), Some(CRSType::Srid)) if !srid.contains(':') => {
+            Ok(Some(format!("EPSG:{}", srid.trim())))
+        }
+        (Some(Value::Number(srid)), Some(CRSType::Srid)) => Ok(Some(format!("EPSG:{srid}"))),
+        (Some(crs @ Value::Object(_)), _) => Ok(Some(crs.to_string())),
+        (Some(Value::String(crs)), _) => Ok(Some(crs.clone())),
+        (Some(crs), _) => Err(GeoArrowError::General(format!(
+            "Unable to convert CRS {crs}: expected PROJJSON or a string"
+        ))),
+    }
+}
+
+/// A [CRSTransform] that uses PROJ to convert between CRS representations.
+///
+/// Any CRS that PROJ can parse can be converted: PROJJSON, WKT and `AUTHORITY:CODE` strings, as
+/// well as SRIDs, which are looked up as EPSG codes. A CRS that PROJ can't parse is an error
+/// rather than being dropped.
+#[cfg(feature = "proj")]
+#[derive(Debug, Clone, Default)]
+pub struct ProjCRSTransform {}
+
+#[cfg(feature = "proj")]
+impl ProjCRSTransform {
+    fn parse(&self, meta: &ArrayMetadata) -> Result<Option<ffi::ProjCrs>> {
+        proj_definition(meta)?
+            .map(|definition| ffi::ProjCrs::try_new(&definition))
+            .transpose()
+    }
+
+    /// Convert the CRS contained in this ArrayMetadata to an `AUTHORITY:CODE` string.
+    ///
+    /// Returns `None` if there is no CRS or if PROJ knows no identifier for it.
+    pub fn extract_authority_code(&self, meta: &ArrayMetadata) -> Result<Option<String>> {
+        if let (Some(Value::String(crs)), Some(CRSType::AuthorityCode)) =
+            (&meta.crs, &meta.crs_type)
+        {
+            return Ok(Some(crs.clone()));
+        }
+
+        Ok(self.parse(meta)?.and_then(|crs| crs.authority_code()))
+    }
+}
+
+#[cfg(feature = "proj")]
+impl CRSTransform for ProjCRSTransform {
+    fn _convert_to_projjson(&self, meta: &ArrayMetadata) -> Result<Option<Value>> {
+        self.parse(meta)?
+            .map(|crs| Ok(serde_json::from_str(&crs.projjson()?)?))
+            .transpose()
+    }
+
+    fn _convert_to_wkt(&self, meta: &ArrayMetadata) -> Result<Option<String>> {
+        self.parse(meta)?.map(|crs| crs.wkt2_2019()).transpose()
+    }
+}
+
+/// Minimal bindings to the parts of the PROJ C API that the `proj` crate doesn't expose.
+#[cfg(feature = "proj")]
+mod ffi {
+    use std::ffi::{CStr, CString};
+    use std::os::raw::c_char;
+    use std::ptr;
+
+    use proj_sys::{
+        proj_as_projjson, proj_as_wkt, proj_context_create, proj_context_destroy, proj_create,
+        proj_destroy, proj_get_id_auth_name, proj_get_id_code, PJ, PJ_CONTEXT,
+        PJ_WKT_TYPE_PJ_WKT2_2019,
+    };
+
+    use crate::error::{GeoArrowError, Result};
+
+    /// A CRS object parsed by PROJ, with its own PROJ context.
+    pub(super) struct ProjCrs {
+        ctx: *mut PJ_CONTEXT,
+        pj: *mut PJ,
+    }
+
+    impl ProjCrs {
+        pub(super) fn try_new(definition: &str) -> Result<Self> {
+            let c_definition =
+                CString::new(definition).map_err(|err| GeoArrowError::General(err.to_string()))?;
+
+            // SAFETY: the context is only used from this object and destroyed on drop. A null PJ
+            // means PROJ could not parse the definition, in which case the context is destroyed
+            // here.
+            unsafe {
+                let ctx = proj_context_create();
+                let pj = proj_create(ctx, c_definition.as_ptr());
+                if pj.is_null() {
+                    proj_context_destroy(ctx);
+                    return Err(GeoArrowError::General(format!(
+                        "PROJ could not parse CRS: {definition}"
+                    )));
+                }
+                Ok(Self { ctx, pj })
+            }
+        }
+
+        pub(super) fn projjson(&self) -> Result<String> {
+            // SAFETY: ctx and pj are valid for the lifetime of self.
+            let output = unsafe { proj_as_projjson(self.ctx, self.pj, ptr::null()) };
+            to_string(output).ok_or_else(|| {
+                GeoArrowError::General("PROJ could not export CRS to PROJJSON".to_string())
+            })
+        }
+
+        pub(super) fn wkt2_2019(&self) -> Result<String> {
+            // SAFETY: ctx and pj are valid for the lifetime of self.
+            let output =
+                unsafe { proj_as_wkt(self.ctx, self.pj, PJ_WKT_TYPE_PJ_WKT2_2019, ptr::null()) };
+            to_string(output).ok_or_else(|| {
+                GeoArrowError::General("PROJ could not export CRS to WKT2:2019".to_string())
+            })
+        }
+
+        pub(super) fn authority_code(&self) -> Option<String> {
+            // SAFETY: pj is valid for the lifetime of self.
+            let (authority, code) = unsafe {
+                (
+                    proj_get_id_auth_name(self.pj, 0),
+                    proj_get_id_code(self.pj, 0),
+                )
+            };
+            Some(format!("{}:{}", to_string(authority)?, to_string(code)?))
+        }
+    }
+
+    impl Drop for ProjCrs {
+        fn drop(&mut self) {
+            // SAFETY: both pointers were created in try_new and are not used after this.
+            unsafe {
+                proj_destroy(self.pj);
+                proj_context_destroy(self.ctx);
+            }
+        }
+    }
+
+    /// Copy a string owned by PROJ. Returns `None` for a null pointer.
+    fn to_string(ptr: *const c_char) -> Option<String> {
+        if ptr.is_null() {
+            return None;
+        }
+        // SAFETY: PROJ returns null-terminated strings that stay valid until the next call on
+        // the same object.
+        Some(
+            unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "proj"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn proj_transform_from_authority_code() {
+        let meta = ArrayMetadata::from_authority_code("EPSG:4326".to_string());
+        let transform = ProjCRSTransform::default();
+
+        let projjson = transform.extract_projjson(&meta).unwrap().unwrap();
+        assert_eq!(projjson["id"]["authority"], "EPSG");
+        assert_eq!(projjson["id"]["code"], 4326);
+
+        let wkt = transform.extract_wkt(&meta).unwrap().unwrap();
+        assert!(wkt.starts_with("GEOGCRS[\"WGS 84\""));
+
+        let round_trip = ArrayMetadata::from_wkt2_2019(wkt);
+        assert_eq!(
+            transform.extract_authority_code(&round_trip).unwrap(),
+            Some("EPSG:4326".to_string())
+        );
+    }
+
+    #[test]
+    fn proj_transform_resolves_srid() {
+        let srid = |srid: &str| ArrayMetadata {
+            crs: Some(Value::String(srid.to_string())),
+            crs_type: Some(CRSType::Srid),
+            ..Default::default()
+        };
+        let transform = ProjCRSTransform::default();
+
+        let wkt = transform.extract_wkt(&srid("4326")).unwrap().unwrap();
+        assert!(wkt.starts_with("GEOGCRS[\"WGS 84\""));
+        assert_eq!(
+            transform.extract_authority_code(&srid("4326")).unwrap(),
+            Some("EPSG:4326".to_string())
+        );
+
+        assert!(transform.extract_wkt(&srid("999999")).is_err());
+    }
+}
@@ -5,9 +5,9 @@ use flatgeobuf::{FgbCrs, FgbWriter, FgbWriterOptions};
 use geozero::GeozeroDatasource;
 
 use crate::array::metadata::ArrayMetadata;
-use crate::datatypes::{Dimension, NativeType};
+use crate::datatypes::NativeType;
 use crate::error::Result;
-use crate::io::crs::{CRSTransform, DefaultCRSTransform};
+use crate::io::crs::{default_crs_transform, CRSTransform};
 use crate::io::stream::RecordBatchReader;
 use crate::schema::GeoSchemaExt;
 
@@ -40,7 +40,7 @@ impl Default for FlatGeobufWriterOptions {
             write_index: true,
             detect_type: true,
             promote_to_multi: true,
-            crs_transform: Some(Box::new(DefaultCRSTransform::default())),
+            crs_transform: Some(default_crs_transform()),
             title: None,
             description: None,
             metadata: None,
@@ -58,7 +58,7 @@ impl FlatGeobufWriterOptions {
         if let Some(crs_transform) = &self.crs_transform {
             crs_transform.extract_wkt(array_meta)
         } else {
-            DefaultCRSTransform::default().extract_wkt(array_meta)
+            default_crs_transform().extract_wkt(array_meta)
         }
     }
 
@@ -69,8 +69,7 @@ impl FlatGeobufWriterOptions {
         wkt_crs: Option<&'a str>,
     ) -> FgbWriterOptions<'a> {
         let (has_z, has_m) = match geo_data_type.dimension() {
-            Some(Dimension::XY) => (false, false),
-            Some(Dimension::XYZ) => (true, false),
+            Some(dim) => (dim.has_z(), dim.has_m()),
             // TODO: not sure how to handle geometry arrays
             None => (false, false),
         };
@@ -154,13 +153,19 @@ fn infer_flatgeobuf_geometry_type(schema: &Schema) -> Result<flatgeobuf::Geometr
     use NativeType::*;
     let geometry_type = match geo_data_type {
         Point(_, _) => flatgeobuf::GeometryType::Point,
-        LineString(_, _) => flatgeobuf::GeometryType::LineString,
-        Polygon(_, _) => flatgeobuf::GeometryType::Polygon,
-        MultiPoint(_, _) => flatgeobuf::GeometryType::MultiPoint,
-        MultiLineString(_, _) => flatgeobuf::GeometryType::MultiLineString,
-        MultiPolygon(_, _) => flatgeobuf::GeometryType::MultiPolygon,
-        Rect(_) | Geometry(_) => flatgeobuf::GeometryType::Unknown,
-        GeometryCollection(_, _) => flatgeobuf::GeometryType::GeometryCollection,
+        LineString(_, _) | LargeLineString(_, _) => flatgeobuf::GeometryType::LineString,
+        Polygon(_, _) | LargePolygon(_, _) => flatgeobuf::GeometryType::Polygon,
+        MultiPoint(_, _) | LargeMultiPoint(_, _) => flatgeobuf::GeometryType::MultiPoint,
+        MultiLineString(_, _) | LargeMultiLineString(_, _) => {
+            flatgeobuf::GeometryType::MultiLineString
+        }
+        MultiPolygon(_, _) | LargeMultiPolygon(_, _) => flatgeobuf::GeometryType::MultiPolygon,
+        Mixed(_, _) | LargeMixed(_, _) | Rect(_) | Geometry(_) | LargeGeometry(_) => {
+            flatgeobuf::GeometryType::Unknown
+        }
+        GeometryCollection(_, _) | LargeGeometryCollection(_, _) => {
+            flatgeobuf::GeometryType::GeometryCollection
+        }
     };
     Ok(geometry_type)
 }
@@ -9,6 +9,8 @@ impl TryFrom<InterleavedCoordBuffer> for CoordSeq {
         match value.dim {
             Dimension::XY => CoordSeq::new_from_buffer(&value.coords, value.len(), false, false),
             Dimension::XYZ => CoordSeq::new_from_buffer(&value.coords, value.len(), true, false),
+            Dimension::XYM => CoordSeq::new_from_buffer(&value.coords, value.len(), false, true),
+            Dimension::XYZM => CoordSeq::new_from_buffer(&value.coords, value.len(), true, true),
         }
     }
 }
@@ -5,11 +5,13 @@ use crate::io::geos::scalar::multipoint::to_geos_multi_point;
 use crate::io::geos::scalar::multipolygon::to_geos_multi_polygon;
 use crate::io::geos::scalar::point::to_geos_point;
 use crate::io::geos::scalar::polygon::to_geos_polygon;
+use crate::io::geos::scalar::rect::to_geos_rect;
 use crate::io::geos::scalar::{
     GEOSGeometryCollection, GEOSLineString, GEOSMultiLineString, GEOSMultiPoint, GEOSMultiPolygon,
     GEOSPoint, GEOSPolygon,
 };
 use crate::scalar::Geometry;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, LineStringTrait, MultiLineStringTrait, MultiPointTrait,
     MultiPolygonTrait, PointTrait, PolygonTrait, UnimplementedLine, UnimplementedRect,
@@ -17,10 +19,10 @@ use geo_traits::{
 };
 use geos::Geom;
 
-impl<'a> TryFrom<&'a Geometry<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a Geometry<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
-    fn try_from(value: &'a Geometry<'_>) -> std::result::Result<geos::Geometry, geos::Error> {
+    fn try_from(value: &'a Geometry<'_, O>) -> std::result::Result<geos::Geometry, geos::Error> {
         match value {
             Geometry::Point(g) => g.try_into(),
             Geometry::LineString(g) => g.try_into(),
@@ -29,7 +31,7 @@ impl<'a> TryFrom<&'a Geometry<'_>> for geos::Geometry {
             Geometry::MultiLineString(g) => g.try_into(),
             Geometry::MultiPolygon(g) => g.try_into(),
             Geometry::GeometryCollection(g) => g.try_into(),
-            Geometry::Rect(_g) => todo!(),
+            Geometry::Rect(g) => g.try_into(),
         }
     }
 }
@@ -47,7 +49,7 @@ pub(crate) fn to_geos_geometry(
         MultiLineString(g) => to_geos_multi_line_string(g),
         MultiPolygon(g) => to_geos_multi_polygon(g),
         GeometryCollection(g) => to_geos_geometry_collection(g),
-        Rect(_) => panic!("Unsupported rect in conversion to GEOS"),
+        Rect(g) => to_geos_rect(g),
         Triangle(_) => panic!("Unsupported triangle in conversion to GEOS"),
         Line(_) => panic!("Unsupported Line in conversion to GEOS"),
     }
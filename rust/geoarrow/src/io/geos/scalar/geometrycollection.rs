@@ -1,10 +1,11 @@
 use crate::io::geos::scalar::geometry::to_geos_geometry;
 use crate::io::geos::scalar::GEOSGeometry;
 use crate::scalar::GeometryCollection;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::GeometryCollectionTrait;
 use geos::Geom;
 
-impl<'a> TryFrom<&'a GeometryCollection<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a GeometryCollection<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
     fn try_from(
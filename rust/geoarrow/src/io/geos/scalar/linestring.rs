@@ -2,13 +2,14 @@ use crate::array::util::OffsetBufferUtils;
 use crate::error::{GeoArrowError, Result};
 use crate::io::geos::scalar::coord::{coords_to_geos, dims_to_geos, GEOSConstCoord};
 use crate::scalar::LineString;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::LineStringTrait;
 use geos::{Geom, GeometryTypes};
 
-impl<'a> TryFrom<&'a LineString<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a LineString<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
-    fn try_from(value: &'a LineString<'_>) -> std::result::Result<geos::Geometry, geos::Error> {
+    fn try_from(value: &'a LineString<'_, O>) -> std::result::Result<geos::Geometry, geos::Error> {
         let (start, end) = value.geom_offsets.start_end(value.geom_index);
 
         let sliced_coords = value.coords.clone().slice(start, end - start);
@@ -17,7 +18,7 @@ impl<'a> TryFrom<&'a LineString<'_>> for geos::Geometry {
     }
 }
 
-impl LineString<'_> {
+impl<O: OffsetSizeTrait> LineString<'_, O> {
     /// Convert to a GEOS LinearRing
     #[allow(dead_code)]
     pub(crate) fn to_geos_linear_ring(&self) -> std::result::Result<geos::Geometry, geos::Error> {
@@ -9,6 +9,7 @@ mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod rect;
 
 pub(crate) use geometry::to_geos_geometry;
 pub use geometry::GEOSGeometry;
@@ -2,14 +2,15 @@ use crate::error::{GeoArrowError, Result};
 use crate::io::geos::scalar::linestring::to_geos_line_string;
 use crate::io::geos::scalar::GEOSConstLineString;
 use crate::scalar::MultiLineString;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::MultiLineStringTrait;
 use geos::{Geom, GeometryTypes};
 
-impl<'a> TryFrom<&'a MultiLineString<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a MultiLineString<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
     fn try_from(
-        value: &'a MultiLineString<'_>,
+        value: &'a MultiLineString<'_, O>,
     ) -> std::result::Result<geos::Geometry, geos::Error> {
         to_geos_multi_line_string(&value)
     }
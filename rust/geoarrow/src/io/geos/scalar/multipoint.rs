@@ -2,13 +2,14 @@ use crate::error::{GeoArrowError, Result};
 use crate::io::geos::scalar::point::to_geos_point;
 use crate::io::geos::scalar::GEOSConstPoint;
 use crate::scalar::MultiPoint;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::MultiPointTrait;
 use geos::{Geom, GeometryTypes};
 
-impl<'a> TryFrom<&'a MultiPoint<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a MultiPoint<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
-    fn try_from(value: &'a MultiPoint<'_>) -> std::result::Result<geos::Geometry, geos::Error> {
+    fn try_from(value: &'a MultiPoint<'_, O>) -> std::result::Result<geos::Geometry, geos::Error> {
         to_geos_multi_point(value)
     }
 }
@@ -2,13 +2,16 @@ use crate::error::{GeoArrowError, Result};
 use crate::io::geos::scalar::polygon::to_geos_polygon;
 use crate::io::geos::scalar::GEOSConstPolygon;
 use crate::scalar::MultiPolygon;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::MultiPolygonTrait;
 use geos::{Geom, GeometryTypes};
 
-impl<'a> TryFrom<&'a MultiPolygon<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a MultiPolygon<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
-    fn try_from(value: &'a MultiPolygon<'_>) -> std::result::Result<geos::Geometry, geos::Error> {
+    fn try_from(
+        value: &'a MultiPolygon<'_, O>,
+    ) -> std::result::Result<geos::Geometry, geos::Error> {
         to_geos_multi_polygon(value)
     }
 }
@@ -2,13 +2,14 @@ use crate::error::{GeoArrowError, Result};
 use crate::io::geos::scalar::linestring::to_geos_linear_ring;
 use crate::io::geos::scalar::GEOSConstLinearRing;
 use crate::scalar::Polygon;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::PolygonTrait;
 use geos::{Geom, GeometryTypes};
 
-impl<'a> TryFrom<&'a Polygon<'_>> for geos::Geometry {
+impl<'a, O: OffsetSizeTrait> TryFrom<&'a Polygon<'_, O>> for geos::Geometry {
     type Error = geos::Error;
 
-    fn try_from(value: &'a Polygon<'_>) -> std::result::Result<geos::Geometry, geos::Error> {
+    fn try_from(value: &'a Polygon<'_, O>) -> std::result::Result<geos::Geometry, geos::Error> {
         to_geos_polygon(value)
     }
 }
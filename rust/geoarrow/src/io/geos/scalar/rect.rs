@@ -0,0 +1,19 @@
+use crate::io::geos::scalar::polygon::to_geos_polygon;
+use crate::scalar::Rect;
+use geo_traits::to_geo::ToGeoRect;
+use geo_traits::RectTrait;
+
+impl<'a> TryFrom<&'a Rect<'_>> for geos::Geometry {
+    type Error = geos::Error;
+
+    fn try_from(value: &'a Rect<'_>) -> std::result::Result<geos::Geometry, geos::Error> {
+        to_geos_rect(value)
+    }
+}
+
+/// GEOS has no rectangle type, so Rects are converted to Polygons.
+pub(crate) fn to_geos_rect(
+    rect: &impl RectTrait<T = f64>,
+) -> std::result::Result<geos::Geometry, geos::Error> {
+    to_geos_polygon(&rect.to_rect().to_polygon())
+}
@@ -21,19 +21,23 @@ impl GeozeroGeometry for NativeArrayDyn {
         match self.inner().data_type() {
             Point(_, _) => impl_process!(as_point),
             LineString(_, _) => impl_process!(as_line_string),
+            LargeLineString(_, _) => impl_process!(as_large_line_string),
             Polygon(_, _) => impl_process!(as_polygon),
+            LargePolygon(_, _) => impl_process!(as_large_polygon),
             MultiPoint(_, _) => impl_process!(as_multi_point),
-            MultiLineString(_, _) => {
-                impl_process!(as_multi_line_string)
-            }
+            LargeMultiPoint(_, _) => impl_process!(as_large_multi_point),
+            MultiLineString(_, _) => impl_process!(as_multi_line_string),
+            LargeMultiLineString(_, _) => impl_process!(as_large_multi_line_string),
             MultiPolygon(_, _) => impl_process!(as_multi_polygon),
-            GeometryCollection(_, _) => {
-                impl_process!(as_geometry_collection)
-            }
-            _ => todo!(),
+            LargeMultiPolygon(_, _) => impl_process!(as_large_multi_polygon),
+            Mixed(_, _) => impl_process!(as_mixed),
+            LargeMixed(_, _) => impl_process!(as_large_mixed),
+            GeometryCollection(_, _) => impl_process!(as_geometry_collection),
+            LargeGeometryCollection(_, _) => impl_process!(as_large_geometry_collection),
+            Rect(_) => impl_process!(as_rect),
+            Geometry(_) => impl_process!(as_geometry),
+            LargeGeometry(_) => impl_process!(as_large_geometry),
             // WKB => impl_process!(as_wkb),
-            // Rect(_) => impl_process!(as_rect)
-            // Rect(XYZ) => impl_process!(as_rect, 3)
         }
     }
 }
@@ -45,8 +49,8 @@ mod test {
     use geozero::ToGeo;
 
     use super::*;
-    use crate::array::PointArray;
-    use crate::test::point;
+    use crate::array::{LargePolygonArray, PointArray};
+    use crate::test::{point, polygon};
     use crate::ArrayBase;
 
     #[test]
@@ -57,4 +61,15 @@ mod test {
         dbg!(geom_arr.to_geo().unwrap());
         dbg!(test);
     }
+
+    #[test]
+    fn large_offsets() {
+        let arr = LargePolygonArray::from(polygon::p_array());
+        let geom_arr = NativeArrayDyn::new(Arc::new(arr));
+        let expected = geo::Geometry::GeometryCollection(geo::GeometryCollection(vec![
+            geo::Geometry::Polygon(polygon::p0()),
+            geo::Geometry::Polygon(polygon::p1()),
+        ]));
+        assert_eq!(geom_arr.to_geo().unwrap(), expected);
+    }
 }
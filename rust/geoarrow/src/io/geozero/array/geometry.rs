@@ -1,15 +1,35 @@
 use std::fmt::Debug;
 use std::sync::Arc;
 
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::NullBufferBuilder;
 use geozero::error::GeozeroError;
 use geozero::geo_types::GeoWriter;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
 use crate::array::metadata::ArrayMetadata;
-use crate::array::{CoordType, GeometryArray, GeometryBuilder};
+use crate::array::{CoordType, GenericGeometryArray, GeometryArray, GeometryBuilder};
 use crate::datatypes::Dimension;
-use crate::trait_::GeometryArrayBuilder;
-use crate::NativeArray;
+use crate::io::geozero::scalar::process_geometry;
+use crate::trait_::{ArrayAccessor, GeometryArrayBuilder};
+use crate::{ArrayBase, NativeArray};
+
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericGeometryArray<O> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for geom_idx in 0..num_geometries {
+            process_geometry(&self.value(geom_idx), geom_idx, processor)?;
+        }
+
+        processor.geometrycollection_end(num_geometries - 1)?;
+        Ok(())
+    }
+}
 
 /// GeoZero trait to convert to GeoArrow [`GeometryArray`].
 pub trait ToGeometryArray {
@@ -145,7 +165,11 @@ impl GeomProcessor for GeometryStreamBuilder {
 
     fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
         self.current_geometry.linestring_end(tagged, idx)?;
-        self.push_current_geometry()
+        // Untagged linestrings are rings of a polygon
+        if tagged {
+            self.push_current_geometry()?;
+        }
+        Ok(())
     }
 
     fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
@@ -168,7 +192,11 @@ impl GeomProcessor for GeometryStreamBuilder {
 
     fn polygon_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
         self.current_geometry.polygon_end(tagged, idx)?;
-        self.push_current_geometry()
+        // Untagged polygons are part of a multipolygon
+        if tagged {
+            self.push_current_geometry()?;
+        }
+        Ok(())
     }
 
     fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
@@ -195,9 +223,8 @@ impl GeometryArrayBuilder for GeometryStreamBuilder {
         self.builder.len()
     }
 
-    fn nulls(&self) -> &arrow_buffer::NullBufferBuilder {
-        // Take this method off trait
-        todo!()
+    fn nulls(&self) -> &NullBufferBuilder {
+        self.builder.nulls()
     }
 
     fn push_geometry(
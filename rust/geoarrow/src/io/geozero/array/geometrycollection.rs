@@ -1,10 +1,11 @@
-use crate::array::GeometryCollectionArray;
+use crate::array::GenericGeometryCollectionArray;
 use crate::io::geozero::scalar::process_geometry_collection;
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
+use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
-impl GeozeroGeometry for GeometryCollectionArray {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericGeometryCollectionArray<O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
@@ -1,13 +1,14 @@
+use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
 use crate::array::linestring::LineStringCapacity;
-use crate::array::{LineStringArray, LineStringBuilder};
+use crate::array::{GenericLineStringArray, LineStringArray, LineStringBuilder};
 use crate::datatypes::Dimension;
 use crate::io::geozero::scalar::process_line_string;
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 
-impl GeozeroGeometry for LineStringArray {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericLineStringArray<O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
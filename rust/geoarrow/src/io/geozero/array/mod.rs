@@ -2,11 +2,13 @@ mod dynamic;
 mod geometry;
 mod geometrycollection;
 mod linestring;
+mod mixed;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod rect;
 
 pub use geometry::{GeometryStreamBuilder, ToGeometryArray};
 pub use linestring::ToLineStringArray;
@@ -1,13 +1,14 @@
+use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
 use crate::array::multilinestring::MultiLineStringCapacity;
-use crate::array::{MultiLineStringArray, MultiLineStringBuilder};
+use crate::array::{GenericMultiLineStringArray, MultiLineStringArray, MultiLineStringBuilder};
 use crate::datatypes::Dimension;
 use crate::io::geozero::scalar::process_multi_line_string;
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 
-impl GeozeroGeometry for MultiLineStringArray {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericMultiLineStringArray<O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
@@ -1,12 +1,13 @@
 use crate::array::multipoint::MultiPointCapacity;
-use crate::array::{MultiPointArray, MultiPointBuilder};
+use crate::array::{GenericMultiPointArray, MultiPointArray, MultiPointBuilder};
 use crate::datatypes::Dimension;
 use crate::io::geozero::scalar::process_multi_point;
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
+use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
-impl GeozeroGeometry for MultiPointArray {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericMultiPointArray<O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
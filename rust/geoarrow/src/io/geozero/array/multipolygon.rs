@@ -1,13 +1,14 @@
+use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
 use crate::array::multipolygon::MultiPolygonCapacity;
-use crate::array::{MultiPolygonArray, MultiPolygonBuilder};
+use crate::array::{GenericMultiPolygonArray, MultiPolygonArray, MultiPolygonBuilder};
 use crate::datatypes::Dimension;
 use crate::io::geozero::scalar::process_multi_polygon;
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 
-impl GeozeroGeometry for MultiPolygonArray {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericMultiPolygonArray<O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
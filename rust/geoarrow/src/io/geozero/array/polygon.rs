@@ -1,12 +1,13 @@
 use crate::array::polygon::PolygonCapacity;
-use crate::array::{PolygonArray, PolygonBuilder};
+use crate::array::{GenericPolygonArray, PolygonArray, PolygonBuilder};
 use crate::datatypes::Dimension;
 use crate::io::geozero::scalar::process_polygon;
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
+use arrow_array::OffsetSizeTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
-impl GeozeroGeometry for PolygonArray {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GenericPolygonArray<O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
@@ -0,0 +1,22 @@
+use crate::array::RectArray;
+use crate::io::geozero::scalar::process_rect;
+use crate::trait_::ArrayAccessor;
+use crate::ArrayBase;
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+impl GeozeroGeometry for RectArray {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        let num_geometries = self.len();
+        processor.geometrycollection_begin(num_geometries, 0)?;
+
+        for geom_idx in 0..num_geometries {
+            process_rect(&self.value(geom_idx), true, geom_idx, processor)?;
+        }
+
+        processor.geometrycollection_end(num_geometries - 1)?;
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use crate::io::geozero::scalar::multipoint::process_multi_point;
 use crate::io::geozero::scalar::multipolygon::process_multi_polygon;
 use crate::io::geozero::scalar::point::process_point;
 use crate::io::geozero::scalar::polygon::process_polygon;
+use crate::io::geozero::scalar::rect::process_rect;
 use crate::io::geozero::ToGeometryArray;
 use crate::scalar::{Geometry, OwnedGeometry};
 use crate::trait_::ArrayAccessor;
@@ -28,14 +29,14 @@ pub(crate) fn process_geometry<P: GeomProcessor>(
         MultiLineString(g) => process_multi_line_string(g, geom_idx, processor)?,
         MultiPolygon(g) => process_multi_polygon(g, geom_idx, processor)?,
         GeometryCollection(g) => process_geometry_collection(g, geom_idx, processor)?,
-        Rect(_g) => todo!(),
+        Rect(g) => process_rect(g, true, geom_idx, processor)?,
         Triangle(_) | Line(_) => todo!(),
     };
 
     Ok(())
 }
 
-impl GeozeroGeometry for Geometry<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for Geometry<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
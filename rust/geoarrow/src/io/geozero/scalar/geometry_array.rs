@@ -8,6 +8,7 @@ use crate::io::geozero::scalar::multipolygon::process_multi_polygon;
 use crate::io::geozero::scalar::point::process_point;
 use crate::io::geozero::scalar::polygon::process_polygon;
 use crate::io::geozero::scalar::process_geometry;
+use crate::io::geozero::scalar::rect::process_rect;
 use crate::scalar::GeometryScalar;
 use crate::trait_::ArrayAccessor;
 use geozero::{GeomProcessor, GeozeroGeometry};
@@ -40,13 +41,25 @@ pub fn process_geometry_scalar_array<P: GeomProcessor>(
     match geom.data_type() {
         Point(_, _) => impl_process!(process_point, as_point),
         LineString(_, _) => impl_process!(process_line_string, as_line_string),
+        LargeLineString(_, _) => impl_process!(process_line_string, as_large_line_string),
         Polygon(_, _) => impl_process!(process_polygon, true, as_polygon),
+        LargePolygon(_, _) => impl_process!(process_polygon, true, as_large_polygon),
         MultiPoint(_, _) => impl_process!(process_multi_point, as_multi_point),
+        LargeMultiPoint(_, _) => impl_process!(process_multi_point, as_large_multi_point),
         MultiLineString(_, _) => impl_process!(process_multi_line_string, as_multi_line_string),
+        LargeMultiLineString(_, _) => {
+            impl_process!(process_multi_line_string, as_large_multi_line_string)
+        }
         MultiPolygon(_, _) => impl_process!(process_multi_polygon, as_multi_polygon),
+        LargeMultiPolygon(_, _) => impl_process!(process_multi_polygon, as_large_multi_polygon),
+        Mixed(_, _) => impl_process!(process_geometry, as_mixed),
+        LargeMixed(_, _) => impl_process!(process_geometry, as_large_mixed),
         GeometryCollection(_, _) => {
             impl_process!(process_geometry_collection, as_geometry_collection)
         }
+        LargeGeometryCollection(_, _) => {
+            impl_process!(process_geometry_collection, as_large_geometry_collection)
+        }
 
         // WKB => {
         //     let arr = &geom.inner().as_ref();
@@ -60,8 +73,9 @@ pub fn process_geometry_scalar_array<P: GeomProcessor>(
         //     let wkb_object = wkb_arr.to_wkb_object();
         //     process_geometry(&wkb_object, geom_idx, processor)
         // }
-        Rect(_) => todo!(),
+        Rect(_) => impl_process!(process_rect, true, as_rect),
         Geometry(_) => impl_process!(process_geometry, as_geometry),
+        LargeGeometry(_) => impl_process!(process_geometry, as_large_geometry),
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::io::geozero::scalar::geometry::process_geometry;
 use crate::scalar::GeometryCollection;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::GeometryCollectionTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -18,7 +19,7 @@ pub(crate) fn process_geometry_collection<P: GeomProcessor>(
     Ok(())
 }
 
-impl GeozeroGeometry for GeometryCollection<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for GeometryCollection<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
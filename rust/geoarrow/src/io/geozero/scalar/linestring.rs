@@ -1,5 +1,6 @@
 use crate::io::geozero::scalar::process_coord;
 use crate::scalar::LineString;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::LineStringTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -18,7 +19,7 @@ pub(crate) fn process_line_string<P: GeomProcessor>(
     Ok(())
 }
 
-impl GeozeroGeometry for LineString<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for LineString<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
@@ -9,6 +9,7 @@ mod multipoint;
 mod multipolygon;
 mod point;
 mod polygon;
+mod rect;
 
 pub(crate) use coord::process_coord;
 pub(crate) use geometry::process_geometry;
@@ -19,5 +20,6 @@ pub(crate) use multipoint::process_multi_point;
 pub(crate) use multipolygon::process_multi_polygon;
 pub(crate) use point::{process_point, process_point_as_coord};
 pub(crate) use polygon::process_polygon;
+pub(crate) use rect::process_rect;
 
 pub use geometry::ToGeometry;
@@ -1,5 +1,6 @@
 use crate::io::geozero::scalar::process_coord;
 use crate::scalar::MultiLineString;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::{LineStringTrait, MultiLineStringTrait};
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -24,7 +25,7 @@ pub(crate) fn process_multi_line_string<P: GeomProcessor>(
     Ok(())
 }
 
-impl GeozeroGeometry for MultiLineString<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for MultiLineString<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
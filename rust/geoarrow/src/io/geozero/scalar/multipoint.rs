@@ -1,5 +1,6 @@
 use crate::io::geozero::scalar::process_point_as_coord;
 use crate::scalar::MultiPoint;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::MultiPointTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -18,7 +19,7 @@ pub(crate) fn process_multi_point<P: GeomProcessor>(
     Ok(())
 }
 
-impl GeozeroGeometry for MultiPoint<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for MultiPoint<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
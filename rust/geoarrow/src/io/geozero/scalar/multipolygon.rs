@@ -1,5 +1,6 @@
 use crate::io::geozero::scalar::polygon::process_polygon;
 use crate::scalar::MultiPolygon;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::MultiPolygonTrait;
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -18,7 +19,7 @@ pub(crate) fn process_multi_polygon<P: GeomProcessor>(
     Ok(())
 }
 
-impl GeozeroGeometry for MultiPolygon<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for MultiPolygon<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
@@ -1,5 +1,6 @@
 use crate::io::geozero::scalar::process_coord;
 use crate::scalar::Polygon;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::{LineStringTrait, PolygonTrait};
 use geozero::{GeomProcessor, GeozeroGeometry};
 
@@ -39,7 +40,7 @@ pub(crate) fn process_polygon<P: GeomProcessor>(
     Ok(())
 }
 
-impl GeozeroGeometry for Polygon<'_> {
+impl<O: OffsetSizeTrait> GeozeroGeometry for Polygon<'_, O> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
     where
         Self: Sized,
@@ -0,0 +1,41 @@
+use crate::io::geozero::scalar::process_coord;
+use crate::scalar::Rect;
+use geo_traits::{CoordTrait, RectTrait};
+use geozero::{GeomProcessor, GeozeroGeometry};
+
+/// Process a Rect as a Polygon with a single, closed ring.
+pub(crate) fn process_rect<P: GeomProcessor>(
+    geom: &impl RectTrait<T = f64>,
+    tagged: bool,
+    geom_idx: usize,
+    processor: &mut P,
+) -> geozero::error::Result<()> {
+    let lower = geom.min();
+    let upper = geom.max();
+    let ring = [
+        (lower.x(), lower.y()),
+        (lower.x(), upper.y()),
+        (upper.x(), upper.y()),
+        (upper.x(), lower.y()),
+        (lower.x(), lower.y()),
+    ];
+
+    processor.polygon_begin(tagged, 1, geom_idx)?;
+    processor.linestring_begin(false, ring.len(), 0)?;
+    for (coord_idx, (x, y)) in ring.into_iter().enumerate() {
+        process_coord(&geo::Coord { x, y }, coord_idx, processor)?;
+    }
+    processor.linestring_end(false, 0)?;
+    processor.polygon_end(tagged, geom_idx)?;
+
+    Ok(())
+}
+
+impl GeozeroGeometry for Rect<'_> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> geozero::error::Result<()>
+    where
+        Self: Sized,
+    {
+        process_rect(self, true, 0, processor)
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arrow_array::RecordBatch;
+use arrow_array::{RecordBatch, RecordBatchOptions};
 use arrow_schema::{Schema, SchemaBuilder};
 use chrono::{DateTime, Utc};
 use geozero::{FeatureProcessor, GeomProcessor, PropertyProcessor};
@@ -128,9 +128,12 @@ impl PropertiesBatchBuilder {
             columns.push(array);
         }
 
-        Ok(RecordBatch::try_new(
+        // Pass the row count explicitly so that features without properties still produce rows
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_counter));
+        Ok(RecordBatch::try_new_with_options(
             Arc::new(schema_builder.finish()),
             columns,
+            &options,
         )?)
     }
 }
@@ -7,7 +7,7 @@ use crate::array::{from_arrow_array, AsNativeArray};
 use crate::datatypes::NativeType;
 use crate::io::geozero::scalar::{
     process_geometry, process_geometry_collection, process_line_string, process_multi_line_string,
-    process_multi_point, process_multi_polygon, process_point, process_polygon,
+    process_multi_point, process_multi_polygon, process_point, process_polygon, process_rect,
 };
 use crate::io::geozero::table::json_encoder::{make_encoder, EncoderOptions};
 use crate::io::stream::RecordBatchReader;
@@ -375,26 +375,58 @@ fn process_geometry_n<P: GeomProcessor>(
             let geom = arr.as_line_string().value(i);
             process_line_string(&geom, 0, processor)?;
         }
+        LargeLineString(_, _) => {
+            let geom = arr.as_large_line_string().value(i);
+            process_line_string(&geom, 0, processor)?;
+        }
         Polygon(_, _) => {
             let geom = arr.as_polygon().value(i);
             process_polygon(&geom, true, 0, processor)?;
         }
+        LargePolygon(_, _) => {
+            let geom = arr.as_large_polygon().value(i);
+            process_polygon(&geom, true, 0, processor)?;
+        }
         MultiPoint(_, _) => {
             let geom = arr.as_multi_point().value(i);
             process_multi_point(&geom, 0, processor)?;
         }
+        LargeMultiPoint(_, _) => {
+            let geom = arr.as_large_multi_point().value(i);
+            process_multi_point(&geom, 0, processor)?;
+        }
         MultiLineString(_, _) => {
             let geom = arr.as_multi_line_string().value(i);
             process_multi_line_string(&geom, 0, processor)?;
         }
+        LargeMultiLineString(_, _) => {
+            let geom = arr.as_large_multi_line_string().value(i);
+            process_multi_line_string(&geom, 0, processor)?;
+        }
         MultiPolygon(_, _) => {
             let geom = arr.as_multi_polygon().value(i);
             process_multi_polygon(&geom, 0, processor)?;
         }
+        LargeMultiPolygon(_, _) => {
+            let geom = arr.as_large_multi_polygon().value(i);
+            process_multi_polygon(&geom, 0, processor)?;
+        }
+        Mixed(_, _) => {
+            let geom = arr.as_mixed().value(i);
+            process_geometry(&geom, 0, processor)?;
+        }
+        LargeMixed(_, _) => {
+            let geom = arr.as_large_mixed().value(i);
+            process_geometry(&geom, 0, processor)?;
+        }
         GeometryCollection(_, _) => {
             let geom = arr.as_geometry_collection().value(i);
             process_geometry_collection(&geom, 0, processor)?;
         }
+        LargeGeometryCollection(_, _) => {
+            let geom = arr.as_large_geometry_collection().value(i);
+            process_geometry_collection(&geom, 0, processor)?;
+        }
         // WKB => {
         //     let geom = arr.as_wkb().value(i);
         //     process_geometry(&geom.to_wkb_object(), 0, processor)?;
@@ -404,14 +436,17 @@ fn process_geometry_n<P: GeomProcessor>(
         //     process_geometry(&geom.to_wkb_object(), 0, processor)?;
         // }
         Rect(_) => {
-            todo!("process rect")
-            // let geom = arr.as_ref().as_rect::<2>().value(i);
-            // process_rect
+            let geom = arr.as_rect().value(i);
+            process_rect(&geom, true, 0, processor)?;
         }
         Geometry(_) => {
             let geom = arr.as_geometry().value(i);
             process_geometry(&geom, 0, processor)?;
         }
+        LargeGeometry(_) => {
+            let geom = arr.as_large_geometry().value(i);
+            process_geometry(&geom, 0, processor)?;
+        }
     }
 
     Ok(())
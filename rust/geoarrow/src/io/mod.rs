@@ -16,8 +16,12 @@ pub mod geojson_lines;
 pub(crate) mod geos;
 pub mod geozero;
 pub mod ipc;
+#[cfg(feature = "mvt")]
+pub mod mvt;
 #[cfg(feature = "parquet")]
 pub mod parquet;
+#[cfg(feature = "pmtiles")]
+pub mod pmtiles;
 #[cfg(feature = "postgis")]
 pub mod postgis;
 pub mod shapefile;
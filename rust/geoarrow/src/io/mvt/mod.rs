@@ -0,0 +1,16 @@
+//! Read from and write to [Mapbox Vector Tiles](https://github.com/mapbox/vector-tile-spec).
+//!
+//! Geometries are converted between longitude/latitude (or Web Mercator) and the integer
+//! coordinate space of a single tile, which is identified by a [`TileCoord`] in the XYZ tiling
+//! scheme.
+
+mod reader;
+mod tile;
+mod writer;
+
+pub use reader::{read_mvt, read_mvt_layer_names, MvtReaderOptions};
+pub use tile::{
+    lon_lat_to_web_mercator, web_mercator_bounds, web_mercator_to_lon_lat, MvtCoordinates,
+    TileCoord, MAX_LATITUDE, WEB_MERCATOR_EXTENT,
+};
+pub use writer::{write_mvt, MvtTileWriter, MvtWriterOptions};
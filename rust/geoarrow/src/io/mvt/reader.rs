@@ -0,0 +1,405 @@
+use std::sync::Arc;
+
+use geo::orient::Direction;
+use geo::{
+    Coord, Geometry, LineString, MapCoords, MultiLineString, MultiPoint, MultiPolygon, Orient,
+    Point, Polygon,
+};
+use geozero::error::GeozeroError;
+use geozero::mvt::{tile, Message, Tile};
+use geozero::{ColumnValue, FeatureProcessor, GeozeroDatasource, GeozeroGeometry};
+
+use crate::array::metadata::ArrayMetadata;
+use crate::array::CoordType;
+use crate::datatypes::Dimension;
+use crate::error::{GeoArrowError, Result};
+use crate::io::geozero::array::GeometryStreamBuilder;
+use crate::io::geozero::table::{GeoTableBuilder, GeoTableBuilderOptions};
+use crate::io::mvt::tile::{MvtCoordinates, TileCoord, TileTransform};
+use crate::io::mvt::writer::signed_area;
+use crate::table::Table;
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// Options for reading vector tiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MvtReaderOptions {
+    /// The coordinate system of the output geometries. Defaults to longitude/latitude.
+    pub coordinates: MvtCoordinates,
+
+    /// The [CoordType] of the output geometry arrays.
+    pub coord_type: CoordType,
+
+    /// The max number of rows in each output batch.
+    pub batch_size: Option<usize>,
+}
+
+impl Default for MvtReaderOptions {
+    fn default() -> Self {
+        Self {
+            coordinates: Default::default(),
+            coord_type: CoordType::Interleaved,
+            batch_size: None,
+        }
+    }
+}
+
+fn decode_tile(bytes: &[u8]) -> Result<Tile> {
+    Tile::decode(bytes).map_err(|err| GeoArrowError::General(err.to_string()))
+}
+
+/// List the names of the layers in a vector tile.
+pub fn read_mvt_layer_names(bytes: &[u8]) -> Result<Vec<String>> {
+    Ok(decode_tile(bytes)?
+        .layers
+        .into_iter()
+        .map(|layer| layer.name)
+        .collect())
+}
+
+/// Read one layer of a vector tile to a Table.
+///
+/// Feature attributes become columns and geometries are converted from the tile's coordinate
+/// space into the coordinate system set in `options`, using the position of `tile`. Features
+/// with an unknown geometry type are skipped. Returns an error if the tile has no layer called
+/// `layer_name` or if the layer has no features left to read.
+pub fn read_mvt(
+    bytes: &[u8],
+    layer_name: &str,
+    tile: TileCoord,
+    options: MvtReaderOptions,
+) -> Result<Table> {
+    let tile_data = decode_tile(bytes)?;
+    let layer = tile_data
+        .layers
+        .iter()
+        .find(|layer| layer.name == layer_name)
+        .ok_or_else(|| GeoArrowError::General(format!("No layer named {layer_name} in tile")))?;
+    if layer.features.is_empty() {
+        return Err(GeoArrowError::General(format!(
+            "Layer {layer_name} has no features"
+        )));
+    }
+
+    let metadata = match options.coordinates {
+        MvtCoordinates::LonLat => ArrayMetadata::from_authority_code("EPSG:4326".to_string()),
+        MvtCoordinates::WebMercator => ArrayMetadata::from_authority_code("EPSG:3857".to_string()),
+        MvtCoordinates::Tile => Default::default(),
+    };
+    let table_options = GeoTableBuilderOptions::new(
+        options.coord_type,
+        true,
+        options.batch_size,
+        None,
+        Some(layer.features.len()),
+        Arc::new(metadata),
+    );
+    let mut geo_table =
+        GeoTableBuilder::<GeometryStreamBuilder>::new_with_options(Dimension::XY, table_options);
+
+    let mut source = MvtLayerSource {
+        layer,
+        transform: TileTransform::new(tile, layer.extent(), options.coordinates),
+    };
+    source.process(&mut geo_table)?;
+    geo_table.finish()
+}
+
+/// A [`GeozeroDatasource`] over the features of a single MVT layer.
+struct MvtLayerSource<'a> {
+    layer: &'a tile::Layer,
+    transform: TileTransform,
+}
+
+impl GeozeroDatasource for MvtLayerSource<'_> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> geozero::error::Result<()> {
+        processor.dataset_begin(Some(&self.layer.name))?;
+
+        let mut row_idx = 0;
+        for feature in self.layer.features.iter() {
+            let Some(geometry) = decode_geometry(feature)? else {
+                continue;
+            };
+            let mut geometry = geometry.map_coords(|coord| self.transform.decode(coord));
+            if self.transform.coordinates() != MvtCoordinates::Tile {
+                // The y axis points down in tile space, so flipping it reverses every ring
+                geometry = orient(geometry);
+            }
+
+            processor.feature_begin(row_idx)?;
+            processor.properties_begin()?;
+            for (property_idx, tag) in feature.tags.chunks_exact(2).enumerate() {
+                let key = self.layer.keys.get(tag[0] as usize);
+                let value = self.layer.values.get(tag[1] as usize);
+                let (Some(key), Some(value)) = (key, value) else {
+                    return Err(GeozeroError::Property(format!(
+                        "Feature tag ({}, {}) out of range",
+                        tag[0], tag[1]
+                    )));
+                };
+                if let Some(value) = column_value(value) {
+                    processor.property(property_idx, key, &value)?;
+                }
+            }
+            processor.properties_end()?;
+
+            processor.geometry_begin()?;
+            geometry.process_geom(processor)?;
+            processor.geometry_end()?;
+
+            processor.feature_end(row_idx)?;
+            row_idx += 1;
+        }
+
+        processor.dataset_end()
+    }
+}
+
+fn column_value(value: &tile::Value) -> Option<ColumnValue<'_>> {
+    if let Some(v) = &value.string_value {
+        Some(ColumnValue::String(v))
+    } else if let Some(v) = value.float_value {
+        Some(ColumnValue::Float(v))
+    } else if let Some(v) = value.double_value {
+        Some(ColumnValue::Double(v))
+    } else if let Some(v) = value.int_value {
+        Some(ColumnValue::Long(v))
+    } else if let Some(v) = value.uint_value {
+        Some(ColumnValue::ULong(v))
+    } else if let Some(v) = value.sint_value {
+        Some(ColumnValue::Long(v))
+    } else {
+        value.bool_value.map(ColumnValue::Bool)
+    }
+}
+
+fn unzigzag(value: u32) -> i32 {
+    ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
+
+/// Decode the command stream of a feature into the paths it draws, in tile coordinates.
+///
+/// Each `MoveTo` starts a new path; closed paths repeat their first coordinate at the end.
+fn decode_paths(commands: &[u32]) -> geozero::error::Result<Vec<Vec<Coord>>> {
+    let mut paths: Vec<Vec<Coord>> = vec![];
+    let mut cursor = (0i32, 0i32);
+    let mut i = 0;
+    while i < commands.len() {
+        let (id, count) = (commands[i] & 0x7, (commands[i] >> 3) as usize);
+        i += 1;
+        match id {
+            MOVE_TO | LINE_TO => {
+                if commands.len() < i + 2 * count {
+                    return Err(GeozeroError::Geometry(
+                        "Truncated MVT geometry command".to_string(),
+                    ));
+                }
+                for param in commands[i..i + 2 * count].chunks_exact(2) {
+                    cursor = match (
+                        cursor.0.checked_add(unzigzag(param[0])),
+                        cursor.1.checked_add(unzigzag(param[1])),
+                    ) {
+                        (Some(x), Some(y)) => (x, y),
+                        _ => {
+                            return Err(GeozeroError::Geometry(
+                                "MVT geometry coordinate out of range".to_string(),
+                            ))
+                        }
+                    };
+                    let coord = Coord {
+                        x: cursor.0 as f64,
+                        y: cursor.1 as f64,
+                    };
+                    match paths.last_mut() {
+                        Some(path) if id == LINE_TO => path.push(coord),
+                        _ => paths.push(vec![coord]),
+                    }
+                }
+                i += 2 * count;
+            }
+            CLOSE_PATH => {
+                if let Some(path) = paths.last_mut() {
+                    if let Some(first) = path.first().copied() {
+                        path.push(first);
+                    }
+                }
+            }
+            _ => {
+                return Err(GeozeroError::Geometry(format!(
+                    "Unknown MVT geometry command {id}"
+                )))
+            }
+        }
+    }
+    Ok(paths)
+}
+
+/// Orient polygon rings with counter-clockwise exteriors and clockwise interiors.
+fn orient(geometry: Geometry) -> Geometry {
+    match geometry {
+        Geometry::Polygon(g) => Geometry::Polygon(g.orient(Direction::Default)),
+        Geometry::MultiPolygon(g) => Geometry::MultiPolygon(g.orient(Direction::Default)),
+        g => g,
+    }
+}
+
+/// Decode the geometry of a feature in tile coordinates.
+///
+/// Polygon rings with positive area in tile coordinates start a new polygon and rings with
+/// negative area are holes of the preceding polygon, as in the MVT specification.
+fn decode_geometry(feature: &tile::Feature) -> geozero::error::Result<Option<Geometry>> {
+    let paths = decode_paths(&feature.geometry)?;
+    let geometry = match feature.r#type() {
+        tile::GeomType::Point => {
+            let mut points = paths
+                .into_iter()
+                .flatten()
+                .map(Point::from)
+                .collect::<Vec<_>>();
+            match points.len() {
+                0 => return Ok(None),
+                1 => Geometry::Point(points.remove(0)),
+                _ => Geometry::MultiPoint(MultiPoint::new(points)),
+            }
+        }
+        tile::GeomType::Linestring => {
+            let mut lines = paths
+                .into_iter()
+                .filter(|path| path.len() >= 2)
+                .map(LineString::new)
+                .collect::<Vec<_>>();
+            match lines.len() {
+                0 => return Ok(None),
+                1 => Geometry::LineString(lines.remove(0)),
+                _ => Geometry::MultiLineString(MultiLineString::new(lines)),
+            }
+        }
+        tile::GeomType::Polygon => {
+            let mut polygons: Vec<Polygon> = vec![];
+            for ring in paths {
+                let area = signed_area(&ring);
+                if area > 0. {
+                    polygons.push(Polygon::new(LineString::new(ring), vec![]));
+                } else if area < 0. {
+                    if let Some(polygon) = polygons.last_mut() {
+                        polygon.interiors_push(LineString::new(ring));
+                    }
+                }
+            }
+            match polygons.len() {
+                0 => return Ok(None),
+                1 => Geometry::Polygon(polygons.remove(0)),
+                _ => Geometry::MultiPolygon(MultiPolygon::new(polygons)),
+            }
+        }
+        tile::GeomType::Unknown => return Ok(None),
+    };
+    Ok(Some(geometry))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::array::AsNativeArray;
+    use crate::io::mvt::writer::{write_mvt, MvtWriterOptions};
+    use crate::test::point;
+    use crate::test::polygon::p_array;
+    use crate::trait_::ArrayAccessor;
+    use crate::ArrayBase;
+    use arrow_array::RecordBatch;
+    use arrow_schema::Schema;
+
+    #[test]
+    fn round_trip_points() {
+        let tile = TileCoord::try_new(0, 0, 0).unwrap();
+        let table = point::table();
+        let mut buffer = vec![];
+        let options = MvtWriterOptions {
+            simplify_tolerance: 0.,
+            ..Default::default()
+        };
+        write_mvt(&table, &mut buffer, tile, "points", options).unwrap();
+
+        assert_eq!(read_mvt_layer_names(&buffer).unwrap(), vec!["points"]);
+        let decoded = read_mvt(&buffer, "points", tile, Default::default()).unwrap();
+        assert_eq!(decoded.len(), table.len());
+        assert!(read_mvt(&buffer, "missing", tile, Default::default()).is_err());
+    }
+
+    #[test]
+    fn round_trip_polygons() {
+        let tile = TileCoord::try_new(0, 0, 0).unwrap();
+        let array = p_array();
+        let schema = Arc::new(Schema::new(vec![array.extension_field()]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![array.into_array_ref()]).unwrap();
+        let table = Table::try_new(vec![batch], schema).unwrap();
+
+        let options = MvtWriterOptions {
+            simplify_tolerance: 0.,
+            ..Default::default()
+        };
+        let mut buffer = vec![];
+        write_mvt(&table, &mut buffer, tile, "polygons", options).unwrap();
+
+        let read_options = MvtReaderOptions {
+            coordinates: MvtCoordinates::Tile,
+            ..Default::default()
+        };
+        let decoded = read_mvt(&buffer, "polygons", tile, read_options).unwrap();
+        let geometry = decoded.geometry_column(None).unwrap();
+        assert_eq!(geometry.len(), 2);
+        let chunks = geometry.geometry_chunks();
+        assert_eq!(chunks[0].null_count(), 0);
+        let num_interiors = match chunks[0].as_ref().as_geometry().value_as_geo(1) {
+            Geometry::Polygon(polygon) => polygon.interiors().len(),
+            Geometry::MultiPolygon(polygons) => polygons.0[0].interiors().len(),
+            _ => 0,
+        };
+        assert_eq!(num_interiors, 1);
+    }
+
+    #[test]
+    fn empty_layer() {
+        let tile = TileCoord::try_new(0, 0, 0).unwrap();
+        let tile_data = Tile {
+            layers: vec![tile::Layer {
+                version: 2,
+                name: "empty".to_string(),
+                ..Default::default()
+            }],
+        };
+        let buffer = tile_data.encode_to_vec();
+
+        assert_eq!(read_mvt_layer_names(&buffer).unwrap(), vec!["empty"]);
+        let err = read_mvt(&buffer, "empty", tile, Default::default())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("no features"), "{err}");
+    }
+
+    #[test]
+    fn decode_overflow() {
+        // Two MoveTo(i32::MAX, 0)
+        let commands = [17, u32::MAX - 1, 0, u32::MAX - 1, 0];
+        assert!(decode_paths(&commands).is_err());
+    }
+
+    #[test]
+    fn decode_commands() {
+        // A square from the MVT specification examples: MoveTo(3, 6), LineTo(8, 12), (20, 34),
+        // ClosePath
+        let commands = [9, 6, 12, 18, 10, 12, 24, 44, 15];
+        let paths = decode_paths(&commands).unwrap();
+        assert_eq!(
+            paths,
+            vec![vec![
+                Coord { x: 3., y: 6. },
+                Coord { x: 8., y: 12. },
+                Coord { x: 20., y: 34. },
+                Coord { x: 3., y: 6. },
+            ]]
+        );
+    }
+}
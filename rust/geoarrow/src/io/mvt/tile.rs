@@ -0,0 +1,225 @@
+use geo::{coord, Coord, Rect};
+
+use crate::error::{GeoArrowError, Result};
+
+/// Half the width of the Web Mercator (EPSG:3857) world in meters.
+pub const WEB_MERCATOR_EXTENT: f64 = 20_037_508.342_789_244;
+
+/// The maximum latitude representable in Web Mercator.
+pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+const EARTH_RADIUS: f64 = 6_378_137.0;
+
+/// The bounds of the Web Mercator (EPSG:3857) world in meters.
+pub fn web_mercator_bounds() -> Rect {
+    Rect::new(
+        coord! { x: -WEB_MERCATOR_EXTENT, y: -WEB_MERCATOR_EXTENT },
+        coord! { x: WEB_MERCATOR_EXTENT, y: WEB_MERCATOR_EXTENT },
+    )
+}
+
+/// Project a longitude/latitude coordinate to Web Mercator meters.
+///
+/// Latitudes are clamped to [`MAX_LATITUDE`].
+pub fn lon_lat_to_web_mercator(coord: Coord) -> Coord {
+    let lat = coord.y.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+    coord! {
+        x: coord.x.to_radians() * EARTH_RADIUS,
+        y: (std::f64::consts::FRAC_PI_4 + lat.to_radians() / 2.).tan().ln() * EARTH_RADIUS,
+    }
+}
+
+/// Unproject a Web Mercator coordinate in meters to longitude/latitude.
+pub fn web_mercator_to_lon_lat(coord: Coord) -> Coord {
+    coord! {
+        x: (coord.x / EARTH_RADIUS).to_degrees(),
+        y: (2. * (coord.y / EARTH_RADIUS).exp().atan() - std::f64::consts::FRAC_PI_2).to_degrees(),
+    }
+}
+
+/// The coordinate system of geometries written to or read from a vector tile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MvtCoordinates {
+    /// Longitude/latitude (EPSG:4326)
+    #[default]
+    LonLat,
+    /// Web Mercator (EPSG:3857) meters
+    WebMercator,
+    /// The tile's own coordinate space, running from 0 to the layer extent with y pointing down
+    Tile,
+}
+
+/// Converts between a coordinate system and the coordinate space of one tile.
+pub(super) struct TileTransform {
+    bounds: Rect,
+    extent: f64,
+    coordinates: MvtCoordinates,
+}
+
+impl TileTransform {
+    pub(super) fn new(tile: TileCoord, extent: u32, coordinates: MvtCoordinates) -> Self {
+        Self {
+            bounds: tile.web_mercator_bounds(),
+            extent: extent as f64,
+            coordinates,
+        }
+    }
+
+    pub(super) fn coordinates(&self) -> MvtCoordinates {
+        self.coordinates
+    }
+
+    /// Convert a coordinate to (unrounded) tile coordinates.
+    pub(super) fn encode(&self, coord: Coord) -> Coord {
+        let mercator = match self.coordinates {
+            MvtCoordinates::LonLat => lon_lat_to_web_mercator(coord),
+            MvtCoordinates::WebMercator => coord,
+            MvtCoordinates::Tile => return coord,
+        };
+        coord! {
+            x: (mercator.x - self.bounds.min().x) / self.bounds.width() * self.extent,
+            y: (self.bounds.max().y - mercator.y) / self.bounds.height() * self.extent,
+        }
+    }
+
+    /// Convert a tile coordinate to this transform's coordinate system.
+    pub(super) fn decode(&self, coord: Coord) -> Coord {
+        let mercator = coord! {
+            x: self.bounds.min().x + coord.x / self.extent * self.bounds.width(),
+            y: self.bounds.max().y - coord.y / self.extent * self.bounds.height(),
+        };
+        match self.coordinates {
+            MvtCoordinates::LonLat => web_mercator_to_lon_lat(mercator),
+            MvtCoordinates::WebMercator => mercator,
+            MvtCoordinates::Tile => coord,
+        }
+    }
+}
+
+/// The address of a tile in the XYZ tiling scheme, where `y` counts down from the north.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCoord {
+    /// The zoom level
+    pub z: u8,
+    /// The column, counting east from the antimeridian
+    pub x: u32,
+    /// The row, counting south from the north edge of the world
+    pub y: u32,
+}
+
+impl TileCoord {
+    /// Create a new tile address, checking that `x` and `y` exist at zoom level `z`.
+    pub fn try_new(z: u8, x: u32, y: u32) -> Result<Self> {
+        check_zoom(z)?;
+        let num_tiles = 1u64 << z;
+        if u64::from(x) >= num_tiles || u64::from(y) >= num_tiles {
+            return Err(GeoArrowError::General(format!(
+                "Tile {z}/{x}/{y} does not exist at zoom level {z}"
+            )));
+        }
+        Ok(Self { z, x, y })
+    }
+
+    /// The tile at zoom level `z` that contains the given longitude/latitude coordinate.
+    pub fn from_lon_lat(coord: Coord, z: u8) -> Result<Self> {
+        check_zoom(z)?;
+        let num_tiles = (1u64 << z) as f64;
+        let mercator = lon_lat_to_web_mercator(coord);
+        let to_index = |value: f64| {
+            ((value + WEB_MERCATOR_EXTENT) / (2. * WEB_MERCATOR_EXTENT) * num_tiles)
+                .floor()
+                .clamp(0., num_tiles - 1.) as u32
+        };
+        Self::try_new(z, to_index(mercator.x), to_index(-mercator.y))
+    }
+
+    /// The bounds of this tile in Web Mercator (EPSG:3857) meters.
+    pub fn web_mercator_bounds(&self) -> Rect {
+        self.envelope(0.)
+    }
+
+    /// The bounds of this tile in Web Mercator (EPSG:3857) meters, expanded on each side by
+    /// `margin`, a fraction of the tile width.
+    ///
+    /// This matches PostGIS' `ST_TileEnvelope`. The expanded envelope is not clamped to the bounds
+    /// of the world.
+    pub fn envelope(&self, margin: f64) -> Rect {
+        let tile_size = 2. * WEB_MERCATOR_EXTENT / (1u64 << self.z) as f64;
+        let min_x = -WEB_MERCATOR_EXTENT + self.x as f64 * tile_size;
+        let max_y = WEB_MERCATOR_EXTENT - self.y as f64 * tile_size;
+        let margin = margin * tile_size;
+        Rect::new(
+            coord! { x: min_x - margin, y: max_y - tile_size - margin },
+            coord! { x: min_x + tile_size + margin, y: max_y + margin },
+        )
+    }
+
+    /// The bounds of this tile in longitude/latitude.
+    pub fn lon_lat_bounds(&self) -> Rect {
+        let bounds = self.web_mercator_bounds();
+        Rect::new(
+            web_mercator_to_lon_lat(bounds.min()),
+            web_mercator_to_lon_lat(bounds.max()),
+        )
+    }
+}
+
+fn check_zoom(z: u8) -> Result<()> {
+    if z > 31 {
+        return Err(GeoArrowError::General(format!(
+            "Zoom level {z} is out of range"
+        )));
+    }
+    Ok(())
+}
+
+impl std::fmt::Display for TileCoord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.z, self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn tile_envelope() {
+        let world = TileCoord::try_new(0, 0, 0).unwrap();
+        assert_eq!(world.web_mercator_bounds(), web_mercator_bounds());
+
+        let tile = TileCoord::try_new(1, 1, 0).unwrap();
+        let bounds = tile.web_mercator_bounds();
+        assert_eq!(bounds.min(), coord! { x: 0., y: 0. });
+        assert_eq!(
+            bounds.max(),
+            coord! { x: WEB_MERCATOR_EXTENT, y: WEB_MERCATOR_EXTENT }
+        );
+
+        let lon_lat = tile.lon_lat_bounds();
+        assert_relative_eq!(lon_lat.max().x, 180.);
+        assert_relative_eq!(lon_lat.max().y, MAX_LATITUDE, epsilon = 1e-9);
+
+        assert!(TileCoord::try_new(1, 2, 0).is_err());
+    }
+
+    #[test]
+    fn tile_transform_round_trip() {
+        let tile = TileCoord::try_new(10, 301, 384).unwrap();
+        let transform = TileTransform::new(tile, 4096, MvtCoordinates::LonLat);
+        let coord = coord! { x: -73.98, y: 40.75 };
+        let tile_coord = transform.encode(coord);
+        assert!((0. ..4096.).contains(&tile_coord.x));
+        assert!((0. ..4096.).contains(&tile_coord.y));
+        let round_trip = transform.decode(tile_coord);
+        assert_relative_eq!(round_trip.x, coord.x, epsilon = 1e-9);
+        assert_relative_eq!(round_trip.y, coord.y, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn from_lon_lat() {
+        let tile = TileCoord::from_lon_lat(coord! { x: -73.98, y: 40.75 }, 10).unwrap();
+        assert_eq!(tile, TileCoord::try_new(10, 301, 384).unwrap());
+    }
+}
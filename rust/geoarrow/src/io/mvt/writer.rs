@@ -0,0 +1,552 @@
+use std::io::Write;
+
+use geo::orient::Direction;
+use geo::{
+    BooleanOps, BoundingRect, Coord, Geometry, LineString, MapCoords, MultiLineString, Orient,
+    Polygon, Rect, RemoveRepeatedPoints, Simplify,
+};
+use geozero::geo_types::GeoWriter;
+use geozero::mvt::{tile, Message, Tile};
+use geozero::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, PropertyProcessor};
+use indexmap::IndexSet;
+
+use crate::error::Result;
+use crate::io::mvt::tile::{MvtCoordinates, TileCoord, TileTransform};
+use crate::io::stream::RecordBatchReader;
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// Options for writing vector tiles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MvtWriterOptions {
+    /// The number of integer units along each side of the tile. Defaults to 4096.
+    pub extent: u32,
+
+    /// The distance in tile units beyond the tile edge to keep when clipping, so that lines and
+    /// polygon edges do not show seams between adjacent tiles. Defaults to 64.
+    pub buffer: u32,
+
+    /// The tolerance in tile units used to simplify lines and polygons with the
+    /// Ramer–Douglas–Peucker algorithm. Set to `0` to disable simplification. Defaults to 1.
+    pub simplify_tolerance: f64,
+
+    /// The coordinate system of the input geometries. Defaults to longitude/latitude.
+    pub coordinates: MvtCoordinates,
+}
+
+impl Default for MvtWriterOptions {
+    fn default() -> Self {
+        Self {
+            extent: 4096,
+            buffer: 64,
+            simplify_tolerance: 1.,
+            coordinates: Default::default(),
+        }
+    }
+}
+
+/// A builder for a vector tile made of one or more layers.
+///
+/// Each layer is encoded from a stream of GeoArrow record batches: geometries are clipped to the
+/// (buffered) tile, simplified, quantized to the tile extent and encoded as MVT commands, and
+/// every non-geometry column is written as a feature attribute.
+pub struct MvtTileWriter {
+    tile: TileCoord,
+    options: MvtWriterOptions,
+    layers: Vec<tile::Layer>,
+}
+
+impl MvtTileWriter {
+    /// Create a writer for the given tile.
+    pub fn new(tile: TileCoord, options: MvtWriterOptions) -> Self {
+        Self {
+            tile,
+            options,
+            layers: vec![],
+        }
+    }
+
+    /// Encode a table or stream of record batches as a new layer of this tile.
+    ///
+    /// Features whose geometry is null, lies entirely outside the buffered tile or collapses
+    /// when quantized are omitted. Geometry collections cannot be represented in a vector tile
+    /// and are omitted as well.
+    pub fn add_layer<S: Into<RecordBatchReader>>(&mut self, name: &str, stream: S) -> Result<()> {
+        let transform =
+            TileTransform::new(self.tile, self.options.extent, self.options.coordinates);
+        let mut encoder = LayerEncoder::new(&transform, &self.options);
+        stream.into().process(&mut encoder)?;
+        self.layers.push(encoder.finish(name));
+        Ok(())
+    }
+
+    /// Whether no features have been written to any layer of this tile.
+    pub fn is_empty(&self) -> bool {
+        self.layers.iter().all(|layer| layer.features.is_empty())
+    }
+
+    /// Serialize the tile to protobuf.
+    pub fn finish<W: Write>(self, mut writer: W) -> Result<()> {
+        let tile = Tile {
+            layers: self.layers,
+        };
+        writer.write_all(&tile.encode_to_vec())?;
+        Ok(())
+    }
+}
+
+/// Write a Table or stream of record batches to a single-layer vector tile.
+///
+/// Use [`MvtTileWriter`] to write multiple layers to one tile.
+pub fn write_mvt<W: Write, S: Into<RecordBatchReader>>(
+    stream: S,
+    writer: W,
+    tile: TileCoord,
+    layer_name: &str,
+    options: MvtWriterOptions,
+) -> Result<()> {
+    let mut tile_writer = MvtTileWriter::new(tile, options);
+    tile_writer.add_layer(layer_name, stream)?;
+    tile_writer.finish(writer)
+}
+
+/// An attribute value that can be deduplicated within a layer.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum TagValue {
+    String(String),
+    Float(u32),
+    Double(u64),
+    Int(i64),
+    UInt(u64),
+    Bool(bool),
+}
+
+impl TagValue {
+    fn from_column_value(value: &ColumnValue) -> Option<Self> {
+        let tag_value = match value {
+            ColumnValue::Byte(v) => Self::Int(*v as i64),
+            ColumnValue::Short(v) => Self::Int(*v as i64),
+            ColumnValue::Int(v) => Self::Int(*v as i64),
+            ColumnValue::Long(v) => Self::Int(*v),
+            ColumnValue::UByte(v) => Self::UInt(*v as u64),
+            ColumnValue::UShort(v) => Self::UInt(*v as u64),
+            ColumnValue::UInt(v) => Self::UInt(*v as u64),
+            ColumnValue::ULong(v) => Self::UInt(*v),
+            ColumnValue::Float(v) => Self::Float(v.to_bits()),
+            ColumnValue::Double(v) => Self::Double(v.to_bits()),
+            ColumnValue::Bool(v) => Self::Bool(*v),
+            ColumnValue::String(v) | ColumnValue::Json(v) | ColumnValue::DateTime(v) => {
+                Self::String(v.to_string())
+            }
+            ColumnValue::Binary(_) => return None,
+        };
+        Some(tag_value)
+    }
+
+    fn into_value(self) -> tile::Value {
+        let mut value = tile::Value::default();
+        match self {
+            Self::String(v) => value.string_value = Some(v),
+            Self::Float(v) => value.float_value = Some(f32::from_bits(v)),
+            Self::Double(v) => value.double_value = Some(f64::from_bits(v)),
+            Self::Int(v) => value.int_value = Some(v),
+            Self::UInt(v) => value.uint_value = Some(v),
+            Self::Bool(v) => value.bool_value = Some(v),
+        }
+        value
+    }
+}
+
+/// A [`FeatureProcessor`] that encodes features into a single MVT layer.
+struct LayerEncoder<'a> {
+    transform: &'a TileTransform,
+    options: &'a MvtWriterOptions,
+    keys: IndexSet<String>,
+    values: IndexSet<TagValue>,
+    features: Vec<tile::Feature>,
+    current_tags: Vec<u32>,
+    current_geometry: GeoWriter,
+}
+
+impl<'a> LayerEncoder<'a> {
+    fn new(transform: &'a TileTransform, options: &'a MvtWriterOptions) -> Self {
+        Self {
+            transform,
+            options,
+            keys: IndexSet::new(),
+            values: IndexSet::new(),
+            features: vec![],
+            current_tags: vec![],
+            current_geometry: GeoWriter::new(),
+        }
+    }
+
+    fn finish(self, name: &str) -> tile::Layer {
+        tile::Layer {
+            version: 2,
+            name: name.to_string(),
+            features: self.features,
+            keys: self.keys.into_iter().collect(),
+            values: self.values.into_iter().map(TagValue::into_value).collect(),
+            extent: Some(self.options.extent),
+        }
+    }
+}
+
+impl FeatureProcessor for LayerEncoder<'_> {
+    fn feature_begin(&mut self, _idx: u64) -> geozero::error::Result<()> {
+        self.current_tags.clear();
+        self.current_geometry = GeoWriter::new();
+        Ok(())
+    }
+
+    fn feature_end(&mut self, idx: u64) -> geozero::error::Result<()> {
+        let Some(geometry) = self.current_geometry.take_geometry() else {
+            return Ok(());
+        };
+        let Some(geometry) = prepare_geometry(geometry, self.transform, self.options) else {
+            return Ok(());
+        };
+        let (geom_type, commands) = encode_geometry(&geometry);
+
+        let mut feature = tile::Feature {
+            id: Some(idx),
+            tags: std::mem::take(&mut self.current_tags),
+            geometry: commands,
+            ..Default::default()
+        };
+        feature.set_type(geom_type);
+        self.features.push(feature);
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for LayerEncoder<'_> {
+    fn property(
+        &mut self,
+        _idx: usize,
+        name: &str,
+        value: &ColumnValue,
+    ) -> geozero::error::Result<bool> {
+        if let Some(value) = TagValue::from_column_value(value) {
+            let (key_idx, _) = self.keys.insert_full(name.to_string());
+            let (value_idx, _) = self.values.insert_full(value);
+            self.current_tags.push(key_idx as u32);
+            self.current_tags.push(value_idx as u32);
+        }
+        Ok(false)
+    }
+}
+
+impl GeomProcessor for LayerEncoder<'_> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.xy(x, y, idx)
+    }
+
+    fn empty_point(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.empty_point(idx)
+    }
+
+    fn point_begin(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.point_begin(idx)
+    }
+
+    fn point_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.point_end(idx)
+    }
+
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.multipoint_begin(size, idx)
+    }
+
+    fn multipoint_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.multipoint_end(idx)
+    }
+
+    fn linestring_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.current_geometry.linestring_begin(tagged, size, idx)
+    }
+
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.linestring_end(tagged, idx)
+    }
+
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.multilinestring_begin(size, idx)
+    }
+
+    fn multilinestring_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.multilinestring_end(idx)
+    }
+
+    fn polygon_begin(
+        &mut self,
+        tagged: bool,
+        size: usize,
+        idx: usize,
+    ) -> geozero::error::Result<()> {
+        self.current_geometry.polygon_begin(tagged, size, idx)
+    }
+
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.polygon_end(tagged, idx)
+    }
+
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.multipolygon_begin(size, idx)
+    }
+
+    fn multipolygon_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.multipolygon_end(idx)
+    }
+
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.geometrycollection_begin(size, idx)
+    }
+
+    fn geometrycollection_end(&mut self, idx: usize) -> geozero::error::Result<()> {
+        self.current_geometry.geometrycollection_end(idx)
+    }
+}
+
+/// A geometry in integer tile coordinates, grouped by the MVT geometry type it is written as.
+#[derive(Debug, PartialEq)]
+enum TileGeometry {
+    Points(Vec<Coord>),
+    Lines(Vec<LineString>),
+    Polygons(Vec<Polygon>),
+}
+
+/// Clip, simplify and quantize a geometry into tile coordinates.
+///
+/// Returns `None` if nothing of the geometry remains.
+fn prepare_geometry(
+    geometry: Geometry,
+    transform: &TileTransform,
+    options: &MvtWriterOptions,
+) -> Option<TileGeometry> {
+    let geometry = geometry.map_coords(|coord| transform.encode(coord));
+    let min = -(options.buffer as f64);
+    let max = options.extent as f64 + options.buffer as f64;
+    let clip_rect = Rect::new((min, min), (max, max));
+    let tolerance = options.simplify_tolerance;
+
+    let tile_geometry = match geometry {
+        Geometry::Point(point) => TileGeometry::Points(clip_points(vec![point.0], &clip_rect)),
+        Geometry::MultiPoint(points) => TileGeometry::Points(clip_points(
+            points.into_iter().map(|point| point.0).collect(),
+            &clip_rect,
+        )),
+        Geometry::Line(line) => TileGeometry::Lines(clip_lines(vec![line.into()], &clip_rect)),
+        Geometry::LineString(line) => TileGeometry::Lines(clip_lines(vec![line], &clip_rect)),
+        Geometry::MultiLineString(lines) => TileGeometry::Lines(clip_lines(lines.0, &clip_rect)),
+        Geometry::Polygon(polygon) => {
+            TileGeometry::Polygons(clip_polygons(vec![polygon], &clip_rect))
+        }
+        Geometry::MultiPolygon(polygons) => {
+            TileGeometry::Polygons(clip_polygons(polygons.0, &clip_rect))
+        }
+        Geometry::Rect(rect) => {
+            TileGeometry::Polygons(clip_polygons(vec![rect.to_polygon()], &clip_rect))
+        }
+        Geometry::Triangle(triangle) => {
+            TileGeometry::Polygons(clip_polygons(vec![triangle.to_polygon()], &clip_rect))
+        }
+        Geometry::GeometryCollection(_) => return None,
+    };
+
+    let tile_geometry = match tile_geometry {
+        TileGeometry::Points(coords) => {
+            let mut coords = coords.into_iter().map(quantize).collect::<Vec<_>>();
+            coords.dedup();
+            TileGeometry::Points(coords)
+        }
+        TileGeometry::Lines(lines) => TileGeometry::Lines(
+            lines
+                .into_iter()
+                .map(|line| simplify(line, tolerance))
+                .filter_map(|line| {
+                    let line = line.map_coords(quantize).remove_repeated_points();
+                    (line.0.len() >= 2).then_some(line)
+                })
+                .collect(),
+        ),
+        TileGeometry::Polygons(polygons) => TileGeometry::Polygons(
+            polygons
+                .into_iter()
+                .filter_map(|polygon| {
+                    let (exterior, interiors) = polygon.into_inner();
+                    let exterior = quantize_ring(simplify(exterior, tolerance))?;
+                    let interiors = interiors
+                        .into_iter()
+                        .filter_map(|ring| quantize_ring(simplify(ring, tolerance)))
+                        .collect();
+                    Some(Polygon::new(exterior, interiors).orient(Direction::Default))
+                })
+                .collect(),
+        ),
+    };
+
+    let is_empty = match &tile_geometry {
+        TileGeometry::Points(coords) => coords.is_empty(),
+        TileGeometry::Lines(lines) => lines.is_empty(),
+        TileGeometry::Polygons(polygons) => polygons.is_empty(),
+    };
+    (!is_empty).then_some(tile_geometry)
+}
+
+fn rect_contains(outer: &Rect, inner: &Rect) -> bool {
+    outer.min().x <= inner.min().x
+        && outer.min().y <= inner.min().y
+        && inner.max().x <= outer.max().x
+        && inner.max().y <= outer.max().y
+}
+
+fn rect_intersects(a: &Rect, b: &Rect) -> bool {
+    a.min().x <= b.max().x
+        && b.min().x <= a.max().x
+        && a.min().y <= b.max().y
+        && b.min().y <= a.max().y
+}
+
+fn clip_points(mut coords: Vec<Coord>, clip_rect: &Rect) -> Vec<Coord> {
+    coords.retain(|coord| rect_contains(clip_rect, &Rect::new(*coord, *coord)));
+    coords
+}
+
+fn clip_lines(lines: Vec<LineString>, clip_rect: &Rect) -> Vec<LineString> {
+    let mut output = Vec::with_capacity(lines.len());
+    let mut to_clip = vec![];
+    for line in lines {
+        match line.bounding_rect() {
+            Some(bounds) if rect_contains(clip_rect, &bounds) => output.push(line),
+            Some(bounds) if rect_intersects(clip_rect, &bounds) => to_clip.push(line),
+            _ => {}
+        }
+    }
+    if !to_clip.is_empty() {
+        let clipped = clip_rect
+            .to_polygon()
+            .clip(&MultiLineString::new(to_clip), false);
+        output.extend(clipped.0);
+    }
+    output
+}
+
+fn clip_polygons(polygons: Vec<Polygon>, clip_rect: &Rect) -> Vec<Polygon> {
+    let clip_polygon = clip_rect.to_polygon();
+    let mut output = Vec::with_capacity(polygons.len());
+    for polygon in polygons {
+        match polygon.bounding_rect() {
+            Some(bounds) if rect_contains(clip_rect, &bounds) => output.push(polygon),
+            Some(bounds) if rect_intersects(clip_rect, &bounds) => {
+                output.extend(clip_polygon.intersection(&polygon).0)
+            }
+            _ => {}
+        }
+    }
+    output
+}
+
+fn simplify(line: LineString, tolerance: f64) -> LineString {
+    if tolerance > 0. {
+        line.simplify(&tolerance)
+    } else {
+        line
+    }
+}
+
+fn quantize(coord: Coord) -> Coord {
+    Coord {
+        x: coord.x.round(),
+        y: coord.y.round(),
+    }
+}
+
+/// Quantize a ring, dropping it if it collapses to zero area.
+fn quantize_ring(ring: LineString) -> Option<LineString> {
+    let ring = ring.map_coords(quantize).remove_repeated_points();
+    (ring.0.len() >= 4 && signed_area(&ring.0) != 0.).then_some(ring)
+}
+
+/// The signed area of a closed ring using the surveyor's formula.
+pub(super) fn signed_area(coords: &[Coord]) -> f64 {
+    coords
+        .windows(2)
+        .map(|pair| pair[0].x * pair[1].y - pair[1].x * pair[0].y)
+        .sum::<f64>()
+        / 2.
+}
+
+fn command(id: u32, count: usize) -> u32 {
+    (id & 0x7) | ((count as u32) << 3)
+}
+
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Encodes coordinates as MVT commands, tracking the cursor across parts of a geometry.
+#[derive(Default)]
+struct CommandEncoder {
+    data: Vec<u32>,
+    cursor: (i32, i32),
+}
+
+impl CommandEncoder {
+    fn push_coords(&mut self, id: u32, coords: &[Coord]) {
+        if coords.is_empty() {
+            return;
+        }
+        self.data.push(command(id, coords.len()));
+        for coord in coords {
+            let (x, y) = (coord.x as i32, coord.y as i32);
+            self.data.push(zigzag(x - self.cursor.0));
+            self.data.push(zigzag(y - self.cursor.1));
+            self.cursor = (x, y);
+        }
+    }
+
+    fn line(&mut self, coords: &[Coord]) {
+        self.push_coords(MOVE_TO, &coords[..1]);
+        self.push_coords(LINE_TO, &coords[1..]);
+    }
+
+    fn ring(&mut self, ring: &LineString) {
+        // The closing coordinate is implied by ClosePath
+        self.line(&ring.0[..ring.0.len() - 1]);
+        self.data.push(command(CLOSE_PATH, 1));
+    }
+}
+
+fn encode_geometry(geometry: &TileGeometry) -> (tile::GeomType, Vec<u32>) {
+    let mut encoder = CommandEncoder::default();
+    let geom_type = match geometry {
+        TileGeometry::Points(coords) => {
+            encoder.push_coords(MOVE_TO, coords);
+            tile::GeomType::Point
+        }
+        TileGeometry::Lines(lines) => {
+            lines.iter().for_each(|line| encoder.line(&line.0));
+            tile::GeomType::Linestring
+        }
+        TileGeometry::Polygons(polygons) => {
+            for polygon in polygons {
+                encoder.ring(polygon.exterior());
+                polygon
+                    .interiors()
+                    .iter()
+                    .for_each(|ring| encoder.ring(ring));
+            }
+            tile::GeomType::Polygon
+        }
+    };
+    (geom_type, encoder.data)
+}
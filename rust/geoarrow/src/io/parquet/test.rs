@@ -6,11 +6,16 @@ use arrow_array::{BooleanArray, RecordBatch};
 use arrow_schema::{Field, Schema};
 use bytes::Bytes;
 
-use crate::array::GeometryBuilder;
+use crate::algorithm::native::Downcast;
+use crate::array::{AsNativeArray, CoordType, GeometryBuilder};
 use crate::chunked_array::ChunkedNativeArrayDyn;
+use crate::chunked_array::ChunkedPointArray;
+use crate::datatypes::NativeType;
 use crate::error::Result;
 use crate::io::parquet::{write_geoparquet, GeoParquetRecordBatchReaderBuilder};
 use crate::table::Table;
+use crate::test::point;
+use crate::NativeArray;
 
 #[ignore = "fails!"]
 #[test]
@@ -71,3 +76,42 @@ fn mixed_geometry_roundtrip() {
         .read_table()
         .unwrap();
 }
+
+#[test]
+fn measured_points_roundtrip() {
+    for array in [point::point_m_array(), point::point_zm_array()] {
+        let dim = array.dimension();
+        let table = Table::from_arrow_and_geometry(
+            vec![RecordBatch::new_empty(Arc::new(Schema::empty()))],
+            Arc::new(Schema::empty()),
+            Arc::new(ChunkedPointArray::new(vec![array.clone()])),
+        )
+        .unwrap();
+        let mut cursor = Cursor::new(Vec::new());
+        write_geoparquet(
+            table.into_record_batch_reader(),
+            &mut cursor,
+            &Default::default(),
+        )
+        .unwrap();
+
+        let bytes = Bytes::from(cursor.into_inner());
+        let again = GeoParquetRecordBatchReaderBuilder::try_new(bytes)
+            .unwrap()
+            .build()
+            .unwrap()
+            .read_table()
+            .unwrap();
+        // GeoParquet has no geometry type identifiers for measured geometries, so the column is
+        // written with empty geometry_types and read back as a geometry array of multi points.
+        let geometry = again.geometry_column(None).unwrap();
+        assert_eq!(
+            geometry.data_type(),
+            NativeType::Geometry(CoordType::Interleaved)
+        );
+        let chunk = geometry.geometry_chunks()[0].clone();
+        assert!(chunk.as_ref().as_geometry().has_multi_points(dim));
+        let downcasted = chunk.as_ref().as_geometry().downcast().unwrap();
+        assert_eq!(downcasted.as_ref().as_point(), &array);
+    }
+}
@@ -7,10 +7,10 @@ use serde_json::Value;
 
 use crate::algorithm::native::bounding_rect::BoundingRect;
 use crate::array::metadata::{ArrayMetadata, Edges};
-use crate::array::{AsNativeArray, CoordType, NativeArrayDyn};
+use crate::array::{AsNativeArray, CoordType, GeometryArray, NativeArrayDyn};
 use crate::datatypes::{Dimension, NativeType, SerializedType};
 use crate::error::Result;
-use crate::io::crs::{CRSTransform, DefaultCRSTransform};
+use crate::io::crs::{default_crs_transform, CRSTransform};
 use crate::io::parquet::metadata::{
     GeoParquetColumnEncoding, GeoParquetColumnMetadata, GeoParquetGeometryType, GeoParquetMetadata,
 };
@@ -53,7 +53,7 @@ impl ColumnInfo {
         let crs = if let Some(crs_transform) = crs_transform {
             crs_transform.extract_projjson(&array_meta)?
         } else {
-            DefaultCRSTransform::default().extract_projjson(&array_meta)?
+            default_crs_transform().extract_projjson(&array_meta)?
         };
         let edges = array_meta.edges;
 
@@ -90,32 +90,72 @@ impl ColumnInfo {
         // We only have to do this for geometry arrays because other arrays are statically known
         if let NativeType::Geometry(_) = array_ref.data_type() {
             let arr = array_ref.as_geometry();
-            if arr.has_points(Dimension::XY) || arr.has_points(Dimension::XYZ) {
+            let has_any = |f: fn(&GeometryArray, Dimension) -> bool| {
+                Dimension::ALL.into_iter().any(|dim| f(arr, dim))
+            };
+            if has_any(GeometryArray::has_points) {
                 self.geometry_types.insert(GeoParquetGeometryType::Point);
             }
-            if arr.has_line_strings(Dimension::XY) || arr.has_line_strings(Dimension::XYZ) {
+            if has_any(GeometryArray::has_line_strings) {
                 self.geometry_types
                     .insert(GeoParquetGeometryType::LineString);
             }
-            if arr.has_polygons(Dimension::XY) || arr.has_polygons(Dimension::XYZ) {
+            if has_any(GeometryArray::has_polygons) {
                 self.geometry_types.insert(GeoParquetGeometryType::Polygon);
             }
-            if arr.has_multi_points(Dimension::XY) || arr.has_multi_points(Dimension::XYZ) {
+            if has_any(GeometryArray::has_multi_points) {
                 self.geometry_types
                     .insert(GeoParquetGeometryType::MultiPoint);
             }
-            if arr.has_multi_line_strings(Dimension::XY)
-                || arr.has_multi_line_strings(Dimension::XYZ)
-            {
+            if has_any(GeometryArray::has_multi_line_strings) {
                 self.geometry_types
                     .insert(GeoParquetGeometryType::MultiLineString);
             }
-            if arr.has_multi_polygons(Dimension::XY) || arr.has_multi_polygons(Dimension::XYZ) {
+            if has_any(GeometryArray::has_multi_polygons) {
                 self.geometry_types
                     .insert(GeoParquetGeometryType::MultiPolygon);
             }
         }
 
+        // Mixed arrays have a single dimension, so we can also tag 3D geometry types
+        if let NativeType::Mixed(_, dim) = array_ref.data_type() {
+            use GeoParquetGeometryType::*;
+            let arr = array_ref.as_mixed();
+            let types = match dim {
+                Dimension::XY => [
+                    Point,
+                    LineString,
+                    Polygon,
+                    MultiPoint,
+                    MultiLineString,
+                    MultiPolygon,
+                ],
+                Dimension::XYZ => [
+                    PointZ,
+                    LineStringZ,
+                    PolygonZ,
+                    MultiPointZ,
+                    MultiLineStringZ,
+                    MultiPolygonZ,
+                ],
+                // GeoParquet has no geometry type identifiers for measured geometries
+                Dimension::XYM | Dimension::XYZM => return Ok(()),
+            };
+            let present = [
+                arr.has_points(),
+                arr.has_line_strings(),
+                arr.has_polygons(),
+                arr.has_multi_points(),
+                arr.has_multi_line_strings(),
+                arr.has_multi_polygons(),
+            ];
+            for (geometry_type, present) in types.into_iter().zip(present) {
+                if present {
+                    self.geometry_types.insert(geometry_type);
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -282,6 +322,9 @@ pub fn get_geometry_types(data_type: &NativeType) -> HashSet<GeoParquetGeometryT
         NativeType::GeometryCollection(_, Dimension::XYZ) => {
             geometry_types.insert(GeometryCollectionZ);
         }
+        // GeoParquet has no geometry type identifiers for measured geometries, and an empty set
+        // means the geometry types are unknown.
+        _ => {}
     };
 
     geometry_types
@@ -27,5 +27,7 @@ pub struct GeoParquetWriterOptions {
     pub writer_properties: Option<WriterProperties>,
 
     /// A transformer for converting CRS from the GeoArrow representation to PROJJSON.
+    ///
+    /// If not provided, [`default_crs_transform`][crate::io::crs::default_crs_transform] is used.
     pub crs_transform: Option<Box<dyn CRSTransform>>,
 }
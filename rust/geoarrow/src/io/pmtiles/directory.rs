@@ -0,0 +1,211 @@
+use crate::error::{GeoArrowError, Result};
+
+/// One entry of a PMTiles directory.
+///
+/// An entry with a `run_length` of 0 points to a leaf directory; otherwise it points to tile
+/// data shared by `run_length` consecutive tile IDs starting at `tile_id`. Offsets are relative
+/// to the start of the leaf directory or tile data section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectoryEntry {
+    /// The first tile ID covered by this entry.
+    pub tile_id: u64,
+    /// The offset of the tile data or leaf directory.
+    pub offset: u64,
+    /// The length of the tile data or leaf directory.
+    pub length: u32,
+    /// The number of consecutive tile IDs that share this tile data, or 0 for a leaf directory.
+    pub run_length: u32,
+}
+
+impl DirectoryEntry {
+    /// Whether this entry points to a leaf directory instead of tile data.
+    pub fn is_leaf(&self) -> bool {
+        self.run_length == 0
+    }
+}
+
+/// A directory of a PMTiles archive, with entries sorted by tile ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Directory {
+    entries: Vec<DirectoryEntry>,
+}
+
+fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn read_varint(bytes: &[u8], position: &mut usize) -> Result<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*position).ok_or_else(|| {
+            GeoArrowError::General("Unexpected end of PMTiles directory".to_string())
+        })?;
+        *position += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(GeoArrowError::General(
+        "Invalid varint in PMTiles directory".to_string(),
+    ))
+}
+
+impl Directory {
+    /// Create a directory from entries sorted by tile ID.
+    pub fn new(entries: Vec<DirectoryEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// The entries of this directory.
+    pub fn entries(&self) -> &[DirectoryEntry] {
+        &self.entries
+    }
+
+    /// Find the entry holding `tile_id`, either the tile data itself or the leaf directory that
+    /// may contain it.
+    pub fn find_tile(&self, tile_id: u64) -> Option<&DirectoryEntry> {
+        let idx = self
+            .entries
+            .partition_point(|entry| entry.tile_id <= tile_id);
+        let entry = self.entries.get(idx.checked_sub(1)?)?;
+        (entry.is_leaf() || tile_id - entry.tile_id < entry.run_length as u64).then_some(entry)
+    }
+
+    /// Parse an uncompressed directory.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut position = 0;
+        let num_entries = read_varint(bytes, &mut position)?;
+        // Every entry takes at least one byte, so a larger count can only come from a corrupt
+        // directory and must not be used to size the allocation below.
+        if num_entries > bytes.len() as u64 {
+            return Err(GeoArrowError::General(format!(
+                "PMTiles directory of {} bytes can't hold {num_entries} entries",
+                bytes.len()
+            )));
+        }
+        let num_entries = num_entries as usize;
+        let mut entries = vec![
+            DirectoryEntry {
+                tile_id: 0,
+                offset: 0,
+                length: 0,
+                run_length: 0,
+            };
+            num_entries
+        ];
+
+        let mut last_id = 0u64;
+        for entry in entries.iter_mut() {
+            last_id = last_id
+                .checked_add(read_varint(bytes, &mut position)?)
+                .ok_or_else(|| {
+                    GeoArrowError::General("Invalid tile ID in PMTiles directory".to_string())
+                })?;
+            entry.tile_id = last_id;
+        }
+        for entry in entries.iter_mut() {
+            entry.run_length = read_varint(bytes, &mut position)? as u32;
+        }
+        for entry in entries.iter_mut() {
+            entry.length = read_varint(bytes, &mut position)? as u32;
+        }
+        for i in 0..num_entries {
+            let value = read_varint(bytes, &mut position)?;
+            let offset = if value == 0 && i > 0 {
+                entries[i - 1]
+                    .offset
+                    .checked_add(entries[i - 1].length as u64)
+            } else {
+                value.checked_sub(1)
+            };
+            entries[i].offset = offset.ok_or_else(|| {
+                GeoArrowError::General("Invalid offset in PMTiles directory".to_string())
+            })?;
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Serialize this directory, without compression.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = vec![];
+        write_varint(&mut buffer, self.entries.len() as u64);
+
+        let mut last_id = 0;
+        for entry in self.entries.iter() {
+            write_varint(&mut buffer, entry.tile_id - last_id);
+            last_id = entry.tile_id;
+        }
+        for entry in self.entries.iter() {
+            write_varint(&mut buffer, entry.run_length as u64);
+        }
+        for entry in self.entries.iter() {
+            write_varint(&mut buffer, entry.length as u64);
+        }
+        for (i, entry) in self.entries.iter().enumerate() {
+            // Offsets directly following the previous entry are stored as 0
+            let contiguous = i > 0 && {
+                let previous = &self.entries[i - 1];
+                entry.offset == previous.offset + previous.length as u64
+            };
+            write_varint(&mut buffer, if contiguous { 0 } else { entry.offset + 1 });
+        }
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(tile_id: u64, offset: u64, length: u32, run_length: u32) -> DirectoryEntry {
+        DirectoryEntry {
+            tile_id,
+            offset,
+            length,
+            run_length,
+        }
+    }
+
+    #[test]
+    fn directory_round_trip() {
+        let directory = Directory::new(vec![
+            entry(0, 0, 10, 1),
+            entry(1, 10, 20, 3),
+            entry(5, 0, 10, 1),
+            entry(300, 30, 1000, 1),
+        ]);
+        let bytes = directory.to_bytes();
+        assert_eq!(Directory::from_bytes(&bytes).unwrap(), directory);
+        assert!(Directory::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn corrupt_directory() {
+        let mut bytes = vec![];
+        write_varint(&mut bytes, u64::MAX);
+        assert!(Directory::from_bytes(&bytes).is_err());
+
+        let mut bytes = vec![];
+        write_varint(&mut bytes, 2);
+        write_varint(&mut bytes, u64::MAX);
+        write_varint(&mut bytes, 1);
+        bytes.extend([1, 1, 1, 1, 1, 1]);
+        assert!(Directory::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn find_tile() {
+        let directory = Directory::new(vec![entry(1, 0, 10, 3), entry(10, 0, 50, 0)]);
+        assert!(directory.find_tile(0).is_none());
+        assert_eq!(directory.find_tile(3).unwrap().tile_id, 1);
+        assert!(directory.find_tile(4).is_none());
+        // Leaf directories cover every tile ID up to the next entry
+        assert_eq!(directory.find_tile(1000).unwrap().tile_id, 10);
+    }
+}
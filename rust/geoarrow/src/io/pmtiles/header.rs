@@ -0,0 +1,308 @@
+use std::io::{Read, Write};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::error::{GeoArrowError, Result};
+
+/// The length in bytes of a PMTiles v3 header.
+pub const HEADER_SIZE: usize = 127;
+
+const MAGIC: &[u8; 7] = b"PMTiles";
+const VERSION: u8 = 3;
+
+/// The compression applied to directories, metadata or tiles of a PMTiles archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// Unknown or unspecified compression
+    Unknown,
+    /// No compression
+    None,
+    /// Gzip compression
+    Gzip,
+    /// Brotli compression
+    Brotli,
+    /// Zstandard compression
+    Zstd,
+}
+
+impl Compression {
+    fn from_byte(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::None),
+            2 => Ok(Self::Gzip),
+            3 => Ok(Self::Brotli),
+            4 => Ok(Self::Zstd),
+            _ => Err(GeoArrowError::General(format!(
+                "Invalid PMTiles compression {value}"
+            ))),
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Unknown => 0,
+            Self::None => 1,
+            Self::Gzip => 2,
+            Self::Brotli => 3,
+            Self::Zstd => 4,
+        }
+    }
+
+    pub(crate) fn compress(self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(vec![], flate2::Compression::default());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            _ => Err(GeoArrowError::NotYetImplemented(format!(
+                "{self:?} compression in PMTiles"
+            ))),
+        }
+    }
+
+    pub(crate) fn decompress(self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Gzip => {
+                let mut output = vec![];
+                GzDecoder::new(data).read_to_end(&mut output)?;
+                Ok(output)
+            }
+            _ => Err(GeoArrowError::NotYetImplemented(format!(
+                "{self:?} compression in PMTiles"
+            ))),
+        }
+    }
+}
+
+/// The format of the tiles in a PMTiles archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TileType {
+    /// Unknown or other format
+    Unknown,
+    /// Mapbox Vector Tiles
+    Mvt,
+    /// PNG images
+    Png,
+    /// JPEG images
+    Jpeg,
+    /// WebP images
+    Webp,
+    /// AVIF images
+    Avif,
+}
+
+impl TileType {
+    fn from_byte(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Unknown),
+            1 => Ok(Self::Mvt),
+            2 => Ok(Self::Png),
+            3 => Ok(Self::Jpeg),
+            4 => Ok(Self::Webp),
+            5 => Ok(Self::Avif),
+            _ => Err(GeoArrowError::General(format!(
+                "Invalid PMTiles tile type {value}"
+            ))),
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::Unknown => 0,
+            Self::Mvt => 1,
+            Self::Png => 2,
+            Self::Jpeg => 3,
+            Self::Webp => 4,
+            Self::Avif => 5,
+        }
+    }
+}
+
+/// The fixed-size header at the start of a PMTiles v3 archive.
+///
+/// Offsets are in bytes from the start of the archive. Positions are longitude/latitude.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PMTilesHeader {
+    /// The offset of the root directory.
+    pub root_directory_offset: u64,
+    /// The length of the root directory.
+    pub root_directory_length: u64,
+    /// The offset of the JSON metadata.
+    pub metadata_offset: u64,
+    /// The length of the JSON metadata.
+    pub metadata_length: u64,
+    /// The offset of the section holding all leaf directories.
+    pub leaf_directories_offset: u64,
+    /// The length of the section holding all leaf directories.
+    pub leaf_directories_length: u64,
+    /// The offset of the section holding all tile data.
+    pub tile_data_offset: u64,
+    /// The length of the section holding all tile data.
+    pub tile_data_length: u64,
+    /// The number of tiles with content, counting each tile of a run.
+    pub num_addressed_tiles: u64,
+    /// The number of tile entries in all directories.
+    pub num_tile_entries: u64,
+    /// The number of distinct tile contents.
+    pub num_tile_contents: u64,
+    /// Whether tile data is ordered by tile ID.
+    pub clustered: bool,
+    /// The compression of directories and metadata.
+    pub internal_compression: Compression,
+    /// The compression of each tile.
+    pub tile_compression: Compression,
+    /// The format of each tile.
+    pub tile_type: TileType,
+    /// The lowest zoom level with tiles.
+    pub min_zoom: u8,
+    /// The highest zoom level with tiles.
+    pub max_zoom: u8,
+    /// The western edge of the archive's bounds.
+    pub min_longitude: f64,
+    /// The southern edge of the archive's bounds.
+    pub min_latitude: f64,
+    /// The eastern edge of the archive's bounds.
+    pub max_longitude: f64,
+    /// The northern edge of the archive's bounds.
+    pub max_latitude: f64,
+    /// The zoom level of the default view.
+    pub center_zoom: u8,
+    /// The longitude of the default view.
+    pub center_longitude: f64,
+    /// The latitude of the default view.
+    pub center_latitude: f64,
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+
+fn read_position(bytes: &[u8], offset: usize) -> f64 {
+    i32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()) as f64 / 1e7
+}
+
+fn write_position(buffer: &mut Vec<u8>, value: f64) {
+    buffer.extend_from_slice(&((value * 1e7).round() as i32).to_le_bytes());
+}
+
+impl PMTilesHeader {
+    /// Parse a header from the first [`HEADER_SIZE`] bytes of an archive.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_SIZE || &bytes[..7] != MAGIC {
+            return Err(GeoArrowError::General("Not a PMTiles archive".to_string()));
+        }
+        if bytes[7] != VERSION {
+            return Err(GeoArrowError::NotYetImplemented(format!(
+                "PMTiles version {}",
+                bytes[7]
+            )));
+        }
+
+        Ok(Self {
+            root_directory_offset: read_u64(bytes, 8),
+            root_directory_length: read_u64(bytes, 16),
+            metadata_offset: read_u64(bytes, 24),
+            metadata_length: read_u64(bytes, 32),
+            leaf_directories_offset: read_u64(bytes, 40),
+            leaf_directories_length: read_u64(bytes, 48),
+            tile_data_offset: read_u64(bytes, 56),
+            tile_data_length: read_u64(bytes, 64),
+            num_addressed_tiles: read_u64(bytes, 72),
+            num_tile_entries: read_u64(bytes, 80),
+            num_tile_contents: read_u64(bytes, 88),
+            clustered: bytes[96] == 1,
+            internal_compression: Compression::from_byte(bytes[97])?,
+            tile_compression: Compression::from_byte(bytes[98])?,
+            tile_type: TileType::from_byte(bytes[99])?,
+            min_zoom: bytes[100],
+            max_zoom: bytes[101],
+            min_longitude: read_position(bytes, 102),
+            min_latitude: read_position(bytes, 106),
+            max_longitude: read_position(bytes, 110),
+            max_latitude: read_position(bytes, 114),
+            center_zoom: bytes[118],
+            center_longitude: read_position(bytes, 119),
+            center_latitude: read_position(bytes, 123),
+        })
+    }
+
+    /// Serialize this header to [`HEADER_SIZE`] bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(HEADER_SIZE);
+        buffer.extend_from_slice(MAGIC);
+        buffer.push(VERSION);
+        for value in [
+            self.root_directory_offset,
+            self.root_directory_length,
+            self.metadata_offset,
+            self.metadata_length,
+            self.leaf_directories_offset,
+            self.leaf_directories_length,
+            self.tile_data_offset,
+            self.tile_data_length,
+            self.num_addressed_tiles,
+            self.num_tile_entries,
+            self.num_tile_contents,
+        ] {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        buffer.push(u8::from(self.clustered));
+        buffer.push(self.internal_compression.to_byte());
+        buffer.push(self.tile_compression.to_byte());
+        buffer.push(self.tile_type.to_byte());
+        buffer.push(self.min_zoom);
+        buffer.push(self.max_zoom);
+        write_position(&mut buffer, self.min_longitude);
+        write_position(&mut buffer, self.min_latitude);
+        write_position(&mut buffer, self.max_longitude);
+        write_position(&mut buffer, self.max_latitude);
+        buffer.push(self.center_zoom);
+        write_position(&mut buffer, self.center_longitude);
+        write_position(&mut buffer, self.center_latitude);
+        buffer
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn header_round_trip() {
+        let header = PMTilesHeader {
+            root_directory_offset: 127,
+            root_directory_length: 25,
+            metadata_offset: 152,
+            metadata_length: 247,
+            leaf_directories_offset: 399,
+            leaf_directories_length: 0,
+            tile_data_offset: 399,
+            tile_data_length: 69,
+            num_addressed_tiles: 1,
+            num_tile_entries: 1,
+            num_tile_contents: 1,
+            clustered: false,
+            internal_compression: Compression::Gzip,
+            tile_compression: Compression::Gzip,
+            tile_type: TileType::Mvt,
+            min_zoom: 0,
+            max_zoom: 3,
+            min_longitude: -180.,
+            min_latitude: -85.,
+            max_longitude: 178.,
+            max_latitude: 83.,
+            center_zoom: 0,
+            center_longitude: -1.,
+            center_latitude: -1.5,
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(bytes.len(), HEADER_SIZE);
+        assert_eq!(PMTilesHeader::from_bytes(&bytes).unwrap(), header);
+        assert!(PMTilesHeader::from_bytes(&bytes[..100]).is_err());
+    }
+}
@@ -0,0 +1,22 @@
+//! Read from and write to [PMTiles](https://github.com/protomaps/PMTiles) v3 archives of vector
+//! tiles.
+//!
+//! The synchronous [`PMTilesReader`] reads from any seekable source, such as a local file. With
+//! the `pmtiles_async` feature, [`PMTilesAsyncReader`] reads from and [`write_pmtiles_async`]
+//! writes to object storage.
+
+mod directory;
+mod header;
+mod reader;
+mod tile_id;
+mod writer;
+
+pub use directory::{Directory, DirectoryEntry};
+pub use header::{Compression, PMTilesHeader, TileType, HEADER_SIZE};
+#[cfg(feature = "pmtiles_async")]
+pub use reader::PMTilesAsyncReader;
+pub use reader::PMTilesReader;
+pub use tile_id::{tile_coord, tile_id};
+#[cfg(feature = "pmtiles_async")]
+pub use writer::write_pmtiles_async;
+pub use writer::{write_pmtiles, PMTilesWriterOptions};
@@ -0,0 +1,135 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use object_store::path::Path;
+use object_store::ObjectStore;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::mvt::{MvtReaderOptions, TileCoord};
+use crate::io::pmtiles::directory::{Directory, DirectoryEntry};
+use crate::io::pmtiles::header::{PMTilesHeader, HEADER_SIZE};
+use crate::io::pmtiles::reader::common::{
+    archive_range, decode_mvt_tile, parse_directory, parse_metadata, MAX_DIRECTORY_DEPTH,
+};
+use crate::io::pmtiles::tile_id::tile_id;
+use crate::table::Table;
+
+/// A reader for PMTiles v3 archives in object storage.
+///
+/// Each directory and tile is fetched with a range request.
+pub struct PMTilesAsyncReader {
+    store: Arc<dyn ObjectStore>,
+    location: Path,
+    size: u64,
+    header: PMTilesHeader,
+    root_directory: Directory,
+}
+
+async fn read_range(store: &dyn ObjectStore, location: &Path, range: Range<u64>) -> Result<Bytes> {
+    let range = range.start as usize..range.end as usize;
+    Ok(store.get_range(location, range).await?)
+}
+
+impl PMTilesAsyncReader {
+    /// Open an archive, reading its header and root directory.
+    pub async fn try_new(store: Arc<dyn ObjectStore>, location: Path) -> Result<Self> {
+        let size = store.head(&location).await?.size as u64;
+        let header_range = archive_range(0, 0, HEADER_SIZE as u64, size)?;
+        let header_bytes = read_range(store.as_ref(), &location, header_range).await?;
+        let header = PMTilesHeader::from_bytes(&header_bytes)?;
+        let root_range = archive_range(
+            header.root_directory_offset,
+            0,
+            header.root_directory_length,
+            size,
+        )?;
+        let root_bytes = read_range(store.as_ref(), &location, root_range).await?;
+        let root_directory = parse_directory(&root_bytes, &header)?;
+        Ok(Self {
+            store,
+            location,
+            size,
+            header,
+            root_directory,
+        })
+    }
+
+    /// The header of this archive.
+    pub fn header(&self) -> &PMTilesHeader {
+        &self.header
+    }
+
+    /// The root directory of this archive.
+    pub fn root_directory(&self) -> &Directory {
+        &self.root_directory
+    }
+
+    /// Read the JSON metadata of this archive.
+    pub async fn metadata(&self) -> Result<serde_json::Value> {
+        let range = archive_range(
+            self.header.metadata_offset,
+            0,
+            self.header.metadata_length,
+            self.size,
+        )?;
+        let bytes = read_range(self.store.as_ref(), &self.location, range).await?;
+        parse_metadata(&bytes, &self.header)
+    }
+
+    /// Read the leaf directory that a directory entry points to.
+    pub async fn read_leaf_directory(&self, entry: &DirectoryEntry) -> Result<Directory> {
+        let range = archive_range(
+            self.header.leaf_directories_offset,
+            entry.offset,
+            entry.length as u64,
+            self.size,
+        )?;
+        let bytes = read_range(self.store.as_ref(), &self.location, range).await?;
+        parse_directory(&bytes, &self.header)
+    }
+
+    /// Read the decompressed contents of a tile, or `None` if the archive has no such tile.
+    pub async fn get_tile(&self, tile: TileCoord) -> Result<Option<Vec<u8>>> {
+        let tile_id = tile_id(tile);
+        let mut entry = self.root_directory.find_tile(tile_id).copied();
+        for _ in 0..MAX_DIRECTORY_DEPTH {
+            let Some(current) = entry else {
+                return Ok(None);
+            };
+            if !current.is_leaf() {
+                let range = archive_range(
+                    self.header.tile_data_offset,
+                    current.offset,
+                    current.length as u64,
+                    self.size,
+                )?;
+                let data = read_range(self.store.as_ref(), &self.location, range).await?;
+                return Ok(Some(self.header.tile_compression.decompress(&data)?));
+            }
+            entry = self
+                .read_leaf_directory(&current)
+                .await?
+                .find_tile(tile_id)
+                .copied();
+        }
+        Err(GeoArrowError::General(
+            "PMTiles directories are nested too deeply".to_string(),
+        ))
+    }
+
+    /// Read one layer of a vector tile to a Table, or `None` if the archive has no such tile.
+    ///
+    /// See [`read_mvt`][crate::io::mvt::read_mvt] for details.
+    pub async fn read_mvt(
+        &self,
+        tile: TileCoord,
+        layer_name: &str,
+        options: MvtReaderOptions,
+    ) -> Result<Option<Table>> {
+        self.get_tile(tile)
+            .await?
+            .map(|data| decode_mvt_tile(&self.header, &data, tile, layer_name, options))
+            .transpose()
+    }
+}
@@ -0,0 +1,57 @@
+use std::ops::Range;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::mvt::{read_mvt, MvtReaderOptions, TileCoord};
+use crate::io::pmtiles::directory::Directory;
+use crate::io::pmtiles::header::{PMTilesHeader, TileType};
+use crate::table::Table;
+
+/// The maximum number of directories visited to find a tile: the root directory and up to three
+/// levels of leaf directories.
+pub(super) const MAX_DIRECTORY_DEPTH: usize = 4;
+
+/// The bytes `offset..offset + length` of the section starting at `section_start`, checked
+/// against the `size` of the archive so that a corrupt header or directory can't request more
+/// than the archive holds.
+pub(super) fn archive_range(
+    section_start: u64,
+    offset: u64,
+    length: u64,
+    size: u64,
+) -> Result<Range<u64>> {
+    let start = section_start.checked_add(offset);
+    match start.and_then(|start| Some(start..start.checked_add(length)?)) {
+        Some(range) if range.end <= size => Ok(range),
+        _ => Err(GeoArrowError::General(format!(
+            "PMTiles range of {length} bytes at offset {offset} from {section_start} is beyond \
+             the end of the archive ({size} bytes)"
+        ))),
+    }
+}
+
+pub(super) fn parse_directory(bytes: &[u8], header: &PMTilesHeader) -> Result<Directory> {
+    Directory::from_bytes(&header.internal_compression.decompress(bytes)?)
+}
+
+pub(super) fn parse_metadata(bytes: &[u8], header: &PMTilesHeader) -> Result<serde_json::Value> {
+    if bytes.is_empty() {
+        return Ok(serde_json::Value::Object(Default::default()));
+    }
+    serde_json::from_slice(&header.internal_compression.decompress(bytes)?)
+        .map_err(|err| GeoArrowError::General(format!("Invalid PMTiles metadata: {err}")))
+}
+
+pub(super) fn decode_mvt_tile(
+    header: &PMTilesHeader,
+    data: &[u8],
+    tile: TileCoord,
+    layer_name: &str,
+    options: MvtReaderOptions,
+) -> Result<Table> {
+    if header.tile_type != TileType::Mvt {
+        return Err(GeoArrowError::IncorrectType(
+            format!("Expected MVT tiles, got {:?}", header.tile_type).into(),
+        ));
+    }
+    read_mvt(data, layer_name, tile, options)
+}
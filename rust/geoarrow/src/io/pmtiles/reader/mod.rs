@@ -0,0 +1,8 @@
+#[cfg(feature = "pmtiles_async")]
+mod r#async;
+mod common;
+mod sync;
+
+#[cfg(feature = "pmtiles_async")]
+pub use r#async::PMTilesAsyncReader;
+pub use sync::PMTilesReader;
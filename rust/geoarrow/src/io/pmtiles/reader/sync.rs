@@ -0,0 +1,132 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+
+use crate::error::{GeoArrowError, Result};
+use crate::io::mvt::{MvtReaderOptions, TileCoord};
+use crate::io::pmtiles::directory::{Directory, DirectoryEntry};
+use crate::io::pmtiles::header::{PMTilesHeader, HEADER_SIZE};
+use crate::io::pmtiles::reader::common::{
+    archive_range, decode_mvt_tile, parse_directory, parse_metadata, MAX_DIRECTORY_DEPTH,
+};
+use crate::io::pmtiles::tile_id::tile_id;
+use crate::table::Table;
+
+fn read_range<R: Read + Seek>(reader: &mut R, range: Range<u64>) -> Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(range.start))?;
+    let mut buffer = vec![0; (range.end - range.start) as usize];
+    reader.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// A reader for PMTiles v3 archives from a local file or other seekable source.
+pub struct PMTilesReader<R> {
+    reader: R,
+    size: u64,
+    header: PMTilesHeader,
+    root_directory: Directory,
+}
+
+impl<R: Read + Seek> PMTilesReader<R> {
+    /// Open an archive, reading its header and root directory.
+    pub fn try_new(mut reader: R) -> Result<Self> {
+        let size = reader.seek(SeekFrom::End(0))?;
+        let header = PMTilesHeader::from_bytes(&read_range(
+            &mut reader,
+            archive_range(0, 0, HEADER_SIZE as u64, size)?,
+        )?)?;
+        let root_directory = parse_directory(
+            &read_range(
+                &mut reader,
+                archive_range(
+                    header.root_directory_offset,
+                    0,
+                    header.root_directory_length,
+                    size,
+                )?,
+            )?,
+            &header,
+        )?;
+        Ok(Self {
+            reader,
+            size,
+            header,
+            root_directory,
+        })
+    }
+
+    /// The header of this archive.
+    pub fn header(&self) -> &PMTilesHeader {
+        &self.header
+    }
+
+    /// The root directory of this archive.
+    pub fn root_directory(&self) -> &Directory {
+        &self.root_directory
+    }
+
+    /// Read the JSON metadata of this archive.
+    pub fn metadata(&mut self) -> Result<serde_json::Value> {
+        let range = archive_range(
+            self.header.metadata_offset,
+            0,
+            self.header.metadata_length,
+            self.size,
+        )?;
+        let bytes = read_range(&mut self.reader, range)?;
+        parse_metadata(&bytes, &self.header)
+    }
+
+    /// Read the leaf directory that a directory entry points to.
+    pub fn read_leaf_directory(&mut self, entry: &DirectoryEntry) -> Result<Directory> {
+        let range = archive_range(
+            self.header.leaf_directories_offset,
+            entry.offset,
+            entry.length as u64,
+            self.size,
+        )?;
+        let bytes = read_range(&mut self.reader, range)?;
+        parse_directory(&bytes, &self.header)
+    }
+
+    /// Read the decompressed contents of a tile, or `None` if the archive has no such tile.
+    pub fn get_tile(&mut self, tile: TileCoord) -> Result<Option<Vec<u8>>> {
+        let tile_id = tile_id(tile);
+        let mut entry = self.root_directory.find_tile(tile_id).copied();
+        for _ in 0..MAX_DIRECTORY_DEPTH {
+            let Some(current) = entry else {
+                return Ok(None);
+            };
+            if !current.is_leaf() {
+                let range = archive_range(
+                    self.header.tile_data_offset,
+                    current.offset,
+                    current.length as u64,
+                    self.size,
+                )?;
+                let data = read_range(&mut self.reader, range)?;
+                return Ok(Some(self.header.tile_compression.decompress(&data)?));
+            }
+            entry = self
+                .read_leaf_directory(&current)?
+                .find_tile(tile_id)
+                .copied();
+        }
+        Err(GeoArrowError::General(
+            "PMTiles directories are nested too deeply".to_string(),
+        ))
+    }
+
+    /// Read one layer of a vector tile to a Table, or `None` if the archive has no such tile.
+    ///
+    /// See [`read_mvt`][crate::io::mvt::read_mvt] for details.
+    pub fn read_mvt(
+        &mut self,
+        tile: TileCoord,
+        layer_name: &str,
+        options: MvtReaderOptions,
+    ) -> Result<Option<Table>> {
+        self.get_tile(tile)?
+            .map(|data| decode_mvt_tile(&self.header, &data, tile, layer_name, options))
+            .transpose()
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::{GeoArrowError, Result};
+use crate::io::mvt::TileCoord;
+
+/// The maximum zoom level that can be addressed by a PMTiles tile ID.
+const MAX_ZOOM: u8 = 31;
+
+fn rotate(n: u64, x: &mut u64, y: &mut u64, rx: u64, ry: u64) {
+    if ry == 0 {
+        if rx == 1 {
+            *x = n.wrapping_sub(1).wrapping_sub(*x);
+            *y = n.wrapping_sub(1).wrapping_sub(*y);
+        }
+        std::mem::swap(x, y);
+    }
+}
+
+/// The number of tiles in all zoom levels below `z`.
+fn tiles_below_zoom(z: u8) -> u64 {
+    ((1u64 << (2 * z as u32)) - 1) / 3
+}
+
+/// Convert a tile address to its PMTiles tile ID.
+///
+/// Tile IDs order tiles by zoom level, and within a zoom level along a Hilbert curve.
+pub fn tile_id(tile: TileCoord) -> u64 {
+    let (mut x, mut y) = (tile.x as u64, tile.y as u64);
+    let mut d = 0;
+    let mut s = (1u64 << tile.z) / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        rotate(s, &mut x, &mut y, rx, ry);
+        s /= 2;
+    }
+    tiles_below_zoom(tile.z) + d
+}
+
+/// Convert a PMTiles tile ID to its tile address.
+pub fn tile_coord(tile_id: u64) -> Result<TileCoord> {
+    let mut acc = 0;
+    for z in 0..=MAX_ZOOM {
+        let num_tiles = 1u64 << (2 * z as u32);
+        if acc + num_tiles > tile_id {
+            let mut t = tile_id - acc;
+            let (mut x, mut y) = (0, 0);
+            let mut s = 1;
+            while s < 1u64 << z {
+                let rx = 1 & (t / 2);
+                let ry = 1 & (t ^ rx);
+                rotate(s, &mut x, &mut y, rx, ry);
+                x += s * rx;
+                y += s * ry;
+                t /= 4;
+                s *= 2;
+            }
+            return TileCoord::try_new(z, x as u32, y as u32);
+        }
+        acc += num_tiles;
+    }
+    Err(GeoArrowError::General(format!(
+        "Tile ID {tile_id} is out of range"
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tile_id_round_trip() {
+        let cases = [
+            ((0, 0, 0), 0),
+            ((1, 0, 0), 1),
+            ((1, 0, 1), 2),
+            ((1, 1, 1), 3),
+            ((1, 1, 0), 4),
+            ((2, 0, 0), 5),
+            ((12, 3423, 1763), 19078479),
+            ((20, 1000, 2000), 366507023509),
+        ];
+        for ((z, x, y), expected) in cases {
+            let tile = TileCoord::try_new(z, x, y).unwrap();
+            assert_eq!(tile_id(tile), expected);
+            assert_eq!(tile_coord(expected).unwrap(), tile);
+        }
+    }
+}
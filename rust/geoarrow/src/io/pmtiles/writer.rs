@@ -0,0 +1,454 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+
+use arrow::compute::take_record_batch;
+use arrow_array::UInt32Array;
+use arrow_schema::DataType;
+use geo::{coord, Coord, Rect};
+use serde_json::json;
+
+use crate::algorithm::geo::BoundingRect;
+use crate::error::{GeoArrowError, Result};
+use crate::io::mvt::{
+    lon_lat_to_web_mercator, web_mercator_to_lon_lat, MvtCoordinates, MvtTileWriter,
+    MvtWriterOptions, TileCoord, WEB_MERCATOR_EXTENT,
+};
+use crate::io::pmtiles::directory::{Directory, DirectoryEntry};
+use crate::io::pmtiles::header::{Compression, PMTilesHeader, TileType, HEADER_SIZE};
+use crate::io::pmtiles::tile_id::tile_id;
+use crate::schema::GeoSchemaExt;
+use crate::table::Table;
+use crate::trait_::ArrayAccessor;
+
+/// The root directory must fit in the first 16 KiB of the archive together with the header.
+const MAX_ROOT_DIRECTORY_SIZE: usize = 16384 - HEADER_SIZE;
+
+/// Options for writing PMTiles archives.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PMTilesWriterOptions {
+    /// The lowest zoom level to write tiles for. Defaults to 0.
+    pub min_zoom: u8,
+
+    /// The highest zoom level to write tiles for. Defaults to 10.
+    pub max_zoom: u8,
+
+    /// The name of the vector tile layer holding the features. Defaults to `"layer"`.
+    pub layer_name: String,
+
+    /// Options for encoding each tile. The coordinates must be longitude/latitude or Web
+    /// Mercator.
+    pub mvt_options: MvtWriterOptions,
+
+    /// The compression of directories and metadata. Defaults to gzip.
+    pub internal_compression: Compression,
+
+    /// The compression of each tile. Defaults to gzip.
+    pub tile_compression: Compression,
+}
+
+impl Default for PMTilesWriterOptions {
+    fn default() -> Self {
+        Self {
+            min_zoom: 0,
+            max_zoom: 10,
+            layer_name: "layer".to_string(),
+            mvt_options: Default::default(),
+            internal_compression: Compression::Gzip,
+            tile_compression: Compression::Gzip,
+        }
+    }
+}
+
+/// Write a Table to a PMTiles v3 archive of vector tiles.
+///
+/// Every tile between `min_zoom` and `max_zoom` that intersects a feature is encoded as a single
+/// layer vector tile. Tiles are written in tile ID order (the archive is clustered) and tiles
+/// with identical contents, like those in the interior of a large polygon, are stored once.
+///
+/// The archive is assembled in memory before being written.
+pub fn write_pmtiles<W: Write>(
+    table: &Table,
+    mut writer: W,
+    options: &PMTilesWriterOptions,
+) -> Result<()> {
+    writer.write_all(&build_archive(table, options)?)?;
+    Ok(())
+}
+
+/// Write a Table to a PMTiles v3 archive of vector tiles in object storage.
+///
+/// See [`write_pmtiles`] for details.
+#[cfg(feature = "pmtiles_async")]
+pub async fn write_pmtiles_async(
+    table: &Table,
+    store: std::sync::Arc<dyn object_store::ObjectStore>,
+    location: object_store::path::Path,
+    options: &PMTilesWriterOptions,
+) -> Result<()> {
+    let archive = build_archive(table, options)?;
+    store.put(&location, archive.into()).await?;
+    Ok(())
+}
+
+/// The tiles at zoom level `z` that a Web Mercator rectangle (expanded by `margin`, a fraction
+/// of the tile width) touches.
+fn tiles_covering(rect: &Rect, z: u8, margin: f64) -> impl Iterator<Item = TileCoord> {
+    let num_tiles = 1u64 << z;
+    let tile_size = 2. * WEB_MERCATOR_EXTENT / num_tiles as f64;
+    let margin = margin * tile_size;
+    let to_index = |value: f64| {
+        (value / tile_size)
+            .floor()
+            .clamp(0., (num_tiles - 1) as f64) as u32
+    };
+    let min_x = to_index(rect.min().x - margin + WEB_MERCATOR_EXTENT);
+    let max_x = to_index(rect.max().x + margin + WEB_MERCATOR_EXTENT);
+    let min_y = to_index(WEB_MERCATOR_EXTENT - rect.max().y - margin);
+    let max_y = to_index(WEB_MERCATOR_EXTENT - rect.min().y + margin);
+    (min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| TileCoord { z, x, y }))
+}
+
+/// Accumulates tile entries and deduplicated tile data in tile ID order.
+#[derive(Default)]
+struct TileDataBuilder {
+    entries: Vec<DirectoryEntry>,
+    data: Vec<u8>,
+    offsets: HashMap<Vec<u8>, u64>,
+    num_addressed_tiles: u64,
+}
+
+impl TileDataBuilder {
+    fn push(&mut self, tile_id: u64, tile: Vec<u8>) {
+        self.num_addressed_tiles += 1;
+        let length = tile.len() as u32;
+        let offset = match self.offsets.get(&tile) {
+            Some(offset) => *offset,
+            None => {
+                let offset = self.data.len() as u64;
+                self.data.extend_from_slice(&tile);
+                self.offsets.insert(tile, offset);
+                offset
+            }
+        };
+
+        if let Some(last) = self.entries.last_mut() {
+            if last.offset == offset && last.tile_id + last.run_length as u64 == tile_id {
+                last.run_length += 1;
+                return;
+            }
+        }
+        self.entries.push(DirectoryEntry {
+            tile_id,
+            offset,
+            length,
+            run_length: 1,
+        });
+    }
+}
+
+/// Serialize the root directory, splitting entries into leaf directories if the root would not
+/// fit in the space reserved for it.
+fn build_directories(
+    entries: &[DirectoryEntry],
+    compression: Compression,
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    let root = compression.compress(&Directory::new(entries.to_vec()).to_bytes())?;
+    if root.len() <= MAX_ROOT_DIRECTORY_SIZE {
+        return Ok((root, vec![]));
+    }
+
+    let mut leaf_size = 4096;
+    loop {
+        let mut root_entries = vec![];
+        let mut leaves = vec![];
+        for chunk in entries.chunks(leaf_size) {
+            let leaf = compression.compress(&Directory::new(chunk.to_vec()).to_bytes())?;
+            root_entries.push(DirectoryEntry {
+                tile_id: chunk[0].tile_id,
+                offset: leaves.len() as u64,
+                length: leaf.len() as u32,
+                run_length: 0,
+            });
+            leaves.extend(leaf);
+        }
+        let root = compression.compress(&Directory::new(root_entries).to_bytes())?;
+        if root.len() <= MAX_ROOT_DIRECTORY_SIZE {
+            return Ok((root, leaves));
+        }
+        leaf_size *= 2;
+    }
+}
+
+fn build_metadata(table: &Table, options: &PMTilesWriterOptions) -> serde_json::Value {
+    let geometry_columns = table.schema().as_ref().geometry_columns();
+    let fields = table
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !geometry_columns.contains(idx))
+        .map(|(_, field)| {
+            let field_type = match field.data_type() {
+                data_type if data_type.is_numeric() => "Number",
+                DataType::Boolean => "Boolean",
+                _ => "String",
+            };
+            (field.name().clone(), json!(field_type))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    json!({
+        "vector_layers": [{
+            "id": options.layer_name,
+            "fields": fields,
+            "minzoom": options.min_zoom,
+            "maxzoom": options.max_zoom,
+        }]
+    })
+}
+
+fn build_archive(table: &Table, options: &PMTilesWriterOptions) -> Result<Vec<u8>> {
+    if options.min_zoom > options.max_zoom {
+        return Err(GeoArrowError::General(
+            "min_zoom must not be greater than max_zoom".to_string(),
+        ));
+    }
+    TileCoord::try_new(options.max_zoom, 0, 0)?;
+    let to_web_mercator: fn(Coord) -> Coord = match options.mvt_options.coordinates {
+        MvtCoordinates::LonLat => lon_lat_to_web_mercator,
+        MvtCoordinates::WebMercator => |coord| coord,
+        MvtCoordinates::Tile => {
+            return Err(GeoArrowError::General(
+                "PMTiles input must be in longitude/latitude or Web Mercator coordinates"
+                    .to_string(),
+            ))
+        }
+    };
+
+    // The Web Mercator bounds of each row, per batch
+    let bounds = table.geometry_column(None)?.as_ref().bounding_rect()?;
+    let row_bounds = bounds
+        .chunks()
+        .iter()
+        .map(|chunk| {
+            chunk
+                .iter_geo()
+                .map(|rect| {
+                    rect.map(|rect| {
+                        Rect::new(to_web_mercator(rect.min()), to_web_mercator(rect.max()))
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let total_bounds = row_bounds
+        .iter()
+        .flatten()
+        .flatten()
+        .copied()
+        .reduce(|a, b| {
+            Rect::new(
+                coord! { x: a.min().x.min(b.min().x), y: a.min().y.min(b.min().y) },
+                coord! { x: a.max().x.max(b.max().x), y: a.max().y.max(b.max().y) },
+            )
+        });
+
+    let margin = options.mvt_options.buffer as f64 / options.mvt_options.extent as f64;
+    let mut tiles = TileDataBuilder::default();
+    for z in options.min_zoom..=options.max_zoom {
+        // The rows touching each tile, per batch. Tile IDs increase with zoom level, so visiting
+        // each zoom level in turn keeps the tiles in tile ID order.
+        let mut rows_by_tile: BTreeMap<u64, (TileCoord, Vec<Vec<u32>>)> = BTreeMap::new();
+        for (batch_idx, batch_bounds) in row_bounds.iter().enumerate() {
+            for (row_idx, rect) in batch_bounds.iter().enumerate() {
+                let Some(rect) = rect else {
+                    continue;
+                };
+                for tile in tiles_covering(rect, z, margin) {
+                    let (_, rows) = rows_by_tile
+                        .entry(tile_id(tile))
+                        .or_insert_with(|| (tile, vec![vec![]; row_bounds.len()]));
+                    rows[batch_idx].push(row_idx as u32);
+                }
+            }
+        }
+
+        for (id, (tile, rows)) in rows_by_tile {
+            let batches = table
+                .batches()
+                .iter()
+                .zip(rows)
+                .filter(|(_, rows)| !rows.is_empty())
+                .map(|(batch, rows)| take_record_batch(batch, &UInt32Array::from(rows)))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let tile_table = Table::try_new(batches, table.schema().clone())?;
+
+            let mut tile_writer = MvtTileWriter::new(tile, options.mvt_options.clone());
+            tile_writer.add_layer(&options.layer_name, &tile_table)?;
+            if tile_writer.is_empty() {
+                continue;
+            }
+            let mut tile_data = vec![];
+            tile_writer.finish(&mut tile_data)?;
+            tiles.push(id, options.tile_compression.compress(&tile_data)?);
+        }
+    }
+
+    let (root_directory, leaf_directories) =
+        build_directories(&tiles.entries, options.internal_compression)?;
+    let metadata = options
+        .internal_compression
+        .compress(&serde_json::to_vec(&build_metadata(table, options)).unwrap())?;
+
+    let lon_lat_bounds = total_bounds
+        .map(|rect| {
+            Rect::new(
+                web_mercator_to_lon_lat(rect.min()),
+                web_mercator_to_lon_lat(rect.max()),
+            )
+        })
+        .unwrap_or_else(|| Rect::new((-180., -85.), (180., 85.)));
+    let root_directory_offset = HEADER_SIZE as u64;
+    let metadata_offset = root_directory_offset + root_directory.len() as u64;
+    let leaf_directories_offset = metadata_offset + metadata.len() as u64;
+    let tile_data_offset = leaf_directories_offset + leaf_directories.len() as u64;
+    let header = PMTilesHeader {
+        root_directory_offset,
+        root_directory_length: root_directory.len() as u64,
+        metadata_offset,
+        metadata_length: metadata.len() as u64,
+        leaf_directories_offset,
+        leaf_directories_length: leaf_directories.len() as u64,
+        tile_data_offset,
+        tile_data_length: tiles.data.len() as u64,
+        num_addressed_tiles: tiles.num_addressed_tiles,
+        num_tile_entries: tiles.entries.len() as u64,
+        num_tile_contents: tiles.offsets.len() as u64,
+        clustered: true,
+        internal_compression: options.internal_compression,
+        tile_compression: options.tile_compression,
+        tile_type: TileType::Mvt,
+        min_zoom: options.min_zoom,
+        max_zoom: options.max_zoom,
+        min_longitude: lon_lat_bounds.min().x,
+        min_latitude: lon_lat_bounds.min().y,
+        max_longitude: lon_lat_bounds.max().x,
+        max_latitude: lon_lat_bounds.max().y,
+        center_zoom: options.min_zoom,
+        center_longitude: lon_lat_bounds.center().x,
+        center_latitude: lon_lat_bounds.center().y,
+    };
+
+    let mut archive = header.to_bytes();
+    archive.extend(root_directory);
+    archive.extend(metadata);
+    archive.extend(leaf_directories);
+    archive.extend(tiles.data);
+    Ok(archive)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::io::mvt::MvtReaderOptions;
+    use crate::io::pmtiles::header::HEADER_SIZE;
+    use crate::io::pmtiles::reader::PMTilesReader;
+    use crate::test::point;
+
+    #[test]
+    fn round_trip() {
+        let table = point::table();
+        let options = PMTilesWriterOptions {
+            max_zoom: 3,
+            ..Default::default()
+        };
+        let mut buffer = vec![];
+        write_pmtiles(&table, &mut buffer, &options).unwrap();
+
+        let mut reader = PMTilesReader::try_new(Cursor::new(buffer)).unwrap();
+        let header = reader.header().clone();
+        assert_eq!(header.tile_type, TileType::Mvt);
+        assert_eq!((header.min_zoom, header.max_zoom), (0, 3));
+        assert!(header.num_addressed_tiles >= 4);
+
+        let metadata = reader.metadata().unwrap();
+        assert_eq!(metadata["vector_layers"][0]["id"], "layer");
+        assert_eq!(metadata["vector_layers"][0]["fields"]["u8"], "Number");
+
+        let tile = TileCoord::from_lon_lat(coord! { x: 1., y: 2. }, 1).unwrap();
+        let decoded = reader
+            .read_mvt(tile, "layer", MvtReaderOptions::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded.len(), table.len());
+
+        let empty_tile = TileCoord::try_new(3, 0, 0).unwrap();
+        assert!(reader.get_tile(empty_tile).unwrap().is_none());
+    }
+
+    #[test]
+    fn truncated_archive() {
+        let mut buffer = vec![];
+        write_pmtiles(&point::table(), &mut buffer, &Default::default()).unwrap();
+        buffer.truncate(HEADER_SIZE);
+
+        let err = PMTilesReader::try_new(Cursor::new(buffer))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("beyond the end of the archive"), "{err}");
+    }
+
+    #[test]
+    fn leaf_directories() {
+        let entries = (0..100_000)
+            .map(|i| DirectoryEntry {
+                tile_id: i * 3,
+                offset: i * 1000,
+                length: 1000,
+                run_length: 1,
+            })
+            .collect::<Vec<_>>();
+        let (root, leaves) = build_directories(&entries, Compression::None).unwrap();
+        assert!(root.len() <= MAX_ROOT_DIRECTORY_SIZE);
+        assert!(!leaves.is_empty());
+
+        let root = Directory::from_bytes(&root).unwrap();
+        let leaf_entry = root.find_tile(30_000).unwrap();
+        assert!(leaf_entry.is_leaf());
+        let start = leaf_entry.offset as usize;
+        let leaf =
+            Directory::from_bytes(&leaves[start..start + leaf_entry.length as usize]).unwrap();
+        assert_eq!(leaf.find_tile(30_000).unwrap().offset, 10_000_000);
+    }
+
+    #[cfg(feature = "pmtiles_async")]
+    #[tokio::test]
+    async fn round_trip_object_store() {
+        use crate::io::pmtiles::reader::PMTilesAsyncReader;
+        use object_store::memory::InMemory;
+        use object_store::path::Path;
+        use std::sync::Arc;
+
+        let store = Arc::new(InMemory::new());
+        let location = Path::from("points.pmtiles");
+        let options = PMTilesWriterOptions {
+            max_zoom: 2,
+            ..Default::default()
+        };
+        write_pmtiles_async(&point::table(), store.clone(), location.clone(), &options)
+            .await
+            .unwrap();
+
+        let reader = PMTilesAsyncReader::try_new(store, location).await.unwrap();
+        let tile = TileCoord::from_lon_lat(coord! { x: 1., y: 2. }, 2).unwrap();
+        let decoded = reader
+            .read_mvt(tile, "layer", MvtReaderOptions::default())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded.len(), 3);
+    }
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::algorithm::native::cast::{small_offset_type, widen_offsets};
 use crate::algorithm::native::Downcast;
 use crate::array::*;
 use crate::chunked_array::*;
@@ -222,6 +223,16 @@ pub fn from_wkb<O: OffsetSizeTrait>(
                 MultiPolygonBuilder::from_wkb(&wkb_objects, dim, coord_type, arr.metadata())?;
             Ok(Arc::new(builder.finish()))
         }
+        Mixed(coord_type, dim) => {
+            let builder = MixedGeometryBuilder::from_wkb(
+                &wkb_objects,
+                dim,
+                coord_type,
+                arr.metadata(),
+                prefer_multi,
+            )?;
+            Ok(Arc::new(builder.finish()))
+        }
         GeometryCollection(coord_type, dim) => {
             let builder = GeometryCollectionBuilder::from_wkb(
                 &wkb_objects,
@@ -241,6 +252,17 @@ pub fn from_wkb<O: OffsetSizeTrait>(
                 GeometryBuilder::from_wkb(&wkb_objects, coord_type, arr.metadata(), prefer_multi)?;
             Ok(Arc::new(builder.finish()))
         }
+        LargeLineString(_, _)
+        | LargePolygon(_, _)
+        | LargeMultiPoint(_, _)
+        | LargeMultiLineString(_, _)
+        | LargeMultiPolygon(_, _)
+        | LargeMixed(_, _)
+        | LargeGeometryCollection(_, _)
+        | LargeGeometry(_) => {
+            let array = from_wkb(arr, small_offset_type(target_type), prefer_multi)?;
+            Ok(widen_offsets(array))
+        }
     }
 }
 
@@ -270,9 +292,18 @@ impl ToWKB for &dyn NativeArray {
             MultiPoint(_, _) => self.as_multi_point().into(),
             MultiLineString(_, _) => self.as_multi_line_string().into(),
             MultiPolygon(_, _) => self.as_multi_polygon().into(),
+            Mixed(_, _) => self.as_mixed().into(),
             GeometryCollection(_, _) => self.as_geometry_collection().into(),
             Rect(_) => self.as_rect().into(),
             Geometry(_) => self.as_geometry().into(),
+            LargeLineString(_, _) => self.as_large_line_string().into(),
+            LargePolygon(_, _) => self.as_large_polygon().into(),
+            LargeMultiPoint(_, _) => self.as_large_multi_point().into(),
+            LargeMultiLineString(_, _) => self.as_large_multi_line_string().into(),
+            LargeMultiPolygon(_, _) => self.as_large_multi_polygon().into(),
+            LargeMixed(_, _) => self.as_large_mixed().into(),
+            LargeGeometryCollection(_, _) => self.as_large_geometry_collection().into(),
+            LargeGeometry(_) => self.as_large_geometry().into(),
         }
     }
 }
@@ -298,11 +329,37 @@ impl ToWKB for &dyn ChunkedNativeArray {
             MultiPolygon(_, _) => {
                 ChunkedGeometryArray::new(self.as_multi_polygon().map(|chunk| chunk.into()))
             }
+            Mixed(_, _) => ChunkedGeometryArray::new(self.as_mixed().map(|chunk| chunk.into())),
             GeometryCollection(_, _) => {
                 ChunkedGeometryArray::new(self.as_geometry_collection().map(|chunk| chunk.into()))
             }
-            Rect(_) => todo!(),
+            Rect(_) => ChunkedGeometryArray::new(self.as_rect().map(|chunk| chunk.into())),
             Geometry(_) => ChunkedGeometryArray::new(self.as_geometry().map(|chunk| chunk.into())),
+            LargeLineString(_, _) => {
+                ChunkedGeometryArray::new(self.as_large_line_string().map(|chunk| chunk.into()))
+            }
+            LargePolygon(_, _) => {
+                ChunkedGeometryArray::new(self.as_large_polygon().map(|chunk| chunk.into()))
+            }
+            LargeMultiPoint(_, _) => {
+                ChunkedGeometryArray::new(self.as_large_multi_point().map(|chunk| chunk.into()))
+            }
+            LargeMultiLineString(_, _) => ChunkedGeometryArray::new(
+                self.as_large_multi_line_string().map(|chunk| chunk.into()),
+            ),
+            LargeMultiPolygon(_, _) => {
+                ChunkedGeometryArray::new(self.as_large_multi_polygon().map(|chunk| chunk.into()))
+            }
+            LargeMixed(_, _) => {
+                ChunkedGeometryArray::new(self.as_large_mixed().map(|chunk| chunk.into()))
+            }
+            LargeGeometryCollection(_, _) => ChunkedGeometryArray::new(
+                self.as_large_geometry_collection()
+                    .map(|chunk| chunk.into()),
+            ),
+            LargeGeometry(_) => {
+                ChunkedGeometryArray::new(self.as_large_geometry().map(|chunk| chunk.into()))
+            }
         }
     }
 }
@@ -318,9 +375,18 @@ pub fn to_wkb<O: OffsetSizeTrait>(arr: &dyn NativeArray) -> WKBArray<O> {
         MultiPoint(_, _) => arr.as_multi_point().into(),
         MultiLineString(_, _) => arr.as_multi_line_string().into(),
         MultiPolygon(_, _) => arr.as_multi_polygon().into(),
+        Mixed(_, _) => arr.as_mixed().into(),
         GeometryCollection(_, _) => arr.as_geometry_collection().into(),
         Rect(_) => arr.as_rect().into(),
         Geometry(_) => arr.as_geometry().into(),
+        LargeLineString(_, _) => arr.as_large_line_string().into(),
+        LargePolygon(_, _) => arr.as_large_polygon().into(),
+        LargeMultiPoint(_, _) => arr.as_large_multi_point().into(),
+        LargeMultiLineString(_, _) => arr.as_large_multi_line_string().into(),
+        LargeMultiPolygon(_, _) => arr.as_large_multi_polygon().into(),
+        LargeMixed(_, _) => arr.as_large_mixed().into(),
+        LargeGeometryCollection(_, _) => arr.as_large_geometry_collection().into(),
+        LargeGeometry(_) => arr.as_large_geometry().into(),
     }
 }
 
@@ -383,4 +449,46 @@ mod test {
         let rt_arr = rt_ref.as_point();
         assert_eq!(rt_arr, &arr);
     }
+
+    #[test]
+    fn point_m_round_trip() {
+        for arr in [point::point_m_array(), point::point_zm_array()] {
+            let dim = arr.dimension();
+            let wkb_arr: WKBArray<i32> = to_wkb(&arr);
+            let roundtrip_mixed = from_wkb(
+                &wkb_arr,
+                NativeType::Geometry(CoordType::Interleaved),
+                false,
+            )
+            .unwrap();
+            let rt_ref = roundtrip_mixed.as_ref();
+            assert!(rt_ref.as_geometry().has_points(dim));
+
+            let roundtrip_point = from_wkb(
+                &wkb_arr,
+                NativeType::Point(CoordType::Interleaved, dim),
+                false,
+            )
+            .unwrap();
+            let rt_ref = roundtrip_point.as_ref();
+            assert_eq!(rt_ref.as_point(), &arr);
+        }
+    }
+
+    #[test]
+    fn rect_as_polygon() {
+        let rect = geo::Rect::new((0., 5.), (10., 15.));
+        let geoms = vec![geo::Geometry::Rect(rect)];
+        let wkb_arr: WKBArray<i32> = geoms.as_slice().try_into().unwrap();
+        let roundtrip = from_wkb(
+            &wkb_arr,
+            NativeType::Polygon(CoordType::Interleaved, Dimension::XY),
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            roundtrip.as_ref().as_polygon().value_as_geo(0),
+            rect.to_polygon()
+        );
+    }
 }
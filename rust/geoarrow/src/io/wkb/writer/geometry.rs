@@ -4,13 +4,13 @@ use wkb::writer::{geometry_wkb_size, write_geometry};
 use wkb::Endianness;
 
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{GeometryArray, MixedGeometryArray, WKBArray};
+use crate::array::{GenericGeometryArray, GenericMixedGeometryArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use std::io::Cursor;
 
-impl<O: OffsetSizeTrait> From<&MixedGeometryArray> for WKBArray<O> {
-    fn from(value: &MixedGeometryArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericMixedGeometryArray<A>> for WKBArray<O> {
+    fn from(value: &GenericMixedGeometryArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -42,8 +42,8 @@ impl<O: OffsetSizeTrait> From<&MixedGeometryArray> for WKBArray<O> {
     }
 }
 
-impl<O: OffsetSizeTrait> From<&GeometryArray> for WKBArray<O> {
-    fn from(value: &GeometryArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericGeometryArray<A>> for WKBArray<O> {
+    fn from(value: &GenericGeometryArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -78,6 +78,7 @@ impl<O: OffsetSizeTrait> From<&GeometryArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::MixedGeometryArray;
     use crate::datatypes::Dimension;
     use crate::test::multilinestring::{ml0, ml1};
     use crate::test::point::{p0, p1};
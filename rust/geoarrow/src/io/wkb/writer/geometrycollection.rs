@@ -1,5 +1,5 @@
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{GeometryCollectionArray, WKBArray};
+use crate::array::{GenericGeometryCollectionArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
@@ -8,8 +8,10 @@ use std::io::Cursor;
 use wkb::writer::{geometry_collection_wkb_size, write_geometry_collection};
 use wkb::Endianness;
 
-impl<O: OffsetSizeTrait> From<&GeometryCollectionArray> for WKBArray<O> {
-    fn from(value: &GeometryCollectionArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericGeometryCollectionArray<A>>
+    for WKBArray<O>
+{
+    fn from(value: &GenericGeometryCollectionArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -46,6 +48,7 @@ impl<O: OffsetSizeTrait> From<&GeometryCollectionArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::GeometryCollectionArray;
     use crate::datatypes::Dimension;
     use crate::test::multipoint;
     use crate::test::multipolygon;
@@ -1,5 +1,5 @@
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{LineStringArray, WKBArray};
+use crate::array::{GenericLineStringArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
@@ -8,8 +8,8 @@ use std::io::Cursor;
 use wkb::writer::{line_string_wkb_size, write_line_string};
 use wkb::Endianness;
 
-impl<O: OffsetSizeTrait> From<&LineStringArray> for WKBArray<O> {
-    fn from(value: &LineStringArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericLineStringArray<A>> for WKBArray<O> {
+    fn from(value: &GenericLineStringArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -44,6 +44,7 @@ impl<O: OffsetSizeTrait> From<&LineStringArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::LineStringArray;
     use crate::datatypes::Dimension;
     use crate::test::linestring::{ls0, ls1};
 
@@ -1,5 +1,5 @@
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{MultiLineStringArray, WKBArray};
+use crate::array::{GenericMultiLineStringArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
@@ -8,8 +8,8 @@ use std::io::Cursor;
 use wkb::writer::{multi_line_string_wkb_size, write_multi_line_string};
 use wkb::Endianness;
 
-impl<O: OffsetSizeTrait> From<&MultiLineStringArray> for WKBArray<O> {
-    fn from(value: &MultiLineStringArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericMultiLineStringArray<A>> for WKBArray<O> {
+    fn from(value: &GenericMultiLineStringArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -46,6 +46,7 @@ impl<O: OffsetSizeTrait> From<&MultiLineStringArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::MultiLineStringArray;
     use crate::datatypes::Dimension;
     use crate::test::multilinestring::{ml0, ml1};
 
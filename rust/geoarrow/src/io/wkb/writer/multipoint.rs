@@ -1,5 +1,5 @@
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{MultiPointArray, WKBArray};
+use crate::array::{GenericMultiPointArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
@@ -8,8 +8,8 @@ use std::io::Cursor;
 use wkb::writer::{multi_point_wkb_size, write_multi_point};
 use wkb::Endianness;
 
-impl<O: OffsetSizeTrait> From<&MultiPointArray> for WKBArray<O> {
-    fn from(value: &MultiPointArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericMultiPointArray<A>> for WKBArray<O> {
+    fn from(value: &GenericMultiPointArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -44,6 +44,7 @@ impl<O: OffsetSizeTrait> From<&MultiPointArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::MultiPointArray;
     use crate::datatypes::Dimension;
     use crate::test::multipoint::{mp0, mp1};
 
@@ -1,5 +1,5 @@
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{MultiPolygonArray, WKBArray};
+use crate::array::{GenericMultiPolygonArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
@@ -8,8 +8,8 @@ use std::io::Cursor;
 use wkb::writer::{multi_polygon_wkb_size, write_multi_polygon};
 use wkb::Endianness;
 
-impl<O: OffsetSizeTrait> From<&MultiPolygonArray> for WKBArray<O> {
-    fn from(value: &MultiPolygonArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericMultiPolygonArray<A>> for WKBArray<O> {
+    fn from(value: &GenericMultiPolygonArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -46,6 +46,7 @@ impl<O: OffsetSizeTrait> From<&MultiPolygonArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::MultiPolygonArray;
     use crate::datatypes::Dimension;
     use crate::test::multipolygon::{mp0, mp1};
 
@@ -1,5 +1,5 @@
 use crate::array::offset_builder::OffsetsBuilder;
-use crate::array::{PolygonArray, WKBArray};
+use crate::array::{GenericPolygonArray, WKBArray};
 use crate::trait_::ArrayAccessor;
 use crate::ArrayBase;
 use arrow_array::{GenericBinaryArray, OffsetSizeTrait};
@@ -8,8 +8,8 @@ use std::io::Cursor;
 use wkb::writer::{polygon_wkb_size, write_polygon};
 use wkb::Endianness;
 
-impl<O: OffsetSizeTrait> From<&PolygonArray> for WKBArray<O> {
-    fn from(value: &PolygonArray) -> Self {
+impl<A: OffsetSizeTrait, O: OffsetSizeTrait> From<&GenericPolygonArray<A>> for WKBArray<O> {
+    fn from(value: &GenericPolygonArray<A>) -> Self {
         let mut offsets: OffsetsBuilder<O> = OffsetsBuilder::with_capacity(value.len());
 
         // First pass: calculate binary array offsets
@@ -44,6 +44,7 @@ impl<O: OffsetSizeTrait> From<&PolygonArray> for WKBArray<O> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::array::PolygonArray;
     use crate::datatypes::Dimension;
     use crate::test::polygon::{p0, p1};
     use crate::trait_::ArrayAccessor;
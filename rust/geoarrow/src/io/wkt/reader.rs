@@ -42,7 +42,10 @@ fn from_str_iter<'a>(
 
 #[cfg(test)]
 mod test {
+    use crate::algorithm::native::Downcast;
     use crate::array::AsNativeArray;
+    use crate::io::wkt::ToWKT;
+    use crate::test::point;
     use crate::trait_::ArrayAccessor;
     use arrow_array::builder::StringBuilder;
 
@@ -72,6 +75,19 @@ mod test {
         // assert_eq!(geo_point.y(), 10.0);
     }
 
+    #[test]
+    fn point_m_round_trip() {
+        for arr in [point::point_m_array(), point::point_zm_array()] {
+            let wkt_arr = (&arr as &dyn NativeArray).to_wkt::<i32>().unwrap();
+            let parsed = read_wkt(&wkt_arr, Default::default(), false).unwrap();
+            let parsed_ref = parsed.as_ref();
+            assert!(parsed_ref.as_geometry().has_points(arr.dimension()));
+
+            let downcasted = parsed_ref.as_geometry().downcast().unwrap();
+            assert_eq!(downcasted.as_ref().as_point(), &arr);
+        }
+    }
+
     // #[test]
     // fn test_read_wkt_downcast_from_multi() {
     //     let wkt_geoms = ["POINT (30 10)", "POINT (20 5)", "POINT (3 10)"];
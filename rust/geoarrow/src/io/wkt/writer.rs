@@ -47,17 +47,30 @@ impl ToWKT for &dyn NativeArray {
         match self.data_type() {
             Point(_, _) => impl_to_wkt!(as_point, write_point),
             LineString(_, _) => impl_to_wkt!(as_line_string, write_linestring),
+            LargeLineString(_, _) => impl_to_wkt!(as_large_line_string, write_linestring),
             Polygon(_, _) => impl_to_wkt!(as_polygon, write_polygon),
+            LargePolygon(_, _) => impl_to_wkt!(as_large_polygon, write_polygon),
             MultiPoint(_, _) => impl_to_wkt!(as_multi_point, write_multi_point),
+            LargeMultiPoint(_, _) => impl_to_wkt!(as_large_multi_point, write_multi_point),
             MultiLineString(_, _) => {
                 impl_to_wkt!(as_multi_line_string, write_multi_linestring)
             }
+            LargeMultiLineString(_, _) => {
+                impl_to_wkt!(as_large_multi_line_string, write_multi_linestring)
+            }
             MultiPolygon(_, _) => impl_to_wkt!(as_multi_polygon, write_multi_polygon),
+            LargeMultiPolygon(_, _) => impl_to_wkt!(as_large_multi_polygon, write_multi_polygon),
+            Mixed(_, _) => impl_to_wkt!(as_mixed, write_geometry),
+            LargeMixed(_, _) => impl_to_wkt!(as_large_mixed, write_geometry),
             GeometryCollection(_, _) => {
                 impl_to_wkt!(as_geometry_collection, write_geometry_collection)
             }
+            LargeGeometryCollection(_, _) => {
+                impl_to_wkt!(as_large_geometry_collection, write_geometry_collection)
+            }
             Rect(_) => impl_to_wkt!(as_rect, write_rect),
             Geometry(_) => impl_to_wkt!(as_geometry, write_geometry),
+            LargeGeometry(_) => impl_to_wkt!(as_large_geometry, write_geometry),
         }
 
         Ok(WKTArray::new(output_array.finish(), metadata))
@@ -80,13 +93,22 @@ impl ToWKT for &dyn ChunkedNativeArray {
         match self.data_type() {
             Point(_, _) => impl_to_wkt!(as_point),
             LineString(_, _) => impl_to_wkt!(as_line_string),
+            LargeLineString(_, _) => impl_to_wkt!(as_large_line_string),
             Polygon(_, _) => impl_to_wkt!(as_polygon),
+            LargePolygon(_, _) => impl_to_wkt!(as_large_polygon),
             MultiPoint(_, _) => impl_to_wkt!(as_multi_point),
+            LargeMultiPoint(_, _) => impl_to_wkt!(as_large_multi_point),
             MultiLineString(_, _) => impl_to_wkt!(as_multi_line_string),
+            LargeMultiLineString(_, _) => impl_to_wkt!(as_large_multi_line_string),
             MultiPolygon(_, _) => impl_to_wkt!(as_multi_polygon),
+            LargeMultiPolygon(_, _) => impl_to_wkt!(as_large_multi_polygon),
+            Mixed(_, _) => impl_to_wkt!(as_mixed),
+            LargeMixed(_, _) => impl_to_wkt!(as_large_mixed),
             GeometryCollection(_, _) => impl_to_wkt!(as_geometry_collection),
+            LargeGeometryCollection(_, _) => impl_to_wkt!(as_large_geometry_collection),
             Rect(_) => impl_to_wkt!(as_rect),
             Geometry(_) => impl_to_wkt!(as_geometry),
+            LargeGeometry(_) => impl_to_wkt!(as_large_geometry),
         }
     }
 }
@@ -2,6 +2,7 @@ use crate::algorithm::native::eq::geometry_eq;
 use crate::io::geo::geometry_to_geo;
 use crate::scalar::*;
 use crate::trait_::NativeScalar;
+use arrow_array::OffsetSizeTrait;
 use geo_traits::{
     GeometryCollectionTrait, GeometryTrait, GeometryType, LineStringTrait, MultiLineStringTrait,
     MultiPointTrait, MultiPolygonTrait, PointTrait, PolygonTrait, RectTrait, UnimplementedLine,
@@ -13,26 +14,26 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [GeometryTrait], which you can use to extract data.
 #[derive(Debug)]
-pub enum Geometry<'a> {
+pub enum Geometry<'a, O: OffsetSizeTrait = i32> {
     /// Point geometry
     Point(crate::scalar::Point<'a>),
     /// LineString geometry
-    LineString(crate::scalar::LineString<'a>),
+    LineString(crate::scalar::LineString<'a, O>),
     /// Polygon geometry
-    Polygon(crate::scalar::Polygon<'a>),
+    Polygon(crate::scalar::Polygon<'a, O>),
     /// MultiPoint geometry
-    MultiPoint(crate::scalar::MultiPoint<'a>),
+    MultiPoint(crate::scalar::MultiPoint<'a, O>),
     /// MultiLineString geometry
-    MultiLineString(crate::scalar::MultiLineString<'a>),
+    MultiLineString(crate::scalar::MultiLineString<'a, O>),
     /// MultiPolygon geometry
-    MultiPolygon(crate::scalar::MultiPolygon<'a>),
+    MultiPolygon(crate::scalar::MultiPolygon<'a, O>),
     /// GeometryCollection geometry
-    GeometryCollection(crate::scalar::GeometryCollection<'a>),
+    GeometryCollection(crate::scalar::GeometryCollection<'a, O>),
     /// Rect geometry
     Rect(crate::scalar::Rect<'a>),
 }
 
-impl NativeScalar for Geometry<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for Geometry<'_, O> {
     type ScalarGeo = geo::Geometry;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -58,34 +59,34 @@ impl NativeScalar for Geometry<'_> {
     }
 }
 
-impl GeometryTrait for Geometry<'_> {
+impl<O: OffsetSizeTrait> GeometryTrait for Geometry<'_, O> {
     type T = f64;
     type PointType<'b>
         = Point<'b>
     where
         Self: 'b;
     type LineStringType<'b>
-        = LineString<'b>
+        = LineString<'b, O>
     where
         Self: 'b;
     type PolygonType<'b>
-        = Polygon<'b>
+        = Polygon<'b, O>
     where
         Self: 'b;
     type MultiPointType<'b>
-        = MultiPoint<'b>
+        = MultiPoint<'b, O>
     where
         Self: 'b;
     type MultiLineStringType<'b>
-        = MultiLineString<'b>
+        = MultiLineString<'b, O>
     where
         Self: 'b;
     type MultiPolygonType<'b>
-        = MultiPolygon<'b>
+        = MultiPolygon<'b, O>
     where
         Self: 'b;
     type GeometryCollectionType<'b>
-        = GeometryCollection<'b>
+        = GeometryCollection<'b, O>
     where
         Self: 'b;
     type RectType<'b>
@@ -119,12 +120,12 @@ impl GeometryTrait for Geometry<'_> {
     ) -> geo_traits::GeometryType<
         '_,
         Point<'_>,
-        LineString<'_>,
-        Polygon<'_>,
-        MultiPoint<'_>,
-        MultiLineString<'_>,
-        MultiPolygon<'_>,
-        GeometryCollection<'_>,
+        LineString<'_, O>,
+        Polygon<'_, O>,
+        MultiPoint<'_, O>,
+        MultiLineString<'_, O>,
+        MultiPolygon<'_, O>,
+        GeometryCollection<'_, O>,
         Rect<'_>,
         UnimplementedTriangle<f64>,
         UnimplementedLine<f64>,
@@ -142,34 +143,34 @@ impl GeometryTrait for Geometry<'_> {
     }
 }
 
-impl<'a> GeometryTrait for &'a Geometry<'a> {
+impl<'a, O: OffsetSizeTrait> GeometryTrait for &'a Geometry<'a, O> {
     type T = f64;
     type PointType<'b>
         = Point<'b>
     where
         Self: 'b;
     type LineStringType<'b>
-        = LineString<'b>
+        = LineString<'b, O>
     where
         Self: 'b;
     type PolygonType<'b>
-        = Polygon<'b>
+        = Polygon<'b, O>
     where
         Self: 'b;
     type MultiPointType<'b>
-        = MultiPoint<'b>
+        = MultiPoint<'b, O>
     where
         Self: 'b;
     type MultiLineStringType<'b>
-        = MultiLineString<'b>
+        = MultiLineString<'b, O>
     where
         Self: 'b;
     type MultiPolygonType<'b>
-        = MultiPolygon<'b>
+        = MultiPolygon<'b, O>
     where
         Self: 'b;
     type GeometryCollectionType<'b>
-        = GeometryCollection<'b>
+        = GeometryCollection<'b, O>
     where
         Self: 'b;
     type RectType<'b>
@@ -203,12 +204,12 @@ impl<'a> GeometryTrait for &'a Geometry<'a> {
     ) -> geo_traits::GeometryType<
         'a,
         Point<'a>,
-        LineString<'a>,
-        Polygon<'a>,
-        MultiPoint<'a>,
-        MultiLineString<'a>,
-        MultiPolygon<'a>,
-        GeometryCollection<'a>,
+        LineString<'a, O>,
+        Polygon<'a, O>,
+        MultiPoint<'a, O>,
+        MultiLineString<'a, O>,
+        MultiPolygon<'a, O>,
+        GeometryCollection<'a, O>,
         Rect<'a>,
         UnimplementedTriangle<f64>,
         UnimplementedLine<f64>,
@@ -226,7 +227,7 @@ impl<'a> GeometryTrait for &'a Geometry<'a> {
     }
 }
 
-impl RTreeObject for Geometry<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for Geometry<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -243,19 +244,19 @@ impl RTreeObject for Geometry<'_> {
     }
 }
 
-impl From<Geometry<'_>> for geo::Geometry {
-    fn from(value: Geometry<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<Geometry<'_, O>> for geo::Geometry {
+    fn from(value: Geometry<'_, O>) -> Self {
         (&value).into()
     }
 }
 
-impl From<&Geometry<'_>> for geo::Geometry {
-    fn from(value: &Geometry<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&Geometry<'_, O>> for geo::Geometry {
+    fn from(value: &Geometry<'_, O>) -> Self {
         geometry_to_geo(value)
     }
 }
 
-impl<G: GeometryTrait<T = f64>> PartialEq<G> for Geometry<'_> {
+impl<O: OffsetSizeTrait, G: GeometryTrait<T = f64>> PartialEq<G> for Geometry<'_, O> {
     fn eq(&self, other: &G) -> bool {
         geometry_eq(self, other)
     }
@@ -1,6 +1,5 @@
 use crate::algorithm::native::eq::geometry_collection_eq;
 use crate::array::{GeometryCollectionArray, MixedGeometryArray};
-use crate::datatypes::Dimension;
 use crate::scalar::{Geometry, GeometryCollection};
 use crate::NativeArray;
 use arrow_buffer::OffsetBuffer;
@@ -64,10 +63,7 @@ impl GeometryCollectionTrait for OwnedGeometryCollection {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.array.dimension() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.array.dimension().into()
     }
 
     fn num_geometries(&self) -> usize {
@@ -1,13 +1,13 @@
 use crate::algorithm::native::bounding_rect::bounding_rect_geometry_collection;
 use crate::algorithm::native::eq::geometry_collection_eq;
 use crate::array::util::OffsetBufferUtils;
-use crate::array::MixedGeometryArray;
-use crate::datatypes::Dimension;
+use crate::array::GenericMixedGeometryArray;
 use crate::io::geo::geometry_collection_to_geo;
 use crate::scalar::Geometry;
 use crate::trait_::ArrayAccessor;
 use crate::trait_::NativeScalar;
 use crate::NativeArray;
+use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo_traits::GeometryCollectionTrait;
 use rstar::{RTreeObject, AABB};
@@ -16,21 +16,21 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [GeometryCollectionTrait], which you can use to extract data.
 #[derive(Debug, Clone)]
-pub struct GeometryCollection<'a> {
-    pub(crate) array: &'a MixedGeometryArray,
+pub struct GeometryCollection<'a, O: OffsetSizeTrait = i32> {
+    pub(crate) array: &'a GenericMixedGeometryArray<O>,
 
     /// Offsets into the geometry array where each geometry starts
-    pub(crate) geom_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) geom_offsets: &'a OffsetBuffer<O>,
 
     pub(crate) geom_index: usize,
 
     start_offset: usize,
 }
 
-impl<'a> GeometryCollection<'a> {
+impl<'a, O: OffsetSizeTrait> GeometryCollection<'a, O> {
     pub(crate) fn new(
-        array: &'a MixedGeometryArray,
-        geom_offsets: &'a OffsetBuffer<i32>,
+        array: &'a GenericMixedGeometryArray<O>,
+        geom_offsets: &'a OffsetBuffer<O>,
         geom_index: usize,
     ) -> Self {
         let (start_offset, _) = geom_offsets.start_end(geom_index);
@@ -43,12 +43,12 @@ impl<'a> GeometryCollection<'a> {
     }
 
     #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn into_inner(&self) -> (&MixedGeometryArray, &OffsetBuffer<i32>, usize) {
+    pub(crate) fn into_inner(&self) -> (&GenericMixedGeometryArray<O>, &OffsetBuffer<O>, usize) {
         (self.array, self.geom_offsets, self.geom_index)
     }
 }
 
-impl NativeScalar for GeometryCollection<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for GeometryCollection<'_, O> {
     type ScalarGeo = geo::GeometryCollection;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -65,18 +65,15 @@ impl NativeScalar for GeometryCollection<'_> {
     }
 }
 
-impl<'a> GeometryCollectionTrait for GeometryCollection<'a> {
+impl<'a, O: OffsetSizeTrait> GeometryCollectionTrait for GeometryCollection<'a, O> {
     type T = f64;
     type GeometryType<'b>
-        = Geometry<'a>
+        = Geometry<'a, O>
     where
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.array.dimension() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.array.dimension().into()
     }
 
     fn num_geometries(&self) -> usize {
@@ -89,18 +86,15 @@ impl<'a> GeometryCollectionTrait for GeometryCollection<'a> {
     }
 }
 
-impl<'a> GeometryCollectionTrait for &'a GeometryCollection<'a> {
+impl<'a, O: OffsetSizeTrait> GeometryCollectionTrait for &'a GeometryCollection<'a, O> {
     type T = f64;
     type GeometryType<'b>
-        = Geometry<'a>
+        = Geometry<'a, O>
     where
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.array.dimension() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.array.dimension().into()
     }
 
     fn num_geometries(&self) -> usize {
@@ -113,14 +107,14 @@ impl<'a> GeometryCollectionTrait for &'a GeometryCollection<'a> {
     }
 }
 
-impl From<&GeometryCollection<'_>> for geo::GeometryCollection {
-    fn from(value: &GeometryCollection<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&GeometryCollection<'_, O>> for geo::GeometryCollection {
+    fn from(value: &GeometryCollection<'_, O>) -> Self {
         geometry_collection_to_geo(value)
     }
 }
 
-impl From<GeometryCollection<'_>> for geo::GeometryCollection {
-    fn from(value: GeometryCollection<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<GeometryCollection<'_, O>> for geo::GeometryCollection {
+    fn from(value: GeometryCollection<'_, O>) -> Self {
         (&value).into()
     }
 }
@@ -176,7 +170,7 @@ impl From<GeometryCollection<'_>> for geo::GeometryCollection {
 //     }
 // }
 
-impl RTreeObject for GeometryCollection<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for GeometryCollection<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -185,7 +179,9 @@ impl RTreeObject for GeometryCollection<'_> {
     }
 }
 
-impl<G: GeometryCollectionTrait<T = f64>> PartialEq<G> for GeometryCollection<'_> {
+impl<O: OffsetSizeTrait, G: GeometryCollectionTrait<T = f64>> PartialEq<G>
+    for GeometryCollection<'_, O>
+{
     fn eq(&self, other: &G) -> bool {
         geometry_collection_eq(self, other)
     }
@@ -195,7 +191,8 @@ impl<G: GeometryCollectionTrait<T = f64>> PartialEq<G> for GeometryCollection<'_
 mod tests {
     use arrow_buffer::OffsetBufferBuilder;
 
-    use crate::array::PointArray;
+    use crate::array::{MixedGeometryArray, PointArray};
+    use crate::datatypes::Dimension;
 
     use super::*;
 
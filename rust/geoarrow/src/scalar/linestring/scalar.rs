@@ -4,6 +4,7 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
 use crate::scalar::Coord;
 use crate::trait_::NativeScalar;
+use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo_traits::to_geo::ToGeoLineString;
 use geo_traits::LineStringTrait;
@@ -13,21 +14,21 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [LineStringTrait], which you can use to extract data.
 #[derive(Debug, Clone)]
-pub struct LineString<'a> {
+pub struct LineString<'a, O: OffsetSizeTrait = i32> {
     pub(crate) coords: &'a CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
-    pub(crate) geom_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) geom_offsets: &'a OffsetBuffer<O>,
 
     pub(crate) geom_index: usize,
 
     start_offset: usize,
 }
 
-impl<'a> LineString<'a> {
+impl<'a, O: OffsetSizeTrait> LineString<'a, O> {
     pub(crate) fn new(
         coords: &'a CoordBuffer,
-        geom_offsets: &'a OffsetBuffer<i32>,
+        geom_offsets: &'a OffsetBuffer<O>,
         geom_index: usize,
     ) -> Self {
         let (start_offset, _) = geom_offsets.start_end(geom_index);
@@ -38,7 +39,9 @@ impl<'a> LineString<'a> {
             start_offset,
         }
     }
+}
 
+impl LineString<'_> {
     pub(crate) fn into_owned_inner(self) -> (CoordBuffer, OffsetBuffer<i32>, usize) {
         (
             self.coords.clone(),
@@ -48,7 +51,7 @@ impl<'a> LineString<'a> {
     }
 }
 
-impl NativeScalar for LineString<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for LineString<'_, O> {
     type ScalarGeo = geo::LineString;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -65,7 +68,7 @@ impl NativeScalar for LineString<'_> {
     }
 }
 
-impl<'a> LineStringTrait for LineString<'a> {
+impl<'a, O: OffsetSizeTrait> LineStringTrait for LineString<'a, O> {
     type T = f64;
     type CoordType<'b>
         = Coord<'a>
@@ -86,7 +89,7 @@ impl<'a> LineStringTrait for LineString<'a> {
     }
 }
 
-impl<'a> LineStringTrait for &'a LineString<'a> {
+impl<'a, O: OffsetSizeTrait> LineStringTrait for &'a LineString<'a, O> {
     type T = f64;
     type CoordType<'b>
         = Coord<'a>
@@ -107,25 +110,25 @@ impl<'a> LineStringTrait for &'a LineString<'a> {
     }
 }
 
-impl From<LineString<'_>> for geo::LineString {
-    fn from(value: LineString<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<LineString<'_, O>> for geo::LineString {
+    fn from(value: LineString<'_, O>) -> Self {
         (&value).into()
     }
 }
 
-impl From<&LineString<'_>> for geo::LineString {
-    fn from(value: &LineString<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&LineString<'_, O>> for geo::LineString {
+    fn from(value: &LineString<'_, O>) -> Self {
         value.to_line_string()
     }
 }
 
-impl From<LineString<'_>> for geo::Geometry {
-    fn from(value: LineString<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<LineString<'_, O>> for geo::Geometry {
+    fn from(value: LineString<'_, O>) -> Self {
         geo::Geometry::LineString(value.into())
     }
 }
 
-impl RTreeObject for LineString<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for LineString<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -134,7 +137,7 @@ impl RTreeObject for LineString<'_> {
     }
 }
 
-impl<G: LineStringTrait<T = f64>> PartialEq<G> for LineString<'_> {
+impl<O: OffsetSizeTrait, G: LineStringTrait<T = f64>> PartialEq<G> for LineString<'_, O> {
     fn eq(&self, other: &G) -> bool {
         line_string_eq(self, other)
     }
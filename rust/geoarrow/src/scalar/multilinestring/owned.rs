@@ -1,6 +1,5 @@
 use crate::algorithm::native::eq::multi_line_string_eq;
 use crate::array::{CoordBuffer, MultiLineStringArray};
-use crate::datatypes::Dimension;
 use crate::scalar::{LineString, MultiLineString};
 use arrow_buffer::OffsetBuffer;
 use geo_traits::MultiLineStringTrait;
@@ -78,10 +77,7 @@ impl MultiLineStringTrait for OwnedMultiLineString {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_line_strings(&self) -> usize {
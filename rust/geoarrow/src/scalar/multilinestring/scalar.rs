@@ -4,6 +4,7 @@ use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
 use crate::scalar::LineString;
 use crate::trait_::NativeScalar;
+use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo_traits::to_geo::ToGeoMultiLineString;
 use geo_traits::MultiLineStringTrait;
@@ -13,25 +14,25 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [MultiLineStringTrait], which you can use to extract data.
 #[derive(Debug, Clone)]
-pub struct MultiLineString<'a> {
+pub struct MultiLineString<'a, O: OffsetSizeTrait = i32> {
     pub(crate) coords: &'a CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
-    pub(crate) geom_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) geom_offsets: &'a OffsetBuffer<O>,
 
     /// Offsets into the coordinate array where each ring starts
-    pub(crate) ring_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) ring_offsets: &'a OffsetBuffer<O>,
 
     pub(crate) geom_index: usize,
 
     start_offset: usize,
 }
 
-impl<'a> MultiLineString<'a> {
+impl<'a, O: OffsetSizeTrait> MultiLineString<'a, O> {
     pub(crate) fn new(
         coords: &'a CoordBuffer,
-        geom_offsets: &'a OffsetBuffer<i32>,
-        ring_offsets: &'a OffsetBuffer<i32>,
+        geom_offsets: &'a OffsetBuffer<O>,
+        ring_offsets: &'a OffsetBuffer<O>,
         geom_index: usize,
     ) -> Self {
         let (start_offset, _) = geom_offsets.start_end(geom_index);
@@ -43,7 +44,9 @@ impl<'a> MultiLineString<'a> {
             start_offset,
         }
     }
+}
 
+impl MultiLineString<'_> {
     pub(crate) fn into_owned_inner(
         self,
     ) -> (CoordBuffer, OffsetBuffer<i32>, OffsetBuffer<i32>, usize) {
@@ -56,7 +59,7 @@ impl<'a> MultiLineString<'a> {
     }
 }
 
-impl NativeScalar for MultiLineString<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for MultiLineString<'_, O> {
     type ScalarGeo = geo::MultiLineString;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -73,10 +76,10 @@ impl NativeScalar for MultiLineString<'_> {
     }
 }
 
-impl<'a> MultiLineStringTrait for MultiLineString<'a> {
+impl<'a, O: OffsetSizeTrait> MultiLineStringTrait for MultiLineString<'a, O> {
     type T = f64;
     type LineStringType<'b>
-        = LineString<'a>
+        = LineString<'a, O>
     where
         Self: 'b;
 
@@ -94,10 +97,10 @@ impl<'a> MultiLineStringTrait for MultiLineString<'a> {
     }
 }
 
-impl<'a> MultiLineStringTrait for &'a MultiLineString<'a> {
+impl<'a, O: OffsetSizeTrait> MultiLineStringTrait for &'a MultiLineString<'a, O> {
     type T = f64;
     type LineStringType<'b>
-        = LineString<'a>
+        = LineString<'a, O>
     where
         Self: 'b;
 
@@ -115,25 +118,25 @@ impl<'a> MultiLineStringTrait for &'a MultiLineString<'a> {
     }
 }
 
-impl From<MultiLineString<'_>> for geo::MultiLineString {
-    fn from(value: MultiLineString<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<MultiLineString<'_, O>> for geo::MultiLineString {
+    fn from(value: MultiLineString<'_, O>) -> Self {
         (&value).into()
     }
 }
 
-impl From<&MultiLineString<'_>> for geo::MultiLineString {
-    fn from(value: &MultiLineString<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&MultiLineString<'_, O>> for geo::MultiLineString {
+    fn from(value: &MultiLineString<'_, O>) -> Self {
         value.to_multi_line_string()
     }
 }
 
-impl From<MultiLineString<'_>> for geo::Geometry {
-    fn from(value: MultiLineString<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<MultiLineString<'_, O>> for geo::Geometry {
+    fn from(value: MultiLineString<'_, O>) -> Self {
         geo::Geometry::MultiLineString(value.into())
     }
 }
 
-impl RTreeObject for MultiLineString<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for MultiLineString<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -142,7 +145,7 @@ impl RTreeObject for MultiLineString<'_> {
     }
 }
 
-impl<G: MultiLineStringTrait<T = f64>> PartialEq<G> for MultiLineString<'_> {
+impl<O: OffsetSizeTrait, G: MultiLineStringTrait<T = f64>> PartialEq<G> for MultiLineString<'_, O> {
     fn eq(&self, other: &G) -> bool {
         multi_line_string_eq(self, other)
     }
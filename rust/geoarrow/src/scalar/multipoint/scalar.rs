@@ -2,9 +2,9 @@ use crate::algorithm::native::bounding_rect::bounding_rect_multipoint;
 use crate::algorithm::native::eq::multi_point_eq;
 use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
-use crate::datatypes::Dimension;
 use crate::scalar::Point;
 use crate::trait_::NativeScalar;
+use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo_traits::to_geo::ToGeoMultiPoint;
 use geo_traits::MultiPointTrait;
@@ -14,22 +14,22 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [MultiPointTrait], which you can use to extract data.
 #[derive(Debug, Clone)]
-pub struct MultiPoint<'a> {
+pub struct MultiPoint<'a, O: OffsetSizeTrait = i32> {
     /// Buffer of coordinates
     pub(crate) coords: &'a CoordBuffer,
 
     /// Offsets into the coordinate array where each geometry starts
-    pub(crate) geom_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) geom_offsets: &'a OffsetBuffer<O>,
 
     pub(crate) geom_index: usize,
 
     start_offset: usize,
 }
 
-impl<'a> MultiPoint<'a> {
+impl<'a, O: OffsetSizeTrait> MultiPoint<'a, O> {
     pub(crate) fn new(
         coords: &'a CoordBuffer,
-        geom_offsets: &'a OffsetBuffer<i32>,
+        geom_offsets: &'a OffsetBuffer<O>,
         geom_index: usize,
     ) -> Self {
         let (start_offset, _) = geom_offsets.start_end(geom_index);
@@ -40,7 +40,9 @@ impl<'a> MultiPoint<'a> {
             start_offset,
         }
     }
+}
 
+impl MultiPoint<'_> {
     pub(crate) fn into_owned_inner(self) -> (CoordBuffer, OffsetBuffer<i32>, usize) {
         (
             self.coords.clone(),
@@ -50,7 +52,7 @@ impl<'a> MultiPoint<'a> {
     }
 }
 
-impl NativeScalar for MultiPoint<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for MultiPoint<'_, O> {
     type ScalarGeo = geo::MultiPoint;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -67,7 +69,7 @@ impl NativeScalar for MultiPoint<'_> {
     }
 }
 
-impl<'a> MultiPointTrait for MultiPoint<'a> {
+impl<'a, O: OffsetSizeTrait> MultiPointTrait for MultiPoint<'a, O> {
     type T = f64;
     type PointType<'b>
         = Point<'a>
@@ -75,10 +77,7 @@ impl<'a> MultiPointTrait for MultiPoint<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_points(&self) -> usize {
@@ -91,7 +90,7 @@ impl<'a> MultiPointTrait for MultiPoint<'a> {
     }
 }
 
-impl<'a> MultiPointTrait for &'a MultiPoint<'a> {
+impl<'a, O: OffsetSizeTrait> MultiPointTrait for &'a MultiPoint<'a, O> {
     type T = f64;
     type PointType<'b>
         = Point<'a>
@@ -99,10 +98,7 @@ impl<'a> MultiPointTrait for &'a MultiPoint<'a> {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_points(&self) -> usize {
@@ -115,25 +111,25 @@ impl<'a> MultiPointTrait for &'a MultiPoint<'a> {
     }
 }
 
-impl From<MultiPoint<'_>> for geo::MultiPoint {
-    fn from(value: MultiPoint<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<MultiPoint<'_, O>> for geo::MultiPoint {
+    fn from(value: MultiPoint<'_, O>) -> Self {
         (&value).into()
     }
 }
 
-impl From<&MultiPoint<'_>> for geo::MultiPoint {
-    fn from(value: &MultiPoint<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&MultiPoint<'_, O>> for geo::MultiPoint {
+    fn from(value: &MultiPoint<'_, O>) -> Self {
         value.to_multi_point()
     }
 }
 
-impl From<MultiPoint<'_>> for geo::Geometry {
-    fn from(value: MultiPoint<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<MultiPoint<'_, O>> for geo::Geometry {
+    fn from(value: MultiPoint<'_, O>) -> Self {
         geo::Geometry::MultiPoint(value.into())
     }
 }
 
-impl RTreeObject for MultiPoint<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for MultiPoint<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -142,7 +138,7 @@ impl RTreeObject for MultiPoint<'_> {
     }
 }
 
-impl<G: MultiPointTrait<T = f64>> PartialEq<G> for MultiPoint<'_> {
+impl<O: OffsetSizeTrait, G: MultiPointTrait<T = f64>> PartialEq<G> for MultiPoint<'_, O> {
     fn eq(&self, other: &G) -> bool {
         multi_point_eq(self, other)
     }
@@ -1,6 +1,5 @@
 use crate::algorithm::native::eq::multi_polygon_eq;
 use crate::array::{CoordBuffer, MultiPolygonArray};
-use crate::datatypes::Dimension;
 use crate::scalar::{MultiPolygon, Polygon};
 use arrow_buffer::OffsetBuffer;
 use geo_traits::MultiPolygonTrait;
@@ -91,10 +90,7 @@ impl MultiPolygonTrait for OwnedMultiPolygon {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_polygons(&self) -> usize {
@@ -2,9 +2,9 @@ use crate::algorithm::native::bounding_rect::bounding_rect_multipolygon;
 use crate::algorithm::native::eq::multi_polygon_eq;
 use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
-use crate::datatypes::Dimension;
 use crate::scalar::Polygon;
 use crate::trait_::NativeScalar;
+use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo_traits::to_geo::ToGeoMultiPolygon;
 use geo_traits::MultiPolygonTrait;
@@ -14,29 +14,29 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [MultiPolygonTrait], which you can use to extract data.
 #[derive(Debug, Clone)]
-pub struct MultiPolygon<'a> {
+pub struct MultiPolygon<'a, O: OffsetSizeTrait = i32> {
     pub(crate) coords: &'a CoordBuffer,
 
     /// Offsets into the polygon array where each geometry starts
-    pub(crate) geom_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) geom_offsets: &'a OffsetBuffer<O>,
 
     /// Offsets into the ring array where each polygon starts
-    pub(crate) polygon_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) polygon_offsets: &'a OffsetBuffer<O>,
 
     /// Offsets into the coordinate array where each ring starts
-    pub(crate) ring_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) ring_offsets: &'a OffsetBuffer<O>,
 
     pub(crate) geom_index: usize,
 
     start_offset: usize,
 }
 
-impl<'a> MultiPolygon<'a> {
+impl<'a, O: OffsetSizeTrait> MultiPolygon<'a, O> {
     pub(crate) fn new(
         coords: &'a CoordBuffer,
-        geom_offsets: &'a OffsetBuffer<i32>,
-        polygon_offsets: &'a OffsetBuffer<i32>,
-        ring_offsets: &'a OffsetBuffer<i32>,
+        geom_offsets: &'a OffsetBuffer<O>,
+        polygon_offsets: &'a OffsetBuffer<O>,
+        ring_offsets: &'a OffsetBuffer<O>,
         geom_index: usize,
     ) -> Self {
         let (start_offset, _) = geom_offsets.start_end(geom_index);
@@ -49,7 +49,9 @@ impl<'a> MultiPolygon<'a> {
             start_offset,
         }
     }
+}
 
+impl MultiPolygon<'_> {
     pub(crate) fn into_owned_inner(
         self,
     ) -> (
@@ -69,7 +71,7 @@ impl<'a> MultiPolygon<'a> {
     }
 }
 
-impl NativeScalar for MultiPolygon<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for MultiPolygon<'_, O> {
     type ScalarGeo = geo::MultiPolygon;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -86,18 +88,15 @@ impl NativeScalar for MultiPolygon<'_> {
     }
 }
 
-impl<'a> MultiPolygonTrait for MultiPolygon<'a> {
+impl<'a, O: OffsetSizeTrait> MultiPolygonTrait for MultiPolygon<'a, O> {
     type T = f64;
     type PolygonType<'b>
-        = Polygon<'a>
+        = Polygon<'a, O>
     where
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_polygons(&self) -> usize {
@@ -115,18 +114,15 @@ impl<'a> MultiPolygonTrait for MultiPolygon<'a> {
     }
 }
 
-impl<'a> MultiPolygonTrait for &'a MultiPolygon<'a> {
+impl<'a, O: OffsetSizeTrait> MultiPolygonTrait for &'a MultiPolygon<'a, O> {
     type T = f64;
     type PolygonType<'b>
-        = Polygon<'a>
+        = Polygon<'a, O>
     where
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn num_polygons(&self) -> usize {
@@ -144,25 +140,25 @@ impl<'a> MultiPolygonTrait for &'a MultiPolygon<'a> {
     }
 }
 
-impl From<MultiPolygon<'_>> for geo::MultiPolygon {
-    fn from(value: MultiPolygon<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<MultiPolygon<'_, O>> for geo::MultiPolygon {
+    fn from(value: MultiPolygon<'_, O>) -> Self {
         (&value).into()
     }
 }
 
-impl From<&MultiPolygon<'_>> for geo::MultiPolygon {
-    fn from(value: &MultiPolygon<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&MultiPolygon<'_, O>> for geo::MultiPolygon {
+    fn from(value: &MultiPolygon<'_, O>) -> Self {
         value.to_multi_polygon()
     }
 }
 
-impl From<MultiPolygon<'_>> for geo::Geometry {
-    fn from(value: MultiPolygon<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<MultiPolygon<'_, O>> for geo::Geometry {
+    fn from(value: MultiPolygon<'_, O>) -> Self {
         geo::Geometry::MultiPolygon(value.into())
     }
 }
 
-impl RTreeObject for MultiPolygon<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for MultiPolygon<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -171,7 +167,7 @@ impl RTreeObject for MultiPolygon<'_> {
     }
 }
 
-impl<G: MultiPolygonTrait<T = f64>> PartialEq<G> for MultiPolygon<'_> {
+impl<O: OffsetSizeTrait, G: MultiPolygonTrait<T = f64>> PartialEq<G> for MultiPolygon<'_, O> {
     fn eq(&self, other: &G) -> bool {
         multi_polygon_eq(self, other)
     }
@@ -1,6 +1,5 @@
 use crate::algorithm::native::eq::polygon_eq;
 use crate::array::{CoordBuffer, PolygonArray};
-use crate::datatypes::Dimension;
 use crate::scalar::{LineString, Polygon};
 use arrow_buffer::OffsetBuffer;
 use geo_traits::PolygonTrait;
@@ -78,10 +77,7 @@ impl PolygonTrait for OwnedPolygon {
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
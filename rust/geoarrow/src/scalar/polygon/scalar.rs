@@ -2,9 +2,9 @@ use crate::algorithm::native::bounding_rect::bounding_rect_polygon;
 use crate::algorithm::native::eq::polygon_eq;
 use crate::array::util::OffsetBufferUtils;
 use crate::array::CoordBuffer;
-use crate::datatypes::Dimension;
 use crate::scalar::LineString;
 use crate::trait_::NativeScalar;
+use arrow_array::OffsetSizeTrait;
 use arrow_buffer::OffsetBuffer;
 use geo_traits::to_geo::ToGeoPolygon;
 use geo_traits::PolygonTrait;
@@ -14,25 +14,25 @@ use rstar::{RTreeObject, AABB};
 ///
 /// This implements [PolygonTrait], which you can use to extract data.
 #[derive(Debug, Clone)]
-pub struct Polygon<'a> {
+pub struct Polygon<'a, O: OffsetSizeTrait = i32> {
     pub(crate) coords: &'a CoordBuffer,
 
     /// Offsets into the ring array where each geometry starts
-    pub(crate) geom_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) geom_offsets: &'a OffsetBuffer<O>,
 
     /// Offsets into the coordinate array where each ring starts
-    pub(crate) ring_offsets: &'a OffsetBuffer<i32>,
+    pub(crate) ring_offsets: &'a OffsetBuffer<O>,
 
     pub(crate) geom_index: usize,
 
     start_offset: usize,
 }
 
-impl<'a> Polygon<'a> {
+impl<'a, O: OffsetSizeTrait> Polygon<'a, O> {
     pub(crate) fn new(
         coords: &'a CoordBuffer,
-        geom_offsets: &'a OffsetBuffer<i32>,
-        ring_offsets: &'a OffsetBuffer<i32>,
+        geom_offsets: &'a OffsetBuffer<O>,
+        ring_offsets: &'a OffsetBuffer<O>,
         geom_index: usize,
     ) -> Self {
         let (start_offset, _) = geom_offsets.start_end(geom_index);
@@ -44,7 +44,9 @@ impl<'a> Polygon<'a> {
             start_offset,
         }
     }
+}
 
+impl Polygon<'_> {
     pub(crate) fn into_owned_inner(
         self,
     ) -> (CoordBuffer, OffsetBuffer<i32>, OffsetBuffer<i32>, usize) {
@@ -57,7 +59,7 @@ impl<'a> Polygon<'a> {
     }
 }
 
-impl NativeScalar for Polygon<'_> {
+impl<O: OffsetSizeTrait> NativeScalar for Polygon<'_, O> {
     type ScalarGeo = geo::Polygon;
 
     fn to_geo(&self) -> Self::ScalarGeo {
@@ -74,18 +76,15 @@ impl NativeScalar for Polygon<'_> {
     }
 }
 
-impl<'a> PolygonTrait for Polygon<'a> {
+impl<'a, O: OffsetSizeTrait> PolygonTrait for Polygon<'a, O> {
     type T = f64;
     type RingType<'b>
-        = LineString<'a>
+        = LineString<'a, O>
     where
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
@@ -98,8 +97,9 @@ impl<'a> PolygonTrait for Polygon<'a> {
     }
 
     fn num_interiors(&self) -> usize {
+        // Null and empty polygons have no rings at all
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        end - start - 1
+        (end - start).saturating_sub(1)
     }
 
     unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
@@ -107,18 +107,15 @@ impl<'a> PolygonTrait for Polygon<'a> {
     }
 }
 
-impl<'a> PolygonTrait for &'a Polygon<'a> {
+impl<'a, O: OffsetSizeTrait> PolygonTrait for &'a Polygon<'a, O> {
     type T = f64;
     type RingType<'b>
-        = LineString<'a>
+        = LineString<'a, O>
     where
         Self: 'b;
 
     fn dim(&self) -> geo_traits::Dimensions {
-        match self.coords.dim() {
-            Dimension::XY => geo_traits::Dimensions::Xy,
-            Dimension::XYZ => geo_traits::Dimensions::Xyz,
-        }
+        self.coords.dim().into()
     }
 
     fn exterior(&self) -> Option<Self::RingType<'_>> {
@@ -131,8 +128,9 @@ impl<'a> PolygonTrait for &'a Polygon<'a> {
     }
 
     fn num_interiors(&self) -> usize {
+        // Null and empty polygons have no rings at all
         let (start, end) = self.geom_offsets.start_end(self.geom_index);
-        end - start - 1
+        (end - start).saturating_sub(1)
     }
 
     unsafe fn interior_unchecked(&self, i: usize) -> Self::RingType<'_> {
@@ -140,25 +138,25 @@ impl<'a> PolygonTrait for &'a Polygon<'a> {
     }
 }
 
-impl From<Polygon<'_>> for geo::Polygon {
-    fn from(value: Polygon<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<Polygon<'_, O>> for geo::Polygon {
+    fn from(value: Polygon<'_, O>) -> Self {
         (&value).into()
     }
 }
 
-impl From<&Polygon<'_>> for geo::Polygon {
-    fn from(value: &Polygon<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<&Polygon<'_, O>> for geo::Polygon {
+    fn from(value: &Polygon<'_, O>) -> Self {
         value.to_polygon()
     }
 }
 
-impl From<Polygon<'_>> for geo::Geometry {
-    fn from(value: Polygon<'_>) -> Self {
+impl<O: OffsetSizeTrait> From<Polygon<'_, O>> for geo::Geometry {
+    fn from(value: Polygon<'_, O>) -> Self {
         geo::Geometry::Polygon(value.into())
     }
 }
 
-impl RTreeObject for Polygon<'_> {
+impl<O: OffsetSizeTrait> RTreeObject for Polygon<'_, O> {
     type Envelope = AABB<[f64; 2]>;
 
     fn envelope(&self) -> Self::Envelope {
@@ -167,7 +165,7 @@ impl RTreeObject for Polygon<'_> {
     }
 }
 
-impl<G: PolygonTrait<T = f64>> PartialEq<G> for Polygon<'_> {
+impl<O: OffsetSizeTrait, G: PolygonTrait<T = f64>> PartialEq<G> for Polygon<'_, O> {
     fn eq(&self, other: &G) -> bool {
         polygon_eq(self, other)
     }
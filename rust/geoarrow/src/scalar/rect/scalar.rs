@@ -49,8 +49,7 @@ impl NativeScalar for Rect<'_> {
 
     #[cfg(feature = "geos")]
     fn to_geos(&self) -> std::result::Result<geos::Geometry, geos::Error> {
-        todo!()
-        // self.try_into()
+        self.try_into()
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::array::{
-    AsNativeArray, GeometryArray, GeometryCollectionArray, LineStringArray, MultiLineStringArray,
-    MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, RectArray,
+    AsNativeArray, GeometryArray, GeometryCollectionArray, LineStringArray, MixedGeometryArray,
+    MultiLineStringArray, MultiPointArray, MultiPolygonArray, PointArray, PolygonArray, RectArray,
 };
 use crate::datatypes::{Dimension, NativeType};
 use crate::error::{GeoArrowError, Result};
@@ -59,13 +59,23 @@ impl GeometryScalar {
             | MultiPoint(_, dim)
             | MultiLineString(_, dim)
             | MultiPolygon(_, dim)
+            | Mixed(_, dim)
             | GeometryCollection(_, dim)
+            | LargeLineString(_, dim)
+            | LargePolygon(_, dim)
+            | LargeMultiPoint(_, dim)
+            | LargeMultiLineString(_, dim)
+            | LargeMultiPolygon(_, dim)
+            | LargeMixed(_, dim)
+            | LargeGeometryCollection(_, dim)
             | Rect(dim) => Some(dim),
-            Geometry(_) => None,
+            Geometry(_) | LargeGeometry(_) => None,
         }
     }
 
     /// Convert to a [Geometry]
+    ///
+    /// Returns `None` if the scalar is null or if it is backed by a large-offset array.
     pub fn as_geometry(&self) -> Option<Geometry<'_>> {
         // Note: we use `.downcast_ref` directly here because we need to pass in the generic
         // TODO: may be able to change this now that we don't have <O>
@@ -104,6 +114,14 @@ impl GeometryScalar {
                 let arr = self.0.as_any().downcast_ref::<MultiPolygonArray>().unwrap();
                 arr.get(0).map(Geometry::MultiPolygon)
             }
+            NativeType::Mixed(_, _) => {
+                let arr = self
+                    .0
+                    .as_any()
+                    .downcast_ref::<MixedGeometryArray>()
+                    .unwrap();
+                arr.get(0)
+            }
             NativeType::GeometryCollection(_, _) => {
                 let arr = self
                     .0
@@ -120,6 +138,14 @@ impl GeometryScalar {
                 let arr = self.0.as_any().downcast_ref::<GeometryArray>().unwrap();
                 arr.get(0)
             }
+            NativeType::LargeLineString(_, _)
+            | NativeType::LargePolygon(_, _)
+            | NativeType::LargeMultiPoint(_, _)
+            | NativeType::LargeMultiLineString(_, _)
+            | NativeType::LargeMultiPolygon(_, _)
+            | NativeType::LargeMixed(_, _)
+            | NativeType::LargeGeometryCollection(_, _)
+            | NativeType::LargeGeometry(_) => None,
         }
     }
 
@@ -136,13 +162,22 @@ impl GeometryScalar {
         match self.data_type() {
             Point(_, _) => impl_to_geo!(as_point),
             LineString(_, _) => impl_to_geo!(as_line_string),
+            LargeLineString(_, _) => impl_to_geo!(as_large_line_string),
             Polygon(_, _) => impl_to_geo!(as_polygon),
+            LargePolygon(_, _) => impl_to_geo!(as_large_polygon),
             MultiPoint(_, _) => impl_to_geo!(as_multi_point),
+            LargeMultiPoint(_, _) => impl_to_geo!(as_large_multi_point),
             MultiLineString(_, _) => impl_to_geo!(as_multi_line_string),
+            LargeMultiLineString(_, _) => impl_to_geo!(as_large_multi_line_string),
             MultiPolygon(_, _) => impl_to_geo!(as_multi_polygon),
+            LargeMultiPolygon(_, _) => impl_to_geo!(as_large_multi_polygon),
+            Mixed(_, _) => impl_to_geo!(as_mixed),
+            LargeMixed(_, _) => impl_to_geo!(as_large_mixed),
             GeometryCollection(_, _) => impl_to_geo!(as_geometry_collection),
+            LargeGeometryCollection(_, _) => impl_to_geo!(as_large_geometry_collection),
             Rect(_) => impl_to_geo!(as_rect),
             Geometry(_) => impl_to_geo!(as_geometry),
+            LargeGeometry(_) => impl_to_geo!(as_large_geometry),
         }
     }
 
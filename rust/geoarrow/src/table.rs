@@ -5,11 +5,12 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
-use arrow_array::{ArrayRef, RecordBatch, RecordBatchIterator, RecordBatchReader};
-use arrow_schema::{ArrowError, FieldRef, Schema, SchemaBuilder, SchemaRef};
+use arrow_array::{Array, ArrayRef, RecordBatch, RecordBatchIterator, RecordBatchReader};
+use arrow_schema::{ArrowError, FieldRef, Fields, Schema, SchemaBuilder, SchemaRef};
 
 use crate::algorithm::native::{Cast, Downcast};
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::distinct_buffer_memory_size;
 use crate::array::*;
 use crate::chunked_array::{ChunkedArray, ChunkedNativeArray, ChunkedNativeArrayDyn};
 use crate::datatypes::{AnyType, NativeType, SerializedType};
@@ -32,6 +33,46 @@ pub(crate) static GEOARROW_EXTENSION_NAMES: Set<&'static str> = phf_set! {
     "ogc.wkb",
 };
 
+/// Whether `fields` only differ from `expected` in how equivalent CRSs of geometry fields are
+/// represented.
+///
+/// If `check_crs` is `true`, returns an error if the CRS of a geometry field is not compatible
+/// with the expected one. Otherwise any CRSs are accepted.
+fn fields_match_up_to_crs(fields: &Fields, expected: &Fields, check_crs: bool) -> Result<bool> {
+    if fields.len() != expected.len() {
+        return Ok(false);
+    }
+
+    for (field, expected) in fields.iter().zip(expected.iter()) {
+        if field == expected {
+            continue;
+        }
+
+        let extension_name = expected.metadata().get("ARROW:extension:name");
+        let is_geometry =
+            extension_name.is_some_and(|name| GEOARROW_EXTENSION_NAMES.contains(name.as_str()));
+        if !is_geometry
+            || field.name() != expected.name()
+            || field.data_type() != expected.data_type()
+            || field.is_nullable() != expected.is_nullable()
+            || field.metadata().get("ARROW:extension:name") != extension_name
+        {
+            return Ok(false);
+        }
+
+        let metadata = ArrayMetadata::try_from(field.as_ref())?;
+        let expected_metadata = ArrayMetadata::try_from(expected.as_ref())?;
+        if metadata.edges != expected_metadata.edges {
+            return Ok(false);
+        }
+        if check_crs {
+            expected_metadata.check_crs_compatible(&metadata, "Table::try_new")?;
+        }
+    }
+
+    Ok(true)
+}
+
 /// An Arrow table that may contain one or more geospatial columns.
 ///
 /// This Table object is designed to be interoperable with non-geospatial Arrow libraries, and thus
@@ -48,7 +89,9 @@ impl Table {
     /// # Errors
     ///
     /// Returns an error if a record batch's schema fields do not match the
-    /// top-level schema's fields.
+    /// top-level schema's fields. Geometry fields may differ in how their CRS is represented, as
+    /// long as the CRSs are equivalent. Use [`Table::try_new_unchecked`] to accept batches whose
+    /// CRSs differ.
     ///
     /// # Examples
     ///
@@ -67,11 +110,32 @@ impl Table {
     /// let table = Table::try_new(vec![batch], schema).unwrap();
     /// ```
     pub fn try_new(batches: Vec<RecordBatch>, schema: SchemaRef) -> Result<Self> {
+        Self::try_new_with_crs_check(batches, schema, true)
+    }
+
+    /// Creates a new table from a schema and a vector of record batches, without comparing the
+    /// CRSs of geometry fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record batch's schema fields do not match the top-level schema's
+    /// fields, other than in the CRS of geometry fields.
+    pub fn try_new_unchecked(batches: Vec<RecordBatch>, schema: SchemaRef) -> Result<Self> {
+        Self::try_new_with_crs_check(batches, schema, false)
+    }
+
+    fn try_new_with_crs_check(
+        batches: Vec<RecordBatch>,
+        schema: SchemaRef,
+        check_crs: bool,
+    ) -> Result<Self> {
         for batch in batches.iter() {
             // Don't check schema metadata in comparisons.
             // TODO: I have some issues in the Parquet reader where the batches are missing the
             // schema metadata.
-            if batch.schema().fields() != schema.fields() {
+            if batch.schema().fields() != schema.fields()
+                && !fields_match_up_to_crs(batch.schema().fields(), schema.fields(), check_crs)?
+            {
                 return Err(GeoArrowError::General(format!("Schema is not consistent across batches. Expected {}, got {}. With expected metadata: {:?}, got {:?}", schema, batch.schema(), schema.metadata(), batch.schema().metadata())));
             }
         }
@@ -469,6 +533,61 @@ impl Table {
         self.schema.fields().len()
     }
 
+    /// Returns the total number of bytes of memory pointed to by the columns of this table.
+    ///
+    /// This counts the full capacity of every buffer the columns reference. Buffers shared between
+    /// batches or columns, such as when the batches are slices of one larger batch, are only
+    /// counted once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # {
+    /// use std::fs::File;
+    ///
+    /// let file = File::open("fixtures/roads.geojson").unwrap();
+    /// let table = geoarrow::io::geojson::read_geojson(file, Default::default()).unwrap();
+    /// assert!(table.get_buffer_memory_size() > 0);
+    /// # }
+    /// ```
+    pub fn get_buffer_memory_size(&self) -> usize {
+        let data = self
+            .batches
+            .iter()
+            .flat_map(|batch| batch.columns())
+            .map(|column| column.to_data())
+            .collect::<Vec<_>>();
+        distinct_buffer_memory_size(&data)
+    }
+
+    /// Returns the total number of bytes of memory occupied by this table, including its buffers
+    /// and the array structs of each column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # {
+    /// use std::fs::File;
+    ///
+    /// let file = File::open("fixtures/roads.geojson").unwrap();
+    /// let table = geoarrow::io::geojson::read_geojson(file, Default::default()).unwrap();
+    /// assert!(table.get_array_memory_size() > table.get_buffer_memory_size());
+    /// # }
+    /// ```
+    pub fn get_array_memory_size(&self) -> usize {
+        let struct_size = self
+            .batches
+            .iter()
+            .flat_map(|batch| batch.columns())
+            .map(|column| {
+                column
+                    .get_array_memory_size()
+                    .saturating_sub(column.get_buffer_memory_size())
+            })
+            .sum::<usize>();
+        self.get_buffer_memory_size() + struct_size + std::mem::size_of_val(self)
+    }
+
     /// Replaces the column at index `i` with the given field and arrays.
     ///
     /// # Examples
@@ -621,3 +740,38 @@ impl TryFrom<Box<dyn arrow_array::RecordBatchReader + Send>> for Table {
         Table::try_new(batches, schema)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::point;
+
+    fn batch_with_crs(metadata: ArrayMetadata) -> RecordBatch {
+        let array = point::point_array().with_metadata(Arc::new(metadata));
+        let field = array.extension_field();
+        let schema = Arc::new(Schema::new(vec![field]));
+        RecordBatch::try_new(schema, vec![array.to_array_ref()]).unwrap()
+    }
+
+    #[test]
+    fn try_new_checks_crs() {
+        let authority_code =
+            batch_with_crs(ArrayMetadata::from_authority_code("EPSG:4326".to_string()));
+        let projjson = batch_with_crs(ArrayMetadata::from_projjson(serde_json::json!({
+            "type": "GeographicCRS",
+            "name": "WGS 84",
+            "id": {"authority": "EPSG", "code": 4326}
+        })));
+        let web_mercator =
+            batch_with_crs(ArrayMetadata::from_authority_code("EPSG:3857".to_string()));
+
+        let schema = authority_code.schema();
+        assert!(Table::try_new(vec![authority_code.clone(), projjson], schema.clone()).is_ok());
+        assert!(Table::try_new(
+            vec![authority_code.clone(), web_mercator.clone()],
+            schema.clone()
+        )
+        .is_err());
+        assert!(Table::try_new_unchecked(vec![authority_code, web_mercator], schema).is_ok());
+    }
+}
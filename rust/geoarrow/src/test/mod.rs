@@ -9,3 +9,4 @@ pub mod multipolygon;
 pub mod point;
 pub mod polygon;
 pub mod properties;
+pub mod table;
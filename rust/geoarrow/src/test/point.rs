@@ -97,6 +97,53 @@ pub(crate) fn point_z_array() -> PointArray {
     builder.finish()
 }
 
+/// A coordinate with a measure value, and a Z value if `dim` is XYZM.
+struct CoordM {
+    coords: Vec<f64>,
+    dim: Dimension,
+}
+
+impl CoordTrait for CoordM {
+    type T = f64;
+
+    fn dim(&self) -> geo_traits::Dimensions {
+        self.dim.into()
+    }
+
+    fn nth_or_panic(&self, n: usize) -> Self::T {
+        self.coords[n]
+    }
+
+    fn x(&self) -> Self::T {
+        self.coords[0]
+    }
+
+    fn y(&self) -> Self::T {
+        self.coords[1]
+    }
+}
+
+fn point_array_with_measures(dim: Dimension) -> PointArray {
+    let mut builder = PointBuilder::with_capacity(dim, 3);
+    for i in 0..3 {
+        let start = (i * dim.size()) as f64;
+        let coord = CoordM {
+            coords: (0..dim.size()).map(|j| start + j as f64).collect(),
+            dim,
+        };
+        builder.push_coord(Some(&coord));
+    }
+    builder.finish()
+}
+
+pub(crate) fn point_m_array() -> PointArray {
+    point_array_with_measures(Dimension::XYM)
+}
+
+pub(crate) fn point_zm_array() -> PointArray {
+    point_array_with_measures(Dimension::XYZM)
+}
+
 pub(crate) fn table() -> Table {
     let point_array = point_array();
     let u8_array = properties::u8_array();
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use arrow_array::{Int32Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+
+use crate::table::Table;
+use crate::NativeArray;
+
+/// A single-batch table with a non-null `id` column followed by `geometry`.
+pub(crate) fn id_table(ids: Vec<i32>, geometry: Arc<dyn NativeArray>) -> Table {
+    let schema: SchemaRef = Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        geometry.extension_field().as_ref().clone(),
+    ])
+    .into();
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int32Array::from(ids)), geometry.to_array_ref()],
+    )
+    .unwrap();
+    Table::try_new(vec![batch], schema).unwrap()
+}
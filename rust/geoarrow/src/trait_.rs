@@ -1,6 +1,7 @@
 //! Defines [`NativeArray`], which all geometry arrays implement, and other traits.
 
 use crate::array::metadata::ArrayMetadata;
+use crate::array::util::distinct_buffer_memory_size;
 use crate::array::{CoordBuffer, CoordType};
 use crate::datatypes::{Dimension, NativeType, SerializedType};
 use crate::error::Result;
@@ -153,6 +154,49 @@ pub trait ArrayBase: std::fmt::Debug + Send + Sync {
         self.len() == 0
     }
 
+    /// Returns the total number of bytes of memory pointed to by this array.
+    ///
+    /// This counts the full capacity of every buffer the array references, so a slice reports the
+    /// size of the buffers it shares with its parent array. Buffers referenced more than once are
+    /// only counted once. Use [`Compact`][crate::algorithm::native::Compact] to copy a slice into
+    /// buffers of its own size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{array::PointArray, ArrayBase};
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let points = vec![geo::point!(x: 1., y: 2.), geo::point!(x: 3., y: 4.)];
+    /// let point_array: PointArray = (points.as_slice(), Dimension::XY).into();
+    /// let sliced = point_array.slice(0, 1);
+    /// assert_eq!(
+    ///     sliced.get_buffer_memory_size(),
+    ///     point_array.get_buffer_memory_size()
+    /// );
+    /// ```
+    fn get_buffer_memory_size(&self) -> usize {
+        let data = arrow_array::Array::to_data(&self.to_array_ref());
+        distinct_buffer_memory_size([&data])
+    }
+
+    /// Returns the total number of bytes of memory occupied by this array, including both its
+    /// buffers and the array struct itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{array::PointArray, ArrayBase};
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let point = geo::point!(x: 1., y: 2.);
+    /// let point_array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+    /// assert!(point_array.get_array_memory_size() > point_array.get_buffer_memory_size());
+    /// ```
+    fn get_array_memory_size(&self) -> usize {
+        self.get_buffer_memory_size() + std::mem::size_of_val(self)
+    }
+
     /// Returns an optional reference to the array's nulls buffer.
     ///
     /// Every array has an optional [`NullBuffer`] that, when available
@@ -363,6 +407,41 @@ pub trait NativeArray: ArrayBase {
     /// This function panics iff `offset + length > self.len()`.
     #[must_use]
     fn slice(&self, offset: usize, length: usize) -> Arc<dyn NativeArray>;
+
+    /// Check that the buffers of this array are consistent with each other.
+    ///
+    /// This is a cheap structural check: it verifies that the validity buffer has one entry per
+    /// geometry, that offsets are non-decreasing and in bounds of the arrays they point into, and
+    /// that union type ids and offsets refer to existing children. Coordinate values are not
+    /// inspected; use [`validate_full`][Self::validate_full] for that.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoarrow::{NativeArray, array::PointArray};
+    /// use geoarrow::datatypes::Dimension;
+    ///
+    /// let point = geo::point!(x: 1., y: 2.);
+    /// let array: PointArray = (vec![point].as_slice(), Dimension::XY).into();
+    /// array.validate().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first offending row if the array is malformed.
+    fn validate(&self) -> Result<()>;
+
+    /// Check the structure of this array as in [`validate`][Self::validate], and additionally
+    /// check each geometry.
+    ///
+    /// This verifies that line strings do not consist of a single coordinate and that polygon
+    /// rings are either empty or closed with at least four coordinates. This is `O(n)` in the
+    /// number of coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first offending row if the array is malformed.
+    fn validate_full(&self) -> Result<()>;
 }
 
 /// Type alias for a dynamic reference to something that implements [NativeArray].